@@ -0,0 +1,89 @@
+using OxidizePdf.NET.Ai;
+using OxidizePdf.NET.Tests.TestHelpers;
+
+namespace OxidizePdf.NET.Tests;
+
+/// <summary>
+/// Tests for the streaming chunk iterators: the streamed sequences match the
+/// one-shot lists on documents without ruled tables.
+/// </summary>
+public class ChunkStreamTests
+{
+    [Fact]
+    public async Task StreamRagChunks_MatchesRagChunksAsync()
+    {
+        var extractor = new PdfExtractor();
+        var pdf = PdfTestFixtures.GetMultiPagePdf(5);
+
+        var expected = await extractor.RagChunksAsync(pdf);
+        var streamed = extractor.StreamRagChunks(pdf).ToList();
+
+        Assert.Equal(expected.Select(c => c.Text), streamed.Select(c => c.Text));
+        Assert.Equal(expected.Select(c => c.ChunkIndex), streamed.Select(c => c.ChunkIndex));
+    }
+
+    [Fact]
+    public async Task StreamSemanticChunks_MatchesSemanticChunksAsync()
+    {
+        var extractor = new PdfExtractor();
+        var pdf = PdfTestFixtures.GetMultiPagePdf(5);
+
+        var expected = await extractor.SemanticChunksAsync(pdf);
+        var streamed = extractor.StreamSemanticChunks(pdf).ToList();
+
+        Assert.Equal(expected.Select(c => c.Text), streamed.Select(c => c.Text));
+    }
+
+    [Fact]
+    public void ChunkPdfStream_NumbersChunksAcrossPages()
+    {
+        var chunker = new DocumentChunker(8, 2);
+        var pdf = PdfTestFixtures.GetMultiPagePdf(3);
+
+        var chunks = chunker.ChunkPdfStream(pdf).ToList();
+
+        Assert.NotEmpty(chunks);
+        Assert.Equal(Enumerable.Range(0, chunks.Count), chunks.Select(c => c.ChunkIndex));
+        Assert.All(chunks, c => Assert.Single(c.PageNumbers));
+        Assert.Equal(new[] { 1, 2, 3 }, chunks.Select(c => c.PageNumbers[0]).Distinct());
+    }
+
+    [Fact]
+    public void Stream_CanStopEarly()
+    {
+        var extractor = new PdfExtractor();
+
+        var first = extractor.StreamRagChunks(PdfTestFixtures.GetMultiPagePdf(5)).First();
+
+        Assert.NotEmpty(first.Text);
+    }
+
+    [Fact]
+    public void Stream_SecondEnumeration_Throws()
+    {
+        var extractor = new PdfExtractor();
+        var chunks = extractor.StreamRagChunks(PdfTestFixtures.GetMultiPagePdf(2));
+
+        _ = chunks.ToList();
+
+        Assert.Throws<ObjectDisposedException>(() => chunks.ToList());
+    }
+
+    [Fact]
+    public void Stream_CorruptedPdf_ThrowsWhenOpened()
+    {
+        var extractor = new PdfExtractor();
+
+        Assert.Throws<PdfExtractionException>(
+            () => extractor.StreamRagChunks(PdfTestFixtures.GetCorruptedPdf()));
+    }
+
+    [Fact]
+    public void Stream_NullBytes_Throws()
+    {
+        var extractor = new PdfExtractor();
+
+        Assert.Throws<ArgumentNullException>(() => extractor.StreamSemanticChunks(null!));
+        Assert.Throws<ArgumentNullException>(() => new DocumentChunker().ChunkPdfStream(null!));
+    }
+}
@@ -0,0 +1,80 @@
+using System.Text.Json;
+using OxidizePdf.NET.Models;
+using OxidizePdf.NET.Tests.TestHelpers;
+
+namespace OxidizePdf.NET.Tests;
+
+/// <summary>
+/// Tests for the <c>*EncodedAsync</c> methods: the JSON encoding decodes to
+/// the records of the typed calls, and the CBOR encoding is a CBOR array.
+/// </summary>
+public class EncodedResultsTests
+{
+    private readonly PdfExtractor _extractor = new();
+
+    // Major type 4 (array) in the top three bits of the initial byte.
+    private static bool IsCborArray(byte[] encoded) => encoded.Length > 0 && encoded[0] >> 5 == 4;
+
+    [Fact]
+    public async Task PartitionEncoded_Json_MatchesPartitionAsync()
+    {
+        var pdf = PdfTestFixtures.GetSamplePdf();
+
+        var json = await _extractor.PartitionEncodedAsync(pdf, PdfResultEncoding.Json);
+        var decoded = JsonSerializer.Deserialize<List<PdfElement>>(json)!;
+
+        var expected = await _extractor.PartitionAsync(pdf);
+        Assert.Equal(expected.Select(e => e.Text), decoded.Select(e => e.Text));
+    }
+
+    [Fact]
+    public async Task RagChunksEncoded_Json_MatchesRagChunksAsync()
+    {
+        var pdf = PdfTestFixtures.GetMultiPagePdf(3);
+
+        var json = await _extractor.RagChunksEncodedAsync(pdf, PdfResultEncoding.Json);
+        var decoded = JsonSerializer.Deserialize<List<RagChunk>>(json)!;
+
+        var expected = await _extractor.RagChunksAsync(pdf);
+        Assert.Equal(expected.Select(c => c.Text), decoded.Select(c => c.Text));
+    }
+
+    [Fact]
+    public async Task Cbor_IsAnArray()
+    {
+        var pdf = PdfTestFixtures.GetSamplePdf();
+
+        Assert.True(IsCborArray(await _extractor.PartitionEncodedAsync(pdf, PdfResultEncoding.Cbor)));
+        Assert.True(IsCborArray(await _extractor.RagChunksEncodedAsync(pdf, PdfResultEncoding.Cbor)));
+        Assert.True(IsCborArray(await _extractor.GetAnnotationsEncodedAsync(pdf, PdfResultEncoding.Cbor)));
+        Assert.True(IsCborArray(await _extractor.GetFormFieldsEncodedAsync(pdf, PdfResultEncoding.Cbor)));
+        Assert.True(IsCborArray(await PdfOperations.ExtractImagesEncodedAsync(pdf, PdfResultEncoding.Cbor)));
+    }
+
+    [Fact]
+    public async Task FormFieldsEncoded_Json_WithoutForm_IsEmptyArray()
+    {
+        var json = await _extractor.GetFormFieldsEncodedAsync(
+            PdfTestFixtures.GetSamplePdf(), PdfResultEncoding.Json);
+
+        Assert.Empty(JsonSerializer.Deserialize<List<FormField>>(json)!);
+    }
+
+    [Fact]
+    public async Task UndefinedEncoding_Throws()
+    {
+        var pdf = PdfTestFixtures.GetSamplePdf();
+
+        await Assert.ThrowsAsync<ArgumentOutOfRangeException>(
+            () => _extractor.PartitionEncodedAsync(pdf, (PdfResultEncoding)7));
+        await Assert.ThrowsAsync<ArgumentOutOfRangeException>(
+            () => PdfOperations.ExtractImagesEncodedAsync(pdf, (PdfResultEncoding)7));
+    }
+
+    [Fact]
+    public async Task CorruptedPdf_ThrowsPdfExtractionException()
+    {
+        await Assert.ThrowsAsync<PdfExtractionException>(
+            () => _extractor.PartitionEncodedAsync(PdfTestFixtures.GetCorruptedPdf(), PdfResultEncoding.Cbor));
+    }
+}
@@ -0,0 +1,74 @@
+using OxidizePdf.NET.Ai;
+using OxidizePdf.NET.Tests.TestHelpers;
+
+namespace OxidizePdf.NET.Tests;
+
+/// <summary>
+/// Tests for the file-path entry points: a memory-mapped file reads the same
+/// as the byte-array overloads on its contents.
+/// </summary>
+public sealed class FileInputTests : IDisposable
+{
+    private readonly PdfExtractor _extractor = new();
+    private readonly string _path = Path.GetTempFileName();
+
+    public void Dispose() => File.Delete(_path);
+
+    [Fact]
+    public async Task OpenFile_MatchesOpenOnBytes()
+    {
+        var pdf = PdfTestFixtures.GetMultiPagePdf(3);
+        await File.WriteAllBytesAsync(_path, pdf);
+
+        using var fromFile = PdfParsedDocument.OpenFile(_path);
+        using var fromBytes = PdfParsedDocument.Open(pdf);
+
+        Assert.Equal(fromBytes.PageCount, fromFile.PageCount);
+        Assert.Equal(fromBytes.ExtractText(), fromFile.ExtractText());
+        Assert.Equal(fromBytes.RagChunks().Select(c => c.Text), fromFile.RagChunks().Select(c => c.Text));
+    }
+
+    [Fact]
+    public async Task OpenFile_WithPassword_UnlocksEncryptedFile()
+    {
+        await File.WriteAllBytesAsync(_path, PdfTestFixtures.GetEncryptedPdf("user-secret"));
+
+        using var doc = PdfParsedDocument.OpenFile(_path, "user-secret");
+
+        Assert.Contains("Encrypted content", doc.ExtractText());
+    }
+
+    [Fact]
+    public async Task StreamsFromFile_MatchByteStreams()
+    {
+        var pdf = PdfTestFixtures.GetMultiPagePdf(4);
+        await File.WriteAllBytesAsync(_path, pdf);
+        var chunker = new DocumentChunker(8, 2);
+
+        Assert.Equal(
+            _extractor.StreamRagChunks(pdf).Select(c => c.Text),
+            _extractor.StreamRagChunksFromFile(_path).Select(c => c.Text));
+        Assert.Equal(
+            _extractor.StreamSemanticChunks(pdf).Select(c => c.Text),
+            _extractor.StreamSemanticChunksFromFile(_path).Select(c => c.Text));
+        Assert.Equal(
+            chunker.ChunkPdfStream(pdf).Select(c => c.Content),
+            chunker.ChunkPdfFileStream(_path).Select(c => c.Content));
+    }
+
+    [Fact]
+    public void MissingFile_ThrowsPdfExtractionException()
+    {
+        var missing = Path.Combine(Path.GetTempPath(), Guid.NewGuid().ToString("N") + ".pdf");
+
+        Assert.Throws<PdfExtractionException>(() => PdfParsedDocument.OpenFile(missing));
+        Assert.Throws<PdfExtractionException>(() => _extractor.StreamRagChunksFromFile(missing));
+    }
+
+    [Fact]
+    public void EmptyPath_Throws()
+    {
+        Assert.Throws<ArgumentNullException>(() => PdfParsedDocument.OpenFile(null!));
+        Assert.Throws<ArgumentException>(() => new DocumentChunker().ChunkPdfFileStream(""));
+    }
+}
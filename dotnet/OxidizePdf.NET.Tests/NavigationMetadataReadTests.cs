@@ -0,0 +1,93 @@
+using OxidizePdf.NET.Tests.TestHelpers;
+
+namespace OxidizePdf.NET.Tests;
+
+/// <summary>
+/// Tests for reading the catalog's navigation entries: what the
+/// <see cref="PdfDocument"/> setters write reads back unchanged.
+/// </summary>
+public class NavigationMetadataReadTests
+{
+    private static byte[] NavigablePdf()
+    {
+        using var doc = new PdfDocument();
+        for (var i = 0; i < 4; i++)
+        {
+            using var page = PdfPage.A4();
+            doc.AddPage(page);
+        }
+
+        doc.SetOpenAction(PdfOpenAction.GoTo(1, PdfDestination.Xyz(1, 10, 20, 1.5)))
+            .SetViewerPreferences(new PdfViewerPreferences
+            {
+                HideToolbar = true,
+                PageLayout = PdfPageLayout.TwoColumnLeft,
+            })
+            .AddNamedDestination("chapter", PdfDestination.Fit(3))
+            .SetPageLabels(PdfPageLabels.Create()
+                .AddRange(0, PdfPageLabelStyle.LowercaseRoman)
+                .AddRange(2, PdfPageLabelStyle.DecimalArabic, prefix: "A-", startAt: 5));
+        return doc.SaveToBytes();
+    }
+
+    [Fact]
+    public async Task GetNavigationMetadataAsync_ReadsBackCatalogEntries()
+    {
+        var meta = await new PdfExtractor().GetNavigationMetadataAsync(NavigablePdf());
+
+        Assert.Equal("goto", meta.OpenAction?.Kind);
+        Assert.Equal(1, meta.OpenAction?.Destination?.PageIndex);
+        Assert.Equal(PdfDestinationFit.Xyz, meta.OpenAction?.Destination?.FitMode);
+        Assert.Equal(1.5, meta.OpenAction?.Destination?.Zoom);
+
+        Assert.True(meta.ViewerPreferences.HideToolbar);
+        Assert.Equal(PdfPageLayout.TwoColumnLeft, meta.ViewerPreferences.PageLayout);
+        Assert.Null(meta.ViewerPreferences.FitWindow);
+
+        var chapter = Assert.Single(meta.NamedDestinations);
+        Assert.Equal("chapter", chapter.Name);
+        Assert.Equal(3, chapter.Destination.PageIndex);
+
+        var labels = meta.PageLabels!;
+        Assert.Equal(2, labels.Ranges.Count);
+        Assert.Equal(PdfPageLabelStyle.DecimalArabic, labels.Ranges[1].Style);
+        Assert.Equal("A-", labels.Ranges[1].Prefix);
+        Assert.Equal(5u, labels.Ranges[1].StartAt);
+        Assert.Equal(new[] { "i", "ii", "A-5", "A-6" }, labels.Labels);
+    }
+
+    [Fact]
+    public async Task PlainDocument_HasNoNavigationEntries()
+    {
+        var meta = await new PdfExtractor().GetNavigationMetadataAsync(PdfTestFixtures.GetValidSinglePagePdf());
+
+        Assert.Null(meta.OpenAction);
+        Assert.Null(meta.PageLabels);
+        Assert.Empty(meta.NamedDestinations);
+        Assert.Null(meta.ViewerPreferences.HideToolbar);
+    }
+
+    [Fact]
+    public async Task DocumentHandle_MatchesExtractor()
+    {
+        var pdf = NavigablePdf();
+        var expected = await new PdfExtractor().GetNavigationMetadataAsync(pdf);
+
+        using var parsed = PdfParsedDocument.Open(pdf);
+        var meta = parsed.GetNavigationMetadata();
+
+        Assert.Equal(expected.PageLabels!.Labels, meta.PageLabels?.Labels);
+        Assert.Equal(expected.NamedDestinations.Select(d => d.Name), meta.NamedDestinations.Select(d => d.Name));
+        Assert.Equal(expected.OpenAction?.Destination?.PageIndex, meta.OpenAction?.Destination?.PageIndex);
+    }
+
+    [Fact]
+    public async Task InvalidInput_Throws()
+    {
+        var extractor = new PdfExtractor();
+
+        await Assert.ThrowsAsync<ArgumentNullException>(() => extractor.GetNavigationMetadataAsync(null!));
+        await Assert.ThrowsAsync<PdfExtractionException>(
+            () => extractor.GetNavigationMetadataAsync(PdfTestFixtures.GetCorruptedPdf()));
+    }
+}
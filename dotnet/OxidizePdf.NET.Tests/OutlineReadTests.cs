@@ -0,0 +1,86 @@
+using OxidizePdf.NET.Tests.TestHelpers;
+
+namespace OxidizePdf.NET.Tests;
+
+/// <summary>
+/// Tests for reading the outline of an existing PDF: an outline written with
+/// <see cref="PdfDocument.SetOutline"/> reads back with its nesting, targets
+/// and styles.
+/// </summary>
+public class OutlineReadTests
+{
+    private static byte[] OutlinedPdf()
+    {
+        using var doc = new PdfDocument();
+        for (var i = 0; i < 3; i++)
+        {
+            using var page = PdfPage.A4();
+            doc.AddPage(page);
+        }
+
+        var outline = new PdfOutline();
+        outline.AddItem(new PdfOutlineItem("Preface", pageIndex: 0) { IsItalic = true });
+        outline.AddItem(new PdfOutlineItem("Chapter 1", pageIndex: 1)
+        {
+            IsBold = true,
+            IsOpen = false,
+            Children = [new PdfOutlineItem("Section 1.1", pageIndex: 2)],
+        });
+        doc.SetOutline(outline);
+        return doc.SaveToBytes();
+    }
+
+    [Fact]
+    public async Task GetOutlineAsync_RoundTripsNestingTargetsAndStyles()
+    {
+        var items = await new PdfExtractor().GetOutlineAsync(OutlinedPdf());
+
+        Assert.Equal(new[] { "Preface", "Chapter 1" }, items.Select(i => i.Title));
+
+        var preface = items[0];
+        Assert.Equal(0, preface.PageIndex);
+        Assert.Equal(0, preface.Destination?.PageIndex);
+        Assert.Equal(PdfDestinationFit.Fit, preface.Destination?.FitMode);
+        Assert.True(preface.IsItalic);
+        Assert.False(preface.IsBold);
+        Assert.Null(preface.Action);
+        Assert.Empty(preface.Children);
+
+        var chapter = items[1];
+        Assert.True(chapter.IsBold);
+        Assert.False(chapter.IsOpen);
+        var section = Assert.Single(chapter.Children);
+        Assert.Equal("Section 1.1", section.Title);
+        Assert.Equal(2, section.PageIndex);
+    }
+
+    [Fact]
+    public async Task DocumentWithoutOutline_HasNoItems()
+    {
+        Assert.Empty(await new PdfExtractor().GetOutlineAsync(PdfTestFixtures.GetValidSinglePagePdf()));
+    }
+
+    [Fact]
+    public async Task DocumentHandle_MatchesExtractor()
+    {
+        var pdf = OutlinedPdf();
+        var expected = await new PdfExtractor().GetOutlineAsync(pdf);
+
+        using var parsed = PdfParsedDocument.Open(pdf);
+        var items = parsed.GetOutline();
+
+        Assert.Equal(expected.Select(i => (i.Title, i.PageIndex)), items.Select(i => (i.Title, i.PageIndex)));
+        Assert.Equal("Section 1.1", items[1].Children[0].Title);
+    }
+
+    [Fact]
+    public async Task InvalidInput_Throws()
+    {
+        var extractor = new PdfExtractor();
+
+        await Assert.ThrowsAsync<ArgumentNullException>(() => extractor.GetOutlineAsync(null!));
+        await Assert.ThrowsAsync<ArgumentException>(() => extractor.GetOutlineAsync(Array.Empty<byte>()));
+        await Assert.ThrowsAsync<PdfExtractionException>(
+            () => extractor.GetOutlineAsync(PdfTestFixtures.GetCorruptedPdf()));
+    }
+}
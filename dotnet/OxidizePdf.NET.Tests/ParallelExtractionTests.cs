@@ -0,0 +1,70 @@
+using OxidizePdf.NET.Tests.TestHelpers;
+
+namespace OxidizePdf.NET.Tests;
+
+/// <summary>
+/// Tests for the parallel page extraction: output matches the sequential
+/// call for any thread count.
+/// </summary>
+public class ParallelExtractionTests
+{
+    private readonly PdfExtractor _extractor = new();
+
+    [Theory]
+    [InlineData(0)]
+    [InlineData(1)]
+    [InlineData(4)]
+    [InlineData(1000)]
+    public async Task ExtractTextParallel_MatchesExtractTextAsync(int threads)
+    {
+        var pdf = PdfTestFixtures.GetMultiPagePdf(6);
+
+        var expected = await _extractor.ExtractTextAsync(pdf);
+
+        Assert.Equal(expected, await _extractor.ExtractTextParallelAsync(pdf, threads));
+    }
+
+    [Theory]
+    [InlineData(0)]
+    [InlineData(1)]
+    [InlineData(4)]
+    public async Task PartitionParallel_MatchesPartitionAsync(int threads)
+    {
+        var pdf = PdfTestFixtures.GetMultiPagePdf(6);
+
+        var expected = await _extractor.PartitionAsync(pdf);
+        var elements = await _extractor.PartitionParallelAsync(pdf, threads);
+
+        Assert.Equal(expected.Select(e => e.Text), elements.Select(e => e.Text));
+        Assert.Equal(expected.Select(e => e.PageNumber), elements.Select(e => e.PageNumber));
+    }
+
+    [Fact]
+    public async Task ConcurrentParallelCalls_AllMatch()
+    {
+        var pdf = PdfTestFixtures.GetMultiPagePdf(4);
+        var expected = await _extractor.ExtractTextAsync(pdf);
+
+        var results = await Task.WhenAll(
+            Enumerable.Range(0, 8).Select(_ => _extractor.ExtractTextParallelAsync(pdf)));
+
+        Assert.All(results, text => Assert.Equal(expected, text));
+    }
+
+    [Fact]
+    public async Task InvalidArguments_Throw()
+    {
+        var pdf = PdfTestFixtures.GetValidSinglePagePdf();
+
+        await Assert.ThrowsAsync<ArgumentOutOfRangeException>(() => _extractor.ExtractTextParallelAsync(pdf, -1));
+        await Assert.ThrowsAsync<ArgumentOutOfRangeException>(() => _extractor.PartitionParallelAsync(pdf, -1));
+        await Assert.ThrowsAsync<ArgumentNullException>(() => _extractor.ExtractTextParallelAsync(null!));
+    }
+
+    [Fact]
+    public async Task CorruptedPdf_ThrowsPdfExtractionException()
+    {
+        await Assert.ThrowsAsync<PdfExtractionException>(
+            () => _extractor.PartitionParallelAsync(PdfTestFixtures.GetCorruptedPdf()));
+    }
+}
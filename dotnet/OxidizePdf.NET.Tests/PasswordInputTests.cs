@@ -0,0 +1,120 @@
+using OxidizePdf.NET.Ai;
+using OxidizePdf.NET.Tests.TestHelpers;
+
+namespace OxidizePdf.NET.Tests;
+
+/// <summary>
+/// Tests for the password overloads: encrypted PDFs are unlocked with the user
+/// or owner password, and unencrypted input ignores the password.
+/// </summary>
+public class PasswordInputTests
+{
+    private const string UserPassword = "user-secret";
+    private const string OwnerPassword = "owner-secret";
+
+    private readonly PdfExtractor _extractor = new();
+    private readonly byte[] _encrypted = PdfTestFixtures.GetEncryptedPdf(UserPassword, OwnerPassword);
+
+    [Fact]
+    public void ParsedDocument_OpenWithPassword_ReadsEverything()
+    {
+        using var doc = PdfParsedDocument.Open(_encrypted, UserPassword);
+
+        Assert.Equal(1, doc.PageCount);
+        Assert.Contains("Encrypted content", doc.ExtractText());
+        Assert.NotEmpty(doc.Partition());
+        Assert.NotEmpty(doc.RagChunks());
+    }
+
+    [Fact]
+    public void ParsedDocument_OwnerPassword_AlsoUnlocks()
+    {
+        using var doc = PdfParsedDocument.Open(_encrypted, OwnerPassword);
+
+        Assert.Contains("Encrypted content", doc.ExtractText());
+    }
+
+    [Fact]
+    public void ParsedDocument_WrongPassword_ReportsPasswordRequired()
+    {
+        var ex = Assert.Throws<PdfExtractionException>(() => PdfParsedDocument.Open(_encrypted, "wrong"));
+
+        Assert.Equal(12, ex.Details?.Code);
+    }
+
+    [Fact]
+    public async Task Extractor_PasswordOverloads_ReadEncryptedInput()
+    {
+        Assert.Contains("Encrypted content", await _extractor.ExtractTextAsync(_encrypted, UserPassword));
+        Assert.Contains("Encrypted content", await _extractor.ExtractTextFromPageAsync(_encrypted, UserPassword, 1));
+        Assert.Equal(1, await _extractor.GetPageCountAsync(_encrypted, UserPassword));
+        Assert.Equal(1, (await _extractor.ExtractMetadataAsync(_encrypted, UserPassword)).PageCount);
+        Assert.Empty(await _extractor.GetFormFieldsAsync(_encrypted, UserPassword));
+    }
+
+    [Fact]
+    public async Task Extractor_PipelinePasswordOverloads_ReadEncryptedInput()
+    {
+        var elements = await _extractor.PartitionAsync(_encrypted, UserPassword, null);
+        var rag = await _extractor.RagChunksAsync(_encrypted, UserPassword, null, null);
+        var semantic = await _extractor.SemanticChunksAsync(_encrypted, UserPassword, null, null);
+
+        Assert.Contains(elements, e => e.Text.Contains("Encrypted content"));
+        Assert.NotEmpty(rag);
+        Assert.NotEmpty(semantic);
+    }
+
+    [Fact]
+    public async Task Extractor_WithoutPassword_FailsWithPasswordRequired()
+    {
+        var ex = await Assert.ThrowsAsync<PdfExtractionException>(() => _extractor.PartitionAsync(_encrypted));
+
+        Assert.Equal("password_required", ex.Details?.Category);
+    }
+
+    [Fact]
+    public async Task UnencryptedInput_IgnoresPassword()
+    {
+        var pdf = PdfTestFixtures.GetMultiPagePdf(2);
+
+        Assert.Equal(await _extractor.ExtractTextAsync(pdf), await _extractor.ExtractTextAsync(pdf, "unused"));
+    }
+
+    [Fact]
+    public void DocumentChunker_WithPassword_ChunksEncryptedInput()
+    {
+        var chunks = new DocumentChunker().ChunkPdf(_encrypted, UserPassword);
+
+        Assert.Contains(chunks, c => c.Content.Contains("Encrypted content"));
+    }
+
+    [Fact]
+    [Trait("Category", "Integration")]
+    public async Task Operations_WithPassword_ProcessEncryptedInput()
+    {
+        Assert.Single(await PdfOperations.SplitAsync(_encrypted, UserPassword));
+        Assert.NotEmpty(await PdfOperations.RotateAsync(_encrypted, UserPassword, 90));
+        Assert.NotEmpty(await PdfOperations.ReversePagesAsync(_encrypted, UserPassword));
+    }
+
+    [Fact]
+    [Trait("Category", "Integration")]
+    public async Task MergeInput_Password_UnlocksEncryptedInput()
+    {
+        var merged = await PdfOperations.MergeAsync(new[]
+        {
+            new PdfMergeInput(_encrypted) { Password = UserPassword },
+            new PdfMergeInput(PdfTestFixtures.GetMultiPagePdf(1)),
+        });
+
+        Assert.Equal(2, await _extractor.GetPageCountAsync(merged));
+    }
+
+    [Fact]
+    public async Task NullPassword_Throws()
+    {
+        await Assert.ThrowsAsync<ArgumentNullException>(
+            () => _extractor.ExtractTextAsync(_encrypted, (string)null!));
+        Assert.Throws<ArgumentNullException>(() => PdfParsedDocument.Open(_encrypted, null!));
+    }
+}
@@ -0,0 +1,133 @@
+using OxidizePdf.NET.Ai;
+using OxidizePdf.NET.Models;
+using OxidizePdf.NET.Pipeline;
+using OxidizePdf.NET.Tests.TestHelpers;
+
+namespace OxidizePdf.NET.Tests;
+
+/// <summary>
+/// Tests for batch processing: each slot matches the single-file call on the
+/// same input, and a bad input fails only its own slot.
+/// </summary>
+public sealed class PdfBatchTests : IDisposable
+{
+    private readonly PdfExtractor _extractor = new();
+    private readonly string _path = Path.GetTempFileName();
+
+    public void Dispose() => File.Delete(_path);
+
+    [Fact]
+    public async Task ExtractText_ReportsEachInputInOrder()
+    {
+        var fromBytes = PdfTestFixtures.GetMultiPagePdf(2);
+        var fromFile = PdfTestFixtures.GetMultiPagePdf(3);
+        await File.WriteAllBytesAsync(_path, fromFile);
+
+        var results = await PdfBatch.ExtractTextAsync(new[]
+        {
+            PdfBatchInput.FromBytes(fromBytes),
+            PdfBatchInput.FromBytes(PdfTestFixtures.GetCorruptedPdf()),
+            PdfBatchInput.FromFile(_path),
+        }, threads: 2);
+
+        Assert.Equal(new[] { 0, 1, 2 }, results.Select(r => r.Index));
+        Assert.True(results[0].Succeeded);
+        Assert.Equal(await _extractor.ExtractTextAsync(fromBytes), results[0].Value);
+        Assert.False(results[1].Succeeded);
+        Assert.Null(results[1].Value);
+        Assert.NotEmpty(results[1].Error!.Category);
+        Assert.Equal(await _extractor.ExtractTextAsync(fromFile), results[2].Value);
+    }
+
+    [Fact]
+    public async Task ExtractText_UsesPasswordAndOptions()
+    {
+        var encrypted = PdfTestFixtures.GetEncryptedPdf("user-secret");
+        var pdf = PdfTestFixtures.GetMultiPagePdf(1);
+        var options = new ExtractionOptions { PreserveLayout = true };
+        var locked = PdfBatchInput.FromBytes(encrypted);
+        var unlocked = PdfBatchInput.FromBytes(encrypted);
+        unlocked.Password = "user-secret";
+        var withOptions = PdfBatchInput.FromBytes(pdf);
+        withOptions.ExtractionOptions = options;
+
+        var results = await PdfBatch.ExtractTextAsync(new[] { locked, unlocked, withOptions });
+
+        Assert.Equal((int)NativeMethods.ErrorCode.PasswordRequired, results[0].Error?.Code);
+        Assert.Contains("Encrypted content", results[1].Value);
+        Assert.Equal(await _extractor.ExtractTextAsync(pdf, options), results[2].Value);
+    }
+
+    [Fact]
+    public async Task Partition_AppliesPerInputOptions()
+    {
+        var pdf = PdfTestFixtures.GetSamplePdf();
+        var config = new PartitionConfig().WithoutTables();
+        var withProfile = PdfBatchInput.FromBytes(pdf);
+        withProfile.Profile = ExtractionProfile.Rag;
+        var withConfig = PdfBatchInput.FromBytes(pdf);
+        withConfig.PartitionConfig = config;
+
+        var results = await PdfBatch.PartitionAsync(new[] { PdfBatchInput.FromBytes(pdf), withProfile, withConfig });
+
+        Assert.All(results, r => Assert.True(r.Succeeded));
+        Assert.Equal(
+            (await _extractor.PartitionAsync(pdf)).Select(e => e.Text),
+            results[0].Value!.Select(e => e.Text));
+        Assert.Equal(
+            (await _extractor.PartitionAsync(pdf, ExtractionProfile.Rag)).Select(e => e.Text),
+            results[1].Value!.Select(e => e.Text));
+        Assert.Equal(
+            (await _extractor.PartitionAsync(pdf, config)).Select(e => e.Text),
+            results[2].Value!.Select(e => e.Text));
+    }
+
+    [Fact]
+    public async Task RagChunksAndChunk_MatchSingleFileCalls()
+    {
+        var pdf = PdfTestFixtures.GetMultiPagePdf(3);
+        var chunker = new DocumentChunker(8, 2);
+        var inputs = new[] { PdfBatchInput.FromBytes(pdf) };
+
+        var rag = await PdfBatch.RagChunksAsync(inputs);
+        var chunks = await PdfBatch.ChunkAsync(inputs, chunker);
+
+        Assert.Equal((await _extractor.RagChunksAsync(pdf)).Select(c => c.Text), rag[0].Value!.Select(c => c.Text));
+        Assert.Equal(chunker.ChunkPdf(pdf).Select(c => c.Content), chunks[0].Value!.Select(c => c.Content));
+    }
+
+    [Fact]
+    public async Task EmptyBatch_ReturnsNoResults()
+    {
+        Assert.Empty(await PdfBatch.ExtractTextAsync(Array.Empty<PdfBatchInput>()));
+    }
+
+    [Fact]
+    public async Task OptionTheOperationDoesNotUse_RejectsTheBatch()
+    {
+        var input = PdfBatchInput.FromBytes(PdfTestFixtures.GetValidSinglePagePdf());
+        input.Profile = ExtractionProfile.Rag;
+
+        var ex = await Assert.ThrowsAsync<PdfExtractionException>(
+            () => PdfBatch.ExtractTextAsync(new[] { input }));
+
+        Assert.Equal("invalid_argument", ex.Details?.Category);
+        Assert.Contains("$.inputs[0].profile", ex.Details?.Message);
+    }
+
+    [Fact]
+    public async Task InvalidArguments_Throw()
+    {
+        var input = PdfBatchInput.FromBytes(PdfTestFixtures.GetValidSinglePagePdf());
+        var invalid = PdfBatchInput.FromBytes(PdfTestFixtures.GetValidSinglePagePdf());
+        invalid.PartitionConfig = new PartitionConfig { HeaderZone = 2 };
+
+        Assert.Throws<ArgumentNullException>(() => PdfBatchInput.FromBytes(null!));
+        Assert.Throws<ArgumentException>(() => PdfBatchInput.FromFile(""));
+        await Assert.ThrowsAsync<ArgumentNullException>(() => PdfBatch.PartitionAsync(null!));
+        await Assert.ThrowsAsync<ArgumentNullException>(() => PdfBatch.PartitionAsync(new PdfBatchInput[] { null! }));
+        await Assert.ThrowsAsync<ArgumentOutOfRangeException>(() => PdfBatch.PartitionAsync(new[] { input }, -1));
+        await Assert.ThrowsAsync<ArgumentException>(() => PdfBatch.PartitionAsync(new[] { invalid }));
+        await Assert.ThrowsAsync<ArgumentNullException>(() => PdfBatch.ChunkAsync(new[] { input }, null!));
+    }
+}
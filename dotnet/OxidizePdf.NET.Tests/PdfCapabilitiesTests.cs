@@ -0,0 +1,53 @@
+using System.Text.Json;
+using OxidizePdf.NET.Pipeline;
+
+namespace OxidizePdf.NET.Tests;
+
+/// <summary>
+/// Tests for <see cref="PdfExtractor.GetCapabilities"/>: versions, features,
+/// encodings and payload schemas of the loaded native library.
+/// </summary>
+public class PdfCapabilitiesTests
+{
+    [Fact]
+    public void Capabilities_ReportVersionsFeaturesAndEncodings()
+    {
+        var caps = PdfExtractor.GetCapabilities();
+
+        Assert.Equal(PdfExtractor.Version, caps.Version);
+        Assert.False(string.IsNullOrEmpty(caps.CoreVersion));
+        Assert.Contains("compression", caps.CoreFeatures);
+        Assert.Equal(
+            Enum.GetNames<PdfResultEncoding>().Select(n => n.ToLowerInvariant()),
+            caps.ResultEncodings);
+    }
+
+    [Fact]
+    public void Payloads_HaveUniqueNamesAndTitledSchemas()
+    {
+        var payloads = PdfExtractor.GetCapabilities().Payloads;
+
+        Assert.NotEmpty(payloads);
+        Assert.Equal(payloads.Count, payloads.Select(p => p.Name).Distinct().Count());
+        Assert.All(payloads, p =>
+        {
+            Assert.Equal(p.Name, p.Schema.GetProperty("title").GetString());
+            Assert.NotEmpty(p.EntryPoints);
+            Assert.All(p.EntryPoints, e => Assert.StartsWith("oxidize_", e));
+        });
+    }
+
+    [Fact]
+    public void PartitionConfigSchema_MatchesTheManagedJson()
+    {
+        var schema = PdfExtractor.GetCapabilities().Payloads.Single(p => p.Name == "PartitionConfig").Schema;
+        var properties = schema.GetProperty("properties");
+
+        using var json = JsonDocument.Parse(new PartitionConfig().ToJson());
+
+        foreach (var field in json.RootElement.EnumerateObject())
+            Assert.True(properties.TryGetProperty(field.Name, out _), $"schema lacks {field.Name}");
+        foreach (var required in schema.GetProperty("required").EnumerateArray())
+            Assert.True(json.RootElement.TryGetProperty(required.GetString()!, out _), $"managed JSON lacks {required}");
+    }
+}
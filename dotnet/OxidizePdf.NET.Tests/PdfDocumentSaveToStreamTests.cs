@@ -0,0 +1,98 @@
+using System.Text;
+
+namespace OxidizePdf.NET.Tests;
+
+/// <summary>
+/// Tests for <c>PdfDocument.Save</c>: the document is written through the
+/// native write callbacks into a caller stream and parses back.
+/// </summary>
+public class PdfDocumentSaveToStreamTests
+{
+    private static PdfDocument CreateDocument()
+    {
+        var doc = new PdfDocument();
+        using var page = PdfPage.A4();
+        page.SetFont(StandardFont.Helvetica, 12)
+            .TextAt(50, 750, "Streamed content");
+        doc.AddPage(page);
+        return doc;
+    }
+
+    [Fact]
+    [Trait("Category", "Integration")]
+    public async Task Save_ToMemoryStream_Reparses()
+    {
+        using var doc = CreateDocument();
+        using var stream = new MemoryStream();
+
+        doc.Save(stream);
+
+        var extractor = new PdfExtractor();
+        var bytes = stream.ToArray();
+        Assert.Equal(1, await extractor.GetPageCountAsync(bytes));
+        Assert.Contains("Streamed content", await extractor.ExtractTextAsync(bytes));
+    }
+
+    [Fact]
+    [Trait("Category", "Integration")]
+    public void Save_WithOptions_WritesThatVersion()
+    {
+        using var doc = CreateDocument();
+        using var stream = new MemoryStream();
+
+        doc.Save(stream, PdfSaveOptions.Modern());
+
+        string text = Encoding.Latin1.GetString(stream.ToArray());
+        Assert.StartsWith("%PDF-1.5", text);
+        Assert.Contains("/Type /XRef", text);
+    }
+
+    [Fact]
+    public void Save_LeavesTheStreamOpen()
+    {
+        using var doc = CreateDocument();
+        using var stream = new MemoryStream();
+
+        doc.Save(stream);
+
+        Assert.True(stream.CanWrite);
+        Assert.True(stream.Length > 0);
+    }
+
+    [Fact]
+    public void Save_StreamException_IsRethrown()
+    {
+        using var doc = CreateDocument();
+        using var stream = new FailingStream();
+
+        Assert.Throws<IOException>(() => doc.Save(stream));
+    }
+
+    [Fact]
+    public void Save_InvalidArguments_Throw()
+    {
+        using var doc = CreateDocument();
+
+        Assert.Throws<ArgumentNullException>(() => doc.Save(null!));
+        Assert.Throws<ArgumentNullException>(() => doc.Save(new MemoryStream(), null!));
+        Assert.Throws<ArgumentException>(() => doc.Save(new MemoryStream(new byte[16], writable: false)));
+    }
+
+    [Fact]
+    public void Save_AfterDispose_Throws()
+    {
+        var doc = CreateDocument();
+        doc.Dispose();
+
+        Assert.Throws<ObjectDisposedException>(() => doc.Save(new MemoryStream()));
+    }
+
+    private sealed class FailingStream : MemoryStream
+    {
+        public override void Write(byte[] buffer, int offset, int count) =>
+            throw new IOException("write failed");
+
+        public override void Write(ReadOnlySpan<byte> buffer) =>
+            throw new IOException("write failed");
+    }
+}
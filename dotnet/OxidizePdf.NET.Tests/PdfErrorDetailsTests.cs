@@ -0,0 +1,56 @@
+using OxidizePdf.NET.Tests.TestHelpers;
+
+namespace OxidizePdf.NET.Tests;
+
+/// <summary>
+/// Tests for <see cref="PdfExtractionException.Details"/>: native failures
+/// carry the structured error recorded by the Rust layer.
+/// </summary>
+public class PdfErrorDetailsTests
+{
+    private readonly PdfExtractor _extractor = new();
+
+    [Fact]
+    public async Task PageOutOfRange_ReportsDedicatedCodeAndPageIndex()
+    {
+        var ex = await Assert.ThrowsAsync<PdfExtractionException>(
+            () => _extractor.GetPageDimensionsAsync(PdfTestFixtures.GetValidSinglePagePdf(), 9));
+
+        Assert.NotNull(ex.Details);
+        Assert.Equal(13, ex.Details!.Code);
+        Assert.Equal("page_out_of_range", ex.Details.Category);
+        Assert.Equal(8, ex.Details.PageIndex);
+        Assert.NotEmpty(ex.Details.Causes);
+    }
+
+    [Fact]
+    public async Task CorruptedPdf_ReportsParseFailureWithCauses()
+    {
+        var ex = await Assert.ThrowsAsync<PdfExtractionException>(
+            () => _extractor.ExtractTextAsync(PdfTestFixtures.GetCorruptedPdf()));
+
+        Assert.NotNull(ex.Details);
+        Assert.NotEqual(0, ex.Details!.Code);
+        Assert.False(string.IsNullOrEmpty(ex.Details.Category));
+        Assert.Contains(ex.Details.Message, ex.Message);
+        Assert.NotEmpty(ex.Details.Causes);
+    }
+
+    [Fact]
+    public async Task EncryptedPdfWithoutPassword_ReportsPasswordRequired()
+    {
+        var ex = await Assert.ThrowsAsync<PdfExtractionException>(
+            () => _extractor.ExtractTextAsync(PdfTestFixtures.GetEncryptedPdf("user-secret")));
+
+        Assert.Equal(12, ex.Details?.Code);
+        Assert.Equal("password_required", ex.Details?.Category);
+    }
+
+    [Fact]
+    public void ManagedSideFailures_HaveNoDetails()
+    {
+        var ex = new PdfExtractionException("managed");
+
+        Assert.Null(ex.Details);
+    }
+}
@@ -0,0 +1,62 @@
+using System.Collections.Concurrent;
+using OxidizePdf.NET.Models;
+using OxidizePdf.NET.Tests.TestHelpers;
+
+namespace OxidizePdf.NET.Tests;
+
+/// <summary>
+/// Tests for the process-wide native log handler. Every test clears the
+/// handler when it finishes.
+/// </summary>
+[Collection(ProcessWideSettingsCollection.Name)]
+public sealed class PdfLoggingTests : IDisposable
+{
+    private readonly PdfExtractor _extractor = new();
+
+    public void Dispose() => PdfExtractor.SetLogHandler(null);
+
+    private static bool IsXrefRepair(PdfLogRecord record) =>
+        record.Target.StartsWith("oxidize_pdf::parser", StringComparison.Ordinal)
+        && record.Message.Contains("XRef", StringComparison.Ordinal);
+
+    [Fact]
+    public async Task Handler_ReceivesRepairWarnings()
+    {
+        var records = new ConcurrentQueue<PdfLogRecord>();
+        PdfExtractor.SetLogHandler(records.Enqueue);
+
+        await _extractor.GetPageCountAsync(PdfTestFixtures.BreakStartXref(PdfTestFixtures.GetWrittenPdf(1)));
+
+        Assert.Contains(records, IsXrefRepair);
+        Assert.All(records.Where(IsXrefRepair), r => Assert.Equal(PdfLogLevel.Warning, r.Level));
+        Assert.All(records, r => Assert.True(r.Level <= PdfLogLevel.Warning));
+    }
+
+    [Fact]
+    public async Task ClearedHandler_ReceivesNothing()
+    {
+        var records = new ConcurrentQueue<PdfLogRecord>();
+        PdfExtractor.SetLogHandler(records.Enqueue);
+        PdfExtractor.SetLogHandler(null);
+
+        await _extractor.GetPageCountAsync(PdfTestFixtures.BreakStartXref(PdfTestFixtures.GetWrittenPdf(1)));
+
+        Assert.Empty(records);
+    }
+
+    [Fact]
+    public async Task ThrowingHandler_DoesNotBreakTheCall()
+    {
+        PdfExtractor.SetLogHandler(_ => throw new InvalidOperationException("handler failed"));
+
+        var count = await _extractor.GetPageCountAsync(PdfTestFixtures.BreakStartXref(PdfTestFixtures.GetWrittenPdf(2)));
+
+        Assert.Equal(2, count);
+    }
+
+    [Fact]
+    public void UndefinedLevel_Throws()
+    {
+        Assert.Throws<ArgumentOutOfRangeException>(() => PdfExtractor.SetLogHandler(_ => { }, (PdfLogLevel)9));
+    }
+}
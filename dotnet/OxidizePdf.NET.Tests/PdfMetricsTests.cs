@@ -0,0 +1,73 @@
+using OxidizePdf.NET.Ai;
+using OxidizePdf.NET.Tests.TestHelpers;
+
+namespace OxidizePdf.NET.Tests;
+
+/// <summary>
+/// Tests for the <c>*WithMetrics</c> calls: the output matches the plain call
+/// and the metrics describe the document.
+/// </summary>
+public class PdfMetricsTests
+{
+    private readonly PdfExtractor _extractor = new();
+
+    [Fact]
+    public async Task ExtractTextWithMetrics_MatchesExtractTextAsync()
+    {
+        var pdf = PdfTestFixtures.GetMultiPagePdf(3);
+
+        var (text, metrics) = await _extractor.ExtractTextWithMetricsAsync(pdf);
+
+        Assert.Equal(await _extractor.ExtractTextAsync(pdf), text);
+        Assert.Equal(3, metrics.PageCount);
+        Assert.Contains(metrics.Phases, p => p.Name == "parse");
+        Assert.Contains(metrics.Phases, p => p.Name == "text_extraction");
+        Assert.All(metrics.Phases, p => Assert.True(p.Ms >= 0));
+        Assert.True(metrics.TotalMs >= 0);
+    }
+
+    [Fact]
+    public async Task PartitionWithMetrics_MatchesPartitionAsync()
+    {
+        var pdf = PdfTestFixtures.GetSamplePdf();
+
+        var (elements, metrics) = await _extractor.PartitionWithMetricsAsync(pdf);
+
+        var expected = await _extractor.PartitionAsync(pdf);
+        Assert.Equal(expected.Select(e => e.Text), elements.Select(e => e.Text));
+        Assert.Equal("parse", metrics.Phases[0].Name);
+    }
+
+    [Fact]
+    public async Task RagChunksWithMetrics_MatchesRagChunksAsync()
+    {
+        var pdf = PdfTestFixtures.GetMultiPagePdf(2);
+
+        var (chunks, metrics) = await _extractor.RagChunksWithMetricsAsync(pdf);
+
+        var expected = await _extractor.RagChunksAsync(pdf);
+        Assert.Equal(expected.Select(c => c.Text), chunks.Select(c => c.Text));
+        Assert.Contains(metrics.Phases, p => p.Name == "chunking");
+    }
+
+    [Fact]
+    public void ChunkPdfWithMetrics_MatchesChunkPdf()
+    {
+        var chunker = new DocumentChunker(8, 2);
+        var pdf = PdfTestFixtures.GetMultiPagePdf(2);
+
+        var (chunks, metrics) = chunker.ChunkPdfWithMetrics(pdf);
+
+        Assert.Equal(chunker.ChunkPdf(pdf).Select(c => c.Content), chunks.Select(c => c.Content));
+        Assert.Equal(2, metrics.PageCount);
+    }
+
+    [Fact]
+    public async Task CorruptedPdf_ThrowsPdfExtractionException()
+    {
+        await Assert.ThrowsAsync<PdfExtractionException>(
+            () => _extractor.PartitionWithMetricsAsync(PdfTestFixtures.GetCorruptedPdf()));
+        Assert.Throws<PdfExtractionException>(
+            () => new DocumentChunker().ChunkPdfWithMetrics(PdfTestFixtures.GetCorruptedPdf()));
+    }
+}
@@ -0,0 +1,67 @@
+using OxidizePdf.NET.Tests.TestHelpers;
+
+namespace OxidizePdf.NET.Tests;
+
+/// <summary>
+/// Page operations run in memory: they keep the original page content and
+/// never stage PDFs in the temp directory, so they also work concurrently.
+/// </summary>
+public class PdfOperationsInMemoryTests
+{
+    [Fact]
+    [Trait("Category", "Integration")]
+    public async Task ReversePagesAsync_KeepsEachPagesContent()
+    {
+        var extractor = new PdfExtractor();
+        var pdf = PdfTestFixtures.GetMultiPagePdf(3);
+
+        var reversed = await PdfOperations.ReversePagesAsync(pdf);
+
+        Assert.Contains("Page 3 of 3", await extractor.ExtractTextFromPageAsync(reversed, 1));
+        Assert.Contains("Page 1 of 3", await extractor.ExtractTextFromPageAsync(reversed, 3));
+    }
+
+    [Fact]
+    [Trait("Category", "Integration")]
+    public async Task RotateAsync_90_SwapsPageSizeAndKeepsText()
+    {
+        var extractor = new PdfExtractor();
+        var pdf = PdfTestFixtures.GetMultiPagePdf(1);
+
+        var rotated = await PdfOperations.RotateAsync(pdf, 90);
+
+        Assert.Equal((792.0, 612.0), await extractor.GetPageDimensionsAsync(rotated, 1));
+        Assert.Contains("Page 1 of 1", await extractor.ExtractTextAsync(rotated));
+    }
+
+    [Fact]
+    [Trait("Category", "Integration")]
+    public async Task Operations_DoNotStageFilesInTempDirectory()
+    {
+        static int Staged() =>
+            Directory.EnumerateFiles(Path.GetTempPath(), "oxidize_*").Count();
+        var pdf = PdfTestFixtures.GetMultiPagePdf(2);
+        var before = Staged();
+
+        await PdfOperations.SplitAsync(pdf);
+        await PdfOperations.ReversePagesAsync(pdf);
+        await PdfOperations.RotateAsync(pdf, 180);
+        await PdfOperations.OverlayAsync(pdf, pdf);
+
+        Assert.Equal(before, Staged());
+    }
+
+    [Fact]
+    [Trait("Category", "Integration")]
+    public async Task ConcurrentOperations_AllSucceed()
+    {
+        var extractor = new PdfExtractor();
+        var pdf = PdfTestFixtures.GetMultiPagePdf(3);
+
+        var results = await Task.WhenAll(
+            Enumerable.Range(0, 16).Select(_ => PdfOperations.ReversePagesAsync(pdf)));
+
+        foreach (var reversed in results)
+            Assert.Equal(3, await extractor.GetPageCountAsync(reversed));
+    }
+}
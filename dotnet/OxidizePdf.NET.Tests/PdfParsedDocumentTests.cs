@@ -0,0 +1,116 @@
+using OxidizePdf.NET.Pipeline;
+using OxidizePdf.NET.Tests.TestHelpers;
+
+namespace OxidizePdf.NET.Tests;
+
+/// <summary>
+/// Tests for the persistent parsed-document handle: each read must match the
+/// stateless <see cref="PdfExtractor"/> call on the same bytes.
+/// </summary>
+public class PdfParsedDocumentTests
+{
+    [Fact]
+    public void Open_NullBytes_Throws()
+    {
+        Assert.Throws<ArgumentNullException>(() => PdfParsedDocument.Open(null!));
+    }
+
+    [Fact]
+    public void Open_EmptyBytes_Throws()
+    {
+        Assert.Throws<ArgumentException>(() => PdfParsedDocument.Open(Array.Empty<byte>()));
+    }
+
+    [Fact]
+    public void Open_CorruptedPdf_ThrowsPdfExtractionException()
+    {
+        Assert.Throws<PdfExtractionException>(
+            () => PdfParsedDocument.Open(PdfTestFixtures.GetCorruptedPdf()));
+    }
+
+    [Fact]
+    public async Task DocumentInfo_MatchesExtractor()
+    {
+        var extractor = new PdfExtractor();
+        var pdf = PdfTestFixtures.GetSamplePdf();
+
+        using var doc = PdfParsedDocument.Open(pdf);
+
+        Assert.Equal(await extractor.GetPageCountAsync(pdf), doc.PageCount);
+        Assert.Equal(await extractor.GetPdfVersionAsync(pdf), doc.PdfVersion);
+        Assert.Equal(await extractor.IsEncryptedAsync(pdf), doc.IsEncrypted);
+        Assert.Equal(await extractor.GetPageDimensionsAsync(pdf, 1), doc.GetPageDimensions(1));
+        Assert.Equal((await extractor.ExtractMetadataAsync(pdf)).PageCount, doc.GetMetadata().PageCount);
+    }
+
+    [Fact]
+    public async Task Text_MatchesExtractor()
+    {
+        var extractor = new PdfExtractor();
+        var pdf = PdfTestFixtures.GetMultiPagePdf(3);
+
+        using var doc = PdfParsedDocument.Open(pdf);
+
+        Assert.Equal(await extractor.ExtractTextAsync(pdf), doc.ExtractText());
+        Assert.Equal(await extractor.ExtractTextFromPageAsync(pdf, 2), doc.ExtractTextFromPage(2));
+    }
+
+    [Fact]
+    public async Task Pipeline_MatchesExtractor()
+    {
+        var extractor = new PdfExtractor();
+        var pdf = PdfTestFixtures.GetSamplePdf();
+
+        using var doc = PdfParsedDocument.Open(pdf);
+
+        var expectedElements = await extractor.PartitionAsync(pdf);
+        var elements = doc.Partition();
+        Assert.Equal(expectedElements.Select(e => e.Text), elements.Select(e => e.Text));
+
+        var expectedChunks = await extractor.RagChunksAsync(pdf, ExtractionProfile.Rag);
+        var chunks = doc.RagChunks(ExtractionProfile.Rag);
+        Assert.Equal(expectedChunks.Select(c => c.Text), chunks.Select(c => c.Text));
+
+        Assert.Equal(await extractor.ToMarkdownAsync(pdf), doc.ToMarkdown());
+    }
+
+    [Fact]
+    public void RepeatedReads_ReturnTheSameResult()
+    {
+        using var doc = PdfParsedDocument.Open(PdfTestFixtures.GetSamplePdf());
+
+        var first = doc.ExtractText();
+        var second = doc.ExtractText();
+
+        Assert.Equal(first, second);
+    }
+
+    [Fact]
+    public void PageNumberZero_ThrowsArgumentOutOfRange()
+    {
+        using var doc = PdfParsedDocument.Open(PdfTestFixtures.GetValidSinglePagePdf());
+
+        Assert.Throws<ArgumentOutOfRangeException>(() => doc.ExtractTextFromPage(0));
+        Assert.Throws<ArgumentOutOfRangeException>(() => doc.GetPageDimensions(0));
+    }
+
+    [Fact]
+    public void PagePastTheEnd_ThrowsPdfExtractionException()
+    {
+        using var doc = PdfParsedDocument.Open(PdfTestFixtures.GetValidSinglePagePdf());
+
+        Assert.Throws<PdfExtractionException>(() => doc.ExtractTextFromPage(2));
+    }
+
+    [Fact]
+    public void Dispose_IsIdempotent_AndBlocksFurtherCalls()
+    {
+        var doc = PdfParsedDocument.Open(PdfTestFixtures.GetValidSinglePagePdf());
+
+        doc.Dispose();
+        doc.Dispose();
+
+        Assert.Throws<ObjectDisposedException>(() => doc.ExtractText());
+        Assert.Throws<ObjectDisposedException>(() => doc.PageCount);
+    }
+}
@@ -0,0 +1,65 @@
+using OxidizePdf.NET.Tests.TestHelpers;
+
+namespace OxidizePdf.NET.Tests;
+
+/// <summary>
+/// Tests for the parse-recovery report and process-wide strict parsing.
+/// Strict parsing is process-wide, so these tests run outside the parallel
+/// collections and reset it when they finish.
+/// </summary>
+[Collection(ProcessWideSettingsCollection.Name)]
+public sealed class PdfRecoveryReportTests : IDisposable
+{
+    private readonly PdfExtractor _extractor = new();
+
+    public void Dispose() => PdfExtractor.StrictParsing = false;
+
+    [Fact]
+    public async Task CleanFile_ReportsNoRepairs()
+    {
+        var report = await _extractor.GetRecoveryReportAsync(PdfTestFixtures.GetWrittenPdf(2), strict: true);
+
+        Assert.False(report.Repaired);
+        Assert.Equal(2, report.PageCount);
+        Assert.Empty(report.Issues);
+    }
+
+    [Fact]
+    public async Task BrokenStartXref_IsReportedAsRebuiltXref()
+    {
+        var report = await _extractor.GetRecoveryReportAsync(PdfTestFixtures.BreakStartXref(PdfTestFixtures.GetWrittenPdf(2)));
+
+        Assert.True(report.Repaired);
+        Assert.Equal(2, report.PageCount);
+        Assert.Equal("xref_rebuilt", report.Issues[0].Kind);
+        Assert.All(report.Issues, i => Assert.Null(i.PageIndex));
+    }
+
+    [Fact]
+    public async Task StrictReport_FailsOnFirstRepair()
+    {
+        var ex = await Assert.ThrowsAsync<PdfExtractionException>(
+            () => _extractor.GetRecoveryReportAsync(PdfTestFixtures.BreakStartXref(PdfTestFixtures.GetWrittenPdf(2)), strict: true));
+
+        Assert.Equal("corrupt_xref", ex.Details?.Category);
+    }
+
+    [Fact]
+    public async Task StrictParsing_AppliesToEveryEntryPoint()
+    {
+        var damaged = PdfTestFixtures.BreakStartXref(PdfTestFixtures.GetWrittenPdf(1));
+        Assert.NotEmpty(await _extractor.ExtractTextAsync(damaged));
+
+        PdfExtractor.StrictParsing = true;
+
+        Assert.True(PdfExtractor.StrictParsing);
+        await Assert.ThrowsAsync<PdfExtractionException>(() => _extractor.ExtractTextAsync(damaged));
+        Assert.Throws<PdfExtractionException>(() => PdfParsedDocument.Open(damaged));
+    }
+
+    [Fact]
+    public void StrictParsing_DefaultsToOff()
+    {
+        Assert.False(PdfExtractor.StrictParsing);
+    }
+}
@@ -0,0 +1,73 @@
+using OxidizePdf.NET.Tests.TestHelpers;
+
+namespace OxidizePdf.NET.Tests;
+
+/// <summary>
+/// Tests for the process-wide resource limits. Every test resets the limits
+/// when it finishes.
+/// </summary>
+[Collection(ProcessWideSettingsCollection.Name)]
+public sealed class PdfResourceLimitsTests : IDisposable
+{
+    private readonly PdfExtractor _extractor = new();
+
+    public void Dispose() => PdfExtractor.SetResourceLimits(null);
+
+    [Fact]
+    public void GetResourceLimits_RoundTripsSetValues()
+    {
+        PdfExtractor.SetResourceLimits(new PdfResourceLimits { MaxPages = 10, MaxStreamBytes = 1 << 20 });
+
+        var limits = PdfExtractor.GetResourceLimits();
+
+        Assert.Equal(10, limits.MaxPages);
+        Assert.Equal(1 << 20, limits.MaxStreamBytes);
+        Assert.Null(limits.MaxObjects);
+        Assert.Null(limits.MaxPageTreeDepth);
+        Assert.Null(limits.MaxDurationMs);
+    }
+
+    [Fact]
+    public void SetResourceLimits_Null_ResetsEveryLimit()
+    {
+        PdfExtractor.SetResourceLimits(new PdfResourceLimits { MaxPages = 1 });
+
+        PdfExtractor.SetResourceLimits(null);
+
+        Assert.Null(PdfExtractor.GetResourceLimits().MaxPages);
+    }
+
+    [Fact]
+    public async Task MaxPages_RejectsLargerDocuments()
+    {
+        PdfExtractor.SetResourceLimits(new PdfResourceLimits { MaxPages = 2 });
+
+        Assert.NotEmpty(await _extractor.ExtractTextAsync(PdfTestFixtures.GetMultiPagePdf(2)));
+        var ex = await Assert.ThrowsAsync<PdfExtractionException>(
+            () => _extractor.ExtractTextAsync(PdfTestFixtures.GetMultiPagePdf(3)));
+
+        Assert.Equal(15, ex.Details?.Code);
+        Assert.Equal("limit_exceeded", ex.Details?.Category);
+    }
+
+    [Fact]
+    public void MaxStreamBytes_AppliesToParsedDocuments()
+    {
+        PdfExtractor.SetResourceLimits(new PdfResourceLimits { MaxStreamBytes = 8 });
+
+        var ex = Assert.ThrowsAny<PdfExtractionException>(() =>
+        {
+            using var doc = PdfParsedDocument.Open(PdfTestFixtures.GetMultiPagePdf(1));
+            doc.ExtractText();
+        });
+
+        Assert.Equal("limit_exceeded", ex.Details?.Category);
+    }
+
+    [Fact]
+    public void NegativeLimit_ThrowsArgumentOutOfRange()
+    {
+        Assert.Throws<ArgumentOutOfRangeException>(
+            () => PdfExtractor.SetResourceLimits(new PdfResourceLimits { MaxPages = -1 }));
+    }
+}
@@ -0,0 +1,129 @@
+using OxidizePdf.NET.Tests.TestHelpers;
+
+namespace OxidizePdf.NET.Tests;
+
+/// <summary>
+/// Tests for the thread-safe shared document: reads match a
+/// <see cref="PdfParsedDocument"/> on the same bytes, from any number of
+/// threads at once.
+/// </summary>
+public class PdfSharedDocumentTests
+{
+    [Fact]
+    public void Reads_MatchParsedDocument()
+    {
+        var pdf = PdfTestFixtures.GetMultiPagePdf(3);
+
+        using var shared = PdfSharedDocument.Open(pdf);
+        using var parsed = PdfParsedDocument.Open(pdf);
+
+        Assert.Equal(parsed.PageCount, shared.PageCount);
+        Assert.Equal(parsed.GetMetadata().PageCount, shared.GetMetadata().PageCount);
+        Assert.Equal(parsed.GetPageDimensions(2), shared.GetPageDimensions(2));
+        Assert.Equal(parsed.ExtractText(), shared.ExtractText());
+        Assert.Equal(parsed.ExtractTextFromPage(3), shared.ExtractTextFromPage(3));
+        Assert.Equal(parsed.Partition().Select(e => e.Text), shared.Partition().Select(e => e.Text));
+        Assert.Equal(parsed.RagChunks().Select(c => c.Text), shared.RagChunks().Select(c => c.Text));
+        Assert.Equal(parsed.GetAnnotations().Count, shared.GetAnnotations().Count);
+    }
+
+    [Fact]
+    public async Task ConcurrentReads_AllMatch()
+    {
+        var pdf = PdfTestFixtures.GetMultiPagePdf(4);
+        using var doc = PdfSharedDocument.Open(pdf);
+        var expected = Enumerable.Range(1, 4).Select(doc.ExtractTextFromPage).ToList();
+
+        var results = await Task.WhenAll(Enumerable.Range(0, 64).Select(i =>
+            Task.Run(() => (Page: i % 4 + 1, Text: doc.ExtractTextFromPage(i % 4 + 1)))));
+
+        Assert.All(results, r => Assert.Equal(expected[r.Page - 1], r.Text));
+    }
+
+    [Fact]
+    public async Task Dispose_DuringConcurrentReads_IsSafe()
+    {
+        var doc = PdfSharedDocument.Open(PdfTestFixtures.GetMultiPagePdf(4));
+
+        var readers = Enumerable.Range(0, 16).Select(_ => Task.Run(() =>
+        {
+            try
+            {
+                for (var i = 0; i < 20; i++)
+                    _ = doc.ExtractText();
+            }
+            catch (ObjectDisposedException)
+            {
+            }
+        })).ToList();
+        doc.Dispose();
+
+        await Task.WhenAll(readers);
+        Assert.Throws<ObjectDisposedException>(() => doc.PageCount);
+    }
+
+    [Fact]
+    public void Open_WithPassword_UnlocksEncryptedPdf()
+    {
+        using var doc = PdfSharedDocument.Open(PdfTestFixtures.GetEncryptedPdf("user-secret"), "user-secret");
+
+        Assert.Contains("Encrypted content", doc.ExtractText());
+    }
+
+    [Fact]
+    public void OpenFile_MatchesOpenOnBytes()
+    {
+        var pdf = PdfTestFixtures.GetMultiPagePdf(2);
+        var path = Path.GetTempFileName();
+        try
+        {
+            File.WriteAllBytes(path, pdf);
+
+            using var fromFile = PdfSharedDocument.OpenFile(path);
+            using var fromBytes = PdfSharedDocument.Open(pdf);
+
+            Assert.Equal(fromBytes.ExtractText(), fromFile.ExtractText());
+        }
+        finally
+        {
+            File.Delete(path);
+        }
+    }
+
+    [Fact]
+    public void GetWritablePage_KeepsOriginalContent()
+    {
+        byte[] src;
+        using (var srcDoc = new PdfDocument())
+        using (var srcPage = new PdfPage(400, 500))
+        {
+            srcPage.DrawTextAt(StandardFont.Helvetica, 12, 72, 420, "ORIGINAL_MARKER");
+            srcDoc.AddPage(srcPage);
+            src = srcDoc.SaveToBytes();
+        }
+        using var source = PdfSharedDocument.Open(src);
+        using var output = new PdfDocument();
+
+        using (var page = source.GetWritablePage(0))
+        {
+            Assert.Equal(400.0, page.Width, 2);
+            output.AddPage(page);
+        }
+
+        using var reparsed = PdfParsedDocument.Open(output.SaveToBytes());
+        Assert.Contains("ORIGINAL_MARKER", reparsed.ExtractText());
+    }
+
+    [Fact]
+    public void InvalidArguments_Throw()
+    {
+        Assert.Throws<ArgumentNullException>(() => PdfSharedDocument.Open(null!));
+        Assert.Throws<ArgumentException>(() => PdfSharedDocument.Open(Array.Empty<byte>()));
+        Assert.Throws<PdfExtractionException>(() => PdfSharedDocument.Open(PdfTestFixtures.GetCorruptedPdf()));
+
+        using var doc = PdfSharedDocument.Open(PdfTestFixtures.GetValidSinglePagePdf());
+        Assert.Throws<ArgumentOutOfRangeException>(() => doc.ExtractTextFromPage(0));
+        Assert.Throws<ArgumentOutOfRangeException>(() => doc.GetWritablePage(-1));
+        Assert.Throws<PdfExtractionException>(() => doc.ExtractTextFromPage(2));
+    }
+}
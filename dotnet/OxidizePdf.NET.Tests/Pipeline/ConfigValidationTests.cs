@@ -0,0 +1,79 @@
+using System.Runtime.InteropServices;
+using OxidizePdf.NET.Pipeline;
+using OxidizePdf.NET.Tests.TestHelpers;
+
+namespace OxidizePdf.NET.Tests.Pipeline;
+
+/// <summary>
+/// Config validation reports every invalid field with its JSON path, on the
+/// managed preflight and on the native side alike.
+/// </summary>
+public class ConfigValidationTests
+{
+    [Fact]
+    public void PartitionConfig_Validate_lists_every_invalid_field()
+    {
+        var bad = new PartitionConfig { TitleMinFontRatio = 0, HeaderZone = 1.5, MinTableConfidence = -0.1 };
+
+        var ex = Assert.Throws<ArgumentException>(() => bad.Validate());
+
+        Assert.Equal(
+            "$.title_min_font_ratio must be a number > 0, got 0; "
+            + "$.header_zone must be a number in [0, 1], got 1.5; "
+            + "$.min_table_confidence must be a number in [0, 1], got -0.1",
+            ex.Message);
+    }
+
+    [Fact]
+    public void PartitionConfig_Validate_rejects_NaN()
+    {
+        var ex = Assert.Throws<ArgumentException>(
+            () => new PartitionConfig { FooterZone = double.NaN }.Validate());
+
+        Assert.StartsWith("$.footer_zone", ex.Message);
+    }
+
+    [Fact]
+    public void ChunkConfigs_Validate_check_the_token_budget()
+    {
+        var hybrid = Assert.Throws<ArgumentException>(
+            () => new HybridChunkConfig { MaxTokens = -1, MergePolicy = (MergePolicy)7 }.Validate());
+        Assert.Equal(
+            "$.max_tokens must be an integer >= 1, got -1; "
+            + "$.merge_policy must be one of \"SameTypeOnly\", \"AnyInlineContent\", got 7",
+            hybrid.Message);
+
+        var semantic = Assert.Throws<ArgumentException>(
+            () => new SemanticChunkConfig { MaxTokens = 10, OverlapTokens = 10 }.Validate());
+        Assert.Equal("$.overlap_tokens must be less than max_tokens (10), got 10", semantic.Message);
+    }
+
+    [Fact]
+    public void Native_rejects_invalid_config_json_with_field_paths()
+    {
+        var pdf = PdfTestFixtures.GetSamplePdf();
+        const string json = """
+            {"detect_tables":true,"detect_headers_footers":true,"title_min_font_ratio":1.3,
+             "header_zone":1.5,"footer_zone":0.05,"reading_order":"Bogus","min_table_confidence":0.5}
+            """;
+
+        var handle = GCHandle.Alloc(pdf, GCHandleType.Pinned);
+        int code;
+        try
+        {
+            code = NativeMethods.oxidize_partition_with_config(
+                handle.AddrOfPinnedObject(), (nuint)pdf.Length, json, out _);
+        }
+        finally
+        {
+            handle.Free();
+        }
+        var details = NativeMethods.GetLastErrorDetails();
+
+        Assert.Equal((int)NativeMethods.ErrorCode.InvalidArgument, code);
+        Assert.NotNull(details);
+        Assert.Equal("invalid_argument", details!.Category);
+        Assert.Contains("$.header_zone must be a number in [0, 1], got 1.5", details.Message);
+        Assert.Contains("$.reading_order must be", details.Message);
+    }
+}
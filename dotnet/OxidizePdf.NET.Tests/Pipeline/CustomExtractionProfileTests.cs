@@ -0,0 +1,116 @@
+using System.Text.Json;
+using OxidizePdf.NET.Models;
+using OxidizePdf.NET.Pipeline;
+using OxidizePdf.NET.Tests.TestHelpers;
+
+namespace OxidizePdf.NET.Tests.Pipeline;
+
+/// <summary>
+/// Tests for named custom profiles: a registered profile is selected by the
+/// same profile overloads as a built-in. Each test registers its own names,
+/// so the process-wide registry needs no reset.
+/// </summary>
+public class CustomExtractionProfileTests
+{
+    [Fact]
+    public void ToJson_omits_null_sections_and_uses_snake_case()
+    {
+        var profile = new CustomExtractionProfile
+        {
+            Extraction = new ExtractionOptions { SpaceThreshold = 0.25 },
+            Semantic = new SemanticChunkConfig(8).WithOverlap(0),
+        };
+
+        using var doc = JsonDocument.Parse(profile.ToJson());
+        var root = doc.RootElement;
+
+        Assert.Equal(0.25, root.GetProperty("extraction").GetProperty("space_threshold").GetDouble());
+        Assert.Equal(8, root.GetProperty("semantic").GetProperty("max_tokens").GetInt32());
+        Assert.False(root.TryGetProperty("partition", out _));
+        Assert.False(root.TryGetProperty("hybrid", out _));
+        Assert.Equal("{}", new CustomExtractionProfile().ToJson());
+    }
+
+    [Fact]
+    public void RegisterProfile_returns_a_custom_id_that_GetProfile_resolves()
+    {
+        var id = PdfExtractor.RegisterProfile("managed-lookup", new CustomExtractionProfile());
+
+        Assert.True((byte)id >= 128);
+        Assert.Equal(id, PdfExtractor.GetProfile("managed-lookup"));
+        Assert.Equal(id, PdfExtractor.RegisterProfile("managed-lookup", new CustomExtractionProfile()));
+        Assert.Equal(ExtractionProfile.Rag, PdfExtractor.GetProfile("Rag"));
+    }
+
+    [Fact]
+    public async Task EmptyProfile_matches_default_partition()
+    {
+        var extractor = new PdfExtractor();
+        var pdf = PdfTestFixtures.GetSamplePdf();
+        var id = PdfExtractor.RegisterProfile("managed-empty", new CustomExtractionProfile());
+
+        var expected = await extractor.PartitionAsync(pdf);
+        var elements = await extractor.PartitionAsync(pdf, id);
+
+        Assert.Equal(expected.Select(e => e.Text), elements.Select(e => e.Text));
+    }
+
+    [Fact]
+    public async Task HybridSection_configures_RagChunks()
+    {
+        var extractor = new PdfExtractor();
+        var pdf = PdfTestFixtures.GetMultiPagePdf(1);
+        var whole = PdfExtractor.RegisterProfile("managed-whole", new CustomExtractionProfile());
+        var small = PdfExtractor.RegisterProfile("managed-small", new CustomExtractionProfile
+        {
+            Hybrid = new HybridChunkConfig
+            {
+                MaxTokens = 4,
+                OverlapTokens = 0,
+                MergeAdjacent = false,
+                PropagateHeadings = false,
+                MergePolicy = MergePolicy.SameTypeOnly,
+            },
+        });
+
+        var wholeChunks = await extractor.RagChunksAsync(pdf, whole);
+        var smallChunks = await extractor.RagChunksAsync(pdf, small);
+
+        Assert.True(smallChunks.Count > wholeChunks.Count);
+    }
+
+    [Fact]
+    public async Task SemanticSection_configures_SemanticChunks()
+    {
+        var extractor = new PdfExtractor();
+        var pdf = PdfTestFixtures.GetMultiPagePdf(2);
+        var config = new SemanticChunkConfig(8).WithOverlap(0);
+        var id = PdfExtractor.RegisterProfile("managed-semantic", new CustomExtractionProfile { Semantic = config });
+
+        var expected = await extractor.SemanticChunksAsync(pdf, config);
+        var chunks = await extractor.SemanticChunksAsync(pdf, id);
+        using var doc = PdfParsedDocument.Open(pdf);
+
+        Assert.Equal(expected.Select(c => c.Text), chunks.Select(c => c.Text));
+        Assert.Equal(expected.Select(c => c.Text), doc.SemanticChunks(id).Select(c => c.Text));
+        Assert.NotEmpty(await extractor.SemanticChunksAsync(pdf, ExtractionProfile.Rag));
+    }
+
+    [Fact]
+    public async Task InvalidRegistrations_Throw()
+    {
+        var extractor = new PdfExtractor();
+
+        Assert.Throws<ArgumentException>(() => PdfExtractor.RegisterProfile("", new CustomExtractionProfile()));
+        Assert.Throws<ArgumentNullException>(() => PdfExtractor.RegisterProfile("managed-null", null!));
+        Assert.Throws<ArgumentException>(() => PdfExtractor.RegisterProfile(
+            "managed-invalid", new CustomExtractionProfile { Partition = new PartitionConfig { HeaderZone = 2 } }));
+
+        var builtin = Assert.Throws<PdfExtractionException>(
+            () => PdfExtractor.RegisterProfile("Rag", new CustomExtractionProfile()));
+        Assert.Equal("invalid_argument", builtin.Details?.Category);
+        Assert.Throws<PdfExtractionException>(() => PdfExtractor.GetProfile("managed-missing"));
+        await Assert.ThrowsAsync<PdfExtractionException>(
+            () => extractor.SemanticChunksAsync(PdfTestFixtures.GetSamplePdf(), (ExtractionProfile)255));
+    }
+}
@@ -0,0 +1,147 @@
+using OxidizePdf.NET.Ai;
+using OxidizePdf.NET.Models;
+using OxidizePdf.NET.Tests.TestHelpers;
+
+namespace OxidizePdf.NET.Tests;
+
+/// <summary>
+/// Tests for the progress overloads: one report per page, results identical
+/// to the plain calls, and cancellation honoured between pages.
+/// </summary>
+public class ProgressCancellationTests
+{
+    /// <summary>Collects reports synchronously, unlike <see cref="Progress{T}"/>.</summary>
+    private sealed class RecordingProgress : IProgress<PdfProgress>
+    {
+        private readonly Action<PdfProgress>? _onReport;
+
+        public RecordingProgress(Action<PdfProgress>? onReport = null) => _onReport = onReport;
+
+        public List<PdfProgress> Reports { get; } = new();
+
+        public void Report(PdfProgress value)
+        {
+            Reports.Add(value);
+            _onReport?.Invoke(value);
+        }
+    }
+
+    [Fact]
+    public async Task ExtractTextAsync_ReportsEachPage_AndMatchesPlainCall()
+    {
+        var extractor = new PdfExtractor();
+        var pdf = PdfTestFixtures.GetMultiPagePdf(4);
+        var progress = new RecordingProgress();
+
+        var text = await extractor.ExtractTextAsync(pdf, progress);
+
+        Assert.Equal(await extractor.ExtractTextAsync(pdf), text);
+        Assert.Equal(new[] { 1, 2, 3, 4 }, progress.Reports.Select(r => r.Completed));
+        Assert.All(progress.Reports, r => Assert.Equal(4, r.Total));
+    }
+
+    [Fact]
+    public async Task PipelineOverloads_MatchPlainCalls()
+    {
+        var extractor = new PdfExtractor();
+        var pdf = PdfTestFixtures.GetMultiPagePdf(3);
+
+        var elements = await extractor.PartitionAsync(pdf, null, new RecordingProgress());
+        var rag = await extractor.RagChunksAsync(pdf, null, null, new RecordingProgress());
+        var semantic = await extractor.SemanticChunksAsync(pdf, null, null, new RecordingProgress());
+
+        Assert.Equal((await extractor.PartitionAsync(pdf)).Select(e => e.Text), elements.Select(e => e.Text));
+        Assert.Equal((await extractor.RagChunksAsync(pdf)).Select(c => c.Text), rag.Select(c => c.Text));
+        Assert.Equal((await extractor.SemanticChunksAsync(pdf)).Select(c => c.Text), semantic.Select(c => c.Text));
+    }
+
+    [Fact]
+    public void ChunkPdf_WithProgress_MatchesPlainCall()
+    {
+        var chunker = new DocumentChunker(8, 2);
+        var pdf = PdfTestFixtures.GetMultiPagePdf(3);
+        var progress = new RecordingProgress();
+
+        var chunks = chunker.ChunkPdf(pdf, progress);
+
+        Assert.Equal(chunker.ChunkPdf(pdf).Select(c => c.Content), chunks.Select(c => c.Content));
+        Assert.Equal(3, progress.Reports.Last().Completed);
+    }
+
+    [Fact]
+    [Trait("Category", "Integration")]
+    public async Task MergeAsync_WithProgress_ReportsCopiedPages()
+    {
+        var extractor = new PdfExtractor();
+        var pdf = PdfTestFixtures.GetMultiPagePdf(2);
+        var progress = new RecordingProgress();
+
+        var merged = await PdfOperations.MergeAsync(
+            new[] { new PdfMergeInput(pdf), new PdfMergeInput(pdf) }, progress);
+
+        Assert.Equal(4, await extractor.GetPageCountAsync(merged));
+        Assert.Equal(4, progress.Reports.Last().Completed);
+        Assert.All(progress.Reports, r => Assert.Equal(4, r.Total));
+    }
+
+    [Fact]
+    public async Task CancelledFromProgressHandler_ThrowsOperationCanceled()
+    {
+        var extractor = new PdfExtractor();
+        using var cts = new CancellationTokenSource();
+        var progress = new RecordingProgress(r =>
+        {
+            if (r.Completed == 1)
+                cts.Cancel();
+        });
+
+        await Assert.ThrowsAnyAsync<OperationCanceledException>(
+            () => extractor.ExtractTextAsync(PdfTestFixtures.GetMultiPagePdf(5), progress, cts.Token));
+
+        Assert.Single(progress.Reports);
+    }
+
+    [Fact]
+    public void ChunkPdf_CancelledMidway_ThrowsOperationCanceled()
+    {
+        using var cts = new CancellationTokenSource();
+        var progress = new RecordingProgress(_ => cts.Cancel());
+
+        Assert.ThrowsAny<OperationCanceledException>(
+            () => new DocumentChunker().ChunkPdf(PdfTestFixtures.GetMultiPagePdf(3), progress, cts.Token));
+    }
+
+    [Fact]
+    public async Task AlreadyCancelledToken_Throws()
+    {
+        var extractor = new PdfExtractor();
+        using var cts = new CancellationTokenSource();
+        cts.Cancel();
+
+        await Assert.ThrowsAnyAsync<OperationCanceledException>(
+            () => extractor.PartitionAsync(PdfTestFixtures.GetMultiPagePdf(2), config: null, progress: null, cts.Token));
+    }
+
+    [Fact]
+    public async Task ProgressHandlerException_IsRethrown()
+    {
+        var extractor = new PdfExtractor();
+        var progress = new RecordingProgress(_ => throw new InvalidOperationException("boom"));
+
+        var ex = await Assert.ThrowsAsync<InvalidOperationException>(
+            () => extractor.ExtractTextAsync(PdfTestFixtures.GetMultiPagePdf(3), progress));
+
+        Assert.Equal("boom", ex.Message);
+    }
+
+    [Fact]
+    public async Task NullProgress_MatchesPlainCall()
+    {
+        var extractor = new PdfExtractor();
+        var pdf = PdfTestFixtures.GetMultiPagePdf(2);
+
+        var text = await extractor.ExtractTextAsync(pdf, progress: null);
+
+        Assert.Equal(await extractor.ExtractTextAsync(pdf), text);
+    }
+}
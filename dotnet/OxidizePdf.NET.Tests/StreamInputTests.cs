@@ -0,0 +1,140 @@
+using OxidizePdf.NET.Tests.TestHelpers;
+
+namespace OxidizePdf.NET.Tests;
+
+/// <summary>
+/// Tests for <see cref="PdfParsedDocument.OpenStream"/>: a document read on
+/// demand through the stream callbacks reads the same as one opened on bytes.
+/// </summary>
+public class StreamInputTests
+{
+    [Fact]
+    public void OpenStream_MatchesOpenOnBytes()
+    {
+        var pdf = PdfTestFixtures.GetMultiPagePdf(3);
+
+        using var stream = new MemoryStream(pdf);
+        using var fromStream = PdfParsedDocument.OpenStream(stream);
+        using var fromBytes = PdfParsedDocument.Open(pdf);
+
+        Assert.Equal(fromBytes.PageCount, fromStream.PageCount);
+        Assert.Equal(fromBytes.ExtractText(), fromStream.ExtractText());
+        Assert.Equal(fromBytes.ExtractTextFromPage(2), fromStream.ExtractTextFromPage(2));
+        Assert.Equal(fromBytes.RagChunks().Select(c => c.Text), fromStream.RagChunks().Select(c => c.Text));
+    }
+
+    [Fact]
+    public void OpenStream_ReadsThroughTheStream()
+    {
+        var pdf = PdfTestFixtures.GetSamplePdf();
+        using var stream = new ProbeStream(pdf);
+
+        using var fromStream = PdfParsedDocument.OpenStream(stream);
+        using var fromBytes = PdfParsedDocument.Open(pdf);
+
+        Assert.Equal(fromBytes.ExtractText(), fromStream.ExtractText());
+        Assert.True(stream.BytesRead > 0);
+    }
+
+    [Fact]
+    public void OpenStream_WithPassword_UnlocksEncryptedStream()
+    {
+        using var stream = new MemoryStream(PdfTestFixtures.GetEncryptedPdf("user-secret"));
+
+        using var doc = PdfParsedDocument.OpenStream(stream, "user-secret");
+
+        Assert.Contains("Encrypted content", doc.ExtractText());
+    }
+
+    [Fact]
+    public void Dispose_LeavesTheStreamOpen()
+    {
+        using var stream = new MemoryStream(PdfTestFixtures.GetValidSinglePagePdf());
+
+        PdfParsedDocument.OpenStream(stream).Dispose();
+
+        Assert.True(stream.CanRead);
+    }
+
+    [Fact]
+    public void OpenStream_StreamException_IsRethrown()
+    {
+        using var stream = new ProbeStream(PdfTestFixtures.GetSamplePdf()) { FailReads = true };
+
+        Assert.Throws<IOException>(() => PdfParsedDocument.OpenStream(stream));
+    }
+
+    [Fact]
+    public void OpenStream_CorruptedPdf_ThrowsPdfExtractionException()
+    {
+        using var stream = new MemoryStream(PdfTestFixtures.GetCorruptedPdf());
+
+        Assert.Throws<PdfExtractionException>(() => PdfParsedDocument.OpenStream(stream));
+    }
+
+    [Fact]
+    public void Signatures_AreUnsupportedOnAStream()
+    {
+        using var stream = new MemoryStream(PdfTestFixtures.GetSamplePdf());
+        using var doc = PdfParsedDocument.OpenStream(stream);
+
+        var ex = Assert.Throws<PdfExtractionException>(() => doc.HasDigitalSignatures);
+
+        Assert.Equal("unsupported", ex.Details?.Category);
+    }
+
+    [Fact]
+    public void OpenStream_InvalidStream_Throws()
+    {
+        Assert.Throws<ArgumentNullException>(() => PdfParsedDocument.OpenStream(null!));
+
+        using var stream = new ProbeStream(PdfTestFixtures.GetSamplePdf()) { Seekable = false };
+        Assert.Throws<ArgumentException>(() => PdfParsedDocument.OpenStream(stream));
+    }
+
+    /// <summary>
+    /// A non-<see cref="MemoryStream"/> wrapper that counts the bytes read and
+    /// can refuse seeking or fail every read.
+    /// </summary>
+    private sealed class ProbeStream(byte[] data) : Stream
+    {
+        private readonly MemoryStream _inner = new(data);
+
+        public bool Seekable { get; init; } = true;
+
+        public bool FailReads { get; init; }
+
+        public long BytesRead { get; private set; }
+
+        public override bool CanRead => true;
+
+        public override bool CanSeek => Seekable;
+
+        public override bool CanWrite => false;
+
+        public override long Length => _inner.Length;
+
+        public override long Position
+        {
+            get => _inner.Position;
+            set => _inner.Position = value;
+        }
+
+        public override int Read(byte[] buffer, int offset, int count)
+        {
+            if (FailReads)
+                throw new IOException("read failed");
+            var read = _inner.Read(buffer, offset, count);
+            BytesRead += read;
+            return read;
+        }
+
+        public override long Seek(long offset, SeekOrigin origin) => _inner.Seek(offset, origin);
+
+        public override void Flush() { }
+
+        public override void SetLength(long value) => throw new NotSupportedException();
+
+        public override void Write(byte[] buffer, int offset, int count) => throw new NotSupportedException();
+    }
+}
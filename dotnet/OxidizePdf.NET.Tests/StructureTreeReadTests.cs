@@ -0,0 +1,108 @@
+using OxidizePdf.NET.Models;
+using OxidizePdf.NET.Tests.TestHelpers;
+
+namespace OxidizePdf.NET.Tests;
+
+/// <summary>
+/// Tests for reading the structure tree of a tagged PDF: a tree written with
+/// <see cref="PdfStructureTree"/> reads back unchanged and can be copied onto
+/// a new document.
+/// </summary>
+public class StructureTreeReadTests
+{
+    private static PdfPage TaggedPage(string tag)
+    {
+        var page = PdfPage.A4();
+        page.BeginMarkedContent(tag);
+        page.DrawTextAt(StandardFont.Helvetica, 12, 72, 700, "TAGGED");
+        page.EndMarkedContent();
+        return page;
+    }
+
+    private static byte[] Save(PdfStructureTree tree)
+    {
+        using var doc = new PdfDocument();
+        using (var h1 = TaggedPage("H1"))
+            doc.AddPage(h1);
+        using (var p = TaggedPage("P"))
+            doc.AddPage(p);
+        doc.SetStructureTree(tree);
+        return doc.SaveToBytes();
+    }
+
+    private static byte[] TaggedPdf()
+    {
+        var tree = new PdfStructureTree();
+        var root = tree.AddRoot("Document", lang: "en-US");
+        tree.AddChild(root, "H1", title: "Intro", mcids: new[] { (0, 0) });
+        var sidebar = tree.AddChild(root, "Sidebar", id: "side-1", altText: "Side note");
+        tree.AddChild(sidebar, "P", lang: "fr", actualText: "TAGGED", mcids: new[] { (1, 0) });
+        tree.MapRole("Sidebar", "Note");
+        return Save(tree);
+    }
+
+    private static IEnumerable<string> Describe(PdfStructureTreeInfo tree) =>
+        tree.Elements.Select(e =>
+            $"{e.Type}|{e.Parent}|{e.Id}|{e.Lang}|{e.AltText}|{e.ActualText}|{e.Title}|" +
+            string.Join(",", e.Mcids.Select(m => $"{m.PageIndex}:{m.Mcid}")));
+
+    [Fact]
+    public async Task GetStructureTreeAsync_RoundTripsTheWrittenTree()
+    {
+        var tree = await new PdfExtractor().GetStructureTreeAsync(TaggedPdf());
+
+        Assert.Equal(
+            new[]
+            {
+                "Document|||en-US||||",
+                "H1|0|||||Intro|0:0",
+                "Sidebar|0|side-1||Side note|||",
+                "P|2||fr||TAGGED||1:0",
+            },
+            Describe(tree));
+        Assert.Equal(new Dictionary<string, string> { ["Sidebar"] = "Note" }, tree.RoleMap);
+    }
+
+    [Fact]
+    public async Task ToStructureTree_CopiesTheTreeOntoANewDocument()
+    {
+        var extractor = new PdfExtractor();
+        var original = await extractor.GetStructureTreeAsync(TaggedPdf());
+
+        var copy = await extractor.GetStructureTreeAsync(Save(original.ToStructureTree()));
+
+        Assert.Equal(Describe(original), Describe(copy));
+        Assert.Equal(original.RoleMap, copy.RoleMap);
+    }
+
+    [Fact]
+    public async Task UntaggedDocument_HasNoElements()
+    {
+        var tree = await new PdfExtractor().GetStructureTreeAsync(PdfTestFixtures.GetValidSinglePagePdf());
+
+        Assert.Empty(tree.Elements);
+        Assert.Empty(tree.RoleMap);
+        Assert.Throws<InvalidOperationException>(() => tree.ToStructureTree());
+    }
+
+    [Fact]
+    public async Task DocumentHandle_MatchesExtractor()
+    {
+        var pdf = TaggedPdf();
+        var expected = await new PdfExtractor().GetStructureTreeAsync(pdf);
+
+        using var parsed = PdfParsedDocument.Open(pdf);
+
+        Assert.Equal(Describe(expected), Describe(parsed.GetStructureTree()));
+    }
+
+    [Fact]
+    public async Task InvalidInput_Throws()
+    {
+        var extractor = new PdfExtractor();
+
+        await Assert.ThrowsAsync<ArgumentNullException>(() => extractor.GetStructureTreeAsync(null!));
+        await Assert.ThrowsAsync<PdfExtractionException>(
+            () => extractor.GetStructureTreeAsync(PdfTestFixtures.GetCorruptedPdf()));
+    }
+}
@@ -0,0 +1,134 @@
+using OxidizePdf.NET.Models;
+using OxidizePdf.NET.Pipeline;
+using OxidizePdf.NET.Tests.TestHelpers;
+
+namespace OxidizePdf.NET.Tests;
+
+/// <summary>
+/// Tests for table extraction: a ruled grid comes back with its spans and
+/// header row, and the CSV / Markdown / HTML renderers flatten it.
+/// </summary>
+public class TableExtractionTests
+{
+    /// <summary>
+    /// A 4x3 ruled table whose header row has no line between the first two
+    /// columns, so "Region" spans two columns.
+    /// </summary>
+    private static byte[] RuledPdf()
+    {
+        using var doc = new PdfDocument();
+        using var page = PdfPage.A4();
+        page.SetFont(StandardFont.Helvetica, 12).TextAt(50, 700, "Quarterly results by region.");
+        page.SetStrokeColor(0, 0, 0).SetLineWidth(1);
+        for (var row = 0; row < 5; row++)
+            page.MoveTo(50, 600 - row * 20).LineTo(350, 600 - row * 20);
+        foreach (var x in new[] { 50.0, 250.0, 350.0 })
+            page.MoveTo(x, 600).LineTo(x, 520);
+        page.MoveTo(150, 580).LineTo(150, 520).Stroke();
+
+        void Cell(StandardFont font, int col, int row, string text) =>
+            page.SetFont(font, 10).TextAt(55 + col * 100, 585 - row * 20, text);
+        Cell(StandardFont.HelveticaBold, 0, 0, "Region");
+        Cell(StandardFont.HelveticaBold, 2, 0, "Sales");
+        var rows = new[] { ("North", "A", "1,200"), ("South", "B", "950"), ("East", "C", "87.5") };
+        for (var i = 0; i < rows.Length; i++)
+        {
+            Cell(StandardFont.Helvetica, 0, i + 1, rows[i].Item1);
+            Cell(StandardFont.Helvetica, 1, i + 1, rows[i].Item2);
+            Cell(StandardFont.Helvetica, 2, i + 1, rows[i].Item3);
+        }
+        doc.AddPage(page);
+        return doc.SaveToBytes();
+    }
+
+    [Fact]
+    public async Task ExtractTablesAsync_ReturnsCellsSpansAndHeader()
+    {
+        var tables = await new PdfExtractor().ExtractTablesAsync(RuledPdf());
+
+        var table = Assert.Single(tables);
+        Assert.Equal("ruling", table.Source);
+        Assert.Equal(1, table.PageNumber);
+        Assert.Equal((4, 3), (table.RowCount, table.ColumnCount));
+        Assert.Equal(1, table.HeaderRows);
+        Assert.Equal(11, table.Cells.Count);
+
+        var region = table.Cells[0];
+        Assert.Equal("Region", region.Text);
+        Assert.Equal((1, 2), (region.RowSpan, region.ColSpan));
+        Assert.Equal(200, region.Width, 0);
+        Assert.Equal("Sales", table.Cells[1].Text);
+        Assert.Equal(2, table.Cells[1].Column);
+    }
+
+    [Fact]
+    public async Task Serializers_RenderTheGrid()
+    {
+        var table = (await new PdfExtractor().ExtractTablesAsync(RuledPdf()))[0];
+
+        Assert.Equal(
+            "Region,,Sales\r\nNorth,A,\"1,200\"\r\nSouth,B,950\r\nEast,C,87.5\r\n",
+            table.ToCsv());
+        Assert.StartsWith("| Region |  | Sales |\n| --- | --- | --- |\n| North | A | 1,200 |", table.ToMarkdown());
+        Assert.Contains("<th colspan=\"2\">Region</th>", table.ToHtml());
+    }
+
+    [Fact]
+    public void Serializers_AcceptBuiltGrids()
+    {
+        var table = new PdfExtractedTable
+        {
+            RowCount = 1,
+            ColumnCount = 2,
+            Cells = { new PdfExtractedTableCell { Text = "a|b" }, new PdfExtractedTableCell { Column = 1, Text = "c" } },
+        };
+
+        Assert.Equal("a|b,c\r\n", table.ToCsv());
+        Assert.Contains("| a\\|b | c |", table.ToMarkdown());
+    }
+
+    [Fact]
+    public void Serializers_RejectInvalidGrids()
+    {
+        var table = new PdfExtractedTable
+        {
+            RowCount = 1,
+            ColumnCount = 2,
+            Cells =
+            {
+                new PdfExtractedTableCell { ColSpan = 2, Text = "a" },
+                new PdfExtractedTableCell { Column = 1, Text = "b" },
+            },
+        };
+
+        var ex = Assert.Throws<PdfExtractionException>(() => table.ToCsv());
+        Assert.Equal("invalid_argument", ex.Details?.Category);
+        Assert.Contains("cells[1] overlaps", ex.Details?.Message);
+    }
+
+    [Fact]
+    public async Task DocumentHandle_MatchesExtractor()
+    {
+        var pdf = RuledPdf();
+        var expected = await new PdfExtractor().ExtractTablesAsync(pdf);
+
+        using var parsed = PdfParsedDocument.Open(pdf);
+
+        Assert.Equal(expected.Select(t => t.ToCsv()), parsed.ExtractTables().Select(t => t.ToCsv()));
+        Assert.Empty(parsed.ExtractTables(new PartitionConfig().WithoutTables()));
+    }
+
+    [Fact]
+    public async Task InvalidArguments_Throw()
+    {
+        var extractor = new PdfExtractor();
+        var pdf = PdfTestFixtures.GetValidSinglePagePdf();
+        using var parsed = PdfParsedDocument.Open(pdf);
+
+        await Assert.ThrowsAsync<ArgumentNullException>(() => extractor.ExtractTablesAsync(null!));
+        await Assert.ThrowsAsync<ArgumentException>(
+            () => extractor.ExtractTablesAsync(pdf, new PartitionConfig { HeaderZone = 2 }));
+        Assert.Throws<ArgumentException>(() => parsed.ExtractTables(new PartitionConfig { HeaderZone = 2 }));
+        Assert.Empty(await extractor.ExtractTablesAsync(pdf));
+    }
+}
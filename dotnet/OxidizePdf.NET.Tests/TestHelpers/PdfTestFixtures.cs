@@ -52,6 +52,54 @@ public static class PdfTestFixtures
         return Encoding.ASCII.GetBytes("%PDF-1.4\n%%EOF\nGarbage data here");
     }
 
+    /// <summary>
+    /// Gets a single-page PDF encrypted with the given passwords, built with
+    /// <see cref="PdfDocument.Encrypt(string, string)"/>.
+    /// </summary>
+    /// <param name="userPassword">Password required to open the document</param>
+    /// <param name="ownerPassword">Owner password</param>
+    public static byte[] GetEncryptedPdf(string userPassword, string ownerPassword = "owner-secret")
+    {
+        using var doc = new PdfDocument();
+        using var page = PdfPage.A4();
+        page.SetFont(StandardFont.Helvetica, 12)
+            .TextAt(50, 750, "Encrypted content");
+        doc.AddPage(page);
+        doc.Encrypt(userPassword, ownerPassword);
+        return doc.SaveToBytes();
+    }
+
+    /// <summary>
+    /// Gets a PDF produced by <see cref="PdfDocument"/>, with correct xref
+    /// offsets and the text "Page N" on each page.
+    /// </summary>
+    /// <param name="pages">Number of pages</param>
+    public static byte[] GetWrittenPdf(int pages)
+    {
+        using var doc = new PdfDocument();
+        for (var i = 1; i <= pages; i++)
+        {
+            using var page = PdfPage.A4();
+            page.SetFont(StandardFont.Helvetica, 12)
+                .TextAt(50, 750, $"Page {i}");
+            doc.AddPage(page);
+        }
+        return doc.SaveToBytes();
+    }
+
+    /// <summary>
+    /// Points <c>startxref</c> past the end of the file, so lenient parsing
+    /// has to rebuild the cross-reference table.
+    /// </summary>
+    /// <param name="pdf">A well-formed PDF</param>
+    public static byte[] BreakStartXref(byte[] pdf)
+    {
+        var text = Encoding.Latin1.GetString(pdf);
+        var pos = text.LastIndexOf("startxref", StringComparison.Ordinal);
+        Assert.True(pos >= 0, "startxref present");
+        return Encoding.Latin1.GetBytes(text[..pos] + "startxref\n999999999\n%%EOF\n");
+    }
+
     /// <summary>
     /// Gets a PDF of approximately the specified size in MB.
     /// </summary>
@@ -0,0 +1,13 @@
+namespace OxidizePdf.NET.Tests.TestHelpers;
+
+/// <summary>
+/// Tests that change process-wide native settings (resource limits, strict
+/// parsing, logging, metrics) join this collection so they never run while
+/// other tests are parsing PDFs.
+/// </summary>
+[CollectionDefinition(Name, DisableParallelization = true)]
+public sealed class ProcessWideSettingsCollection
+{
+    /// <summary>Collection name for <see cref="CollectionAttribute"/>.</summary>
+    public const string Name = "Process-wide native settings";
+}
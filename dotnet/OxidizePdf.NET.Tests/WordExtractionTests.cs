@@ -0,0 +1,87 @@
+using OxidizePdf.NET.Tests.TestHelpers;
+
+namespace OxidizePdf.NET.Tests;
+
+/// <summary>
+/// Tests for word- and glyph-level extraction: geometry and style come from
+/// the content stream, and the document handles match the stateless calls.
+/// </summary>
+public class WordExtractionTests
+{
+    private static byte[] StyledPdf()
+    {
+        using var doc = new PdfDocument();
+        using var page = PdfPage.A4();
+        page.SetFont(StandardFont.Helvetica, 12)
+            .TextAt(50, 750, "Hello brave world")
+            .SetFont(StandardFont.TimesBold, 20)
+            .SetTextColor(1, 0, 0)
+            .SetTextRenderingMode(TextRenderingMode.FillStroke)
+            .TextAt(50, 700, "Red");
+        doc.AddPage(page);
+        return doc.SaveToBytes();
+    }
+
+    [Fact]
+    public async Task ExtractWordsAsync_ReportsGeometryAndStyle()
+    {
+        var words = await new PdfExtractor().ExtractWordsAsync(StyledPdf(), 1);
+
+        Assert.Equal(new[] { "Hello", "brave", "world", "Red" }, words.Select(w => w.Text));
+
+        var hello = words[0];
+        Assert.Equal(50, hello.X, 2);
+        Assert.True(hello.Y < 750);
+        Assert.True(hello.Width > 0 && hello.Height > 0);
+        Assert.Equal("Helvetica", hello.FontName);
+        Assert.Equal(12, hello.FontSize, 2);
+        Assert.Equal(new[] { 0.0, 0.0, 0.0 }, hello.FillColor);
+        Assert.Equal(0, hello.RenderMode);
+        Assert.Equal(0, hello.Rotation, 2);
+        Assert.True(words[1].X > hello.X + hello.Width);
+
+        var red = words[3];
+        Assert.Equal("Times-Bold", red.FontName);
+        Assert.Equal(new[] { 1.0, 0.0, 0.0 }, red.FillColor);
+        Assert.Equal((int)TextRenderingMode.FillStroke, red.RenderMode);
+    }
+
+    [Fact]
+    public async Task ExtractGlyphsAsync_KeepsEveryGlyph()
+    {
+        var glyphs = await new PdfExtractor().ExtractGlyphsAsync(StyledPdf(), 1);
+
+        Assert.Equal("Hello brave worldRed", string.Concat(glyphs.Select(g => g.Text)));
+    }
+
+    [Fact]
+    public async Task DocumentHandles_MatchExtractor()
+    {
+        var pdf = StyledPdf();
+        var expected = await new PdfExtractor().ExtractWordsAsync(pdf, 1);
+
+        using var parsed = PdfParsedDocument.Open(pdf);
+        using var shared = PdfSharedDocument.Open(pdf);
+
+        Assert.Equal(expected.Select(w => (w.Text, w.X, w.Y)), parsed.ExtractWords(1).Select(w => (w.Text, w.X, w.Y)));
+        Assert.Equal(expected.Select(w => (w.Text, w.X, w.Y)), shared.ExtractWords(1).Select(w => (w.Text, w.X, w.Y)));
+        Assert.Equal(
+            (await new PdfExtractor().ExtractGlyphsAsync(pdf, 1)).Count,
+            parsed.ExtractGlyphs(1).Count);
+    }
+
+    [Fact]
+    public async Task InvalidPage_Throws()
+    {
+        var extractor = new PdfExtractor();
+        var pdf = PdfTestFixtures.GetValidSinglePagePdf();
+        using var parsed = PdfParsedDocument.Open(pdf);
+
+        await Assert.ThrowsAsync<ArgumentOutOfRangeException>(() => extractor.ExtractWordsAsync(pdf, 0));
+        await Assert.ThrowsAsync<ArgumentNullException>(() => extractor.ExtractGlyphsAsync(null!, 1));
+        Assert.Throws<ArgumentOutOfRangeException>(() => parsed.ExtractWords(0));
+
+        var ex = await Assert.ThrowsAsync<PdfExtractionException>(() => extractor.ExtractWordsAsync(pdf, 2));
+        Assert.Equal("page_out_of_range", ex.Details?.Category);
+    }
+}
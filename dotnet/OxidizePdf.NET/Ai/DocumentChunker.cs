@@ -152,6 +152,182 @@ public class DocumentChunker
         }
     }
 
+    /// <summary>
+    /// <see cref="ChunkPdf(byte[])"/> that also reports how long each native
+    /// phase took.
+    /// </summary>
+    /// <param name="pdfBytes">The PDF document bytes. Must not be null.</param>
+    /// <returns>The chunks, identical to <see cref="ChunkPdf(byte[])"/>, and the call metrics.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="pdfBytes"/> is null.</exception>
+    /// <exception cref="PdfExtractionException">If parsing or chunking fails.</exception>
+    public (List<DocumentChunk> Chunks, PdfCallMetrics Metrics) ChunkPdfWithMetrics(byte[] pdfBytes)
+    {
+        ArgumentNullException.ThrowIfNull(pdfBytes);
+
+        var (json, metrics) = PdfExtractor.CallNativeWithMetrics(
+            pdfBytes,
+            (IntPtr ptr, nuint len, out IntPtr outJson, out IntPtr outMetrics) =>
+                NativeMethods.oxidize_chunk_pdf_with_metrics(
+                    ptr,
+                    len,
+                    (nuint)ChunkSize,
+                    (nuint)Overlap,
+                    (byte)(LanguageDetectionEnabled ? 1 : 0),
+                    out outJson,
+                    out outMetrics),
+            "oxidize_chunk_pdf_with_metrics failed");
+        return (JsonSerializer.Deserialize<List<DocumentChunk>>(json) ?? new List<DocumentChunk>(), metrics);
+    }
+
+    /// <summary>
+    /// <see cref="ChunkPdf(byte[])"/> for an encrypted PDF, unlocked with
+    /// <paramref name="password"/>. Unencrypted input ignores the password.
+    /// </summary>
+    /// <param name="pdfBytes">The PDF document bytes. Must not be null.</param>
+    /// <param name="password">The user or owner password.</param>
+    /// <returns>The chunks in sequence.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="pdfBytes"/> or <paramref name="password"/> is null.</exception>
+    /// <exception cref="PdfExtractionException">If parsing or chunking fails, or the password is wrong.</exception>
+    public List<DocumentChunk> ChunkPdf(byte[] pdfBytes, string password)
+    {
+        ArgumentNullException.ThrowIfNull(pdfBytes);
+        ArgumentNullException.ThrowIfNull(password);
+
+        IntPtr pdfPtr = IntPtr.Zero;
+        IntPtr outJson = IntPtr.Zero;
+        try
+        {
+            pdfPtr = Marshal.AllocHGlobal(pdfBytes.Length);
+            Marshal.Copy(pdfBytes, 0, pdfPtr, pdfBytes.Length);
+
+            var rc = NativeMethods.oxidize_chunk_pdf_with_password(
+                pdfPtr,
+                (nuint)pdfBytes.Length,
+                password,
+                (nuint)ChunkSize,
+                (nuint)Overlap,
+                (byte)(LanguageDetectionEnabled ? 1 : 0),
+                out outJson);
+            PdfExtractor.ThrowIfError(rc, "oxidize_chunk_pdf_with_password failed");
+
+            var json = Marshal.PtrToStringUTF8(outJson) ?? "[]";
+            return JsonSerializer.Deserialize<List<DocumentChunk>>(json) ?? new List<DocumentChunk>();
+        }
+        finally
+        {
+            if (outJson != IntPtr.Zero)
+                NativeMethods.oxidize_free_string(outJson);
+            if (pdfPtr != IntPtr.Zero)
+                Marshal.FreeHGlobal(pdfPtr);
+        }
+    }
+
+    /// <summary>
+    /// <see cref="ChunkPdf(byte[])"/> reporting progress after each page.
+    /// <paramref name="cancellationToken"/> is polled between pages, so a
+    /// long document stops mid-way rather than after the last page.
+    /// </summary>
+    /// <param name="pdfBytes">The PDF document bytes. Must not be null.</param>
+    /// <param name="progress">Receives the completed and total page counts. May be null to only honour cancellation.</param>
+    /// <param name="cancellationToken">Cancellation token, checked between pages.</param>
+    /// <returns>The chunks in sequence, identical to <see cref="ChunkPdf(byte[])"/>.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="pdfBytes"/> is null.</exception>
+    /// <exception cref="OperationCanceledException">If the operation is cancelled.</exception>
+    /// <exception cref="PdfExtractionException">If parsing or chunking fails.</exception>
+    public List<DocumentChunk> ChunkPdf(
+        byte[] pdfBytes,
+        IProgress<PdfProgress>? progress,
+        CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(pdfBytes);
+        cancellationToken.ThrowIfCancellationRequested();
+
+        IntPtr pdfPtr = IntPtr.Zero;
+        IntPtr outJson = IntPtr.Zero;
+        try
+        {
+            pdfPtr = Marshal.AllocHGlobal(pdfBytes.Length);
+            Marshal.Copy(pdfBytes, 0, pdfPtr, pdfBytes.Length);
+
+            new NativeProgress(progress, cancellationToken).Call(
+                (ref NativeMethods.ProgressCallbacksNative callbacks) => NativeMethods.oxidize_chunk_pdf_with_progress(
+                    pdfPtr,
+                    (nuint)pdfBytes.Length,
+                    (nuint)ChunkSize,
+                    (nuint)Overlap,
+                    (byte)(LanguageDetectionEnabled ? 1 : 0),
+                    ref callbacks,
+                    out outJson),
+                "oxidize_chunk_pdf_with_progress failed");
+
+            var json = Marshal.PtrToStringUTF8(outJson) ?? "[]";
+            return JsonSerializer.Deserialize<List<DocumentChunk>>(json) ?? new List<DocumentChunk>();
+        }
+        finally
+        {
+            if (outJson != IntPtr.Zero)
+                NativeMethods.oxidize_free_string(outJson);
+            if (pdfPtr != IntPtr.Zero)
+                Marshal.FreeHGlobal(pdfPtr);
+        }
+    }
+
+    /// <summary>
+    /// Stream the chunks of <see cref="ChunkPdf(byte[])"/> one at a time.
+    /// Text is extracted a page at a time, so memory stays bounded by a page;
+    /// unlike <see cref="ChunkPdf(byte[])"/>, token windows never span a page
+    /// break. Ids, chunk indices and character positions count across the
+    /// whole stream.
+    /// </summary>
+    /// <param name="pdfBytes">The PDF document bytes. Must not be null.</param>
+    /// <returns>A sequence that can be enumerated once. The native stream is
+    /// opened by this call and freed when the enumeration ends or is disposed.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="pdfBytes"/> is null.</exception>
+    /// <exception cref="PdfExtractionException">If the PDF cannot be opened, or chunking fails while enumerating.</exception>
+    public IEnumerable<DocumentChunk> ChunkPdfStream(byte[] pdfBytes)
+    {
+        ArgumentNullException.ThrowIfNull(pdfBytes);
+
+        var stream = ChunkStreams.Open(
+            pdfBytes,
+            (IntPtr ptr, nuint len, out IntPtr outStream) => NativeMethods.oxidize_chunk_pdf_stream_open(
+                ptr,
+                len,
+                (nuint)ChunkSize,
+                (nuint)Overlap,
+                (byte)(LanguageDetectionEnabled ? 1 : 0),
+                out outStream),
+            "oxidize_chunk_pdf_stream_open failed");
+        return ChunkStreams.Enumerate<DocumentChunk>(stream);
+    }
+
+    /// <summary>
+    /// <see cref="ChunkPdfStream(byte[])"/> reading from a file. The file is
+    /// memory-mapped by the native library, so no managed copy of the PDF is
+    /// made.
+    /// </summary>
+    /// <param name="path">Path of the PDF file.</param>
+    /// <param name="password">Password (user or owner) for an encrypted file, or <c>null</c>.</param>
+    /// <returns>A sequence that can be enumerated once. The native stream is
+    /// opened by this call and freed when the enumeration ends or is disposed.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="path"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="path"/> is empty.</exception>
+    /// <exception cref="PdfExtractionException">If the file cannot be read or parsed, or chunking fails while enumerating.</exception>
+    public IEnumerable<DocumentChunk> ChunkPdfFileStream(string path, string? password = null)
+    {
+        ArgumentException.ThrowIfNullOrEmpty(path);
+
+        var detectLanguage = (byte)(LanguageDetectionEnabled ? 1 : 0);
+        var stream = ChunkStreams.OpenPath(
+            (out IntPtr outStream) => password is null
+                ? NativeMethods.oxidize_chunk_pdf_stream_open_path(
+                    path, (nuint)ChunkSize, (nuint)Overlap, detectLanguage, out outStream)
+                : NativeMethods.oxidize_chunk_pdf_stream_open_path_with_password(
+                    path, password, (nuint)ChunkSize, (nuint)Overlap, detectLanguage, out outStream),
+            "oxidize_chunk_pdf_stream_open_path failed");
+        return ChunkStreams.Enumerate<DocumentChunk>(stream);
+    }
+
     /// <summary>
     /// Compute the dominant language across the given chunks, weighted by chunk
     /// content length (mirrors <c>DocumentChunker::document_language</c>).
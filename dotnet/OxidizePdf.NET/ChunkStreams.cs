@@ -0,0 +1,81 @@
+using System.Runtime.InteropServices;
+using System.Text.Json;
+
+namespace OxidizePdf.NET;
+
+/// <summary>
+/// Shared plumbing for the <c>oxidize_*_chunk_stream_*</c> handles behind
+/// <c>PdfExtractor.StreamRagChunks</c>, <c>PdfExtractor.StreamSemanticChunks</c>
+/// and <c>DocumentChunker.ChunkPdfStream</c>.
+/// </summary>
+internal static class ChunkStreams
+{
+    internal delegate int NativeBytesOpen(IntPtr pdfBytes, nuint pdfLen, out IntPtr outStream);
+    internal delegate int NativePathOpen(out IntPtr outStream);
+
+    /// <summary>
+    /// Opens a stream over a copy of <paramref name="pdfBytes"/>; the native
+    /// stream keeps its own copy, so the unmanaged buffer is released on return.
+    /// </summary>
+    internal static ChunkStreamSafeHandle Open(byte[] pdfBytes, NativeBytesOpen open, string errorMsg)
+    {
+        IntPtr pdfPtr = IntPtr.Zero;
+        try
+        {
+            pdfPtr = Marshal.AllocHGlobal(pdfBytes.Length);
+            Marshal.Copy(pdfBytes, 0, pdfPtr, pdfBytes.Length);
+            var result = open(pdfPtr, (nuint)pdfBytes.Length, out var stream);
+            PdfExtractor.ThrowIfError(result, errorMsg);
+            return new ChunkStreamSafeHandle(stream);
+        }
+        finally
+        {
+            if (pdfPtr != IntPtr.Zero)
+                Marshal.FreeHGlobal(pdfPtr);
+        }
+    }
+
+    /// <summary>
+    /// Opens a stream over a file; the native side memory-maps it, so no
+    /// managed copy of the PDF is made.
+    /// </summary>
+    internal static ChunkStreamSafeHandle OpenPath(NativePathOpen open, string errorMsg)
+    {
+        var result = open(out var stream);
+        PdfExtractor.ThrowIfError(result, errorMsg);
+        return new ChunkStreamSafeHandle(stream);
+    }
+
+    /// <summary>
+    /// Yields the chunks of an open stream and frees it once the enumeration
+    /// ends or is abandoned. The sequence can be enumerated once.
+    /// </summary>
+    internal static IEnumerable<T> Enumerate<T>(ChunkStreamSafeHandle stream)
+    {
+        using (stream)
+        {
+            while (Next(stream) is { } json)
+                yield return JsonSerializer.Deserialize<T>(json)
+                    ?? throw new PdfExtractionException("Chunk stream yielded a null chunk");
+        }
+    }
+
+    private static string? Next(ChunkStreamSafeHandle stream)
+    {
+        if (stream.IsClosed)
+            throw new ObjectDisposedException(nameof(ChunkStreamSafeHandle), "A chunk stream can only be enumerated once");
+
+        IntPtr jsonPtr = IntPtr.Zero;
+        try
+        {
+            var result = NativeMethods.oxidize_chunk_stream_next(stream.DangerousGetHandle(), out jsonPtr);
+            PdfExtractor.ThrowIfError(result, "Failed to read the next chunk");
+            return jsonPtr == IntPtr.Zero ? null : Marshal.PtrToStringUTF8(jsonPtr);
+        }
+        finally
+        {
+            if (jsonPtr != IntPtr.Zero)
+                NativeMethods.oxidize_free_string(jsonPtr);
+        }
+    }
+}
@@ -334,6 +334,10 @@ public enum PdfDestinationFit
     FitR = 4,
     /// <summary>Fit bounding box of page contents.</summary>
     FitB = 5,
+    /// <summary>Fit width of the page contents' bounding box, optional top coordinate. Reported by readers only.</summary>
+    FitBH = 6,
+    /// <summary>Fit height of the page contents' bounding box, optional left coordinate. Reported by readers only.</summary>
+    FitBV = 7,
 }
 
 /// <summary>
@@ -355,3 +359,34 @@ public enum PdfPageLabelStyle
     /// <summary>No numeric portion (prefix only, or blank). Omits <c>/S</c>.</summary>
     None = 5,
 }
+
+/// <summary>
+/// Serialization of the records returned by the <c>*EncodedAsync</c> methods.
+/// Discriminants match the <c>encoding</c> argument of the native
+/// <c>oxidize_*_encoded</c> entry points.
+/// </summary>
+public enum PdfResultEncoding
+{
+    /// <summary>UTF-8 JSON, identical to the text returned by the JSON entry points.</summary>
+    Json = 0,
+    /// <summary>CBOR (RFC 8949) with the same field names; binary data is a raw byte string instead of base64.</summary>
+    Cbor = 1,
+}
+
+/// <summary>
+/// Severity of a native log record. Discriminants match the
+/// <c>OXIDIZE_LOG_*</c> levels of <c>oxidize_set_log_callback</c>.
+/// </summary>
+public enum PdfLogLevel
+{
+    /// <summary>Failures, including caught native panics.</summary>
+    Error = 1,
+    /// <summary>Repairs made by lenient parsing (xref recovery, substituted objects, malformed font data).</summary>
+    Warning = 2,
+    /// <summary>Informational records.</summary>
+    Info = 3,
+    /// <summary>Debugging detail.</summary>
+    Debug = 4,
+    /// <summary>Fine-grained tracing.</summary>
+    Trace = 5,
+}
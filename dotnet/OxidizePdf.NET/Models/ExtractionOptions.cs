@@ -1,3 +1,6 @@
+using System.Text.Json;
+using System.Text.Json.Nodes;
+
 namespace OxidizePdf.NET.Models;
 
 /// <summary>
@@ -72,4 +75,26 @@ public class ExtractionOptions
         if (TjSpaceThreshold < 0)
             throw new ArgumentException("TjSpaceThreshold must be non-negative", nameof(TjSpaceThreshold));
     }
+
+    private static readonly JsonSerializerOptions JsonOptions = new()
+    {
+        PropertyNamingPolicy = JsonNamingPolicy.SnakeCaseLower,
+    };
+
+    /// <summary>Serialises the options with snake_case names, as the native JSON payloads expect.</summary>
+    internal JsonNode? ToJsonNode() => JsonSerializer.SerializeToNode(this, JsonOptions);
+
+    internal NativeMethods.ExtractionOptionsNative ToNative() => new()
+    {
+        PreserveLayout = PreserveLayout,
+        SpaceThreshold = SpaceThreshold,
+        NewlineThreshold = NewlineThreshold,
+        SortByPosition = SortByPosition,
+        DetectColumns = DetectColumns,
+        ColumnThreshold = ColumnThreshold,
+        MergeHyphenated = MergeHyphenated,
+        TjSpaceThreshold = TjSpaceThreshold,
+        ReconstructParagraphs = ReconstructParagraphs,
+        IncludeArtifacts = IncludeArtifacts
+    };
 }
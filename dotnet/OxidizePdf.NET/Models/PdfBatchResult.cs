@@ -0,0 +1,22 @@
+namespace OxidizePdf.NET.Models;
+
+/// <summary>
+/// The outcome of one input of a <see cref="PdfBatch"/> call: either the
+/// value the single-file call would have returned, or the error it would have
+/// thrown.
+/// </summary>
+/// <typeparam name="T">The result type of the batch operation.</typeparam>
+public sealed class PdfBatchResult<T>
+{
+    /// <summary>0-based position of the input in the batch.</summary>
+    public int Index { get; init; }
+
+    /// <summary>The result for this input; <c>null</c> when it failed.</summary>
+    public T? Value { get; init; }
+
+    /// <summary>The structured error for this input; <c>null</c> when it succeeded.</summary>
+    public PdfErrorDetails? Error { get; init; }
+
+    /// <summary>Whether this input was processed successfully.</summary>
+    public bool Succeeded => Error is null;
+}
@@ -0,0 +1,52 @@
+using System.Text.Json.Serialization;
+
+namespace OxidizePdf.NET.Models;
+
+/// <summary>
+/// Timings and counts measured by the native library for one
+/// <c>*WithMetrics</c> call.
+/// </summary>
+public sealed class PdfCallMetrics
+{
+    /// <summary>Wall-clock time of the whole native call, in milliseconds.</summary>
+    [JsonPropertyName("total_ms")]
+    public double TotalMs { get; set; }
+
+    /// <summary>
+    /// Time per phase, in execution order: <c>parse</c>, <c>text_extraction</c>,
+    /// <c>table_detection</c>, <c>layout</c>, <c>chunking</c>, <c>serialize</c>.
+    /// Phases a call does not run are absent.
+    /// </summary>
+    [JsonPropertyName("phases")]
+    public List<PdfPhaseTiming> Phases { get; set; } = new();
+
+    /// <summary>Number of objects in the cross-reference table, when known.</summary>
+    [JsonPropertyName("object_count")]
+    public int? ObjectCount { get; set; }
+
+    /// <summary>Number of pages processed.</summary>
+    [JsonPropertyName("page_count")]
+    public int PageCount { get; set; }
+
+    /// <summary>
+    /// Peak native allocation on the calling thread, in bytes. Only measured
+    /// when the native library is built with the <c>alloc-metrics</c> cargo
+    /// feature; always <c>null</c> in default builds.
+    /// </summary>
+    [JsonPropertyName("peak_alloc_bytes")]
+    public long? PeakAllocBytes { get; set; }
+}
+
+/// <summary>
+/// Time spent in one phase of a native call.
+/// </summary>
+public sealed class PdfPhaseTiming
+{
+    /// <summary>Phase name, e.g. <c>parse</c> or <c>chunking</c>.</summary>
+    [JsonPropertyName("name")]
+    public string Name { get; set; } = string.Empty;
+
+    /// <summary>Time spent in the phase, in milliseconds.</summary>
+    [JsonPropertyName("ms")]
+    public double Ms { get; set; }
+}
@@ -0,0 +1,51 @@
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace OxidizePdf.NET.Models;
+
+/// <summary>
+/// What the loaded native library supports, read from
+/// <c>oxidize_capabilities_json</c>. Compare <see cref="Version"/> with the
+/// version this package was built against to detect skew at load time.
+/// </summary>
+public sealed class PdfCapabilities
+{
+    /// <summary>Version of the native binding library.</summary>
+    [JsonPropertyName("version")]
+    public string Version { get; set; } = string.Empty;
+
+    /// <summary>Version of the linked oxidize-pdf core.</summary>
+    [JsonPropertyName("core_version")]
+    public string CoreVersion { get; set; } = string.Empty;
+
+    /// <summary>Core cargo features compiled in, e.g. <c>compression</c> or <c>signatures</c>.</summary>
+    [JsonPropertyName("core_features")]
+    public List<string> CoreFeatures { get; set; } = new();
+
+    /// <summary>Encodings accepted by the encoded-result calls (<c>json</c>, <c>cbor</c>).</summary>
+    [JsonPropertyName("result_encodings")]
+    public List<string> ResultEncodings { get; set; } = new();
+
+    /// <summary>Every JSON payload the library accepts, with its schema.</summary>
+    [JsonPropertyName("payloads")]
+    public List<PdfPayloadSchema> Payloads { get; set; } = new();
+}
+
+/// <summary>
+/// One JSON payload accepted by the native library (a config, options or
+/// input object) and the entry points taking it.
+/// </summary>
+public sealed class PdfPayloadSchema
+{
+    /// <summary>Payload name, e.g. <c>PartitionConfig</c>; also the schema title.</summary>
+    [JsonPropertyName("name")]
+    public string Name { get; set; } = string.Empty;
+
+    /// <summary>Native entry points that accept the payload.</summary>
+    [JsonPropertyName("entry_points")]
+    public List<string> EntryPoints { get; set; } = new();
+
+    /// <summary>JSON Schema (draft 2020-12) describing the payload.</summary>
+    [JsonPropertyName("schema")]
+    public JsonElement Schema { get; set; }
+}
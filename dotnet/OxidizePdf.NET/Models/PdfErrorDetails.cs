@@ -0,0 +1,51 @@
+using System.Text.Json.Serialization;
+
+namespace OxidizePdf.NET.Models;
+
+/// <summary>
+/// Structured details of a native failure, read from
+/// <c>oxidize_get_last_error_json</c> and attached to
+/// <see cref="PdfExtractionException.Details"/>.
+/// </summary>
+public sealed class PdfErrorDetails
+{
+    /// <summary>
+    /// Native <c>ErrorCode</c> the failing call returned (e.g. 1 for a parse
+    /// error, 12 for a missing password, 13 for a page out of range).
+    /// </summary>
+    [JsonPropertyName("code")]
+    public int Code { get; set; }
+
+    /// <summary>
+    /// Snake-case classification finer than <see cref="Code"/>:
+    /// <c>corrupt_xref</c>, <c>password_required</c>, <c>wrong_password</c>,
+    /// <c>invalid_reference</c>, <c>page_out_of_range</c>, <c>syntax</c>,
+    /// <c>limit_exceeded</c>, …
+    /// </summary>
+    [JsonPropertyName("category")]
+    public string Category { get; set; } = string.Empty;
+
+    /// <summary>The native error message.</summary>
+    [JsonPropertyName("message")]
+    public string Message { get; set; } = string.Empty;
+
+    /// <summary>0-based page index the failure relates to, if known.</summary>
+    [JsonPropertyName("page_index")]
+    public int? PageIndex { get; set; }
+
+    /// <summary>Object number of the indirect object involved, if known.</summary>
+    [JsonPropertyName("object_number")]
+    public int? ObjectNumber { get; set; }
+
+    /// <summary>Generation number of the indirect object involved, if known.</summary>
+    [JsonPropertyName("generation")]
+    public int? Generation { get; set; }
+
+    /// <summary>Byte offset in the file where the failure was detected, if known.</summary>
+    [JsonPropertyName("byte_offset")]
+    public long? ByteOffset { get; set; }
+
+    /// <summary>Underlying error chain, outermost first.</summary>
+    [JsonPropertyName("causes")]
+    public List<string> Causes { get; set; } = new();
+}
@@ -0,0 +1,142 @@
+using System.Runtime.InteropServices;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace OxidizePdf.NET.Models;
+
+/// <summary>
+/// A table detected on a page, as a grid of cells, as returned by
+/// <c>ExtractTablesAsync</c>. Render it with <see cref="ToCsv"/>,
+/// <see cref="ToMarkdown"/> or <see cref="ToHtml"/>.
+/// </summary>
+public class PdfExtractedTable
+{
+    /// <summary>Page number (1-based).</summary>
+    [JsonPropertyName("page_number")]
+    public int PageNumber { get; set; }
+
+    /// <summary>Bounding box X coordinate.</summary>
+    [JsonPropertyName("x")]
+    public double X { get; set; }
+
+    /// <summary>Bounding box Y coordinate.</summary>
+    [JsonPropertyName("y")]
+    public double Y { get; set; }
+
+    /// <summary>Bounding box width.</summary>
+    [JsonPropertyName("width")]
+    public double Width { get; set; }
+
+    /// <summary>Bounding box height.</summary>
+    [JsonPropertyName("height")]
+    public double Height { get; set; }
+
+    /// <summary>Detection confidence (0.0–1.0).</summary>
+    [JsonPropertyName("confidence")]
+    public double Confidence { get; set; }
+
+    /// <summary>Detector that found the table: "ruling" (drawn grid lines) or "spatial" (aligned text without borders).</summary>
+    [JsonPropertyName("source")]
+    public string Source { get; set; } = string.Empty;
+
+    /// <summary>Number of grid rows.</summary>
+    [JsonPropertyName("row_count")]
+    public int RowCount { get; set; }
+
+    /// <summary>Number of grid columns.</summary>
+    [JsonPropertyName("column_count")]
+    public int ColumnCount { get; set; }
+
+    /// <summary>Number of leading rows detected as headers.</summary>
+    [JsonPropertyName("header_rows")]
+    public int HeaderRows { get; set; }
+
+    /// <summary>
+    /// Cells in row-major order. A merged cell appears once, at its top-left
+    /// grid position; the positions its span covers have no entry of their own.
+    /// </summary>
+    [JsonPropertyName("cells")]
+    public List<PdfExtractedTableCell> Cells { get; set; } = new();
+
+    /// <summary>
+    /// Render the table as RFC 4180 CSV: one CRLF-terminated line per grid row,
+    /// with a merged cell's text at its top-left position.
+    /// </summary>
+    /// <returns>The CSV text.</returns>
+    /// <exception cref="PdfExtractionException">If the grid is invalid, e.g. a cell lies outside it or overlaps another.</exception>
+    public string ToCsv() => Render(NativeMethods.oxidize_table_to_csv, "Failed to render table as CSV");
+
+    /// <summary>
+    /// Render the table as a GitHub-flavoured Markdown table. The first header
+    /// row becomes the Markdown header; merged cells are flattened as in <see cref="ToCsv"/>.
+    /// </summary>
+    /// <returns>The Markdown text.</returns>
+    /// <exception cref="PdfExtractionException">If the grid is invalid, e.g. a cell lies outside it or overlaps another.</exception>
+    public string ToMarkdown() => Render(NativeMethods.oxidize_table_to_markdown, "Failed to render table as Markdown");
+
+    /// <summary>
+    /// Render the table as an HTML <c>&lt;table&gt;</c>, with header rows in
+    /// <c>&lt;thead&gt;</c> and spans as <c>rowspan</c> / <c>colspan</c>.
+    /// </summary>
+    /// <returns>The HTML text.</returns>
+    /// <exception cref="PdfExtractionException">If the grid is invalid, e.g. a cell lies outside it or overlaps another.</exception>
+    public string ToHtml() => Render(NativeMethods.oxidize_table_to_html, "Failed to render table as HTML");
+
+    private delegate int NativeRender(string tableJson, out IntPtr outText);
+
+    private string Render(NativeRender render, string errorMsg)
+    {
+        var json = JsonSerializer.Serialize(this);
+        IntPtr textPtr = IntPtr.Zero;
+        try
+        {
+            PdfExtractor.ThrowIfError(render(json, out textPtr), errorMsg);
+            return Marshal.PtrToStringUTF8(textPtr) ?? string.Empty;
+        }
+        finally
+        {
+            if (textPtr != IntPtr.Zero)
+                NativeMethods.oxidize_free_string(textPtr);
+        }
+    }
+}
+
+/// <summary>One cell of a <see cref="PdfExtractedTable"/>.</summary>
+public class PdfExtractedTableCell
+{
+    /// <summary>Grid row of the cell's top-left position (0-based).</summary>
+    [JsonPropertyName("row")]
+    public int Row { get; set; }
+
+    /// <summary>Grid column of the cell's top-left position (0-based).</summary>
+    [JsonPropertyName("column")]
+    public int Column { get; set; }
+
+    /// <summary>Number of grid rows the cell covers.</summary>
+    [JsonPropertyName("row_span")]
+    public int RowSpan { get; set; } = 1;
+
+    /// <summary>Number of grid columns the cell covers.</summary>
+    [JsonPropertyName("col_span")]
+    public int ColSpan { get; set; } = 1;
+
+    /// <summary>Text of the cell.</summary>
+    [JsonPropertyName("text")]
+    public string Text { get; set; } = string.Empty;
+
+    /// <summary>Bounding box X coordinate.</summary>
+    [JsonPropertyName("x")]
+    public double X { get; set; }
+
+    /// <summary>Bounding box Y coordinate.</summary>
+    [JsonPropertyName("y")]
+    public double Y { get; set; }
+
+    /// <summary>Bounding box width.</summary>
+    [JsonPropertyName("width")]
+    public double Width { get; set; }
+
+    /// <summary>Bounding box height.</summary>
+    [JsonPropertyName("height")]
+    public double Height { get; set; }
+}
@@ -0,0 +1,30 @@
+namespace OxidizePdf.NET.Models;
+
+/// <summary>
+/// One diagnostic record emitted by the native library, delivered to the
+/// handler registered with <see cref="PdfExtractor.SetLogHandler"/>.
+/// </summary>
+public readonly struct PdfLogRecord
+{
+    /// <summary>
+    /// Creates a log record.
+    /// </summary>
+    /// <param name="level">Severity of the record.</param>
+    /// <param name="target">Emitting native module.</param>
+    /// <param name="message">Event text followed by any structured fields as <c>key=value</c>.</param>
+    public PdfLogRecord(PdfLogLevel level, string target, string message)
+    {
+        Level = level;
+        Target = target;
+        Message = message;
+    }
+
+    /// <summary>Severity of the record.</summary>
+    public PdfLogLevel Level { get; }
+
+    /// <summary>Emitting native module, e.g. <c>oxidize_pdf::parser::reader</c>.</summary>
+    public string Target { get; }
+
+    /// <summary>Event text followed by any structured fields as <c>key=value</c>.</summary>
+    public string Message { get; }
+}
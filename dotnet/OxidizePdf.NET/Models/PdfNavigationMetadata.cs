@@ -0,0 +1,71 @@
+using System.Text.Json.Serialization;
+
+namespace OxidizePdf.NET.Models;
+
+/// <summary>
+/// The catalog's navigation entries read from an existing PDF, as returned by
+/// <c>GetNavigationMetadataAsync</c>. Viewer preferences and page-label ranges
+/// use the same types as the <see cref="PdfDocument"/> setters.
+/// </summary>
+public class PdfNavigationMetadata
+{
+    /// <summary>The action run when the document opens; <c>null</c> when there is none.</summary>
+    [JsonPropertyName("open_action")]
+    public PdfOpenActionInfo? OpenAction { get; set; }
+
+    /// <summary>Viewer preferences; unset entries are <c>null</c>.</summary>
+    [JsonPropertyName("viewer_preferences")]
+    public PdfViewerPreferences ViewerPreferences { get; set; } = new();
+
+    /// <summary>Named destinations from the catalog <c>/Dests</c> and the <c>/Names</c> tree.</summary>
+    [JsonPropertyName("named_destinations")]
+    public List<PdfNamedDestination> NamedDestinations { get; set; } = new();
+
+    /// <summary>Page labels; <c>null</c> when the document has none.</summary>
+    [JsonPropertyName("page_labels")]
+    public PdfPageLabelsInfo? PageLabels { get; set; }
+}
+
+/// <summary>The catalog's open action, as read from an existing PDF.</summary>
+public class PdfOpenActionInfo
+{
+    /// <summary>
+    /// <c>"goto"</c> (a <c>GoTo</c> action or a bare destination) or <c>"uri"</c>,
+    /// as in <see cref="PdfOpenAction"/>; otherwise the action subtype as written
+    /// (<c>"JavaScript"</c>, <c>"Named"</c>, …).
+    /// </summary>
+    [JsonPropertyName("kind")]
+    public string Kind { get; set; } = string.Empty;
+
+    /// <summary>Target of a <c>goto</c> action.</summary>
+    [JsonPropertyName("destination")]
+    public PdfResolvedDestination? Destination { get; set; }
+
+    /// <summary>Target of a <c>uri</c> action.</summary>
+    [JsonPropertyName("uri")]
+    public string? Uri { get; set; }
+}
+
+/// <summary>A named destination, as read from an existing PDF.</summary>
+public class PdfNamedDestination
+{
+    /// <summary>The destination's name.</summary>
+    [JsonPropertyName("name")]
+    public string Name { get; set; } = string.Empty;
+
+    /// <summary>The resolved target.</summary>
+    [JsonPropertyName("destination")]
+    public PdfResolvedDestination Destination { get; set; } = new();
+}
+
+/// <summary>A document's page labels, as read from an existing PDF.</summary>
+public class PdfPageLabelsInfo
+{
+    /// <summary>The numbering ranges, in document order.</summary>
+    [JsonPropertyName("ranges")]
+    public List<PdfPageLabelRange> Ranges { get; set; } = new();
+
+    /// <summary>The label of every page, in page order.</summary>
+    [JsonPropertyName("labels")]
+    public List<string> Labels { get; set; } = new();
+}
@@ -0,0 +1,58 @@
+using System.Text.Json.Serialization;
+
+namespace OxidizePdf.NET.Models;
+
+/// <summary>
+/// One bookmark read from an existing PDF's outline, as returned by
+/// <c>GetOutlineAsync</c>: the fields of a <see cref="PdfOutlineItem"/> plus
+/// the resolved target, action and colour.
+/// </summary>
+public class PdfOutlineEntry
+{
+    /// <summary>The visible bookmark title.</summary>
+    [JsonPropertyName("title")]
+    public string Title { get; set; } = string.Empty;
+
+    /// <summary>0-based target page; <c>null</c> when the item has no resolvable target.</summary>
+    [JsonPropertyName("page")]
+    public int? PageIndex { get; set; }
+
+    /// <summary>The resolved target, named destinations included; <c>null</c> when the item has none.</summary>
+    [JsonPropertyName("destination")]
+    public PdfResolvedDestination? Destination { get; set; }
+
+    /// <summary>Subtype of the item's action (<c>GoTo</c>, <c>URI</c>, …); <c>null</c> when it links through a destination or has no target.</summary>
+    [JsonPropertyName("action")]
+    public string? Action { get; set; }
+
+    /// <summary>Target of a <c>URI</c> action.</summary>
+    [JsonPropertyName("uri")]
+    public string? Uri { get; set; }
+
+    /// <summary>Whether the title is rendered in bold.</summary>
+    [JsonPropertyName("bold")]
+    public bool IsBold { get; set; }
+
+    /// <summary>Whether the title is rendered in italic.</summary>
+    [JsonPropertyName("italic")]
+    public bool IsItalic { get; set; }
+
+    /// <summary>Title colour as RGB components in [0, 1]; <c>null</c> when unset.</summary>
+    [JsonPropertyName("color")]
+    public double[]? Color { get; set; }
+
+    /// <summary>Whether the item's children are shown expanded.</summary>
+    [JsonPropertyName("open")]
+    public bool IsOpen { get; set; }
+
+    /// <summary>Child items nested under this bookmark.</summary>
+    [JsonPropertyName("children")]
+    public List<PdfOutlineEntry> Children { get; set; } = new();
+}
+
+/// <summary>The <c>{"items": [...]}</c> envelope of the native outline JSON.</summary>
+internal sealed class PdfOutlineResult
+{
+    [JsonPropertyName("items")]
+    public List<PdfOutlineEntry> Items { get; set; } = new();
+}
@@ -0,0 +1,25 @@
+namespace OxidizePdf.NET.Models;
+
+/// <summary>
+/// Progress of a long-running call, reported after each unit of work
+/// (a page, or an input document for merges).
+/// </summary>
+public readonly struct PdfProgress
+{
+    /// <summary>
+    /// Creates a progress report.
+    /// </summary>
+    /// <param name="completed">Units of work completed so far.</param>
+    /// <param name="total">Total units of work.</param>
+    public PdfProgress(int completed, int total)
+    {
+        Completed = completed;
+        Total = total;
+    }
+
+    /// <summary>Units of work completed so far.</summary>
+    public int Completed { get; }
+
+    /// <summary>Total units of work.</summary>
+    public int Total { get; }
+}
@@ -0,0 +1,52 @@
+using System.Text.Json.Serialization;
+
+namespace OxidizePdf.NET.Models;
+
+/// <summary>
+/// Repairs lenient parsing performed while loading a PDF, returned by
+/// <c>PdfExtractor.GetRecoveryReportAsync</c>.
+/// </summary>
+public class PdfRecoveryReport
+{
+    /// <summary><c>true</c> when at least one issue was recorded.</summary>
+    [JsonPropertyName("repaired")]
+    public bool Repaired { get; set; }
+
+    /// <summary>Number of pages in the document.</summary>
+    [JsonPropertyName("page_count")]
+    public int PageCount { get; set; }
+
+    /// <summary>Each repair, in the order the parser performed it.</summary>
+    [JsonPropertyName("issues")]
+    public List<PdfRecoveryIssue> Issues { get; set; } = new();
+}
+
+/// <summary>
+/// One repair performed by lenient parsing.
+/// </summary>
+public class PdfRecoveryIssue
+{
+    /// <summary>
+    /// Kind of repair: <c>xref_rebuilt</c>, <c>structure_repaired</c>,
+    /// <c>unreadable_page</c>, <c>skipped_object</c>, <c>repaired_stream</c>,
+    /// <c>broken_stream</c> or <c>bad_font</c>.
+    /// </summary>
+    [JsonPropertyName("kind")]
+    public string Kind { get; set; } = string.Empty;
+
+    /// <summary>Parser message describing the repair.</summary>
+    [JsonPropertyName("message")]
+    public string Message { get; set; } = string.Empty;
+
+    /// <summary>0-based page index the repair relates to, if known.</summary>
+    [JsonPropertyName("page_index")]
+    public int? PageIndex { get; set; }
+
+    /// <summary>Object number of the repaired object, if known.</summary>
+    [JsonPropertyName("object_number")]
+    public int? ObjectNumber { get; set; }
+
+    /// <summary>Generation number of the repaired object, if known.</summary>
+    [JsonPropertyName("generation")]
+    public int? Generation { get; set; }
+}
@@ -0,0 +1,39 @@
+using System.Text.Json.Serialization;
+
+namespace OxidizePdf.NET.Models;
+
+/// <summary>
+/// A destination read from an existing PDF, resolved to a page index. Unlike
+/// <see cref="PdfDestination"/>, the page may be unresolvable and every fit
+/// mode's parameters are reported.
+/// </summary>
+public class PdfResolvedDestination
+{
+    /// <summary>0-based index of the target page; <c>null</c> when the page reference does not resolve.</summary>
+    [JsonPropertyName("page")]
+    public int? PageIndex { get; set; }
+
+    /// <summary>How the viewport is positioned when opening the destination.</summary>
+    [JsonPropertyName("fit")]
+    public PdfDestinationFit FitMode { get; set; }
+
+    /// <summary>Left coordinate; <c>null</c> when the fit mode does not take it or it is unset.</summary>
+    [JsonPropertyName("left")]
+    public double? Left { get; set; }
+
+    /// <summary>Top coordinate; <c>null</c> when the fit mode does not take it or it is unset.</summary>
+    [JsonPropertyName("top")]
+    public double? Top { get; set; }
+
+    /// <summary>Zoom factor of an XYZ destination; <c>null</c> means inherit the current zoom.</summary>
+    [JsonPropertyName("zoom")]
+    public double? Zoom { get; set; }
+
+    /// <summary>Right coordinate of a FitR rectangle.</summary>
+    [JsonPropertyName("right")]
+    public double? Right { get; set; }
+
+    /// <summary>Bottom coordinate of a FitR rectangle.</summary>
+    [JsonPropertyName("bottom")]
+    public double? Bottom { get; set; }
+}
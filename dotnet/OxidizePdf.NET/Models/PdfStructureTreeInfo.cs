@@ -0,0 +1,100 @@
+using System.Text.Json.Serialization;
+
+namespace OxidizePdf.NET.Models;
+
+/// <summary>
+/// The logical structure tree read from a tagged PDF, as returned by
+/// <c>GetStructureTreeAsync</c>, in the shape <see cref="PdfStructureTree"/>
+/// builds. An untagged document has no elements.
+/// </summary>
+/// <remarks>
+/// Several top-level elements are wrapped in a synthetic <c>Document</c> root.
+/// Role-map chains are resolved to the standard type they end at; entries
+/// ending at an unknown type or looping are omitted.
+/// </remarks>
+public class PdfStructureTreeInfo
+{
+    /// <summary>
+    /// Elements in depth-first order: the root first, every parent before its
+    /// children.
+    /// </summary>
+    [JsonPropertyName("elements")]
+    public List<PdfStructureElementInfo> Elements { get; set; } = new();
+
+    /// <summary>Custom structure type names mapped to standard types.</summary>
+    [JsonPropertyName("role_map")]
+    public Dictionary<string, string> RoleMap { get; set; } = new();
+
+    /// <summary>
+    /// Rebuild the tree as a <see cref="PdfStructureTree"/>, e.g. to copy it
+    /// onto a new document with <see cref="PdfDocument.SetStructureTree"/>.
+    /// </summary>
+    /// <returns>A builder holding the same elements and role map.</returns>
+    /// <exception cref="InvalidOperationException">If the tree has no elements.</exception>
+    public PdfStructureTree ToStructureTree()
+    {
+        if (Elements.Count == 0)
+            throw new InvalidOperationException("The document has no structure tree to copy.");
+
+        var tree = new PdfStructureTree();
+        foreach (var e in Elements)
+        {
+            var mcids = e.Mcids.Select(m => (m.PageIndex, m.Mcid));
+            if (e.Parent is { } parent)
+                tree.AddChild(parent, e.Type, e.Id, e.Lang, e.AltText, e.ActualText, e.Title, mcids);
+            else
+                tree.AddRoot(e.Type, e.Id, e.Lang, e.AltText, e.ActualText, e.Title, mcids);
+        }
+        foreach (var (custom, standard) in RoleMap)
+            tree.MapRole(custom, standard);
+        return tree;
+    }
+}
+
+/// <summary>One element of a <see cref="PdfStructureTreeInfo"/>.</summary>
+public class PdfStructureElementInfo
+{
+    /// <summary>Structure type name, e.g. <c>Document</c>, <c>H1</c>, <c>P</c>, or a role-mapped custom type.</summary>
+    [JsonPropertyName("type")]
+    public string Type { get; set; } = string.Empty;
+
+    /// <summary>Index of the parent in <see cref="PdfStructureTreeInfo.Elements"/>; <c>null</c> for the root.</summary>
+    [JsonPropertyName("parent")]
+    public int? Parent { get; set; }
+
+    /// <summary>Element identifier (<c>/ID</c>).</summary>
+    [JsonPropertyName("id")]
+    public string? Id { get; set; }
+
+    /// <summary>Language (<c>/Lang</c>), e.g. <c>en-US</c>.</summary>
+    [JsonPropertyName("lang")]
+    public string? Lang { get; set; }
+
+    /// <summary>Alternate description (<c>/Alt</c>).</summary>
+    [JsonPropertyName("alt_text")]
+    public string? AltText { get; set; }
+
+    /// <summary>Replacement text (<c>/ActualText</c>).</summary>
+    [JsonPropertyName("actual_text")]
+    public string? ActualText { get; set; }
+
+    /// <summary>Title (<c>/T</c>).</summary>
+    [JsonPropertyName("title")]
+    public string? Title { get; set; }
+
+    /// <summary>Marked-content sequences of page content this element tags.</summary>
+    [JsonPropertyName("mcids")]
+    public List<PdfMarkedContentRef> Mcids { get; set; } = new();
+}
+
+/// <summary>A reference from a structure element to a marked-content sequence on a page.</summary>
+public class PdfMarkedContentRef
+{
+    /// <summary>0-based index of the page holding the content.</summary>
+    [JsonPropertyName("page")]
+    public int PageIndex { get; set; }
+
+    /// <summary>The marked-content identifier, as returned by <see cref="PdfPage.BeginMarkedContent"/>.</summary>
+    [JsonPropertyName("mcid")]
+    public int Mcid { get; set; }
+}
@@ -0,0 +1,53 @@
+using System.Text.Json.Serialization;
+
+namespace OxidizePdf.NET.Models;
+
+/// <summary>
+/// A word or glyph of a page with its geometry and style, as returned by
+/// <c>ExtractWordsAsync</c> and <c>ExtractGlyphsAsync</c>.
+/// </summary>
+public class PdfTextItem
+{
+    /// <summary>The word, or the single glyph's text.</summary>
+    [JsonPropertyName("text")]
+    public string Text { get; set; } = string.Empty;
+
+    /// <summary>Left edge of the axis-aligned bounding box, in user space (origin bottom-left).</summary>
+    [JsonPropertyName("x")]
+    public double X { get; set; }
+
+    /// <summary>Bottom edge of the bounding box, in user space.</summary>
+    [JsonPropertyName("y")]
+    public double Y { get; set; }
+
+    /// <summary>Width of the bounding box.</summary>
+    [JsonPropertyName("width")]
+    public double Width { get; set; }
+
+    /// <summary>Height of the bounding box.</summary>
+    [JsonPropertyName("height")]
+    public double Height { get; set; }
+
+    /// <summary>Font name, e.g. <c>Helvetica</c>.</summary>
+    [JsonPropertyName("font_name")]
+    public string FontName { get; set; } = string.Empty;
+
+    /// <summary>Effective font size: the <c>Tf</c> size scaled by the text and graphics matrices.</summary>
+    [JsonPropertyName("font_size")]
+    public double FontSize { get; set; }
+
+    /// <summary>
+    /// Fill colour as RGB components in [0, 1]; <c>null</c> for pattern,
+    /// separation, indexed and other colour spaces.
+    /// </summary>
+    [JsonPropertyName("fill_color")]
+    public double[]? FillColor { get; set; }
+
+    /// <summary>Text rendering mode (<c>Tr</c>), 0–7; 3 is invisible text such as an OCR layer.</summary>
+    [JsonPropertyName("render_mode")]
+    public int RenderMode { get; set; }
+
+    /// <summary>Baseline direction in degrees counter-clockwise from the x axis.</summary>
+    [JsonPropertyName("rotation")]
+    public double Rotation { get; set; }
+}
@@ -27,6 +27,21 @@ internal static class NativeMethods
 
         /// <summary>A Rust panic was caught at the FFI boundary (see last error message).</summary>
         Panic = 10,
+
+        /// <summary>The caller's cancellation callback aborted the operation.</summary>
+        Cancelled = 11,
+
+        /// <summary>The PDF is encrypted and needs (the correct) password.</summary>
+        PasswordRequired = 12,
+
+        /// <summary>A page number or index lies outside the document.</summary>
+        PageOutOfRange = 13,
+
+        /// <summary>The PDF uses an unsupported feature or version.</summary>
+        Unsupported = 14,
+
+        /// <summary>A configured resource limit was exceeded.</summary>
+        LimitExceeded = 15,
     }
 
     /// <summary>
@@ -289,6 +304,42 @@ internal static class NativeMethods
         out IntPtr outBytes,
         out nuint outLen);
 
+    /// <summary>Consume all <paramref name="len"/> bytes at <paramref name="buf"/>; return 0 on success</summary>
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    internal delegate int WriteCallback(IntPtr userData, IntPtr buf, nuint len);
+
+    /// <summary>Called once after the last write; return 0 on success</summary>
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    internal delegate int FlushCallback(IntPtr userData);
+
+    /// <summary>
+    /// Sink for the <c>oxidize_document_save_to_callbacks*</c> calls.
+    /// Field order MUST match <c>WriteCallbacks</c> in <c>native/src/callback_writer.rs</c>.
+    /// <c>Write</c> is required; <c>Flush</c> may be IntPtr.Zero.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    internal struct WriteCallbacksNative
+    {
+        public IntPtr UserData;
+        public IntPtr Write;
+        public IntPtr Flush;
+    }
+
+    /// <summary>Serialize the document through a caller-supplied write callback</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_document_save_to_callbacks(
+        IntPtr handle, ref WriteCallbacksNative callbacks);
+
+    /// <summary>Serialize the document with an explicit WriterConfig through a write callback</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_document_save_to_callbacks_with_config(
+        IntPtr handle,
+        int useXrefStreams,
+        int useObjectStreams,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string pdfVersion,
+        int compressStreams,
+        ref WriteCallbacksNative callbacks);
+
     /// <summary>Get the number of pages in the document</summary>
     [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
     internal static extern int oxidize_document_page_count(IntPtr handle, out nuint outCount);
@@ -1084,12 +1135,27 @@ internal static class NativeMethods
     [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
     internal static extern int oxidize_version(out IntPtr outVersion);
 
+    /// <summary>
+    /// Describe this build as JSON: library and core versions, compiled-in core
+    /// features, result encodings and the JSON Schema of every accepted payload.
+    /// </summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_capabilities_json(out IntPtr outJson);
+
     /// <summary>
     /// Get the last error message from native library
     /// </summary>
     [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
     internal static extern int oxidize_get_last_error(out IntPtr outError);
 
+    /// <summary>
+    /// Get the last error on this thread as a JSON object with the error code,
+    /// a finer-grained category, location fields and the cause chain.
+    /// <paramref name="outJson"/> is IntPtr.Zero when no error is recorded.
+    /// </summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_get_last_error_json(out IntPtr outJson);
+
     /// <summary>
     /// Get the number of pages in a PDF
     /// </summary>
@@ -1210,6 +1276,15 @@ internal static class NativeMethods
         [MarshalAs(UnmanagedType.LPUTF8Str)] string semanticConfigJson,
         out IntPtr outJson);
 
+    /// <summary>
+    /// Extract semantic chunks using a pre-configured or registered profile. A
+    /// custom profile's <c>semantic</c> section supplies the chunk config;
+    /// otherwise the default <c>SemanticChunkConfig</c> is used.
+    /// </summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_semantic_chunks_with_profile(
+        IntPtr pdfBytes, nuint pdfLen, byte profile, out IntPtr outJson);
+
     // ── Parser — structured export ─────────────────────────────────────────────
 
     /// <summary>Export PDF content as Markdown</summary>
@@ -1512,6 +1587,27 @@ internal static class NativeMethods
         nuint pdfLen,
         out IntPtr outVersion);
 
+    /// <summary>
+    /// Parse a PDF and report every repair lenient parsing performed (JSON object).
+    /// With <paramref name="strict"/> non-zero, or while strict parsing is on,
+    /// the first repair fails the call with <c>PdfParseError</c> instead.
+    /// </summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parse_recovery_report(
+        IntPtr pdfBytes, nuint pdfLen, byte strict, out IntPtr outJson);
+
+    /// <summary>
+    /// Turn process-wide strict parsing on (non-zero) or off. While on, every entry
+    /// point fails on damaged files instead of repairing them.
+    /// </summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_set_strict_parsing(byte strict);
+
+    /// <summary>Get whether process-wide strict parsing is on</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_get_strict_parsing(
+        [MarshalAs(UnmanagedType.I1)] out bool strict);
+
     /// <summary>Get the dimensions of a specific page from a parsed PDF (1-based)</summary>
     [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
     internal static extern int oxidize_get_page_dimensions(
@@ -1521,6 +1617,19 @@ internal static class NativeMethods
         out double outWidth,
         out double outHeight);
 
+    /// <summary>
+    /// Extract the words of a page (1-based) with geometry, font, colour and
+    /// render mode as a JSON array. With <paramref name="glyphs"/> non-zero every
+    /// glyph is returned instead, whitespace included.
+    /// </summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_extract_words(
+        IntPtr pdfBytes,
+        nuint pdfLen,
+        nuint pageNumber,
+        byte glyphs,
+        out IntPtr outJson);
+
     /// <summary>Measure the width and height of a string using an embedded TTF/OTF font.</summary>
     [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
     internal static extern int oxidize_measure_text(
@@ -1947,37 +2056,1183 @@ internal static class NativeMethods
         double rx,
         double ry);
 
+    // ── Parsed document handle ────────────────────────────────────────────────
+
     /// <summary>
-    /// Gets the last error message from the native library and clears it
+    /// Parse a PDF once and return a handle for the <c>oxidize_parsed_document_*</c>
+    /// calls. The bytes are copied; the handle must be freed with
+    /// <c>oxidize_parsed_document_free</c>.
     /// </summary>
-    /// <returns>The error message, or null if no error was set</returns>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_open(
+        IntPtr pdfBytes, nuint pdfLen, out IntPtr outHandle);
+
     /// <summary>
-    /// Reads the native library's last error message for the current thread.
+    /// <c>oxidize_parsed_document_open</c> for encrypted files: the document is
+    /// unlocked with <paramref name="password"/> (user or owner password) before
+    /// the handle is returned.
+    /// </summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_open_with_password(
+        IntPtr pdfBytes, nuint pdfLen,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        out IntPtr outHandle);
+
+    /// <summary>
+    /// Open a PDF file as a parsed document handle. The file is memory-mapped,
+    /// not copied. <paramref name="password"/> may be <c>null</c>.
+    /// </summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_open_path(
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string path,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? password,
+        out IntPtr outHandle);
+
+    /// <summary>Read up to <paramref name="len"/> bytes; return the count (0 at end) or a negative value on error</summary>
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    internal delegate nint ReadCallback(IntPtr userData, IntPtr buf, nuint len);
+
+    /// <summary>Move to an absolute byte offset; return 0 on success</summary>
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    internal delegate int SeekCallback(IntPtr userData, ulong offset);
+
+    /// <summary>Return the total input length in bytes, or a negative value on error</summary>
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    internal delegate long LengthCallback(IntPtr userData);
+
+    /// <summary>
+    /// Read callbacks backing <c>oxidize_parsed_document_open_callbacks</c>.
+    /// Field order MUST match <c>ReadCallbacks</c> in <c>native/src/callback_reader.rs</c>.
+    /// All three function pointers are required; the delegates and
+    /// <c>UserData</c> must stay alive until the handle is freed.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    internal struct ReadCallbacksNative
+    {
+        public IntPtr UserData;
+        public IntPtr Read;
+        public IntPtr Seek;
+        public IntPtr Length;
+    }
+
+    /// <summary>
+    /// Open a parsed document handle over caller-supplied read callbacks; only
+    /// the regions the parser needs are read. <paramref name="password"/> may be <c>null</c>.
+    /// </summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_open_callbacks(
+        ref ReadCallbacksNative callbacks,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? password,
+        out IntPtr outHandle);
+
+    /// <summary>Free a parsed document handle (null is a no-op)</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern void oxidize_parsed_document_free(IntPtr handle);
+
+    /// <summary>Get the number of pages</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_get_page_count(
+        IntPtr handle, out nuint outCount);
+
+    /// <summary>Get the dimensions of a page (1-based)</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_get_page_dimensions(
+        IntPtr handle,
+        nuint pageNumber,
+        out double outWidth,
+        out double outHeight);
+
+    /// <summary>Get the PDF version string</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_get_pdf_version(
+        IntPtr handle, out IntPtr outVersion);
+
+    /// <summary>Get document metadata as JSON</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_get_metadata(
+        IntPtr handle, out IntPtr outJson);
+
+    /// <summary>Check whether the document is encrypted</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_is_encrypted(
+        IntPtr handle, [MarshalAs(UnmanagedType.I1)] out bool outEncrypted);
+
+    /// <summary>Extract text from all pages</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_extract_text(
+        IntPtr handle, out IntPtr outText);
+
+    /// <summary>Extract text from a specific page (1-based)</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_extract_text_from_page(
+        IntPtr handle, nuint pageNumber, out IntPtr outText);
+
+    /// <summary>Extract the words, or every glyph, of a page (1-based) as a JSON array</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_extract_words(
+        IntPtr handle, nuint pageNumber, byte glyphs, out IntPtr outJson);
+
+    /// <summary>Extract text from all pages with explicit extraction options</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_extract_text_with_options(
+        IntPtr handle,
+        ref ExtractionOptionsNative options,
+        out IntPtr outText);
+
+    /// <summary>Extract text chunks optimized for RAG/LLM pipelines</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_extract_chunks(
+        IntPtr handle,
+        ref ChunkOptionsNative options,
+        out IntPtr outJson);
+
+    /// <summary>Extract text chunks from a specific page (1-based)</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_extract_chunks_from_page(
+        IntPtr handle,
+        nuint pageNumber,
+        ref ChunkOptionsNative options,
+        out IntPtr outJson);
+
+    /// <summary>Chunk the document text using a fixed-size + overlap strategy</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_chunk_pdf(
+        IntPtr handle,
+        nuint chunkSize, nuint overlap, byte detectLanguage,
+        out IntPtr outJson);
+
+    /// <summary>Analyze a page's content to determine if it's text, scanned, or mixed</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_analyze_page_content(
+        IntPtr handle, nuint pageNumber, out IntPtr outJson);
+
+    /// <summary>Get raw content streams for a page (1-based) as base64-encoded JSON</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_get_page_content_stream(
+        IntPtr handle, nuint pageNumber, out IntPtr outJson);
+
+    /// <summary>Get page resources (fonts, images, resource keys) as JSON (1-based)</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_get_page_resources(
+        IntPtr handle, nuint pageNumber, out IntPtr outJson);
+
+    /// <summary>Get all annotations as a JSON array</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_get_annotations(
+        IntPtr handle, out IntPtr outJson);
+
+    /// <summary>Check whether the document has AcroForm fields</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_has_form_fields(
+        IntPtr handle, [MarshalAs(UnmanagedType.I1)] out bool outHas);
+
+    /// <summary>Get all AcroForm fields as a JSON array</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_get_form_fields(
+        IntPtr handle, out IntPtr outJson);
+
+    /// <summary>Check whether the document contains digital signatures</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_has_signatures(
+        IntPtr handle, [MarshalAs(UnmanagedType.I1)] out bool outHasSignatures);
+
+    /// <summary>Get the digital signature fields as a JSON array</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_get_signatures(
+        IntPtr handle, out IntPtr outJson);
+
+    /// <summary>Verify all digital signatures and return the results as JSON</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_verify_signatures(
+        IntPtr handle, out IntPtr outJson);
+
+    /// <summary>Partition the document into typed semantic elements (JSON array)</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_partition(
+        IntPtr handle, out IntPtr outJson);
+
+    /// <summary>Partition the document using a pre-configured ExtractionProfile</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_partition_with_profile(
+        IntPtr handle, byte profile, out IntPtr outJson);
+
+    /// <summary>Partition the document using a <c>PartitionConfig</c> serialised as JSON</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_partition_with_config(
+        IntPtr handle,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string configJson,
+        out IntPtr outJson);
+
+    /// <summary>Extract structure-aware RAG chunks (JSON array)</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_rag_chunks(
+        IntPtr handle, out IntPtr outJson);
+
+    /// <summary>Extract RAG chunks using a pre-configured ExtractionProfile</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_rag_chunks_with_profile(
+        IntPtr handle, byte profile, out IntPtr outJson);
+
+    /// <summary>
+    /// Extract RAG chunks with optional partition and hybrid configs (pass
+    /// <c>null</c> for either to use the upstream default for that stage).
+    /// </summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_rag_chunks_with_config(
+        IntPtr handle,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? partitionConfigJson,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? hybridConfigJson,
+        out IntPtr outJson);
+
+    /// <summary>
+    /// Extract semantic chunks. The semantic config is required; the partition
+    /// config is optional.
+    /// </summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_semantic_chunks(
+        IntPtr handle,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? partitionConfigJson,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string semanticConfigJson,
+        out IntPtr outJson);
+
+    /// <summary>Extract semantic chunks using a pre-configured or registered profile</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_semantic_chunks_with_profile(
+        IntPtr handle, byte profile, out IntPtr outJson);
+
+    /// <summary>Extract the detected tables as a JSON array of cell grids</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_extract_tables(
+        IntPtr handle,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? partitionConfigJson,
+        out IntPtr outJson);
+
+    /// <summary>Read the document outline (bookmarks) as a JSON tree</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_get_outline(
+        IntPtr handle, out IntPtr outJson);
+
+    /// <summary>Read the open action, viewer preferences, named destinations and page labels as JSON</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_get_navigation_metadata(
+        IntPtr handle, out IntPtr outJson);
+
+    /// <summary>Read the logical structure tree as JSON</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_get_struct_tree(
+        IntPtr handle, out IntPtr outJson);
+
+    /// <summary>Export the document as Markdown</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_to_markdown(
+        IntPtr handle, out IntPtr outText);
+
+    /// <summary>Export the document as Markdown with explicit <c>MarkdownOptions</c> JSON</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_to_markdown_with_options(
+        IntPtr handle,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string optionsJson,
+        out IntPtr outText);
+
+    /// <summary>Export the document in contextual format (LLM-optimized)</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_to_contextual(
+        IntPtr handle, out IntPtr outText);
+
+    /// <summary>Export the document as structured JSON</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_parsed_document_to_json(
+        IntPtr handle, out IntPtr outText);
+
+    /// <summary>
+    /// Convert a page (0-based) into a writable page. Returns a page handle
+    /// (IntPtr.Zero on error) that must be freed with <c>oxidize_page_free</c>
+    /// or handed to <c>oxidize_document_add_page</c>.
+    /// </summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern IntPtr oxidize_parsed_document_page_to_writable(
+        IntPtr handle, uint pageIndex);
+
+    // ── Chunk streams ─────────────────────────────────────────────────────────
+
+    /// <summary>
+    /// Open a stream that yields <c>oxidize_chunk_pdf</c> chunks one at a time.
+    /// The stream must be freed with <c>oxidize_chunk_stream_free</c>.
+    /// </summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_chunk_pdf_stream_open(
+        IntPtr pdfBytes, nuint pdfLen,
+        nuint chunkSize, nuint overlap, byte detectLanguage,
+        out IntPtr outStream);
+
+    /// <summary>
+    /// Open a stream that yields <c>oxidize_rag_chunks_with_config</c> chunks.
     /// </summary>
     /// <remarks>
-    /// The native error channel is THREAD-LOCAL (issue #55). This MUST be called
-    /// synchronously, on the same thread, immediately after the failing native
-    /// call — before any <c>await</c>. An <c>await</c> may resume the
-    /// continuation on a different thread-pool thread, which would read that
-    /// thread's (empty or unrelated) error slot. All wrappers satisfy this by
-    /// invoking it from synchronous <c>ThrowIfError</c> helpers; async methods
-    /// keep the whole call+check sequence inside a single <c>Task.Run</c> body.
+    /// Pages are partitioned one at a time, so memory stays bounded by a page.
+    /// A page with a ruled table grid is partitioned from its paragraph-level
+    /// text, so the grid's cell text is yielded as prose rather than as a table
+    /// chunk. The same applies to <c>oxidize_semantic_chunk_stream_open</c>.
     /// </remarks>
-    internal static string? GetLastError()
-    {
-        IntPtr errorPtr = IntPtr.Zero;
-        try
-        {
-            var result = oxidize_get_last_error(out errorPtr);
-            if (result != (int)ErrorCode.Success || errorPtr == IntPtr.Zero)
-                return null;
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_rag_chunk_stream_open(
+        IntPtr pdfBytes, nuint pdfLen,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? partitionConfigJson,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? hybridConfigJson,
+        out IntPtr outStream);
 
-            return Marshal.PtrToStringUTF8(errorPtr);
-        }
-        finally
-        {
-            if (errorPtr != IntPtr.Zero)
-                oxidize_free_string(errorPtr);
+    /// <summary>Open a stream that yields <c>oxidize_semantic_chunks</c> chunks</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_semantic_chunk_stream_open(
+        IntPtr pdfBytes, nuint pdfLen,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? partitionConfigJson,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string semanticConfigJson,
+        out IntPtr outStream);
+
+    /// <summary><c>oxidize_chunk_pdf_stream_open</c> reading from a file path</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_chunk_pdf_stream_open_path(
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string path,
+        nuint chunkSize, nuint overlap, byte detectLanguage,
+        out IntPtr outStream);
+
+    /// <summary><c>oxidize_rag_chunk_stream_open</c> reading from a file path</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_rag_chunk_stream_open_path(
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string path,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? partitionConfigJson,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? hybridConfigJson,
+        out IntPtr outStream);
+
+    /// <summary><c>oxidize_semantic_chunk_stream_open</c> reading from a file path</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_semantic_chunk_stream_open_path(
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string path,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? partitionConfigJson,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string semanticConfigJson,
+        out IntPtr outStream);
+
+    /// <summary>
+    /// Advance a chunk stream. <paramref name="outJson"/> receives the next chunk
+    /// as a JSON object, or IntPtr.Zero once the stream is exhausted.
+    /// </summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_chunk_stream_next(
+        IntPtr stream, out IntPtr outJson);
+
+    /// <summary>Free a chunk stream (null is a no-op)</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern void oxidize_chunk_stream_free(IntPtr stream);
+
+    // ── Progress reporting and cancellation ───────────────────────────────────
+
+    /// <summary>Called after each unit of work with the completed and total counts</summary>
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    internal delegate void ProgressCallback(IntPtr userData, uint completed, uint total);
+
+    /// <summary>Polled before each unit of work; return non-zero to cancel the call</summary>
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    internal delegate byte CancelledCallback(IntPtr userData);
+
+    /// <summary>
+    /// Progress callbacks for the <c>*_with_progress</c> calls.
+    /// Field order MUST match <c>ProgressCallbacks</c> in <c>native/src/progress.rs</c>.
+    /// Either function pointer may be IntPtr.Zero; delegates must be kept alive
+    /// for the duration of the call.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    internal struct ProgressCallbacksNative
+    {
+        public IntPtr UserData;
+        public IntPtr OnProgress;
+        public IntPtr IsCancelled;
+    }
+
+    /// <summary>
+    /// <c>oxidize_extract_text</c> with per-page progress. Returns <c>Cancelled</c>
+    /// when <c>IsCancelled</c> fires.
+    /// </summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_extract_text_with_progress(
+        IntPtr pdfBytes, nuint pdfLen,
+        ref ProgressCallbacksNative progress,
+        out IntPtr outText);
+
+    /// <summary><c>oxidize_chunk_pdf</c> with per-page progress and cancellation</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_chunk_pdf_with_progress(
+        IntPtr pdfBytes, nuint pdfLen,
+        nuint chunkSize, nuint overlap, byte detectLanguage,
+        ref ProgressCallbacksNative progress,
+        out IntPtr outJson);
+
+    /// <summary>
+    /// Partition with per-page progress and cancellation. <paramref name="configJson"/>
+    /// is optional (<c>null</c> uses the default <c>PartitionConfig</c>).
+    /// </summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_partition_with_progress(
+        IntPtr pdfBytes, nuint pdfLen,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? configJson,
+        ref ProgressCallbacksNative progress,
+        out IntPtr outJson);
+
+    /// <summary><c>oxidize_rag_chunks_with_config</c> with per-page progress and cancellation</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_rag_chunks_with_progress(
+        IntPtr pdfBytes, nuint pdfLen,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? partitionConfigJson,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? hybridConfigJson,
+        ref ProgressCallbacksNative progress,
+        out IntPtr outJson);
+
+    /// <summary><c>oxidize_semantic_chunks</c> with per-page progress and cancellation</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_semantic_chunks_with_progress(
+        IntPtr pdfBytes, nuint pdfLen,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? partitionConfigJson,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string semanticConfigJson,
+        ref ProgressCallbacksNative progress,
+        out IntPtr outJson);
+
+    /// <summary><c>oxidize_merge_pdfs_with_ranges</c> with per-page progress and cancellation</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_merge_pdfs_with_progress(
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string inputsJson,
+        ref ProgressCallbacksNative progress,
+        out IntPtr outBytes,
+        out nuint outLen);
+
+    // ── Resource limits ───────────────────────────────────────────────────────
+
+    /// <summary>
+    /// Set the process-wide resource limits from a JSON object
+    /// (<c>max_stream_bytes</c>, <c>max_objects</c>, <c>max_page_tree_depth</c>,
+    /// <c>max_pages</c>, <c>max_duration_ms</c>). Pass <c>null</c> to reset every limit.
+    /// </summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_set_resource_limits(
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? limitsJson);
+
+    /// <summary>Get the process-wide resource limits as JSON (unset limits as <c>null</c>)</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_get_resource_limits(out IntPtr outJson);
+
+    // ── Encoded results (JSON or CBOR) ────────────────────────────────────────
+
+    /// <summary><c>oxidize_extract_images_bytes</c> as JSON (0) or CBOR (1); free with <c>oxidize_free_bytes</c></summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_extract_images_encoded(
+        IntPtr pdfBytes, nuint pdfLen, byte encoding,
+        out IntPtr outBytes, out nuint outLen);
+
+    /// <summary><c>oxidize_get_annotations</c> as JSON (0) or CBOR (1); free with <c>oxidize_free_bytes</c></summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_get_annotations_encoded(
+        IntPtr pdfBytes, nuint pdfLen, byte encoding,
+        out IntPtr outBytes, out nuint outLen);
+
+    /// <summary><c>oxidize_get_form_fields</c> as JSON (0) or CBOR (1); free with <c>oxidize_free_bytes</c></summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_get_form_fields_encoded(
+        IntPtr pdfBytes, nuint pdfLen, byte encoding,
+        out IntPtr outBytes, out nuint outLen);
+
+    /// <summary><c>oxidize_partition</c> as JSON (0) or CBOR (1); free with <c>oxidize_free_bytes</c></summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_partition_encoded(
+        IntPtr pdfBytes, nuint pdfLen, byte encoding,
+        out IntPtr outBytes, out nuint outLen);
+
+    /// <summary><c>oxidize_rag_chunks</c> as JSON (0) or CBOR (1); free with <c>oxidize_free_bytes</c></summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_rag_chunks_encoded(
+        IntPtr pdfBytes, nuint pdfLen, byte encoding,
+        out IntPtr outBytes, out nuint outLen);
+
+    // ── Parallel page extraction ──────────────────────────────────────────────
+
+    /// <summary>
+    /// <c>oxidize_extract_text</c> spread over <paramref name="threads"/> native
+    /// worker threads (0 = one per CPU, clamped to the CPU count). Output
+    /// matches the sequential call.
+    /// </summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_extract_text_parallel(
+        IntPtr pdfBytes, nuint pdfLen, uint threads, out IntPtr outText);
+
+    /// <summary>
+    /// <c>oxidize_partition</c> spread over <paramref name="threads"/> native
+    /// worker threads (0 = one per CPU, clamped to the CPU count). Output
+    /// matches the sequential call.
+    /// </summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_partition_parallel(
+        IntPtr pdfBytes, nuint pdfLen, uint threads, out IntPtr outJson);
+
+    // ── Shared document handle ────────────────────────────────────────────────
+
+    /// <summary>
+    /// Open a read-only document handle that may be used from several threads
+    /// at once. <paramref name="password"/> may be <c>null</c>. The handle must
+    /// be freed with <c>oxidize_shared_document_free</c>.
+    /// </summary>
+    /// <remarks>
+    /// The handle is a bounded pool of parsed readers over one copy of the
+    /// bytes, at most one per CPU. Each call runs on a reader of its own; when
+    /// every reader is busy the call waits for one to be returned. Caches are
+    /// per reader and reused by later calls on it, not shared between readers.
+    /// </remarks>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_shared_document_open(
+        IntPtr pdfBytes, nuint pdfLen,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? password,
+        out IntPtr outHandle);
+
+    /// <summary><c>oxidize_shared_document_open</c> for a file on disk</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_shared_document_open_path(
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string path,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? password,
+        out IntPtr outHandle);
+
+    /// <summary>Free a shared document handle; no other call on it may be in progress</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern void oxidize_shared_document_free(IntPtr handle);
+
+    /// <summary>Get the number of pages</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_shared_document_get_page_count(
+        IntPtr handle, out nuint outCount);
+
+    /// <summary>Get document metadata as JSON</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_shared_document_get_metadata(
+        IntPtr handle, out IntPtr outJson);
+
+    /// <summary>Extract text from all pages</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_shared_document_extract_text(
+        IntPtr handle, out IntPtr outText);
+
+    /// <summary>Extract text from a specific page (1-based)</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_shared_document_extract_text_from_page(
+        IntPtr handle, nuint pageNumber, out IntPtr outText);
+
+    /// <summary>Partition the document into typed semantic elements (JSON array)</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_shared_document_partition(
+        IntPtr handle, out IntPtr outJson);
+
+    /// <summary>Extract structure-aware RAG chunks (JSON array)</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_shared_document_rag_chunks(
+        IntPtr handle, out IntPtr outJson);
+
+    /// <summary>Get the dimensions of a page (1-based)</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_shared_document_get_page_dimensions(
+        IntPtr handle,
+        nuint pageNumber,
+        out double outWidth,
+        out double outHeight);
+
+    /// <summary>Extract text chunks from a specific page (1-based)</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_shared_document_extract_chunks_from_page(
+        IntPtr handle,
+        nuint pageNumber,
+        ref ChunkOptionsNative options,
+        out IntPtr outJson);
+
+    /// <summary>Extract the words, or every glyph, of a page (1-based) as a JSON array</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_shared_document_extract_words(
+        IntPtr handle, nuint pageNumber, byte glyphs, out IntPtr outJson);
+
+    /// <summary>Get all annotations as a JSON array</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_shared_document_get_annotations(
+        IntPtr handle, out IntPtr outJson);
+
+    /// <summary>Get page resources (fonts, images, resource keys) as JSON (1-based)</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_shared_document_get_page_resources(
+        IntPtr handle, nuint pageNumber, out IntPtr outJson);
+
+    /// <summary>Get raw content streams for a page (1-based) as base64-encoded JSON</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_shared_document_get_page_content_stream(
+        IntPtr handle, nuint pageNumber, out IntPtr outJson);
+
+    /// <summary>Analyze a page's content to determine if it's text, scanned, or mixed</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_shared_document_analyze_page_content(
+        IntPtr handle, nuint pageNumber, out IntPtr outJson);
+
+    /// <summary>
+    /// Convert a page (0-based) into a writable page. Returns a page handle
+    /// (IntPtr.Zero on error) that must be freed with <c>oxidize_page_free</c>
+    /// or handed to <c>oxidize_document_add_page</c>.
+    /// </summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern IntPtr oxidize_shared_document_page_to_writable(
+        IntPtr handle, uint pageIndex);
+
+    // ── Logging ───────────────────────────────────────────────────────────────
+
+    /// <summary>
+    /// Receives one native log record. <paramref name="target"/> and
+    /// <paramref name="message"/> are UTF-8 strings valid only for the call.
+    /// </summary>
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    internal delegate void LogCallback(IntPtr userData, int level, IntPtr target, IntPtr message);
+
+    /// <summary>
+    /// Register the process-wide log callback, or clear it with <c>null</c>.
+    /// Records from level 1 (error) up to <paramref name="maxLevel"/> (5 = trace)
+    /// are delivered, possibly from several threads at once. The delegate must
+    /// be kept alive until it is replaced or cleared.
+    /// </summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_set_log_callback(
+        LogCallback? callback, IntPtr userData, int maxLevel);
+
+    // ── Performance metrics ───────────────────────────────────────────────────
+
+    /// <summary>
+    /// <c>oxidize_extract_text</c> plus a metrics JSON object (total and per-phase
+    /// milliseconds, object and page counts)
+    /// </summary>
+    /// <remarks>
+    /// The metrics object has <c>total_ms</c>, <c>phases</c> (name and
+    /// <c>ms</c>), <c>object_count</c>, <c>page_count</c> and
+    /// <c>peak_alloc_bytes</c>. <c>peak_alloc_bytes</c> is only measured when the native library is
+    /// built with the <c>alloc-metrics</c> cargo feature and is always null in
+    /// default builds. The same object is returned by every
+    /// <c>*_with_metrics</c> call.
+    /// </remarks>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_extract_text_with_metrics(
+        IntPtr pdfBytes, nuint pdfLen,
+        out IntPtr outText, out IntPtr outMetrics);
+
+    /// <summary><c>oxidize_partition</c> plus a metrics JSON object</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_partition_with_metrics(
+        IntPtr pdfBytes, nuint pdfLen,
+        out IntPtr outJson, out IntPtr outMetrics);
+
+    /// <summary><c>oxidize_rag_chunks</c> plus a metrics JSON object</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_rag_chunks_with_metrics(
+        IntPtr pdfBytes, nuint pdfLen,
+        out IntPtr outJson, out IntPtr outMetrics);
+
+    /// <summary><c>oxidize_chunk_pdf</c> plus a metrics JSON object</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_chunk_pdf_with_metrics(
+        IntPtr pdfBytes, nuint pdfLen,
+        nuint chunkSize, nuint overlap, byte detectLanguage,
+        out IntPtr outJson, out IntPtr outMetrics);
+
+    // ── Custom extraction profiles ────────────────────────────────────────────
+
+    /// <summary>
+    /// Register a custom profile from JSON (<c>extraction</c>, <c>partition</c>,
+    /// <c>hybrid</c> and <c>semantic</c> sections, all optional).
+    /// <paramref name="outId"/> receives the id to pass as <c>profile</c>;
+    /// re-registering a name keeps its id.
+    /// </summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_register_profile(
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string name,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string profileJson,
+        out byte outId);
+
+    /// <summary>Look up the id of a built-in or registered custom profile by name</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_profile_id(
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string name,
+        out byte outId);
+
+    // ── Batch processing ──────────────────────────────────────────────────────
+
+    /// <summary>
+    /// Run one extraction or chunking operation over many PDFs described by a
+    /// JSON manifest, on <paramref name="threads"/> native worker threads
+    /// (0 = one per CPU, clamped to the CPU count). Returns <c>Success</c> whatever the per-input outcome;
+    /// <paramref name="outJson"/> holds one <c>{index, result, error}</c> per input.
+    /// </summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_process_batch(
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string manifestJson,
+        uint threads,
+        out IntPtr outJson);
+
+    // ── Table extraction ──────────────────────────────────────────────────────
+
+    /// <summary>
+    /// Extract the tables the partitioner detects as a JSON array of cell grids.
+    /// <paramref name="partitionConfigJson"/> is optional (<c>null</c> uses the
+    /// default <c>PartitionConfig</c>).
+    /// </summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_extract_tables(
+        IntPtr pdfBytes, nuint pdfLen,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? partitionConfigJson,
+        out IntPtr outJson);
+
+    /// <summary>Render one extracted table as RFC 4180 CSV</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_table_to_csv(
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string tableJson,
+        out IntPtr outText);
+
+    /// <summary>Render one extracted table as a GitHub-flavoured Markdown table</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_table_to_markdown(
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string tableJson,
+        out IntPtr outText);
+
+    /// <summary>Render one extracted table as an HTML <c>&lt;table&gt;</c></summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_table_to_html(
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string tableJson,
+        out IntPtr outText);
+
+    // ── Navigation (read) ─────────────────────────────────────────────────────
+
+    /// <summary>
+    /// Read the document outline (bookmarks) as <c>{"items": [...]}</c>, a
+    /// superset of the <c>oxidize_document_set_outline</c> input with 0-based
+    /// pages and resolved destinations.
+    /// </summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_get_outline(
+        IntPtr pdfBytes, nuint pdfLen, out IntPtr outJson);
+
+    /// <summary>
+    /// Read the catalog's open action, viewer preferences, named destinations
+    /// and page labels as JSON, in the shapes the matching
+    /// <c>oxidize_document_set_*_json</c> setters take.
+    /// </summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_get_navigation_metadata(
+        IntPtr pdfBytes, nuint pdfLen, out IntPtr outJson);
+
+    // ── Tagged structure (read) ───────────────────────────────────────────────
+
+    /// <summary>
+    /// Read the logical structure tree in the JSON shape
+    /// <c>oxidize_document_set_struct_tree_json</c> accepts. An untagged
+    /// document yields <c>"elements": []</c>; role-map chains are resolved to a
+    /// standard type.
+    /// </summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_get_struct_tree(
+        IntPtr pdfBytes, nuint pdfLen, out IntPtr outJson);
+
+    // ── Password-protected input ──────────────────────────────────────────────
+    //
+    // Each import below is its counterpart with a password after the input
+    // buffer (or path): an encrypted PDF is unlocked with the user or owner
+    // password and unencrypted input ignores it. A wrong password returns
+    // PasswordRequired.
+
+    /// <summary><c>oxidize_extract_text</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_extract_text_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        out IntPtr outText);
+
+    /// <summary><c>oxidize_extract_text_with_progress</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_extract_text_with_progress_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        ref ProgressCallbacksNative progress, out IntPtr outText);
+
+    /// <summary><c>oxidize_extract_chunks</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_extract_chunks_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        ref ChunkOptionsNative options, out IntPtr outJson);
+
+    /// <summary><c>oxidize_get_page_count</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_get_page_count_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        out nuint outCount);
+
+    /// <summary><c>oxidize_extract_text_from_page</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_extract_text_from_page_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        nuint pageNumber, out IntPtr outText);
+
+    /// <summary><c>oxidize_extract_chunks_from_page</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_extract_chunks_from_page_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        nuint pageNumber, ref ChunkOptionsNative options, out IntPtr outJson);
+
+    /// <summary><c>oxidize_get_pdf_version</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_get_pdf_version_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        out IntPtr outVersion);
+
+    /// <summary><c>oxidize_get_page_dimensions</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_get_page_dimensions_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        nuint pageNumber, out double outWidth, out double outHeight);
+
+    /// <summary><c>oxidize_get_metadata</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_get_metadata_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        out IntPtr outJson);
+
+    /// <summary><c>oxidize_extract_text_with_options</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_extract_text_with_options_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        ref ExtractionOptionsNative options, out IntPtr outText);
+
+    /// <summary><c>oxidize_to_markdown_with_options</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_to_markdown_with_options_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string optionsJson, out IntPtr outText);
+
+    /// <summary><c>oxidize_to_markdown</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_to_markdown_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        out IntPtr outText);
+
+    /// <summary><c>oxidize_to_contextual</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_to_contextual_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        out IntPtr outText);
+
+    /// <summary><c>oxidize_to_json</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_to_json_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        out IntPtr outText);
+
+    /// <summary><c>oxidize_partition</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_partition_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        out IntPtr outJson);
+
+    /// <summary><c>oxidize_partition_with_profile</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_partition_with_profile_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        byte profile, out IntPtr outJson);
+
+    /// <summary><c>oxidize_partition_with_config</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_partition_with_config_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string configJson, out IntPtr outJson);
+
+    /// <summary><c>oxidize_partition_with_progress</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_partition_with_progress_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? configJson,
+        ref ProgressCallbacksNative progress, out IntPtr outJson);
+
+    /// <summary><c>oxidize_rag_chunks</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_rag_chunks_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        out IntPtr outJson);
+
+    /// <summary><c>oxidize_rag_chunks_with_profile</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_rag_chunks_with_profile_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        byte profile, out IntPtr outJson);
+
+    /// <summary><c>oxidize_rag_chunks_with_config</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_rag_chunks_with_config_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? partitionConfigJson,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? hybridConfigJson, out IntPtr outJson);
+
+    /// <summary><c>oxidize_rag_chunks_with_progress</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_rag_chunks_with_progress_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? partitionConfigJson,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? hybridConfigJson,
+        ref ProgressCallbacksNative progress, out IntPtr outJson);
+
+    /// <summary><c>oxidize_semantic_chunks</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_semantic_chunks_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? partitionConfigJson,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string semanticConfigJson, out IntPtr outJson);
+
+    /// <summary><c>oxidize_semantic_chunks_with_profile</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_semantic_chunks_with_profile_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        byte profile, out IntPtr outJson);
+
+    /// <summary><c>oxidize_semantic_chunks_with_progress</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_semantic_chunks_with_progress_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? partitionConfigJson,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string semanticConfigJson,
+        ref ProgressCallbacksNative progress, out IntPtr outJson);
+
+    /// <summary><c>oxidize_get_annotations</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_get_annotations_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        out IntPtr outJson);
+
+    /// <summary><c>oxidize_get_page_resources</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_get_page_resources_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        nuint pageNumber, out IntPtr outJson);
+
+    /// <summary><c>oxidize_get_page_content_stream</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_get_page_content_stream_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        nuint pageNumber, out IntPtr outJson);
+
+    /// <summary><c>oxidize_analyze_page_content</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_analyze_page_content_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        nuint pageNumber, out IntPtr outJson);
+
+    /// <summary><c>oxidize_has_signatures</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_has_signatures_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        [MarshalAs(UnmanagedType.I1)] out bool hasSignatures);
+
+    /// <summary><c>oxidize_get_signatures</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_get_signatures_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        out IntPtr outJson);
+
+    /// <summary><c>oxidize_verify_signatures</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_verify_signatures_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        out IntPtr outJson);
+
+    /// <summary><c>oxidize_has_form_fields</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_has_form_fields_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        [MarshalAs(UnmanagedType.I1)] out bool outHasFields);
+
+    /// <summary><c>oxidize_get_form_fields</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_get_form_fields_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        out IntPtr outJson);
+
+    /// <summary><c>oxidize_chunk_pdf</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_chunk_pdf_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        nuint chunkSize, nuint overlap, byte detectLanguage, out IntPtr outJson);
+
+    /// <summary><c>oxidize_chunk_pdf_with_progress</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_chunk_pdf_with_progress_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        nuint chunkSize, nuint overlap, byte detectLanguage, ref ProgressCallbacksNative progress,
+        out IntPtr outJson);
+
+    /// <summary><c>oxidize_rag_chunk_stream_open</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_rag_chunk_stream_open_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? partitionConfigJson,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? hybridConfigJson, out IntPtr outStream);
+
+    /// <summary><c>oxidize_semantic_chunk_stream_open</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_semantic_chunk_stream_open_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? partitionConfigJson,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string semanticConfigJson, out IntPtr outStream);
+
+    /// <summary><c>oxidize_chunk_pdf_stream_open</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_chunk_pdf_stream_open_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        nuint chunkSize, nuint overlap, byte detectLanguage, out IntPtr outStream);
+
+    /// <summary><c>oxidize_rag_chunk_stream_open_path</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_rag_chunk_stream_open_path_with_password(
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string path,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? partitionConfigJson,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? hybridConfigJson, out IntPtr outStream);
+
+    /// <summary><c>oxidize_semantic_chunk_stream_open_path</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_semantic_chunk_stream_open_path_with_password(
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string path,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string? partitionConfigJson,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string semanticConfigJson, out IntPtr outStream);
+
+    /// <summary><c>oxidize_chunk_pdf_stream_open_path</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_chunk_pdf_stream_open_path_with_password(
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string path,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string password, nuint chunkSize, nuint overlap,
+        byte detectLanguage, out IntPtr outStream);
+
+    /// <summary><c>oxidize_split_pdf_bytes</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_split_pdf_bytes_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        out IntPtr outJson);
+
+    /// <summary><c>oxidize_split_pdf_bytes_with_options</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_split_pdf_bytes_with_options_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string optionsJson, out IntPtr outJson);
+
+    /// <summary><c>oxidize_rotate_pdf_bytes</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_rotate_pdf_bytes_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        int degrees, out IntPtr outBytes, out nuint outLen);
+
+    /// <summary><c>oxidize_rotate_pages_bytes</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_rotate_pages_bytes_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        int degrees, [MarshalAs(UnmanagedType.LPUTF8Str)] string? pagesJson, out IntPtr outBytes,
+        out nuint outLen);
+
+    /// <summary><c>oxidize_extract_pages_bytes</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_extract_pages_bytes_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string pagesJson, out IntPtr outBytes,
+        out nuint outLen);
+
+    /// <summary><c>oxidize_reorder_pages_bytes</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_reorder_pages_bytes_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string orderJson, out IntPtr outBytes,
+        out nuint outLen);
+
+    /// <summary><c>oxidize_swap_pages_bytes</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_swap_pages_bytes_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        nuint pageA, nuint pageB, out IntPtr outBytes, out nuint outLen);
+
+    /// <summary><c>oxidize_move_page_bytes</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_move_page_bytes_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        nuint fromIndex, nuint toIndex, out IntPtr outBytes, out nuint outLen);
+
+    /// <summary><c>oxidize_reverse_pages_bytes</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_reverse_pages_bytes_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        out IntPtr outBytes, out nuint outLen);
+
+    /// <summary><c>oxidize_overlay_pdf_bytes</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_overlay_pdf_bytes_with_password(
+        IntPtr baseBytes, nuint baseLen, IntPtr overlayBytes, nuint overlayLen,
+        [MarshalAs(UnmanagedType.LPUTF8Str)] string password, out IntPtr outBytes,
+        out nuint outLen);
+
+    /// <summary><c>oxidize_extract_images_bytes</c> for an encrypted PDF</summary>
+    [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+    internal static extern int oxidize_extract_images_bytes_with_password(
+        IntPtr pdfBytes, nuint pdfLen, [MarshalAs(UnmanagedType.LPUTF8Str)] string password,
+        out IntPtr outJson);
+
+    /// <summary>
+    /// Gets the last error message from the native library and clears it
+    /// </summary>
+    /// <returns>The error message, or null if no error was set</returns>
+    /// <summary>
+    /// Reads the native library's last error message for the current thread.
+    /// </summary>
+    /// <remarks>
+    /// The native error channel is THREAD-LOCAL (issue #55). This MUST be called
+    /// synchronously, on the same thread, immediately after the failing native
+    /// call — before any <c>await</c>. An <c>await</c> may resume the
+    /// continuation on a different thread-pool thread, which would read that
+    /// thread's (empty or unrelated) error slot. All wrappers satisfy this by
+    /// invoking it from synchronous <c>ThrowIfError</c> helpers; async methods
+    /// keep the whole call+check sequence inside a single <c>Task.Run</c> body.
+    /// </remarks>
+    internal static string? GetLastError()
+    {
+        IntPtr errorPtr = IntPtr.Zero;
+        try
+        {
+            var result = oxidize_get_last_error(out errorPtr);
+            if (result != (int)ErrorCode.Success || errorPtr == IntPtr.Zero)
+                return null;
+
+            return Marshal.PtrToStringUTF8(errorPtr);
+        }
+        finally
+        {
+            if (errorPtr != IntPtr.Zero)
+                oxidize_free_string(errorPtr);
+        }
+    }
+
+    /// <summary>
+    /// Reads the structured form of the last error for the current thread, or
+    /// <c>null</c> when none is recorded. Same thread-affinity rule as
+    /// <see cref="GetLastError"/>.
+    /// </summary>
+    internal static Models.PdfErrorDetails? GetLastErrorDetails()
+    {
+        IntPtr jsonPtr = IntPtr.Zero;
+        try
+        {
+            var result = oxidize_get_last_error_json(out jsonPtr);
+            if (result != (int)ErrorCode.Success || jsonPtr == IntPtr.Zero)
+                return null;
+
+            var json = Marshal.PtrToStringUTF8(jsonPtr);
+            return json is null ? null : System.Text.Json.JsonSerializer.Deserialize<Models.PdfErrorDetails>(json);
+        }
+        finally
+        {
+            if (jsonPtr != IntPtr.Zero)
+                oxidize_free_string(jsonPtr);
         }
     }
 
@@ -0,0 +1,72 @@
+using System.Runtime.ExceptionServices;
+using System.Runtime.InteropServices;
+using OxidizePdf.NET.Models;
+
+namespace OxidizePdf.NET;
+
+/// <summary>
+/// Bridges an <see cref="IProgress{T}"/> and a <see cref="CancellationToken"/>
+/// to the native <c>ProgressCallbacks</c> of the <c>*_with_progress</c> calls.
+/// The delegates are rooted by this instance for the duration of
+/// <see cref="Call"/>.
+/// </summary>
+internal sealed class NativeProgress
+{
+    internal delegate int NativeCall(ref NativeMethods.ProgressCallbacksNative progress);
+
+    private readonly IProgress<PdfProgress>? _progress;
+    private readonly CancellationToken _cancellationToken;
+    private readonly NativeMethods.ProgressCallback _onProgress;
+    private readonly NativeMethods.CancelledCallback _isCancelled;
+    private Exception? _callbackFailure;
+
+    internal NativeProgress(IProgress<PdfProgress>? progress, CancellationToken cancellationToken)
+    {
+        _progress = progress;
+        _cancellationToken = cancellationToken;
+        _onProgress = OnProgress;
+        _isCancelled = IsCancelled;
+    }
+
+    /// <summary>
+    /// Runs <paramref name="call"/> with the callbacks and maps its result:
+    /// a <c>Cancelled</c> return becomes <see cref="OperationCanceledException"/>,
+    /// an exception thrown by the progress handler is rethrown, and any other
+    /// error becomes <see cref="PdfExtractionException"/>.
+    /// </summary>
+    internal void Call(NativeCall call, string errorMsg)
+    {
+        var callbacks = new NativeMethods.ProgressCallbacksNative
+        {
+            UserData = IntPtr.Zero,
+            OnProgress = _progress is null ? IntPtr.Zero : Marshal.GetFunctionPointerForDelegate(_onProgress),
+            IsCancelled = Marshal.GetFunctionPointerForDelegate(_isCancelled),
+        };
+
+        var result = call(ref callbacks);
+        GC.KeepAlive(this);
+
+        if (_callbackFailure is not null)
+            ExceptionDispatchInfo.Throw(_callbackFailure);
+        if (result == (int)NativeMethods.ErrorCode.Cancelled)
+            throw new OperationCanceledException(_cancellationToken);
+        PdfExtractor.ThrowIfError(result, errorMsg);
+    }
+
+    private void OnProgress(IntPtr userData, uint completed, uint total)
+    {
+        // Exceptions must not unwind through native frames: record the first
+        // one and cancel the call, then rethrow it from Call.
+        try
+        {
+            _progress?.Report(new PdfProgress((int)completed, (int)total));
+        }
+        catch (Exception ex)
+        {
+            _callbackFailure ??= ex;
+        }
+    }
+
+    private byte IsCancelled(IntPtr userData) =>
+        (byte)(_callbackFailure is not null || _cancellationToken.IsCancellationRequested ? 1 : 0);
+}
@@ -0,0 +1,156 @@
+using System.Runtime.InteropServices;
+using System.Text.Json;
+using System.Text.Json.Nodes;
+using System.Text.Json.Serialization;
+using OxidizePdf.NET.Ai;
+using OxidizePdf.NET.Models;
+
+namespace OxidizePdf.NET;
+
+/// <summary>
+/// Runs one extraction or chunking operation over many PDFs in a single native
+/// call, on a pool of native worker threads. Each input is processed exactly
+/// as the single-file call would process it, and a failing input is reported
+/// in its own <see cref="PdfBatchResult{T}"/> without affecting the others.
+/// </summary>
+public static class PdfBatch
+{
+    /// <summary>
+    /// Extract the plain text of every input, as <c>PdfExtractor.ExtractTextAsync</c> would.
+    /// </summary>
+    /// <param name="inputs">The PDFs to process; inputs may only set <see cref="PdfBatchInput.ExtractionOptions"/>.</param>
+    /// <param name="threads">Worker threads; 0 uses one per CPU.</param>
+    /// <param name="ct">Cancellation token, checked before the batch starts.</param>
+    /// <returns>One result per input, in input order.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="inputs"/> or one of its elements is null.</exception>
+    /// <exception cref="ArgumentException">If an input's options fail validation.</exception>
+    /// <exception cref="ArgumentOutOfRangeException">If <paramref name="threads"/> is negative.</exception>
+    /// <exception cref="OperationCanceledException">If the operation is cancelled.</exception>
+    /// <exception cref="PdfExtractionException">If the manifest is rejected, e.g. for an option the operation does not use.</exception>
+    public static Task<List<PdfBatchResult<string>>> ExtractTextAsync(
+        IReadOnlyList<PdfBatchInput> inputs, int threads = 0, CancellationToken ct = default) =>
+        RunAsync<string>("extract_text", inputs, threads, null, ct);
+
+    /// <summary>
+    /// Partition every input into typed elements, as <c>PdfExtractor.PartitionAsync</c> would.
+    /// </summary>
+    /// <param name="inputs">The PDFs to process; inputs may set <see cref="PdfBatchInput.Profile"/> or <see cref="PdfBatchInput.PartitionConfig"/>.</param>
+    /// <param name="threads">Worker threads; 0 uses one per CPU.</param>
+    /// <param name="ct">Cancellation token, checked before the batch starts.</param>
+    /// <returns>One result per input, in input order.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="inputs"/> or one of its elements is null.</exception>
+    /// <exception cref="ArgumentException">If an input's options fail validation.</exception>
+    /// <exception cref="ArgumentOutOfRangeException">If <paramref name="threads"/> is negative.</exception>
+    /// <exception cref="OperationCanceledException">If the operation is cancelled.</exception>
+    /// <exception cref="PdfExtractionException">If the manifest is rejected, e.g. for an option the operation does not use.</exception>
+    public static Task<List<PdfBatchResult<List<PdfElement>>>> PartitionAsync(
+        IReadOnlyList<PdfBatchInput> inputs, int threads = 0, CancellationToken ct = default) =>
+        RunAsync<List<PdfElement>>("partition", inputs, threads, null, ct);
+
+    /// <summary>
+    /// Extract RAG chunks from every input, as <c>PdfExtractor.RagChunksAsync</c> would.
+    /// </summary>
+    /// <param name="inputs">The PDFs to process; inputs may set <see cref="PdfBatchInput.Profile"/>, or
+    /// <see cref="PdfBatchInput.PartitionConfig"/> and <see cref="PdfBatchInput.HybridConfig"/>.</param>
+    /// <param name="threads">Worker threads; 0 uses one per CPU.</param>
+    /// <param name="ct">Cancellation token, checked before the batch starts.</param>
+    /// <returns>One result per input, in input order.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="inputs"/> or one of its elements is null.</exception>
+    /// <exception cref="ArgumentException">If an input's options fail validation.</exception>
+    /// <exception cref="ArgumentOutOfRangeException">If <paramref name="threads"/> is negative.</exception>
+    /// <exception cref="OperationCanceledException">If the operation is cancelled.</exception>
+    /// <exception cref="PdfExtractionException">If the manifest is rejected, e.g. for an option the operation does not use.</exception>
+    public static Task<List<PdfBatchResult<List<RagChunk>>>> RagChunksAsync(
+        IReadOnlyList<PdfBatchInput> inputs, int threads = 0, CancellationToken ct = default) =>
+        RunAsync<List<RagChunk>>("rag_chunks", inputs, threads, null, ct);
+
+    /// <summary>
+    /// Chunk every input with <paramref name="chunker"/>, as
+    /// <c>DocumentChunker.ChunkPdf</c> would.
+    /// </summary>
+    /// <param name="inputs">The PDFs to process; inputs may only set <see cref="PdfBatchInput.Password"/>.</param>
+    /// <param name="chunker">Supplies the chunk size, overlap and language detection for every input.</param>
+    /// <param name="threads">Worker threads; 0 uses one per CPU.</param>
+    /// <param name="ct">Cancellation token, checked before the batch starts.</param>
+    /// <returns>One result per input, in input order.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="inputs"/>, one of its elements or <paramref name="chunker"/> is null.</exception>
+    /// <exception cref="ArgumentOutOfRangeException">If <paramref name="threads"/> is negative.</exception>
+    /// <exception cref="OperationCanceledException">If the operation is cancelled.</exception>
+    /// <exception cref="PdfExtractionException">If the manifest is rejected, e.g. for an option the operation does not use.</exception>
+    public static Task<List<PdfBatchResult<List<Ai.DocumentChunk>>>> ChunkAsync(
+        IReadOnlyList<PdfBatchInput> inputs, DocumentChunker chunker, int threads = 0, CancellationToken ct = default)
+    {
+        ArgumentNullException.ThrowIfNull(chunker);
+        return RunAsync<List<Ai.DocumentChunk>>("chunk", inputs, threads, input =>
+        {
+            input["chunk_size"] = chunker.ChunkSize;
+            input["overlap"] = chunker.Overlap;
+            input["detect_language"] = chunker.LanguageDetectionEnabled;
+        }, ct);
+    }
+
+    // ── Helpers ──────────────────────────────────────────────────────────────
+
+    private static Task<List<PdfBatchResult<T>>> RunAsync<T>(
+        string operation,
+        IReadOnlyList<PdfBatchInput> inputs,
+        int threads,
+        Action<JsonObject>? addOptions,
+        CancellationToken ct)
+    {
+        ct.ThrowIfCancellationRequested();
+        ArgumentNullException.ThrowIfNull(inputs);
+        ArgumentOutOfRangeException.ThrowIfNegative(threads);
+
+        var items = new JsonArray();
+        foreach (var input in inputs)
+        {
+            if (input is null)
+                throw new ArgumentNullException(nameof(inputs), "Batch inputs cannot contain null.");
+            input.Validate();
+            var item = input.ToJsonObject();
+            addOptions?.Invoke(item);
+            items.Add(item);
+        }
+        var manifest = new JsonObject { ["operation"] = operation, ["inputs"] = items }.ToJsonString();
+
+        ct.ThrowIfCancellationRequested();
+        return Task.Run(() => Run<T>(manifest, threads), ct);
+    }
+
+    private static List<PdfBatchResult<T>> Run<T>(string manifest, int threads)
+    {
+        IntPtr jsonPtr = IntPtr.Zero;
+        try
+        {
+            var result = NativeMethods.oxidize_process_batch(manifest, (uint)threads, out jsonPtr);
+            PdfExtractor.ThrowIfError(result, "Failed to process batch");
+
+            var json = Marshal.PtrToStringUTF8(jsonPtr) ?? "[]";
+            var items = JsonSerializer.Deserialize<List<BatchItem>>(json) ?? new();
+            return items.Select(item => new PdfBatchResult<T>
+            {
+                Index = item.Index,
+                Value = item.Result is { } value ? value.Deserialize<T>() : default,
+                Error = item.Error,
+            }).ToList();
+        }
+        finally
+        {
+            if (jsonPtr != IntPtr.Zero)
+                NativeMethods.oxidize_free_string(jsonPtr);
+        }
+    }
+
+    private sealed class BatchItem
+    {
+        [JsonPropertyName("index")]
+        public int Index { get; set; }
+
+        [JsonPropertyName("result")]
+        public JsonElement? Result { get; set; }
+
+        [JsonPropertyName("error")]
+        public PdfErrorDetails? Error { get; set; }
+    }
+}
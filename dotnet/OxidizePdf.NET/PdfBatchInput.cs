@@ -0,0 +1,97 @@
+using System.Text.Json.Nodes;
+using OxidizePdf.NET.Models;
+using OxidizePdf.NET.Pipeline;
+
+namespace OxidizePdf.NET;
+
+/// <summary>
+/// One PDF of a <see cref="PdfBatch"/> call: a file path or in-memory bytes,
+/// plus the options for that file.
+/// </summary>
+/// <remarks>
+/// Only the options the batch operation uses may be set: <see cref="ExtractionOptions"/>
+/// for text extraction, <see cref="Profile"/> or <see cref="PartitionConfig"/> for
+/// partitioning, and <see cref="Profile"/> or <see cref="PartitionConfig"/> /
+/// <see cref="HybridConfig"/> for RAG chunking. Any other option makes the whole
+/// batch fail with a <see cref="PdfExtractionException"/> naming the field.
+/// </remarks>
+public sealed class PdfBatchInput
+{
+    private PdfBatchInput(string? path, byte[]? pdfBytes)
+    {
+        Path = path;
+        PdfBytes = pdfBytes;
+    }
+
+    /// <summary>Path of the file, memory-mapped by the native library; <c>null</c> for in-memory input.</summary>
+    public string? Path { get; }
+
+    /// <summary>The PDF content; <c>null</c> for file input.</summary>
+    public byte[]? PdfBytes { get; }
+
+    /// <summary>Password (user or owner) for an encrypted input, or <c>null</c>.</summary>
+    public string? Password { get; set; }
+
+    /// <summary>Built-in or registered profile for partitioning and RAG chunking.</summary>
+    public ExtractionProfile? Profile { get; set; }
+
+    /// <summary>Text extraction options for text extraction.</summary>
+    public ExtractionOptions? ExtractionOptions { get; set; }
+
+    /// <summary>Partition configuration for partitioning and RAG chunking.</summary>
+    public PartitionConfig? PartitionConfig { get; set; }
+
+    /// <summary>Hybrid chunk configuration for RAG chunking.</summary>
+    public HybridChunkConfig? HybridConfig { get; set; }
+
+    /// <summary>Create an input read from a file.</summary>
+    /// <param name="path">Path of the PDF file.</param>
+    /// <exception cref="ArgumentNullException">If <paramref name="path"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="path"/> is empty.</exception>
+    public static PdfBatchInput FromFile(string path)
+    {
+        ArgumentException.ThrowIfNullOrEmpty(path);
+        return new PdfBatchInput(path, null);
+    }
+
+    /// <summary>Create an input from in-memory PDF bytes.</summary>
+    /// <param name="pdfBytes">The PDF content. Must not be null or empty.</param>
+    /// <exception cref="ArgumentNullException">If <paramref name="pdfBytes"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="pdfBytes"/> is empty.</exception>
+    public static PdfBatchInput FromBytes(byte[] pdfBytes)
+    {
+        ArgumentNullException.ThrowIfNull(pdfBytes);
+        if (pdfBytes.Length == 0)
+            throw new ArgumentException("PDF bytes cannot be empty.", nameof(pdfBytes));
+        return new PdfBatchInput(null, pdfBytes);
+    }
+
+    /// <summary>Validate every option that is set.</summary>
+    internal void Validate()
+    {
+        ExtractionOptions?.Validate();
+        PartitionConfig?.Validate();
+        HybridConfig?.Validate();
+    }
+
+    /// <summary>Serialises the input to one element of the manifest's <c>inputs</c> array.</summary>
+    internal JsonObject ToJsonObject()
+    {
+        var o = new JsonObject();
+        if (Path is not null)
+            o["path"] = Path;
+        else
+            o["pdf"] = Convert.ToBase64String(PdfBytes!);
+        if (Password is not null)
+            o["password"] = Password;
+        if (Profile is { } profile)
+            o["profile"] = (byte)profile;
+        if (ExtractionOptions is not null)
+            o["extraction"] = ExtractionOptions.ToJsonNode();
+        if (PartitionConfig is not null)
+            o["partition_config"] = JsonNode.Parse(PartitionConfig.ToJson());
+        if (HybridConfig is not null)
+            o["hybrid_config"] = JsonNode.Parse(HybridConfig.ToJson());
+        return o;
+    }
+}
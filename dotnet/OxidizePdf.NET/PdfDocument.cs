@@ -649,6 +649,65 @@ public sealed class PdfDocument : IDisposable
         }
     }
 
+    /// <summary>
+    /// Serializes the document into <paramref name="stream"/> as it is
+    /// written, without building the whole file in memory first. The stream
+    /// is flushed once at the end and left open.
+    /// </summary>
+    /// <param name="stream">A writable stream; it need not be seekable.</param>
+    /// <exception cref="ArgumentNullException">If <paramref name="stream"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="stream"/> is not writable.</exception>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If serialization fails.</exception>
+    /// <remarks>An exception thrown by the stream is rethrown unchanged.</remarks>
+    public void Save(Stream stream)
+    {
+        ValidateWritable(stream);
+        ThrowIfDisposed();
+
+        new StreamWriteCallbacks(stream).Call(
+            (ref NativeMethods.WriteCallbacksNative callbacks) =>
+                NativeMethods.oxidize_document_save_to_callbacks(_handle, ref callbacks),
+            "Failed to save document to stream");
+    }
+
+    /// <summary>
+    /// Serializes the document into <paramref name="stream"/> using explicit
+    /// writer options (PDF version, xref/object streams, stream compression).
+    /// The stream is flushed once at the end and left open.
+    /// </summary>
+    /// <param name="stream">A writable stream; it need not be seekable.</param>
+    /// <param name="options">Writer configuration. See <see cref="PdfSaveOptions"/>.</param>
+    /// <exception cref="ArgumentNullException">If <paramref name="stream"/> or <paramref name="options"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="stream"/> is not writable.</exception>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If serialization fails.</exception>
+    /// <remarks>An exception thrown by the stream is rethrown unchanged.</remarks>
+    public void Save(Stream stream, PdfSaveOptions options)
+    {
+        ValidateWritable(stream);
+        ArgumentNullException.ThrowIfNull(options);
+        ThrowIfDisposed();
+
+        new StreamWriteCallbacks(stream).Call(
+            (ref NativeMethods.WriteCallbacksNative callbacks) =>
+                NativeMethods.oxidize_document_save_to_callbacks_with_config(
+                    _handle,
+                    options.UseXrefStreams ? 1 : 0,
+                    options.UseObjectStreams ? 1 : 0,
+                    options.PdfVersion,
+                    options.CompressStreams ? 1 : 0,
+                    ref callbacks),
+            "Failed to save document to stream with config");
+    }
+
+    private static void ValidateWritable(Stream stream)
+    {
+        ArgumentNullException.ThrowIfNull(stream);
+        if (!stream.CanWrite)
+            throw new ArgumentException("Stream must be writable", nameof(stream));
+    }
+
     /// <summary>
     /// Sets the action triggered when the document is opened (navigate to a
     /// destination, or open a URI). Returns <c>this</c> for fluent chaining.
@@ -1282,6 +1341,6 @@ public sealed class PdfDocument : IDisposable
 
         var rustError = NativeMethods.GetLastError();
         var detail = !string.IsNullOrEmpty(rustError) ? rustError : ((NativeMethods.ErrorCode)errorCode).ToString();
-        throw new PdfExtractionException($"{message}: {detail}");
+        throw new PdfExtractionException($"{message}: {detail}", NativeMethods.GetLastErrorDetails());
     }
 }
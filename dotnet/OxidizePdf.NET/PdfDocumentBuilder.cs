@@ -197,6 +197,6 @@ public sealed class PdfDocumentBuilder : IDisposable
 
         var rustError = NativeMethods.GetLastError();
         var detail = !string.IsNullOrEmpty(rustError) ? rustError : ((NativeMethods.ErrorCode)errorCode).ToString();
-        throw new PdfExtractionException($"{message}: {detail}");
+        throw new PdfExtractionException($"{message}: {detail}", NativeMethods.GetLastErrorDetails());
     }
 }
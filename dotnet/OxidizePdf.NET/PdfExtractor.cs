@@ -68,6 +68,188 @@ public class PdfExtractor
         }
     }
 
+    /// <summary>
+    /// Describe the loaded native library: its version and core version, the
+    /// core features compiled in, the result encodings, and the JSON Schema of
+    /// every payload it accepts.
+    /// </summary>
+    /// <returns>The capabilities of the native library.</returns>
+    /// <exception cref="PdfExtractionException">If the native call fails.</exception>
+    public static PdfCapabilities GetCapabilities()
+    {
+        IntPtr jsonPtr = IntPtr.Zero;
+        try
+        {
+            var result = NativeMethods.oxidize_capabilities_json(out jsonPtr);
+            ThrowIfError(result, "Failed to get capabilities");
+
+            var json = Marshal.PtrToStringUTF8(jsonPtr) ?? "{}";
+            return JsonSerializer.Deserialize<PdfCapabilities>(json) ?? new PdfCapabilities();
+        }
+        finally
+        {
+            if (jsonPtr != IntPtr.Zero)
+                NativeMethods.oxidize_free_string(jsonPtr);
+        }
+    }
+
+    /// <summary>
+    /// Register a named custom profile for the whole process. The returned value
+    /// (128 and up, not a named <see cref="ExtractionProfile"/> member) is accepted
+    /// wherever a built-in profile is: the profile overloads of
+    /// <c>PartitionAsync</c>, <c>RagChunksAsync</c> and <c>SemanticChunksAsync</c>
+    /// and their <see cref="PdfParsedDocument"/> counterparts.
+    /// </summary>
+    /// <param name="name">Profile name. Built-in names (<c>Standard</c> … <c>Rag</c>) are rejected.</param>
+    /// <param name="profile">The profile definition, validated before the FFI call.</param>
+    /// <returns>The profile id. Re-registering a name replaces its definition and keeps its id.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="name"/> or <paramref name="profile"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="name"/> is empty or a section fails validation.</exception>
+    /// <exception cref="PdfExtractionException">If the name is a built-in, or all 128 custom ids are taken.</exception>
+    public static ExtractionProfile RegisterProfile(string name, CustomExtractionProfile profile)
+    {
+        ArgumentException.ThrowIfNullOrEmpty(name);
+        ArgumentNullException.ThrowIfNull(profile);
+        profile.Validate();
+
+        var result = NativeMethods.oxidize_register_profile(name, profile.ToJson(), out var id);
+        ThrowIfError(result, $"Failed to register profile \"{name}\"");
+        return (ExtractionProfile)id;
+    }
+
+    /// <summary>
+    /// Look up a built-in or registered custom profile by name.
+    /// </summary>
+    /// <param name="name">Profile name, e.g. <c>Rag</c> or a name passed to <see cref="RegisterProfile"/>.</param>
+    /// <returns>The profile id.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="name"/> is null.</exception>
+    /// <exception cref="PdfExtractionException">If no profile has that name.</exception>
+    public static ExtractionProfile GetProfile(string name)
+    {
+        ArgumentNullException.ThrowIfNull(name);
+
+        var result = NativeMethods.oxidize_profile_id(name, out var id);
+        ThrowIfError(result, $"Failed to look up profile \"{name}\"");
+        return (ExtractionProfile)id;
+    }
+
+    /// <summary>
+    /// Process-wide strict parsing. While <c>true</c>, every parse in the
+    /// process fails on a damaged file with a <see cref="PdfExtractionException"/>
+    /// instead of repairing it. Defaults to <c>false</c>.
+    /// </summary>
+    /// <exception cref="PdfExtractionException">If the native call fails.</exception>
+    public static bool StrictParsing
+    {
+        get
+        {
+            var result = NativeMethods.oxidize_get_strict_parsing(out var strict);
+            ThrowIfError(result, "Failed to get strict parsing");
+            return strict;
+        }
+        set
+        {
+            var result = NativeMethods.oxidize_set_strict_parsing((byte)(value ? 1 : 0));
+            ThrowIfError(result, "Failed to set strict parsing");
+        }
+    }
+
+    /// <summary>
+    /// Set the process-wide resource limits checked whenever the native library
+    /// opens a PDF. They apply to every <see cref="PdfExtractor"/>,
+    /// <see cref="PdfParsedDocument"/> and <see cref="PdfOperations"/> call in
+    /// the process, on all threads.
+    /// </summary>
+    /// <param name="limits">The limits to apply, or <c>null</c> to remove every limit.</param>
+    /// <exception cref="ArgumentOutOfRangeException">If a limit is negative.</exception>
+    /// <exception cref="PdfExtractionException">If the native library rejects the limits.</exception>
+    public static void SetResourceLimits(PdfResourceLimits? limits)
+    {
+        limits?.Validate();
+
+        var json = limits is null ? null : JsonSerializer.Serialize(limits);
+        var result = NativeMethods.oxidize_set_resource_limits(json);
+        ThrowIfError(result, "Failed to set resource limits");
+    }
+
+    /// <summary>
+    /// Get the process-wide resource limits currently in force. Unset limits
+    /// are <c>null</c>.
+    /// </summary>
+    /// <returns>A snapshot of the current limits.</returns>
+    /// <exception cref="PdfExtractionException">If the native call fails.</exception>
+    public static PdfResourceLimits GetResourceLimits()
+    {
+        IntPtr jsonPtr = IntPtr.Zero;
+        try
+        {
+            var result = NativeMethods.oxidize_get_resource_limits(out jsonPtr);
+            ThrowIfError(result, "Failed to get resource limits");
+
+            var json = Marshal.PtrToStringUTF8(jsonPtr) ?? "{}";
+            return JsonSerializer.Deserialize<PdfResourceLimits>(json) ?? new PdfResourceLimits();
+        }
+        finally
+        {
+            if (jsonPtr != IntPtr.Zero)
+                NativeMethods.oxidize_free_string(jsonPtr);
+        }
+    }
+
+    private static readonly object LogLock = new();
+    private static readonly NativeMethods.LogCallback NativeLogThunk = OnNativeLog;
+    private static volatile Action<PdfLogRecord>? _logHandler;
+
+    /// <summary>
+    /// Register a process-wide handler for native diagnostics: the repairs
+    /// lenient parsing makes on damaged files, caught panics and similar
+    /// decisions. Records from <see cref="PdfLogLevel.Error"/> up to
+    /// <paramref name="maxLevel"/> are delivered. Replaces any previous
+    /// handler; pass <c>null</c> to stop delivery.
+    /// </summary>
+    /// <remarks>
+    /// The handler may run on any thread, including several at once, and must
+    /// be thread-safe. An exception it throws is discarded.
+    /// </remarks>
+    /// <param name="handler">The handler, or <c>null</c> to clear it.</param>
+    /// <param name="maxLevel">Most verbose level delivered; ignored when clearing.</param>
+    /// <exception cref="ArgumentOutOfRangeException">If <paramref name="maxLevel"/> is not a defined level.</exception>
+    /// <exception cref="PdfExtractionException">If the native library rejects the registration.</exception>
+    public static void SetLogHandler(Action<PdfLogRecord>? handler, PdfLogLevel maxLevel = PdfLogLevel.Warning)
+    {
+        if (handler is not null && !Enum.IsDefined(maxLevel))
+            throw new ArgumentOutOfRangeException(nameof(maxLevel), maxLevel, "Unknown log level");
+
+        lock (LogLock)
+        {
+            // The static delegate stays registered for the process lifetime,
+            // so only the managed handler it forwards to is swapped.
+            var result = NativeMethods.oxidize_set_log_callback(
+                handler is null ? null : NativeLogThunk, IntPtr.Zero, (int)maxLevel);
+            ThrowIfError(result, "Failed to set log handler");
+            _logHandler = handler;
+        }
+    }
+
+    private static void OnNativeLog(IntPtr userData, int level, IntPtr target, IntPtr message)
+    {
+        var handler = _logHandler;
+        if (handler is null)
+            return;
+
+        // Exceptions must not unwind through native frames.
+        try
+        {
+            handler(new PdfLogRecord(
+                (PdfLogLevel)level,
+                Marshal.PtrToStringUTF8(target) ?? string.Empty,
+                Marshal.PtrToStringUTF8(message) ?? string.Empty));
+        }
+        catch
+        {
+        }
+    }
+
     /// <summary>
     /// Extract plain text from PDF bytes
     /// </summary>
@@ -319,6 +501,43 @@ public class PdfExtractor
             cancellationToken);
     }
 
+    /// <summary>
+    /// Extract semantic chunks using a built-in <see cref="ExtractionProfile"/> or
+    /// one returned by <see cref="RegisterProfile"/>. A custom profile's semantic
+    /// section supplies the chunk config; built-ins use
+    /// <c>SemanticChunkConfig::default()</c>.
+    /// </summary>
+    /// <param name="pdfBytes">PDF file content as byte array.</param>
+    /// <param name="profile">Extraction profile selecting partitioner and chunker defaults.</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>List of semantic chunks for the chosen profile.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="pdfBytes"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="pdfBytes"/> is empty or exceeds the configured maximum size.</exception>
+    /// <exception cref="PdfExtractionException">If chunking fails or the profile id is unknown to the FFI.</exception>
+    /// <exception cref="OperationCanceledException">If the operation is cancelled.</exception>
+    public Task<List<SemanticChunk>> SemanticChunksAsync(
+        byte[] pdfBytes,
+        ExtractionProfile profile,
+        CancellationToken cancellationToken = default)
+    {
+        cancellationToken.ThrowIfCancellationRequested();
+
+        ArgumentNullException.ThrowIfNull(pdfBytes);
+        if (pdfBytes.Length == 0)
+            throw new ArgumentException("PDF bytes cannot be empty", nameof(pdfBytes));
+        ValidatePdfSize(pdfBytes);
+
+        cancellationToken.ThrowIfCancellationRequested();
+
+        return Task.Run(
+            () => CallNativeJsonWithProfile<List<SemanticChunk>>(
+                pdfBytes,
+                (byte)profile,
+                NativeMethods.oxidize_semantic_chunks_with_profile,
+                $"Failed to extract semantic chunks with profile {profile}"),
+            cancellationToken);
+    }
+
     /// <summary>
     /// Export PDF content as Markdown.
     /// </summary>
@@ -542,6 +761,84 @@ public class PdfExtractor
         return Task.Run(() => ExtractTextFromPage(pdfBytes, pageNumber), cancellationToken);
     }
 
+    /// <summary>
+    /// Extract the words of a page with their bounding box, font, fill colour,
+    /// rendering mode and baseline rotation. Words are runs of glyphs on one
+    /// baseline, split at whitespace and wide gaps, in content-stream order.
+    /// </summary>
+    /// <param name="pdfBytes">PDF file content as byte array.</param>
+    /// <param name="pageNumber">Page number (1-based).</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>The words of the page.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="pdfBytes"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="pdfBytes"/> is empty or exceeds the configured maximum size.</exception>
+    /// <exception cref="ArgumentOutOfRangeException">If <paramref name="pageNumber"/> is less than 1.</exception>
+    /// <exception cref="OperationCanceledException">If the operation is cancelled.</exception>
+    /// <exception cref="PdfExtractionException">If the page does not exist or uses an unsupported composite font encoding.</exception>
+    public Task<List<PdfTextItem>> ExtractWordsAsync(
+        byte[] pdfBytes, int pageNumber, CancellationToken cancellationToken = default) =>
+        ExtractTextItemsAsync(pdfBytes, pageNumber, glyphs: false, cancellationToken);
+
+    /// <summary>
+    /// Extract every glyph of a page, whitespace included, with the same
+    /// geometry and style as <c>ExtractWordsAsync</c>.
+    /// </summary>
+    /// <param name="pdfBytes">PDF file content as byte array.</param>
+    /// <param name="pageNumber">Page number (1-based).</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>The glyphs of the page.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="pdfBytes"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="pdfBytes"/> is empty or exceeds the configured maximum size.</exception>
+    /// <exception cref="ArgumentOutOfRangeException">If <paramref name="pageNumber"/> is less than 1.</exception>
+    /// <exception cref="OperationCanceledException">If the operation is cancelled.</exception>
+    /// <exception cref="PdfExtractionException">If the page does not exist or uses an unsupported composite font encoding.</exception>
+    public Task<List<PdfTextItem>> ExtractGlyphsAsync(
+        byte[] pdfBytes, int pageNumber, CancellationToken cancellationToken = default) =>
+        ExtractTextItemsAsync(pdfBytes, pageNumber, glyphs: true, cancellationToken);
+
+    private Task<List<PdfTextItem>> ExtractTextItemsAsync(
+        byte[] pdfBytes, int pageNumber, bool glyphs, CancellationToken cancellationToken)
+    {
+        cancellationToken.ThrowIfCancellationRequested();
+        ValidateInput(pdfBytes);
+        if (pageNumber < 1)
+            throw new ArgumentOutOfRangeException(nameof(pageNumber), "Page number must be >= 1 (1-based indexing)");
+
+        return Task.Run(() => CallNativeJson<List<PdfTextItem>>(
+            pdfBytes,
+            (IntPtr ptr, nuint len, out IntPtr json) => NativeMethods.oxidize_extract_words(
+                ptr, len, (nuint)pageNumber, (byte)(glyphs ? 1 : 0), out json),
+            $"Failed to extract {(glyphs ? "glyphs" : "words")} from page {pageNumber}"), cancellationToken);
+    }
+
+    /// <summary>
+    /// Extract the tables the partitioner detects, each as a grid of cells with
+    /// text, bounding boxes, row/column spans and detected header rows.
+    /// </summary>
+    /// <param name="pdfBytes">PDF file content as byte array.</param>
+    /// <param name="config">Partition configuration controlling table detection;
+    /// <c>null</c> uses the upstream default.</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>The detected tables in page order.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="pdfBytes"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="pdfBytes"/> is empty, exceeds the configured maximum size, or <paramref name="config"/> fails validation.</exception>
+    /// <exception cref="OperationCanceledException">If the operation is cancelled.</exception>
+    /// <exception cref="PdfExtractionException">If extraction fails.</exception>
+    public Task<List<PdfExtractedTable>> ExtractTablesAsync(
+        byte[] pdfBytes, PartitionConfig? config = null, CancellationToken cancellationToken = default)
+    {
+        cancellationToken.ThrowIfCancellationRequested();
+        ValidateInput(pdfBytes);
+        config?.Validate();
+        var configJson = config?.ToJson();
+
+        return Task.Run(() => CallNativeJson<List<PdfExtractedTable>>(
+            pdfBytes,
+            (IntPtr ptr, nuint len, out IntPtr json) =>
+                NativeMethods.oxidize_extract_tables(ptr, len, configJson, out json),
+            "Failed to extract tables"), cancellationToken);
+    }
+
     /// <summary>
     /// Extract text chunks from a specific page of a PDF
     /// </summary>
@@ -675,6 +972,101 @@ public class PdfExtractor
         return Task.Run(() => ExtractMetadata(pdfBytes), cancellationToken);
     }
 
+    /// <summary>
+    /// Read the document outline (bookmarks) of an existing PDF: titles,
+    /// nesting, resolved targets, action subtypes, styles and open state.
+    /// Named destinations are resolved; cyclic or over-deep outlines are cut off.
+    /// </summary>
+    /// <param name="pdfBytes">PDF file content as byte array.</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>The top-level outline items; empty when the document has no outline.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="pdfBytes"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="pdfBytes"/> is empty or exceeds the configured maximum size.</exception>
+    /// <exception cref="OperationCanceledException">If the operation is cancelled.</exception>
+    /// <exception cref="PdfExtractionException">If the PDF cannot be parsed.</exception>
+    public Task<List<PdfOutlineEntry>> GetOutlineAsync(byte[] pdfBytes, CancellationToken cancellationToken = default)
+    {
+        cancellationToken.ThrowIfCancellationRequested();
+        ValidateInput(pdfBytes);
+
+        return Task.Run(() => CallNativeJson<PdfOutlineResult>(
+            pdfBytes, NativeMethods.oxidize_get_outline, "Failed to read outline").Items, cancellationToken);
+    }
+
+    /// <summary>
+    /// Read the catalog's open action, viewer preferences, named destinations
+    /// and page labels of an existing PDF.
+    /// </summary>
+    /// <param name="pdfBytes">PDF file content as byte array.</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>The navigation metadata; absent entries are <c>null</c> or empty.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="pdfBytes"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="pdfBytes"/> is empty or exceeds the configured maximum size.</exception>
+    /// <exception cref="OperationCanceledException">If the operation is cancelled.</exception>
+    /// <exception cref="PdfExtractionException">If the PDF cannot be parsed.</exception>
+    public Task<PdfNavigationMetadata> GetNavigationMetadataAsync(
+        byte[] pdfBytes, CancellationToken cancellationToken = default)
+    {
+        cancellationToken.ThrowIfCancellationRequested();
+        ValidateInput(pdfBytes);
+
+        return Task.Run(() => CallNativeJson<PdfNavigationMetadata>(
+            pdfBytes, NativeMethods.oxidize_get_navigation_metadata, "Failed to read navigation metadata"),
+            cancellationToken);
+    }
+
+    /// <summary>
+    /// Read the logical structure tree of a tagged PDF: element types, nesting,
+    /// IDs, language, alternate and actual text, titles, marked-content
+    /// references and the role map.
+    /// </summary>
+    /// <param name="pdfBytes">PDF file content as byte array.</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>The structure tree; it has no elements when the document is untagged.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="pdfBytes"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="pdfBytes"/> is empty or exceeds the configured maximum size.</exception>
+    /// <exception cref="OperationCanceledException">If the operation is cancelled.</exception>
+    /// <exception cref="PdfExtractionException">If the PDF cannot be parsed.</exception>
+    public Task<PdfStructureTreeInfo> GetStructureTreeAsync(
+        byte[] pdfBytes, CancellationToken cancellationToken = default)
+    {
+        cancellationToken.ThrowIfCancellationRequested();
+        ValidateInput(pdfBytes);
+
+        return Task.Run(() => CallNativeJson<PdfStructureTreeInfo>(
+            pdfBytes, NativeMethods.oxidize_get_struct_tree, "Failed to read structure tree"),
+            cancellationToken);
+    }
+
+    /// <summary>
+    /// Parse a PDF and report every repair lenient parsing performed (rebuilt
+    /// cross-reference table, skipped objects, broken streams, bad fonts, …).
+    /// </summary>
+    /// <param name="pdfBytes">PDF file content as byte array.</param>
+    /// <param name="strict">
+    /// When <c>true</c>, the first repair fails the call instead of being
+    /// reported. <see cref="StrictParsing"/> has the same effect.
+    /// </param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>A <see cref="PdfRecoveryReport"/>; <see cref="PdfRecoveryReport.Repaired"/> is <c>false</c> for a clean file.</returns>
+    /// <exception cref="ArgumentNullException">If pdfBytes is null.</exception>
+    /// <exception cref="ArgumentException">If pdfBytes is empty or exceeds maximum size.</exception>
+    /// <exception cref="PdfExtractionException">If the PDF cannot be parsed, or in strict mode needs a repair.</exception>
+    public Task<PdfRecoveryReport> GetRecoveryReportAsync(
+        byte[] pdfBytes,
+        bool strict = false,
+        CancellationToken cancellationToken = default)
+    {
+        cancellationToken.ThrowIfCancellationRequested();
+
+        ArgumentNullException.ThrowIfNull(pdfBytes);
+        if (pdfBytes.Length == 0)
+            throw new ArgumentException("PDF bytes cannot be empty", nameof(pdfBytes));
+        ValidatePdfSize(pdfBytes);
+
+        return Task.Run(() => GetRecoveryReport(pdfBytes, strict), cancellationToken);
+    }
+
     /// <summary>
     /// Check whether a PDF document contains any digital signature fields.
     /// </summary>
@@ -922,46 +1314,786 @@ public class PdfExtractor
         return Task.Run(() => GetPageDimensions(pdfBytes, pageNumber), cancellationToken);
     }
 
-    private void ValidatePdfSize(byte[] pdfBytes)
+    /// <summary>
+    /// Extract plain text from PDF bytes, reporting progress after each page.
+    /// Unlike the other overloads, <paramref name="cancellationToken"/> is
+    /// also polled between pages, so a long extraction stops mid-document.
+    /// </summary>
+    /// <param name="pdfBytes">PDF file content as byte array.</param>
+    /// <param name="progress">Receives the completed and total page counts. May be null to only honour cancellation.</param>
+    /// <param name="cancellationToken">Cancellation token, checked between pages.</param>
+    /// <returns>Extracted plain text, identical to <see cref="ExtractTextAsync(byte[], CancellationToken)"/>.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="pdfBytes"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="pdfBytes"/> is empty or exceeds maximum size.</exception>
+    /// <exception cref="OperationCanceledException">If the operation is cancelled.</exception>
+    /// <exception cref="PdfExtractionException">If extraction fails.</exception>
+    public Task<string> ExtractTextAsync(
+        byte[] pdfBytes,
+        IProgress<PdfProgress>? progress,
+        CancellationToken cancellationToken = default)
     {
-        if (pdfBytes.LongLength > _maxFileSizeBytes)
-        {
-            throw new ArgumentException(
-                $"PDF size ({pdfBytes.LongLength:N0} bytes) exceeds maximum allowed size ({_maxFileSizeBytes:N0} bytes). " +
-                "Consider using a smaller file or increasing the maxFileSizeBytes limit.",
-                nameof(pdfBytes));
-        }
-    }
-
-    // ── FFI helpers ──────────────────────────────────────────────────────────
+        cancellationToken.ThrowIfCancellationRequested();
 
-    private delegate int NativeJsonCall(IntPtr pdfBytes, nuint pdfLen, out IntPtr outJson);
-    private delegate int NativeJsonCallWithProfile(IntPtr pdfBytes, nuint pdfLen, byte profile, out IntPtr outJson);
-    private delegate int NativeJsonCallWithConfig(IntPtr pdfBytes, nuint pdfLen, string configJson, out IntPtr outJson);
-    private delegate int NativeStringCall(IntPtr pdfBytes, nuint pdfLen, out IntPtr outText);
+        ArgumentNullException.ThrowIfNull(pdfBytes);
+        if (pdfBytes.Length == 0)
+            throw new ArgumentException("PDF bytes cannot be empty", nameof(pdfBytes));
+        ValidatePdfSize(pdfBytes);
 
-    private static T WithPinnedPdf<T>(byte[] pdfBytes, Func<IntPtr, nuint, T> action)
-    {
-        IntPtr pdfPtr = IntPtr.Zero;
-        try
-        {
-            pdfPtr = Marshal.AllocHGlobal(pdfBytes.Length);
-            Marshal.Copy(pdfBytes, 0, pdfPtr, pdfBytes.Length);
-            return action(pdfPtr, (nuint)pdfBytes.Length);
-        }
-        finally
-        {
-            if (pdfPtr != IntPtr.Zero)
-                Marshal.FreeHGlobal(pdfPtr);
-        }
+        var nativeProgress = new NativeProgress(progress, cancellationToken);
+        return Task.Run(() => ExtractTextWithProgress(pdfBytes, nativeProgress), cancellationToken);
     }
 
-    private static T CallNativeJson<T>(byte[] pdfBytes, NativeJsonCall nativeCall, string errorMsg) where T : class, new()
+    /// <summary>
+    /// Partition a PDF into typed semantic elements, reporting progress after
+    /// each page and polling <paramref name="cancellationToken"/> between pages.
+    /// </summary>
+    /// <param name="pdfBytes">PDF file content as byte array.</param>
+    /// <param name="config">Optional partition configuration. <c>null</c> uses <c>PartitionConfig::default()</c>.</param>
+    /// <param name="progress">Receives the completed and total page counts. May be null to only honour cancellation.</param>
+    /// <param name="cancellationToken">Cancellation token, checked between pages.</param>
+    /// <returns>List of semantic elements, identical to the overloads without progress.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="pdfBytes"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="pdfBytes"/> is empty/oversize, or a non-null <paramref name="config"/> fails validation.</exception>
+    /// <exception cref="OperationCanceledException">If the operation is cancelled.</exception>
+    /// <exception cref="PdfExtractionException">If partitioning fails.</exception>
+    public Task<List<PdfElement>> PartitionAsync(
+        byte[] pdfBytes,
+        PartitionConfig? config,
+        IProgress<PdfProgress>? progress,
+        CancellationToken cancellationToken = default)
     {
-        return WithPinnedPdf(pdfBytes, (ptr, len) =>
-        {
-            IntPtr jsonPtr = IntPtr.Zero;
-            try
+        cancellationToken.ThrowIfCancellationRequested();
+
+        ArgumentNullException.ThrowIfNull(pdfBytes);
+        if (pdfBytes.Length == 0)
+            throw new ArgumentException("PDF bytes cannot be empty", nameof(pdfBytes));
+        ValidatePdfSize(pdfBytes);
+        config?.Validate();
+
+        var configJson = config?.ToJson();
+        var nativeProgress = new NativeProgress(progress, cancellationToken);
+        return Task.Run(
+            () => CallNativeJsonWithProgress<List<PdfElement>>(
+                pdfBytes,
+                nativeProgress,
+                (IntPtr ptr, nuint len, ref NativeMethods.ProgressCallbacksNative callbacks, out IntPtr json) =>
+                    NativeMethods.oxidize_partition_with_progress(ptr, len, configJson, ref callbacks, out json),
+                "Failed to partition PDF"),
+            cancellationToken);
+    }
+
+    /// <summary>
+    /// Extract structure-aware RAG chunks with optional configs, reporting
+    /// progress after each page and polling <paramref name="cancellationToken"/>
+    /// between pages.
+    /// </summary>
+    /// <param name="pdfBytes">PDF file content as byte array.</param>
+    /// <param name="partitionConfig">Optional partition configuration. <c>null</c> uses <c>PartitionConfig::default()</c>.</param>
+    /// <param name="hybridConfig">Optional hybrid-chunker configuration. <c>null</c> uses <c>HybridChunkConfig::default()</c>.</param>
+    /// <param name="progress">Receives the completed and total page counts. May be null to only honour cancellation.</param>
+    /// <param name="cancellationToken">Cancellation token, checked between pages.</param>
+    /// <returns>List of RAG-ready chunks, identical to the overloads without progress.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="pdfBytes"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="pdfBytes"/> is empty/oversize, or either non-null config fails validation.</exception>
+    /// <exception cref="OperationCanceledException">If the operation is cancelled.</exception>
+    /// <exception cref="PdfExtractionException">If chunking fails.</exception>
+    public Task<List<RagChunk>> RagChunksAsync(
+        byte[] pdfBytes,
+        PartitionConfig? partitionConfig,
+        HybridChunkConfig? hybridConfig,
+        IProgress<PdfProgress>? progress,
+        CancellationToken cancellationToken = default)
+    {
+        cancellationToken.ThrowIfCancellationRequested();
+
+        ArgumentNullException.ThrowIfNull(pdfBytes);
+        if (pdfBytes.Length == 0)
+            throw new ArgumentException("PDF bytes cannot be empty", nameof(pdfBytes));
+        ValidatePdfSize(pdfBytes);
+        partitionConfig?.Validate();
+        hybridConfig?.Validate();
+
+        var partitionJson = partitionConfig?.ToJson();
+        var hybridJson = hybridConfig?.ToJson();
+        var nativeProgress = new NativeProgress(progress, cancellationToken);
+        return Task.Run(
+            () => CallNativeJsonWithProgress<List<RagChunk>>(
+                pdfBytes,
+                nativeProgress,
+                (IntPtr ptr, nuint len, ref NativeMethods.ProgressCallbacksNative callbacks, out IntPtr json) =>
+                    NativeMethods.oxidize_rag_chunks_with_progress(
+                        ptr, len, partitionJson, hybridJson, ref callbacks, out json),
+                "Failed to extract RAG chunks"),
+            cancellationToken);
+    }
+
+    /// <summary>
+    /// Extract semantic chunks, reporting progress after each page and polling
+    /// <paramref name="cancellationToken"/> between pages.
+    /// </summary>
+    /// <param name="pdfBytes">PDF file content as byte array.</param>
+    /// <param name="config">Semantic-chunker configuration. <c>null</c> uses <c>SemanticChunkConfig::default()</c>.</param>
+    /// <param name="partitionConfig">Optional partition configuration. <c>null</c> uses <c>PartitionConfig::default()</c>.</param>
+    /// <param name="progress">Receives the completed and total page counts. May be null to only honour cancellation.</param>
+    /// <param name="cancellationToken">Cancellation token, checked between pages.</param>
+    /// <returns>List of semantic chunks, identical to the overload without progress.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="pdfBytes"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="pdfBytes"/> is empty/oversize, or either non-null config fails validation.</exception>
+    /// <exception cref="OperationCanceledException">If the operation is cancelled.</exception>
+    /// <exception cref="PdfExtractionException">If chunking fails.</exception>
+    public Task<List<SemanticChunk>> SemanticChunksAsync(
+        byte[] pdfBytes,
+        SemanticChunkConfig? config,
+        PartitionConfig? partitionConfig,
+        IProgress<PdfProgress>? progress,
+        CancellationToken cancellationToken = default)
+    {
+        cancellationToken.ThrowIfCancellationRequested();
+
+        ArgumentNullException.ThrowIfNull(pdfBytes);
+        if (pdfBytes.Length == 0)
+            throw new ArgumentException("PDF bytes cannot be empty", nameof(pdfBytes));
+        ValidatePdfSize(pdfBytes);
+        config ??= new SemanticChunkConfig();
+        config.Validate();
+        partitionConfig?.Validate();
+
+        var partitionJson = partitionConfig?.ToJson();
+        var semanticJson = config.ToJson();
+        var nativeProgress = new NativeProgress(progress, cancellationToken);
+        return Task.Run(
+            () => CallNativeJsonWithProgress<List<SemanticChunk>>(
+                pdfBytes,
+                nativeProgress,
+                (IntPtr ptr, nuint len, ref NativeMethods.ProgressCallbacksNative callbacks, out IntPtr json) =>
+                    NativeMethods.oxidize_semantic_chunks_with_progress(
+                        ptr, len, partitionJson, semanticJson, ref callbacks, out json),
+                "Failed to extract semantic chunks"),
+            cancellationToken);
+    }
+
+    /// <summary>
+    /// Extract plain text from an encrypted PDF, unlocking it with
+    /// <paramref name="password"/>. Unencrypted input ignores the password.
+    /// </summary>
+    /// <param name="pdfBytes">PDF file content as byte array.</param>
+    /// <param name="password">The user or owner password.</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>Extracted plain text.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="pdfBytes"/> or <paramref name="password"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="pdfBytes"/> is empty or exceeds maximum size.</exception>
+    /// <exception cref="PdfExtractionException">If extraction fails or the password is wrong.</exception>
+    public Task<string> ExtractTextAsync(byte[] pdfBytes, string password, CancellationToken cancellationToken = default)
+    {
+        cancellationToken.ThrowIfCancellationRequested();
+        ValidatePasswordInput(pdfBytes, password);
+
+        return Task.Run(() => CallNativeString(
+            pdfBytes,
+            (IntPtr ptr, nuint len, out IntPtr text) =>
+                NativeMethods.oxidize_extract_text_with_password(ptr, len, password, out text),
+            "Failed to extract text from PDF"), cancellationToken);
+    }
+
+    /// <summary>
+    /// Extract plain text from one page of an encrypted PDF, unlocking it
+    /// with <paramref name="password"/>.
+    /// </summary>
+    /// <param name="pdfBytes">PDF file content as byte array.</param>
+    /// <param name="password">The user or owner password.</param>
+    /// <param name="pageNumber">Page number (1-based).</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>Extracted plain text of the page.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="pdfBytes"/> or <paramref name="password"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="pdfBytes"/> is empty or exceeds maximum size.</exception>
+    /// <exception cref="ArgumentOutOfRangeException">If <paramref name="pageNumber"/> is less than 1.</exception>
+    /// <exception cref="PdfExtractionException">If extraction fails or the password is wrong.</exception>
+    public Task<string> ExtractTextFromPageAsync(
+        byte[] pdfBytes,
+        string password,
+        int pageNumber,
+        CancellationToken cancellationToken = default)
+    {
+        cancellationToken.ThrowIfCancellationRequested();
+        ValidatePasswordInput(pdfBytes, password);
+        if (pageNumber < 1)
+            throw new ArgumentOutOfRangeException(nameof(pageNumber), "Page number must be >= 1 (1-based indexing)");
+
+        return Task.Run(() => CallNativeString(
+            pdfBytes,
+            (IntPtr ptr, nuint len, out IntPtr text) =>
+                NativeMethods.oxidize_extract_text_from_page_with_password(ptr, len, password, (nuint)pageNumber, out text),
+            $"Failed to extract text from page {pageNumber}"), cancellationToken);
+    }
+
+    /// <summary>
+    /// Get the page count of an encrypted PDF, unlocking it with <paramref name="password"/>.
+    /// </summary>
+    /// <param name="pdfBytes">PDF file content as byte array.</param>
+    /// <param name="password">The user or owner password.</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>Number of pages.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="pdfBytes"/> or <paramref name="password"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="pdfBytes"/> is empty or exceeds maximum size.</exception>
+    /// <exception cref="PdfExtractionException">If parsing fails or the password is wrong.</exception>
+    public Task<int> GetPageCountAsync(byte[] pdfBytes, string password, CancellationToken cancellationToken = default)
+    {
+        cancellationToken.ThrowIfCancellationRequested();
+        ValidatePasswordInput(pdfBytes, password);
+
+        return Task.Run(() => WithPinnedPdf(pdfBytes, (ptr, len) =>
+        {
+            var result = NativeMethods.oxidize_get_page_count_with_password(ptr, len, password, out var pageCount);
+            ThrowIfError(result, "Failed to get page count from PDF");
+            return (int)pageCount;
+        }), cancellationToken);
+    }
+
+    /// <summary>
+    /// Extract document metadata from an encrypted PDF, unlocking it with
+    /// <paramref name="password"/>.
+    /// </summary>
+    /// <param name="pdfBytes">PDF file content as byte array.</param>
+    /// <param name="password">The user or owner password.</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>A <see cref="PdfMetadata"/> instance with the extracted metadata.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="pdfBytes"/> or <paramref name="password"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="pdfBytes"/> is empty or exceeds maximum size.</exception>
+    /// <exception cref="PdfExtractionException">If extraction fails or the password is wrong.</exception>
+    public Task<PdfMetadata> ExtractMetadataAsync(byte[] pdfBytes, string password, CancellationToken cancellationToken = default)
+    {
+        cancellationToken.ThrowIfCancellationRequested();
+        ValidatePasswordInput(pdfBytes, password);
+
+        return Task.Run(() => CallNativeJson<PdfMetadata>(
+            pdfBytes,
+            (IntPtr ptr, nuint len, out IntPtr json) =>
+                NativeMethods.oxidize_get_metadata_with_password(ptr, len, password, out json),
+            "Failed to extract metadata from PDF"), cancellationToken);
+    }
+
+    /// <summary>
+    /// Get the AcroForm fields of an encrypted PDF, unlocking it with
+    /// <paramref name="password"/>.
+    /// </summary>
+    /// <param name="pdfBytes">PDF file content as byte array.</param>
+    /// <param name="password">The user or owner password.</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>The form fields; empty when the document has none.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="pdfBytes"/> or <paramref name="password"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="pdfBytes"/> is empty or exceeds maximum size.</exception>
+    /// <exception cref="PdfExtractionException">If extraction fails or the password is wrong.</exception>
+    public Task<List<FormField>> GetFormFieldsAsync(byte[] pdfBytes, string password, CancellationToken cancellationToken = default)
+    {
+        cancellationToken.ThrowIfCancellationRequested();
+        ValidatePasswordInput(pdfBytes, password);
+
+        return Task.Run(() => CallNativeJson<List<FormField>>(
+            pdfBytes,
+            (IntPtr ptr, nuint len, out IntPtr json) =>
+                NativeMethods.oxidize_get_form_fields_with_password(ptr, len, password, out json),
+            "Failed to get form fields"), cancellationToken);
+    }
+
+    /// <summary>
+    /// Partition an encrypted PDF into typed semantic elements, unlocking it
+    /// with <paramref name="password"/>.
+    /// </summary>
+    /// <param name="pdfBytes">PDF file content as byte array.</param>
+    /// <param name="password">The user or owner password.</param>
+    /// <param name="config">Optional partition configuration. <c>null</c> uses <c>PartitionConfig::default()</c>.</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>List of semantic elements.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="pdfBytes"/> or <paramref name="password"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="pdfBytes"/> is empty/oversize, or a non-null <paramref name="config"/> fails validation.</exception>
+    /// <exception cref="PdfExtractionException">If partitioning fails or the password is wrong.</exception>
+    public Task<List<PdfElement>> PartitionAsync(
+        byte[] pdfBytes,
+        string password,
+        PartitionConfig? config,
+        CancellationToken cancellationToken = default)
+    {
+        cancellationToken.ThrowIfCancellationRequested();
+        ValidatePasswordInput(pdfBytes, password);
+        config?.Validate();
+
+        var configJson = config?.ToJson();
+        return Task.Run(() => CallNativeJson<List<PdfElement>>(
+            pdfBytes,
+            (IntPtr ptr, nuint len, out IntPtr json) => configJson is null
+                ? NativeMethods.oxidize_partition_with_password(ptr, len, password, out json)
+                : NativeMethods.oxidize_partition_with_config_with_password(ptr, len, password, configJson, out json),
+            "Failed to partition PDF"), cancellationToken);
+    }
+
+    /// <summary>
+    /// Extract structure-aware RAG chunks from an encrypted PDF, unlocking it
+    /// with <paramref name="password"/>.
+    /// </summary>
+    /// <param name="pdfBytes">PDF file content as byte array.</param>
+    /// <param name="password">The user or owner password.</param>
+    /// <param name="partitionConfig">Optional partition configuration. <c>null</c> uses <c>PartitionConfig::default()</c>.</param>
+    /// <param name="hybridConfig">Optional hybrid-chunker configuration. <c>null</c> uses <c>HybridChunkConfig::default()</c>.</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>List of RAG-ready chunks.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="pdfBytes"/> or <paramref name="password"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="pdfBytes"/> is empty/oversize, or either non-null config fails validation.</exception>
+    /// <exception cref="PdfExtractionException">If chunking fails or the password is wrong.</exception>
+    public Task<List<RagChunk>> RagChunksAsync(
+        byte[] pdfBytes,
+        string password,
+        PartitionConfig? partitionConfig,
+        HybridChunkConfig? hybridConfig,
+        CancellationToken cancellationToken = default)
+    {
+        cancellationToken.ThrowIfCancellationRequested();
+        ValidatePasswordInput(pdfBytes, password);
+        partitionConfig?.Validate();
+        hybridConfig?.Validate();
+
+        var partitionJson = partitionConfig?.ToJson();
+        var hybridJson = hybridConfig?.ToJson();
+        return Task.Run(() => CallNativeJson<List<RagChunk>>(
+            pdfBytes,
+            (IntPtr ptr, nuint len, out IntPtr json) =>
+                NativeMethods.oxidize_rag_chunks_with_config_with_password(
+                    ptr, len, password, partitionJson, hybridJson, out json),
+            "Failed to extract RAG chunks"), cancellationToken);
+    }
+
+    /// <summary>
+    /// Extract semantic chunks from an encrypted PDF, unlocking it with
+    /// <paramref name="password"/>.
+    /// </summary>
+    /// <param name="pdfBytes">PDF file content as byte array.</param>
+    /// <param name="password">The user or owner password.</param>
+    /// <param name="config">Semantic-chunker configuration. <c>null</c> uses <c>SemanticChunkConfig::default()</c>.</param>
+    /// <param name="partitionConfig">Optional partition configuration. <c>null</c> uses <c>PartitionConfig::default()</c>.</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>List of semantic chunks.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="pdfBytes"/> or <paramref name="password"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="pdfBytes"/> is empty/oversize, or either non-null config fails validation.</exception>
+    /// <exception cref="PdfExtractionException">If chunking fails or the password is wrong.</exception>
+    public Task<List<SemanticChunk>> SemanticChunksAsync(
+        byte[] pdfBytes,
+        string password,
+        SemanticChunkConfig? config,
+        PartitionConfig? partitionConfig,
+        CancellationToken cancellationToken = default)
+    {
+        cancellationToken.ThrowIfCancellationRequested();
+        ValidatePasswordInput(pdfBytes, password);
+        config ??= new SemanticChunkConfig();
+        config.Validate();
+        partitionConfig?.Validate();
+
+        var partitionJson = partitionConfig?.ToJson();
+        var semanticJson = config.ToJson();
+        return Task.Run(() => CallNativeJson<List<SemanticChunk>>(
+            pdfBytes,
+            (IntPtr ptr, nuint len, out IntPtr json) =>
+                NativeMethods.oxidize_semantic_chunks_with_password(
+                    ptr, len, password, partitionJson, semanticJson, out json),
+            "Failed to extract semantic chunks"), cancellationToken);
+    }
+
+    /// <summary>
+    /// Extract the text of all pages on a pool of native worker threads. The
+    /// result is identical to <c>ExtractTextAsync</c> for any thread count.
+    /// </summary>
+    /// <param name="pdfBytes">PDF file content as byte array.</param>
+    /// <param name="threads">Worker threads: 0 = one per CPU, 1 = sequential; larger values are clamped to the CPU count.</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>Extracted text content.</returns>
+    /// <exception cref="ArgumentNullException">If pdfBytes is null.</exception>
+    /// <exception cref="ArgumentException">If pdfBytes is empty or exceeds maximum size.</exception>
+    /// <exception cref="ArgumentOutOfRangeException">If <paramref name="threads"/> is negative.</exception>
+    /// <exception cref="PdfExtractionException">If extraction fails.</exception>
+    public Task<string> ExtractTextParallelAsync(
+        byte[] pdfBytes, int threads = 0, CancellationToken cancellationToken = default)
+    {
+        cancellationToken.ThrowIfCancellationRequested();
+        ValidateParallelInput(pdfBytes, threads);
+
+        return Task.Run(() => CallNativeString(
+            pdfBytes,
+            (IntPtr ptr, nuint len, out IntPtr text) =>
+                NativeMethods.oxidize_extract_text_parallel(ptr, len, (uint)threads, out text),
+            "Failed to extract text"), cancellationToken);
+    }
+
+    /// <summary>
+    /// Partition a PDF with the default configuration on a pool of native
+    /// worker threads. The elements are identical to <c>PartitionAsync</c>
+    /// for any thread count; documents with ruled tables are partitioned
+    /// sequentially.
+    /// </summary>
+    /// <param name="pdfBytes">PDF file content as byte array.</param>
+    /// <param name="threads">Worker threads: 0 = one per CPU, 1 = sequential; larger values are clamped to the CPU count.</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>List of semantic elements in page order.</returns>
+    /// <exception cref="ArgumentNullException">If pdfBytes is null.</exception>
+    /// <exception cref="ArgumentException">If pdfBytes is empty or exceeds maximum size.</exception>
+    /// <exception cref="ArgumentOutOfRangeException">If <paramref name="threads"/> is negative.</exception>
+    /// <exception cref="PdfExtractionException">If partitioning fails.</exception>
+    public Task<List<PdfElement>> PartitionParallelAsync(
+        byte[] pdfBytes, int threads = 0, CancellationToken cancellationToken = default)
+    {
+        cancellationToken.ThrowIfCancellationRequested();
+        ValidateParallelInput(pdfBytes, threads);
+
+        return Task.Run(() => CallNativeJson<List<PdfElement>>(
+            pdfBytes,
+            (IntPtr ptr, nuint len, out IntPtr json) =>
+                NativeMethods.oxidize_partition_parallel(ptr, len, (uint)threads, out json),
+            "Failed to partition PDF"), cancellationToken);
+    }
+
+    /// <summary>
+    /// Extract plain text and report how long each native phase took.
+    /// </summary>
+    /// <param name="pdfBytes">PDF file content as byte array.</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>The text, identical to <c>ExtractTextAsync</c>, and the call metrics.</returns>
+    /// <exception cref="ArgumentNullException">If pdfBytes is null.</exception>
+    /// <exception cref="ArgumentException">If pdfBytes is empty or exceeds maximum size.</exception>
+    /// <exception cref="PdfExtractionException">If extraction fails.</exception>
+    public Task<(string Text, PdfCallMetrics Metrics)> ExtractTextWithMetricsAsync(
+        byte[] pdfBytes, CancellationToken cancellationToken = default)
+    {
+        cancellationToken.ThrowIfCancellationRequested();
+        ValidateInput(pdfBytes);
+
+        return Task.Run(() => CallNativeWithMetrics(
+            pdfBytes, NativeMethods.oxidize_extract_text_with_metrics, "Failed to extract text"), cancellationToken);
+    }
+
+    /// <summary>
+    /// Partition a PDF with the default configuration and report how long
+    /// each native phase took.
+    /// </summary>
+    /// <param name="pdfBytes">PDF file content as byte array.</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>The elements, identical to <c>PartitionAsync</c>, and the call metrics.</returns>
+    /// <exception cref="ArgumentNullException">If pdfBytes is null.</exception>
+    /// <exception cref="ArgumentException">If pdfBytes is empty or exceeds maximum size.</exception>
+    /// <exception cref="PdfExtractionException">If partitioning fails.</exception>
+    public Task<(List<PdfElement> Elements, PdfCallMetrics Metrics)> PartitionWithMetricsAsync(
+        byte[] pdfBytes, CancellationToken cancellationToken = default)
+    {
+        cancellationToken.ThrowIfCancellationRequested();
+        ValidateInput(pdfBytes);
+
+        return Task.Run(() =>
+        {
+            var (json, metrics) = CallNativeWithMetrics(
+                pdfBytes, NativeMethods.oxidize_partition_with_metrics, "Failed to partition PDF");
+            return (JsonSerializer.Deserialize<List<PdfElement>>(json) ?? new List<PdfElement>(), metrics);
+        }, cancellationToken);
+    }
+
+    /// <summary>
+    /// Extract RAG chunks with the default configuration and report how long
+    /// each native phase took.
+    /// </summary>
+    /// <param name="pdfBytes">PDF file content as byte array.</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>The chunks, identical to <c>RagChunksAsync</c>, and the call metrics.</returns>
+    /// <exception cref="ArgumentNullException">If pdfBytes is null.</exception>
+    /// <exception cref="ArgumentException">If pdfBytes is empty or exceeds maximum size.</exception>
+    /// <exception cref="PdfExtractionException">If chunking fails.</exception>
+    public Task<(List<RagChunk> Chunks, PdfCallMetrics Metrics)> RagChunksWithMetricsAsync(
+        byte[] pdfBytes, CancellationToken cancellationToken = default)
+    {
+        cancellationToken.ThrowIfCancellationRequested();
+        ValidateInput(pdfBytes);
+
+        return Task.Run(() =>
+        {
+            var (json, metrics) = CallNativeWithMetrics(
+                pdfBytes, NativeMethods.oxidize_rag_chunks_with_metrics, "Failed to extract RAG chunks");
+            return (JsonSerializer.Deserialize<List<RagChunk>>(json) ?? new List<RagChunk>(), metrics);
+        }, cancellationToken);
+    }
+
+    /// <summary>
+    /// Partition a PDF with the default configuration and return the elements
+    /// serialized in <paramref name="encoding"/>, for callers that forward or
+    /// store the records rather than read them.
+    /// </summary>
+    /// <param name="pdfBytes">PDF file content as byte array.</param>
+    /// <param name="encoding">JSON or CBOR.</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>The encoded element list; JSON matches <c>PartitionAsync</c>.</returns>
+    /// <exception cref="ArgumentNullException">If pdfBytes is null.</exception>
+    /// <exception cref="ArgumentException">If pdfBytes is empty or exceeds maximum size.</exception>
+    /// <exception cref="ArgumentOutOfRangeException">If <paramref name="encoding"/> is not a defined value.</exception>
+    /// <exception cref="PdfExtractionException">If partitioning fails.</exception>
+    public Task<byte[]> PartitionEncodedAsync(
+        byte[] pdfBytes, PdfResultEncoding encoding, CancellationToken cancellationToken = default) =>
+        CallEncodedAsync(pdfBytes, encoding, NativeMethods.oxidize_partition_encoded,
+            "Failed to partition PDF", cancellationToken);
+
+    /// <summary>
+    /// Extract RAG chunks with the default configuration and return them
+    /// serialized in <paramref name="encoding"/>.
+    /// </summary>
+    /// <param name="pdfBytes">PDF file content as byte array.</param>
+    /// <param name="encoding">JSON or CBOR.</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>The encoded chunk list; JSON matches <c>RagChunksAsync</c>.</returns>
+    /// <exception cref="ArgumentNullException">If pdfBytes is null.</exception>
+    /// <exception cref="ArgumentException">If pdfBytes is empty or exceeds maximum size.</exception>
+    /// <exception cref="ArgumentOutOfRangeException">If <paramref name="encoding"/> is not a defined value.</exception>
+    /// <exception cref="PdfExtractionException">If chunking fails.</exception>
+    public Task<byte[]> RagChunksEncodedAsync(
+        byte[] pdfBytes, PdfResultEncoding encoding, CancellationToken cancellationToken = default) =>
+        CallEncodedAsync(pdfBytes, encoding, NativeMethods.oxidize_rag_chunks_encoded,
+            "Failed to extract RAG chunks", cancellationToken);
+
+    /// <summary>
+    /// Get the annotations of every page serialized in <paramref name="encoding"/>.
+    /// </summary>
+    /// <param name="pdfBytes">PDF file content as byte array.</param>
+    /// <param name="encoding">JSON or CBOR.</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>The encoded annotation list; JSON matches <see cref="GetAnnotationsAsync"/>.</returns>
+    /// <exception cref="ArgumentNullException">If pdfBytes is null.</exception>
+    /// <exception cref="ArgumentException">If pdfBytes is empty or exceeds maximum size.</exception>
+    /// <exception cref="ArgumentOutOfRangeException">If <paramref name="encoding"/> is not a defined value.</exception>
+    /// <exception cref="PdfExtractionException">If extraction fails.</exception>
+    public Task<byte[]> GetAnnotationsEncodedAsync(
+        byte[] pdfBytes, PdfResultEncoding encoding, CancellationToken cancellationToken = default) =>
+        CallEncodedAsync(pdfBytes, encoding, NativeMethods.oxidize_get_annotations_encoded,
+            "Failed to get annotations", cancellationToken);
+
+    /// <summary>
+    /// Get the form fields serialized in <paramref name="encoding"/>.
+    /// </summary>
+    /// <param name="pdfBytes">PDF file content as byte array.</param>
+    /// <param name="encoding">JSON or CBOR.</param>
+    /// <param name="cancellationToken">Cancellation token.</param>
+    /// <returns>The encoded field list; JSON matches <c>GetFormFieldsAsync</c>.</returns>
+    /// <exception cref="ArgumentNullException">If pdfBytes is null.</exception>
+    /// <exception cref="ArgumentException">If pdfBytes is empty or exceeds maximum size.</exception>
+    /// <exception cref="ArgumentOutOfRangeException">If <paramref name="encoding"/> is not a defined value.</exception>
+    /// <exception cref="PdfExtractionException">If extraction fails.</exception>
+    public Task<byte[]> GetFormFieldsEncodedAsync(
+        byte[] pdfBytes, PdfResultEncoding encoding, CancellationToken cancellationToken = default) =>
+        CallEncodedAsync(pdfBytes, encoding, NativeMethods.oxidize_get_form_fields_encoded,
+            "Failed to get form fields", cancellationToken);
+
+    /// <summary>
+    /// Stream structure-aware RAG chunks one at a time instead of building the
+    /// whole list. Pages are partitioned one at a time, so memory stays bounded
+    /// by a page. The chunks match the <c>RagChunksAsync</c> overload taking
+    /// both configs, except that the cell text of a ruled table grid is yielded as prose
+    /// rather than as a table chunk.
+    /// </summary>
+    /// <param name="pdfBytes">PDF file content as byte array.</param>
+    /// <param name="partitionConfig">Optional partition configuration. <c>null</c> uses <c>PartitionConfig::default()</c>.</param>
+    /// <param name="hybridConfig">Optional hybrid-chunker configuration. <c>null</c> uses <c>HybridChunkConfig::default()</c>.</param>
+    /// <returns>A sequence that can be enumerated once. The native stream is
+    /// opened by this call and freed when the enumeration ends or is disposed.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="pdfBytes"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="pdfBytes"/> is empty/oversize, or either non-null config fails validation.</exception>
+    /// <exception cref="PdfExtractionException">If the PDF cannot be opened, or chunking fails while enumerating.</exception>
+    public IEnumerable<RagChunk> StreamRagChunks(
+        byte[] pdfBytes,
+        PartitionConfig? partitionConfig = null,
+        HybridChunkConfig? hybridConfig = null)
+    {
+        ArgumentNullException.ThrowIfNull(pdfBytes);
+        if (pdfBytes.Length == 0)
+            throw new ArgumentException("PDF bytes cannot be empty", nameof(pdfBytes));
+        ValidatePdfSize(pdfBytes);
+        partitionConfig?.Validate();
+        hybridConfig?.Validate();
+
+        var partitionJson = partitionConfig?.ToJson();
+        var hybridJson = hybridConfig?.ToJson();
+        var stream = ChunkStreams.Open(
+            pdfBytes,
+            (IntPtr ptr, nuint len, out IntPtr outStream) =>
+                NativeMethods.oxidize_rag_chunk_stream_open(ptr, len, partitionJson, hybridJson, out outStream),
+            "Failed to open RAG chunk stream");
+        return ChunkStreams.Enumerate<RagChunk>(stream);
+    }
+
+    /// <summary>
+    /// Stream semantic chunks one at a time instead of building the whole
+    /// list. Pages are partitioned one at a time; the chunks match
+    /// <c>SemanticChunksAsync</c> except that the cell text of a ruled
+    /// table grid is yielded as prose.
+    /// </summary>
+    /// <param name="pdfBytes">PDF file content as byte array.</param>
+    /// <param name="config">Semantic-chunker configuration. <c>null</c> uses <c>SemanticChunkConfig::default()</c>.</param>
+    /// <param name="partitionConfig">Optional partition configuration. <c>null</c> uses <c>PartitionConfig::default()</c>.</param>
+    /// <returns>A sequence that can be enumerated once. The native stream is
+    /// opened by this call and freed when the enumeration ends or is disposed.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="pdfBytes"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="pdfBytes"/> is empty/oversize, or either non-null config fails validation.</exception>
+    /// <exception cref="PdfExtractionException">If the PDF cannot be opened, or chunking fails while enumerating.</exception>
+    public IEnumerable<SemanticChunk> StreamSemanticChunks(
+        byte[] pdfBytes,
+        SemanticChunkConfig? config = null,
+        PartitionConfig? partitionConfig = null)
+    {
+        ArgumentNullException.ThrowIfNull(pdfBytes);
+        if (pdfBytes.Length == 0)
+            throw new ArgumentException("PDF bytes cannot be empty", nameof(pdfBytes));
+        ValidatePdfSize(pdfBytes);
+        config ??= new SemanticChunkConfig();
+        config.Validate();
+        partitionConfig?.Validate();
+
+        var partitionJson = partitionConfig?.ToJson();
+        var semanticJson = config.ToJson();
+        var stream = ChunkStreams.Open(
+            pdfBytes,
+            (IntPtr ptr, nuint len, out IntPtr outStream) =>
+                NativeMethods.oxidize_semantic_chunk_stream_open(ptr, len, partitionJson, semanticJson, out outStream),
+            "Failed to open semantic chunk stream");
+        return ChunkStreams.Enumerate<SemanticChunk>(stream);
+    }
+
+    /// <summary>
+    /// <c>StreamRagChunks</c> reading from a file. The file is memory-mapped
+    /// by the native library, so no managed copy of the PDF is made; the size
+    /// limit of this extractor does not apply.
+    /// </summary>
+    /// <param name="path">Path of the PDF file.</param>
+    /// <param name="partitionConfig">Optional partition configuration. <c>null</c> uses <c>PartitionConfig::default()</c>.</param>
+    /// <param name="hybridConfig">Optional hybrid-chunker configuration. <c>null</c> uses <c>HybridChunkConfig::default()</c>.</param>
+    /// <param name="password">Password (user or owner) for an encrypted file, or <c>null</c>.</param>
+    /// <returns>A sequence that can be enumerated once. The native stream is
+    /// opened by this call and freed when the enumeration ends or is disposed.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="path"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="path"/> is empty, or either non-null config fails validation.</exception>
+    /// <exception cref="PdfExtractionException">If the file cannot be read or parsed, or chunking fails while enumerating.</exception>
+    public IEnumerable<RagChunk> StreamRagChunksFromFile(
+        string path,
+        PartitionConfig? partitionConfig = null,
+        HybridChunkConfig? hybridConfig = null,
+        string? password = null)
+    {
+        ArgumentException.ThrowIfNullOrEmpty(path);
+        partitionConfig?.Validate();
+        hybridConfig?.Validate();
+
+        var partitionJson = partitionConfig?.ToJson();
+        var hybridJson = hybridConfig?.ToJson();
+        var stream = ChunkStreams.OpenPath(
+            (out IntPtr outStream) => password is null
+                ? NativeMethods.oxidize_rag_chunk_stream_open_path(path, partitionJson, hybridJson, out outStream)
+                : NativeMethods.oxidize_rag_chunk_stream_open_path_with_password(
+                    path, password, partitionJson, hybridJson, out outStream),
+            $"Failed to open RAG chunk stream for '{path}'");
+        return ChunkStreams.Enumerate<RagChunk>(stream);
+    }
+
+    /// <summary>
+    /// <c>StreamSemanticChunks</c> reading from a file. The file is
+    /// memory-mapped by the native library, so no managed copy of the PDF is
+    /// made; the size limit of this extractor does not apply.
+    /// </summary>
+    /// <param name="path">Path of the PDF file.</param>
+    /// <param name="config">Semantic-chunker configuration. <c>null</c> uses <c>SemanticChunkConfig::default()</c>.</param>
+    /// <param name="partitionConfig">Optional partition configuration. <c>null</c> uses <c>PartitionConfig::default()</c>.</param>
+    /// <param name="password">Password (user or owner) for an encrypted file, or <c>null</c>.</param>
+    /// <returns>A sequence that can be enumerated once. The native stream is
+    /// opened by this call and freed when the enumeration ends or is disposed.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="path"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="path"/> is empty, or either non-null config fails validation.</exception>
+    /// <exception cref="PdfExtractionException">If the file cannot be read or parsed, or chunking fails while enumerating.</exception>
+    public IEnumerable<SemanticChunk> StreamSemanticChunksFromFile(
+        string path,
+        SemanticChunkConfig? config = null,
+        PartitionConfig? partitionConfig = null,
+        string? password = null)
+    {
+        ArgumentException.ThrowIfNullOrEmpty(path);
+        config ??= new SemanticChunkConfig();
+        config.Validate();
+        partitionConfig?.Validate();
+
+        var partitionJson = partitionConfig?.ToJson();
+        var semanticJson = config.ToJson();
+        var stream = ChunkStreams.OpenPath(
+            (out IntPtr outStream) => password is null
+                ? NativeMethods.oxidize_semantic_chunk_stream_open_path(path, partitionJson, semanticJson, out outStream)
+                : NativeMethods.oxidize_semantic_chunk_stream_open_path_with_password(
+                    path, password, partitionJson, semanticJson, out outStream),
+            $"Failed to open semantic chunk stream for '{path}'");
+        return ChunkStreams.Enumerate<SemanticChunk>(stream);
+    }
+
+    private void ValidatePasswordInput(byte[] pdfBytes, string password)
+    {
+        ArgumentNullException.ThrowIfNull(pdfBytes);
+        ArgumentNullException.ThrowIfNull(password);
+        if (pdfBytes.Length == 0)
+            throw new ArgumentException("PDF bytes cannot be empty", nameof(pdfBytes));
+        ValidatePdfSize(pdfBytes);
+    }
+
+    private void ValidateParallelInput(byte[] pdfBytes, int threads)
+    {
+        ValidateInput(pdfBytes);
+        ArgumentOutOfRangeException.ThrowIfNegative(threads);
+    }
+
+    private void ValidateInput(byte[] pdfBytes)
+    {
+        ArgumentNullException.ThrowIfNull(pdfBytes);
+        if (pdfBytes.Length == 0)
+            throw new ArgumentException("PDF bytes cannot be empty", nameof(pdfBytes));
+        ValidatePdfSize(pdfBytes);
+    }
+
+    private void ValidatePdfSize(byte[] pdfBytes)
+    {
+        if (pdfBytes.LongLength > _maxFileSizeBytes)
+        {
+            throw new ArgumentException(
+                $"PDF size ({pdfBytes.LongLength:N0} bytes) exceeds maximum allowed size ({_maxFileSizeBytes:N0} bytes). " +
+                "Consider using a smaller file or increasing the maxFileSizeBytes limit.",
+                nameof(pdfBytes));
+        }
+    }
+
+    // ── FFI helpers ──────────────────────────────────────────────────────────
+
+    private delegate int NativeJsonCall(IntPtr pdfBytes, nuint pdfLen, out IntPtr outJson);
+    private delegate int NativeJsonCallWithProfile(IntPtr pdfBytes, nuint pdfLen, byte profile, out IntPtr outJson);
+    private delegate int NativeJsonCallWithConfig(IntPtr pdfBytes, nuint pdfLen, string configJson, out IntPtr outJson);
+    private delegate int NativeStringCall(IntPtr pdfBytes, nuint pdfLen, out IntPtr outText);
+
+    private delegate int NativeJsonCallWithProgress(
+        IntPtr pdfBytes, nuint pdfLen, ref NativeMethods.ProgressCallbacksNative progress, out IntPtr outJson);
+
+    private static T WithPinnedPdf<T>(byte[] pdfBytes, Func<IntPtr, nuint, T> action)
+    {
+        IntPtr pdfPtr = IntPtr.Zero;
+        try
+        {
+            pdfPtr = Marshal.AllocHGlobal(pdfBytes.Length);
+            Marshal.Copy(pdfBytes, 0, pdfPtr, pdfBytes.Length);
+            return action(pdfPtr, (nuint)pdfBytes.Length);
+        }
+        finally
+        {
+            if (pdfPtr != IntPtr.Zero)
+                Marshal.FreeHGlobal(pdfPtr);
+        }
+    }
+
+    private static T CallNativeJson<T>(byte[] pdfBytes, NativeJsonCall nativeCall, string errorMsg) where T : class, new()
+    {
+        return WithPinnedPdf(pdfBytes, (ptr, len) =>
+        {
+            IntPtr jsonPtr = IntPtr.Zero;
+            try
             {
                 var result = nativeCall(ptr, len, out jsonPtr);
                 ThrowIfError(result, errorMsg);
@@ -1026,6 +2158,101 @@ public class PdfExtractor
         });
     }
 
+    private static T CallNativeJsonWithProgress<T>(
+        byte[] pdfBytes,
+        NativeProgress progress,
+        NativeJsonCallWithProgress nativeCall,
+        string errorMsg) where T : class, new()
+    {
+        return WithPinnedPdf(pdfBytes, (ptr, len) =>
+        {
+            IntPtr jsonPtr = IntPtr.Zero;
+            try
+            {
+                progress.Call(
+                    (ref NativeMethods.ProgressCallbacksNative callbacks) =>
+                        nativeCall(ptr, len, ref callbacks, out jsonPtr),
+                    errorMsg);
+                var json = Marshal.PtrToStringUTF8(jsonPtr) ?? "[]";
+                return JsonSerializer.Deserialize<T>(json) ?? new T();
+            }
+            finally
+            {
+                if (jsonPtr != IntPtr.Zero)
+                    NativeMethods.oxidize_free_string(jsonPtr);
+            }
+        });
+    }
+
+    private delegate int NativeEncodedCall(
+        IntPtr pdfBytes, nuint pdfLen, byte encoding, out IntPtr outBytes, out nuint outLen);
+
+    private Task<byte[]> CallEncodedAsync(
+        byte[] pdfBytes,
+        PdfResultEncoding encoding,
+        NativeEncodedCall nativeCall,
+        string errorMsg,
+        CancellationToken cancellationToken)
+    {
+        cancellationToken.ThrowIfCancellationRequested();
+        ArgumentNullException.ThrowIfNull(pdfBytes);
+        if (pdfBytes.Length == 0)
+            throw new ArgumentException("PDF bytes cannot be empty", nameof(pdfBytes));
+        ValidatePdfSize(pdfBytes);
+        if (!Enum.IsDefined(encoding))
+            throw new ArgumentOutOfRangeException(nameof(encoding), encoding, "Unknown result encoding");
+
+        return Task.Run(() => WithPinnedPdf(pdfBytes, (ptr, len) =>
+        {
+            IntPtr outPtr = IntPtr.Zero;
+            nuint outLen = 0;
+            try
+            {
+                ThrowIfError(nativeCall(ptr, len, (byte)encoding, out outPtr, out outLen), errorMsg);
+                var result = new byte[checked((int)outLen)];
+                if (result.Length > 0)
+                    Marshal.Copy(outPtr, result, 0, result.Length);
+                return result;
+            }
+            finally
+            {
+                if (outPtr != IntPtr.Zero)
+                    NativeMethods.oxidize_free_bytes(outPtr, outLen);
+            }
+        }), cancellationToken);
+    }
+
+    internal delegate int NativeCallWithMetrics(
+        IntPtr pdfBytes, nuint pdfLen, out IntPtr outResult, out IntPtr outMetrics);
+
+    /// <summary>
+    /// Runs a <c>*_with_metrics</c> entry point and returns its output string
+    /// (text or JSON) with the parsed metrics object.
+    /// </summary>
+    internal static (string Output, PdfCallMetrics Metrics) CallNativeWithMetrics(
+        byte[] pdfBytes, NativeCallWithMetrics nativeCall, string errorMsg)
+    {
+        return WithPinnedPdf(pdfBytes, (ptr, len) =>
+        {
+            IntPtr resultPtr = IntPtr.Zero;
+            IntPtr metricsPtr = IntPtr.Zero;
+            try
+            {
+                ThrowIfError(nativeCall(ptr, len, out resultPtr, out metricsPtr), errorMsg);
+                var output = Marshal.PtrToStringUTF8(resultPtr) ?? string.Empty;
+                var metricsJson = Marshal.PtrToStringUTF8(metricsPtr) ?? "{}";
+                return (output, JsonSerializer.Deserialize<PdfCallMetrics>(metricsJson) ?? new PdfCallMetrics());
+            }
+            finally
+            {
+                if (resultPtr != IntPtr.Zero)
+                    NativeMethods.oxidize_free_string(resultPtr);
+                if (metricsPtr != IntPtr.Zero)
+                    NativeMethods.oxidize_free_string(metricsPtr);
+            }
+        });
+    }
+
     private static string CallNativeString(byte[] pdfBytes, NativeStringCall nativeCall, string errorMsg)
     {
         return WithPinnedPdf(pdfBytes, (ptr, len) =>
@@ -1050,6 +2277,25 @@ public class PdfExtractor
     private string ExtractText(byte[] pdfBytes) =>
         CallNativeString(pdfBytes, NativeMethods.oxidize_extract_text, "Failed to extract text from PDF");
 
+    private static string ExtractTextWithProgress(byte[] pdfBytes, NativeProgress progress) =>
+        WithPinnedPdf(pdfBytes, (ptr, len) =>
+        {
+            IntPtr textPtr = IntPtr.Zero;
+            try
+            {
+                progress.Call(
+                    (ref NativeMethods.ProgressCallbacksNative callbacks) =>
+                        NativeMethods.oxidize_extract_text_with_progress(ptr, len, ref callbacks, out textPtr),
+                    "Failed to extract text from PDF");
+                return Marshal.PtrToStringUTF8(textPtr) ?? string.Empty;
+            }
+            finally
+            {
+                if (textPtr != IntPtr.Zero)
+                    NativeMethods.oxidize_free_string(textPtr);
+            }
+        });
+
 #pragma warning disable CS0618 // Legacy ChunkOptions kept callable for one minor release.
     private List<DocumentChunk> ExtractChunks(byte[] pdfBytes, ChunkOptions options)
     {
@@ -1281,19 +2527,7 @@ public class PdfExtractor
             pdfPtr = Marshal.AllocHGlobal(pdfBytes.Length);
             Marshal.Copy(pdfBytes, 0, pdfPtr, pdfBytes.Length);
 
-            var nativeOptions = new NativeMethods.ExtractionOptionsNative
-            {
-                PreserveLayout = options.PreserveLayout,
-                SpaceThreshold = options.SpaceThreshold,
-                NewlineThreshold = options.NewlineThreshold,
-                SortByPosition = options.SortByPosition,
-                DetectColumns = options.DetectColumns,
-                ColumnThreshold = options.ColumnThreshold,
-                MergeHyphenated = options.MergeHyphenated,
-                TjSpaceThreshold = options.TjSpaceThreshold,
-                ReconstructParagraphs = options.ReconstructParagraphs,
-                IncludeArtifacts = options.IncludeArtifacts
-            };
+            var nativeOptions = options.ToNative();
 
             var result = NativeMethods.oxidize_extract_text_with_options(
                 pdfPtr,
@@ -1343,6 +2577,13 @@ public class PdfExtractor
     private PdfMetadata ExtractMetadata(byte[] pdfBytes) =>
         CallNativeJson<PdfMetadata>(pdfBytes, NativeMethods.oxidize_get_metadata, "Failed to extract metadata from PDF");
 
+    private static PdfRecoveryReport GetRecoveryReport(byte[] pdfBytes, bool strict) =>
+        CallNativeJson<PdfRecoveryReport>(
+            pdfBytes,
+            (IntPtr ptr, nuint len, out IntPtr json) =>
+                NativeMethods.oxidize_parse_recovery_report(ptr, len, (byte)(strict ? 1 : 0), out json),
+            "Failed to build the recovery report");
+
     private List<PdfAnnotation> GetAnnotations(byte[] pdfBytes) =>
         CallNativeJson<List<PdfAnnotation>>(pdfBytes, NativeMethods.oxidize_get_annotations, "Failed to get annotations from PDF");
 
@@ -1423,7 +2664,7 @@ public class PdfExtractor
             ? $"{message}: {rustError}"
             : $"{message}: {error}";
 
-        throw new PdfExtractionException(detailedMessage);
+        throw new PdfExtractionException(detailedMessage, NativeMethods.GetLastErrorDetails());
     }
 }
 
@@ -1445,4 +2686,17 @@ public class PdfExtractionException : Exception
     /// <param name="innerException">The exception that is the cause of the current exception</param>
     public PdfExtractionException(string message, Exception innerException)
         : base(message, innerException) { }
+
+    internal PdfExtractionException(string message, PdfErrorDetails? details)
+        : base(message)
+    {
+        Details = details;
+    }
+
+    /// <summary>
+    /// Structured details reported by the native library (category, page,
+    /// object, byte offset and cause chain), or <c>null</c> when the failure
+    /// was detected on the managed side.
+    /// </summary>
+    public PdfErrorDetails? Details { get; }
 }
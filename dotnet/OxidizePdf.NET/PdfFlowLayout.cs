@@ -223,6 +223,6 @@ public sealed class PdfFlowLayout : IDisposable
 
         var rustError = NativeMethods.GetLastError();
         var detail = !string.IsNullOrEmpty(rustError) ? rustError : ((NativeMethods.ErrorCode)errorCode).ToString();
-        throw new PdfExtractionException($"{message}: {detail}");
+        throw new PdfExtractionException($"{message}: {detail}", NativeMethods.GetLastErrorDetails());
     }
 }
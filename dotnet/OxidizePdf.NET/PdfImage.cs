@@ -160,6 +160,6 @@ public sealed class PdfImage : IDisposable
 
         var rustError = NativeMethods.GetLastError();
         var detail = !string.IsNullOrEmpty(rustError) ? rustError : ((NativeMethods.ErrorCode)errorCode).ToString();
-        throw new PdfExtractionException($"{message}: {detail}");
+        throw new PdfExtractionException($"{message}: {detail}", NativeMethods.GetLastErrorDetails());
     }
 }
@@ -92,6 +92,12 @@ public sealed class PdfMergeInput
     /// </summary>
     public PdfPageRange? Pages { get; }
 
+    /// <summary>
+    /// Password (user or owner) for an encrypted input. Ignored when the input
+    /// is not encrypted.
+    /// </summary>
+    public string? Password { get; init; }
+
     /// <param name="pdfBytes">The PDF content. Must not be null or empty.</param>
     /// <param name="pages">Pages to include, or <c>null</c> for all pages.</param>
     public PdfMergeInput(byte[] pdfBytes, PdfPageRange? pages = null)
@@ -108,6 +114,6 @@ public sealed class PdfMergeInput
     {
         var b64 = Convert.ToBase64String(PdfBytes);
         object? pagesObj = Pages?.ToJsonObject();
-        return new { pdf = b64, pages = pagesObj };
+        return new { pdf = b64, pages = pagesObj, password = Password };
     }
 }
@@ -1,6 +1,7 @@
 using System.Runtime.InteropServices;
 using System.Text.Json;
 using System.Text.Json.Serialization;
+using OxidizePdf.NET.Models;
 
 namespace OxidizePdf.NET;
 
@@ -225,6 +226,35 @@ public static class PdfOperations
         return Task.Run(() => ExtractImages(pdfBytes), ct);
     }
 
+    /// <summary>
+    /// Extracts all images from a PDF document and returns the records
+    /// serialized in <paramref name="encoding"/>. With <see cref="PdfResultEncoding.Cbor"/>
+    /// the image data is a raw byte string, avoiding the one-third growth of
+    /// base64 in the JSON.
+    /// </summary>
+    /// <param name="pdfBytes">The source PDF as a byte array.</param>
+    /// <param name="encoding">JSON or CBOR.</param>
+    /// <param name="ct">Cancellation token.</param>
+    /// <returns>The encoded image list.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="pdfBytes"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="pdfBytes"/> is empty.</exception>
+    /// <exception cref="ArgumentOutOfRangeException">If <paramref name="encoding"/> is not a defined value.</exception>
+    /// <exception cref="OperationCanceledException">If the operation is cancelled.</exception>
+    /// <exception cref="PdfExtractionException">If the native extraction operation fails.</exception>
+    public static Task<byte[]> ExtractImagesEncodedAsync(
+        byte[] pdfBytes, PdfResultEncoding encoding, CancellationToken ct = default)
+    {
+        ct.ThrowIfCancellationRequested();
+        ArgumentNullException.ThrowIfNull(pdfBytes);
+        if (pdfBytes.Length == 0)
+            throw new ArgumentException("PDF bytes cannot be empty", nameof(pdfBytes));
+        if (!Enum.IsDefined(encoding))
+            throw new ArgumentOutOfRangeException(nameof(encoding), encoding, "Unknown result encoding");
+
+        ct.ThrowIfCancellationRequested();
+        return Task.Run(() => ExtractImagesEncoded(pdfBytes, encoding), ct);
+    }
+
     /// <summary>
     /// Overlays one PDF on top of another using default options.
     /// </summary>
@@ -269,6 +299,74 @@ public static class PdfOperations
         return Task.Run(() => SplitWithOptions(pdfBytes, options), ct);
     }
 
+    /// <summary>
+    /// Splits an encrypted PDF into individual single-page PDFs, unlocking it
+    /// with <paramref name="password"/>. Unencrypted input ignores the password.
+    /// </summary>
+    /// <param name="pdfBytes">The source PDF as a byte array.</param>
+    /// <param name="password">The user or owner password.</param>
+    /// <param name="ct">Cancellation token.</param>
+    /// <returns>A list of byte arrays, one per page of the source PDF.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="pdfBytes"/> or <paramref name="password"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="pdfBytes"/> is empty.</exception>
+    /// <exception cref="OperationCanceledException">If the operation is cancelled.</exception>
+    /// <exception cref="PdfExtractionException">If the native split operation fails or the password is wrong.</exception>
+    public static Task<List<byte[]>> SplitAsync(byte[] pdfBytes, string password, CancellationToken ct = default)
+    {
+        ct.ThrowIfCancellationRequested();
+        ArgumentNullException.ThrowIfNull(pdfBytes);
+        ArgumentNullException.ThrowIfNull(password);
+        if (pdfBytes.Length == 0)
+            throw new ArgumentException("PDF bytes cannot be empty", nameof(pdfBytes));
+
+        return Task.Run(() => Split(pdfBytes, password), ct);
+    }
+
+    /// <summary>
+    /// Rotates all pages of an encrypted PDF, unlocking it with <paramref name="password"/>.
+    /// </summary>
+    /// <param name="pdfBytes">The source PDF as a byte array.</param>
+    /// <param name="password">The user or owner password.</param>
+    /// <param name="degrees">Rotation angle. Must be 0, 90, 180, or 270.</param>
+    /// <param name="ct">Cancellation token.</param>
+    /// <returns>The rotated PDF as a byte array.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="pdfBytes"/> or <paramref name="password"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="pdfBytes"/> is empty.</exception>
+    /// <exception cref="OperationCanceledException">If the operation is cancelled.</exception>
+    /// <exception cref="PdfExtractionException">If the native rotate operation fails, degrees is invalid or the password is wrong.</exception>
+    public static Task<byte[]> RotateAsync(byte[] pdfBytes, string password, int degrees, CancellationToken ct = default)
+    {
+        ct.ThrowIfCancellationRequested();
+        ArgumentNullException.ThrowIfNull(pdfBytes);
+        ArgumentNullException.ThrowIfNull(password);
+        if (pdfBytes.Length == 0)
+            throw new ArgumentException("PDF bytes cannot be empty", nameof(pdfBytes));
+
+        return Task.Run(() => Rotate(pdfBytes, degrees, password), ct);
+    }
+
+    /// <summary>
+    /// Reverses the page order of an encrypted PDF, unlocking it with <paramref name="password"/>.
+    /// </summary>
+    /// <param name="pdfBytes">The source PDF as a byte array.</param>
+    /// <param name="password">The user or owner password.</param>
+    /// <param name="ct">Cancellation token.</param>
+    /// <returns>The PDF with reversed page order as a byte array.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="pdfBytes"/> or <paramref name="password"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="pdfBytes"/> is empty.</exception>
+    /// <exception cref="OperationCanceledException">If the operation is cancelled.</exception>
+    /// <exception cref="PdfExtractionException">If the native operation fails or the password is wrong.</exception>
+    public static Task<byte[]> ReversePagesAsync(byte[] pdfBytes, string password, CancellationToken ct = default)
+    {
+        ct.ThrowIfCancellationRequested();
+        ArgumentNullException.ThrowIfNull(pdfBytes);
+        ArgumentNullException.ThrowIfNull(password);
+        if (pdfBytes.Length == 0)
+            throw new ArgumentException("PDF bytes cannot be empty", nameof(pdfBytes));
+
+        return Task.Run(() => ReversePages(pdfBytes, password), ct);
+    }
+
     /// <summary>
     /// Merges multiple PDFs into a single PDF, with optional per-input page range selection.
     /// </summary>
@@ -290,6 +388,33 @@ public static class PdfOperations
         return Task.Run(() => MergeWithRanges(inputs), ct);
     }
 
+    /// <summary>
+    /// Merges multiple PDFs with optional page ranges, reporting progress after
+    /// each copied page. Unlike the other overloads, <paramref name="ct"/> is
+    /// also polled between pages, so a large merge stops mid-way.
+    /// </summary>
+    /// <param name="inputs">Collection of <see cref="PdfMergeInput"/> entries to merge in order.</param>
+    /// <param name="progress">Receives the copied and total page counts. May be null to only honour cancellation.</param>
+    /// <param name="ct">Cancellation token, checked between pages.</param>
+    /// <returns>The merged PDF as a byte array.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="inputs"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="inputs"/> is empty.</exception>
+    /// <exception cref="OperationCanceledException">If the operation is cancelled.</exception>
+    /// <exception cref="PdfExtractionException">If the native merge operation fails.</exception>
+    public static Task<byte[]> MergeAsync(
+        IReadOnlyList<PdfMergeInput> inputs,
+        IProgress<PdfProgress>? progress,
+        CancellationToken ct = default)
+    {
+        ct.ThrowIfCancellationRequested();
+        ArgumentNullException.ThrowIfNull(inputs);
+        if (inputs.Count == 0)
+            throw new ArgumentException("At least one PDF is required for merge", nameof(inputs));
+
+        var nativeProgress = new NativeProgress(progress, ct);
+        return Task.Run(() => MergeWithProgress(inputs, nativeProgress), ct);
+    }
+
     /// <summary>
     /// Rotates specific pages of a PDF by the specified number of degrees.
     /// </summary>
@@ -353,7 +478,7 @@ public static class PdfOperations
 
     // ── Private synchronous implementations ──────────────────────────────────
 
-    private static List<byte[]> Split(byte[] pdfBytes)
+    private static List<byte[]> Split(byte[] pdfBytes, string? password = null)
     {
         IntPtr pdfPtr = IntPtr.Zero;
         IntPtr jsonPtr = IntPtr.Zero;
@@ -363,10 +488,10 @@ public static class PdfOperations
             pdfPtr = Marshal.AllocHGlobal(pdfBytes.Length);
             Marshal.Copy(pdfBytes, 0, pdfPtr, pdfBytes.Length);
 
-            var result = NativeMethods.oxidize_split_pdf_bytes(
-                pdfPtr,
-                (nuint)pdfBytes.Length,
-                out jsonPtr);
+            var result = password is null
+                ? NativeMethods.oxidize_split_pdf_bytes(pdfPtr, (nuint)pdfBytes.Length, out jsonPtr)
+                : NativeMethods.oxidize_split_pdf_bytes_with_password(
+                    pdfPtr, (nuint)pdfBytes.Length, password, out jsonPtr);
 
             ThrowIfError(result, "Failed to split PDF");
 
@@ -417,7 +542,7 @@ public static class PdfOperations
         }
     }
 
-    private static byte[] Rotate(byte[] pdfBytes, int degrees)
+    private static byte[] Rotate(byte[] pdfBytes, int degrees, string? password = null)
     {
         IntPtr pdfPtr = IntPtr.Zero;
         IntPtr outPtr = IntPtr.Zero;
@@ -428,12 +553,11 @@ public static class PdfOperations
             pdfPtr = Marshal.AllocHGlobal(pdfBytes.Length);
             Marshal.Copy(pdfBytes, 0, pdfPtr, pdfBytes.Length);
 
-            var result = NativeMethods.oxidize_rotate_pdf_bytes(
-                pdfPtr,
-                (nuint)pdfBytes.Length,
-                degrees,
-                out outPtr,
-                out outLen);
+            var result = password is null
+                ? NativeMethods.oxidize_rotate_pdf_bytes(
+                    pdfPtr, (nuint)pdfBytes.Length, degrees, out outPtr, out outLen)
+                : NativeMethods.oxidize_rotate_pdf_bytes_with_password(
+                    pdfPtr, (nuint)pdfBytes.Length, password, degrees, out outPtr, out outLen);
 
             ThrowIfError(result, $"Failed to rotate PDF by {degrees} degrees");
 
@@ -573,7 +697,7 @@ public static class PdfOperations
         }
     }
 
-    private static byte[] ReversePages(byte[] pdfBytes)
+    private static byte[] ReversePages(byte[] pdfBytes, string? password = null)
     {
         IntPtr pdfPtr = IntPtr.Zero;
         IntPtr outPtr = IntPtr.Zero;
@@ -584,8 +708,10 @@ public static class PdfOperations
             pdfPtr = Marshal.AllocHGlobal(pdfBytes.Length);
             Marshal.Copy(pdfBytes, 0, pdfPtr, pdfBytes.Length);
 
-            var result = NativeMethods.oxidize_reverse_pages_bytes(
-                pdfPtr, (nuint)pdfBytes.Length, out outPtr, out outLen);
+            var result = password is null
+                ? NativeMethods.oxidize_reverse_pages_bytes(pdfPtr, (nuint)pdfBytes.Length, out outPtr, out outLen)
+                : NativeMethods.oxidize_reverse_pages_bytes_with_password(
+                    pdfPtr, (nuint)pdfBytes.Length, password, out outPtr, out outLen);
 
             ThrowIfError(result, "Failed to reverse pages");
 
@@ -679,6 +805,38 @@ public static class PdfOperations
         }
     }
 
+    private static byte[] ExtractImagesEncoded(byte[] pdfBytes, PdfResultEncoding encoding)
+    {
+        IntPtr pdfPtr = IntPtr.Zero;
+        IntPtr outPtr = IntPtr.Zero;
+        nuint outLen = 0;
+
+        try
+        {
+            pdfPtr = Marshal.AllocHGlobal(pdfBytes.Length);
+            Marshal.Copy(pdfBytes, 0, pdfPtr, pdfBytes.Length);
+
+            var result = NativeMethods.oxidize_extract_images_encoded(
+                pdfPtr,
+                (nuint)pdfBytes.Length,
+                (byte)encoding,
+                out outPtr,
+                out outLen);
+
+            ThrowIfError(result, "Failed to extract images from PDF");
+
+            var encoded = new byte[checked((int)outLen)];
+            if (encoded.Length > 0)
+                Marshal.Copy(outPtr, encoded, 0, encoded.Length);
+            return encoded;
+        }
+        finally
+        {
+            if (pdfPtr != IntPtr.Zero) Marshal.FreeHGlobal(pdfPtr);
+            if (outPtr != IntPtr.Zero) NativeMethods.oxidize_free_bytes(outPtr, outLen);
+        }
+    }
+
     private static List<byte[]> SplitWithOptions(byte[] pdfBytes, PdfSplitOptions options)
     {
         IntPtr pdfPtr = IntPtr.Zero;
@@ -743,6 +901,33 @@ public static class PdfOperations
         }
     }
 
+    private static byte[] MergeWithProgress(IReadOnlyList<PdfMergeInput> inputs, NativeProgress progress)
+    {
+        IntPtr outPtr = IntPtr.Zero;
+        nuint outLen = 0;
+
+        try
+        {
+            var inputObjects = inputs.Select(i => i.ToJsonObject()).ToArray();
+            var inputsJson = JsonSerializer.Serialize(inputObjects);
+
+            progress.Call(
+                (ref NativeMethods.ProgressCallbacksNative callbacks) =>
+                    NativeMethods.oxidize_merge_pdfs_with_progress(inputsJson, ref callbacks, out outPtr, out outLen),
+                "Failed to merge PDFs with page ranges");
+
+            var length = (int)outLen;
+            var output = new byte[length];
+            Marshal.Copy(outPtr, output, 0, length);
+            return output;
+        }
+        finally
+        {
+            if (outPtr != IntPtr.Zero)
+                NativeMethods.oxidize_free_bytes(outPtr, outLen);
+        }
+    }
+
     private static byte[] RotatePages(byte[] pdfBytes, int degrees, PdfPageRange pages)
     {
         IntPtr pdfPtr = IntPtr.Zero;
@@ -828,7 +1013,7 @@ public static class PdfOperations
 
         var rustError = NativeMethods.GetLastError();
         var detail = !string.IsNullOrEmpty(rustError) ? rustError : ((NativeMethods.ErrorCode)errorCode).ToString();
-        throw new PdfExtractionException($"{message}: {detail}");
+        throw new PdfExtractionException($"{message}: {detail}", NativeMethods.GetLastErrorDetails());
     }
 
     // ── Private DTOs ──────────────────────────────────────────────────────────
@@ -0,0 +1,741 @@
+using System.Runtime.ExceptionServices;
+using System.Runtime.InteropServices;
+using System.Text.Json;
+using OxidizePdf.NET.Ai;
+using OxidizePdf.NET.Models;
+using OxidizePdf.NET.Pipeline;
+
+namespace OxidizePdf.NET;
+
+/// <summary>
+/// A PDF parsed once and kept open for repeated reads. Where every
+/// <see cref="PdfExtractor"/> call parses the bytes from scratch, the calls on
+/// a <see cref="PdfParsedDocument"/> reuse the parsed cross-reference table
+/// and the object caches, so running several extractions on the same file
+/// pays the parse cost once.
+/// Implements <see cref="IDisposable"/> to ensure native resources are freed.
+/// </summary>
+/// <remarks>
+/// Each method returns the same result as its <see cref="PdfExtractor"/>
+/// counterpart. An instance is not thread-safe: use it from one thread at a time.
+/// </remarks>
+/// <example>
+/// <code>
+/// using var doc = PdfParsedDocument.Open(pdfBytes);
+/// var text = doc.ExtractText();
+/// var chunks = doc.RagChunks();
+/// </code>
+/// </example>
+public sealed class PdfParsedDocument : IDisposable
+{
+    private readonly ParsedDocumentSafeHandle _safeHandle;
+
+    private IntPtr _handle => _safeHandle.DangerousGetHandle();
+
+    private PdfParsedDocument(ParsedDocumentSafeHandle safeHandle)
+    {
+        _safeHandle = safeHandle;
+    }
+
+    /// <summary>
+    /// Parses a PDF and keeps it open. The bytes are copied, so
+    /// <paramref name="pdfBytes"/> may be reused once this returns.
+    /// </summary>
+    /// <param name="pdfBytes">PDF file content as byte array.</param>
+    /// <returns>The parsed document.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="pdfBytes"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="pdfBytes"/> is empty.</exception>
+    /// <exception cref="PdfExtractionException">If the PDF cannot be parsed.</exception>
+    public static PdfParsedDocument Open(byte[] pdfBytes)
+    {
+        ArgumentNullException.ThrowIfNull(pdfBytes);
+        if (pdfBytes.Length == 0)
+            throw new ArgumentException("PDF bytes cannot be empty", nameof(pdfBytes));
+
+        var handle = WithPdfBuffer(pdfBytes, (ptr, len) =>
+        {
+            var result = NativeMethods.oxidize_parsed_document_open(ptr, len, out var h);
+            PdfExtractor.ThrowIfError(result, "Failed to open PDF");
+            return h;
+        });
+        return new PdfParsedDocument(new ParsedDocumentSafeHandle(handle));
+    }
+
+    /// <summary>
+    /// Parses an encrypted PDF, unlocks it with <paramref name="password"/>
+    /// once and keeps it open, so every read on the returned document works
+    /// on the decrypted content. Unencrypted input ignores the password.
+    /// </summary>
+    /// <param name="pdfBytes">PDF file content as byte array.</param>
+    /// <param name="password">The user or owner password.</param>
+    /// <returns>The parsed, unlocked document.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="pdfBytes"/> or <paramref name="password"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="pdfBytes"/> is empty.</exception>
+    /// <exception cref="PdfExtractionException">
+    /// If the PDF cannot be parsed, or the password is wrong
+    /// (<see cref="PdfExtractionException.Details"/> category <c>password_required</c>).
+    /// </exception>
+    public static PdfParsedDocument Open(byte[] pdfBytes, string password)
+    {
+        ArgumentNullException.ThrowIfNull(pdfBytes);
+        ArgumentNullException.ThrowIfNull(password);
+        if (pdfBytes.Length == 0)
+            throw new ArgumentException("PDF bytes cannot be empty", nameof(pdfBytes));
+
+        var handle = WithPdfBuffer(pdfBytes, (ptr, len) =>
+        {
+            var result = NativeMethods.oxidize_parsed_document_open_with_password(ptr, len, password, out var h);
+            PdfExtractor.ThrowIfError(result, "Failed to open PDF");
+            return h;
+        });
+        return new PdfParsedDocument(new ParsedDocumentSafeHandle(handle));
+    }
+
+    /// <summary>
+    /// Opens a PDF file and keeps it parsed. The file is memory-mapped by the
+    /// native library rather than read into a managed array, so multi-gigabyte
+    /// files cost no managed memory; it must not be truncated while open.
+    /// </summary>
+    /// <param name="path">Path of the PDF file.</param>
+    /// <param name="password">Password (user or owner) for an encrypted file, or <c>null</c>.</param>
+    /// <returns>The parsed document.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="path"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="path"/> is empty.</exception>
+    /// <exception cref="PdfExtractionException">If the file cannot be read or parsed, or the password is wrong.</exception>
+    public static PdfParsedDocument OpenFile(string path, string? password = null)
+    {
+        ArgumentException.ThrowIfNullOrEmpty(path);
+
+        var result = NativeMethods.oxidize_parsed_document_open_path(path, password, out var handle);
+        PdfExtractor.ThrowIfError(result, $"Failed to open '{path}'");
+        return new PdfParsedDocument(new ParsedDocumentSafeHandle(handle));
+    }
+
+    /// <summary>
+    /// Opens a PDF read on demand from <paramref name="stream"/>. Opening reads
+    /// the header, trailer and cross-reference data; page content is read when
+    /// a call needs it, so a large file or a remote blob is never loaded whole.
+    /// </summary>
+    /// <remarks>
+    /// The document does not own the stream: keep it open, and do not move
+    /// its position, until the document is disposed. Calls on the document
+    /// read from the stream on the calling thread. An exception thrown by the
+    /// stream while opening is rethrown; one thrown during a later read
+    /// surfaces as <see cref="PdfExtractionException"/>. The document keeps no
+    /// copy of the bytes, so the signature members, which hash the raw file,
+    /// throw <see cref="PdfExtractionException"/> with category <c>unsupported</c>.
+    /// </remarks>
+    /// <param name="stream">A readable, seekable stream positioned anywhere.</param>
+    /// <param name="password">Password (user or owner) for an encrypted file, or <c>null</c>.</param>
+    /// <returns>The parsed document.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="stream"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="stream"/> is not readable and seekable.</exception>
+    /// <exception cref="PdfExtractionException">If the PDF cannot be parsed, or the password is wrong.</exception>
+    public static PdfParsedDocument OpenStream(Stream stream, string? password = null)
+    {
+        ArgumentNullException.ThrowIfNull(stream);
+        if (!stream.CanRead || !stream.CanSeek)
+            throw new ArgumentException("Stream must be readable and seekable", nameof(stream));
+
+        var source = new StreamReadCallbacks(stream);
+        try
+        {
+            var callbacks = source.ToNative();
+            var result = NativeMethods.oxidize_parsed_document_open_callbacks(ref callbacks, password, out var handle);
+            if (result != 0 && source.Failure is not null)
+                ExceptionDispatchInfo.Throw(source.Failure);
+            PdfExtractor.ThrowIfError(result, "Failed to open PDF stream");
+            return new PdfParsedDocument(new ParsedDocumentSafeHandle(handle, source));
+        }
+        catch
+        {
+            source.Dispose();
+            throw;
+        }
+    }
+
+    // ── Document info ─────────────────────────────────────────────────────────
+
+    /// <summary>
+    /// Gets the number of pages in the document.
+    /// </summary>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If the native call fails.</exception>
+    public int PageCount
+    {
+        get
+        {
+            ThrowIfDisposed();
+            ThrowIfError(
+                NativeMethods.oxidize_parsed_document_get_page_count(_handle, out var count),
+                "Failed to get page count");
+            return (int)count;
+        }
+    }
+
+    /// <summary>
+    /// Gets the PDF version from the file header (e.g. "1.7").
+    /// </summary>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If the native call fails.</exception>
+    public string PdfVersion => CallString(NativeMethods.oxidize_parsed_document_get_pdf_version, "Failed to get PDF version");
+
+    /// <summary>
+    /// Gets whether the document is encrypted.
+    /// </summary>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If the native call fails.</exception>
+    public bool IsEncrypted
+    {
+        get
+        {
+            ThrowIfDisposed();
+            ThrowIfError(
+                NativeMethods.oxidize_parsed_document_is_encrypted(_handle, out var encrypted),
+                "Failed to check if PDF is encrypted");
+            return encrypted;
+        }
+    }
+
+    /// <summary>
+    /// Reads the document metadata (Info dictionary, version, page count).
+    /// </summary>
+    /// <returns>The document metadata.</returns>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If the native call fails.</exception>
+    public PdfMetadata GetMetadata() =>
+        CallJson<PdfMetadata>(NativeMethods.oxidize_parsed_document_get_metadata, "Failed to extract metadata from PDF");
+
+    /// <summary>
+    /// Gets the dimensions of a page.
+    /// </summary>
+    /// <param name="pageNumber">Page number (1-based).</param>
+    /// <returns>A tuple with the page width and height in PDF points.</returns>
+    /// <exception cref="ArgumentOutOfRangeException">If <paramref name="pageNumber"/> is less than 1.</exception>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If the page does not exist or the native call fails.</exception>
+    public (double Width, double Height) GetPageDimensions(int pageNumber)
+    {
+        ValidatePageNumber(pageNumber);
+        ThrowIfDisposed();
+        ThrowIfError(
+            NativeMethods.oxidize_parsed_document_get_page_dimensions(
+                _handle, (nuint)pageNumber, out var width, out var height),
+            $"Failed to get dimensions for page {pageNumber}");
+        return (width, height);
+    }
+
+    // ── Navigation ────────────────────────────────────────────────────────────
+
+    /// <summary>
+    /// Reads the document outline (bookmarks) with resolved targets, styles and open state.
+    /// </summary>
+    /// <returns>The top-level outline items; empty when the document has no outline.</returns>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If the native call fails.</exception>
+    public List<PdfOutlineEntry> GetOutline() =>
+        CallJson<PdfOutlineResult>(NativeMethods.oxidize_parsed_document_get_outline, "Failed to read outline").Items;
+
+    /// <summary>
+    /// Reads the catalog's open action, viewer preferences, named destinations and page labels.
+    /// </summary>
+    /// <returns>The navigation metadata; absent entries are <c>null</c> or empty.</returns>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If the native call fails.</exception>
+    public PdfNavigationMetadata GetNavigationMetadata() =>
+        CallJson<PdfNavigationMetadata>(
+            NativeMethods.oxidize_parsed_document_get_navigation_metadata, "Failed to read navigation metadata");
+
+    /// <summary>
+    /// Reads the logical structure tree; it has no elements when the document is untagged.
+    /// </summary>
+    /// <returns>The structure tree.</returns>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If the native call fails.</exception>
+    public PdfStructureTreeInfo GetStructureTree() =>
+        CallJson<PdfStructureTreeInfo>(
+            NativeMethods.oxidize_parsed_document_get_struct_tree, "Failed to read structure tree");
+
+    // ── Text ──────────────────────────────────────────────────────────────────
+
+    /// <summary>
+    /// Extracts plain text from all pages.
+    /// </summary>
+    /// <returns>Extracted plain text.</returns>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If extraction fails.</exception>
+    public string ExtractText() =>
+        CallString(NativeMethods.oxidize_parsed_document_extract_text, "Failed to extract text from PDF");
+
+    /// <summary>
+    /// Extracts plain text from all pages using custom extraction options.
+    /// </summary>
+    /// <param name="options">Extraction options controlling layout, columns, hyphenation, etc.</param>
+    /// <returns>Extracted plain text.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="options"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="options"/> fails validation.</exception>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If extraction fails.</exception>
+    public string ExtractText(ExtractionOptions options)
+    {
+        ArgumentNullException.ThrowIfNull(options);
+        options.Validate();
+        var nativeOptions = options.ToNative();
+        return CallString(
+            (IntPtr handle, out IntPtr text) =>
+                NativeMethods.oxidize_parsed_document_extract_text_with_options(handle, ref nativeOptions, out text),
+            "Failed to extract text with options");
+    }
+
+    /// <summary>
+    /// Extracts plain text from a single page.
+    /// </summary>
+    /// <param name="pageNumber">Page number (1-based).</param>
+    /// <returns>Extracted plain text from the page.</returns>
+    /// <exception cref="ArgumentOutOfRangeException">If <paramref name="pageNumber"/> is less than 1.</exception>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If the page does not exist or extraction fails.</exception>
+    public string ExtractTextFromPage(int pageNumber)
+    {
+        ValidatePageNumber(pageNumber);
+        return CallString(
+            (IntPtr handle, out IntPtr text) =>
+                NativeMethods.oxidize_parsed_document_extract_text_from_page(handle, (nuint)pageNumber, out text),
+            $"Failed to extract text from page {pageNumber}");
+    }
+
+    /// <summary>
+    /// Extracts the words of a page with their bounding box, font, fill colour,
+    /// rendering mode and baseline rotation, in content-stream order.
+    /// </summary>
+    /// <param name="pageNumber">Page number (1-based).</param>
+    /// <returns>The words of the page.</returns>
+    /// <exception cref="ArgumentOutOfRangeException">If <paramref name="pageNumber"/> is less than 1.</exception>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If the page does not exist or uses an unsupported composite font encoding.</exception>
+    public List<PdfTextItem> ExtractWords(int pageNumber) => ExtractTextItems(pageNumber, glyphs: false);
+
+    /// <summary>
+    /// Extracts every glyph of a page, whitespace included, with the same
+    /// geometry and style as <see cref="ExtractWords"/>.
+    /// </summary>
+    /// <param name="pageNumber">Page number (1-based).</param>
+    /// <returns>The glyphs of the page.</returns>
+    /// <exception cref="ArgumentOutOfRangeException">If <paramref name="pageNumber"/> is less than 1.</exception>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If the page does not exist or uses an unsupported composite font encoding.</exception>
+    public List<PdfTextItem> ExtractGlyphs(int pageNumber) => ExtractTextItems(pageNumber, glyphs: true);
+
+    private List<PdfTextItem> ExtractTextItems(int pageNumber, bool glyphs)
+    {
+        ValidatePageNumber(pageNumber);
+        return CallJson<List<PdfTextItem>>(
+            (IntPtr handle, out IntPtr json) => NativeMethods.oxidize_parsed_document_extract_words(
+                handle, (nuint)pageNumber, (byte)(glyphs ? 1 : 0), out json),
+            $"Failed to extract {(glyphs ? "glyphs" : "words")} from page {pageNumber}");
+    }
+
+    /// <summary>
+    /// Chunks the document text with the fixed-size + overlap strategy of
+    /// <see cref="DocumentChunker.ChunkPdf(byte[])"/>.
+    /// </summary>
+    /// <param name="chunker">Chunker carrying the chunk size, overlap and language-detection flag.</param>
+    /// <returns>The chunks in sequence.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="chunker"/> is null.</exception>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If chunking fails.</exception>
+    public List<Ai.DocumentChunk> ChunkPdf(DocumentChunker chunker)
+    {
+        ArgumentNullException.ThrowIfNull(chunker);
+        return CallJson<List<Ai.DocumentChunk>>(
+            (IntPtr handle, out IntPtr json) => NativeMethods.oxidize_parsed_document_chunk_pdf(
+                handle,
+                (nuint)chunker.ChunkSize,
+                (nuint)chunker.Overlap,
+                (byte)(chunker.LanguageDetectionEnabled ? 1 : 0),
+                out json),
+            "Failed to chunk PDF");
+    }
+
+    // ── Pages ─────────────────────────────────────────────────────────────────
+
+    /// <summary>
+    /// Analyzes a page's content to determine if it is text, scanned, or mixed.
+    /// </summary>
+    /// <param name="pageNumber">Page number (1-based).</param>
+    /// <returns>The content analysis of the page.</returns>
+    /// <exception cref="ArgumentOutOfRangeException">If <paramref name="pageNumber"/> is less than 1.</exception>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If the page does not exist or analysis fails.</exception>
+    public ContentAnalysis AnalyzePageContent(int pageNumber)
+    {
+        ValidatePageNumber(pageNumber);
+        return CallJson<ContentAnalysis>(
+            (IntPtr handle, out IntPtr json) =>
+                NativeMethods.oxidize_parsed_document_analyze_page_content(handle, (nuint)pageNumber, out json),
+            $"Failed to analyze content for page {pageNumber}");
+    }
+
+    /// <summary>
+    /// Gets the resources (fonts, images, resource keys) of a page.
+    /// </summary>
+    /// <param name="pageNumber">Page number (1-based).</param>
+    /// <returns>The page resources.</returns>
+    /// <exception cref="ArgumentOutOfRangeException">If <paramref name="pageNumber"/> is less than 1.</exception>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If the page does not exist or the native call fails.</exception>
+    public PageResources GetPageResources(int pageNumber)
+    {
+        ValidatePageNumber(pageNumber);
+        return CallJson<PageResources>(
+            (IntPtr handle, out IntPtr json) =>
+                NativeMethods.oxidize_parsed_document_get_page_resources(handle, (nuint)pageNumber, out json),
+            $"Failed to get resources for page {pageNumber}");
+    }
+
+    /// <summary>
+    /// Gets the decoded content streams of a page.
+    /// </summary>
+    /// <param name="pageNumber">Page number (1-based).</param>
+    /// <returns>The page content streams, in drawing order.</returns>
+    /// <exception cref="ArgumentOutOfRangeException">If <paramref name="pageNumber"/> is less than 1.</exception>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If the page does not exist or the native call fails.</exception>
+    public PageContentStreams GetPageContentStream(int pageNumber)
+    {
+        ValidatePageNumber(pageNumber);
+        var result = CallJson<ContentStreamResult>(
+            (IntPtr handle, out IntPtr json) =>
+                NativeMethods.oxidize_parsed_document_get_page_content_stream(handle, (nuint)pageNumber, out json),
+            $"Failed to get content streams for page {pageNumber}");
+        return new PageContentStreams(result.Streams.Select(Convert.FromBase64String).ToList());
+    }
+
+    /// <summary>
+    /// Gets all annotations in the document.
+    /// </summary>
+    /// <returns>The annotations, page by page.</returns>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If the native call fails.</exception>
+    public List<PdfAnnotation> GetAnnotations() =>
+        CallJson<List<PdfAnnotation>>(NativeMethods.oxidize_parsed_document_get_annotations, "Failed to get annotations from PDF");
+
+    // ── Forms and signatures ──────────────────────────────────────────────────
+
+    /// <summary>
+    /// Gets whether the document has AcroForm fields.
+    /// </summary>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If the native call fails.</exception>
+    public bool HasFormFields
+    {
+        get
+        {
+            ThrowIfDisposed();
+            ThrowIfError(
+                NativeMethods.oxidize_parsed_document_has_form_fields(_handle, out var hasFields),
+                "Failed to check for form fields");
+            return hasFields;
+        }
+    }
+
+    /// <summary>
+    /// Gets the AcroForm fields of the document.
+    /// </summary>
+    /// <returns>The form fields.</returns>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If the native call fails.</exception>
+    public List<FormField> GetFormFields() =>
+        CallJson<List<FormField>>(NativeMethods.oxidize_parsed_document_get_form_fields, "Failed to get form fields");
+
+    /// <summary>
+    /// Gets whether the document contains digital signatures.
+    /// </summary>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If the native call fails.</exception>
+    public bool HasDigitalSignatures
+    {
+        get
+        {
+            ThrowIfDisposed();
+            ThrowIfError(
+                NativeMethods.oxidize_parsed_document_has_signatures(_handle, out var hasSignatures),
+                "Failed to check for digital signatures");
+            return hasSignatures;
+        }
+    }
+
+    /// <summary>
+    /// Gets the digital signature fields of the document.
+    /// </summary>
+    /// <returns>The signatures.</returns>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If the native call fails.</exception>
+    public List<DigitalSignature> GetDigitalSignatures() =>
+        CallJson<List<DigitalSignature>>(NativeMethods.oxidize_parsed_document_get_signatures, "Failed to get digital signatures");
+
+    /// <summary>
+    /// Verifies every digital signature of the document.
+    /// </summary>
+    /// <returns>One verification result per signature.</returns>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If the native call fails.</exception>
+    public List<SignatureVerificationResult> VerifySignatures() =>
+        CallJson<List<SignatureVerificationResult>>(NativeMethods.oxidize_parsed_document_verify_signatures, "Failed to verify signatures");
+
+    // ── Pipeline ──────────────────────────────────────────────────────────────
+
+    /// <summary>
+    /// Partitions the document into typed semantic elements (title, paragraph, table, etc.).
+    /// </summary>
+    /// <returns>List of semantic elements.</returns>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If partitioning fails.</exception>
+    public List<PdfElement> Partition() =>
+        CallJson<List<PdfElement>>(NativeMethods.oxidize_parsed_document_partition, "Failed to partition PDF");
+
+    /// <summary>
+    /// Partitions the document using a pre-configured <see cref="ExtractionProfile"/>.
+    /// </summary>
+    /// <param name="profile">Extraction profile selecting partitioner defaults.</param>
+    /// <returns>List of semantic elements for the chosen profile.</returns>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If partitioning fails or the profile is rejected by the FFI.</exception>
+    public List<PdfElement> Partition(ExtractionProfile profile) =>
+        CallJson<List<PdfElement>>(
+            (IntPtr handle, out IntPtr json) =>
+                NativeMethods.oxidize_parsed_document_partition_with_profile(handle, (byte)profile, out json),
+            $"Failed to partition PDF with profile {profile}");
+
+    /// <summary>
+    /// Partitions the document using an explicit <see cref="PartitionConfig"/>.
+    /// </summary>
+    /// <param name="config">Partition configuration, validated before the FFI call.</param>
+    /// <returns>List of semantic elements.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="config"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="config"/> fails validation.</exception>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If partitioning fails.</exception>
+    public List<PdfElement> Partition(PartitionConfig config)
+    {
+        ArgumentNullException.ThrowIfNull(config);
+        config.Validate();
+        var json = config.ToJson();
+        return CallJson<List<PdfElement>>(
+            (IntPtr handle, out IntPtr outJson) =>
+                NativeMethods.oxidize_parsed_document_partition_with_config(handle, json, out outJson),
+            "Failed to partition PDF with explicit PartitionConfig");
+    }
+
+    /// <summary>
+    /// Extracts structure-aware RAG chunks using the hybrid chunking pipeline.
+    /// </summary>
+    /// <returns>List of RAG-ready chunks.</returns>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If chunking fails.</exception>
+    public List<RagChunk> RagChunks() =>
+        CallJson<List<RagChunk>>(NativeMethods.oxidize_parsed_document_rag_chunks, "Failed to extract RAG chunks");
+
+    /// <summary>
+    /// Extracts RAG chunks using a pre-configured <see cref="ExtractionProfile"/>.
+    /// </summary>
+    /// <param name="profile">Extraction profile selecting partitioner defaults.</param>
+    /// <returns>List of RAG-ready chunks for the chosen profile.</returns>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If chunking fails or the profile is rejected by the FFI.</exception>
+    public List<RagChunk> RagChunks(ExtractionProfile profile) =>
+        CallJson<List<RagChunk>>(
+            (IntPtr handle, out IntPtr json) =>
+                NativeMethods.oxidize_parsed_document_rag_chunks_with_profile(handle, (byte)profile, out json),
+            $"Failed to extract RAG chunks with profile {profile}");
+
+    /// <summary>
+    /// Extracts RAG chunks with optional partition and hybrid chunk configs.
+    /// Pass <c>null</c> for either to use the corresponding upstream default.
+    /// </summary>
+    /// <param name="partitionConfig">Optional partition configuration.</param>
+    /// <param name="hybridConfig">Optional hybrid-chunker configuration.</param>
+    /// <returns>List of RAG-ready chunks.</returns>
+    /// <exception cref="ArgumentException">If either non-null config fails validation.</exception>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If chunking fails.</exception>
+    public List<RagChunk> RagChunks(PartitionConfig? partitionConfig, HybridChunkConfig? hybridConfig)
+    {
+        partitionConfig?.Validate();
+        hybridConfig?.Validate();
+        var partitionJson = partitionConfig?.ToJson();
+        var hybridJson = hybridConfig?.ToJson();
+        return CallJson<List<RagChunk>>(
+            (IntPtr handle, out IntPtr json) => NativeMethods.oxidize_parsed_document_rag_chunks_with_config(
+                handle, partitionJson, hybridJson, out json),
+            "Failed to extract RAG chunks with explicit configs");
+    }
+
+    /// <summary>
+    /// Extracts semantic (element-boundary-aware) chunks.
+    /// </summary>
+    /// <param name="config">Semantic-chunker configuration. <c>null</c> uses the upstream default.</param>
+    /// <param name="partitionConfig">Optional partition configuration. <c>null</c> uses the upstream default.</param>
+    /// <returns>List of semantic chunks.</returns>
+    /// <exception cref="ArgumentException">If either non-null config fails validation.</exception>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If chunking fails.</exception>
+    public List<SemanticChunk> SemanticChunks(
+        SemanticChunkConfig? config = null,
+        PartitionConfig? partitionConfig = null)
+    {
+        config ??= new SemanticChunkConfig();
+        config.Validate();
+        partitionConfig?.Validate();
+        var partitionJson = partitionConfig?.ToJson();
+        var semanticJson = config.ToJson();
+        return CallJson<List<SemanticChunk>>(
+            (IntPtr handle, out IntPtr json) => NativeMethods.oxidize_parsed_document_semantic_chunks(
+                handle, partitionJson, semanticJson, out json),
+            "Failed to extract semantic chunks");
+    }
+
+    /// <summary>
+    /// Extracts semantic chunks using a built-in or registered <see cref="ExtractionProfile"/>.
+    /// </summary>
+    /// <param name="profile">Extraction profile selecting partitioner and chunker defaults.</param>
+    /// <returns>List of semantic chunks for the chosen profile.</returns>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If chunking fails or the profile is rejected by the FFI.</exception>
+    public List<SemanticChunk> SemanticChunks(ExtractionProfile profile) =>
+        CallJson<List<SemanticChunk>>(
+            (IntPtr handle, out IntPtr json) =>
+                NativeMethods.oxidize_parsed_document_semantic_chunks_with_profile(handle, (byte)profile, out json),
+            $"Failed to extract semantic chunks with profile {profile}");
+
+    /// <summary>
+    /// Extracts the tables the partitioner detects, each as a grid of cells.
+    /// </summary>
+    /// <param name="config">Partition configuration controlling table detection;
+    /// <c>null</c> uses the upstream default.</param>
+    /// <returns>The detected tables in page order.</returns>
+    /// <exception cref="ArgumentException">If <paramref name="config"/> fails validation.</exception>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If extraction fails.</exception>
+    public List<PdfExtractedTable> ExtractTables(PartitionConfig? config = null)
+    {
+        config?.Validate();
+        var configJson = config?.ToJson();
+        return CallJson<List<PdfExtractedTable>>(
+            (IntPtr handle, out IntPtr json) =>
+                NativeMethods.oxidize_parsed_document_extract_tables(handle, configJson, out json),
+            "Failed to extract tables");
+    }
+
+    // ── Export ────────────────────────────────────────────────────────────────
+
+    /// <summary>
+    /// Exports the document as Markdown.
+    /// </summary>
+    /// <returns>Markdown representation of the document.</returns>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If export fails.</exception>
+    public string ToMarkdown() =>
+        CallString(NativeMethods.oxidize_parsed_document_to_markdown, "Failed to export PDF as markdown");
+
+    /// <summary>
+    /// Exports the document as Markdown using explicit <see cref="MarkdownOptions"/>.
+    /// </summary>
+    /// <param name="options">Markdown export options.</param>
+    /// <returns>Markdown representation of the document.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="options"/> is null.</exception>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If export fails.</exception>
+    public string ToMarkdown(MarkdownOptions options)
+    {
+        ArgumentNullException.ThrowIfNull(options);
+        var optionsJson = options.ToJson();
+        return CallString(
+            (IntPtr handle, out IntPtr text) =>
+                NativeMethods.oxidize_parsed_document_to_markdown_with_options(handle, optionsJson, out text),
+            "Failed to export PDF as markdown with explicit options");
+    }
+
+    /// <summary>
+    /// Exports the document in contextual format (optimized for LLM context windows).
+    /// </summary>
+    /// <returns>Contextual representation of the document.</returns>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If export fails.</exception>
+    public string ToContextual() =>
+        CallString(NativeMethods.oxidize_parsed_document_to_contextual, "Failed to export PDF as contextual");
+
+    /// <summary>
+    /// Exports the document as structured JSON.
+    /// </summary>
+    /// <returns>JSON representation of the document.</returns>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If export fails.</exception>
+    public string ToJson() =>
+        CallString(NativeMethods.oxidize_parsed_document_to_json, "Failed to export PDF as JSON");
+
+    // ── IDisposable ───────────────────────────────────────────────────────────
+
+    /// <inheritdoc/>
+    public void Dispose()
+    {
+        _safeHandle.Dispose();
+    }
+
+    // ── Helpers ───────────────────────────────────────────────────────────────
+
+    private delegate int NativeHandleCall(IntPtr handle, out IntPtr outPtr);
+
+    private static T WithPdfBuffer<T>(byte[] pdfBytes, Func<IntPtr, nuint, T> action)
+    {
+        IntPtr pdfPtr = IntPtr.Zero;
+        try
+        {
+            pdfPtr = Marshal.AllocHGlobal(pdfBytes.Length);
+            Marshal.Copy(pdfBytes, 0, pdfPtr, pdfBytes.Length);
+            return action(pdfPtr, (nuint)pdfBytes.Length);
+        }
+        finally
+        {
+            if (pdfPtr != IntPtr.Zero)
+                Marshal.FreeHGlobal(pdfPtr);
+        }
+    }
+
+    private T CallJson<T>(NativeHandleCall nativeCall, string errorMsg) where T : class, new()
+    {
+        var json = CallString(nativeCall, errorMsg);
+        return json.Length == 0 ? new T() : JsonSerializer.Deserialize<T>(json) ?? new T();
+    }
+
+    private string CallString(NativeHandleCall nativeCall, string errorMsg)
+    {
+        ThrowIfDisposed();
+        IntPtr ptr = IntPtr.Zero;
+        try
+        {
+            ThrowIfError(nativeCall(_handle, out ptr), errorMsg);
+            return Marshal.PtrToStringUTF8(ptr) ?? string.Empty;
+        }
+        finally
+        {
+            if (ptr != IntPtr.Zero)
+                NativeMethods.oxidize_free_string(ptr);
+        }
+    }
+
+    private static void ValidatePageNumber(int pageNumber)
+    {
+        if (pageNumber < 1)
+            throw new ArgumentOutOfRangeException(nameof(pageNumber), "Page number must be >= 1 (1-based indexing)");
+    }
+
+    private void ThrowIfDisposed()
+    {
+        if (_safeHandle.IsClosed)
+            throw new ObjectDisposedException(nameof(PdfParsedDocument));
+    }
+
+    private static void ThrowIfError(int errorCode, string message) =>
+        PdfExtractor.ThrowIfError(errorCode, message);
+}
@@ -0,0 +1,56 @@
+using System.Text.Json.Serialization;
+
+namespace OxidizePdf.NET;
+
+/// <summary>
+/// Process-wide limits on the PDFs the native library will process, applied
+/// with <see cref="PdfExtractor.SetResourceLimits"/>. A <c>null</c> limit is
+/// unlimited. Violations fail with a <see cref="PdfExtractionException"/>
+/// whose <see cref="PdfExtractionException.Details"/> category is
+/// <c>limit_exceeded</c>.
+/// </summary>
+public sealed class PdfResourceLimits
+{
+    /// <summary>
+    /// Maximum decoded size of a single stream, in bytes. Decompression bombs
+    /// are rejected without being expanded.
+    /// </summary>
+    [JsonPropertyName("max_stream_bytes")]
+    public long? MaxStreamBytes { get; init; }
+
+    /// <summary>Maximum number of indirect objects (trailer <c>/Size</c>).</summary>
+    [JsonPropertyName("max_objects")]
+    public int? MaxObjects { get; init; }
+
+    /// <summary>Maximum nesting of the page tree; the root <c>/Pages</c> node is depth 1.</summary>
+    [JsonPropertyName("max_page_tree_depth")]
+    public int? MaxPageTreeDepth { get; init; }
+
+    /// <summary>Maximum number of pages in a document.</summary>
+    [JsonPropertyName("max_pages")]
+    public int? MaxPages { get; init; }
+
+    /// <summary>
+    /// Wall-clock budget for one native call, in milliseconds, checked between
+    /// pages. A call that finishes in time returns the same result as without it.
+    /// </summary>
+    [JsonPropertyName("max_duration_ms")]
+    public long? MaxDurationMs { get; init; }
+
+    /// <summary>No limits. Equivalent to passing <c>null</c> to <see cref="PdfExtractor.SetResourceLimits"/>.</summary>
+    public static PdfResourceLimits Unlimited() => new();
+
+    internal void Validate()
+    {
+        if (MaxStreamBytes < 0)
+            throw new ArgumentOutOfRangeException(nameof(MaxStreamBytes), "MaxStreamBytes must be >= 0");
+        if (MaxObjects < 0)
+            throw new ArgumentOutOfRangeException(nameof(MaxObjects), "MaxObjects must be >= 0");
+        if (MaxPageTreeDepth < 0)
+            throw new ArgumentOutOfRangeException(nameof(MaxPageTreeDepth), "MaxPageTreeDepth must be >= 0");
+        if (MaxPages < 0)
+            throw new ArgumentOutOfRangeException(nameof(MaxPages), "MaxPages must be >= 0");
+        if (MaxDurationMs < 0)
+            throw new ArgumentOutOfRangeException(nameof(MaxDurationMs), "MaxDurationMs must be >= 0");
+    }
+}
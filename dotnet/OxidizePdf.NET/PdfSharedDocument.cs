@@ -0,0 +1,367 @@
+using System.Runtime.InteropServices;
+using System.Text.Json;
+using OxidizePdf.NET.Models;
+
+namespace OxidizePdf.NET;
+
+/// <summary>
+/// A read-only parsed PDF that several threads may use at once. Where a
+/// <see cref="PdfParsedDocument"/> must be used from one thread at a time, the
+/// native side of a <see cref="PdfSharedDocument"/> is a bounded pool of
+/// parsed readers, at most one per CPU: each call runs on a reader of its own
+/// and waits when all of them are busy.
+/// Implements <see cref="IDisposable"/> to ensure native resources are freed.
+/// </summary>
+/// <remarks>
+/// Disposing while calls are in progress is safe: the native handle is freed
+/// once the last of them returns, and later calls throw
+/// <see cref="ObjectDisposedException"/>.
+/// </remarks>
+/// <example>
+/// <code>
+/// using var doc = PdfSharedDocument.Open(pdfBytes);
+/// var pages = await Task.WhenAll(Enumerable.Range(1, doc.PageCount)
+///     .Select(n => Task.Run(() => doc.ExtractTextFromPage(n))));
+/// </code>
+/// </example>
+public sealed class PdfSharedDocument : IDisposable
+{
+    private readonly SharedDocumentSafeHandle _safeHandle;
+
+    private PdfSharedDocument(SharedDocumentSafeHandle safeHandle)
+    {
+        _safeHandle = safeHandle;
+    }
+
+    /// <summary>
+    /// Parses a PDF and keeps it open for concurrent reads. The bytes are
+    /// copied, so <paramref name="pdfBytes"/> may be reused once this returns.
+    /// </summary>
+    /// <param name="pdfBytes">PDF file content as byte array.</param>
+    /// <param name="password">Password (user or owner) for an encrypted file, or <c>null</c>.</param>
+    /// <returns>The shared document.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="pdfBytes"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="pdfBytes"/> is empty.</exception>
+    /// <exception cref="PdfExtractionException">If the PDF cannot be parsed, or the password is wrong.</exception>
+    public static PdfSharedDocument Open(byte[] pdfBytes, string? password = null)
+    {
+        ArgumentNullException.ThrowIfNull(pdfBytes);
+        if (pdfBytes.Length == 0)
+            throw new ArgumentException("PDF bytes cannot be empty", nameof(pdfBytes));
+
+        IntPtr pdfPtr = IntPtr.Zero;
+        try
+        {
+            pdfPtr = Marshal.AllocHGlobal(pdfBytes.Length);
+            Marshal.Copy(pdfBytes, 0, pdfPtr, pdfBytes.Length);
+            var result = NativeMethods.oxidize_shared_document_open(
+                pdfPtr, (nuint)pdfBytes.Length, password, out var handle);
+            PdfExtractor.ThrowIfError(result, "Failed to open PDF");
+            return new PdfSharedDocument(new SharedDocumentSafeHandle(handle));
+        }
+        finally
+        {
+            if (pdfPtr != IntPtr.Zero)
+                Marshal.FreeHGlobal(pdfPtr);
+        }
+    }
+
+    /// <summary>
+    /// Opens a PDF file for concurrent reads. The file is memory-mapped by the
+    /// native library; it must not be truncated while open.
+    /// </summary>
+    /// <param name="path">Path of the PDF file.</param>
+    /// <param name="password">Password (user or owner) for an encrypted file, or <c>null</c>.</param>
+    /// <returns>The shared document.</returns>
+    /// <exception cref="ArgumentNullException">If <paramref name="path"/> is null.</exception>
+    /// <exception cref="ArgumentException">If <paramref name="path"/> is empty.</exception>
+    /// <exception cref="PdfExtractionException">If the file cannot be read or parsed, or the password is wrong.</exception>
+    public static PdfSharedDocument OpenFile(string path, string? password = null)
+    {
+        ArgumentException.ThrowIfNullOrEmpty(path);
+
+        var result = NativeMethods.oxidize_shared_document_open_path(path, password, out var handle);
+        PdfExtractor.ThrowIfError(result, $"Failed to open '{path}'");
+        return new PdfSharedDocument(new SharedDocumentSafeHandle(handle));
+    }
+
+    // ── Document info ─────────────────────────────────────────────────────────
+
+    /// <summary>
+    /// Gets the number of pages in the document.
+    /// </summary>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If the native call fails.</exception>
+    public int PageCount => WithHandle(handle =>
+    {
+        ThrowIfError(
+            NativeMethods.oxidize_shared_document_get_page_count(handle, out var count),
+            "Failed to get page count");
+        return (int)count;
+    });
+
+    /// <summary>
+    /// Reads the document metadata (Info dictionary, version, page count).
+    /// </summary>
+    /// <returns>The document metadata.</returns>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If the native call fails.</exception>
+    public PdfMetadata GetMetadata() =>
+        CallJson<PdfMetadata>(NativeMethods.oxidize_shared_document_get_metadata, "Failed to extract metadata from PDF");
+
+    /// <summary>
+    /// Gets the dimensions of a page.
+    /// </summary>
+    /// <param name="pageNumber">Page number (1-based).</param>
+    /// <returns>A tuple with the page width and height in PDF points.</returns>
+    /// <exception cref="ArgumentOutOfRangeException">If <paramref name="pageNumber"/> is less than 1.</exception>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If the page does not exist or the native call fails.</exception>
+    public (double Width, double Height) GetPageDimensions(int pageNumber)
+    {
+        ValidatePageNumber(pageNumber);
+        return WithHandle(handle =>
+        {
+            ThrowIfError(
+                NativeMethods.oxidize_shared_document_get_page_dimensions(
+                    handle, (nuint)pageNumber, out var width, out var height),
+                $"Failed to get dimensions for page {pageNumber}");
+            return (width, height);
+        });
+    }
+
+    // ── Text ──────────────────────────────────────────────────────────────────
+
+    /// <summary>
+    /// Extracts plain text from all pages.
+    /// </summary>
+    /// <returns>Extracted plain text.</returns>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If extraction fails.</exception>
+    public string ExtractText() =>
+        CallString(NativeMethods.oxidize_shared_document_extract_text, "Failed to extract text from PDF");
+
+    /// <summary>
+    /// Extracts plain text from a single page.
+    /// </summary>
+    /// <param name="pageNumber">Page number (1-based).</param>
+    /// <returns>Extracted plain text from the page.</returns>
+    /// <exception cref="ArgumentOutOfRangeException">If <paramref name="pageNumber"/> is less than 1.</exception>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If the page does not exist or extraction fails.</exception>
+    public string ExtractTextFromPage(int pageNumber)
+    {
+        ValidatePageNumber(pageNumber);
+        return CallString(
+            (IntPtr handle, out IntPtr text) =>
+                NativeMethods.oxidize_shared_document_extract_text_from_page(handle, (nuint)pageNumber, out text),
+            $"Failed to extract text from page {pageNumber}");
+    }
+
+    /// <summary>
+    /// Extracts the words of a page with their bounding box, font, fill colour,
+    /// rendering mode and baseline rotation, in content-stream order.
+    /// </summary>
+    /// <param name="pageNumber">Page number (1-based).</param>
+    /// <returns>The words of the page.</returns>
+    /// <exception cref="ArgumentOutOfRangeException">If <paramref name="pageNumber"/> is less than 1.</exception>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If the page does not exist or uses an unsupported composite font encoding.</exception>
+    public List<PdfTextItem> ExtractWords(int pageNumber) => ExtractTextItems(pageNumber, glyphs: false);
+
+    /// <summary>
+    /// Extracts every glyph of a page, whitespace included, with the same
+    /// geometry and style as <see cref="ExtractWords"/>.
+    /// </summary>
+    /// <param name="pageNumber">Page number (1-based).</param>
+    /// <returns>The glyphs of the page.</returns>
+    /// <exception cref="ArgumentOutOfRangeException">If <paramref name="pageNumber"/> is less than 1.</exception>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If the page does not exist or uses an unsupported composite font encoding.</exception>
+    public List<PdfTextItem> ExtractGlyphs(int pageNumber) => ExtractTextItems(pageNumber, glyphs: true);
+
+    private List<PdfTextItem> ExtractTextItems(int pageNumber, bool glyphs)
+    {
+        ValidatePageNumber(pageNumber);
+        return CallJson<List<PdfTextItem>>(
+            (IntPtr handle, out IntPtr json) => NativeMethods.oxidize_shared_document_extract_words(
+                handle, (nuint)pageNumber, (byte)(glyphs ? 1 : 0), out json),
+            $"Failed to extract {(glyphs ? "glyphs" : "words")} from page {pageNumber}");
+    }
+
+    // ── Pages ─────────────────────────────────────────────────────────────────
+
+    /// <summary>
+    /// Analyzes a page's content to determine if it is text, scanned, or mixed.
+    /// </summary>
+    /// <param name="pageNumber">Page number (1-based).</param>
+    /// <returns>The content analysis of the page.</returns>
+    /// <exception cref="ArgumentOutOfRangeException">If <paramref name="pageNumber"/> is less than 1.</exception>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If the page does not exist or analysis fails.</exception>
+    public ContentAnalysis AnalyzePageContent(int pageNumber)
+    {
+        ValidatePageNumber(pageNumber);
+        return CallJson<ContentAnalysis>(
+            (IntPtr handle, out IntPtr json) =>
+                NativeMethods.oxidize_shared_document_analyze_page_content(handle, (nuint)pageNumber, out json),
+            $"Failed to analyze content for page {pageNumber}");
+    }
+
+    /// <summary>
+    /// Gets the resources (fonts, images, resource keys) of a page.
+    /// </summary>
+    /// <param name="pageNumber">Page number (1-based).</param>
+    /// <returns>The page resources.</returns>
+    /// <exception cref="ArgumentOutOfRangeException">If <paramref name="pageNumber"/> is less than 1.</exception>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If the page does not exist or the native call fails.</exception>
+    public PageResources GetPageResources(int pageNumber)
+    {
+        ValidatePageNumber(pageNumber);
+        return CallJson<PageResources>(
+            (IntPtr handle, out IntPtr json) =>
+                NativeMethods.oxidize_shared_document_get_page_resources(handle, (nuint)pageNumber, out json),
+            $"Failed to get resources for page {pageNumber}");
+    }
+
+    /// <summary>
+    /// Gets the decoded content streams of a page.
+    /// </summary>
+    /// <param name="pageNumber">Page number (1-based).</param>
+    /// <returns>The page content streams, in drawing order.</returns>
+    /// <exception cref="ArgumentOutOfRangeException">If <paramref name="pageNumber"/> is less than 1.</exception>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If the page does not exist or the native call fails.</exception>
+    public PageContentStreams GetPageContentStream(int pageNumber)
+    {
+        ValidatePageNumber(pageNumber);
+        var result = CallJson<ContentStreamResult>(
+            (IntPtr handle, out IntPtr json) =>
+                NativeMethods.oxidize_shared_document_get_page_content_stream(handle, (nuint)pageNumber, out json),
+            $"Failed to get content streams for page {pageNumber}");
+        return new PageContentStreams(result.Streams.Select(Convert.FromBase64String).ToList());
+    }
+
+    /// <summary>
+    /// Gets all annotations in the document.
+    /// </summary>
+    /// <returns>The annotations, page by page.</returns>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If the native call fails.</exception>
+    public List<PdfAnnotation> GetAnnotations() =>
+        CallJson<List<PdfAnnotation>>(NativeMethods.oxidize_shared_document_get_annotations, "Failed to get annotations from PDF");
+
+    /// <summary>
+    /// Converts a page into a writable page that keeps the original content
+    /// streams and resources, like <see cref="PdfPage.FromParsedBytes"/>
+    /// without parsing the bytes again.
+    /// </summary>
+    /// <param name="pageIndex">Zero-based index of the page.</param>
+    /// <returns>A writable <see cref="PdfPage"/> seeded with the original content.</returns>
+    /// <exception cref="ArgumentOutOfRangeException">If <paramref name="pageIndex"/> is negative.</exception>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If the page does not exist or conversion fails.</exception>
+    public PdfPage GetWritablePage(int pageIndex)
+    {
+        if (pageIndex < 0)
+            throw new ArgumentOutOfRangeException(nameof(pageIndex), pageIndex, "Page index must be non-negative.");
+
+        return WithHandle(handle =>
+        {
+            var page = NativeMethods.oxidize_shared_document_page_to_writable(handle, (uint)pageIndex);
+            if (page == IntPtr.Zero)
+                throw new PdfExtractionException(
+                    $"Failed to create editable page {pageIndex}: {NativeMethods.GetLastError()}",
+                    NativeMethods.GetLastErrorDetails());
+            return new PdfPage(page);
+        });
+    }
+
+    // ── Pipeline ──────────────────────────────────────────────────────────────
+
+    /// <summary>
+    /// Partitions the document into typed semantic elements (title, paragraph, table, etc.).
+    /// </summary>
+    /// <returns>List of semantic elements.</returns>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If partitioning fails.</exception>
+    public List<PdfElement> Partition() =>
+        CallJson<List<PdfElement>>(NativeMethods.oxidize_shared_document_partition, "Failed to partition PDF");
+
+    /// <summary>
+    /// Extracts structure-aware RAG chunks using the hybrid chunking pipeline.
+    /// </summary>
+    /// <returns>List of RAG-ready chunks.</returns>
+    /// <exception cref="ObjectDisposedException">If this document has been disposed.</exception>
+    /// <exception cref="PdfExtractionException">If chunking fails.</exception>
+    public List<RagChunk> RagChunks() =>
+        CallJson<List<RagChunk>>(NativeMethods.oxidize_shared_document_rag_chunks, "Failed to extract RAG chunks");
+
+    // ── IDisposable ───────────────────────────────────────────────────────────
+
+    /// <inheritdoc/>
+    public void Dispose()
+    {
+        _safeHandle.Dispose();
+    }
+
+    // ── Helpers ───────────────────────────────────────────────────────────────
+
+    private delegate int NativeHandleCall(IntPtr handle, out IntPtr outPtr);
+
+    // Holds a reference on the safe handle for the duration of the call, so a
+    // concurrent Dispose defers the native free until the call has returned.
+    private T WithHandle<T>(Func<IntPtr, T> action)
+    {
+        var added = false;
+        try
+        {
+            _safeHandle.DangerousAddRef(ref added);
+        }
+        catch (ObjectDisposedException)
+        {
+            throw new ObjectDisposedException(nameof(PdfSharedDocument));
+        }
+
+        try
+        {
+            return action(_safeHandle.DangerousGetHandle());
+        }
+        finally
+        {
+            if (added)
+                _safeHandle.DangerousRelease();
+        }
+    }
+
+    private T CallJson<T>(NativeHandleCall nativeCall, string errorMsg) where T : class, new()
+    {
+        var json = CallString(nativeCall, errorMsg);
+        return json.Length == 0 ? new T() : JsonSerializer.Deserialize<T>(json) ?? new T();
+    }
+
+    private string CallString(NativeHandleCall nativeCall, string errorMsg) => WithHandle(handle =>
+    {
+        IntPtr ptr = IntPtr.Zero;
+        try
+        {
+            ThrowIfError(nativeCall(handle, out ptr), errorMsg);
+            return Marshal.PtrToStringUTF8(ptr) ?? string.Empty;
+        }
+        finally
+        {
+            if (ptr != IntPtr.Zero)
+                NativeMethods.oxidize_free_string(ptr);
+        }
+    });
+
+    private static void ValidatePageNumber(int pageNumber)
+    {
+        if (pageNumber < 1)
+            throw new ArgumentOutOfRangeException(nameof(pageNumber), "Page number must be >= 1 (1-based indexing)");
+    }
+
+    private static void ThrowIfError(int errorCode, string message) =>
+        PdfExtractor.ThrowIfError(errorCode, message);
+}
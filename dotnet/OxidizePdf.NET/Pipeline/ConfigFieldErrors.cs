@@ -0,0 +1,70 @@
+using System.Globalization;
+
+namespace OxidizePdf.NET.Pipeline;
+
+/// <summary>
+/// Collects every invalid field of one config, using the same paths and
+/// wording as the native validation (<c>$.header_zone must be a number in
+/// [0, 1], got 1.5</c>), and throws them together.
+/// </summary>
+internal sealed class ConfigFieldErrors
+{
+    private readonly List<string> _errors = new();
+
+    /// <summary>A number that must be &gt; 0.</summary>
+    public void Positive(string path, double value)
+    {
+        if (!(value > 0))
+            Fail(path, "must be a number > 0", value);
+    }
+
+    /// <summary>A number that must be &gt;= 0.</summary>
+    public void NonNegative(string path, double value)
+    {
+        if (!(value >= 0))
+            Fail(path, "must be a number >= 0", value);
+    }
+
+    /// <summary>A number that must lie in [0, 1].</summary>
+    public void Fraction(string path, double value)
+    {
+        if (!(value >= 0 && value <= 1))
+            Fail(path, "must be a number in [0, 1]", value);
+    }
+
+    /// <summary>
+    /// A size (&gt;= 1) and an overlap (&gt;= 0) with the overlap below the size.
+    /// </summary>
+    public void Budget(string sizePath, int size, string overlapPath, int overlap)
+    {
+        if (size < 1)
+            _errors.Add($"{sizePath} must be an integer >= 1, got {size}");
+        if (overlap < 0)
+            _errors.Add($"{overlapPath} must be an integer >= 0, got {overlap}");
+        else if (size >= 1 && overlap >= size)
+            _errors.Add($"{overlapPath} must be less than {sizePath[2..]} ({size}), got {overlap}");
+    }
+
+    /// <summary>An enum value that must be one of the defined members.</summary>
+    public void Defined<T>(string path, T value) where T : struct, Enum
+    {
+        if (!Enum.IsDefined(value))
+        {
+            var accepted = string.Join(", ", Enum.GetNames<T>().Select(n => $"\"{n}\""));
+            _errors.Add($"{path} must be one of {accepted}, got {value}");
+        }
+    }
+
+    /// <summary>
+    /// Throw an <see cref="ArgumentException"/> listing every recorded error,
+    /// joined by <c>"; "</c>; do nothing when the config is valid.
+    /// </summary>
+    public void ThrowIfAny()
+    {
+        if (_errors.Count > 0)
+            throw new ArgumentException(string.Join("; ", _errors));
+    }
+
+    private void Fail(string path, string accepted, double value) =>
+        _errors.Add($"{path} {accepted}, got {value.ToString(CultureInfo.InvariantCulture)}");
+}
@@ -0,0 +1,59 @@
+using System.Text.Json.Nodes;
+using OxidizePdf.NET.Models;
+
+namespace OxidizePdf.NET.Pipeline;
+
+/// <summary>
+/// A named profile registered with <see cref="PdfExtractor.RegisterProfile"/>.
+/// Bundles text extraction options, a partition config and the hybrid and
+/// semantic chunk configs; every section is optional and falls back to the
+/// native default when <c>null</c>.
+/// </summary>
+/// <remarks>
+/// When <see cref="Extraction"/> is set every option is sent, so unset
+/// properties take the <see cref="ExtractionOptions"/> defaults rather than the
+/// partitioner's own (layout preservation and paragraph reconstruction on).
+/// </remarks>
+public class CustomExtractionProfile
+{
+    /// <summary>Text extraction options used while partitioning. Default <c>null</c>.</summary>
+    public ExtractionOptions? Extraction { get; set; }
+
+    /// <summary>Partition configuration. Default <c>null</c> (upstream <c>PartitionConfig::default()</c>).</summary>
+    public PartitionConfig? Partition { get; set; }
+
+    /// <summary>Hybrid chunk configuration used by RAG chunking. Default <c>null</c>.</summary>
+    public HybridChunkConfig? Hybrid { get; set; }
+
+    /// <summary>Semantic chunk configuration used by semantic chunking. Default <c>null</c>.</summary>
+    public SemanticChunkConfig? Semantic { get; set; }
+
+    /// <summary>
+    /// Validate every non-null section. Throws <see cref="ArgumentException"/> if a field is out of range.
+    /// </summary>
+    public void Validate()
+    {
+        Extraction?.Validate();
+        Partition?.Validate();
+        Hybrid?.Validate();
+        Semantic?.Validate();
+    }
+
+    /// <summary>
+    /// Serialize this profile to the <c>profile_json</c> shape of
+    /// <c>oxidize_register_profile</c>, omitting <c>null</c> sections.
+    /// </summary>
+    public string ToJson()
+    {
+        var root = new JsonObject();
+        if (Extraction is not null)
+            root["extraction"] = Extraction.ToJsonNode();
+        if (Partition is not null)
+            root["partition"] = JsonNode.Parse(Partition.ToJson());
+        if (Hybrid is not null)
+            root["hybrid"] = JsonNode.Parse(Hybrid.ToJson());
+        if (Semantic is not null)
+            root["semantic"] = JsonNode.Parse(Semantic.ToJson());
+        return root.ToJsonString();
+    }
+}
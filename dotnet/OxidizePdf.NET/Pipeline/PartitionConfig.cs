@@ -91,23 +91,21 @@ public class PartitionConfig
     }
 
     /// <summary>
-    /// Validate this configuration. Throws <see cref="ArgumentException"/> if any field is out of range.
+    /// Validate this configuration. Throws <see cref="ArgumentException"/> listing every
+    /// field that is out of range, e.g. <c>$.header_zone must be a number in [0, 1], got 1.5</c>.
     /// </summary>
     /// <remarks>
-    /// This is a C#-side preflight. The Rust core <c>oxidize_pdf::pipeline::PartitionConfig</c>
-    /// has no validation of its own; values outside sensible ranges may produce unexpected
-    /// partitioning behaviour rather than a crash.
+    /// This is a C#-side preflight. The native layer checks the same ranges on the JSON it
+    /// receives and reports the same paths as an <c>InvalidArgument</c> error.
     /// </remarks>
     public void Validate()
     {
-        if (TitleMinFontRatio <= 0)
-            throw new ArgumentException("TitleMinFontRatio must be positive");
-        if (HeaderZone < 0 || HeaderZone > 1)
-            throw new ArgumentException("HeaderZone must be in [0, 1]");
-        if (FooterZone < 0 || FooterZone > 1)
-            throw new ArgumentException("FooterZone must be in [0, 1]");
-        if (MinTableConfidence < 0 || MinTableConfidence > 1)
-            throw new ArgumentException("MinTableConfidence must be in [0, 1]");
+        var errors = new ConfigFieldErrors();
+        errors.Positive("$.title_min_font_ratio", TitleMinFontRatio);
+        errors.Fraction("$.header_zone", HeaderZone);
+        errors.Fraction("$.footer_zone", FooterZone);
+        errors.Fraction("$.min_table_confidence", MinTableConfidence);
+        errors.ThrowIfAny();
     }
 
     /// <summary>Serialize this configuration to JSON using <see cref="JsonOptions"/>.</summary>
@@ -41,19 +41,18 @@ public class SemanticChunkConfig
     public SemanticChunkConfig WithOverlap(int n) { OverlapTokens = n; return this; }
 
     /// <summary>
-    /// Validate this configuration. Throws <see cref="ArgumentException"/> if any field is out of range.
+    /// Validate this configuration. Throws <see cref="ArgumentException"/> listing every
+    /// field that is out of range.
     /// </summary>
     /// <remarks>
-    /// This is a C#-side preflight. The Rust core has no validation of its own.
+    /// This is a C#-side preflight. The native layer checks the same rules on the JSON it
+    /// receives and reports the same paths as an <c>InvalidArgument</c> error.
     /// </remarks>
     public void Validate()
     {
-        if (MaxTokens <= 0)
-            throw new ArgumentException("MaxTokens must be positive");
-        if (OverlapTokens < 0)
-            throw new ArgumentException("OverlapTokens must be non-negative");
-        if (OverlapTokens >= MaxTokens)
-            throw new ArgumentException("OverlapTokens must be less than MaxTokens");
+        var errors = new ConfigFieldErrors();
+        errors.Budget("$.max_tokens", MaxTokens, "$.overlap_tokens", OverlapTokens);
+        errors.ThrowIfAny();
     }
 
     /// <summary>Serialize this configuration to JSON using <see cref="JsonOptions"/>.</summary>
@@ -115,3 +115,52 @@ internal sealed class DocumentBuilderSafeHandle : OxidizeSafeHandle
         return true;
     }
 }
+
+internal sealed class ParsedDocumentSafeHandle : OxidizeSafeHandle
+{
+    // Backs a handle opened over read callbacks: released only after the
+    // native free, since the handle calls back into it until then.
+    private readonly IDisposable? _source;
+
+    public ParsedDocumentSafeHandle() { }
+
+    public ParsedDocumentSafeHandle(IntPtr existing) : base(existing) { }
+
+    public ParsedDocumentSafeHandle(IntPtr existing, IDisposable source) : base(existing)
+    {
+        _source = source;
+    }
+
+    protected override bool ReleaseHandle()
+    {
+        NativeMethods.oxidize_parsed_document_free(handle);
+        _source?.Dispose();
+        return true;
+    }
+}
+
+internal sealed class SharedDocumentSafeHandle : OxidizeSafeHandle
+{
+    public SharedDocumentSafeHandle() { }
+
+    public SharedDocumentSafeHandle(IntPtr existing) : base(existing) { }
+
+    protected override bool ReleaseHandle()
+    {
+        NativeMethods.oxidize_shared_document_free(handle);
+        return true;
+    }
+}
+
+internal sealed class ChunkStreamSafeHandle : OxidizeSafeHandle
+{
+    public ChunkStreamSafeHandle() { }
+
+    public ChunkStreamSafeHandle(IntPtr existing) : base(existing) { }
+
+    protected override bool ReleaseHandle()
+    {
+        NativeMethods.oxidize_chunk_stream_free(handle);
+        return true;
+    }
+}
@@ -0,0 +1,99 @@
+using System.Runtime.InteropServices;
+
+namespace OxidizePdf.NET;
+
+/// <summary>
+/// Bridges a readable, seekable <see cref="Stream"/> to the native
+/// <c>ReadCallbacks</c> of <c>oxidize_parsed_document_open_callbacks</c>.
+/// <c>UserData</c> is a <see cref="GCHandle"/> to this instance and the
+/// function pointers come from static delegates, so both stay valid until
+/// <see cref="Dispose"/>, which the owning <see cref="ParsedDocumentSafeHandle"/>
+/// calls only after the native handle is freed.
+/// </summary>
+internal sealed class StreamReadCallbacks : IDisposable
+{
+    private static readonly NativeMethods.ReadCallback ReadThunk = Read;
+    private static readonly NativeMethods.SeekCallback SeekThunk = Seek;
+    private static readonly NativeMethods.LengthCallback LengthThunk = Length;
+
+    private readonly Stream _stream;
+    private GCHandle _self;
+
+    internal StreamReadCallbacks(Stream stream)
+    {
+        _stream = stream;
+        _self = GCHandle.Alloc(this);
+    }
+
+    /// <summary>
+    /// The first exception thrown by the stream, if any. The callback that
+    /// caught it reported an error to the native side instead.
+    /// </summary>
+    internal Exception? Failure { get; private set; }
+
+    internal NativeMethods.ReadCallbacksNative ToNative() => new()
+    {
+        UserData = GCHandle.ToIntPtr(_self),
+        Read = Marshal.GetFunctionPointerForDelegate(ReadThunk),
+        Seek = Marshal.GetFunctionPointerForDelegate(SeekThunk),
+        Length = Marshal.GetFunctionPointerForDelegate(LengthThunk),
+    };
+
+    public void Dispose()
+    {
+        if (_self.IsAllocated)
+            _self.Free();
+    }
+
+    private static StreamReadCallbacks FromUserData(IntPtr userData) =>
+        (StreamReadCallbacks)GCHandle.FromIntPtr(userData).Target!;
+
+    // Exceptions must not unwind through native frames: each callback records
+    // the first one and returns an error code instead.
+
+    private static unsafe nint Read(IntPtr userData, IntPtr buf, nuint len)
+    {
+        var self = FromUserData(userData);
+        try
+        {
+            var span = new Span<byte>((void*)buf, (int)Math.Min(len, (nuint)int.MaxValue));
+            return self._stream.Read(span);
+        }
+        catch (Exception ex)
+        {
+            self.Failure ??= ex;
+            return -1;
+        }
+    }
+
+    private static int Seek(IntPtr userData, ulong offset)
+    {
+        var self = FromUserData(userData);
+        try
+        {
+            if (offset > long.MaxValue)
+                return -1;
+            self._stream.Position = (long)offset;
+            return 0;
+        }
+        catch (Exception ex)
+        {
+            self.Failure ??= ex;
+            return -1;
+        }
+    }
+
+    private static long Length(IntPtr userData)
+    {
+        var self = FromUserData(userData);
+        try
+        {
+            return self._stream.Length;
+        }
+        catch (Exception ex)
+        {
+            self.Failure ??= ex;
+            return -1;
+        }
+    }
+}
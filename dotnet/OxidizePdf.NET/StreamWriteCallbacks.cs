@@ -0,0 +1,86 @@
+using System.Runtime.ExceptionServices;
+using System.Runtime.InteropServices;
+
+namespace OxidizePdf.NET;
+
+/// <summary>
+/// Bridges a writable <see cref="Stream"/> to the native <c>WriteCallbacks</c>
+/// of the <c>oxidize_document_save_to_callbacks*</c> calls. The delegates are
+/// rooted by this instance for the duration of <see cref="Call"/>.
+/// </summary>
+internal sealed class StreamWriteCallbacks
+{
+    internal delegate int NativeCall(ref NativeMethods.WriteCallbacksNative callbacks);
+
+    private readonly Stream _stream;
+    private readonly NativeMethods.WriteCallback _write;
+    private readonly NativeMethods.FlushCallback _flush;
+    private Exception? _callbackFailure;
+
+    internal StreamWriteCallbacks(Stream stream)
+    {
+        _stream = stream;
+        _write = Write;
+        _flush = Flush;
+    }
+
+    /// <summary>
+    /// Runs <paramref name="call"/> with the callbacks and maps its result:
+    /// an exception thrown by the stream is rethrown, and any other error
+    /// becomes <see cref="PdfExtractionException"/>.
+    /// </summary>
+    internal void Call(NativeCall call, string errorMsg)
+    {
+        var callbacks = new NativeMethods.WriteCallbacksNative
+        {
+            UserData = IntPtr.Zero,
+            Write = Marshal.GetFunctionPointerForDelegate(_write),
+            Flush = Marshal.GetFunctionPointerForDelegate(_flush),
+        };
+
+        var result = call(ref callbacks);
+        GC.KeepAlive(this);
+
+        if (_callbackFailure is not null)
+            ExceptionDispatchInfo.Throw(_callbackFailure);
+        PdfExtractor.ThrowIfError(result, errorMsg);
+    }
+
+    // Exceptions must not unwind through native frames: record the first one
+    // and fail the write, then rethrow it from Call.
+
+    private unsafe int Write(IntPtr userData, IntPtr buf, nuint len)
+    {
+        try
+        {
+            var src = (byte*)buf;
+            while (len > 0)
+            {
+                var chunk = (int)Math.Min(len, (nuint)int.MaxValue);
+                _stream.Write(new ReadOnlySpan<byte>(src, chunk));
+                src += chunk;
+                len -= (nuint)chunk;
+            }
+            return 0;
+        }
+        catch (Exception ex)
+        {
+            _callbackFailure ??= ex;
+            return -1;
+        }
+    }
+
+    private int Flush(IntPtr userData)
+    {
+        try
+        {
+            _stream.Flush();
+            return 0;
+        }
+        catch (Exception ex)
+        {
+            _callbackFailure ??= ex;
+            return -1;
+        }
+    }
+}
@@ -0,0 +1,561 @@
+//! Batch processing of many PDFs in one call.
+//!
+//! [`oxidize_process_batch`] takes a manifest naming one operation and a list
+//! of inputs (a file path or a base64 buffer, plus per-file options) and runs
+//! the operation over every input on a pool of native worker threads. Each
+//! file is processed exactly as the matching single-file entry point would
+//! process it — same output, same error codes and details — and a failing
+//! or panicking file is reported in its slot without affecting the others.
+//!
+//! Every file runs under its own FFI guard, so the wall-clock budget
+//! (`max_duration_ms`) and the other resource limits apply per file.
+
+use std::ffi::CString;
+use std::io::Cursor;
+use std::os::raw::{c_char, c_int};
+use std::path::Path;
+use std::ptr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use base64::Engine as _;
+use oxidize_pdf::parser::PdfDocument;
+use oxidize_pdf::text::ExtractionOptions;
+use serde::{Deserialize, Serialize};
+use serde_json::value::RawValue;
+
+use crate::chunking::chunk_pdf_impl;
+use crate::error_detail::{ErrorCategory, LastError};
+use crate::mapped_file::{map_path, SharedBytes};
+use crate::parallel::{run_workers, worker_count};
+use crate::parser::{
+    extract_text_impl, extract_text_with_options_impl, open_lenient, open_with_password,
+    parse_config_arg, partition_impl, partition_with_config_impl, partition_with_profile_impl,
+    rag_chunks_impl, rag_chunks_with_config_impl, rag_chunks_with_profile_impl,
+};
+use crate::pipeline_config::{
+    ExtractionOptionsDto, Fields, HybridChunkConfigDto, PartitionConfigDto, ValidatedConfig,
+};
+use crate::profiles::Profile;
+use crate::progress::Progress;
+use crate::{clear_last_error, set_last_error, take_last_error, ErrorCode};
+
+/// Operation names accepted in `operation`, in [`Operation`] order.
+const OPERATIONS: [&str; 4] = ["extract_text", "partition", "rag_chunks", "chunk"];
+
+/// What a batch runs over each input.
+#[derive(Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Operation {
+    /// `oxidize_extract_text` / `oxidize_extract_text_with_options`.
+    ExtractText,
+    /// `oxidize_partition` and its `_with_profile` / `_with_config` variants.
+    Partition,
+    /// `oxidize_rag_chunks` and its `_with_profile` / `_with_config` variants.
+    RagChunks,
+    /// `oxidize_chunk_pdf`.
+    Chunk,
+}
+
+impl Operation {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "extract_text" => Operation::ExtractText,
+            "partition" => Operation::Partition,
+            "rag_chunks" => Operation::RagChunks,
+            "chunk" => Operation::Chunk,
+            _ => return None,
+        })
+    }
+}
+
+/// `manifest_json` of [`oxidize_process_batch`].
+#[derive(Deserialize)]
+pub(crate) struct BatchManifest {
+    operation: Operation,
+    inputs: Vec<BatchInput>,
+}
+
+impl ValidatedConfig for BatchManifest {
+    fn check(f: &mut Fields<'_>) {
+        f.only(&["operation", "inputs"]);
+        let operation = f.one_of("operation", &OPERATIONS);
+        let operation = operation.and_then(Operation::from_name);
+        f.items("inputs", |input| BatchInput::check(input, operation));
+    }
+}
+
+/// One file of a batch: its source and the options for the operation.
+#[derive(Deserialize)]
+pub(crate) struct BatchInput {
+    path: Option<String>,
+    /// Base64-encoded PDF bytes.
+    pdf: Option<String>,
+    password: Option<String>,
+    profile: Option<u8>,
+    extraction: Option<ExtractionOptionsDto>,
+    partition_config: Option<PartitionConfigDto>,
+    hybrid_config: Option<HybridChunkConfigDto>,
+    chunk_size: Option<usize>,
+    overlap: Option<usize>,
+    detect_language: Option<bool>,
+}
+
+impl BatchInput {
+    /// Validate an input object; option fields are checked against
+    /// `operation` when it is valid.
+    fn check(f: &mut Fields<'_>, operation: Option<Operation>) {
+        f.exclusive(&["path", "pdf"], true);
+        f.string("path", false);
+        f.string("pdf", false);
+        f.string("password", false);
+        let mut accepted = vec!["path", "pdf", "password"];
+        match operation {
+            Some(Operation::ExtractText) => {
+                accepted.push("extraction");
+                f.section::<ExtractionOptionsDto>("extraction");
+            }
+            Some(Operation::Partition) => {
+                accepted.extend(["profile", "partition_config"]);
+                f.exclusive(&["profile", "partition_config"], false);
+                f.optional_byte("profile");
+                f.section::<PartitionConfigDto>("partition_config");
+            }
+            Some(Operation::RagChunks) => {
+                accepted.extend(["profile", "partition_config", "hybrid_config"]);
+                f.exclusive(&["profile", "partition_config"], false);
+                f.exclusive(&["profile", "hybrid_config"], false);
+                f.optional_byte("profile");
+                f.section::<PartitionConfigDto>("partition_config");
+                f.section::<HybridChunkConfigDto>("hybrid_config");
+            }
+            Some(Operation::Chunk) => {
+                accepted.extend(["chunk_size", "overlap", "detect_language"]);
+                f.budget("chunk_size", "overlap");
+                f.boolean("detect_language", false);
+            }
+            None => return,
+        }
+        f.only(&accepted);
+    }
+
+    /// Open the input as a document, unlocking it when a password is given.
+    fn open(&self) -> Result<PdfDocument<Cursor<SharedBytes>>, c_int> {
+        let bytes = match (&self.path, &self.pdf) {
+            (Some(path), _) => map_path(Path::new(path)).map_err(|(code, e)| {
+                set_last_error(e);
+                code as c_int
+            })?,
+            (None, Some(pdf)) => {
+                let decoded = base64::engine::general_purpose::STANDARD
+                    .decode(pdf)
+                    .map_err(|e| {
+                        set_last_error(format!("Failed to decode pdf: {e}"));
+                        ErrorCode::PdfParseError as c_int
+                    })?;
+                if decoded.is_empty() {
+                    set_last_error("PDF data is empty (0 bytes)");
+                    return Err(ErrorCode::PdfParseError as c_int);
+                }
+                SharedBytes::Owned(Arc::from(decoded))
+            }
+            (None, None) => {
+                set_last_error(LastError::new(
+                    ErrorCategory::InvalidArgument,
+                    "input needs a path or pdf",
+                ));
+                return Err(ErrorCode::InvalidArgument as c_int);
+            }
+        };
+        match &self.password {
+            Some(password) => open_with_password(bytes, password),
+            None => open_lenient(bytes),
+        }
+        .map(PdfDocument::new)
+        .map_err(|e| {
+            set_last_error(e);
+            ErrorCode::PdfParseError as c_int
+        })
+    }
+
+    /// Run `operation` through the single-file entry point's body and return
+    /// its output as JSON (text results become JSON strings).
+    unsafe fn run(self, operation: Operation) -> Result<Box<RawValue>, c_int> {
+        let document = self.open()?;
+        let profile = match self.profile.map(Profile::resolve).transpose() {
+            Ok(p) => p,
+            Err(e) => {
+                set_last_error(e);
+                return Err(ErrorCode::InvalidArgument as c_int);
+            }
+        };
+        let mut out: *mut c_char = ptr::null_mut();
+        let code = match operation {
+            Operation::ExtractText => match self.extraction {
+                Some(options) => extract_text_with_options_impl(
+                    &document,
+                    options.apply(ExtractionOptions::default()),
+                    &mut out,
+                ),
+                None => extract_text_impl(&document, Progress::NONE, &mut out),
+            },
+            Operation::Partition => match (profile, self.partition_config) {
+                (Some(p), _) => partition_with_profile_impl(&document, &p, &mut out),
+                (None, Some(cfg)) => {
+                    partition_with_config_impl(&document, cfg.into(), Progress::NONE, &mut out)
+                }
+                (None, None) => partition_impl(&document, &mut out),
+            },
+            Operation::RagChunks => match (profile, self.partition_config, self.hybrid_config) {
+                (Some(p), _, _) => rag_chunks_with_profile_impl(&document, &p, &mut out),
+                (None, None, None) => rag_chunks_impl(&document, &mut out),
+                (None, partition, hybrid) => rag_chunks_with_config_impl(
+                    &document,
+                    partition.map(Into::into).unwrap_or_default(),
+                    hybrid.map(Into::into).unwrap_or_default(),
+                    Progress::NONE,
+                    &mut out,
+                ),
+            },
+            Operation::Chunk => chunk_pdf_impl(
+                &document,
+                self.chunk_size.unwrap_or_default(),
+                self.overlap.unwrap_or_default(),
+                u8::from(self.detect_language.unwrap_or(false)),
+                Progress::NONE,
+                &mut out,
+            ),
+        };
+        if code != ErrorCode::Success as c_int {
+            return Err(code);
+        }
+        let output = match CString::from_raw(out).into_string() {
+            Ok(output) => output,
+            Err(e) => {
+                set_last_error(LastError::caused_by("Result is not valid UTF-8", &e));
+                return Err(ErrorCode::InvalidUtf8 as c_int);
+            }
+        };
+        let json = match operation {
+            Operation::ExtractText => serde_json::to_string(&output),
+            _ => Ok(output),
+        };
+        json.and_then(RawValue::from_string).map_err(|e| {
+            set_last_error(LastError::caused_by("Failed to serialize result", &e));
+            ErrorCode::SerializationError as c_int
+        })
+    }
+}
+
+/// One slot of the batch result.
+#[derive(Serialize)]
+struct BatchItem {
+    index: usize,
+    /// The single-file entry point's output; `null` on failure.
+    result: Option<Box<RawValue>>,
+    /// The `oxidize_get_last_error_json` document; `null` on success.
+    error: Option<serde_json::Value>,
+}
+
+/// Process one input as its own FFI call on the current worker thread.
+fn process(index: usize, input: BatchInput, operation: Operation) -> BatchItem {
+    let mut result = None;
+    let code = crate::ffi_guard(|| {
+        clear_last_error();
+        match unsafe { input.run(operation) } {
+            Ok(output) => {
+                result = Some(output);
+                ErrorCode::Success as c_int
+            }
+            Err(code) => code,
+        }
+    });
+    let error = (code != ErrorCode::Success as c_int).then(|| {
+        let mut detail = take_last_error()
+            .unwrap_or_else(|| LastError::from(format!("failed with error code {code}")));
+        detail.settle(code);
+        detail.to_value().unwrap_or_default()
+    });
+    clear_last_error();
+    BatchItem {
+        index,
+        result,
+        error,
+    }
+}
+
+/// Run one extraction or chunking operation over many PDFs.
+///
+/// `manifest_json` is an object:
+/// - `operation` — `"extract_text"`, `"partition"`, `"rag_chunks"` or
+///   `"chunk"`;
+/// - `inputs` — an array of objects, each with exactly one of `path` (a
+///   file, memory-mapped like the `_open_path` entry points) or `pdf`
+///   (base64 bytes), an optional `password`, and the options of the
+///   operation:
+///   - `extract_text`: `extraction` — `ExtractionOptionsFFI` fields, upstream
+///     defaults for the rest; plain `oxidize_extract_text` when omitted;
+///   - `partition`: `profile` (built-in or custom id) or `partition_config`;
+///   - `rag_chunks`: `profile`, or `partition_config` and/or
+///     `hybrid_config` (defaults for the one omitted);
+///   - `chunk`: `chunk_size`, `overlap` (required, `overlap < chunk_size`)
+///     and `detect_language`.
+///
+/// The manifest is validated up front and every invalid field is reported.
+/// Inputs then run on `threads` worker threads (0 = one per available CPU,
+/// never more than there are CPUs or inputs).
+///
+/// `*out_json` receives an array with one object per input, in input order:
+/// `{"index", "result", "error"}`. `result` is the JSON the single-file entry
+/// point returns (a JSON string for `extract_text`) and `error` is `null`;
+/// for a failed input `result` is `null` and `error` is the document
+/// `oxidize_get_last_error_json` would have returned for that file.
+///
+/// # Returns
+/// `Success` once every input has been processed, whatever their outcome;
+/// `NullPointer`; `InvalidUtf8`; `InvalidArgument` for an invalid manifest;
+/// `IoError` if the worker threads cannot be started.
+///
+/// # Safety
+/// - `manifest_json` must be a NUL-terminated UTF-8 C string.
+/// - `out_json` must be a writeable `*mut *mut c_char`; free the result with
+///   `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_process_batch(
+    manifest_json: *const c_char,
+    threads: u32,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if manifest_json.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_process_batch");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+
+        let manifest: BatchManifest =
+            match parse_config_arg(manifest_json, "manifest_json", "BatchManifest") {
+                Ok(m) => m,
+                Err(code) => return code,
+            };
+        let operation = manifest.operation;
+        let workers = worker_count(threads).min(manifest.inputs.len().max(1));
+        let inputs: Vec<Mutex<Option<BatchInput>>> = manifest
+            .inputs
+            .into_iter()
+            .map(|input| Mutex::new(Some(input)))
+            .collect();
+        let next = AtomicUsize::new(0);
+        let outcomes = run_workers(workers, || {
+            let mut done = Vec::new();
+            loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(slot) = inputs.get(index) else {
+                    break;
+                };
+                let input = slot.lock().unwrap_or_else(|e| e.into_inner()).take();
+                if let Some(input) = input {
+                    done.push(process(index, input, operation));
+                }
+            }
+            done
+        });
+        let mut items: Vec<BatchItem> = match outcomes {
+            Ok(o) => o.into_iter().flatten().collect(),
+            Err(e) => {
+                set_last_error(e);
+                return ErrorCode::IoError as c_int;
+            }
+        };
+        items.sort_unstable_by_key(|item| item.index);
+
+        let json = match serde_json::to_string(&items) {
+            Ok(j) => j,
+            Err(e) => {
+                set_last_error(LastError::caused_by("Failed to serialize batch result", &e));
+                return ErrorCode::SerializationError as c_int;
+            }
+        };
+        match CString::new(json) {
+            Ok(s) => {
+                *out_json = s.into_raw();
+                ErrorCode::Success as c_int
+            }
+            Err(e) => {
+                set_last_error(LastError::caused_by("Batch result contains NUL", &e));
+                ErrorCode::InvalidUtf8 as c_int
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chunking::oxidize_chunk_pdf;
+    use crate::mapped_file::tests::TempPdf;
+    use crate::parser::{oxidize_extract_text, oxidize_partition};
+    use crate::test_fixtures::SamplePdf;
+    use serde_json::{json, Value};
+    use std::ffi::CStr;
+
+    fn b64(bytes: &[u8]) -> String {
+        base64::engine::general_purpose::STANDARD.encode(bytes)
+    }
+
+    unsafe fn take(code: c_int, out: *mut c_char) -> String {
+        assert_eq!(code, ErrorCode::Success as c_int);
+        let s = CStr::from_ptr(out).to_str().unwrap().to_owned();
+        crate::oxidize_free_string(out);
+        s
+    }
+
+    fn batch(manifest: &Value, threads: u32) -> Result<Vec<Value>, c_int> {
+        let manifest = CString::new(manifest.to_string()).unwrap();
+        let mut out = ptr::null_mut();
+        match unsafe { oxidize_process_batch(manifest.as_ptr(), threads, &mut out) } {
+            0 => Ok(serde_json::from_str(&unsafe { take(0, out) }).unwrap()),
+            code => {
+                assert!(out.is_null());
+                Err(code)
+            }
+        }
+    }
+
+    #[test]
+    fn results_match_single_file_calls_and_failures_stay_in_their_slot() {
+        let first = SamplePdf::new(1).heading("Batch input one").build();
+        let second = SamplePdf::new(1).heading("Batch input two").build();
+        let file = TempPdf::new("batch-second", &second);
+        let manifest = json!({
+            "operation": "extract_text",
+            "inputs": [
+                {"pdf": b64(&first)},
+                {"pdf": b64(b"not a pdf")},
+                {"path": file.0.to_str().unwrap()},
+                {"path": "/definitely/missing/batch.pdf"},
+            ],
+        });
+        for threads in [1, 3] {
+            let items = batch(&manifest, threads).unwrap();
+            assert_eq!(items.len(), 4);
+            for (slot, pdf) in [(0, &first), (2, &second)] {
+                let mut out = ptr::null_mut();
+                let text =
+                    unsafe { take(oxidize_extract_text(pdf.as_ptr(), pdf.len(), &mut out), out) };
+                assert_eq!(items[slot]["index"], slot);
+                assert_eq!(items[slot]["result"], text.as_str());
+                assert!(items[slot]["error"].is_null());
+            }
+            assert!(items[1]["result"].is_null());
+            assert_eq!(items[1]["error"]["code"], ErrorCode::PdfParseError as c_int);
+            assert_eq!(items[3]["error"]["code"], ErrorCode::IoError as c_int);
+        }
+    }
+
+    #[test]
+    fn per_file_options_select_the_matching_entry_point() {
+        let pdf = SamplePdf::new(1)
+            .heading("Per-file options reach the extraction")
+            .build();
+        let locked = SamplePdf::new(1)
+            .heading("Locked batch input")
+            .encrypted("user-secret")
+            .build();
+        let items = batch(
+            &json!({
+                "operation": "partition",
+                "inputs": [
+                    {"pdf": b64(&pdf)},
+                    {"pdf": b64(&pdf), "profile": 6},
+                    {"pdf": b64(&locked), "password": "user-secret"},
+                    {"pdf": b64(&locked), "password": "wrong"},
+                    {"pdf": b64(&pdf), "profile": 250},
+                ],
+            }),
+            0,
+        )
+        .unwrap();
+        let mut out = ptr::null_mut();
+        let plain: Value = serde_json::from_str(&unsafe {
+            take(oxidize_partition(pdf.as_ptr(), pdf.len(), &mut out), out)
+        })
+        .unwrap();
+        assert_eq!(items[0]["result"], plain);
+        assert!(items[1]["result"].is_array());
+        assert!(items[2]["result"].is_array());
+        assert_eq!(
+            items[3]["error"]["code"],
+            ErrorCode::PasswordRequired as c_int
+        );
+        assert_eq!(
+            items[4]["error"]["code"],
+            ErrorCode::InvalidArgument as c_int
+        );
+
+        let items = batch(
+            &json!({
+                "operation": "chunk",
+                "inputs": [{"pdf": b64(&pdf), "chunk_size": 4, "overlap": 1}],
+            }),
+            1,
+        )
+        .unwrap();
+        let mut out = ptr::null_mut();
+        let chunks: Value = serde_json::from_str(&unsafe {
+            take(
+                oxidize_chunk_pdf(pdf.as_ptr(), pdf.len(), 4, 1, 0, &mut out),
+                out,
+            )
+        })
+        .unwrap();
+        assert_eq!(items[0]["result"], chunks);
+    }
+
+    #[test]
+    fn invalid_manifests_report_every_field() {
+        let code = batch(
+            &json!({
+                "operation": "chunk",
+                "inputs": [
+                    {"pdf": "", "path": "/a.pdf", "chunk_size": 4, "overlap": 4},
+                    {"profile": 1},
+                    7,
+                ],
+            }),
+            1,
+        );
+        assert_eq!(code, Err(ErrorCode::InvalidArgument as c_int));
+        let mut err = ptr::null_mut();
+        let message = unsafe { take(crate::oxidize_get_last_error(&mut err), err) };
+        for expected in [
+            "$.inputs[0].pdf cannot be combined with path",
+            "$.inputs[0].overlap must be less than chunk_size (4), got 4",
+            "$.inputs[1].path is required (or pdf)",
+            "$.inputs[1].profile is not a known field",
+            "$.inputs[2] must be a JSON object, got 7",
+        ] {
+            assert!(message.contains(expected), "{expected} not in {message}");
+        }
+
+        let code = batch(&json!({"operation": "ocr", "inputs": []}), 1);
+        assert_eq!(code, Err(ErrorCode::InvalidArgument as c_int));
+        assert_eq!(
+            batch(&json!({"operation": "partition", "inputs": []}), 1),
+            Ok(vec![])
+        );
+    }
+
+    #[test]
+    fn input_without_path_or_pdf_is_an_invalid_argument() {
+        let input: BatchInput = serde_json::from_value(json!({})).unwrap();
+        assert_eq!(
+            input.open().err(),
+            Some(ErrorCode::InvalidArgument as c_int)
+        );
+        let mut err = ptr::null_mut();
+        let message = unsafe { take(crate::oxidize_get_last_error(&mut err), err) };
+        assert_eq!(message, "input needs a path or pdf");
+    }
+}
@@ -0,0 +1,432 @@
+//! Decoded size of a stream, without materializing more than the limit.
+//!
+//! [`decoded_len`] runs a stream's whole filter chain the way the core
+//! library's `decode_stream` does, but every stage writes into a [`Sink`]
+//! that stops one byte past the limit, so a decompression bomb nested in
+//! any chain (`[/FlateDecode /FlateDecode]`, `/ASCII85Decode` over Flate,
+//! LZW, run-length) is detected after decoding at most `max + 1` bytes per
+//! stage. Intermediate stages are buffered, since the next filter reads
+//! them; the last stage is only counted.
+//!
+//! The chain follows the core's conventions: the `/DecodeParms` dictionary
+//! (or the first entry of a `/DecodeParms` array) applies to every filter,
+//! `FlateDecode` falls back to raw deflate, gzip and skipped-header
+//! decoding when zlib fails, and a predictor never enlarges its input.
+//! `DCTDecode` output is a slice of its input, and filters the core does not
+//! decode (`JPXDecode`, `Crypt`, unknown names) pass their input through.
+//! `CCITTFaxDecode` is bounded by its row width times `/Rows` (else the
+//! image `/Height`, else one row per input bit).
+//!
+//! Stages that cannot be bounded without running the core's decoder fail
+//! closed ([`Bound::Unbounded`]): `JBIG2Decode`, whose page and symbol
+//! bitmaps are sized by the segments themselves, an image filter before the
+//! end of the chain, and a predictor on a chain of several filters.
+
+use std::io::Read;
+
+use oxidize_pdf::parser::{PdfDictionary, PdfObject, PdfStream};
+
+/// Outcome of [`decoded_len`].
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum Bound {
+    /// Decoded length, or `max + 1` when a stage produced more than `max`
+    /// bytes.
+    Len(u64),
+    /// The chain cannot be bounded; the reason names the filter.
+    Unbounded(String),
+}
+
+/// Output of one stage, kept up to `cap` bytes (counted only for the last
+/// stage).
+struct Sink {
+    data: Option<Vec<u8>>,
+    len: u64,
+    cap: u64,
+}
+
+impl Sink {
+    fn new(cap: u64, keep: bool) -> Self {
+        Sink {
+            data: keep.then(Vec::new),
+            len: 0,
+            cap,
+        }
+    }
+
+    fn full(&self) -> bool {
+        self.len >= self.cap
+    }
+
+    /// Append `bytes` up to the cap; `false` once the sink is full.
+    fn push(&mut self, bytes: &[u8]) -> bool {
+        let room = (self.cap - self.len).min(bytes.len() as u64) as usize;
+        if let Some(data) = &mut self.data {
+            data.extend_from_slice(&bytes[..room]);
+        }
+        self.len += room as u64;
+        !self.full()
+    }
+
+    fn byte(&mut self, byte: u8) -> bool {
+        self.push(&[byte])
+    }
+}
+
+/// The filter names of `dict`, as the core reads them.
+fn filter_names(dict: &PdfDictionary) -> Vec<&str> {
+    match dict.get("Filter") {
+        Some(PdfObject::Name(name)) => vec![name.0.as_str()],
+        Some(PdfObject::Array(array)) => array
+            .0
+            .iter()
+            .filter_map(|f| f.as_name().map(|n| n.0.as_str()))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn int(dict: Option<&PdfDictionary>, key: &str) -> Option<i64> {
+    dict?.get(key)?.as_integer()
+}
+
+/// Length `stream` decodes to, capped at `max + 1`.
+pub(crate) fn decoded_len(stream: &PdfStream, max: u64) -> Bound {
+    let cap = max.saturating_add(1);
+    let filters = filter_names(&stream.dict);
+    let params = match stream.dict.get("DecodeParms") {
+        Some(PdfObject::Dictionary(dict)) => Some(dict),
+        Some(PdfObject::Array(array)) => array.0.first().and_then(PdfObject::as_dict),
+        _ => None,
+    };
+    let predictor = int(params, "Predictor").is_some();
+    if predictor && filters.len() > 1 {
+        return Bound::Unbounded("a predictor on a chain of several filters".to_owned());
+    }
+
+    let mut input = stream.raw_data().to_vec();
+    let mut len = input.len() as u64;
+    for (i, &name) in filters.iter().enumerate() {
+        let last = i + 1 == filters.len();
+        let mut sink = Sink::new(cap, !last);
+        match name {
+            "FlateDecode" | "Fl" => inflate(&input, predictor, &mut sink),
+            "ASCIIHexDecode" | "AHx" => ascii_hex(&input, &mut sink),
+            "ASCII85Decode" | "A85" => ascii85(&input, &mut sink),
+            "LZWDecode" | "LZW" => lzw(&input, int(params, "EarlyChange") != Some(0), &mut sink),
+            "RunLengthDecode" | "RL" => run_length(&input, &mut sink),
+            "CCITTFaxDecode" | "CCF" if last => {
+                let columns = int(params, "Columns").unwrap_or(1728).max(1) as u64;
+                let rows = int(params, "Rows")
+                    .filter(|&r| r > 0)
+                    .or_else(|| stream.dict.get("Height")?.as_integer())
+                    .filter(|&r| r > 0)
+                    .map_or(8 * input.len() as u64, |r| r as u64);
+                sink.len = columns.div_ceil(8).saturating_mul(rows).min(cap);
+            }
+            "JBIG2Decode" | "CCITTFaxDecode" | "CCF" => {
+                return Bound::Unbounded(format!("/{name}"));
+            }
+            "DCTDecode" | "DCT" | "JPXDecode" if !last => {
+                return Bound::Unbounded(format!("/{name} before the end of the chain"));
+            }
+            // Not expanded by the core: at most the input.
+            _ => {
+                sink.push(&input);
+            }
+        }
+        len = sink.len;
+        if sink.full() {
+            break;
+        }
+        input = sink.data.unwrap_or_default();
+    }
+    Bound::Len(len)
+}
+
+/// Copy `reader` into `sink`; `true` when it ended cleanly (or filled the
+/// sink), `false` on a decoding error.
+fn pump(mut reader: impl Read, sink: &mut Sink) -> bool {
+    let mut buf = [0u8; 8192];
+    loop {
+        match reader.read(&mut buf) {
+            Ok(0) => return true,
+            Ok(n) => {
+                if !sink.push(&buf[..n]) {
+                    return true;
+                }
+            }
+            Err(_) => return false,
+        }
+    }
+}
+
+/// `FlateDecode`: zlib, else the core's fallbacks, keeping the longest
+/// output. With a predictor the core falls back to the raw input instead.
+fn inflate(data: &[u8], predictor: bool, sink: &mut Sink) {
+    use flate2::read::{DeflateDecoder, GzDecoder, ZlibDecoder};
+
+    let keep = sink.data.is_some();
+    if pump(ZlibDecoder::new(data), sink) {
+        return;
+    }
+    if predictor {
+        *sink = Sink::new(sink.cap, keep);
+        sink.push(data);
+        return;
+    }
+    let fallback = |attempt: &dyn Fn(&mut Sink) -> bool| {
+        let mut candidate = Sink::new(sink.cap, keep);
+        attempt(&mut candidate);
+        candidate
+    };
+    let mut candidates = vec![
+        fallback(&|s| pump(DeflateDecoder::new(data), s)),
+        fallback(&|s| pump(GzDecoder::new(data), s)),
+    ];
+    for skip in 1..=5.min(data.len()) {
+        let rest = &data[skip..];
+        candidates.push(fallback(&|s| pump(ZlibDecoder::new(rest), s)));
+        candidates.push(fallback(&|s| pump(DeflateDecoder::new(rest), s)));
+    }
+    if let Some(longest) = candidates.into_iter().max_by_key(|c| c.len) {
+        if longest.len > sink.len {
+            *sink = longest;
+        }
+    }
+}
+
+fn ascii_hex(data: &[u8], sink: &mut Sink) {
+    let mut high = None;
+    for &b in data {
+        if b == b'>' {
+            break;
+        }
+        let Some(digit) = (b as char).to_digit(16) else {
+            continue;
+        };
+        match high.take() {
+            None => high = Some(digit as u8),
+            Some(h) => {
+                if !sink.byte(h << 4 | digit as u8) {
+                    return;
+                }
+            }
+        }
+    }
+    if let Some(h) = high {
+        sink.byte(h << 4);
+    }
+}
+
+fn ascii85(data: &[u8], sink: &mut Sink) {
+    let data = data.strip_prefix(b"<~").unwrap_or(data);
+    let mut group = [0u8; 5];
+    let mut n = 0;
+    for &b in data.iter().filter(|b| !b.is_ascii_whitespace()) {
+        match b {
+            b'~' => break,
+            b'z' if n == 0 => {
+                if !sink.push(&[0; 4]) {
+                    return;
+                }
+            }
+            b'!'..=b'u' => {
+                group[n] = b - b'!';
+                n += 1;
+                if n == 5 {
+                    let value = group
+                        .iter()
+                        .fold(0u32, |v, &d| v.wrapping_mul(85) + d as u32);
+                    if !sink.push(&value.to_be_bytes()) {
+                        return;
+                    }
+                    n = 0;
+                }
+            }
+            _ => return,
+        }
+    }
+    if n > 1 {
+        group[n..].fill(84);
+        let value = group
+            .iter()
+            .fold(0u32, |v, &d| v.wrapping_mul(85) + d as u32);
+        sink.push(&value.to_be_bytes()[..n - 1]);
+    }
+}
+
+fn run_length(data: &[u8], sink: &mut Sink) {
+    let mut i = 0;
+    while let Some(&length) = data.get(i) {
+        i += 1;
+        let more = match length {
+            128 => return,
+            0..=127 => {
+                let end = (i + length as usize + 1).min(data.len());
+                let more = sink.push(&data[i..end]);
+                i = end;
+                more
+            }
+            _ => match data.get(i) {
+                Some(&b) => {
+                    i += 1;
+                    sink.push(&[b; 128][..257 - length as usize])
+                }
+                None => return,
+            },
+        };
+        if !more {
+            return;
+        }
+    }
+}
+
+/// `LZWDecode` with 9- to 12-bit codes, `early_change` as `/EarlyChange`.
+fn lzw(data: &[u8], early_change: bool, sink: &mut Sink) {
+    const CLEAR: usize = 256;
+    const END: usize = 257;
+    // Entry `i` is entry `prefix` followed by `byte`; roots have no prefix.
+    let mut table: Vec<(Option<u16>, u8)> = (0..=255u8).map(|b| (None, b)).collect();
+    table.extend([(None, 0), (None, 0)]);
+    let mut width = 9;
+    let mut previous: Option<usize> = None;
+    let (mut bits, mut held) = (0u32, 0u32);
+    let mut entry = Vec::new();
+    let spell = |table: &[(Option<u16>, u8)], mut code: usize, out: &mut Vec<u8>| {
+        out.clear();
+        loop {
+            let (prefix, byte) = table[code];
+            out.push(byte);
+            match prefix {
+                Some(p) => code = p as usize,
+                None => break,
+            }
+        }
+        out.reverse();
+    };
+
+    for &b in data {
+        bits = bits << 8 | b as u32;
+        held += 8;
+        while held >= width {
+            held -= width;
+            let code = ((bits >> held) & ((1 << width) - 1)) as usize;
+            match code {
+                CLEAR => {
+                    table.truncate(258);
+                    width = 9;
+                    previous = None;
+                    continue;
+                }
+                END => return,
+                _ => {}
+            }
+            match previous {
+                Some(p) if code < table.len() => {
+                    spell(&table, code, &mut entry);
+                    table.push((Some(p as u16), entry[0]));
+                }
+                Some(p) if code == table.len() => {
+                    spell(&table, p, &mut entry);
+                    entry.push(entry[0]);
+                    table.push((Some(p as u16), entry[0]));
+                }
+                None if code < table.len() => spell(&table, code, &mut entry),
+                _ => return,
+            }
+            if !sink.push(&entry) {
+                return;
+            }
+            previous = Some(code);
+            if table.len() + usize::from(early_change) >= 1 << width && width < 12 {
+                width += 1;
+            }
+            if table.len() >= 4096 {
+                previous = None;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    use flate2::write::ZlibEncoder;
+    use oxidize_pdf::parser::{PdfArray, PdfName};
+
+    fn stream(filters: &[&str], data: Vec<u8>) -> PdfStream {
+        let mut dict = PdfDictionary::new();
+        let names = filters
+            .iter()
+            .map(|f| PdfObject::Name(PdfName((*f).into())))
+            .collect();
+        dict.insert("Filter".to_string(), PdfObject::Array(PdfArray(names)));
+        PdfStream { dict, data }
+    }
+
+    fn zlib(data: &[u8]) -> Vec<u8> {
+        let mut encoder = ZlibEncoder::new(Vec::new(), flate2::Compression::best());
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[test]
+    fn simple_filters_decode_like_the_core() {
+        let hex = stream(&["ASCIIHexDecode"], b"48 65 6c6C 6>".to_vec());
+        assert_eq!(decoded_len(&hex, 100), Bound::Len(5));
+        let a85 = stream(&["ASCII85Decode"], b"<~87cURD]i,\"Ebo80~>".to_vec());
+        assert_eq!(decoded_len(&a85, 100), Bound::Len(12));
+        let z = stream(&["ASCII85Decode"], b"zz!!~>".to_vec());
+        assert_eq!(decoded_len(&z, 100), Bound::Len(9));
+        // 3 literal bytes, then 'x' repeated 128 times.
+        let rl = stream(&["RunLengthDecode"], b"\x02abc\x81x\x80".to_vec());
+        assert_eq!(decoded_len(&rl, 1000), Bound::Len(131));
+        assert_eq!(decoded_len(&rl, 100), Bound::Len(101));
+    }
+
+    #[test]
+    fn lzw_decodes_the_specification_example() {
+        // ISO 32000-1 7.4.4.2: 45 45 45 45 45 65 45 45 45 66.
+        let data = vec![0x80, 0x0B, 0x60, 0x50, 0x22, 0x0C, 0x0C, 0x85, 0x01];
+        let mut sink = Sink::new(100, true);
+        lzw(&data, true, &mut sink);
+        assert_eq!(sink.data.unwrap(), [45, 45, 45, 45, 45, 65, 45, 45, 45, 66]);
+    }
+
+    #[test]
+    fn chains_are_decoded_stage_by_stage() {
+        let inner = zlib(&vec![b' '; 1 << 20]);
+        let double = stream(&["FlateDecode", "FlateDecode"], zlib(&inner));
+        assert_eq!(decoded_len(&double, 2 << 20), Bound::Len(1 << 20));
+        assert_eq!(decoded_len(&double, 1000), Bound::Len(1001));
+
+        let hex: Vec<u8> = inner
+            .iter()
+            .flat_map(|b| format!("{b:02x}").into_bytes())
+            .collect();
+        let hex_flate = stream(&["ASCIIHexDecode", "FlateDecode"], hex);
+        assert_eq!(decoded_len(&hex_flate, 1000), Bound::Len(1001));
+    }
+
+    #[test]
+    fn unboundable_filters_fail_closed() {
+        let jbig2 = stream(&["JBIG2Decode"], vec![0; 16]);
+        assert!(matches!(decoded_len(&jbig2, 1 << 20), Bound::Unbounded(_)));
+        let dct_first = stream(&["DCTDecode", "FlateDecode"], vec![0; 16]);
+        assert!(matches!(
+            decoded_len(&dct_first, 1 << 20),
+            Bound::Unbounded(_)
+        ));
+
+        let dct = stream(&["DCTDecode"], vec![0; 16]);
+        assert_eq!(decoded_len(&dct, 1 << 20), Bound::Len(16));
+        let mut ccitt = stream(&["CCITTFaxDecode"], vec![0; 16]);
+        ccitt
+            .dict
+            .insert("Height".to_string(), PdfObject::Integer(100));
+        // 1728 columns: 216 bytes per row.
+        assert_eq!(decoded_len(&ccitt, 1 << 20), Bound::Len(21_600));
+    }
+}
@@ -0,0 +1,243 @@
+//! PDF input read through caller-supplied callbacks.
+//!
+//! [`ReadCallbacks`] lets the caller back a document with anything that can
+//! read at an offset — a .NET `Stream`, a blob-storage client, an HTTP range
+//! reader — instead of a byte buffer. The parser only pulls the regions it
+//! needs (header, trailer, xref, then objects on demand, through an 8 KiB
+//! read buffer), so a large remote file is parsed lazily rather than
+//! downloaded up front.
+//!
+//! Callback sources are parsed with [`parse_options`]: the lenient settings
+//! minus lenient syntax, whose hybrid-xref pass scans the whole file for
+//! objects missing from the cross-reference data. A damaged cross-reference
+//! table is still rebuilt (which does read the whole file).
+//!
+//! Seeks are recorded and forwarded to `seek` only before the next `read`,
+//! so consecutive seeks cost a single callback.
+
+use std::ffi::c_void;
+use std::io::{self, Cursor, Read, Seek, SeekFrom};
+use std::os::raw::c_int;
+
+use oxidize_pdf::parser::ParseOptions;
+
+use crate::mapped_file::SharedBytes;
+
+/// Callbacks backing a callback-read document.
+///
+/// All three functions are required. They are called on the thread that
+/// calls into the library with the handle, never concurrently, and
+/// `user_data` must stay valid until the handle is freed.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ReadCallbacks {
+    /// Opaque caller state passed to every callback.
+    pub user_data: *mut c_void,
+    /// Read up to `len` bytes at the current position into `buf`. Returns the
+    /// number of bytes read (0 at end of input) or a negative value on error.
+    pub read: Option<unsafe extern "C" fn(*mut c_void, *mut u8, usize) -> isize>,
+    /// Move to the absolute byte `offset`. Returns 0 on success.
+    pub seek: Option<unsafe extern "C" fn(*mut c_void, u64) -> c_int>,
+    /// Total input length in bytes, or a negative value on error.
+    pub length: Option<unsafe extern "C" fn(*mut c_void) -> i64>,
+}
+
+type ReadFn = unsafe extern "C" fn(*mut c_void, *mut u8, usize) -> isize;
+type SeekFn = unsafe extern "C" fn(*mut c_void, u64) -> c_int;
+
+/// `Read + Seek` over [`ReadCallbacks`].
+pub(crate) struct CallbackReader {
+    user_data: *mut c_void,
+    read: ReadFn,
+    seek: SeekFn,
+    len: u64,
+    pos: u64,
+    /// Whether the caller's stream is known to be at `pos`.
+    synced: bool,
+}
+
+impl CallbackReader {
+    /// Validate `callbacks` and query the input length.
+    ///
+    /// # Safety
+    /// The callbacks must behave as documented on [`ReadCallbacks`] for as
+    /// long as the returned reader is alive.
+    pub(crate) unsafe fn new(callbacks: &ReadCallbacks) -> io::Result<Self> {
+        let (Some(read), Some(seek), Some(length)) =
+            (callbacks.read, callbacks.seek, callbacks.length)
+        else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "read, seek and length callbacks are required",
+            ));
+        };
+        let len = u64::try_from(length(callbacks.user_data))
+            .map_err(|_| io::Error::other("length callback failed"))?;
+        Ok(CallbackReader {
+            user_data: callbacks.user_data,
+            read,
+            seek,
+            len,
+            pos: 0,
+            synced: false,
+        })
+    }
+}
+
+impl Read for CallbackReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() || self.pos >= self.len {
+            return Ok(0);
+        }
+        if !self.synced {
+            if unsafe { (self.seek)(self.user_data, self.pos) } != 0 {
+                return Err(io::Error::other("seek callback failed"));
+            }
+            self.synced = true;
+        }
+        let n = unsafe { (self.read)(self.user_data, buf.as_mut_ptr(), buf.len()) };
+        let n = usize::try_from(n).map_err(|_| {
+            self.synced = false;
+            io::Error::other("read callback failed")
+        })?;
+        let n = n.min(buf.len());
+        self.pos += n as u64;
+        Ok(n)
+    }
+}
+
+impl Seek for CallbackReader {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let target = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(delta) => self.len.checked_add_signed(delta),
+            SeekFrom::Current(delta) => self.pos.checked_add_signed(delta),
+        }
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "seek before start of input"))?;
+        if target != self.pos {
+            self.pos = target;
+            self.synced = false;
+        }
+        Ok(target)
+    }
+}
+
+/// Parse options for callback sources: lenient, without the whole-file
+/// hybrid-xref scan (`lenient_syntax` and `collect_warnings` both enable it).
+pub(crate) fn parse_options() -> ParseOptions {
+    ParseOptions {
+        lenient_syntax: false,
+        collect_warnings: false,
+        ..ParseOptions::lenient()
+    }
+}
+
+/// What a long-lived document reads from: in-memory bytes (copied or
+/// mapped) or caller callbacks.
+pub(crate) enum PdfSource {
+    Bytes(Cursor<SharedBytes>),
+    Callbacks(CallbackReader),
+}
+
+impl Read for PdfSource {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            PdfSource::Bytes(cursor) => cursor.read(buf),
+            PdfSource::Callbacks(reader) => reader.read(buf),
+        }
+    }
+}
+
+impl Seek for PdfSource {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        match self {
+            PdfSource::Bytes(cursor) => cursor.seek(pos),
+            PdfSource::Callbacks(reader) => reader.seek(pos),
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// In-memory backing store that counts the bytes handed out.
+    pub(crate) struct Source {
+        pub(crate) data: Vec<u8>,
+        pub(crate) pos: usize,
+        pub(crate) bytes_read: usize,
+        pub(crate) fail_reads: bool,
+    }
+
+    impl Source {
+        pub(crate) fn new(data: Vec<u8>) -> Self {
+            Source {
+                data,
+                pos: 0,
+                bytes_read: 0,
+                fail_reads: false,
+            }
+        }
+
+        pub(crate) fn callbacks(&mut self) -> ReadCallbacks {
+            ReadCallbacks {
+                user_data: self as *mut Source as *mut c_void,
+                read: Some(read_cb),
+                seek: Some(seek_cb),
+                length: Some(length_cb),
+            }
+        }
+    }
+
+    unsafe extern "C" fn read_cb(user_data: *mut c_void, buf: *mut u8, len: usize) -> isize {
+        let source = &mut *(user_data as *mut Source);
+        if source.fail_reads {
+            return -1;
+        }
+        let n = len.min(source.data.len().saturating_sub(source.pos));
+        std::ptr::copy_nonoverlapping(source.data[source.pos..].as_ptr(), buf, n);
+        source.pos += n;
+        source.bytes_read += n;
+        n as isize
+    }
+
+    unsafe extern "C" fn seek_cb(user_data: *mut c_void, offset: u64) -> c_int {
+        let source = &mut *(user_data as *mut Source);
+        source.pos = offset as usize;
+        0
+    }
+
+    unsafe extern "C" fn length_cb(user_data: *mut c_void) -> i64 {
+        (*(user_data as *mut Source)).data.len() as i64
+    }
+
+    #[test]
+    fn reads_and_seeks_like_a_cursor() {
+        let mut source = Source::new((0u8..=255).collect());
+        let mut reader = unsafe { CallbackReader::new(&source.callbacks()) }.unwrap();
+
+        let mut buf = [0u8; 4];
+        reader.seek(SeekFrom::End(-4)).unwrap();
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(buf, [252, 253, 254, 255]);
+        assert_eq!(reader.read(&mut buf).unwrap(), 0);
+
+        reader.seek(SeekFrom::Start(10)).unwrap();
+        reader.seek(SeekFrom::Current(2)).unwrap();
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(buf, [12, 13, 14, 15]);
+        assert!(reader.seek(SeekFrom::Current(-100)).is_err());
+    }
+
+    #[test]
+    fn missing_callbacks_and_read_errors_are_reported() {
+        let mut source = Source::new(vec![1, 2, 3]);
+        let mut callbacks = source.callbacks();
+        callbacks.seek = None;
+        assert!(unsafe { CallbackReader::new(&callbacks) }.is_err());
+
+        let mut reader = unsafe { CallbackReader::new(&source.callbacks()) }.unwrap();
+        source.fail_reads = true;
+        assert!(reader.read(&mut [0u8; 2]).is_err());
+    }
+}
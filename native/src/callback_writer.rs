@@ -0,0 +1,208 @@
+//! PDF output written through a caller-supplied sink callback.
+//!
+//! `oxidize_document_save_to_callbacks` and
+//! `oxidize_document_save_to_callbacks_with_config` serialize a document
+//! straight into [`WriteCallbacks`] instead of one native buffer, so the
+//! caller can pipe it into a .NET `Stream` or an HTTP response without a
+//! second full copy of the file. The writer's many small writes are batched
+//! through a 64 KiB buffer before reaching the callback.
+//!
+//! A callback failure aborts the save with [`ErrorCode::IoError`]; whatever
+//! was already written is left to the caller to discard.
+
+use std::ffi::c_void;
+use std::io::{self, BufWriter, Write};
+use std::os::raw::c_int;
+
+use oxidize_pdf::writer::{PdfWriter, WriterConfig};
+use oxidize_pdf::Document;
+
+use crate::error_detail::LastError;
+use crate::{set_last_error, ErrorCode};
+
+/// Output buffer size. Small writes are batched up to this size; a single
+/// larger write (e.g. an image stream) reaches the callback unsplit.
+const CHUNK: usize = 64 * 1024;
+
+/// Sink for a callback save.
+///
+/// `write` is required; `flush` may be null. Both run synchronously on the
+/// calling thread and must not unwind.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct WriteCallbacks {
+    /// Opaque caller state passed to every callback.
+    pub user_data: *mut c_void,
+    /// Consume all `len` bytes at `buf`. Returns 0 on success; any other
+    /// value aborts the save.
+    pub write: Option<unsafe extern "C" fn(*mut c_void, *const u8, usize) -> c_int>,
+    /// Called once after the last `write`. Returns 0 on success.
+    pub flush: Option<unsafe extern "C" fn(*mut c_void) -> c_int>,
+}
+
+/// `Write` over [`WriteCallbacks`].
+struct CallbackWriter<'a> {
+    callbacks: &'a WriteCallbacks,
+    write: unsafe extern "C" fn(*mut c_void, *const u8, usize) -> c_int,
+}
+
+impl Write for CallbackWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        if unsafe { (self.write)(self.callbacks.user_data, buf.as_ptr(), buf.len()) } != 0 {
+            return Err(io::Error::other("write callback failed"));
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        // The writer flushes on its own; the caller's `flush` runs once, at
+        // the end of `save_to_callbacks`.
+        Ok(())
+    }
+}
+
+/// Serialize `document` with `config` into `callbacks`, updating the
+/// modification date first as `Document::to_bytes` does. Sets the last error
+/// and returns the error code on failure.
+pub(crate) fn save_to_callbacks(
+    document: &mut Document,
+    config: WriterConfig,
+    callbacks: &WriteCallbacks,
+) -> c_int {
+    let Some(write) = callbacks.write else {
+        set_last_error("WriteCallbacks.write must not be null");
+        return ErrorCode::InvalidArgument as c_int;
+    };
+
+    document.update_modification_date();
+    let mut buffered = BufWriter::with_capacity(CHUNK, CallbackWriter { callbacks, write });
+    let written = PdfWriter::with_config(&mut buffered, config)
+        .write_document(document)
+        .map_err(|e| LastError::caused_by("Failed to write document", &e))
+        .and_then(|()| {
+            buffered
+                .flush()
+                .map_err(|e| LastError::caused_by("Failed to write document", &e))
+        })
+        .and_then(|()| match callbacks.flush {
+            Some(flush) if unsafe { flush(callbacks.user_data) } != 0 => Err(LastError::from(
+                "Failed to write document: flush callback failed",
+            )),
+            _ => Ok(()),
+        });
+
+    match written {
+        Ok(()) => ErrorCode::Success as c_int,
+        Err(e) => {
+            set_last_error(e);
+            ErrorCode::IoError as c_int
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::document::{oxidize_document_create, oxidize_document_free};
+    use oxidize_pdf::{Font, Page};
+
+    /// In-memory sink recording each write call.
+    #[derive(Default)]
+    pub(crate) struct Sink {
+        pub(crate) data: Vec<u8>,
+        pub(crate) writes: usize,
+        pub(crate) flushes: usize,
+        /// Fail the write call with this index.
+        pub(crate) fail_at: Option<usize>,
+    }
+
+    impl Sink {
+        pub(crate) fn callbacks(&mut self) -> WriteCallbacks {
+            WriteCallbacks {
+                user_data: self as *mut Sink as *mut c_void,
+                write: Some(write_cb),
+                flush: Some(flush_cb),
+            }
+        }
+    }
+
+    unsafe extern "C" fn write_cb(user_data: *mut c_void, buf: *const u8, len: usize) -> c_int {
+        let sink = &mut *(user_data as *mut Sink);
+        if sink.fail_at == Some(sink.writes) {
+            return -1;
+        }
+        sink.writes += 1;
+        sink.data
+            .extend_from_slice(std::slice::from_raw_parts(buf, len));
+        0
+    }
+
+    unsafe extern "C" fn flush_cb(user_data: *mut c_void) -> c_int {
+        (*(user_data as *mut Sink)).flushes += 1;
+        0
+    }
+
+    unsafe fn text_document(pages: usize) -> *mut crate::document::DocumentHandle {
+        let handle = oxidize_document_create();
+        for p in 0..pages {
+            let mut page = Page::a4();
+            page.text()
+                .set_font(Font::Helvetica, 12.0)
+                .at(50.0, 750.0)
+                .write(&format!("Streamed page {}", p + 1))
+                .unwrap();
+            (*handle).inner.add_page(page);
+        }
+        handle
+    }
+
+    #[test]
+    fn save_streams_a_parseable_pdf_in_chunks() {
+        unsafe {
+            let handle = text_document(300);
+            (*handle).inner.set_compress(false);
+            let mut sink = Sink::default();
+            let code =
+                crate::document::oxidize_document_save_to_callbacks(handle, &sink.callbacks());
+            assert_eq!(code, ErrorCode::Success as c_int);
+            oxidize_document_free(handle);
+
+            assert!(sink.data.starts_with(b"%PDF-1.7"));
+            assert!(sink.data.len() > CHUNK, "{} bytes", sink.data.len());
+            assert!(sink.writes > 1 && sink.writes <= sink.data.len() / 1024);
+            assert_eq!(sink.flushes, 1);
+
+            let reader = crate::parser::open_lenient(&sink.data[..]).unwrap();
+            let doc = oxidize_pdf::parser::PdfDocument::new(reader);
+            assert_eq!(doc.page_count().unwrap(), 300);
+            let text = doc.extract_text_from_page(299).unwrap().text;
+            assert!(text.contains("Streamed page 300"), "{text}");
+        }
+    }
+
+    #[test]
+    fn callback_failure_aborts_the_save() {
+        unsafe {
+            let handle = text_document(300);
+            (*handle).inner.set_compress(false);
+            let mut sink = Sink {
+                fail_at: Some(1),
+                ..Default::default()
+            };
+            let code =
+                crate::document::oxidize_document_save_to_callbacks(handle, &sink.callbacks());
+            assert_eq!(code, ErrorCode::IoError as c_int);
+            assert_eq!(sink.writes, 1);
+            assert_eq!(sink.flushes, 0);
+
+            let mut missing = sink.callbacks();
+            missing.write = None;
+            let code = crate::document::oxidize_document_save_to_callbacks(handle, &missing);
+            assert_eq!(code, ErrorCode::InvalidArgument as c_int);
+            oxidize_document_free(handle);
+        }
+    }
+}
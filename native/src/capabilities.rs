@@ -0,0 +1,1328 @@
+//! Capability and payload-schema introspection.
+//!
+//! [`oxidize_capabilities_json`] reports what this build was compiled with
+//! (binding and core versions, core feature flags, result encodings) and a
+//! JSON Schema (draft 2020-12) for every JSON payload an entry point accepts.
+//! Callers can detect version skew once at load time and validate payloads
+//! before crossing the boundary.
+//!
+//! The schemas are written by hand next to the serde DTOs they describe:
+//! `required` lists exactly the fields without a serde default, and
+//! `additionalProperties: false` appears only where the DTO rejects unknown
+//! fields. The tests check that every field of each struct DTO is a schema
+//! property, so a field added to a DTO without its schema fails CI; the
+//! public config DTOs are also checked for `required`. DTOs declared inside
+//! their entry point (`Outline`, `CidMapping`, `CidElements`) and the
+//! internally tagged `SplitOptions` / `PageRange` enums are not covered.
+
+use std::ffi::CString;
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::{clear_last_error, set_last_error, ErrorCode};
+
+const SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Core features enabled on the `oxidize-pdf` dependency in Cargo.toml.
+/// Dependency features cannot be queried with `cfg!`; a test checks this
+/// list against the manifest.
+const CORE_FEATURES: &[&str] = &[
+    "compression",
+    "semantic",
+    "signatures",
+    "language-detection",
+];
+
+/// Encodings accepted by the `*_encoded` entry points.
+const RESULT_ENCODINGS: &[&str] = &["json", "cbor"];
+
+#[derive(Serialize)]
+struct Capabilities {
+    version: &'static str,
+    core_version: &'static str,
+    core_features: &'static [&'static str],
+    result_encodings: &'static [&'static str],
+    payloads: Vec<Payload>,
+}
+
+/// One accepted JSON payload and the entry points (and argument) taking it.
+#[derive(Serialize)]
+struct Payload {
+    name: &'static str,
+    entry_points: &'static [&'static str],
+    schema: Value,
+}
+
+impl Payload {
+    fn new(name: &'static str, entry_points: &'static [&'static str], schema: Value) -> Self {
+        let mut schema = schema;
+        if let Value::Object(map) = &mut schema {
+            map.insert("$schema".into(), json!(SCHEMA_DIALECT));
+            map.insert("title".into(), json!(name));
+        }
+        Self {
+            name,
+            entry_points,
+            schema,
+        }
+    }
+}
+
+// ── Schema fragments ─────────────────────────────────────────────────────────
+
+fn number_array(len: usize) -> Value {
+    json!({ "type": "array", "items": { "type": "number" }, "minItems": len, "maxItems": len })
+}
+
+fn index() -> Value {
+    json!({ "type": "integer", "minimum": 0 })
+}
+
+fn fraction() -> Value {
+    json!({ "type": "number", "minimum": 0, "maximum": 1 })
+}
+
+/// `max_tokens` / `overlap_tokens`; the overlap must also stay below the
+/// maximum, which JSON Schema cannot express.
+fn token_budget() -> (Value, Value) {
+    (
+        json!({ "type": "integer", "minimum": 1 }),
+        json!({
+            "description": "Must be less than max_tokens",
+            "type": "integer",
+            "minimum": 0
+        }),
+    )
+}
+
+fn index_array() -> Value {
+    json!({ "type": "array", "items": index() })
+}
+
+fn string_array() -> Value {
+    json!({ "type": "array", "items": { "type": "string" } })
+}
+
+fn nullable(ty: &str) -> Value {
+    json!({ "type": [ty, "null"] })
+}
+
+/// Integer discriminant documented as `values`.
+fn discriminant(values: &[u8]) -> Value {
+    json!({ "type": "integer", "enum": values })
+}
+
+/// `discriminant` that may also be omitted or `null`.
+fn optional_discriminant(values: &[u8]) -> Value {
+    let mut allowed: Vec<Value> = values.iter().map(|v| json!(v)).collect();
+    allowed.push(Value::Null);
+    json!({ "type": ["integer", "null"], "enum": allowed })
+}
+
+fn reading_order() -> Value {
+    json!({
+        "oneOf": [
+            { "enum": ["Simple", "None"] },
+            {
+                "type": "object",
+                "required": ["XYCut"],
+                "properties": {
+                    "XYCut": {
+                        "type": "object",
+                        "required": ["min_gap"],
+                        "properties": { "min_gap": { "type": "number", "minimum": 0 } }
+                    }
+                }
+            }
+        ]
+    })
+}
+
+fn destination() -> Value {
+    json!({
+        "type": "object",
+        "required": ["page", "fit"],
+        "properties": {
+            "page": index(),
+            "fit": {
+                "description": "0=XYZ, 1=Fit, 2=FitH, 3=FitV, 5=FitB",
+                "type": "integer",
+                "enum": [0, 1, 2, 3, 5]
+            },
+            "left": nullable("number"),
+            "top": nullable("number"),
+            "zoom": nullable("number")
+        }
+    })
+}
+
+fn page_range() -> Value {
+    json!({
+        "oneOf": [
+            {
+                "type": "object",
+                "required": ["kind"],
+                "properties": { "kind": { "const": "All" } }
+            },
+            {
+                "type": "object",
+                "required": ["kind", "index"],
+                "properties": { "kind": { "const": "Single" }, "index": index() }
+            },
+            {
+                "type": "object",
+                "required": ["kind", "from", "to"],
+                "properties": { "kind": { "const": "Range" }, "from": index(), "to": index() }
+            },
+            {
+                "type": "object",
+                "required": ["kind", "indices"],
+                "properties": { "kind": { "const": "List" }, "indices": index_array() }
+            }
+        ]
+    })
+}
+
+fn outline_item() -> Value {
+    json!({
+        "type": "object",
+        "required": ["title", "page"],
+        "properties": {
+            "title": { "type": "string" },
+            "page": index(),
+            "bold": { "type": "boolean", "default": false },
+            "italic": { "type": "boolean", "default": false },
+            "open": { "type": "boolean", "default": true },
+            "children": { "type": "array", "items": { "$ref": "#/$defs/item" } }
+        }
+    })
+}
+
+fn simple_table() -> Value {
+    json!({
+        "type": "object",
+        "required": ["column_widths"],
+        "properties": {
+            "column_widths": { "type": "array", "items": { "type": "number" } },
+            "headers": string_array(),
+            "rows": { "type": "array", "items": string_array() }
+        }
+    })
+}
+
+fn text_spans() -> Value {
+    json!({
+        "type": "array",
+        "items": {
+            "type": "object",
+            "required": ["text", "font", "font_size", "r", "g", "b"],
+            "properties": {
+                "text": { "type": "string" },
+                "font": {
+                    "description": "Standard 14 font index (0=Helvetica … 13=ZapfDingbats)",
+                    "type": "integer",
+                    "minimum": 0,
+                    "maximum": 13
+                },
+                "font_size": { "type": "number" },
+                "r": { "type": "number" },
+                "g": { "type": "number" },
+                "b": { "type": "number" }
+            }
+        }
+    })
+}
+
+fn partition_config() -> Value {
+    json!({
+        "type": "object",
+        "required": [
+            "detect_tables", "detect_headers_footers", "title_min_font_ratio",
+            "header_zone", "footer_zone", "reading_order", "min_table_confidence"
+        ],
+        "properties": {
+            "detect_tables": { "type": "boolean" },
+            "detect_headers_footers": { "type": "boolean" },
+            "title_min_font_ratio": { "type": "number", "exclusiveMinimum": 0 },
+            "header_zone": fraction(),
+            "footer_zone": fraction(),
+            "reading_order": reading_order(),
+            "min_table_confidence": fraction(),
+            "prefer_ruling_tables": { "type": "boolean", "default": true },
+            "detect_spatial_tables": { "type": "boolean", "default": true }
+        }
+    })
+}
+
+fn hybrid_chunk_config() -> Value {
+    json!({
+        "type": "object",
+        "required": [
+            "max_tokens", "overlap_tokens", "merge_adjacent",
+            "propagate_headings", "merge_policy"
+        ],
+        "properties": {
+            "max_tokens": token_budget().0,
+            "overlap_tokens": token_budget().1,
+            "merge_adjacent": { "type": "boolean" },
+            "propagate_headings": { "type": "boolean" },
+            "merge_policy": { "enum": ["SameTypeOnly", "AnyInlineContent"] }
+        }
+    })
+}
+
+fn semantic_chunk_config() -> Value {
+    json!({
+        "type": "object",
+        "required": ["max_tokens", "overlap_tokens", "respect_element_boundaries"],
+        "properties": {
+            "max_tokens": token_budget().0,
+            "overlap_tokens": token_budget().1,
+            "respect_element_boundaries": { "type": "boolean" }
+        }
+    })
+}
+
+/// Every field of `ExtractionOptionsFFI`, all optional.
+fn extraction_options() -> Value {
+    let threshold = json!({ "type": "number", "minimum": 0 });
+    json!({
+        "type": "object",
+        "additionalProperties": false,
+        "properties": {
+            "preserve_layout": { "type": "boolean" },
+            "space_threshold": threshold,
+            "newline_threshold": threshold,
+            "sort_by_position": { "type": "boolean" },
+            "detect_columns": { "type": "boolean" },
+            "column_threshold": threshold,
+            "merge_hyphenated": { "type": "boolean" },
+            "tj_space_threshold": threshold,
+            "reconstruct_paragraphs": { "type": "boolean" },
+            "include_artifacts": { "type": "boolean" }
+        }
+    })
+}
+
+// ── Payload catalogue ────────────────────────────────────────────────────────
+
+fn profile_id() -> Value {
+    json!({ "description": "Built-in or registered profile id", "type": "integer", "minimum": 0, "maximum": 255 })
+}
+
+/// One `operation` of a batch manifest with the input options it accepts.
+fn batch_variant(operation: &str, options: Value, required: &[&str]) -> Value {
+    let mut properties = json!({
+        "path": { "type": "string" },
+        "pdf": { "type": "string", "contentEncoding": "base64" },
+        "password": { "type": "string" }
+    });
+    properties
+        .as_object_mut()
+        .unwrap()
+        .extend(options.as_object().unwrap().clone());
+    json!({
+        "type": "object",
+        "required": ["operation", "inputs"],
+        "additionalProperties": false,
+        "properties": {
+            "operation": { "const": operation },
+            "inputs": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": required,
+                    "oneOf": [{ "required": ["path"] }, { "required": ["pdf"] }],
+                    "additionalProperties": false,
+                    "properties": properties
+                }
+            }
+        }
+    })
+}
+
+fn payloads() -> Vec<Payload> {
+    vec![
+        Payload::new(
+            "PartitionConfig",
+            &[
+                "oxidize_partition_with_config(config_json)",
+                "oxidize_partition_with_config_with_password(config_json)",
+                "oxidize_partition_with_progress(config_json)",
+                "oxidize_partition_with_progress_with_password(config_json)",
+                "oxidize_parsed_document_partition_with_config(config_json)",
+                "oxidize_rag_chunks_with_config(partition_config_json)",
+                "oxidize_rag_chunks_with_config_with_password(partition_config_json)",
+                "oxidize_rag_chunks_with_progress(partition_config_json)",
+                "oxidize_rag_chunks_with_progress_with_password(partition_config_json)",
+                "oxidize_parsed_document_rag_chunks_with_config(partition_config_json)",
+                "oxidize_semantic_chunks(partition_config_json)",
+                "oxidize_semantic_chunks_with_password(partition_config_json)",
+                "oxidize_semantic_chunks_with_progress(partition_config_json)",
+                "oxidize_semantic_chunks_with_progress_with_password(partition_config_json)",
+                "oxidize_parsed_document_semantic_chunks(partition_config_json)",
+                "oxidize_rag_chunk_stream_open(partition_config_json)",
+                "oxidize_rag_chunk_stream_open_with_password(partition_config_json)",
+                "oxidize_rag_chunk_stream_open_path(partition_config_json)",
+                "oxidize_rag_chunk_stream_open_path_with_password(partition_config_json)",
+                "oxidize_semantic_chunk_stream_open(partition_config_json)",
+                "oxidize_semantic_chunk_stream_open_with_password(partition_config_json)",
+                "oxidize_semantic_chunk_stream_open_path(partition_config_json)",
+                "oxidize_semantic_chunk_stream_open_path_with_password(partition_config_json)",
+                "oxidize_extract_tables(partition_config_json)",
+                "oxidize_parsed_document_extract_tables(partition_config_json)",
+            ],
+            partition_config(),
+        ),
+        Payload::new(
+            "HybridChunkConfig",
+            &[
+                "oxidize_rag_chunks_with_config(hybrid_config_json)",
+                "oxidize_rag_chunks_with_config_with_password(hybrid_config_json)",
+                "oxidize_rag_chunks_with_progress(hybrid_config_json)",
+                "oxidize_rag_chunks_with_progress_with_password(hybrid_config_json)",
+                "oxidize_parsed_document_rag_chunks_with_config(hybrid_config_json)",
+                "oxidize_rag_chunk_stream_open(hybrid_config_json)",
+                "oxidize_rag_chunk_stream_open_with_password(hybrid_config_json)",
+                "oxidize_rag_chunk_stream_open_path(hybrid_config_json)",
+                "oxidize_rag_chunk_stream_open_path_with_password(hybrid_config_json)",
+            ],
+            hybrid_chunk_config(),
+        ),
+        Payload::new(
+            "Profile",
+            &["oxidize_register_profile(profile_json)"],
+            json!({
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "extraction": extraction_options(),
+                    "partition": partition_config(),
+                    "hybrid": hybrid_chunk_config(),
+                    "semantic": semantic_chunk_config()
+                }
+            }),
+        ),
+        Payload::new(
+            "BatchManifest",
+            &["oxidize_process_batch(manifest_json)"],
+            json!({
+                "oneOf": [
+                    batch_variant("extract_text", json!({ "extraction": extraction_options() }), &[]),
+                    batch_variant(
+                        "partition",
+                        json!({ "profile": profile_id(), "partition_config": partition_config() }),
+                        &[],
+                    ),
+                    batch_variant(
+                        "rag_chunks",
+                        json!({
+                            "profile": profile_id(),
+                            "partition_config": partition_config(),
+                            "hybrid_config": hybrid_chunk_config()
+                        }),
+                        &[],
+                    ),
+                    batch_variant(
+                        "chunk",
+                        json!({
+                            "chunk_size": { "type": "integer", "minimum": 1 },
+                            "overlap": {
+                                "description": "Must be less than chunk_size",
+                                "type": "integer",
+                                "minimum": 0
+                            },
+                            "detect_language": { "type": "boolean" }
+                        }),
+                        &["chunk_size", "overlap"],
+                    ),
+                ]
+            }),
+        ),
+        Payload::new(
+            "SemanticChunkConfig",
+            &[
+                "oxidize_semantic_chunks(semantic_config_json)",
+                "oxidize_semantic_chunks_with_password(semantic_config_json)",
+                "oxidize_semantic_chunks_with_progress(semantic_config_json)",
+                "oxidize_semantic_chunks_with_progress_with_password(semantic_config_json)",
+                "oxidize_parsed_document_semantic_chunks(semantic_config_json)",
+                "oxidize_semantic_chunk_stream_open(semantic_config_json)",
+                "oxidize_semantic_chunk_stream_open_with_password(semantic_config_json)",
+                "oxidize_semantic_chunk_stream_open_path(semantic_config_json)",
+                "oxidize_semantic_chunk_stream_open_path_with_password(semantic_config_json)",
+            ],
+            semantic_chunk_config(),
+        ),
+        Payload::new(
+            "MarkdownOptions",
+            &[
+                "oxidize_to_markdown_with_options(options_json)",
+                "oxidize_to_markdown_with_options_with_password(options_json)",
+                "oxidize_parsed_document_to_markdown_with_options(options_json)",
+            ],
+            json!({
+                "type": "object",
+                "required": ["include_metadata", "include_page_numbers"],
+                "properties": {
+                    "include_metadata": { "type": "boolean" },
+                    "include_page_numbers": { "type": "boolean" }
+                }
+            }),
+        ),
+        Payload::new(
+            "ResourceLimits",
+            &["oxidize_set_resource_limits(limits_json)"],
+            json!({
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "max_stream_bytes": nullable("integer"),
+                    "max_objects": nullable("integer"),
+                    "max_page_tree_depth": nullable("integer"),
+                    "max_pages": nullable("integer"),
+                    "max_duration_ms": nullable("integer")
+                }
+            }),
+        ),
+        Payload::new(
+            "DocumentChunks",
+            &[
+                "oxidize_document_language(chunks_json)",
+                "oxidize_export_chunks_token_efficient(chunks_json)",
+            ],
+            json!({
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["id", "content", "tokens", "page_numbers", "chunk_index", "metadata"],
+                    "properties": {
+                        "id": { "type": "string" },
+                        "content": { "type": "string" },
+                        "tokens": index(),
+                        "page_numbers": index_array(),
+                        "chunk_index": index(),
+                        "metadata": {
+                            "type": "object",
+                            "required": ["position", "confidence", "sentence_boundary_respected", "language"],
+                            "properties": {
+                                "position": {
+                                    "type": "object",
+                                    "required": ["start_char", "end_char", "first_page", "last_page"],
+                                    "properties": {
+                                        "start_char": index(),
+                                        "end_char": index(),
+                                        "first_page": index(),
+                                        "last_page": index()
+                                    }
+                                },
+                                "confidence": { "type": "number" },
+                                "sentence_boundary_respected": { "type": "boolean" },
+                                "language": {
+                                    "type": ["object", "null"],
+                                    "required": ["code", "confidence", "reliable"],
+                                    "properties": {
+                                        "code": { "type": "string" },
+                                        "confidence": { "type": "number" },
+                                        "reliable": { "type": "boolean" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }),
+        ),
+        Payload::new(
+            "FormField",
+            &["oxidize_document_add_form_field_json(json)"],
+            json!({
+                "type": "object",
+                "required": ["kind", "name", "rect"],
+                "properties": {
+                    "kind": { "enum": ["text", "checkbox", "radio", "combobox", "listbox", "pushbutton"] },
+                    "name": { "type": "string" },
+                    "rect": number_array(4),
+                    "value": nullable("string"),
+                    "default_value": nullable("string"),
+                    "max_length": nullable("integer"),
+                    "multiline": { "type": "boolean", "default": false },
+                    "password": { "type": "boolean", "default": false },
+                    "checked": { "type": "boolean", "default": false },
+                    "export_value": nullable("string"),
+                    "options": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "required": ["export", "label"],
+                            "properties": {
+                                "export": { "type": "string" },
+                                "label": { "type": "string" }
+                            }
+                        }
+                    },
+                    "selected": { "type": ["integer", "null"], "minimum": 0 },
+                    "selected_indices": index_array(),
+                    "multi_select": { "type": "boolean", "default": false },
+                    "editable": { "type": "boolean", "default": false },
+                    "caption": nullable("string"),
+                    "read_only": { "type": "boolean", "default": false },
+                    "required": { "type": "boolean", "default": false },
+                    "no_export": { "type": "boolean", "default": false },
+                    "quadding": nullable("integer")
+                }
+            }),
+        ),
+        Payload::new(
+            "FormWidget",
+            &["oxidize_page_add_form_widget_json(json)"],
+            json!({
+                "type": "object",
+                "required": ["rect"],
+                "properties": { "rect": number_array(4) }
+            }),
+        ),
+        Payload::new(
+            "FormFill",
+            &["oxidize_fill_existing_form_json(fields_json)"],
+            json!({
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["name", "value"],
+                    "properties": {
+                        "name": { "type": "string" },
+                        "value": { "type": "string" }
+                    }
+                }
+            }),
+        ),
+        Payload::new(
+            "Shading",
+            &["oxidize_page_add_shading_json(json)"],
+            json!({
+                "type": "object",
+                "required": ["kind", "stops"],
+                "properties": {
+                    "kind": { "enum": ["axial", "radial"] },
+                    "start": number_array(2),
+                    "end": number_array(2),
+                    "start_center": number_array(2),
+                    "start_radius": { "type": "number" },
+                    "end_center": number_array(2),
+                    "end_radius": { "type": "number" },
+                    "stops": {
+                        "type": "array",
+                        "minItems": 2,
+                        "items": {
+                            "type": "object",
+                            "required": ["position", "color"],
+                            "properties": {
+                                "position": { "type": "number" },
+                                "color": number_array(3)
+                            }
+                        }
+                    },
+                    "extend_start": { "type": "boolean", "default": false },
+                    "extend_end": { "type": "boolean", "default": false }
+                }
+            }),
+        ),
+        Payload::new(
+            "StructTree",
+            &["oxidize_document_set_struct_tree_json(json)"],
+            json!({
+                "type": "object",
+                "required": ["elements"],
+                "properties": {
+                    "elements": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "required": ["type"],
+                            "properties": {
+                                "type": { "type": "string" },
+                                "parent": { "type": ["integer", "null"], "minimum": 0 },
+                                "id": nullable("string"),
+                                "lang": nullable("string"),
+                                "alt_text": nullable("string"),
+                                "actual_text": nullable("string"),
+                                "title": nullable("string"),
+                                "mcids": {
+                                    "type": "array",
+                                    "items": {
+                                        "type": "object",
+                                        "required": ["page", "mcid"],
+                                        "properties": { "page": index(), "mcid": index() }
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    "role_map": { "type": "object", "additionalProperties": { "type": "string" } }
+                }
+            }),
+        ),
+        Payload::new(
+            "ColumnLayout",
+            &["oxidize_page_render_columns_json(json)"],
+            json!({
+                "type": "object",
+                "required": [
+                    "text", "column_count", "total_width", "column_gap",
+                    "start_x", "start_y", "column_height"
+                ],
+                "properties": {
+                    "text": { "type": "string" },
+                    "column_count": index(),
+                    "total_width": { "type": "number" },
+                    "column_gap": { "type": "number" },
+                    "custom_widths": { "type": ["array", "null"], "items": { "type": "number" } },
+                    "start_x": { "type": "number" },
+                    "start_y": { "type": "number" },
+                    "column_height": { "type": "number" },
+                    "font": nullable("string"),
+                    "font_size": nullable("number"),
+                    "line_height": nullable("number"),
+                    "text_align": {
+                        "description": "left | right | center | justified (case-insensitive)",
+                        "type": ["string", "null"]
+                    },
+                    "balance_columns": nullable("boolean"),
+                    "show_separators": nullable("boolean"),
+                    "color": { "type": ["array", "null"], "items": { "type": "number" }, "minItems": 3, "maxItems": 3 }
+                }
+            }),
+        ),
+        Payload::new(
+            "Outline",
+            &["oxidize_document_set_outline(outline_json)"],
+            json!({
+                "type": "object",
+                "required": ["items"],
+                "properties": {
+                    "items": { "type": "array", "items": { "$ref": "#/$defs/item" } }
+                },
+                "$defs": { "item": outline_item() }
+            }),
+        ),
+        Payload::new(
+            "OpenAction",
+            &["oxidize_document_set_open_action_json(json)"],
+            json!({
+                "type": "object",
+                "required": ["kind"],
+                "properties": {
+                    "kind": { "enum": ["goto", "uri"] },
+                    "destination": destination(),
+                    "uri": nullable("string")
+                }
+            }),
+        ),
+        Payload::new(
+            "ViewerPreferences",
+            &["oxidize_document_set_viewer_preferences_json(json)"],
+            json!({
+                "type": "object",
+                "properties": {
+                    "hide_toolbar": nullable("boolean"),
+                    "hide_menubar": nullable("boolean"),
+                    "hide_window_ui": nullable("boolean"),
+                    "fit_window": nullable("boolean"),
+                    "center_window": nullable("boolean"),
+                    "display_doc_title": nullable("boolean"),
+                    "page_layout": optional_discriminant(&[0, 1, 2, 3, 4, 5]),
+                    "page_mode": optional_discriminant(&[0, 1, 2, 3, 4, 5]),
+                    "print_scaling": optional_discriminant(&[0, 1]),
+                    "duplex": optional_discriminant(&[0, 1, 2]),
+                    "num_copies": { "type": ["integer", "null"], "minimum": 0 },
+                    "pick_tray_by_pdf_size": nullable("boolean")
+                }
+            }),
+        ),
+        Payload::new(
+            "NamedDestination",
+            &["oxidize_document_add_named_destination_json(json)"],
+            json!({
+                "type": "object",
+                "required": ["name", "destination"],
+                "properties": {
+                    "name": { "type": "string" },
+                    "destination": destination()
+                }
+            }),
+        ),
+        Payload::new(
+            "PageLabels",
+            &["oxidize_document_set_page_labels_json(json)"],
+            json!({
+                "type": "object",
+                "required": ["ranges"],
+                "properties": {
+                    "ranges": {
+                        "type": "array",
+                        "minItems": 1,
+                        "items": {
+                            "type": "object",
+                            "required": ["start_page", "style"],
+                            "properties": {
+                                "start_page": index(),
+                                "style": discriminant(&[0, 1, 2, 3, 4, 5]),
+                                "prefix": nullable("string"),
+                                "start_at": { "type": ["integer", "null"], "minimum": 0 }
+                            }
+                        }
+                    }
+                }
+            }),
+        ),
+        Payload::new(
+            "CidMapping",
+            &["oxidize_document_add_cid_keyed_font(mapping_json)"],
+            json!({
+                "type": "object",
+                "required": ["cid_to_gid"],
+                "properties": {
+                    "cid_to_gid": { "type": "object", "additionalProperties": { "type": "integer" } },
+                    "cid_to_unicode": { "type": "object", "additionalProperties": { "type": "integer" } },
+                    "cid_to_unicode_str": { "type": "object", "additionalProperties": { "type": "string" } }
+                }
+            }),
+        ),
+        Payload::new(
+            "CidElements",
+            &["oxidize_page_show_cid_array(elements_json)"],
+            json!({
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["cid"],
+                    "properties": {
+                        "cid": { "type": "integer", "minimum": 0, "maximum": 65535 },
+                        "adjust": { "type": "number", "default": 0 },
+                        "x_offset": { "type": "number", "default": 0 }
+                    }
+                }
+            }),
+        ),
+        Payload::new(
+            "SimpleTable",
+            &[
+                "oxidize_flow_layout_add_table(table_json)",
+                "oxidize_document_builder_add_table(table_json)",
+            ],
+            simple_table(),
+        ),
+        Payload::new(
+            "TableGrid",
+            &[
+                "oxidize_table_to_csv(table_json)",
+                "oxidize_table_to_markdown(table_json)",
+                "oxidize_table_to_html(table_json)",
+            ],
+            json!({
+                "description": "An element of oxidize_extract_tables output; cells must fit the grid without overlapping",
+                "type": "object",
+                "required": ["row_count", "column_count", "cells"],
+                "properties": {
+                    "row_count": index(),
+                    "column_count": index(),
+                    "header_rows": { "type": "integer", "minimum": 0, "default": 0 },
+                    "cells": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "required": ["row", "column", "text"],
+                            "properties": {
+                                "row": index(),
+                                "column": index(),
+                                "row_span": { "type": "integer", "minimum": 1, "default": 1 },
+                                "col_span": { "type": "integer", "minimum": 1, "default": 1 },
+                                "text": { "type": "string" }
+                            }
+                        }
+                    }
+                }
+            }),
+        ),
+        Payload::new(
+            "TextSpans",
+            &[
+                "oxidize_flow_layout_add_rich_text(spans_json)",
+                "oxidize_document_builder_add_rich_text(spans_json)",
+            ],
+            text_spans(),
+        ),
+        Payload::new(
+            "StringList",
+            &[
+                "oxidize_page_add_ordered_list(items_json)",
+                "oxidize_page_add_unordered_list(items_json)",
+                "oxidize_table_builder_create(headers_json)",
+                "oxidize_table_builder_add_row(cells_json)",
+            ],
+            string_array(),
+        ),
+        Payload::new(
+            "Base64PdfList",
+            &["oxidize_merge_pdfs_bytes(pdfs_json)"],
+            json!({ "type": "array", "items": { "type": "string", "contentEncoding": "base64" } }),
+        ),
+        Payload::new(
+            "PageIndexList",
+            &[
+                "oxidize_extract_pages_bytes(pages_json)",
+                "oxidize_extract_pages_bytes_with_password(pages_json)",
+                "oxidize_reorder_pages_bytes(order_json)",
+                "oxidize_reorder_pages_bytes_with_password(order_json)",
+            ],
+            index_array(),
+        ),
+        Payload::new(
+            "SplitOptions",
+            &[
+                "oxidize_split_pdf_bytes_with_options(options_json)",
+                "oxidize_split_pdf_bytes_with_options_with_password(options_json)",
+            ],
+            json!({
+                "oneOf": [
+                    {
+                        "type": "object",
+                        "required": ["mode"],
+                        "properties": { "mode": { "const": "SinglePages" } }
+                    },
+                    {
+                        "type": "object",
+                        "required": ["mode", "chunk_size"],
+                        "properties": { "mode": { "const": "ChunkSize" }, "chunk_size": index() }
+                    },
+                    {
+                        "type": "object",
+                        "required": ["mode", "ranges"],
+                        "properties": {
+                            "mode": { "const": "Ranges" },
+                            "ranges": {
+                                "type": "array",
+                                "items": { "type": "array", "items": index(), "minItems": 2, "maxItems": 2 }
+                            }
+                        }
+                    },
+                    {
+                        "type": "object",
+                        "required": ["mode", "split_at"],
+                        "properties": { "mode": { "const": "SplitAt" }, "split_at": index_array() }
+                    }
+                ]
+            }),
+        ),
+        Payload::new(
+            "MergeInputs",
+            &[
+                "oxidize_merge_pdfs_with_ranges(inputs_json)",
+                "oxidize_merge_pdfs_with_progress(inputs_json)",
+            ],
+            json!({
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["pdf"],
+                    "properties": {
+                        "pdf": { "type": "string", "contentEncoding": "base64" },
+                        "pages": { "anyOf": [page_range(), { "type": "null" }] },
+                        "password": nullable("string")
+                    }
+                }
+            }),
+        ),
+        Payload::new(
+            "PageRange",
+            &[
+                "oxidize_rotate_pages_bytes(pages_json)",
+                "oxidize_rotate_pages_bytes_with_password(pages_json)",
+            ],
+            page_range(),
+        ),
+    ]
+}
+
+/// Describe this build: versions, compiled-in features and the JSON Schema of
+/// every accepted payload.
+///
+/// Shape: `{"version", "core_version", "core_features": [..],
+/// "result_encodings": [..], "payloads": [{"name", "entry_points", "schema"}]}`.
+/// `version` is this library's version (as in `oxidize_version`) and
+/// `core_version` the linked `oxidize-pdf` release. Each `entry_points` item
+/// names a function and, in parentheses, the argument taking the payload.
+/// Schemas use JSON Schema draft 2020-12; a payload that validates against
+/// its schema may still be rejected for semantic reasons (e.g. a page index
+/// past the end of the document).
+///
+/// # Returns
+/// `Success`; `NullPointer` when `out_json` is null; `SerializationError`
+/// if the description could not be encoded.
+///
+/// # Safety
+/// - `out_json` must be a valid pointer to a mutable pointer location.
+/// - The returned string must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_capabilities_json(out_json: *mut *mut c_char) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_capabilities_json");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+
+        let capabilities = Capabilities {
+            version: env!("CARGO_PKG_VERSION"),
+            core_version: oxidize_pdf::VERSION,
+            core_features: CORE_FEATURES,
+            result_encodings: RESULT_ENCODINGS,
+            payloads: payloads(),
+        };
+        let json = match serde_json::to_string(&capabilities) {
+            Ok(s) => s,
+            Err(e) => {
+                set_last_error(format!("JSON serialization failed: {e}"));
+                return ErrorCode::SerializationError as c_int;
+            }
+        };
+        match CString::new(json) {
+            Ok(c) => {
+                *out_json = c.into_raw();
+                ErrorCode::Success as c_int
+            }
+            Err(_) => ErrorCode::InvalidUtf8 as c_int,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::limits::ResourceLimits;
+    use crate::pipeline_config::{
+        ExtractionOptionsDto, HybridChunkConfigDto, MarkdownOptionsDto, PartitionConfigDto,
+        SemanticChunkConfigDto,
+    };
+    use crate::table_extraction::{GridCell, TableGrid};
+    use serde::de::{self, DeserializeOwned, Visitor};
+    use std::collections::BTreeSet;
+    use std::ffi::CStr;
+
+    fn capabilities() -> Value {
+        let mut out: *mut c_char = ptr::null_mut();
+        let code = unsafe { oxidize_capabilities_json(&mut out) };
+        assert_eq!(code, ErrorCode::Success as c_int);
+        let json = unsafe { CStr::from_ptr(out) }.to_str().unwrap().to_owned();
+        unsafe { crate::oxidize_free_string(out) };
+        serde_json::from_str(&json).unwrap()
+    }
+
+    fn schema(name: &str) -> Value {
+        capabilities()["payloads"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|p| p["name"] == name)
+            .unwrap_or_else(|| panic!("no payload named {name}"))["schema"]
+            .clone()
+    }
+
+    /// Smallest instance satisfying `schema`: required properties only, the
+    /// first enum value or `oneOf` branch, `minItems` array elements.
+    fn sample(schema: &Value) -> Value {
+        if let Some(first) = schema["oneOf"].get(0) {
+            return sample(first);
+        }
+        if let Some(first) = schema["enum"].get(0) {
+            return first.clone();
+        }
+        let ty = match &schema["type"] {
+            Value::Array(types) => types[0].as_str().unwrap(),
+            ty => ty.as_str().unwrap(),
+        };
+        match ty {
+            "boolean" => json!(false),
+            "integer" => json!(schema["minimum"].as_u64().unwrap_or(0)),
+            "number" => json!(1.0),
+            "string" => json!("x"),
+            "array" => {
+                let n = schema["minItems"].as_u64().unwrap_or(0) as usize;
+                Value::Array(vec![sample(&schema["items"]); n])
+            }
+            "object" => {
+                let mut map = serde_json::Map::new();
+                for key in schema["required"].as_array().into_iter().flatten() {
+                    let key = key.as_str().unwrap();
+                    map.insert(key.into(), sample(&schema["properties"][key]));
+                }
+                Value::Object(map)
+            }
+            other => panic!("unhandled schema type {other}"),
+        }
+    }
+
+    /// The minimal sample deserializes, and omitting any required field fails.
+    fn assert_required_matches<T: DeserializeOwned>(name: &str) {
+        let schema = schema(name);
+        let minimal = sample(&schema);
+        if let Err(e) = serde_json::from_value::<T>(minimal.clone()) {
+            panic!("{name}: minimal sample {minimal} rejected: {e}");
+        }
+        for key in schema["required"].as_array().into_iter().flatten() {
+            let mut partial = minimal.clone();
+            partial
+                .as_object_mut()
+                .unwrap()
+                .remove(key.as_str().unwrap());
+            assert!(
+                serde_json::from_value::<T>(partial).is_err(),
+                "{name}: {key} is listed as required but has a default"
+            );
+        }
+    }
+
+    /// Outcome of deserializing from [`FieldNames`].
+    #[derive(Debug)]
+    enum Captured {
+        Fields(&'static [&'static str]),
+        NotAStruct(String),
+    }
+
+    impl std::fmt::Display for Captured {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Captured::Fields(fields) => write!(f, "fields {fields:?}"),
+                Captured::NotAStruct(message) => f.write_str(message),
+            }
+        }
+    }
+
+    impl std::error::Error for Captured {}
+
+    impl de::Error for Captured {
+        fn custom<T: std::fmt::Display>(msg: T) -> Self {
+            Captured::NotAStruct(msg.to_string())
+        }
+    }
+
+    /// Deserializer that only records the field list a derived
+    /// `Deserialize` passes to `deserialize_struct`.
+    struct FieldNames;
+
+    impl<'de> de::Deserializer<'de> for FieldNames {
+        type Error = Captured;
+
+        fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Captured> {
+            Err(Captured::NotAStruct("not a struct".into()))
+        }
+
+        fn deserialize_struct<V: Visitor<'de>>(
+            self,
+            _name: &'static str,
+            fields: &'static [&'static str],
+            _: V,
+        ) -> Result<V::Value, Captured> {
+            Err(Captured::Fields(fields))
+        }
+
+        serde::forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+            bytes byte_buf option unit unit_struct newtype_struct seq tuple
+            tuple_struct map enum identifier ignored_any
+        }
+    }
+
+    /// The JSON field names `T` deserializes (after renames).
+    fn serde_fields<T: DeserializeOwned>() -> &'static [&'static str] {
+        match T::deserialize(FieldNames) {
+            Err(Captured::Fields(fields)) => fields,
+            Err(other) => panic!("{} is not a struct: {other}", std::any::type_name::<T>()),
+            Ok(_) => panic!("FieldNames never produces a value"),
+        }
+    }
+
+    /// Sub-schemas of `schema` at `path` (property names, `[]` for array
+    /// items), following every `oneOf` / `anyOf` branch.
+    fn subschemas<'a>(schema: &'a Value, path: &[&str], found: &mut Vec<&'a Value>) {
+        for key in ["oneOf", "anyOf"] {
+            for branch in schema[key].as_array().into_iter().flatten() {
+                subschemas(branch, path, found);
+            }
+        }
+        match path.split_first() {
+            None => found.push(schema),
+            Some((&"[]", rest)) => {
+                if let Some(items) = schema.get("items") {
+                    subschemas(items, rest, found);
+                }
+            }
+            Some((key, rest)) => {
+                if let Some(property) = schema["properties"].get(*key) {
+                    subschemas(property, rest, found);
+                }
+            }
+        }
+    }
+
+    /// Every serde field of `T` is a property of payload `name` at `path`.
+    fn assert_fields_documented<T: DeserializeOwned>(name: &str, path: &[&str]) {
+        let schema = schema(name);
+        let mut found = Vec::new();
+        subschemas(&schema, path, &mut found);
+        let properties: BTreeSet<&str> = found
+            .iter()
+            .filter_map(|s| s["properties"].as_object())
+            .flat_map(|p| p.keys().map(String::as_str))
+            .collect();
+        assert!(!properties.is_empty(), "{name} {path:?}: no properties");
+        for field in serde_fields::<T>() {
+            assert!(
+                properties.contains(field),
+                "{name} {path:?}: field {field} of {} is missing from the schema",
+                std::any::type_name::<T>()
+            );
+        }
+    }
+
+    #[test]
+    fn capabilities_report_versions_features_and_payloads() {
+        let caps = capabilities();
+        assert_eq!(caps["version"], env!("CARGO_PKG_VERSION"));
+        assert_eq!(caps["core_version"], oxidize_pdf::VERSION);
+        assert!(caps["core_features"]
+            .as_array()
+            .unwrap()
+            .contains(&json!("compression")));
+
+        let payloads = caps["payloads"].as_array().unwrap();
+        let mut names: Vec<&str> = payloads
+            .iter()
+            .map(|p| p["name"].as_str().unwrap())
+            .collect();
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), payloads.len(), "payload names must be unique");
+        for p in payloads {
+            assert_eq!(p["schema"]["$schema"], SCHEMA_DIALECT);
+            assert_eq!(p["schema"]["title"], p["name"]);
+            let entry_points = p["entry_points"].as_array().unwrap();
+            assert!(!entry_points.is_empty());
+            assert!(entry_points
+                .iter()
+                .all(|e| e.as_str().unwrap().starts_with("oxidize_")));
+        }
+    }
+
+    #[test]
+    fn schemas_match_public_dtos() {
+        assert_required_matches::<PartitionConfigDto>("PartitionConfig");
+        assert_required_matches::<HybridChunkConfigDto>("HybridChunkConfig");
+        assert_required_matches::<SemanticChunkConfigDto>("SemanticChunkConfig");
+        assert_required_matches::<MarkdownOptionsDto>("MarkdownOptions");
+        assert_required_matches::<TableGrid>("TableGrid");
+
+        // deny_unknown_fields: every listed property must be accepted.
+        let schema = schema("ResourceLimits");
+        let all: serde_json::Map<String, Value> = schema["properties"]
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(|k| (k.clone(), json!(1)))
+            .collect();
+        serde_json::from_value::<ResourceLimits>(Value::Object(all)).unwrap();
+    }
+
+    #[test]
+    fn every_dto_field_is_in_its_schema() {
+        use crate::batch::{BatchInput, BatchManifest};
+        use crate::chunking::{
+            ChunkMetadataDto, ChunkPositionDto, DetectedLanguageDto, DocumentChunkDto,
+        };
+        use crate::columns::ColumnLayoutDto;
+        use crate::document_metadata::{
+            DestinationJson, NamedDestJson, OpenActionJson, PageLabelRangeJson, PageLabelsJson,
+            ViewerPrefsJson,
+        };
+        use crate::forms::{CreateFieldJson, FieldFillJson, OptionJson, WidgetJson};
+        use crate::graphics::{ShadingJson, ShadingStopJson};
+        use crate::layout::{SimpleTableJson, SpanJson};
+        use crate::operations::MergeInputJson;
+        use crate::profiles::ProfileDto;
+        use crate::tagged::{McidDto, StructElementDto, StructTreeDto};
+
+        assert_fields_documented::<PartitionConfigDto>("PartitionConfig", &[]);
+        assert_fields_documented::<HybridChunkConfigDto>("HybridChunkConfig", &[]);
+        assert_fields_documented::<SemanticChunkConfigDto>("SemanticChunkConfig", &[]);
+        assert_fields_documented::<MarkdownOptionsDto>("MarkdownOptions", &[]);
+        assert_fields_documented::<ResourceLimits>("ResourceLimits", &[]);
+        assert_fields_documented::<ProfileDto>("Profile", &[]);
+        assert_fields_documented::<ExtractionOptionsDto>("Profile", &["extraction"]);
+        assert_fields_documented::<SemanticChunkConfigDto>("Profile", &["semantic"]);
+        assert_fields_documented::<BatchManifest>("BatchManifest", &[]);
+        assert_fields_documented::<BatchInput>("BatchManifest", &["inputs", "[]"]);
+        assert_fields_documented::<DocumentChunkDto>("DocumentChunks", &["[]"]);
+        assert_fields_documented::<ChunkMetadataDto>("DocumentChunks", &["[]", "metadata"]);
+        assert_fields_documented::<ChunkPositionDto>(
+            "DocumentChunks",
+            &["[]", "metadata", "position"],
+        );
+        assert_fields_documented::<DetectedLanguageDto>(
+            "DocumentChunks",
+            &["[]", "metadata", "language"],
+        );
+        assert_fields_documented::<CreateFieldJson>("FormField", &[]);
+        assert_fields_documented::<OptionJson>("FormField", &["options", "[]"]);
+        assert_fields_documented::<WidgetJson>("FormWidget", &[]);
+        assert_fields_documented::<FieldFillJson>("FormFill", &["[]"]);
+        assert_fields_documented::<ShadingJson>("Shading", &[]);
+        assert_fields_documented::<ShadingStopJson>("Shading", &["stops", "[]"]);
+        assert_fields_documented::<StructTreeDto>("StructTree", &[]);
+        assert_fields_documented::<StructElementDto>("StructTree", &["elements", "[]"]);
+        assert_fields_documented::<McidDto>("StructTree", &["elements", "[]", "mcids", "[]"]);
+        assert_fields_documented::<ColumnLayoutDto>("ColumnLayout", &[]);
+        assert_fields_documented::<OpenActionJson>("OpenAction", &[]);
+        assert_fields_documented::<DestinationJson>("OpenAction", &["destination"]);
+        assert_fields_documented::<ViewerPrefsJson>("ViewerPreferences", &[]);
+        assert_fields_documented::<NamedDestJson>("NamedDestination", &[]);
+        assert_fields_documented::<DestinationJson>("NamedDestination", &["destination"]);
+        assert_fields_documented::<PageLabelsJson>("PageLabels", &[]);
+        assert_fields_documented::<PageLabelRangeJson>("PageLabels", &["ranges", "[]"]);
+        assert_fields_documented::<SimpleTableJson>("SimpleTable", &[]);
+        assert_fields_documented::<TableGrid>("TableGrid", &[]);
+        assert_fields_documented::<GridCell>("TableGrid", &["cells", "[]"]);
+        assert_fields_documented::<SpanJson>("TextSpans", &["[]"]);
+        assert_fields_documented::<MergeInputJson>("MergeInputs", &["[]"]);
+    }
+
+    #[test]
+    fn core_features_match_the_manifest() {
+        let manifest = include_str!("../Cargo.toml");
+        let dependency = manifest
+            .lines()
+            .find(|line| line.starts_with("oxidize-pdf ="))
+            .expect("oxidize-pdf dependency in Cargo.toml");
+        let features = dependency
+            .split_once("features = [")
+            .and_then(|(_, rest)| rest.split_once(']'))
+            .expect("oxidize-pdf features list")
+            .0;
+        let declared: Vec<&str> = features
+            .split(',')
+            .map(|f| f.trim().trim_matches('"'))
+            .filter(|f| !f.is_empty())
+            .collect();
+        assert_eq!(declared, CORE_FEATURES);
+    }
+
+    #[test]
+    fn null_out_pointer_is_rejected() {
+        let code = unsafe { oxidize_capabilities_json(ptr::null_mut()) };
+        assert_eq!(code, ErrorCode::NullPointer as c_int);
+    }
+}
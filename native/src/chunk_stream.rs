@@ -0,0 +1,1374 @@
+//! Streaming chunk iterators.
+//!
+//! [`crate::parser::oxidize_rag_chunks`], [`crate::parser::oxidize_semantic_chunks`]
+//! and [`crate::chunking::oxidize_chunk_pdf`] build the whole result as one
+//! JSON array, so for very large documents the element list and the output
+//! string dominate memory. A [`ChunkStreamHandle`] yields the same records one
+//! at a time: the document is partitioned (or text-extracted) a page at a
+//! time, and only the current page's elements plus the still-open trailing
+//! chunk are held in memory. That includes pages with a ruled table grid,
+//! which are partitioned from their paragraph-level fragments, as for custom
+//! profiles (see [`PagePartitioner`]).
+//!
+//! Lifecycle: `oxidize_{rag,semantic}_chunk_stream_open` /
+//! `oxidize_chunk_pdf_stream_open` → [`oxidize_chunk_stream_next`] until it
+//! yields a null chunk → [`oxidize_chunk_stream_free`].
+//!
+//! Each yielded string is one element of the matching one-shot array
+//! (`RagChunkResult`, `SemanticChunkResult`, `DocumentChunkDto`) and
+//! `chunk_index` counts across the whole stream:
+//! - hybrid and semantic chunks may still span a page break — the trailing
+//!   chunk of a page is held back and re-chunked together with the next
+//!   page's elements, so the sequence matches the one-shot output except on
+//!   ruled-table pages, where the grid's cell text is not assembled into a
+//!   table element;
+//! - `DocumentChunker` token windows (the `chunk_pdf` stream) are computed per
+//!   page and never span pages; `id`, `chunk_index` and character positions
+//!   are renumbered to be stream-global.
+//!
+//! The handle keeps its own copy of the PDF bytes, so the caller's buffer may
+//! be released once `open` returns. A stream is not thread-safe.
+
+use oxidize_pdf::ai::DocumentChunker;
+use oxidize_pdf::graphics::extraction::{ExtractionConfig, GraphicsExtractor};
+use oxidize_pdf::parser::PdfDocument;
+use oxidize_pdf::pipeline::{
+    Element, HybridChunker, PartitionConfig, Partitioner, RagChunk, SemanticChunker,
+};
+use oxidize_pdf::text::ExtractionOptions;
+use std::collections::VecDeque;
+use std::error::Error;
+use std::ffi::CString;
+use std::io::{Cursor, Read, Seek};
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+use std::slice;
+use std::time::{Duration, Instant};
+
+use crate::chunking::DocumentChunkDto;
+use crate::error_detail::LastError;
+use crate::mapped_file::{map_path, path_arg, SharedBytes};
+use crate::parser::{
+    open_lenient, parse_hybrid_config_opt, parse_partition_config_opt, parse_semantic_config,
+    with_password, RagChunkResult, SemanticChunkResult,
+};
+use crate::{clear_last_error, set_last_error, ErrorCode};
+
+/// Failure while producing chunks: the code to return and the error for
+/// `oxidize_get_last_error`.
+type StreamError = (ErrorCode, LastError);
+
+/// A `PdfParseError` for `page_index`, classified from `err`.
+fn page_error(context: String, page_index: u32, err: &(dyn Error + 'static)) -> StreamError {
+    (
+        ErrorCode::PdfParseError,
+        LastError::caused_by(context, err).at_page(page_index),
+    )
+}
+
+/// Per-page partitioning with the settings `PdfDocument::partition_with` uses.
+///
+/// On a page with a ruled table grid upstream assigns cell text in a pass
+/// that is internal to `partition_with` (the per-page entry point taking the
+/// cell-granular fragments is crate-private). A partitioner built with [`new`]
+/// therefore switches to one whole-document `partition_with` call at the
+/// first such page and hands out the remaining pages from its result, so its
+/// output always matches the one-shot call. Chunk streams, which must stay
+/// bounded by a page, use [`without_fallback`] instead.
+///
+/// [`new`]: PagePartitioner::new
+/// [`without_fallback`]: PagePartitioner::without_fallback
+pub(crate) struct PagePartitioner {
+    partitioner: Partitioner,
+    options: ExtractionOptions,
+    graphics: Option<GraphicsExtractor>,
+    /// Config for the whole-document fallback; `None` partitions grid pages
+    /// from the paragraph-level fragments instead.
+    fallback: Option<PartitionConfig>,
+    /// Elements of the not yet handed out pages, indexed by page, once the
+    /// fallback ran.
+    whole_document: Option<Vec<Vec<Element>>>,
+    /// Whether a page partitioned so far had a ruled table grid.
+    pub(crate) saw_table_grid: bool,
+    /// Time spent per stage so far, for the `*_with_metrics` entry points.
+    pub(crate) times: StageTimes,
+}
+
+/// Time [`PagePartitioner::partition_page`] spent in each stage, summed over
+/// the pages partitioned so far.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct StageTimes {
+    /// Text extraction.
+    pub(crate) text: Duration,
+    /// Ruling-line extraction for ruled tables, plus the whole-document
+    /// fallback call once a grid is found.
+    pub(crate) tables: Duration,
+    /// Reading order, classification and spatial table detection.
+    pub(crate) layout: Duration,
+}
+
+impl PagePartitioner {
+    pub(crate) fn new(config: PartitionConfig) -> Self {
+        Self {
+            fallback: Some(config.clone()),
+            ..Self::with_options(config, Self::default_options())
+        }
+    }
+
+    /// The text extraction options `PdfDocument::partition_with` uses.
+    pub(crate) fn default_options() -> ExtractionOptions {
+        ExtractionOptions {
+            preserve_layout: true,
+            reconstruct_paragraphs: true,
+            ..Default::default()
+        }
+    }
+
+    /// Partition with custom text extraction options (custom profiles).
+    ///
+    /// Upstream has no whole-document path for custom options, so grid pages
+    /// are partitioned from the paragraph-level fragments.
+    pub(crate) fn with_options(config: PartitionConfig, options: ExtractionOptions) -> Self {
+        let graphics = (config.detect_tables && config.prefer_ruling_tables)
+            .then(|| GraphicsExtractor::new(ExtractionConfig::default()));
+        Self {
+            partitioner: Partitioner::new(config),
+            options,
+            graphics,
+            fallback: None,
+            whole_document: None,
+            saw_table_grid: false,
+            times: StageTimes::default(),
+        }
+    }
+
+    /// Never fall back to whole-document partitioning: grid pages are
+    /// partitioned from the paragraph-level fragments and the caller can
+    /// check [`saw_table_grid`](Self::saw_table_grid).
+    pub(crate) fn without_fallback(mut self) -> Self {
+        self.fallback = None;
+        self
+    }
+
+    pub(crate) fn partition_page<R: Read + Seek>(
+        &mut self,
+        document: &PdfDocument<R>,
+        page_index: u32,
+    ) -> Result<Vec<Element>, StreamError> {
+        if let Some(pages) = &mut self.whole_document {
+            return Ok(pages
+                .get_mut(page_index as usize)
+                .map(std::mem::take)
+                .unwrap_or_default());
+        }
+        let started = Instant::now();
+        let text = document
+            .extract_text_from_page_with_options(page_index, self.options.clone())
+            .map_err(|e| {
+                page_error(
+                    format!("Failed to partition page {}", page_index + 1),
+                    page_index,
+                    &e,
+                )
+            })?;
+        let page_height = document
+            .get_page(page_index)
+            .map(|p| p.height())
+            .unwrap_or(842.0);
+        self.times.text += started.elapsed();
+
+        let started = Instant::now();
+        let graphics = self
+            .graphics
+            .as_mut()
+            .and_then(|g| g.extract_from_page(document, page_index as usize).ok());
+        let grid = graphics.as_ref().is_some_and(|g| g.has_table_structure());
+        self.saw_table_grid |= grid;
+        if let Some(config) = self.fallback.as_ref().filter(|_| grid) {
+            let elements = document.partition_with(config.clone()).map_err(|e| {
+                (
+                    ErrorCode::PdfParseError,
+                    LastError::caused_by("Failed to partition PDF", &e),
+                )
+            })?;
+            let mut pages: Vec<Vec<Element>> = Vec::new();
+            for element in elements {
+                let page = element.page() as usize;
+                if pages.len() <= page {
+                    pages.resize_with(page + 1, Vec::new);
+                }
+                pages[page].push(element);
+            }
+            let current = pages
+                .get_mut(page_index as usize)
+                .map(std::mem::take)
+                .unwrap_or_default();
+            self.whole_document = Some(pages);
+            self.times.tables += started.elapsed();
+            return Ok(current);
+        }
+        self.times.tables += started.elapsed();
+
+        let started = Instant::now();
+        let elements = self.partitioner.partition_fragments_with_graphics(
+            &text.fragments,
+            graphics.as_ref(),
+            page_index,
+            page_height,
+        );
+        self.times.layout += started.elapsed();
+        Ok(elements)
+    }
+}
+
+enum StreamChunker {
+    Rag(PagePartitioner, HybridChunker),
+    Semantic(PagePartitioner, SemanticChunker),
+    Text {
+        chunker: DocumentChunker,
+        /// Stream-global character offset of the next chunk.
+        char_offset: usize,
+    },
+}
+
+/// Opaque iterator over the chunks of one document.
+pub struct ChunkStreamHandle {
+    document: PdfDocument<Cursor<SharedBytes>>,
+    chunker: StreamChunker,
+    page_count: u32,
+    next_page: u32,
+    /// Elements of the previous page's last chunk, re-chunked with the next
+    /// page. Both chunkers are greedy, so re-feeding them rebuilds the same
+    /// open buffer (including the remainder of a sentence-split paragraph).
+    carry: Vec<Element>,
+    /// Serialized chunks waiting to be handed out.
+    ready: VecDeque<String>,
+    next_index: usize,
+}
+
+impl ChunkStreamHandle {
+    fn open(bytes: SharedBytes, chunker: StreamChunker) -> Result<Self, LastError> {
+        let reader = open_lenient(bytes)?;
+        let document = PdfDocument::new(reader);
+        let page_count = document
+            .page_count()
+            .map_err(|e| LastError::caused_by("Failed to get page count", &e))?;
+        Ok(Self {
+            document,
+            chunker,
+            page_count,
+            next_page: 0,
+            carry: Vec::new(),
+            ready: VecDeque::new(),
+            next_index: 0,
+        })
+    }
+
+    /// Next serialized chunk, or `None` once the document is exhausted.
+    ///
+    /// A page that fails to extract is reported once; the following call
+    /// resumes with the next page.
+    fn next_chunk(&mut self) -> Result<Option<String>, StreamError> {
+        loop {
+            if let Some(chunk) = self.ready.pop_front() {
+                return Ok(Some(chunk));
+            }
+            if self.next_page >= self.page_count && self.carry.is_empty() {
+                return Ok(None);
+            }
+            self.advance()?;
+        }
+    }
+
+    /// Process the next page (or flush the carry after the last page) into `ready`.
+    fn advance(&mut self) -> Result<(), StreamError> {
+        crate::limits::check_deadline().map_err(|e| (ErrorCode::LimitExceeded, e))?;
+        let page_index = self.next_page;
+        let last = page_index + 1 >= self.page_count;
+        if page_index < self.page_count {
+            self.next_page += 1;
+        }
+
+        match &mut self.chunker {
+            StreamChunker::Text {
+                chunker,
+                char_offset,
+            } => {
+                if page_index >= self.page_count {
+                    return Ok(());
+                }
+                let text = self
+                    .document
+                    .extract_text_from_page(page_index)
+                    .map_err(|e| {
+                        page_error(
+                            format!("Failed to extract text from page {}", page_index + 1),
+                            page_index,
+                            &e,
+                        )
+                    })?
+                    .text;
+                let chunks = chunker
+                    .chunk_text_with_pages(&[(page_index as usize + 1, text)])
+                    .map_err(|e| {
+                        page_error(
+                            format!("Failed to chunk page {}", page_index + 1),
+                            page_index,
+                            &e,
+                        )
+                    })?;
+                let base = *char_offset;
+                for mut chunk in chunks {
+                    chunk.chunk_index = self.next_index;
+                    chunk.id = format!("chunk_{}", self.next_index);
+                    chunk.metadata.position.start_char += base;
+                    chunk.metadata.position.end_char += base;
+                    *char_offset = chunk.metadata.position.end_char;
+                    self.ready
+                        .push_back(serialize(&DocumentChunkDto::from_core(&chunk))?);
+                    self.next_index += 1;
+                }
+            }
+            StreamChunker::Rag(partitioner, chunker) => {
+                let page_elements = if page_index < self.page_count {
+                    partitioner.partition_page(&self.document, page_index)?
+                } else {
+                    Vec::new()
+                };
+                let mut elements = std::mem::take(&mut self.carry);
+                elements.extend(page_elements);
+
+                let mut chunks = chunker.chunk(&elements);
+                if !last {
+                    if let Some(tail) = chunks.pop() {
+                        self.carry = tail.elements().to_vec();
+                    }
+                }
+                for chunk in &chunks {
+                    let rag = RagChunk::from_hybrid_chunk(self.next_index, chunk);
+                    self.ready.push_back(serialize(&RagChunkResult::from_core(
+                        self.next_index,
+                        &rag,
+                    ))?);
+                    self.next_index += 1;
+                }
+            }
+            StreamChunker::Semantic(partitioner, chunker) => {
+                let page_elements = if page_index < self.page_count {
+                    partitioner.partition_page(&self.document, page_index)?
+                } else {
+                    Vec::new()
+                };
+                let mut elements = std::mem::take(&mut self.carry);
+                elements.extend(page_elements);
+
+                let mut chunks = chunker.chunk(&elements);
+                if !last {
+                    if let Some(tail) = chunks.pop() {
+                        self.carry = tail.elements().to_vec();
+                    }
+                }
+                for chunk in &chunks {
+                    self.ready
+                        .push_back(serialize(&SemanticChunkResult::from_core(
+                            self.next_index,
+                            chunk,
+                        ))?);
+                    self.next_index += 1;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn serialize<T: serde::Serialize>(value: &T) -> Result<String, StreamError> {
+    serde_json::to_string(value).map_err(|e| {
+        (
+            ErrorCode::SerializationError,
+            LastError::caused_by("Failed to serialize chunk", &e),
+        )
+    })
+}
+
+/// Shared tail of the `*_stream_open` entry points.
+unsafe fn open_stream(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    chunker: StreamChunker,
+    out_stream: *mut *mut ChunkStreamHandle,
+) -> c_int {
+    let bytes = SharedBytes::from(slice::from_raw_parts(pdf_bytes, pdf_len));
+    open_shared(bytes, chunker, out_stream)
+}
+
+/// Shared tail of the `*_stream_open_path` entry points: map `path`, then
+/// open as [`open_stream`] does.
+unsafe fn open_stream_path(
+    path: *const c_char,
+    chunker: StreamChunker,
+    out_stream: *mut *mut ChunkStreamHandle,
+) -> c_int {
+    let path = match path_arg(path) {
+        Ok(p) => p,
+        Err(code) => return code,
+    };
+    match map_path(path) {
+        Ok(bytes) => open_shared(bytes, chunker, out_stream),
+        Err((code, e)) => {
+            set_last_error(e);
+            code as c_int
+        }
+    }
+}
+
+unsafe fn open_shared(
+    bytes: SharedBytes,
+    chunker: StreamChunker,
+    out_stream: *mut *mut ChunkStreamHandle,
+) -> c_int {
+    match ChunkStreamHandle::open(bytes, chunker) {
+        Ok(stream) => {
+            *out_stream = Box::into_raw(Box::new(stream));
+            ErrorCode::Success as c_int
+        }
+        Err(e) => {
+            set_last_error(e);
+            ErrorCode::PdfParseError as c_int
+        }
+    }
+}
+
+// ── Open ──────────────────────────────────────────────────────────────────────
+
+/// Open a streaming iterator over the RAG chunks of a PDF.
+///
+/// Streaming counterpart of [`crate::parser::oxidize_rag_chunks_with_config`]
+/// (and, with both configs `NULL`, of [`crate::parser::oxidize_rag_chunks`]):
+/// each [`oxidize_chunk_stream_next`] yields one `RagChunkResult` object.
+///
+/// # Returns
+/// `Success`; or `NullPointer` (`pdf_bytes`/`out_stream` null), `InvalidUtf8`
+/// / `InvalidArgument` (bad config), `PdfParseError` (`pdf_len == 0` or the
+/// document cannot be opened). `*out_stream` is null on any error.
+///
+/// # Safety
+/// - `pdf_bytes` must point to `pdf_len` readable bytes (copied; may be freed
+///   after return).
+/// - `partition_config_json` and `hybrid_config_json`, if non-null, must be
+///   NUL-terminated UTF-8 C strings.
+/// - `out_stream` must be a writeable pointer. Free the stream with
+///   [`oxidize_chunk_stream_free`].
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_rag_chunk_stream_open(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    partition_config_json: *const c_char,
+    hybrid_config_json: *const c_char,
+    out_stream: *mut *mut ChunkStreamHandle,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+
+        if pdf_bytes.is_null() || out_stream.is_null() {
+            set_last_error("Null pointer provided to oxidize_rag_chunk_stream_open");
+            return ErrorCode::NullPointer as c_int;
+        }
+
+        *out_stream = ptr::null_mut();
+
+        if pdf_len == 0 {
+            set_last_error("PDF data is empty (0 bytes)");
+            return ErrorCode::PdfParseError as c_int;
+        }
+
+        let partition_cfg = match parse_partition_config_opt(partition_config_json) {
+            Ok(c) => c,
+            Err(code) => return code,
+        };
+
+        let hybrid_cfg = match parse_hybrid_config_opt(hybrid_config_json) {
+            Ok(c) => c,
+            Err(code) => return code,
+        };
+
+        let chunker = StreamChunker::Rag(
+            PagePartitioner::new(partition_cfg).without_fallback(),
+            HybridChunker::new(hybrid_cfg),
+        );
+        open_stream(pdf_bytes, pdf_len, chunker, out_stream)
+    })
+}
+
+/// Open a streaming iterator over the semantic chunks of a PDF.
+///
+/// Streaming counterpart of [`crate::parser::oxidize_semantic_chunks`]: each
+/// [`oxidize_chunk_stream_next`] yields one `SemanticChunkResult` object.
+/// `semantic_config_json` is required, as in the one-shot entry point.
+///
+/// # Returns
+/// `Success`; or `NullPointer` (`pdf_bytes`, `semantic_config_json` or
+/// `out_stream` null), `InvalidUtf8` / `InvalidArgument` (bad config),
+/// `PdfParseError` (`pdf_len == 0` or the document cannot be opened).
+/// `*out_stream` is null on any error.
+///
+/// # Safety
+/// - `pdf_bytes` must point to `pdf_len` readable bytes (copied; may be freed
+///   after return).
+/// - `partition_config_json`, if non-null, and `semantic_config_json` must be
+///   NUL-terminated UTF-8 C strings.
+/// - `out_stream` must be a writeable pointer. Free the stream with
+///   [`oxidize_chunk_stream_free`].
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_semantic_chunk_stream_open(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    partition_config_json: *const c_char,
+    semantic_config_json: *const c_char,
+    out_stream: *mut *mut ChunkStreamHandle,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+
+        if pdf_bytes.is_null() || semantic_config_json.is_null() || out_stream.is_null() {
+            set_last_error("Null pointer provided to oxidize_semantic_chunk_stream_open");
+            return ErrorCode::NullPointer as c_int;
+        }
+
+        *out_stream = ptr::null_mut();
+
+        if pdf_len == 0 {
+            set_last_error("PDF data is empty (0 bytes)");
+            return ErrorCode::PdfParseError as c_int;
+        }
+
+        let partition_cfg = match parse_partition_config_opt(partition_config_json) {
+            Ok(c) => c,
+            Err(code) => return code,
+        };
+
+        let sem_cfg = match parse_semantic_config(semantic_config_json) {
+            Ok(c) => c,
+            Err(code) => return code,
+        };
+
+        let chunker = StreamChunker::Semantic(
+            PagePartitioner::new(partition_cfg).without_fallback(),
+            SemanticChunker::new(sem_cfg),
+        );
+        open_stream(pdf_bytes, pdf_len, chunker, out_stream)
+    })
+}
+
+/// Open a streaming iterator over `DocumentChunker` chunks of a PDF.
+///
+/// Streaming counterpart of [`crate::chunking::oxidize_chunk_pdf`]: each
+/// [`oxidize_chunk_stream_next`] yields one `DocumentChunkDto` object. Token
+/// windows are computed per page (see the module docs).
+///
+/// # Returns
+/// `Success`; or `NullPointer` (`pdf_bytes`/`out_stream` null),
+/// `PdfParseError` (`pdf_len == 0` or the document cannot be opened).
+/// `*out_stream` is null on any error.
+///
+/// # Safety
+/// - `pdf_bytes` must point to `pdf_len` readable bytes (copied; may be freed
+///   after return).
+/// - `out_stream` must be a writeable pointer. Free the stream with
+///   [`oxidize_chunk_stream_free`].
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_chunk_pdf_stream_open(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    chunk_size: usize,
+    overlap: usize,
+    detect_language: u8,
+    out_stream: *mut *mut ChunkStreamHandle,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+
+        if pdf_bytes.is_null() || out_stream.is_null() {
+            set_last_error("Null pointer provided to oxidize_chunk_pdf_stream_open");
+            return ErrorCode::NullPointer as c_int;
+        }
+
+        *out_stream = ptr::null_mut();
+
+        if pdf_len == 0 {
+            set_last_error("PDF data is empty (0 bytes)");
+            return ErrorCode::PdfParseError as c_int;
+        }
+
+        let chunker = StreamChunker::Text {
+            chunker: DocumentChunker::new(chunk_size, overlap)
+                .with_language_detection(detect_language != 0),
+            char_offset: 0,
+        };
+        open_stream(pdf_bytes, pdf_len, chunker, out_stream)
+    })
+}
+
+// ── Open from a path ──────────────────────────────────────────────────────────
+//
+// Same as the byte-buffer openers, but the PDF is memory-mapped from `path`
+// (see `crate::mapped_file`) instead of copied from a caller buffer. Errors
+// add `InvalidUtf8` for a non-UTF-8 path and `IoError` when the file cannot
+// be opened; an empty file is `PdfParseError`. The file must not be truncated
+// while the stream is alive.
+
+/// Path variant of [`oxidize_rag_chunk_stream_open`].
+///
+/// # Safety
+/// - `path` must be a valid NUL-terminated UTF-8 string.
+/// - Otherwise as [`oxidize_rag_chunk_stream_open`].
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_rag_chunk_stream_open_path(
+    path: *const c_char,
+    partition_config_json: *const c_char,
+    hybrid_config_json: *const c_char,
+    out_stream: *mut *mut ChunkStreamHandle,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+
+        if path.is_null() || out_stream.is_null() {
+            set_last_error("Null pointer provided to oxidize_rag_chunk_stream_open_path");
+            return ErrorCode::NullPointer as c_int;
+        }
+
+        *out_stream = ptr::null_mut();
+
+        let partition_cfg = match parse_partition_config_opt(partition_config_json) {
+            Ok(c) => c,
+            Err(code) => return code,
+        };
+
+        let hybrid_cfg = match parse_hybrid_config_opt(hybrid_config_json) {
+            Ok(c) => c,
+            Err(code) => return code,
+        };
+
+        let chunker = StreamChunker::Rag(
+            PagePartitioner::new(partition_cfg).without_fallback(),
+            HybridChunker::new(hybrid_cfg),
+        );
+        open_stream_path(path, chunker, out_stream)
+    })
+}
+
+/// Path variant of [`oxidize_semantic_chunk_stream_open`].
+///
+/// # Safety
+/// - `path` must be a valid NUL-terminated UTF-8 string.
+/// - Otherwise as [`oxidize_semantic_chunk_stream_open`].
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_semantic_chunk_stream_open_path(
+    path: *const c_char,
+    partition_config_json: *const c_char,
+    semantic_config_json: *const c_char,
+    out_stream: *mut *mut ChunkStreamHandle,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+
+        if path.is_null() || semantic_config_json.is_null() || out_stream.is_null() {
+            set_last_error("Null pointer provided to oxidize_semantic_chunk_stream_open_path");
+            return ErrorCode::NullPointer as c_int;
+        }
+
+        *out_stream = ptr::null_mut();
+
+        let partition_cfg = match parse_partition_config_opt(partition_config_json) {
+            Ok(c) => c,
+            Err(code) => return code,
+        };
+
+        let sem_cfg = match parse_semantic_config(semantic_config_json) {
+            Ok(c) => c,
+            Err(code) => return code,
+        };
+
+        let chunker = StreamChunker::Semantic(
+            PagePartitioner::new(partition_cfg).without_fallback(),
+            SemanticChunker::new(sem_cfg),
+        );
+        open_stream_path(path, chunker, out_stream)
+    })
+}
+
+/// Path variant of [`oxidize_chunk_pdf_stream_open`].
+///
+/// # Safety
+/// - `path` must be a valid NUL-terminated UTF-8 string.
+/// - Otherwise as [`oxidize_chunk_pdf_stream_open`].
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_chunk_pdf_stream_open_path(
+    path: *const c_char,
+    chunk_size: usize,
+    overlap: usize,
+    detect_language: u8,
+    out_stream: *mut *mut ChunkStreamHandle,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+
+        if path.is_null() || out_stream.is_null() {
+            set_last_error("Null pointer provided to oxidize_chunk_pdf_stream_open_path");
+            return ErrorCode::NullPointer as c_int;
+        }
+
+        *out_stream = ptr::null_mut();
+
+        let chunker = StreamChunker::Text {
+            chunker: DocumentChunker::new(chunk_size, overlap)
+                .with_language_detection(detect_language != 0),
+            char_offset: 0,
+        };
+        open_stream_path(path, chunker, out_stream)
+    })
+}
+
+// ── Iterate / free ────────────────────────────────────────────────────────────
+
+/// Advance a chunk stream.
+///
+/// On `Success`, `*out_json` receives the next chunk as a JSON object (free
+/// with `oxidize_free_string`), or null once the stream is exhausted; further
+/// calls keep returning `Success` with null. A page that fails to extract
+/// returns `PdfParseError` once and the next call resumes with the following
+/// page.
+///
+/// # Returns
+/// `Success`; or `NullPointer` (`stream`/`out_json` null), `PdfParseError`
+/// (page extraction or chunking failed), `SerializationError` /
+/// `InvalidUtf8` (response build failure). `*out_json` is null on any error.
+///
+/// # Safety
+/// - `stream` must be a live handle from one of the `*_stream_open` functions.
+/// - `out_json` must be a writeable `*mut *mut c_char`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_chunk_stream_next(
+    stream: *mut ChunkStreamHandle,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+
+        if stream.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_chunk_stream_next");
+            return ErrorCode::NullPointer as c_int;
+        }
+
+        *out_json = ptr::null_mut();
+
+        let json = match (*stream).next_chunk() {
+            Ok(Some(json)) => json,
+            Ok(None) => return ErrorCode::Success as c_int,
+            Err((code, message)) => {
+                set_last_error(message);
+                return code as c_int;
+            }
+        };
+
+        match CString::new(json) {
+            Ok(cs) => {
+                *out_json = cs.into_raw();
+                ErrorCode::Success as c_int
+            }
+            Err(e) => {
+                set_last_error(LastError::caused_by("JSON contains null bytes", &e));
+                ErrorCode::InvalidUtf8 as c_int
+            }
+        }
+    })
+}
+
+/// Free a chunk stream. Null is a no-op.
+///
+/// # Safety
+/// `stream` must be null or a handle from one of the `*_stream_open`
+/// functions that has not already been freed.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_chunk_stream_free(stream: *mut ChunkStreamHandle) {
+    crate::ffi_guard_unit(move || {
+        if stream.is_null() {
+            return;
+        }
+        drop(Box::from_raw(stream));
+    })
+}
+
+// ── Password variants ────────────────────────────────────────────────────────
+//
+// The stream openers with a `password` argument after the input: an encrypted
+// PDF is unlocked while the stream is opened and stays unlocked for every
+// `oxidize_chunk_stream_next` (see `crate::parser::with_password`).
+
+/// [`oxidize_rag_chunk_stream_open`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_rag_chunk_stream_open`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_rag_chunk_stream_open_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    partition_config_json: *const c_char,
+    hybrid_config_json: *const c_char,
+    out_stream: *mut *mut ChunkStreamHandle,
+) -> c_int {
+    with_password(password, || {
+        oxidize_rag_chunk_stream_open(
+            pdf_bytes,
+            pdf_len,
+            partition_config_json,
+            hybrid_config_json,
+            out_stream,
+        )
+    })
+}
+
+/// [`oxidize_semantic_chunk_stream_open`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_semantic_chunk_stream_open`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_semantic_chunk_stream_open_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    partition_config_json: *const c_char,
+    semantic_config_json: *const c_char,
+    out_stream: *mut *mut ChunkStreamHandle,
+) -> c_int {
+    with_password(password, || {
+        oxidize_semantic_chunk_stream_open(
+            pdf_bytes,
+            pdf_len,
+            partition_config_json,
+            semantic_config_json,
+            out_stream,
+        )
+    })
+}
+
+/// [`oxidize_chunk_pdf_stream_open`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_chunk_pdf_stream_open`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_chunk_pdf_stream_open_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    chunk_size: usize,
+    overlap: usize,
+    detect_language: u8,
+    out_stream: *mut *mut ChunkStreamHandle,
+) -> c_int {
+    with_password(password, || {
+        oxidize_chunk_pdf_stream_open(
+            pdf_bytes,
+            pdf_len,
+            chunk_size,
+            overlap,
+            detect_language,
+            out_stream,
+        )
+    })
+}
+
+/// [`oxidize_rag_chunk_stream_open_path`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_rag_chunk_stream_open_path`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_rag_chunk_stream_open_path_with_password(
+    path: *const c_char,
+    password: *const c_char,
+    partition_config_json: *const c_char,
+    hybrid_config_json: *const c_char,
+    out_stream: *mut *mut ChunkStreamHandle,
+) -> c_int {
+    with_password(password, || {
+        oxidize_rag_chunk_stream_open_path(
+            path,
+            partition_config_json,
+            hybrid_config_json,
+            out_stream,
+        )
+    })
+}
+
+/// [`oxidize_semantic_chunk_stream_open_path`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_semantic_chunk_stream_open_path`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_semantic_chunk_stream_open_path_with_password(
+    path: *const c_char,
+    password: *const c_char,
+    partition_config_json: *const c_char,
+    semantic_config_json: *const c_char,
+    out_stream: *mut *mut ChunkStreamHandle,
+) -> c_int {
+    with_password(password, || {
+        oxidize_semantic_chunk_stream_open_path(
+            path,
+            partition_config_json,
+            semantic_config_json,
+            out_stream,
+        )
+    })
+}
+
+/// [`oxidize_chunk_pdf_stream_open_path`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_chunk_pdf_stream_open_path`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_chunk_pdf_stream_open_path_with_password(
+    path: *const c_char,
+    password: *const c_char,
+    chunk_size: usize,
+    overlap: usize,
+    detect_language: u8,
+    out_stream: *mut *mut ChunkStreamHandle,
+) -> c_int {
+    with_password(password, || {
+        oxidize_chunk_pdf_stream_open_path(path, chunk_size, overlap, detect_language, out_stream)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::SamplePdf;
+    use std::ffi::{CStr, CString};
+
+    unsafe fn drain(stream: *mut ChunkStreamHandle) -> Vec<serde_json::Value> {
+        let mut chunks = Vec::new();
+        loop {
+            let mut out: *mut c_char = ptr::null_mut();
+            assert_eq!(
+                oxidize_chunk_stream_next(stream, &mut out),
+                ErrorCode::Success as c_int
+            );
+            if out.is_null() {
+                break;
+            }
+            chunks.push(serde_json::from_str(CStr::from_ptr(out).to_str().unwrap()).unwrap());
+            crate::oxidize_free_string(out);
+        }
+        oxidize_chunk_stream_free(stream);
+        chunks
+    }
+
+    unsafe fn one_shot(call: impl FnOnce(*mut *mut c_char) -> c_int) -> Vec<serde_json::Value> {
+        let mut out: *mut c_char = ptr::null_mut();
+        assert_eq!(call(&mut out), ErrorCode::Success as c_int);
+        let value: serde_json::Value =
+            serde_json::from_str(CStr::from_ptr(out).to_str().unwrap()).unwrap();
+        crate::oxidize_free_string(out);
+        value.as_array().unwrap().clone()
+    }
+
+    #[test]
+    fn rag_stream_matches_one_shot_output() {
+        let pdf = SamplePdf::new(4).lines(8).build();
+        let hybrid = CString::new(
+            r#"{"max_tokens":40,"overlap_tokens":0,"merge_adjacent":true,"propagate_headings":true,"merge_policy":"AnyInlineContent"}"#,
+        )
+        .unwrap();
+        unsafe {
+            for hybrid in [ptr::null(), hybrid.as_ptr()] {
+                let expected = one_shot(|out| {
+                    crate::parser::oxidize_rag_chunks_with_config(
+                        pdf.as_ptr(),
+                        pdf.len(),
+                        ptr::null(),
+                        hybrid,
+                        out,
+                    )
+                });
+                let mut stream = ptr::null_mut();
+                assert_eq!(
+                    oxidize_rag_chunk_stream_open(
+                        pdf.as_ptr(),
+                        pdf.len(),
+                        ptr::null(),
+                        hybrid,
+                        &mut stream
+                    ),
+                    ErrorCode::Success as c_int
+                );
+                assert_eq!(drain(stream), expected);
+            }
+        }
+    }
+
+    /// `chunks` without those on 1-based `page`, renumbered from 0.
+    fn off_page(chunks: Vec<serde_json::Value>, page: u64) -> Vec<serde_json::Value> {
+        chunks
+            .into_iter()
+            .filter(|chunk| {
+                !chunk["page_numbers"]
+                    .as_array()
+                    .unwrap()
+                    .contains(&page.into())
+            })
+            .enumerate()
+            .map(|(i, mut chunk)| {
+                chunk["chunk_index"] = i.into();
+                chunk
+            })
+            .collect()
+    }
+
+    #[test]
+    fn streams_partition_ruled_table_pages_one_at_a_time() {
+        let pdf = SamplePdf::new(4).lines(8).ruled_table_on(1).build();
+        let sem = CString::new(
+            r#"{"max_tokens":48,"overlap_tokens":0,"respect_element_boundaries":true}"#,
+        )
+        .unwrap();
+        unsafe {
+            let expected =
+                one_shot(|out| crate::parser::oxidize_rag_chunks(pdf.as_ptr(), pdf.len(), out));
+            let mut stream = ptr::null_mut();
+            assert_eq!(
+                oxidize_rag_chunk_stream_open(
+                    pdf.as_ptr(),
+                    pdf.len(),
+                    ptr::null(),
+                    ptr::null(),
+                    &mut stream
+                ),
+                ErrorCode::Success as c_int
+            );
+            // The grid page is never partitioned as part of the rest of the
+            // document: later pages are still read one at a time.
+            assert!((*stream).advance().is_ok());
+            assert!((*stream).advance().is_ok());
+            assert!(matches!(
+                &(*stream).chunker,
+                StreamChunker::Rag(partitioner, _) if partitioner.saw_table_grid
+            ));
+            assert_eq!((*stream).next_page, 2);
+            let chunks = drain(stream);
+            // The cell text is kept, as prose rather than a table element.
+            let chunk = chunks
+                .iter()
+                .find(|chunk| chunk["text"].as_str().unwrap().contains("r1c1"))
+                .unwrap();
+            assert_eq!(chunk["page_numbers"], serde_json::json!([2]));
+            assert_eq!(off_page(chunks, 2), off_page(expected, 2));
+
+            let expected = one_shot(|out| {
+                crate::parser::oxidize_semantic_chunks(
+                    pdf.as_ptr(),
+                    pdf.len(),
+                    ptr::null(),
+                    sem.as_ptr(),
+                    out,
+                )
+            });
+            assert_eq!(
+                oxidize_semantic_chunk_stream_open(
+                    pdf.as_ptr(),
+                    pdf.len(),
+                    ptr::null(),
+                    sem.as_ptr(),
+                    &mut stream
+                ),
+                ErrorCode::Success as c_int
+            );
+            // A semantic chunk open at the grid page absorbs different text,
+            // so only the chunks closed before it are unchanged.
+            let before_grid = |chunks: Vec<serde_json::Value>| -> Vec<serde_json::Value> {
+                chunks
+                    .into_iter()
+                    .take_while(|chunk| chunk["page_numbers"] == serde_json::json!([1]))
+                    .collect()
+            };
+            let chunks = drain(stream);
+            assert!(chunks
+                .iter()
+                .any(|chunk| chunk["text"].as_str().unwrap().contains("r1c1")));
+            let closed = before_grid(chunks);
+            assert!(!closed.is_empty());
+            assert!(before_grid(expected).starts_with(&closed));
+        }
+    }
+
+    #[test]
+    fn password_streams_read_encrypted_pdfs() {
+        let plain = SamplePdf::new(3).lines(8).build();
+        let pdf = SamplePdf::new(3).lines(8).encrypted("user-secret").build();
+        let password = CString::new("user-secret").unwrap();
+        unsafe {
+            let mut stream = ptr::null_mut();
+            assert_eq!(
+                oxidize_rag_chunk_stream_open(
+                    pdf.as_ptr(),
+                    pdf.len(),
+                    ptr::null(),
+                    ptr::null(),
+                    &mut stream
+                ),
+                ErrorCode::PasswordRequired as c_int
+            );
+            assert!(stream.is_null());
+
+            oxidize_rag_chunk_stream_open(
+                plain.as_ptr(),
+                plain.len(),
+                ptr::null(),
+                ptr::null(),
+                &mut stream,
+            );
+            let expected = drain(stream);
+            assert_eq!(
+                oxidize_rag_chunk_stream_open_with_password(
+                    pdf.as_ptr(),
+                    pdf.len(),
+                    password.as_ptr(),
+                    ptr::null(),
+                    ptr::null(),
+                    &mut stream
+                ),
+                ErrorCode::Success as c_int
+            );
+            // Pages after the open are read from the unlocked document.
+            assert_eq!(drain(stream), expected);
+
+            oxidize_chunk_pdf_stream_open(plain.as_ptr(), plain.len(), 64, 8, 0, &mut stream);
+            let expected = drain(stream);
+            assert_eq!(
+                oxidize_chunk_pdf_stream_open_with_password(
+                    pdf.as_ptr(),
+                    pdf.len(),
+                    password.as_ptr(),
+                    64,
+                    8,
+                    0,
+                    &mut stream
+                ),
+                ErrorCode::Success as c_int
+            );
+            assert_eq!(drain(stream), expected);
+        }
+    }
+
+    #[test]
+    fn path_streams_match_byte_streams() {
+        let pdf = SamplePdf::new(4).lines(8).build();
+        let file = crate::mapped_file::tests::TempPdf::new("chunk-stream", &pdf);
+        let path = file.c_path();
+        unsafe {
+            let mut from_bytes = ptr::null_mut();
+            let mut from_path = ptr::null_mut();
+            assert_eq!(
+                oxidize_rag_chunk_stream_open(
+                    pdf.as_ptr(),
+                    pdf.len(),
+                    ptr::null(),
+                    ptr::null(),
+                    &mut from_bytes
+                ),
+                ErrorCode::Success as c_int
+            );
+            assert_eq!(
+                oxidize_rag_chunk_stream_open_path(
+                    path.as_ptr(),
+                    ptr::null(),
+                    ptr::null(),
+                    &mut from_path
+                ),
+                ErrorCode::Success as c_int
+            );
+            assert_eq!(drain(from_path), drain(from_bytes));
+
+            assert_eq!(
+                oxidize_chunk_pdf_stream_open(pdf.as_ptr(), pdf.len(), 64, 8, 0, &mut from_bytes),
+                ErrorCode::Success as c_int
+            );
+            assert_eq!(
+                oxidize_chunk_pdf_stream_open_path(path.as_ptr(), 64, 8, 0, &mut from_path),
+                ErrorCode::Success as c_int
+            );
+            assert_eq!(drain(from_path), drain(from_bytes));
+
+            let missing = CString::new("/nonexistent/oxidize.pdf").unwrap();
+            assert_eq!(
+                oxidize_chunk_pdf_stream_open_path(missing.as_ptr(), 64, 8, 0, &mut from_path),
+                ErrorCode::IoError as c_int
+            );
+            assert!(from_path.is_null());
+        }
+    }
+
+    #[test]
+    fn semantic_stream_matches_one_shot_output() {
+        let pdf = SamplePdf::new(4).lines(8).build();
+        let sem = CString::new(
+            r#"{"max_tokens":48,"overlap_tokens":0,"respect_element_boundaries":true}"#,
+        )
+        .unwrap();
+        unsafe {
+            let expected = one_shot(|out| {
+                crate::parser::oxidize_semantic_chunks(
+                    pdf.as_ptr(),
+                    pdf.len(),
+                    ptr::null(),
+                    sem.as_ptr(),
+                    out,
+                )
+            });
+            assert!(expected.len() > 1);
+            let mut stream = ptr::null_mut();
+            assert_eq!(
+                oxidize_semantic_chunk_stream_open(
+                    pdf.as_ptr(),
+                    pdf.len(),
+                    ptr::null(),
+                    sem.as_ptr(),
+                    &mut stream
+                ),
+                ErrorCode::Success as c_int
+            );
+            assert_eq!(drain(stream), expected);
+        }
+    }
+
+    #[test]
+    fn chunk_pdf_stream_numbers_chunks_across_pages() {
+        let pdf = SamplePdf::new(4).lines(8).build();
+        unsafe {
+            let mut stream = ptr::null_mut();
+            assert_eq!(
+                oxidize_chunk_pdf_stream_open(pdf.as_ptr(), pdf.len(), 30, 5, 0, &mut stream),
+                ErrorCode::Success as c_int
+            );
+            let chunks = drain(stream);
+            assert!(chunks.len() >= 4);
+
+            let mut last_page = 0;
+            let mut last_end = 0;
+            for (i, chunk) in chunks.iter().enumerate() {
+                assert_eq!(chunk["chunk_index"], i);
+                assert_eq!(chunk["id"], format!("chunk_{i}"));
+                let pages = chunk["page_numbers"].as_array().unwrap();
+                assert_eq!(pages.len(), 1, "stream chunks never span pages");
+                let page = pages[0].as_u64().unwrap();
+                assert!(page >= last_page);
+                last_page = page;
+                let position = &chunk["metadata"]["position"];
+                assert_eq!(position["start_char"].as_u64().unwrap(), last_end);
+                last_end = position["end_char"].as_u64().unwrap();
+            }
+            assert_eq!(last_page, 4);
+        }
+    }
+
+    #[test]
+    fn exhausted_stream_keeps_returning_null() {
+        let pdf = SamplePdf::new(4).lines(8).build();
+        unsafe {
+            let mut stream = ptr::null_mut();
+            oxidize_chunk_pdf_stream_open(pdf.as_ptr(), pdf.len(), 1000, 0, 0, &mut stream);
+            let mut out: *mut c_char = ptr::null_mut();
+            while {
+                assert_eq!(
+                    oxidize_chunk_stream_next(stream, &mut out),
+                    ErrorCode::Success as c_int
+                );
+                !out.is_null()
+            } {
+                crate::oxidize_free_string(out);
+            }
+            assert_eq!(
+                oxidize_chunk_stream_next(stream, &mut out),
+                ErrorCode::Success as c_int
+            );
+            assert!(out.is_null());
+            oxidize_chunk_stream_free(stream);
+        }
+    }
+
+    #[test]
+    fn open_validates_arguments() {
+        let pdf = SamplePdf::new(4).lines(8).build();
+        let bad = CString::new("{not json").unwrap();
+        unsafe {
+            let mut stream = ptr::null_mut();
+            assert_eq!(
+                oxidize_rag_chunk_stream_open(
+                    ptr::null(),
+                    1,
+                    ptr::null(),
+                    ptr::null(),
+                    &mut stream
+                ),
+                ErrorCode::NullPointer as c_int
+            );
+            assert_eq!(
+                oxidize_rag_chunk_stream_open(
+                    pdf.as_ptr(),
+                    0,
+                    ptr::null(),
+                    ptr::null(),
+                    &mut stream
+                ),
+                ErrorCode::PdfParseError as c_int
+            );
+            assert_eq!(
+                oxidize_semantic_chunk_stream_open(
+                    pdf.as_ptr(),
+                    pdf.len(),
+                    ptr::null(),
+                    ptr::null(),
+                    &mut stream
+                ),
+                ErrorCode::NullPointer as c_int
+            );
+            assert_eq!(
+                oxidize_rag_chunk_stream_open(
+                    pdf.as_ptr(),
+                    pdf.len(),
+                    bad.as_ptr(),
+                    ptr::null(),
+                    &mut stream
+                ),
+                ErrorCode::InvalidArgument as c_int
+            );
+            let garbage = b"not a pdf";
+            assert_eq!(
+                oxidize_chunk_pdf_stream_open(
+                    garbage.as_ptr(),
+                    garbage.len(),
+                    10,
+                    0,
+                    0,
+                    &mut stream
+                ),
+                ErrorCode::PdfParseError as c_int
+            );
+            assert!(stream.is_null());
+
+            let mut out: *mut c_char = ptr::null_mut();
+            assert_eq!(
+                oxidize_chunk_stream_next(ptr::null_mut(), &mut out),
+                ErrorCode::NullPointer as c_int
+            );
+            oxidize_chunk_stream_free(ptr::null_mut());
+        }
+    }
+}
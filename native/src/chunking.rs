@@ -15,19 +15,27 @@
 //!   token-efficient TOON-style payload string.
 //! - [`oxidize_parse_chunks_token_efficient`] — token-efficient payload string
 //!   → `DocumentChunkDto[]` JSON (inverse of the exporter).
+//!
+//! [`oxidize_chunk_pdf`] takes no password. For an encrypted PDF, open it with
+//! `oxidize_parsed_document_open_with_password` and call
+//! `oxidize_parsed_document_chunk_pdf`.
 
 use oxidize_pdf::ai::{
     ChunkMetadata, ChunkPosition, DetectedLanguage, DocumentChunk, DocumentChunker,
     TokenEfficientExporter,
 };
 use oxidize_pdf::parser::PdfDocument;
+use oxidize_pdf::text::ExtractedText;
 use serde::{Deserialize, Serialize};
 use std::ffi::{CStr, CString};
+use std::io::{Read, Seek};
 use std::os::raw::{c_char, c_int};
 use std::ptr;
 use std::slice;
 
-use crate::parser::open_lenient;
+use crate::error_detail::LastError;
+use crate::parser::{open_lenient, with_password};
+use crate::progress::{extract_pages, Progress, ProgressCallbacks};
 use crate::{clear_last_error, set_last_error, ErrorCode};
 
 // ── DTOs ────────────────────────────────────────────────────────────────────
@@ -88,7 +96,7 @@ pub struct DocumentChunkDto {
 }
 
 impl DocumentChunkDto {
-    fn from_core(c: &DocumentChunk) -> Self {
+    pub(crate) fn from_core(c: &DocumentChunk) -> Self {
         Self {
             id: c.id.clone(),
             content: c.content.clone(),
@@ -146,7 +154,10 @@ unsafe fn emit_cstring(s: String, out: *mut *mut c_char) -> c_int {
             ErrorCode::Success as c_int
         }
         Err(e) => {
-            set_last_error(format!("output contains interior NUL byte: {e}"));
+            set_last_error(LastError::caused_by(
+                "output contains interior NUL byte",
+                &e,
+            ));
             ErrorCode::InvalidUtf8 as c_int
         }
     }
@@ -157,14 +168,14 @@ unsafe fn read_chunks(json_ptr: *const c_char) -> Result<Vec<DocumentChunk>, c_i
     let s = match CStr::from_ptr(json_ptr).to_str() {
         Ok(v) => v,
         Err(e) => {
-            set_last_error(format!("invalid UTF-8 in chunks JSON: {e}"));
+            set_last_error(LastError::caused_by("invalid UTF-8 in chunks JSON", &e));
             return Err(ErrorCode::InvalidUtf8 as c_int);
         }
     };
     match serde_json::from_str::<Vec<DocumentChunkDto>>(s) {
         Ok(dtos) => Ok(dtos.into_iter().map(DocumentChunkDto::into_core).collect()),
         Err(e) => {
-            set_last_error(format!("invalid DocumentChunk[] JSON: {e}"));
+            set_last_error(LastError::caused_by("invalid DocumentChunk[] JSON", &e));
             Err(ErrorCode::InvalidArgument as c_int)
         }
     }
@@ -229,40 +240,134 @@ pub unsafe extern "C" fn oxidize_chunk_pdf(
         };
 
         let document = PdfDocument::new(reader);
-        let text_pages = match document.extract_text() {
-            Ok(pages) => pages,
-            Err(e) => {
-                set_last_error(format!("Failed to extract text from PDF: {e}"));
-                return ErrorCode::PdfParseError as c_int;
-            }
-        };
+        chunk_pdf_impl(
+            &document,
+            chunk_size,
+            overlap,
+            detect_language,
+            Progress::NONE,
+            out_json,
+        )
+    })
+}
 
-        let page_texts: Vec<(usize, String)> = text_pages
-            .iter()
-            .enumerate()
-            .map(|(i, page)| (i + 1, page.text.clone()))
-            .collect();
+/// Body of [`oxidize_chunk_pdf`] over an already-opened document.
+pub(crate) unsafe fn chunk_pdf_impl<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    chunk_size: usize,
+    overlap: usize,
+    detect_language: u8,
+    progress: Progress,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    let text_pages = match extract_pages(document, Default::default(), progress) {
+        Ok(pages) => pages,
+        Err(code) => return code,
+    };
 
-        let chunker =
-            DocumentChunker::new(chunk_size, overlap).with_language_detection(detect_language != 0);
-        let chunks = match chunker.chunk_text_with_pages(&page_texts) {
-            Ok(c) => c,
-            Err(e) => {
-                set_last_error(format!("Failed to chunk PDF text: {e}"));
-                return ErrorCode::PdfParseError as c_int;
-            }
-        };
+    match chunk_page_texts(&text_pages, chunk_size, overlap, detect_language) {
+        Ok(dtos) => write_chunk_dtos(&dtos, out_json),
+        Err(code) => code,
+    }
+}
 
-        let dtos: Vec<DocumentChunkDto> = chunks.iter().map(DocumentChunkDto::from_core).collect();
-        let json = match serde_json::to_string(&dtos) {
-            Ok(j) => j,
+/// Chunk extracted page texts as [`oxidize_chunk_pdf`] does. Sets the last
+/// error on failure.
+pub(crate) fn chunk_page_texts(
+    text_pages: &[ExtractedText],
+    chunk_size: usize,
+    overlap: usize,
+    detect_language: u8,
+) -> Result<Vec<DocumentChunkDto>, c_int> {
+    let page_texts: Vec<(usize, String)> = text_pages
+        .iter()
+        .enumerate()
+        .map(|(i, page)| (i + 1, page.text.clone()))
+        .collect();
+
+    let chunker =
+        DocumentChunker::new(chunk_size, overlap).with_language_detection(detect_language != 0);
+    let chunks = match chunker.chunk_text_with_pages(&page_texts) {
+        Ok(c) => c,
+        Err(e) => {
+            set_last_error(LastError::caused_by("Failed to chunk PDF text", &e));
+            return Err(ErrorCode::PdfParseError as c_int);
+        }
+    };
+
+    Ok(chunks.iter().map(DocumentChunkDto::from_core).collect())
+}
+
+/// Serialize chunk records into `*out_json`.
+pub(crate) unsafe fn write_chunk_dtos(
+    dtos: &[DocumentChunkDto],
+    out_json: *mut *mut c_char,
+) -> c_int {
+    let json = match serde_json::to_string(dtos) {
+        Ok(j) => j,
+        Err(e) => {
+            set_last_error(LastError::caused_by("Failed to serialize chunks", &e));
+            return ErrorCode::SerializationError as c_int;
+        }
+    };
+
+    emit_cstring(json, out_json)
+}
+
+/// [`oxidize_chunk_pdf`] with per-page progress reporting and cancellation
+/// during text extraction.
+///
+/// `progress` may be null. Returns `Cancelled` when the caller's
+/// `is_cancelled` callback fires.
+///
+/// # Safety
+/// - `pdf_bytes` must point to `pdf_len` readable bytes.
+/// - `progress` must be null or point to a valid `ProgressCallbacks` for
+///   the duration of the call.
+/// - `out_json` must be a writeable `*mut *mut c_char`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_chunk_pdf_with_progress(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    chunk_size: usize,
+    overlap: usize,
+    detect_language: u8,
+    progress: *const ProgressCallbacks,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+
+        if pdf_bytes.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_chunk_pdf_with_progress");
+            return ErrorCode::NullPointer as c_int;
+        }
+
+        *out_json = ptr::null_mut();
+
+        if pdf_len == 0 {
+            set_last_error("PDF data is empty (0 bytes)");
+            return ErrorCode::PdfParseError as c_int;
+        }
+
+        let bytes = slice::from_raw_parts(pdf_bytes, pdf_len);
+        let reader = match open_lenient(bytes) {
+            Ok(r) => r,
             Err(e) => {
-                set_last_error(format!("Failed to serialize chunks: {e}"));
-                return ErrorCode::SerializationError as c_int;
+                set_last_error(e);
+                return ErrorCode::PdfParseError as c_int;
             }
         };
 
-        emit_cstring(json, out_json)
+        let document = PdfDocument::new(reader);
+        chunk_pdf_impl(
+            &document,
+            chunk_size,
+            overlap,
+            detect_language,
+            Progress::from_ptr(progress),
+            out_json,
+        )
     })
 }
 
@@ -307,7 +412,10 @@ pub unsafe extern "C" fn oxidize_document_language(
             Some(lang) => match serde_json::to_string(&DetectedLanguageDto::from_core(&lang)) {
                 Ok(j) => j,
                 Err(e) => {
-                    set_last_error(format!("Failed to serialize detected language: {e}"));
+                    set_last_error(LastError::caused_by(
+                        "Failed to serialize detected language",
+                        &e,
+                    ));
                     return ErrorCode::SerializationError as c_int;
                 }
             },
@@ -356,7 +464,7 @@ pub unsafe extern "C" fn oxidize_export_chunks_token_efficient(
         let payload = match TokenEfficientExporter::new().export_chunks(&chunks) {
             Ok(s) => s,
             Err(e) => {
-                set_last_error(format!("Failed to export chunks: {e}"));
+                set_last_error(LastError::caused_by("Failed to export chunks", &e));
                 return ErrorCode::SerializationError as c_int;
             }
         };
@@ -400,7 +508,10 @@ pub unsafe extern "C" fn oxidize_parse_chunks_token_efficient(
         let s = match CStr::from_ptr(input).to_str() {
             Ok(v) => v,
             Err(e) => {
-                set_last_error(format!("invalid UTF-8 in token-efficient payload: {e}"));
+                set_last_error(LastError::caused_by(
+                    "invalid UTF-8 in token-efficient payload",
+                    &e,
+                ));
                 return ErrorCode::InvalidUtf8 as c_int;
             }
         };
@@ -408,7 +519,10 @@ pub unsafe extern "C" fn oxidize_parse_chunks_token_efficient(
         let chunks = match TokenEfficientExporter::parse_chunks(s) {
             Ok(c) => c,
             Err(e) => {
-                set_last_error(format!("Failed to parse token-efficient payload: {e}"));
+                set_last_error(LastError::caused_by(
+                    "Failed to parse token-efficient payload",
+                    &e,
+                ));
                 return ErrorCode::InvalidArgument as c_int;
             }
         };
@@ -417,7 +531,10 @@ pub unsafe extern "C" fn oxidize_parse_chunks_token_efficient(
         let json = match serde_json::to_string(&dtos) {
             Ok(j) => j,
             Err(e) => {
-                set_last_error(format!("Failed to serialize parsed chunks: {e}"));
+                set_last_error(LastError::caused_by(
+                    "Failed to serialize parsed chunks",
+                    &e,
+                ));
                 return ErrorCode::SerializationError as c_int;
             }
         };
@@ -426,6 +543,67 @@ pub unsafe extern "C" fn oxidize_parse_chunks_token_efficient(
     })
 }
 
+// ── Password variants ────────────────────────────────────────────────────────
+//
+// As in `crate::parser`: the counterpart with a `password` argument that
+// unlocks an encrypted input (see `crate::parser::with_password`).
+
+/// [`oxidize_chunk_pdf`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_chunk_pdf`]; `password` must be a valid NUL-terminated UTF-8
+/// string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_chunk_pdf_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    chunk_size: usize,
+    overlap: usize,
+    detect_language: u8,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    with_password(password, || {
+        oxidize_chunk_pdf(
+            pdf_bytes,
+            pdf_len,
+            chunk_size,
+            overlap,
+            detect_language,
+            out_json,
+        )
+    })
+}
+
+/// [`oxidize_chunk_pdf_with_progress`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_chunk_pdf_with_progress`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_chunk_pdf_with_progress_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    chunk_size: usize,
+    overlap: usize,
+    detect_language: u8,
+    progress: *const ProgressCallbacks,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    with_password(password, || {
+        oxidize_chunk_pdf_with_progress(
+            pdf_bytes,
+            pdf_len,
+            chunk_size,
+            overlap,
+            detect_language,
+            progress,
+            out_json,
+        )
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -474,6 +652,35 @@ mod tests {
         assert!(chunks.iter().all(|c| c.metadata.language.is_none()));
     }
 
+    #[test]
+    fn chunk_pdf_with_password_reads_encrypted_pdf() {
+        let plain = crate::test_fixtures::SamplePdf::new(2).build();
+        let pdf = crate::test_fixtures::SamplePdf::new(2)
+            .encrypted("user-secret")
+            .build();
+        let password = CString::new("user-secret").unwrap();
+        let mut out: *mut c_char = ptr::null_mut();
+        unsafe {
+            let code = oxidize_chunk_pdf(plain.as_ptr(), plain.len(), 64, 8, 0, &mut out);
+            assert_eq!(code, ErrorCode::Success as c_int);
+            let expected = read_and_free(out);
+            let code = oxidize_chunk_pdf(pdf.as_ptr(), pdf.len(), 64, 8, 0, &mut out);
+            assert_eq!(code, ErrorCode::PasswordRequired as c_int);
+
+            let code = oxidize_chunk_pdf_with_password(
+                pdf.as_ptr(),
+                pdf.len(),
+                password.as_ptr(),
+                64,
+                8,
+                0,
+                &mut out,
+            );
+            assert_eq!(code, ErrorCode::Success as c_int);
+            assert_eq!(read_and_free(out), expected);
+        }
+    }
+
     #[test]
     fn chunk_pdf_with_detection_populates_language() {
         let pdf = english_pdf();
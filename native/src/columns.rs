@@ -17,7 +17,7 @@ use crate::page::PageHandle;
 use crate::{clear_last_error, set_last_error, ErrorCode};
 
 #[derive(Debug, Deserialize)]
-struct ColumnLayoutDto {
+pub(crate) struct ColumnLayoutDto {
     text: String,
     column_count: usize,
     total_width: f64,
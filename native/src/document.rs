@@ -2,9 +2,15 @@ use std::ffi::CStr;
 use std::os::raw::{c_char, c_int};
 use std::ptr;
 
+use oxidize_pdf::writer::WriterConfig;
+
+use crate::callback_writer::{save_to_callbacks, WriteCallbacks};
 use crate::{clear_last_error, set_last_error, ErrorCode};
 
 /// Opaque handle wrapping an `oxidize_pdf::Document`.
+///
+/// Not thread-safe: a handle may move between threads but must be used by
+/// one thread at a time.
 pub struct DocumentHandle {
     pub(crate) inner: oxidize_pdf::Document,
 }
@@ -465,6 +471,40 @@ pub unsafe extern "C" fn oxidize_document_save_to_bytes(
     })
 }
 
+/// Serialize the document through a caller-supplied write callback instead
+/// of one native buffer (see [`crate::callback_writer`]). Output is the same
+/// as `oxidize_document_save_to_bytes`.
+///
+/// # Returns
+/// `Success`; `NullPointer` if `handle` or `callbacks` is null;
+/// `InvalidArgument` if `callbacks.write` is null; `IoError` if serialization
+/// or a callback fails (output already written is incomplete).
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_document_create`.
+/// - `callbacks` must point to a valid `WriteCallbacks` whose functions are
+///   callable with its `user_data` for the duration of the call.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_document_save_to_callbacks(
+    handle: *mut DocumentHandle,
+    callbacks: *const WriteCallbacks,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || callbacks.is_null() {
+            set_last_error("Null pointer provided to oxidize_document_save_to_callbacks");
+            return ErrorCode::NullPointer as c_int;
+        }
+
+        let document = &mut (*handle).inner;
+        let config = WriterConfig {
+            compress_streams: document.get_compress(),
+            ..WriterConfig::default()
+        };
+        save_to_callbacks(document, config, &*callbacks)
+    })
+}
+
 /// Get the number of pages in the document.
 ///
 /// # Safety
@@ -20,13 +20,14 @@ use oxidize_pdf::writer::WriterConfig;
 use oxidize_pdf::{PageLabel, PageLabelStyle, PageLabelTree};
 use serde::Deserialize;
 
+use crate::callback_writer::{save_to_callbacks, WriteCallbacks};
 use crate::document::DocumentHandle;
 use crate::{clear_last_error, set_last_error, ErrorCode};
 
 // ── Shared destination payload (used by DOC-014 and DOC-017) ─────────────────
 
 #[derive(Deserialize)]
-struct DestinationJson {
+pub(crate) struct DestinationJson {
     page: u32,
     fit: u8,
     left: Option<f64>,
@@ -51,7 +52,7 @@ impl DestinationJson {
 // ── DOC-014: Open action ─────────────────────────────────────────────────────
 
 #[derive(Deserialize)]
-struct OpenActionJson {
+pub(crate) struct OpenActionJson {
     kind: String,
     destination: Option<DestinationJson>,
     uri: Option<String>,
@@ -120,7 +121,7 @@ pub unsafe extern "C" fn oxidize_document_set_open_action_json(
 // ── DOC-015: Viewer preferences ──────────────────────────────────────────────
 
 #[derive(Deserialize)]
-struct ViewerPrefsJson {
+pub(crate) struct ViewerPrefsJson {
     hide_toolbar: Option<bool>,
     hide_menubar: Option<bool>,
     hide_window_ui: Option<bool>,
@@ -245,7 +246,7 @@ pub unsafe extern "C" fn oxidize_document_set_viewer_preferences_json(
 // ── DOC-017: Named destinations ──────────────────────────────────────────────
 
 #[derive(Deserialize)]
-struct NamedDestJson {
+pub(crate) struct NamedDestJson {
     name: String,
     destination: DestinationJson,
 }
@@ -302,7 +303,7 @@ pub unsafe extern "C" fn oxidize_document_add_named_destination_json(
 // ── DOC-018: Page labels ─────────────────────────────────────────────────────
 
 #[derive(Deserialize)]
-struct PageLabelRangeJson {
+pub(crate) struct PageLabelRangeJson {
     start_page: u32,
     style: u8,
     prefix: Option<String>,
@@ -310,7 +311,7 @@ struct PageLabelRangeJson {
 }
 
 #[derive(Deserialize)]
-struct PageLabelsJson {
+pub(crate) struct PageLabelsJson {
     ranges: Vec<PageLabelRangeJson>,
 }
 
@@ -385,6 +386,29 @@ pub unsafe extern "C" fn oxidize_document_set_page_labels_json(
 
 // ── DOC-020: Save with WriterConfig ──────────────────────────────────────────
 
+/// The `WriterConfig` described by the save-with-config arguments.
+unsafe fn writer_config(
+    use_xref_streams: c_int,
+    use_object_streams: c_int,
+    pdf_version: *const c_char,
+    compress_streams: c_int,
+) -> Result<WriterConfig, c_int> {
+    let version = match CStr::from_ptr(pdf_version).to_str() {
+        Ok(v) => v.to_string(),
+        Err(_) => {
+            set_last_error("Invalid UTF-8 in pdf_version");
+            return Err(ErrorCode::InvalidUtf8 as c_int);
+        }
+    };
+    Ok(WriterConfig {
+        use_xref_streams: use_xref_streams != 0,
+        use_object_streams: use_object_streams != 0,
+        pdf_version: version,
+        compress_streams: compress_streams != 0,
+        incremental_update: false,
+    })
+}
+
 /// Serialize the document to PDF bytes using a custom writer configuration
 /// (PDF version, xref streams, object streams, stream compression).
 ///
@@ -415,20 +439,14 @@ pub unsafe extern "C" fn oxidize_document_save_to_bytes_with_config(
         *out_ptr = ptr::null_mut();
         *out_len = 0;
 
-        let version = match CStr::from_ptr(pdf_version).to_str() {
-            Ok(v) => v.to_string(),
-            Err(_) => {
-                set_last_error("Invalid UTF-8 in pdf_version");
-                return ErrorCode::InvalidUtf8 as c_int;
-            }
-        };
-
-        let config = WriterConfig {
-            use_xref_streams: use_xref_streams != 0,
-            use_object_streams: use_object_streams != 0,
-            pdf_version: version,
-            compress_streams: compress_streams != 0,
-            incremental_update: false,
+        let config = match writer_config(
+            use_xref_streams,
+            use_object_streams,
+            pdf_version,
+            compress_streams,
+        ) {
+            Ok(c) => c,
+            Err(code) => return code,
         };
 
         let bytes = match (*handle).inner.to_bytes_with_config(config) {
@@ -449,6 +467,44 @@ pub unsafe extern "C" fn oxidize_document_save_to_bytes_with_config(
     })
 }
 
+/// Serialize the document through a caller-supplied write callback using a
+/// custom writer configuration. Arguments are as for
+/// [`oxidize_document_save_to_bytes_with_config`]; output goes to `callbacks`
+/// as for `oxidize_document_save_to_callbacks`.
+///
+/// # Safety
+/// - `handle` must be a valid pointer from `oxidize_document_create`.
+/// - `pdf_version` must be a NUL-terminated UTF-8 string.
+/// - `callbacks` must point to a valid `WriteCallbacks` whose functions are
+///   callable with its `user_data` for the duration of the call.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_document_save_to_callbacks_with_config(
+    handle: *mut DocumentHandle,
+    use_xref_streams: c_int,
+    use_object_streams: c_int,
+    pdf_version: *const c_char,
+    compress_streams: c_int,
+    callbacks: *const WriteCallbacks,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || pdf_version.is_null() || callbacks.is_null() {
+            set_last_error("Null pointer to oxidize_document_save_to_callbacks_with_config");
+            return ErrorCode::NullPointer as c_int;
+        }
+
+        match writer_config(
+            use_xref_streams,
+            use_object_streams,
+            pdf_version,
+            compress_streams,
+        ) {
+            Ok(config) => save_to_callbacks(&mut (*handle).inner, config, &*callbacks),
+            Err(code) => code,
+        }
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -527,6 +583,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn save_to_callbacks_with_config_honours_the_config() {
+        use crate::callback_writer::tests::Sink;
+        unsafe {
+            let handle = one_page_handle();
+            let ver = CString::new("1.5").unwrap();
+            let mut sink = Sink::default();
+            let code = oxidize_document_save_to_callbacks_with_config(
+                handle,
+                1,
+                1,
+                ver.as_ptr(),
+                1,
+                &sink.callbacks(),
+            );
+            assert_eq!(code, ErrorCode::Success as c_int);
+            crate::document::oxidize_document_free(handle);
+
+            assert!(sink.data.starts_with(b"%PDF-1.5"));
+            assert!(String::from_utf8_lossy(&sink.data).contains("/Type /XRef"));
+            let reader = crate::parser::open_lenient(&sink.data[..]).unwrap();
+            let doc = oxidize_pdf::parser::PdfDocument::new(reader);
+            assert_eq!(doc.page_count().unwrap(), 1);
+        }
+    }
+
     /// Serialize a handle's document to bytes via the core writer (default config).
     unsafe fn to_bytes(handle: *mut DocumentHandle) -> Vec<u8> {
         (*handle).inner.to_bytes().unwrap()
@@ -0,0 +1,385 @@
+//! Binary encoding of structured results.
+//!
+//! The JSON entry points return results as a NUL-terminated string, and
+//! byte payloads (extracted images) as base64 inside it. The `*_encoded`
+//! variants return the same records as a byte buffer in the
+//! [`ResultEncoding`] chosen per call:
+//!
+//! - `0` (JSON): the same UTF-8 JSON as the string entry point, without the
+//!   trailing NUL;
+//! - `1` (CBOR, RFC 8949): the same field names and values; byte payloads
+//!   are CBOR byte strings instead of base64 text.
+//!
+//! Buffers are freed with `oxidize_free_bytes`.
+
+use std::io::Cursor;
+use std::os::raw::c_int;
+use std::ptr;
+use std::slice;
+
+use base64::Engine as _;
+use oxidize_pdf::parser::PdfDocument;
+use serde::{Serialize, Serializer};
+
+use crate::error_detail::LastError;
+use crate::operations::{extract_images, set_out_bytes};
+use crate::parser::{
+    annotation_results, form_field_results, open_lenient, partition_results, rag_chunk_results,
+};
+use crate::{clear_last_error, set_last_error, ErrorCode};
+
+/// Wire encoding of an `*_encoded` result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ResultEncoding {
+    Json,
+    Cbor,
+}
+
+impl ResultEncoding {
+    /// Map the FFI discriminant. Sets the last error for unknown values.
+    pub(crate) fn from_u8(value: u8) -> Result<Self, c_int> {
+        match value {
+            0 => Ok(ResultEncoding::Json),
+            1 => Ok(ResultEncoding::Cbor),
+            other => {
+                set_last_error(format!(
+                    "Invalid encoding {other}: expected 0 (JSON) or 1 (CBOR)"
+                ));
+                Err(ErrorCode::InvalidArgument as c_int)
+            }
+        }
+    }
+}
+
+/// Serialize `value` in `encoding`. Sets the last error on failure.
+pub(crate) fn encode<T: Serialize + ?Sized>(
+    value: &T,
+    encoding: ResultEncoding,
+) -> Result<Vec<u8>, c_int> {
+    let encoded = match encoding {
+        ResultEncoding::Json => serde_json::to_vec(value)
+            .map_err(|e| LastError::caused_by("Failed to serialize result as JSON", &e)),
+        ResultEncoding::Cbor => {
+            let mut out = Vec::new();
+            ciborium::into_writer(value, &mut out)
+                .map(|()| out)
+                .map_err(|e| LastError::caused_by("Failed to serialize result as CBOR", &e))
+        }
+    };
+    encoded.map_err(|e| {
+        set_last_error(e);
+        ErrorCode::SerializationError as c_int
+    })
+}
+
+/// `serialize_with` for byte payloads: base64 text in human-readable formats
+/// (JSON), a native byte string otherwise (CBOR).
+pub(crate) fn raw_bytes<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&base64::engine::general_purpose::STANDARD.encode(bytes))
+    } else {
+        serializer.serialize_bytes(bytes)
+    }
+}
+
+/// Shared body of the `*_encoded` entry points: validate the arguments, open
+/// the PDF, collect the records with `results` and encode them.
+unsafe fn encoded_call<'a, T: Serialize>(
+    fn_name: &str,
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    encoding: u8,
+    out_bytes: *mut *mut u8,
+    out_len: *mut usize,
+    results: impl FnOnce(&PdfDocument<Cursor<&'a [u8]>>) -> Result<T, c_int>,
+) -> c_int {
+    clear_last_error();
+
+    if pdf_bytes.is_null() || out_bytes.is_null() || out_len.is_null() {
+        set_last_error(format!("Null pointer provided to {fn_name}"));
+        return ErrorCode::NullPointer as c_int;
+    }
+
+    *out_bytes = ptr::null_mut();
+    *out_len = 0;
+
+    let encoding = match ResultEncoding::from_u8(encoding) {
+        Ok(e) => e,
+        Err(code) => return code,
+    };
+
+    if pdf_len == 0 {
+        set_last_error("PDF data is empty (0 bytes)");
+        return ErrorCode::PdfParseError as c_int;
+    }
+
+    let bytes: &'a [u8] = slice::from_raw_parts(pdf_bytes, pdf_len);
+    let reader = match open_lenient(bytes) {
+        Ok(r) => r,
+        Err(e) => {
+            set_last_error(e);
+            return ErrorCode::PdfParseError as c_int;
+        }
+    };
+
+    let document = PdfDocument::new(reader);
+    match results(&document).and_then(|r| encode(&r, encoding)) {
+        Ok(encoded) => {
+            set_out_bytes(encoded, out_bytes, out_len);
+            ErrorCode::Success as c_int
+        }
+        Err(code) => code,
+    }
+}
+
+/// [`crate::parser::oxidize_partition`] with the element array returned in
+/// `encoding` (0 = JSON, 1 = CBOR).
+///
+/// # Safety
+/// - `pdf_bytes` must be a valid pointer to `pdf_len` bytes.
+/// - `out_bytes` and `out_len` must be valid pointers. The buffer must be
+///   freed with `oxidize_free_bytes`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_partition_encoded(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    encoding: u8,
+    out_bytes: *mut *mut u8,
+    out_len: *mut usize,
+) -> c_int {
+    crate::ffi_guard(move || {
+        encoded_call(
+            "oxidize_partition_encoded",
+            pdf_bytes,
+            pdf_len,
+            encoding,
+            out_bytes,
+            out_len,
+            partition_results,
+        )
+    })
+}
+
+/// [`crate::parser::oxidize_rag_chunks`] with the chunk array returned in
+/// `encoding` (0 = JSON, 1 = CBOR).
+///
+/// # Safety
+/// - `pdf_bytes` must be a valid pointer to `pdf_len` bytes.
+/// - `out_bytes` and `out_len` must be valid pointers. The buffer must be
+///   freed with `oxidize_free_bytes`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_rag_chunks_encoded(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    encoding: u8,
+    out_bytes: *mut *mut u8,
+    out_len: *mut usize,
+) -> c_int {
+    crate::ffi_guard(move || {
+        encoded_call(
+            "oxidize_rag_chunks_encoded",
+            pdf_bytes,
+            pdf_len,
+            encoding,
+            out_bytes,
+            out_len,
+            rag_chunk_results,
+        )
+    })
+}
+
+/// [`crate::parser::oxidize_get_annotations`] with the annotation array
+/// returned in `encoding` (0 = JSON, 1 = CBOR).
+///
+/// # Safety
+/// - `pdf_bytes` must be a valid pointer to `pdf_len` bytes.
+/// - `out_bytes` and `out_len` must be valid pointers. The buffer must be
+///   freed with `oxidize_free_bytes`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_get_annotations_encoded(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    encoding: u8,
+    out_bytes: *mut *mut u8,
+    out_len: *mut usize,
+) -> c_int {
+    crate::ffi_guard(move || {
+        encoded_call(
+            "oxidize_get_annotations_encoded",
+            pdf_bytes,
+            pdf_len,
+            encoding,
+            out_bytes,
+            out_len,
+            annotation_results,
+        )
+    })
+}
+
+/// [`crate::parser::oxidize_get_form_fields`] with the field array returned
+/// in `encoding` (0 = JSON, 1 = CBOR).
+///
+/// # Safety
+/// - `pdf_bytes` must be a valid pointer to `pdf_len` bytes.
+/// - `out_bytes` and `out_len` must be valid pointers. The buffer must be
+///   freed with `oxidize_free_bytes`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_get_form_fields_encoded(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    encoding: u8,
+    out_bytes: *mut *mut u8,
+    out_len: *mut usize,
+) -> c_int {
+    crate::ffi_guard(move || {
+        encoded_call(
+            "oxidize_get_form_fields_encoded",
+            pdf_bytes,
+            pdf_len,
+            encoding,
+            out_bytes,
+            out_len,
+            form_field_results,
+        )
+    })
+}
+
+/// [`crate::operations::oxidize_extract_images_bytes`] with the image array
+/// returned in `encoding` (0 = JSON, 1 = CBOR). In CBOR each `data` field is
+/// the image file as a byte string rather than base64.
+///
+/// # Safety
+/// - `pdf_bytes` must be a valid pointer to `pdf_len` bytes.
+/// - `out_bytes` and `out_len` must be valid pointers. The buffer must be
+///   freed with `oxidize_free_bytes`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_extract_images_encoded(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    encoding: u8,
+    out_bytes: *mut *mut u8,
+    out_len: *mut usize,
+) -> c_int {
+    crate::ffi_guard(move || {
+        encoded_call(
+            "oxidize_extract_images_encoded",
+            pdf_bytes,
+            pdf_len,
+            encoding,
+            out_bytes,
+            out_len,
+            |document| {
+                extract_images(document).map_err(|e| {
+                    set_last_error(e);
+                    ErrorCode::IoError as c_int
+                })
+            },
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::SamplePdf;
+    use ciborium::Value;
+    use oxidize_pdf::Image;
+    use std::ffi::CStr;
+    use std::os::raw::c_char;
+
+    /// One text page that also draws a small grayscale image.
+    fn imaged_pdf() -> Vec<u8> {
+        SamplePdf::new(1)
+            .heading("Encoded Results")
+            .decorate(|page, _| {
+                let gray: Vec<u8> = (0..16 * 12).map(|i| (i % 256) as u8).collect();
+                page.add_image("Im1", Image::from_gray_data(gray, 16, 12).unwrap());
+                page.draw_image("Im1", 50.0, 500.0, 160.0, 120.0).unwrap();
+            })
+            .build()
+    }
+
+    type EncodedFn = unsafe extern "C" fn(*const u8, usize, u8, *mut *mut u8, *mut usize) -> c_int;
+
+    unsafe fn call(f: EncodedFn, pdf: &[u8], encoding: u8) -> Vec<u8> {
+        let mut out: *mut u8 = ptr::null_mut();
+        let mut len = 0usize;
+        let code = f(pdf.as_ptr(), pdf.len(), encoding, &mut out, &mut len);
+        assert_eq!(code, ErrorCode::Success as c_int);
+        let bytes = slice::from_raw_parts(out, len).to_vec();
+        crate::oxidize_free_bytes(out, len);
+        bytes
+    }
+
+    /// CBOR value → JSON value, with byte strings as base64 (the JSON form).
+    fn to_json(value: Value) -> serde_json::Value {
+        match value {
+            Value::Bytes(b) => base64::engine::general_purpose::STANDARD.encode(b).into(),
+            Value::Array(items) => items.into_iter().map(to_json).collect(),
+            Value::Map(entries) => entries
+                .into_iter()
+                .map(|(k, v)| (k.into_text().unwrap(), to_json(v)))
+                .collect::<serde_json::Map<_, _>>()
+                .into(),
+            other => serde_json::to_value(other).unwrap(),
+        }
+    }
+
+    #[test]
+    fn cbor_matches_json_for_every_result_kind() {
+        let pdf = imaged_pdf();
+        let entry_points: [EncodedFn; 5] = [
+            oxidize_partition_encoded,
+            oxidize_rag_chunks_encoded,
+            oxidize_get_annotations_encoded,
+            oxidize_get_form_fields_encoded,
+            oxidize_extract_images_encoded,
+        ];
+        for f in entry_points {
+            unsafe {
+                let json: serde_json::Value = serde_json::from_slice(&call(f, &pdf, 0)).unwrap();
+                let cbor: Value = ciborium::from_reader(&call(f, &pdf, 1)[..]).unwrap();
+                assert_eq!(to_json(cbor), json);
+            }
+        }
+
+        // The encoded JSON is the string entry point's output.
+        unsafe {
+            let mut out: *mut c_char = ptr::null_mut();
+            let code = crate::parser::oxidize_partition(pdf.as_ptr(), pdf.len(), &mut out);
+            assert_eq!(code, ErrorCode::Success as c_int);
+            let string = CStr::from_ptr(out).to_bytes().to_vec();
+            crate::oxidize_free_string(out);
+            assert_eq!(call(oxidize_partition_encoded, &pdf, 0), string);
+        }
+    }
+
+    #[test]
+    fn cbor_images_carry_raw_bytes() {
+        let pdf = imaged_pdf();
+        let cbor: Value =
+            ciborium::from_reader(&unsafe { call(oxidize_extract_images_encoded, &pdf, 1) }[..])
+                .unwrap();
+        let images = cbor.into_array().unwrap();
+        assert_eq!(images.len(), 1);
+        let data = images[0]
+            .as_map()
+            .unwrap()
+            .iter()
+            .find(|(k, _)| k.as_text() == Some("data"))
+            .and_then(|(_, v)| v.as_bytes())
+            .unwrap();
+        assert!(data.starts_with(b"\x89PNG"));
+    }
+
+    #[test]
+    fn unknown_encoding_is_rejected() {
+        let pdf = imaged_pdf();
+        let mut out: *mut u8 = ptr::null_mut();
+        let mut len = 0usize;
+        let code =
+            unsafe { oxidize_partition_encoded(pdf.as_ptr(), pdf.len(), 2, &mut out, &mut len) };
+        assert_eq!(code, ErrorCode::InvalidArgument as c_int);
+        assert!(out.is_null());
+    }
+}
@@ -0,0 +1,416 @@
+//! Structured error details behind `oxidize_get_last_error_json`.
+//!
+//! Every failing call records a [`LastError`] in the thread-local slot read by
+//! `oxidize_get_last_error`. Besides the message it can carry a category, the
+//! page / object / byte offset the failure relates to and the chain of
+//! underlying causes. Plain messages (`set_last_error("…")`) convert into a
+//! `LastError` whose category is derived from the returned [`ErrorCode`];
+//! failures built with [`LastError::caused_by`] from an upstream
+//! `ParseError` are classified precisely (corrupt xref, password required,
+//! invalid reference, …).
+
+use std::error::Error;
+use std::fmt;
+use std::os::raw::c_int;
+
+use oxidize_pdf::parser::ParseError;
+use serde::Serialize;
+
+use crate::ErrorCode;
+
+/// Failure category reported in the `category` field of the error JSON.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum ErrorCategory {
+    NullPointer,
+    InvalidUtf8,
+    Parse,
+    Allocation,
+    Serialization,
+    Io,
+    Encryption,
+    Permission,
+    InvalidArgument,
+    Panic,
+    Cancelled,
+    PasswordRequired,
+    WrongPassword,
+    PageOutOfRange,
+    Unsupported,
+    LimitExceeded,
+    EmptyFile,
+    InvalidHeader,
+    CorruptXref,
+    InvalidTrailer,
+    Syntax,
+    Encoding,
+    InvalidReference,
+    CircularReference,
+    MissingKey,
+    StreamDecode,
+}
+
+impl ErrorCategory {
+    /// The category implied by a return code when nothing finer is known.
+    fn from_code(code: c_int) -> Self {
+        match code {
+            c if c == ErrorCode::NullPointer as c_int => Self::NullPointer,
+            c if c == ErrorCode::InvalidUtf8 as c_int => Self::InvalidUtf8,
+            c if c == ErrorCode::AllocationError as c_int => Self::Allocation,
+            c if c == ErrorCode::SerializationError as c_int => Self::Serialization,
+            c if c == ErrorCode::IoError as c_int => Self::Io,
+            c if c == ErrorCode::EncryptionError as c_int => Self::Encryption,
+            c if c == ErrorCode::PermissionError as c_int => Self::Permission,
+            c if c == ErrorCode::InvalidArgument as c_int => Self::InvalidArgument,
+            c if c == ErrorCode::Panic as c_int => Self::Panic,
+            c if c == ErrorCode::Cancelled as c_int => Self::Cancelled,
+            c if c == ErrorCode::PasswordRequired as c_int => Self::PasswordRequired,
+            c if c == ErrorCode::PageOutOfRange as c_int => Self::PageOutOfRange,
+            c if c == ErrorCode::Unsupported as c_int => Self::Unsupported,
+            c if c == ErrorCode::LimitExceeded as c_int => Self::LimitExceeded,
+            _ => Self::Parse,
+        }
+    }
+
+    /// The dedicated error code for categories that have one.
+    fn specific_code(self) -> Option<ErrorCode> {
+        match self {
+            Self::Cancelled => Some(ErrorCode::Cancelled),
+            Self::PasswordRequired | Self::WrongPassword => Some(ErrorCode::PasswordRequired),
+            Self::PageOutOfRange => Some(ErrorCode::PageOutOfRange),
+            Self::Unsupported => Some(ErrorCode::Unsupported),
+            Self::LimitExceeded => Some(ErrorCode::LimitExceeded),
+            _ => None,
+        }
+    }
+}
+
+/// The error recorded for the current thread.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct LastError {
+    pub(crate) message: String,
+    code: Option<c_int>,
+    category: Option<ErrorCategory>,
+    page_index: Option<u32>,
+    object_number: Option<u32>,
+    generation: Option<u16>,
+    byte_offset: Option<u64>,
+    causes: Vec<String>,
+}
+
+impl LastError {
+    /// `"{context}: {err}"`, classified from `err` and carrying its cause chain.
+    pub(crate) fn caused_by(context: impl fmt::Display, err: &(dyn Error + 'static)) -> Self {
+        let mut error = LastError {
+            message: format!("{context}: {err}"),
+            ..Default::default()
+        };
+        let mut source = Some(err);
+        while let Some(cause) = source {
+            if let Some(parse) = cause.downcast_ref::<ParseError>() {
+                error.classify(parse);
+            }
+            error.causes.push(cause.to_string());
+            source = cause.source();
+        }
+        error
+    }
+
+    /// Attach the 0-based page index the failure relates to.
+    pub(crate) fn at_page(mut self, page_index: u32) -> Self {
+        self.page_index = Some(page_index);
+        self
+    }
+
+    /// Attach the indirect object the failure relates to.
+    pub(crate) fn at_object(mut self, object_number: u32, generation: u16) -> Self {
+        self.object_number = Some(object_number);
+        self.generation = Some(generation);
+        self
+    }
+
+    /// A plain message with an explicit category.
+    pub(crate) fn new(category: ErrorCategory, message: impl Into<String>) -> Self {
+        LastError {
+            message: message.into(),
+            category: Some(category),
+            ..Default::default()
+        }
+    }
+
+    /// Prefix the message with `context`, keeping the classification.
+    pub(crate) fn context(mut self, context: impl fmt::Display) -> Self {
+        self.message = format!("{context}: {}", self.message);
+        self
+    }
+
+    /// Fill in category and location from the first `ParseError` in the chain.
+    fn classify(&mut self, err: &ParseError) {
+        if self.category.is_some() {
+            return;
+        }
+        let category = match err {
+            ParseError::Io(_) => ErrorCategory::Io,
+            ParseError::InvalidHeader => ErrorCategory::InvalidHeader,
+            ParseError::UnsupportedVersion(_) => ErrorCategory::Unsupported,
+            ParseError::SyntaxError { position, message } => {
+                if message.starts_with("Page index") && message.contains("out of range") {
+                    ErrorCategory::PageOutOfRange
+                } else {
+                    self.byte_offset = Some(*position as u64);
+                    ErrorCategory::Syntax
+                }
+            }
+            ParseError::UnexpectedToken { .. } | ParseError::UnexpectedCharacter { .. } => {
+                ErrorCategory::Syntax
+            }
+            ParseError::CharacterEncodingError { position, .. } => {
+                self.byte_offset = Some(*position as u64);
+                ErrorCategory::Encoding
+            }
+            ParseError::InvalidReference(number, generation) => {
+                self.object_number = Some(*number);
+                self.generation = Some(*generation);
+                ErrorCategory::InvalidReference
+            }
+            ParseError::MissingKey(_) => ErrorCategory::MissingKey,
+            ParseError::InvalidXRef => ErrorCategory::CorruptXref,
+            ParseError::InvalidTrailer => ErrorCategory::InvalidTrailer,
+            ParseError::CircularReference => ErrorCategory::CircularReference,
+            ParseError::StreamDecodeError(_) | ParseError::StreamLengthMismatch { .. } => {
+                ErrorCategory::StreamDecode
+            }
+            ParseError::EncryptionNotSupported | ParseError::PdfLocked => {
+                ErrorCategory::PasswordRequired
+            }
+            ParseError::WrongPassword => ErrorCategory::WrongPassword,
+            ParseError::EmptyFile => ErrorCategory::EmptyFile,
+            ParseError::SerializationError(_) => ErrorCategory::Serialization,
+        };
+        self.category = Some(category);
+    }
+
+    /// Record the code the FFI call returned and return the code the caller
+    /// should see: a generic failure code (`PdfParseError`, `IoError`,
+    /// `EncryptionError`) is narrowed to the dedicated code of the recorded
+    /// category (e.g. `PasswordRequired`, `PageOutOfRange`).
+    pub(crate) fn settle(&mut self, code: c_int) -> c_int {
+        let generic = [
+            ErrorCode::PdfParseError as c_int,
+            ErrorCode::IoError as c_int,
+            ErrorCode::EncryptionError as c_int,
+        ];
+        let code = match self.category.and_then(ErrorCategory::specific_code) {
+            Some(specific) if generic.contains(&code) => specific as c_int,
+            _ => code,
+        };
+        self.code.get_or_insert(code);
+        code
+    }
+
+    /// JSON document returned by `oxidize_get_last_error_json`.
+    pub(crate) fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(&self.wire())
+    }
+
+    /// [`LastError::to_json`] as a value, for embedding in a larger document.
+    pub(crate) fn to_value(&self) -> serde_json::Result<serde_json::Value> {
+        serde_json::to_value(self.wire())
+    }
+
+    fn wire(&self) -> LastErrorJson<'_> {
+        let code = self.code.unwrap_or(ErrorCode::PdfParseError as c_int);
+        LastErrorJson {
+            code,
+            category: self
+                .category
+                .unwrap_or_else(|| ErrorCategory::from_code(code)),
+            message: &self.message,
+            page_index: self.page_index,
+            object_number: self.object_number,
+            generation: self.generation,
+            byte_offset: self.byte_offset,
+            causes: &self.causes,
+        }
+    }
+}
+
+impl fmt::Display for LastError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl From<String> for LastError {
+    fn from(message: String) -> Self {
+        LastError {
+            message,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&str> for LastError {
+    fn from(message: &str) -> Self {
+        message.to_string().into()
+    }
+}
+
+/// Wire shape of `oxidize_get_last_error_json`.
+#[derive(Serialize)]
+struct LastErrorJson<'a> {
+    code: c_int,
+    category: ErrorCategory,
+    message: &'a str,
+    page_index: Option<u32>,
+    object_number: Option<u32>,
+    generation: Option<u16>,
+    byte_offset: Option<u64>,
+    causes: &'a [String],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{sample_pdf, SamplePdf};
+    use crate::{oxidize_free_string, oxidize_get_last_error_json};
+    use std::ffi::CStr;
+    use std::os::raw::c_char;
+    use std::ptr;
+
+    fn last_error_json() -> serde_json::Value {
+        let mut out: *mut c_char = ptr::null_mut();
+        unsafe {
+            assert_eq!(
+                oxidize_get_last_error_json(&mut out),
+                ErrorCode::Success as c_int
+            );
+            assert!(!out.is_null(), "an error must be recorded");
+            let value = serde_json::from_str(CStr::from_ptr(out).to_str().unwrap()).unwrap();
+            oxidize_free_string(out);
+            value
+        }
+    }
+
+    #[test]
+    fn json_is_null_when_no_error_is_recorded() {
+        crate::clear_last_error();
+        let mut out: *mut c_char = 1 as *mut c_char;
+        unsafe {
+            assert_eq!(
+                oxidize_get_last_error_json(&mut out),
+                ErrorCode::Success as c_int
+            );
+            assert_eq!(
+                oxidize_get_last_error_json(ptr::null_mut()),
+                ErrorCode::NullPointer as c_int
+            );
+        }
+        assert!(out.is_null());
+    }
+
+    #[test]
+    fn null_pointer_errors_carry_code_and_category() {
+        let code = unsafe { crate::parser::oxidize_extract_text(ptr::null(), 0, ptr::null_mut()) };
+        assert_eq!(code, ErrorCode::NullPointer as c_int);
+        let json = last_error_json();
+        assert_eq!(json["code"], ErrorCode::NullPointer as c_int);
+        assert_eq!(json["category"], "null_pointer");
+        assert!(json["message"]
+            .as_str()
+            .unwrap()
+            .contains("oxidize_extract_text"));
+        assert!(json["page_index"].is_null());
+        assert_eq!(json["causes"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn page_out_of_range_gets_dedicated_code() {
+        let pdf = sample_pdf(1);
+        let (mut w, mut h) = (0.0, 0.0);
+        let code = unsafe {
+            crate::parser::oxidize_get_page_dimensions(pdf.as_ptr(), pdf.len(), 9, &mut w, &mut h)
+        };
+        assert_eq!(code, ErrorCode::PageOutOfRange as c_int);
+        let json = last_error_json();
+        assert_eq!(json["code"], ErrorCode::PageOutOfRange as c_int);
+        assert_eq!(json["category"], "page_out_of_range");
+        assert_eq!(json["page_index"], 8);
+        assert!(!json["causes"].as_array().unwrap().is_empty());
+    }
+
+    #[test]
+    fn unparseable_input_reports_cause_chain() {
+        let garbage = b"definitely not a pdf";
+        let mut out: *mut c_char = ptr::null_mut();
+        let code = unsafe {
+            crate::parser::oxidize_extract_text(garbage.as_ptr(), garbage.len(), &mut out)
+        };
+        assert_ne!(code, ErrorCode::Success as c_int);
+        let json = last_error_json();
+        assert_eq!(json["code"], code);
+        let message = json["message"].as_str().unwrap();
+        assert!(message.starts_with("Failed to parse PDF: "), "{message}");
+        assert_eq!(
+            json["causes"][0].as_str().unwrap(),
+            message.trim_start_matches("Failed to parse PDF: ")
+        );
+    }
+
+    #[test]
+    fn encrypted_pdf_without_password_reports_password_required() {
+        let pdf = SamplePdf::new(1).encrypted("user-secret").build();
+        let mut out: *mut c_char = ptr::null_mut();
+        let code =
+            unsafe { crate::parser::oxidize_extract_text(pdf.as_ptr(), pdf.len(), &mut out) };
+        assert_eq!(code, ErrorCode::PasswordRequired as c_int);
+        assert!(out.is_null());
+        let json = last_error_json();
+        assert_eq!(json["code"], ErrorCode::PasswordRequired as c_int);
+        assert_eq!(json["category"], "password_required");
+    }
+
+    #[test]
+    fn parse_errors_are_classified_with_location() {
+        let err = LastError::caused_by("ctx", &ParseError::InvalidReference(12, 3));
+        assert_eq!(err.category, Some(ErrorCategory::InvalidReference));
+        assert_eq!((err.object_number, err.generation), (Some(12), Some(3)));
+
+        let err = LastError::caused_by(
+            "ctx",
+            &ParseError::SyntaxError {
+                position: 4096,
+                message: "bad token".into(),
+            },
+        );
+        assert_eq!(err.category, Some(ErrorCategory::Syntax));
+        assert_eq!(err.byte_offset, Some(4096));
+
+        let err = LastError::caused_by("ctx", &ParseError::InvalidXRef);
+        assert_eq!(err.category, Some(ErrorCategory::CorruptXref));
+        assert_eq!(err.message, "ctx: Invalid xref table");
+    }
+
+    #[test]
+    fn settle_narrows_only_generic_codes() {
+        let mut err = LastError::caused_by("ctx", &ParseError::PdfLocked);
+        assert_eq!(
+            err.settle(ErrorCode::PdfParseError as c_int),
+            ErrorCode::PasswordRequired as c_int
+        );
+
+        let mut err = LastError::caused_by("ctx", &ParseError::PdfLocked);
+        assert_eq!(
+            err.settle(ErrorCode::InvalidArgument as c_int),
+            ErrorCode::InvalidArgument as c_int
+        );
+
+        let mut err = LastError::from("plain message");
+        assert_eq!(
+            err.settle(ErrorCode::PdfParseError as c_int),
+            ErrorCode::PdfParseError as c_int
+        );
+        let json: serde_json::Value = serde_json::from_str(&err.to_json().unwrap()).unwrap();
+        assert_eq!(json["category"], "parse");
+    }
+}
@@ -45,7 +45,7 @@ use crate::{clear_last_error, set_last_error, ErrorCode};
 type RectArray = [f64; 4];
 
 #[derive(Deserialize)]
-struct OptionJson {
+pub(crate) struct OptionJson {
     /// Export value (the value stored in `/V` / the option's machine value).
     export: String,
     /// Human-visible label.
@@ -57,7 +57,7 @@ struct OptionJson {
 /// serde). The widget `rect` travels with the field so the
 /// `FormManager::add_*(field, widget, options)` call is made atomically.
 #[derive(Deserialize)]
-struct CreateFieldJson {
+pub(crate) struct CreateFieldJson {
     kind: String,
     name: String,
     rect: RectArray,
@@ -108,7 +108,7 @@ struct CreateFieldJson {
 }
 
 #[derive(Deserialize)]
-struct WidgetJson {
+pub(crate) struct WidgetJson {
     rect: RectArray,
 }
 
@@ -413,7 +413,7 @@ pub unsafe extern "C" fn oxidize_document_fill_field(
 
 /// One `{ "name": .., "value": .. }` entry for `oxidize_fill_existing_form_json`.
 #[derive(Deserialize)]
-struct FieldFillJson {
+pub(crate) struct FieldFillJson {
     /// Fully-qualified AcroForm field name (e.g. `"address.street"`).
     name: String,
     /// New text value to set on the field's `/V`.
@@ -2138,7 +2138,7 @@ pub unsafe extern "C" fn oxidize_page_clip_ellipse(
 /// One gradient color stop: a position in `[0.0, 1.0]` and an RGB color
 /// (components in `[0.0, 1.0]`).
 #[derive(serde::Deserialize)]
-struct ShadingStopJson {
+pub(crate) struct ShadingStopJson {
     position: f64,
     /// RGB color components `[r, g, b]`, each in `[0.0, 1.0]`.
     color: [f64; 3],
@@ -2148,7 +2148,7 @@ struct ShadingStopJson {
 /// `axial` uses `start`/`end`; `radial` uses `start_center`/`start_radius`/
 /// `end_center`/`end_radius`. Both use `stops` (≥2) and the `extend_*` flags.
 #[derive(serde::Deserialize)]
-struct ShadingJson {
+pub(crate) struct ShadingJson {
     kind: String,
     // axial
     start: Option<[f64; 2]>,
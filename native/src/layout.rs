@@ -752,7 +752,7 @@ pub unsafe extern "C" fn oxidize_document_builder_build(
 
 /// JSON representation of a simple table for deserialization.
 #[derive(serde::Deserialize)]
-struct SimpleTableJson {
+pub(crate) struct SimpleTableJson {
     column_widths: Vec<f64>,
     #[serde(default)]
     headers: Vec<String>,
@@ -798,7 +798,7 @@ unsafe fn parse_simple_table(table_json: *const c_char) -> Result<oxidize_pdf::t
 
 /// JSON representation of a text span for deserialization.
 #[derive(serde::Deserialize)]
-struct SpanJson {
+pub(crate) struct SpanJson {
     text: String,
     font: i32,
     font_size: f64,
@@ -3,43 +3,69 @@ use std::ffi::CString;
 use std::os::raw::{c_char, c_int};
 use std::ptr;
 
+use error_detail::LastError;
+
 // ── Sub-modules ───────────────────────────────────────────────────────────────
 
 pub mod annotations;
+pub mod batch;
+mod bounded_decode;
+pub mod callback_reader;
+pub mod callback_writer;
+pub mod capabilities;
+pub mod chunk_stream;
 pub mod chunking;
 pub mod cid_font;
 pub mod columns;
 pub mod document;
 pub mod document_metadata;
+pub mod encoding;
+pub mod error_detail;
 pub mod forms;
 pub mod graphics;
 pub mod header_footer;
 pub mod image;
 pub mod layout;
+pub mod limits;
 pub mod lists;
+pub mod logging;
+pub mod mapped_file;
 pub mod measure;
+pub mod metrics;
+pub mod navigation;
 pub mod operations;
 pub mod page;
+pub mod parallel;
+pub mod parsed_document;
 pub mod parser;
 pub mod pipeline_config;
+pub mod profiles;
+pub mod progress;
+pub mod recovery_report;
 pub mod security;
 pub mod semantic;
+pub mod shared_document;
 pub mod table;
+pub mod table_extraction;
 pub mod tagged;
+#[cfg(test)]
+mod test_fixtures;
 pub mod text;
 pub mod text_validation;
 pub mod types;
+pub mod words;
 
 // ── Error infrastructure ──────────────────────────────────────────────────────
 
-// Thread-local storage for the last error message.
+// Thread-local storage for the last error (message plus structured details).
 thread_local! {
-    static LAST_ERROR: RefCell<Option<String>> = const { RefCell::new(None) };
+    static LAST_ERROR: RefCell<Option<LastError>> = const { RefCell::new(None) };
 }
 
-/// Store an error message for later retrieval via `oxidize_get_last_error`.
-pub(crate) fn set_last_error<S: Into<String>>(msg: S) {
-    LAST_ERROR.with(|e| *e.borrow_mut() = Some(msg.into()));
+/// Store an error for later retrieval via `oxidize_get_last_error` /
+/// `oxidize_get_last_error_json`. Accepts a plain message or a [`LastError`].
+pub(crate) fn set_last_error<E: Into<LastError>>(err: E) {
+    LAST_ERROR.with(|e| *e.borrow_mut() = Some(err.into()));
 }
 
 /// Clear any previously stored error message.
@@ -47,6 +73,22 @@ pub(crate) fn clear_last_error() {
     LAST_ERROR.with(|e| *e.borrow_mut() = None);
 }
 
+/// Prefix this thread's last error with `context`, keeping the error itself
+/// (for failures while reporting it).
+fn annotate_last_error(context: impl std::fmt::Display) {
+    LAST_ERROR.with(|e| {
+        let mut slot = e.borrow_mut();
+        if let Some(err) = slot.take() {
+            *slot = Some(err.context(context));
+        }
+    });
+}
+
+/// Remove and return this thread's last error.
+pub(crate) fn take_last_error() -> Option<LastError> {
+    LAST_ERROR.with(|e| e.borrow_mut().take())
+}
+
 /// Find the nearest valid UTF-8 char boundary at or after `index`.
 /// Prevents panics when slicing strings at arbitrary byte positions.
 pub(crate) fn find_char_boundary(s: &str, mut index: usize) -> usize {
@@ -60,6 +102,11 @@ pub(crate) fn find_char_boundary(s: &str, mut index: usize) -> usize {
 }
 
 /// Error codes returned by all FFI functions.
+///
+/// `PasswordRequired`, `PageOutOfRange`, `Unsupported` and `LimitExceeded`
+/// are returned instead of the generic `PdfParseError` / `IoError` /
+/// `EncryptionError` whenever the underlying failure is known to be one of
+/// those (see `oxidize_get_last_error_json` for the full classification).
 #[repr(C)]
 pub enum ErrorCode {
     Success = 0,
@@ -74,6 +121,16 @@ pub enum ErrorCode {
     InvalidArgument = 9,
     /// A Rust panic was caught at the FFI boundary (see `oxidize_get_last_error`).
     Panic = 10,
+    /// The caller's `is_cancelled` callback requested cancellation (see `progress`).
+    Cancelled = 11,
+    /// The PDF is encrypted and could not be opened without (the right) password.
+    PasswordRequired = 12,
+    /// A page number or index lies outside the document.
+    PageOutOfRange = 13,
+    /// The PDF uses a feature or version this library does not support.
+    Unsupported = 14,
+    /// A configured resource limit was exceeded.
+    LimitExceeded = 15,
 }
 
 // ── Panic guards ──────────────────────────────────────────────────────────────
@@ -81,27 +138,63 @@ pub enum ErrorCode {
 /// Message stored in `LAST_ERROR` when a panic is caught at the FFI boundary.
 pub(crate) const PANIC_MESSAGE: &str = "internal error: a panic was caught at the FFI boundary";
 
+/// Record a caught panic on the last error and the log callback.
+fn record_panic() {
+    tracing::error!("{PANIC_MESSAGE}");
+    set_last_error(PANIC_MESSAGE);
+}
+
 /// Run an FFI body that returns an error code, catching any panic.
 ///
 /// Without this, a panic unwinding across the `extern "C"` boundary aborts the
 /// entire host process. Caught panics are converted to [`ErrorCode::Panic`] and
 /// recorded via [`set_last_error`]. Requires `panic = "unwind"` (see Cargo.toml).
+///
+/// Each call runs under its own wall-clock budget when
+/// `max_duration_ms` is configured (see [`limits`]).
+///
+/// A failing code is also recorded on the thread's last error (see
+/// [`LastError::settle`], which may narrow a generic code to a dedicated one).
 pub(crate) fn ffi_guard<F: FnOnce() -> c_int>(f: F) -> c_int {
-    match std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)) {
+    let _deadline = limits::Deadline::arm();
+    let code = match std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)) {
         Ok(code) => code,
         Err(_) => {
-            set_last_error(PANIC_MESSAGE);
+            record_panic();
             ErrorCode::Panic as c_int
         }
+    };
+    if code == ErrorCode::Success as c_int {
+        return code;
     }
+    settle_last_error(code)
+}
+
+/// Record `code` on the thread's last error (see [`LastError::settle`]) and
+/// return the code the caller should see.
+fn settle_last_error(code: c_int) -> c_int {
+    LAST_ERROR.with(|e| match e.borrow_mut().as_mut() {
+        Some(err) => err.settle(code),
+        None => code,
+    })
 }
 
 /// Run an FFI body that returns a pointer, catching any panic (returns null).
+///
+/// A null result settles the recorded error like a failing [`ffi_guard`]
+/// body returning `PdfParseError`, so `oxidize_get_last_error_json` reports
+/// the narrowed code (e.g. `PageOutOfRange`) rather than the generic one.
 pub(crate) fn ffi_guard_ptr<T, F: FnOnce() -> *mut T>(f: F) -> *mut T {
+    let _deadline = limits::Deadline::arm();
     match std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)) {
-        Ok(p) => p,
+        Ok(p) if !p.is_null() => p,
+        Ok(p) => {
+            settle_last_error(ErrorCode::PdfParseError as c_int);
+            p
+        }
         Err(_) => {
-            set_last_error(PANIC_MESSAGE);
+            record_panic();
+            settle_last_error(ErrorCode::Panic as c_int);
             ptr::null_mut()
         }
     }
@@ -110,7 +203,7 @@ pub(crate) fn ffi_guard_ptr<T, F: FnOnce() -> *mut T>(f: F) -> *mut T {
 /// Run an FFI body that returns nothing, catching any panic (swallows it).
 pub(crate) fn ffi_guard_unit<F: FnOnce()>(f: F) {
     if std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)).is_err() {
-        set_last_error(PANIC_MESSAGE);
+        record_panic();
     }
 }
 
@@ -173,7 +266,7 @@ pub unsafe extern "C" fn oxidize_get_last_error(out_error: *mut *mut c_char) ->
 
         *out_error = ptr::null_mut();
 
-        let error_msg = LAST_ERROR.with(|e| e.borrow().clone());
+        let error_msg = LAST_ERROR.with(|e| e.borrow().as_ref().map(|err| err.message.clone()));
 
         match error_msg {
             Some(msg) if !msg.is_empty() => match CString::new(msg) {
@@ -188,6 +281,54 @@ pub unsafe extern "C" fn oxidize_get_last_error(out_error: *mut *mut c_char) ->
     })
 }
 
+/// Retrieve the last error on this thread as a JSON object.
+///
+/// Shape: `{"code", "category", "message", "page_index", "object_number",
+/// "generation", "byte_offset", "causes"}`. `code` is the `ErrorCode` the
+/// failing call returned; `category` is a snake_case classification finer
+/// than the code (`corrupt_xref`, `password_required`, `invalid_reference`,
+/// `page_out_of_range`, `syntax`, …). The location fields are `null` when
+/// unknown; `causes` lists the underlying error chain, outermost first.
+/// `*out_json` is set to null when no error is recorded.
+///
+/// # Safety
+/// - `out_json` must be a valid pointer to a mutable pointer location.
+/// - The returned string must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_get_last_error_json(out_json: *mut *mut c_char) -> c_int {
+    // Not wrapped in `ffi_guard`: a failure here must not overwrite the error
+    // being reported, only prefix it, and a caught panic leaves it untouched.
+    let body = move || {
+        if out_json.is_null() {
+            return ErrorCode::NullPointer as c_int;
+        }
+
+        *out_json = ptr::null_mut();
+
+        let json = LAST_ERROR.with(|e| e.borrow().as_ref().map(LastError::to_json));
+
+        match json {
+            Some(Ok(json)) => match CString::new(json) {
+                Ok(c_string) => {
+                    *out_json = c_string.into_raw();
+                    ErrorCode::Success as c_int
+                }
+                Err(e) => {
+                    annotate_last_error(format_args!("Error JSON contains null bytes ({e})"));
+                    ErrorCode::InvalidUtf8 as c_int
+                }
+            },
+            Some(Err(e)) => {
+                annotate_last_error(format_args!("Failed to serialize error ({e})"));
+                ErrorCode::SerializationError as c_int
+            }
+            None => ErrorCode::Success as c_int,
+        }
+    };
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(body))
+        .unwrap_or(ErrorCode::Panic as c_int)
+}
+
 /// Return the library version string.
 ///
 /// # Safety
@@ -250,6 +391,21 @@ mod tests {
         assert_eq!(result, ErrorCode::NullPointer as c_int);
     }
 
+    #[test]
+    fn annotating_the_last_error_keeps_it() {
+        set_last_error(error_detail::LastError::new(
+            error_detail::ErrorCategory::Io,
+            "disk unplugged",
+        ));
+        annotate_last_error("Failed to report error");
+        let err = take_last_error().unwrap();
+        assert_eq!(err.message, "Failed to report error: disk unplugged");
+        assert!(err.to_json().unwrap().contains(r#""category":"io""#));
+
+        annotate_last_error("ignored without an error");
+        assert!(take_last_error().is_none());
+    }
+
     #[test]
     fn ffi_guard_returns_closure_value_when_no_panic() {
         let code = ffi_guard(|| ErrorCode::Success as c_int);
@@ -283,6 +439,26 @@ mod tests {
         assert!(p.is_null());
     }
 
+    #[test]
+    fn ffi_guard_ptr_settles_the_recorded_code() {
+        let p = ffi_guard_ptr::<i32, _>(|| {
+            clear_last_error();
+            set_last_error(LastError::new(
+                error_detail::ErrorCategory::PageOutOfRange,
+                "no such page",
+            ));
+            ptr::null_mut()
+        });
+        assert!(p.is_null());
+
+        let mut err: *mut c_char = ptr::null_mut();
+        unsafe { oxidize_get_last_error_json(&mut err) };
+        let json: serde_json::Value =
+            serde_json::from_str(unsafe { CStr::from_ptr(err) }.to_str().unwrap()).unwrap();
+        unsafe { oxidize_free_string(err) };
+        assert_eq!(json["code"], ErrorCode::PageOutOfRange as c_int);
+    }
+
     #[test]
     fn ffi_guard_unit_swallows_panic() {
         // Must not propagate; reaching the assert proves the panic was caught.
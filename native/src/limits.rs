@@ -0,0 +1,842 @@
+//! Resource limits for parsing untrusted PDFs.
+//!
+//! Limits are process-wide and set with [`oxidize_set_resource_limits`]. Every
+//! entry point that parses a PDF opens it through `parser::open_lenient`, which
+//! runs [`preflight`] before any content is decoded:
+//!
+//! - `max_objects` is checked against the trailer's `/Size`;
+//! - `max_page_tree_depth` and `max_pages` are checked by walking `/Kids`
+//!   (cycles are skipped, so a recursive tree cannot loop the walk);
+//! - `max_stream_bytes` bounds the decoded size of object streams, before
+//!   any object is loaded from them, and of every stream a page draws with:
+//!   content streams and, through its resources (including those of nested
+//!   forms) and annotation appearances, XObjects, patterns, shadings, font
+//!   programs, CMaps and Type 3 glyph procedures. The whole filter chain is
+//!   decoded into sinks that stop one byte past the limit, so a
+//!   decompression bomb, nested or not, is detected without being
+//!   materialized; a chain that cannot be bounded that way (`JBIG2Decode`,
+//!   for one) fails closed as over the limit. Streams
+//!   nothing on a page uses (embedded files, metadata, thumbnails) are not
+//!   checked, nor are cross-reference streams, which are decoded while the
+//!   file is opened, or the object streams of a file opened locked.
+//!
+//! `max_duration_ms` is a wall-clock budget per FFI call: `ffi_guard` arms a
+//! [`Deadline`] and the budget is checked during the pre-flight walk and at
+//! every page boundary of page-at-a-time work (text extraction, partitioning,
+//! chunking, merge, chunk streams). A single page already being processed by
+//! the core library is not interrupted.
+//!
+//! Violations fail with [`ErrorCode::LimitExceeded`]; the last-error category
+//! is `limit_exceeded`. All limits are unset (unlimited) by default.
+
+use std::cell::Cell;
+use std::collections::{BTreeSet, HashSet};
+use std::ffi::CString;
+use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+use oxidize_pdf::parser::xref::XRefTable;
+use oxidize_pdf::parser::{ParseOptions, PdfDictionary, PdfObject, PdfReader, PdfStream};
+use serde::{Deserialize, Serialize};
+
+use crate::bounded_decode::{self, Bound};
+use crate::error_detail::{ErrorCategory, LastError};
+use crate::parser::parse_json_arg;
+use crate::{clear_last_error, set_last_error, ErrorCode};
+
+/// Caps applied to every parse. `None` means unlimited.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ResourceLimits {
+    /// Maximum decoded size of a single stream, in bytes.
+    pub max_stream_bytes: Option<u64>,
+    /// Maximum number of indirect objects (trailer `/Size`).
+    pub max_objects: Option<u32>,
+    /// Maximum nesting of the page tree; the root `/Pages` node is depth 1.
+    pub max_page_tree_depth: Option<u32>,
+    /// Maximum number of pages in a document.
+    pub max_pages: Option<u32>,
+    /// Wall-clock budget for one FFI call, in milliseconds.
+    pub max_duration_ms: Option<u64>,
+}
+
+static LIMITS: RwLock<ResourceLimits> = RwLock::new(ResourceLimits {
+    max_stream_bytes: None,
+    max_objects: None,
+    max_page_tree_depth: None,
+    max_pages: None,
+    max_duration_ms: None,
+});
+
+thread_local! {
+    static DEADLINE: Cell<Option<(Instant, u64)>> = const { Cell::new(None) };
+}
+
+/// The limits currently in force.
+pub(crate) fn current() -> ResourceLimits {
+    *LIMITS.read().unwrap_or_else(|e| e.into_inner())
+}
+
+fn limit_error(message: String) -> LastError {
+    LastError::new(ErrorCategory::LimitExceeded, message)
+}
+
+/// Wall-clock budget of the FFI call running on this thread.
+///
+/// Arming keeps an earlier, stricter deadline, so an entry point called from
+/// another one does not extend the caller's budget. Dropping restores the
+/// previous deadline.
+pub(crate) struct Deadline {
+    previous: Option<(Instant, u64)>,
+}
+
+impl Deadline {
+    pub(crate) fn arm() -> Self {
+        let previous = DEADLINE.with(Cell::get);
+        if let Some(ms) = current().max_duration_ms {
+            let at = Instant::now() + Duration::from_millis(ms);
+            let armed = match previous {
+                Some((earlier, budget)) if earlier <= at => (earlier, budget),
+                _ => (at, ms),
+            };
+            DEADLINE.with(|d| d.set(Some(armed)));
+        }
+        Deadline { previous }
+    }
+
+    /// Arm `deadline`, taken with [`armed_deadline`] on the calling thread,
+    /// on a worker thread so the worker shares the caller's budget.
+    pub(crate) fn inherit(deadline: Option<(Instant, u64)>) -> Self {
+        let previous = DEADLINE.with(Cell::get);
+        DEADLINE.with(|d| d.set(deadline));
+        Deadline { previous }
+    }
+}
+
+impl Drop for Deadline {
+    fn drop(&mut self) {
+        DEADLINE.with(|d| d.set(self.previous));
+    }
+}
+
+/// The deadline armed on this thread, for [`Deadline::inherit`].
+pub(crate) fn armed_deadline() -> Option<(Instant, u64)> {
+    DEADLINE.with(Cell::get)
+}
+
+/// Whether a wall-clock budget is armed on this thread.
+pub(crate) fn has_deadline() -> bool {
+    DEADLINE.with(Cell::get).is_some()
+}
+
+/// Fail once the armed wall-clock budget has run out.
+pub(crate) fn check_deadline() -> Result<(), LastError> {
+    match DEADLINE.with(Cell::get) {
+        Some((at, budget)) if Instant::now() >= at => Err(limit_error(format!(
+            "Wall-clock budget of {budget} ms exceeded (max_duration_ms)"
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Check an opened document against `limits` before any page is processed.
+pub(crate) fn preflight<R: Read + Seek>(
+    reader: &mut PdfReader<R>,
+    limits: &ResourceLimits,
+) -> Result<(), LastError> {
+    check_deadline()?;
+
+    if let Some(max) = limits.max_objects {
+        if let Ok(size) = reader.trailer().size() {
+            if size > max {
+                return Err(limit_error(format!(
+                    "PDF declares {size} objects, above the limit of {max} (max_objects)"
+                )));
+            }
+        }
+    }
+
+    if limits.max_page_tree_depth.is_some()
+        || limits.max_pages.is_some()
+        || limits.max_stream_bytes.is_some()
+    {
+        walk_page_tree(reader, limits)?;
+    }
+    Ok(())
+}
+
+/// Object numbers of the object streams named by `source`'s
+/// cross-reference data, when `max_stream_bytes` is set.
+///
+/// `PdfReader` keeps its table private, so the cross-reference data is
+/// parsed a second time here; `source` is left where it started.
+pub(crate) fn object_streams<R: Read + Seek>(
+    source: &mut R,
+    options: &ParseOptions,
+    limits: &ResourceLimits,
+) -> Vec<u32> {
+    if limits.max_stream_bytes.is_none() {
+        return Vec::new();
+    }
+    let Ok(start) = source.stream_position() else {
+        return Vec::new();
+    };
+    let xref = XRefTable::parse_with_options(&mut BufReader::new(&mut *source), options);
+    if source.seek(SeekFrom::Start(start)).is_err() {
+        return Vec::new();
+    }
+    let Ok(xref) = xref else {
+        return Vec::new();
+    };
+    let streams: BTreeSet<u32> = xref
+        .iter()
+        .filter_map(|(num, _)| xref.get_extended_entry(*num)?.compressed_info)
+        .map(|(stream, _)| stream)
+        .collect();
+    streams.into_iter().collect()
+}
+
+/// Check the object streams found by [`object_streams`] before any object
+/// is loaded from them, which would decode them in full.
+///
+/// The streams of a file that is still locked cannot be decoded yet and are
+/// skipped.
+pub(crate) fn check_object_streams<R: Read + Seek>(
+    reader: &mut PdfReader<R>,
+    streams: &[u32],
+    limits: &ResourceLimits,
+) -> Result<(), LastError> {
+    let Some(max) = limits.max_stream_bytes else {
+        return Ok(());
+    };
+    for &num in streams {
+        check_deadline()?;
+        if let Ok(PdfObject::Stream(stream)) = reader.get_object(num, 0) {
+            check_stream(stream, max).map_err(|e| e.at_object(num, 0))?;
+        }
+    }
+    Ok(())
+}
+
+/// Depth-first walk of the page tree in document order.
+///
+/// A document without a usable page tree is left to the normal parse path,
+/// which reports the structural error itself.
+fn walk_page_tree<R: Read + Seek>(
+    reader: &mut PdfReader<R>,
+    limits: &ResourceLimits,
+) -> Result<(), LastError> {
+    let root = match reader.pages() {
+        Ok(root) => root.clone(),
+        Err(_) => return Ok(()),
+    };
+
+    let mut visited = HashSet::new();
+    let mut checked_streams = HashSet::new();
+    let mut stack = vec![(root, 1u32)];
+    let mut pages = 0u32;
+
+    while let Some((node, depth)) = stack.pop() {
+        check_deadline()?;
+
+        if let Some(max) = limits.max_page_tree_depth {
+            if depth > max {
+                return Err(limit_error(format!(
+                    "Page tree is nested deeper than {max} levels (max_page_tree_depth)"
+                )));
+            }
+        }
+
+        if let Some(kids) = node.get("Kids").and_then(PdfObject::as_array) {
+            let mut children = Vec::with_capacity(kids.len());
+            for kid in &kids.0 {
+                let child = match kid {
+                    PdfObject::Reference(num, generation) => {
+                        if !visited.insert((*num, *generation)) {
+                            continue;
+                        }
+                        reader
+                            .get_object(*num, *generation)
+                            .ok()
+                            .and_then(PdfObject::as_dict)
+                            .cloned()
+                    }
+                    PdfObject::Dictionary(dict) => Some(dict.clone()),
+                    _ => None,
+                };
+                if let Some(child) = child {
+                    children.push((child, depth + 1));
+                }
+            }
+            stack.extend(children.into_iter().rev());
+            continue;
+        }
+
+        let page_index = pages;
+        pages += 1;
+        if let Some(max) = limits.max_pages {
+            if pages > max {
+                return Err(
+                    limit_error(format!("PDF has more than {max} pages (max_pages)"))
+                        .at_page(page_index),
+                );
+            }
+        }
+        if let Some(max) = limits.max_stream_bytes {
+            check_page_streams(reader, &node, max, &mut checked_streams)
+                .map_err(|e| e.at_page(page_index))?;
+        }
+    }
+    Ok(())
+}
+
+/// How an object reached from a page is used, which decides the entries
+/// followed from it.
+#[derive(Clone, Copy)]
+enum Reach {
+    /// Content stream, font program, CMap or shading: checked, not followed.
+    Stream,
+    /// Resource dictionary.
+    Resources,
+    /// Dictionary whose values are all reached as `Form`.
+    Forms,
+    /// Dictionary whose values are all reached as `Font`.
+    Fonts,
+    /// Dictionary whose values are all reached as `Stream`.
+    Streams,
+    /// XObject, pattern, glyph procedure or appearance stream.
+    Form,
+    Font,
+    FontDescriptor,
+    Annotation,
+    /// An `/AP` entry: one appearance stream, or a dictionary of them per state.
+    Appearance,
+}
+
+/// Entries followed from a form XObject, pattern or appearance stream.
+const FORM: &[(&str, Reach)] = &[
+    ("Resources", Reach::Resources),
+    ("SMask", Reach::Form),
+    ("Shading", Reach::Stream),
+];
+
+/// Check every stream the page draws with: its content streams, and through
+/// its resources and annotation appearances the XObjects, patterns,
+/// shadings, font programs, CMaps and Type 3 glyph procedures, including
+/// those of nested form XObjects.
+fn check_page_streams<R: Read + Seek>(
+    reader: &mut PdfReader<R>,
+    page: &PdfDictionary,
+    max: u64,
+    checked: &mut HashSet<(u32, u16)>,
+) -> Result<(), LastError> {
+    let mut pending: Vec<(PdfObject, Reach)> = [
+        ("Contents", Reach::Stream),
+        ("Resources", Reach::Resources),
+        ("Annots", Reach::Annotation),
+    ]
+    .into_iter()
+    .filter_map(|(key, reach)| Some((page.get(key)?.clone(), reach)))
+    .collect();
+
+    while let Some((object, reach)) = pending.pop() {
+        let object = match object {
+            PdfObject::Reference(num, generation) => {
+                if !checked.insert((num, generation)) {
+                    continue;
+                }
+                check_deadline()?;
+                let Ok(object) = reader.get_object(num, generation) else {
+                    continue;
+                };
+                if let PdfObject::Stream(stream) = object {
+                    check_stream(stream, max).map_err(|e| e.at_object(num, generation))?;
+                }
+                object.clone()
+            }
+            other => other,
+        };
+        let dict = match &object {
+            PdfObject::Array(array) => {
+                pending.extend(array.0.iter().map(|item| (item.clone(), reach)));
+                continue;
+            }
+            PdfObject::Dictionary(dict) => dict,
+            PdfObject::Stream(stream) => &stream.dict,
+            _ => continue,
+        };
+        let follow: &[(&str, Reach)] = match reach {
+            Reach::Stream => &[],
+            Reach::Resources => &[
+                ("XObject", Reach::Forms),
+                ("Pattern", Reach::Forms),
+                ("Font", Reach::Fonts),
+                ("Shading", Reach::Streams),
+            ],
+            Reach::Forms | Reach::Fonts | Reach::Streams => {
+                let values = match reach {
+                    Reach::Forms => Reach::Form,
+                    Reach::Fonts => Reach::Font,
+                    _ => Reach::Stream,
+                };
+                pending.extend(dict.0.values().map(|value| (value.clone(), values)));
+                continue;
+            }
+            Reach::Form => FORM,
+            Reach::Font => &[
+                ("FontDescriptor", Reach::FontDescriptor),
+                ("DescendantFonts", Reach::Font),
+                ("ToUnicode", Reach::Stream),
+                ("Encoding", Reach::Stream),
+                ("CharProcs", Reach::Forms),
+                ("Resources", Reach::Resources),
+            ],
+            Reach::FontDescriptor => &[
+                ("FontFile", Reach::Stream),
+                ("FontFile2", Reach::Stream),
+                ("FontFile3", Reach::Stream),
+            ],
+            Reach::Annotation => &[("AP", Reach::Appearance)],
+            // `/N`, `/R` and `/D` hold a stream or a per-state dictionary of
+            // streams; the streams are forms.
+            Reach::Appearance if matches!(object, PdfObject::Stream(_)) => FORM,
+            Reach::Appearance => {
+                pending.extend(dict.0.values().map(|value| (value.clone(), reach)));
+                continue;
+            }
+        };
+        for (key, next) in follow {
+            if let Some(value) = dict.get(key) {
+                pending.push((value.clone(), *next));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Fail when `stream` decodes to more than `max` bytes, or when its filter
+/// chain cannot be bounded without decoding it.
+fn check_stream(stream: &PdfStream, max: u64) -> Result<(), LastError> {
+    let decoded = match bounded_decode::decoded_len(stream, max) {
+        Bound::Len(len) => len,
+        Bound::Unbounded(filter) => {
+            return Err(limit_error(format!(
+                "Stream filter {filter} cannot be bounded before decoding (max_stream_bytes)"
+            )))
+        }
+    };
+    if decoded > max {
+        return Err(limit_error(format!(
+            "Stream decodes to more than {max} bytes (max_stream_bytes)"
+        )));
+    }
+    Ok(())
+}
+
+// ── FFI ───────────────────────────────────────────────────────────────────────
+
+/// Set the process-wide resource limits.
+///
+/// `limits_json` is a JSON object with any of `max_stream_bytes`,
+/// `max_objects`, `max_page_tree_depth`, `max_pages` and `max_duration_ms`;
+/// omitted or `null` fields are unlimited. Passing null resets every limit.
+/// Unknown fields are rejected so a misspelled limit is not silently ignored.
+///
+/// # Errors
+/// - `InvalidUtf8`: `limits_json` is not valid UTF-8.
+/// - `InvalidArgument`: `limits_json` is not a valid limits object.
+///
+/// # Safety
+/// - `limits_json` must be null or a valid NUL-terminated UTF-8 C string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_set_resource_limits(limits_json: *const c_char) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+
+        let limits = if limits_json.is_null() {
+            ResourceLimits::default()
+        } else {
+            match parse_json_arg(limits_json, "limits_json", "ResourceLimits") {
+                Ok(l) => l,
+                Err(code) => return code,
+            }
+        };
+
+        *LIMITS.write().unwrap_or_else(|e| e.into_inner()) = limits;
+        ErrorCode::Success as c_int
+    })
+}
+
+/// Get the process-wide resource limits as JSON (same shape as
+/// [`oxidize_set_resource_limits`], unset limits as `null`).
+///
+/// # Safety
+/// - `out_json` must be a valid pointer to a mutable pointer location.
+/// - The returned string must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_get_resource_limits(out_json: *mut *mut c_char) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+
+        if out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_get_resource_limits");
+            return ErrorCode::NullPointer as c_int;
+        }
+
+        *out_json = ptr::null_mut();
+
+        let json = match serde_json::to_string(&current()) {
+            Ok(j) => j,
+            Err(e) => {
+                set_last_error(LastError::caused_by("Failed to serialize limits", &e));
+                return ErrorCode::SerializationError as c_int;
+            }
+        };
+        match CString::new(json) {
+            Ok(c_string) => {
+                *out_json = c_string.into_raw();
+                ErrorCode::Success as c_int
+            }
+            Err(_) => ErrorCode::InvalidUtf8 as c_int,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::open_lenient;
+    use crate::test_fixtures::{raw_pdf, sample_pdf};
+    use std::io::Cursor;
+
+    fn reader(bytes: &[u8]) -> PdfReader<Cursor<&[u8]>> {
+        open_lenient(bytes).expect("sample parses")
+    }
+
+    /// The error as reported by `oxidize_get_last_error_json`.
+    fn detail(err: &LastError) -> serde_json::Value {
+        serde_json::from_str(&err.to_json().unwrap()).unwrap()
+    }
+
+    fn assert_limit(result: Result<(), LastError>, needle: &str) -> serde_json::Value {
+        let err = result.expect_err("limit must be enforced");
+        assert!(err.message.contains(needle), "{}", err.message);
+        let detail = detail(&err);
+        assert_eq!(detail["category"], "limit_exceeded");
+        detail
+    }
+
+    #[test]
+    fn unlimited_by_default() {
+        let bytes = sample_pdf(3);
+        assert!(preflight(&mut reader(&bytes), &ResourceLimits::default()).is_ok());
+    }
+
+    #[test]
+    fn page_count_and_depth_limits() {
+        let bytes = sample_pdf(3);
+        let within = ResourceLimits {
+            max_pages: Some(3),
+            max_page_tree_depth: Some(2),
+            ..Default::default()
+        };
+        assert!(preflight(&mut reader(&bytes), &within).is_ok());
+
+        let pages = ResourceLimits {
+            max_pages: Some(2),
+            ..Default::default()
+        };
+        let detail = assert_limit(preflight(&mut reader(&bytes), &pages), "max_pages");
+        assert_eq!(detail["page_index"], 2);
+
+        let depth = ResourceLimits {
+            max_page_tree_depth: Some(1),
+            ..Default::default()
+        };
+        assert_limit(
+            preflight(&mut reader(&bytes), &depth),
+            "max_page_tree_depth",
+        );
+    }
+
+    #[test]
+    fn object_count_limit_uses_trailer_size() {
+        let bytes = sample_pdf(1);
+        let size = reader(&bytes).trailer().size().unwrap();
+        let at = ResourceLimits {
+            max_objects: Some(size),
+            ..Default::default()
+        };
+        assert!(preflight(&mut reader(&bytes), &at).is_ok());
+        let below = ResourceLimits {
+            max_objects: Some(size - 1),
+            ..Default::default()
+        };
+        assert_limit(preflight(&mut reader(&bytes), &below), "max_objects");
+    }
+
+    /// `prefix` followed by 4 MiB of spaces, flate-compressed to a few KiB.
+    fn flate_bomb(prefix: &[u8]) -> Vec<u8> {
+        use flate2::write::ZlibEncoder;
+        use std::io::Write;
+
+        let mut encoder = ZlibEncoder::new(Vec::new(), flate2::Compression::best());
+        encoder.write_all(prefix).unwrap();
+        encoder.write_all(&vec![b' '; 4 << 20]).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    /// Body of a stream object with `dict` entries and a computed `/Length`.
+    fn stream_object(dict: &str, data: &[u8]) -> Vec<u8> {
+        let mut body = format!("<< {dict} /Length {} >>\nstream\n", data.len()).into_bytes();
+        body.extend_from_slice(data);
+        body.extend_from_slice(b"\nendstream");
+        body
+    }
+
+    #[test]
+    fn stream_limit_detects_flate_bomb_without_inflating_it() {
+        let compressed = flate_bomb(b"");
+        assert!(compressed.len() < 64 * 1024);
+
+        let mut dict = PdfDictionary::new();
+        dict.insert(
+            "Filter".to_string(),
+            PdfObject::Name(oxidize_pdf::parser::PdfName("FlateDecode".into())),
+        );
+        let stream = PdfStream {
+            dict,
+            data: compressed,
+        };
+        assert!(check_stream(&stream, 8 << 20).is_ok());
+        assert_limit(check_stream(&stream, 1 << 20), "max_stream_bytes");
+    }
+
+    /// A stream of `data` with the `filters` chain.
+    fn filtered_stream(filters: &[&str], data: Vec<u8>) -> PdfStream {
+        let names = filters
+            .iter()
+            .map(|f| PdfObject::Name(oxidize_pdf::parser::PdfName((*f).into())))
+            .collect();
+        let mut dict = PdfDictionary::new();
+        dict.insert(
+            "Filter".to_string(),
+            PdfObject::Array(oxidize_pdf::parser::PdfArray(names)),
+        );
+        PdfStream { dict, data }
+    }
+
+    #[test]
+    fn stream_limit_detects_double_flate_bomb() {
+        use flate2::write::ZlibEncoder;
+        use std::io::Write;
+
+        // The outer layer inflates to a few KiB, the inner one to 4 MiB.
+        let mut encoder = ZlibEncoder::new(Vec::new(), flate2::Compression::best());
+        encoder.write_all(&flate_bomb(b"")).unwrap();
+        let stream = filtered_stream(&["FlateDecode", "FlateDecode"], encoder.finish().unwrap());
+        assert!(stream.data.len() < 64 * 1024);
+
+        assert!(check_stream(&stream, 8 << 20).is_ok());
+        assert_limit(check_stream(&stream, 1 << 20), "max_stream_bytes");
+    }
+
+    #[test]
+    fn stream_limit_detects_ascii85_flate_bomb() {
+        let compressed = flate_bomb(b"");
+        let mut encoded = Vec::new();
+        for group in compressed.chunks(4) {
+            let mut word = [0u8; 4];
+            word[..group.len()].copy_from_slice(group);
+            let mut value = u32::from_be_bytes(word);
+            let mut digits = [0u8; 5];
+            for digit in digits.iter_mut().rev() {
+                *digit = b'!' + (value % 85) as u8;
+                value /= 85;
+            }
+            encoded.extend_from_slice(&digits[..group.len() + 1]);
+        }
+        encoded.extend_from_slice(b"~>");
+        let stream = filtered_stream(&["ASCII85Decode", "FlateDecode"], encoded);
+
+        assert!(check_stream(&stream, 8 << 20).is_ok());
+        assert_limit(check_stream(&stream, 1 << 20), "max_stream_bytes");
+    }
+
+    #[test]
+    fn stream_limit_fails_closed_on_unboundable_filters() {
+        let stream = filtered_stream(&["JBIG2Decode"], vec![0; 16]);
+        let detail = assert_limit(check_stream(&stream, 8 << 20), "max_stream_bytes");
+        assert!(detail["message"].as_str().unwrap().contains("JBIG2Decode"));
+    }
+
+    #[test]
+    fn stream_limit_applies_to_page_contents() {
+        let bytes = sample_pdf(2);
+        let tiny = ResourceLimits {
+            max_stream_bytes: Some(8),
+            ..Default::default()
+        };
+        let detail = assert_limit(preflight(&mut reader(&bytes), &tiny), "max_stream_bytes");
+        assert_eq!(detail["page_index"], 0);
+        assert!(detail["object_number"].is_u64());
+    }
+
+    #[test]
+    fn stream_limit_applies_to_fonts_of_nested_forms() {
+        let objects = [
+            b"<< /Type /Catalog /Pages 2 0 R >>".to_vec(),
+            b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_vec(),
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 200 200] \
+               /Resources << /XObject << /Fm1 4 0 R >> >> /Contents 5 0 R >>"
+                .to_vec(),
+            stream_object(
+                "/Type /XObject /Subtype /Form /BBox [0 0 200 200] \
+                 /Resources << /Font << /F1 6 0 R >> >>",
+                b"BT /F1 12 Tf (x) Tj ET",
+            ),
+            stream_object("", b"/Fm1 Do"),
+            b"<< /Type /Font /Subtype /TrueType /BaseFont /Bomb /FontDescriptor 7 0 R >>".to_vec(),
+            b"<< /Type /FontDescriptor /FontName /Bomb /FontFile2 8 0 R >>".to_vec(),
+            stream_object("/Filter /FlateDecode", &flate_bomb(b"")),
+        ];
+        let bytes = raw_pdf(&objects);
+        let roomy = ResourceLimits {
+            max_stream_bytes: Some(8 << 20),
+            ..Default::default()
+        };
+        assert!(preflight(&mut reader(&bytes), &roomy).is_ok());
+
+        let tight = ResourceLimits {
+            max_stream_bytes: Some(1 << 20),
+            ..Default::default()
+        };
+        let detail = assert_limit(preflight(&mut reader(&bytes), &tight), "max_stream_bytes");
+        assert_eq!(detail["page_index"], 0);
+        assert_eq!(detail["object_number"], 8);
+    }
+
+    #[test]
+    fn stream_limit_applies_to_object_streams_before_they_are_decoded() {
+        // Objects 1-3 live in object stream 4, padded into a bomb; object 5
+        // is an uncompressed cross-reference stream.
+        let members = [
+            "<< /Type /Catalog /Pages 2 0 R >>",
+            "<< /Type /Pages /Kids [3 0 R] /Count 1 >>",
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 200 200] >>",
+        ];
+        let mut header = String::new();
+        let mut body = String::new();
+        for (i, member) in members.iter().enumerate() {
+            header.push_str(&format!("{} {} ", i + 1, body.len()));
+            body.push_str(member);
+            body.push(' ');
+        }
+        let object_stream = stream_object(
+            &format!(
+                "/Type /ObjStm /N 3 /First {} /Filter /FlateDecode",
+                header.len()
+            ),
+            &flate_bomb(format!("{header}{body}").as_bytes()),
+        );
+
+        let mut bytes = b"%PDF-1.5\n".to_vec();
+        let object_stream_at = bytes.len();
+        bytes.extend_from_slice(b"4 0 obj\n");
+        bytes.extend_from_slice(&object_stream);
+        bytes.extend_from_slice(b"\nendobj\n");
+        let xref_at = bytes.len();
+        let mut entries = vec![0u8, 0, 0, 0, 0, 0xff, 0xff];
+        for index in 0..3u16 {
+            entries.extend_from_slice(&[2, 0, 0, 0, 4]);
+            entries.extend_from_slice(&index.to_be_bytes());
+        }
+        for offset in [object_stream_at, xref_at] {
+            entries.push(1);
+            entries.extend_from_slice(&(offset as u32).to_be_bytes());
+            entries.extend_from_slice(&[0, 0]);
+        }
+        bytes.extend_from_slice(b"5 0 obj\n");
+        bytes.extend_from_slice(&stream_object(
+            "/Type /XRef /Size 6 /W [1 4 2] /Root 1 0 R",
+            &entries,
+        ));
+        bytes.extend_from_slice(format!("\nendobj\nstartxref\n{xref_at}\n%%EOF\n").as_bytes());
+
+        let open = || {
+            PdfReader::new_with_options(Cursor::new(&bytes[..]), ParseOptions::lenient()).unwrap()
+        };
+        let tight = ResourceLimits {
+            max_stream_bytes: Some(1 << 20),
+            ..Default::default()
+        };
+        let streams = object_streams(
+            &mut Cursor::new(&bytes[..]),
+            &ParseOptions::lenient(),
+            &tight,
+        );
+        assert_eq!(streams, vec![4]);
+        let detail = assert_limit(
+            check_object_streams(&mut open(), &streams, &tight),
+            "max_stream_bytes",
+        );
+        assert_eq!(detail["object_number"], 4);
+
+        let roomy = ResourceLimits {
+            max_stream_bytes: Some(8 << 20),
+            ..Default::default()
+        };
+        let mut reader = open();
+        assert!(check_object_streams(&mut reader, &streams, &roomy).is_ok());
+        assert_eq!(reader.page_count().unwrap(), 1);
+        assert!(object_streams(
+            &mut Cursor::new(&bytes[..]),
+            &ParseOptions::lenient(),
+            &ResourceLimits::default()
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn deadline_is_scoped_to_the_armed_call() {
+        assert!(!has_deadline());
+        DEADLINE.with(|d| d.set(Some((Instant::now(), 5))));
+        assert_limit(check_deadline(), "max_duration_ms");
+        {
+            // Limits are unset, so arming keeps the outer deadline.
+            let _inner = Deadline::arm();
+            assert!(has_deadline());
+        }
+        assert!(has_deadline());
+        DEADLINE.with(|d| d.set(None));
+        assert!(check_deadline().is_ok());
+    }
+
+    #[test]
+    fn ffi_rejects_unknown_fields_and_null_output() {
+        let bad = CString::new(r#"{"max_page":1}"#).unwrap();
+        let rc = unsafe { oxidize_set_resource_limits(bad.as_ptr()) };
+        assert_eq!(rc, ErrorCode::InvalidArgument as c_int);
+
+        let rc = unsafe { oxidize_get_resource_limits(ptr::null_mut()) };
+        assert_eq!(rc, ErrorCode::NullPointer as c_int);
+
+        // Null resets to the default, which is what every other test runs with.
+        let rc = unsafe { oxidize_set_resource_limits(ptr::null()) };
+        assert_eq!(rc, ErrorCode::Success as c_int);
+    }
+
+    #[test]
+    fn ffi_get_reports_current_limits() {
+        let mut out: *mut c_char = ptr::null_mut();
+        let rc = unsafe { oxidize_get_resource_limits(&mut out) };
+        assert_eq!(rc, ErrorCode::Success as c_int);
+        let json = unsafe { std::ffi::CStr::from_ptr(out) }.to_str().unwrap();
+        let parsed: ResourceLimits = serde_json::from_str(json).unwrap();
+        assert_eq!(parsed, current());
+        unsafe { crate::oxidize_free_string(out) };
+    }
+}
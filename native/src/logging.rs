@@ -0,0 +1,326 @@
+//! Process-wide log callback for native diagnostics.
+//!
+//! The core library reports the decisions it makes on damaged input
+//! (xref recovery, substituted objects, malformed font data, …) as `tracing`
+//! events. [`oxidize_set_log_callback`] installs a subscriber that forwards
+//! every event at or above the chosen level to the caller as
+//! `(level, target, message)`, where `target` is the emitting module
+//! (e.g. `oxidize_pdf::parser::reader`) and `message` is the event text
+//! followed by any structured fields as ` key=value`.
+//! Events from this crate (caught panics, fallbacks) use `oxidize_pdf_ffi::…`
+//! targets.
+//!
+//! The subscriber is installed as the process's global `tracing` dispatcher
+//! on first registration and stays installed; clearing the callback only
+//! stops delivery. Events emitted while the callback runs on the same thread
+//! (a callback that calls back into the library) are dropped.
+
+use std::cell::Cell;
+use std::ffi::{c_void, CString};
+use std::fmt::{self, Write as _};
+use std::os::raw::{c_char, c_int};
+use std::sync::atomic::{AtomicI32, Ordering};
+use std::sync::{OnceLock, RwLock};
+
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::subscriber::{Interest, Subscriber};
+use tracing::{Event, Level, Metadata};
+
+use crate::error_detail::{ErrorCategory, LastError};
+use crate::{clear_last_error, set_last_error, ErrorCode};
+
+/// Receives one log record. `target` and `message` are NUL-terminated UTF-8
+/// strings valid only for the duration of the call.
+pub type LogCallback = unsafe extern "C" fn(
+    user_data: *mut c_void,
+    level: c_int,
+    target: *const c_char,
+    message: *const c_char,
+);
+
+/// Log levels, most to least severe.
+pub const OXIDIZE_LOG_ERROR: c_int = 1;
+pub const OXIDIZE_LOG_WARN: c_int = 2;
+pub const OXIDIZE_LOG_INFO: c_int = 3;
+pub const OXIDIZE_LOG_DEBUG: c_int = 4;
+pub const OXIDIZE_LOG_TRACE: c_int = 5;
+
+struct Sink {
+    callback: LogCallback,
+    user_data: *mut c_void,
+}
+
+// The caller guarantees `user_data` may be used from any thread (see
+// `oxidize_set_log_callback`).
+unsafe impl Send for Sink {}
+unsafe impl Sync for Sink {}
+
+static SINK: RwLock<Option<Sink>> = RwLock::new(None);
+
+/// Most verbose level delivered; 0 while no callback is registered.
+static MAX_LEVEL: AtomicI32 = AtomicI32::new(0);
+
+/// Outcome of installing the global dispatcher (first registration only).
+static INSTALLED: OnceLock<bool> = OnceLock::new();
+
+thread_local! {
+    static IN_CALLBACK: Cell<bool> = const { Cell::new(false) };
+}
+
+fn level_value(level: &Level) -> c_int {
+    match *level {
+        Level::ERROR => OXIDIZE_LOG_ERROR,
+        Level::WARN => OXIDIZE_LOG_WARN,
+        Level::INFO => OXIDIZE_LOG_INFO,
+        Level::DEBUG => OXIDIZE_LOG_DEBUG,
+        Level::TRACE => OXIDIZE_LOG_TRACE,
+    }
+}
+
+/// Event text: the `message` field, then the other fields as ` key=value`.
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: String,
+}
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message.push_str(value);
+        } else {
+            let _ = write!(self.fields, " {}={value}", field.name());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.message, "{value:?}");
+        } else {
+            let _ = write!(self.fields, " {}={value:?}", field.name());
+        }
+    }
+}
+
+/// Forwards events to the registered [`Sink`]. Spans are not tracked.
+struct CallbackSubscriber;
+
+impl Subscriber for CallbackSubscriber {
+    fn register_callsite(&self, _: &'static Metadata<'static>) -> Interest {
+        // The level can change at any time, so ask `enabled` on every event.
+        Interest::sometimes()
+    }
+
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.is_event() && wants(metadata.level())
+    }
+
+    fn new_span(&self, _: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        forward(event);
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+/// Whether the registered callback takes events at `level`.
+pub(crate) fn wants(level: &Level) -> bool {
+    level_value(level) <= MAX_LEVEL.load(Ordering::Relaxed)
+}
+
+/// Event text as delivered to the callback: the `message` field, then the
+/// other fields as ` key=value`.
+pub(crate) fn event_text(event: &Event<'_>) -> String {
+    let mut visitor = MessageVisitor::default();
+    event.record(&mut visitor);
+    let mut message = visitor.message.trim().to_owned();
+    message.push_str(&visitor.fields);
+    message
+}
+
+/// Deliver `event` to the registered callback, if any. Scoped subscribers
+/// (see [`crate::recovery_report`]) call this so the callback keeps
+/// receiving events while they are installed.
+pub(crate) fn forward(event: &Event<'_>) {
+    if !wants(event.metadata().level()) || IN_CALLBACK.with(Cell::get) {
+        return;
+    }
+    let sink = SINK.read().unwrap_or_else(|e| e.into_inner());
+    let Some(sink) = sink.as_ref() else {
+        return;
+    };
+
+    let metadata = event.metadata();
+    let message = CString::new(event_text(event).replace('\0', " ")).unwrap_or_default();
+    let target = CString::new(metadata.target()).unwrap_or_default();
+
+    IN_CALLBACK.with(|c| c.set(true));
+    unsafe {
+        (sink.callback)(
+            sink.user_data,
+            level_value(metadata.level()),
+            target.as_ptr(),
+            message.as_ptr(),
+        )
+    };
+    IN_CALLBACK.with(|c| c.set(false));
+}
+
+/// Register the process-wide log callback, replacing any previous one, or
+/// clear it with a null `callback`.
+///
+/// Events from `OXIDIZE_LOG_ERROR` (1) up to `max_level` (1–5, through
+/// `OXIDIZE_LOG_TRACE`) are delivered; `max_level` is ignored when clearing.
+/// The callback may run on any thread, including several at once (parallel
+/// entry points, concurrent callers), so it and `user_data` must be
+/// thread-safe. It must not unwind or call `oxidize_set_log_callback`.
+///
+/// Once this returns, the previous callback is no longer running and will
+/// not be called again.
+///
+/// # Returns
+/// `Success`; `InvalidArgument` for a `max_level` outside 1–5;
+/// `Unsupported` if another `tracing` subscriber already owns the process's
+/// global dispatcher.
+///
+/// # Safety
+/// - `callback` must be null or a function matching [`LogCallback`].
+/// - `user_data` must stay valid until the callback is replaced or cleared.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_set_log_callback(
+    callback: Option<LogCallback>,
+    user_data: *mut c_void,
+    max_level: c_int,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+
+        let Some(callback) = callback else {
+            MAX_LEVEL.store(0, Ordering::Relaxed);
+            *SINK.write().unwrap_or_else(|e| e.into_inner()) = None;
+            return ErrorCode::Success as c_int;
+        };
+
+        if !(OXIDIZE_LOG_ERROR..=OXIDIZE_LOG_TRACE).contains(&max_level) {
+            set_last_error(LastError::new(
+                ErrorCategory::InvalidArgument,
+                format!("max_level must be 1 (error) to 5 (trace), got {max_level}"),
+            ));
+            return ErrorCode::InvalidArgument as c_int;
+        }
+
+        let installed = *INSTALLED.get_or_init(|| {
+            tracing::dispatcher::set_global_default(tracing::Dispatch::new(CallbackSubscriber))
+                .is_ok()
+        });
+        if !installed {
+            set_last_error(LastError::new(
+                ErrorCategory::Unsupported,
+                "Another tracing subscriber is already installed in this process",
+            ));
+            return ErrorCode::Unsupported as c_int;
+        }
+
+        *SINK.write().unwrap_or_else(|e| e.into_inner()) = Some(Sink {
+            callback,
+            user_data,
+        });
+        MAX_LEVEL.store(max_level, Ordering::Relaxed);
+        ErrorCode::Success as c_int
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+    use std::sync::Mutex;
+
+    type Records = Mutex<Vec<(c_int, String, String)>>;
+
+    unsafe extern "C" fn record(
+        user_data: *mut c_void,
+        level: c_int,
+        target: *const c_char,
+        message: *const c_char,
+    ) {
+        let records = &*(user_data as *const Records);
+        let target = CStr::from_ptr(target).to_string_lossy().into_owned();
+        let message = CStr::from_ptr(message).to_string_lossy().into_owned();
+        records.lock().unwrap().push((level, target, message));
+    }
+
+    fn damaged_pdf() -> Vec<u8> {
+        use oxidize_pdf::{Document, Page};
+        let mut doc = Document::new();
+        doc.add_page(Page::a4());
+        let mut pdf = doc.to_bytes().unwrap();
+        // Point startxref past the end of the file so the reader recovers.
+        let at = pdf.windows(9).rposition(|w| w == b"startxref").unwrap();
+        pdf.truncate(at);
+        pdf.extend_from_slice(b"startxref\n99999999\n%%EOF\n");
+        pdf
+    }
+
+    fn recovery_warnings(records: &Records) -> Vec<(c_int, String, String)> {
+        records
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(_, target, message)| {
+                target.starts_with("oxidize_pdf::parser") && message.contains("XRef")
+            })
+            .cloned()
+            .collect()
+    }
+
+    // One test drives the process-wide callback, so parallel tests cannot
+    // race on registration.
+    #[test]
+    fn callback_receives_parser_warnings_until_cleared() {
+        let _lenient = crate::test_fixtures::strict_parsing_lock();
+        let records: &'static Records = Box::leak(Box::default());
+        let user_data = records as *const Records as *mut c_void;
+        let pdf = damaged_pdf();
+        unsafe {
+            let code = oxidize_set_log_callback(Some(record), user_data, 9);
+            assert_eq!(code, ErrorCode::InvalidArgument as c_int);
+
+            let code = oxidize_set_log_callback(Some(record), user_data, OXIDIZE_LOG_WARN);
+            assert_eq!(code, ErrorCode::Success as c_int);
+            let mut count = 0;
+            let code = crate::parser::oxidize_get_page_count(pdf.as_ptr(), pdf.len(), &mut count);
+            assert_eq!(code, ErrorCode::Success as c_int);
+            assert_eq!(count, 1);
+
+            let warnings = recovery_warnings(records);
+            assert!(!warnings.is_empty(), "{:?}", records.lock().unwrap());
+            let (level, _, message) = &warnings[0];
+            assert_eq!(*level, OXIDIZE_LOG_WARN);
+            assert!(!message.starts_with(' '), "{message}");
+            assert!(records
+                .lock()
+                .unwrap()
+                .iter()
+                .all(|(level, _, _)| *level <= OXIDIZE_LOG_WARN));
+
+            assert_eq!(
+                oxidize_set_log_callback(None, std::ptr::null_mut(), 0),
+                ErrorCode::Success as c_int
+            );
+            records.lock().unwrap().clear();
+            crate::parser::oxidize_get_page_count(pdf.as_ptr(), pdf.len(), &mut count);
+            assert!(recovery_warnings(records).is_empty());
+        }
+    }
+}
@@ -0,0 +1,332 @@
+//! Memory-mapped PDF input for the path-based entry points.
+//!
+//! `oxidize_parsed_document_open_path` and the `oxidize_*_chunk_stream_open_path`
+//! functions take a file path instead of a caller-owned buffer, so the .NET
+//! side never marshals the PDF into a managed array. The file is mapped
+//! read-only (`mmap` with `MAP_PRIVATE` on Unix, `CreateFileMappingW` /
+//! `MapViewOfFile` on Windows) and pages are faulted in as the lenient
+//! parser touches them; on other targets it is read once into native memory.
+//! Either way the parse runs through the same [`crate::parser::open_lenient`]
+//! pipeline as the byte-buffer entry points.
+//!
+//! The length is taken from the file's metadata before mapping and checked
+//! again afterwards, so a file that shrinks in between is rejected. On Unix
+//! a mapped file must still not be truncated while a handle that reads it is
+//! alive: accessing the missing pages would raise `SIGBUS`. Windows refuses
+//! to truncate a file with a mapped view.
+
+use std::ffi::CStr;
+use std::fs::File;
+use std::io;
+use std::ops::Deref;
+use std::os::raw::{c_char, c_int};
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::error_detail::{ErrorCategory, LastError};
+use crate::{set_last_error, ErrorCode};
+
+#[cfg(unix)]
+mod imp {
+    use std::fs::File;
+    use std::io;
+    use std::os::unix::io::AsRawFd;
+    use std::ptr;
+
+    /// A read-only private mapping of a whole file.
+    pub(super) struct Mapping {
+        ptr: *mut libc::c_void,
+        len: usize,
+    }
+
+    // The mapping is read-only and owned; sharing `&[u8]` views is sound.
+    unsafe impl Send for Mapping {}
+    unsafe impl Sync for Mapping {}
+
+    impl Mapping {
+        pub(super) fn new(file: &File, len: usize) -> io::Result<Self> {
+            if len == 0 {
+                // `mmap` rejects empty lengths; an empty view needs no mapping.
+                return Ok(Mapping {
+                    ptr: ptr::NonNull::dangling().as_ptr(),
+                    len,
+                });
+            }
+            let ptr = unsafe {
+                libc::mmap(
+                    ptr::null_mut(),
+                    len,
+                    libc::PROT_READ,
+                    libc::MAP_PRIVATE,
+                    file.as_raw_fd(),
+                    0,
+                )
+            };
+            if ptr == libc::MAP_FAILED {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(Mapping { ptr, len })
+        }
+
+        pub(super) fn bytes(&self) -> &[u8] {
+            unsafe { std::slice::from_raw_parts(self.ptr as *const u8, self.len) }
+        }
+    }
+
+    impl Drop for Mapping {
+        fn drop(&mut self) {
+            if self.len != 0 {
+                unsafe { libc::munmap(self.ptr, self.len) };
+            }
+        }
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use std::fs::File;
+    use std::io;
+    use std::os::windows::io::AsRawHandle;
+    use std::ptr;
+
+    use windows_sys::Win32::Foundation::CloseHandle;
+    use windows_sys::Win32::System::Memory::{
+        CreateFileMappingW, MapViewOfFile, UnmapViewOfFile, FILE_MAP_READ,
+        MEMORY_MAPPED_VIEW_ADDRESS, PAGE_READONLY,
+    };
+
+    /// A read-only view of a whole file.
+    pub(super) struct Mapping {
+        view: MEMORY_MAPPED_VIEW_ADDRESS,
+        len: usize,
+    }
+
+    // The view is read-only and owned; sharing `&[u8]` views is sound.
+    unsafe impl Send for Mapping {}
+    unsafe impl Sync for Mapping {}
+
+    impl Mapping {
+        pub(super) fn new(file: &File, len: usize) -> io::Result<Self> {
+            if len == 0 {
+                // Empty files cannot be mapped; an empty view needs no mapping.
+                return Ok(Mapping {
+                    view: MEMORY_MAPPED_VIEW_ADDRESS {
+                        Value: ptr::NonNull::dangling().as_ptr(),
+                    },
+                    len,
+                });
+            }
+            // A zero maximum size maps the file at its current length; a
+            // view longer than that fails below.
+            let mapping = unsafe {
+                CreateFileMappingW(
+                    file.as_raw_handle() as _,
+                    ptr::null(),
+                    PAGE_READONLY,
+                    0,
+                    0,
+                    ptr::null(),
+                )
+            };
+            if mapping.is_null() {
+                return Err(io::Error::last_os_error());
+            }
+            let view = unsafe { MapViewOfFile(mapping, FILE_MAP_READ, 0, 0, len) };
+            // The view keeps the mapping object alive.
+            let error = view.Value.is_null().then(io::Error::last_os_error);
+            unsafe { CloseHandle(mapping) };
+            match error {
+                Some(error) => Err(error),
+                None => Ok(Mapping { view, len }),
+            }
+        }
+
+        pub(super) fn bytes(&self) -> &[u8] {
+            unsafe { std::slice::from_raw_parts(self.view.Value as *const u8, self.len) }
+        }
+    }
+
+    impl Drop for Mapping {
+        fn drop(&mut self) {
+            if self.len != 0 {
+                unsafe { UnmapViewOfFile(self.view) };
+            }
+        }
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+mod imp {
+    use std::fs::File;
+    use std::io::{self, Read};
+
+    /// Fallback without `mmap`: the file is read once into native memory.
+    pub(super) struct Mapping(Vec<u8>);
+
+    impl Mapping {
+        pub(super) fn new(mut file: &File, len: usize) -> io::Result<Self> {
+            let mut bytes = Vec::with_capacity(len);
+            file.read_to_end(&mut bytes)?;
+            Ok(Mapping(bytes))
+        }
+
+        pub(super) fn bytes(&self) -> &[u8] {
+            &self.0
+        }
+    }
+}
+
+/// A PDF file mapped into memory.
+pub struct MappedFile(imp::Mapping);
+
+impl MappedFile {
+    fn open(path: &Path) -> io::Result<Self> {
+        let file = File::open(path)?;
+        let len = usize::try_from(file.metadata()?.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "file too large to map"))?;
+        Self::map(&file, len)
+    }
+
+    /// Map the first `len` bytes of `file`, the length its metadata reported.
+    /// Fails if the file is shorter than that once mapped: reading the
+    /// missing tail through the mapping would fault.
+    fn map(file: &File, len: usize) -> io::Result<Self> {
+        let mapping = imp::Mapping::new(file, len)?;
+        if file.metadata()?.len() < len as u64 {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "file shrank while it was being mapped",
+            ));
+        }
+        Ok(MappedFile(mapping))
+    }
+}
+
+/// Bytes a long-lived document reads from: a copied buffer or a mapped file.
+///
+/// Cheap to clone; both variants share the underlying storage.
+#[derive(Clone)]
+pub enum SharedBytes {
+    Owned(Arc<[u8]>),
+    Mapped(Arc<MappedFile>),
+}
+
+impl Deref for SharedBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            SharedBytes::Owned(bytes) => bytes,
+            SharedBytes::Mapped(file) => file.0.bytes(),
+        }
+    }
+}
+
+impl AsRef<[u8]> for SharedBytes {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+impl From<&[u8]> for SharedBytes {
+    fn from(bytes: &[u8]) -> Self {
+        SharedBytes::Owned(Arc::from(bytes))
+    }
+}
+
+/// Map the file at `path`. An empty file is a parse error, as an empty
+/// buffer is for the byte-based entry points.
+pub(crate) fn map_path(path: &Path) -> Result<SharedBytes, (ErrorCode, LastError)> {
+    let file = MappedFile::open(path).map_err(|e| {
+        (
+            ErrorCode::IoError,
+            LastError::caused_by(format_args!("Failed to open {}", path.display()), &e),
+        )
+    })?;
+    if file.0.bytes().is_empty() {
+        return Err((
+            ErrorCode::PdfParseError,
+            LastError::new(ErrorCategory::EmptyFile, "PDF file is empty (0 bytes)"),
+        ));
+    }
+    Ok(SharedBytes::Mapped(Arc::new(file)))
+}
+
+/// Read a NUL-terminated UTF-8 path argument.
+pub(crate) unsafe fn path_arg<'a>(path: *const c_char) -> Result<&'a Path, c_int> {
+    match CStr::from_ptr(path).to_str() {
+        Ok(p) => Ok(Path::new(p)),
+        Err(_) => {
+            set_last_error("Invalid UTF-8 in path");
+            Err(ErrorCode::InvalidUtf8 as c_int)
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    /// A file in the temp directory, removed on drop.
+    pub(crate) struct TempPdf(pub(crate) PathBuf);
+
+    impl TempPdf {
+        pub(crate) fn new(name: &str, bytes: &[u8]) -> Self {
+            let path =
+                std::env::temp_dir().join(format!("oxidize-ffi-{}-{name}.pdf", std::process::id()));
+            std::fs::write(&path, bytes).unwrap();
+            TempPdf(path)
+        }
+
+        pub(crate) fn c_path(&self) -> std::ffi::CString {
+            std::ffi::CString::new(self.0.to_str().unwrap()).unwrap()
+        }
+    }
+
+    impl Drop for TempPdf {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    #[test]
+    fn mapped_bytes_match_file_contents() {
+        let file = TempPdf::new("mapped-bytes", b"%PDF-1.4 mapped");
+        let bytes = map_path(&file.0).ok().unwrap();
+        assert_eq!(&bytes[..], b"%PDF-1.4 mapped");
+        let clone = bytes.clone();
+        drop(bytes);
+        assert_eq!(clone.as_ref(), b"%PDF-1.4 mapped");
+    }
+
+    #[test]
+    fn missing_and_empty_files_are_rejected() {
+        let missing = std::env::temp_dir().join("oxidize-ffi-definitely-missing.pdf");
+        let (code, _) = map_path(&missing).err().unwrap();
+        assert_eq!(code as c_int, ErrorCode::IoError as c_int);
+
+        let empty = TempPdf::new("mapped-empty", b"");
+        let (code, err) = map_path(&empty.0).err().unwrap();
+        assert_eq!(code as c_int, ErrorCode::PdfParseError as c_int);
+        assert_eq!(err.message, "PDF file is empty (0 bytes)");
+    }
+
+    #[test]
+    fn file_shrunk_between_stat_and_map_is_rejected() {
+        let temp = TempPdf::new("mapped-shrunk", &[b'%'; 64]);
+        let file = File::open(&temp.0).unwrap();
+        let len = file.metadata().unwrap().len() as usize;
+        std::fs::OpenOptions::new()
+            .write(true)
+            .open(&temp.0)
+            .unwrap()
+            .set_len(8)
+            .unwrap();
+        assert!(MappedFile::map(&file, len).is_err());
+        assert_eq!(
+            MappedFile::map(&file, 8).ok().unwrap().0.bytes(),
+            b"%%%%%%%%"
+        );
+    }
+}
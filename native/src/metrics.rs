@@ -0,0 +1,637 @@
+//! Per-call performance metrics.
+//!
+//! The `oxidize_*_with_metrics` entry points behave exactly like their plain
+//! counterparts and additionally return a metrics JSON object:
+//!
+//! ```json
+//! {"total_ms": 41.7,
+//!  "phases": [{"name": "parse", "ms": 1.2}, {"name": "text_extraction", "ms": 18.1}, {"name": "table_detection", "ms": 2.4},
+//!             {"name": "layout", "ms": 19.2}, {"name": "serialize", "ms": 0.8}],
+//!  "object_count": 214, "page_count": 12, "peak_alloc_bytes": null}
+//! ```
+//!
+//! - `parse` opens the document (header, xref, trailer, resource-limit
+//!   pre-flight, page tree).
+//! - `text_extraction` extracts the page text. Content streams are decoded
+//!   here, as part of the real extraction.
+//! - `table_detection` extracts the ruling lines ruled tables are detected
+//!   from. From the first page with a ruled grid on, the core library
+//!   partitions the rest of the document in one call (see
+//!   [`PagePartitioner`]); that call is reported here too.
+//! - `layout` orders and classifies the text, including the spatial table
+//!   pass, which the core library runs as part of classification.
+//! - `chunking` groups extracted text or elements into chunks.
+//! - `serialize` builds the output string.
+//!
+//! `object_count` is the trailer `/Size` (`null` when absent).
+//! `peak_alloc_bytes` is the peak of native heap memory allocated on the
+//! calling thread during the call, above what was live when it started. It
+//! is only measured when the crate is built with the `alloc-metrics`
+//! feature, which installs a counting global allocator for the whole
+//! process; in default builds it is always `null`.
+//!
+//! Metrics are only returned on success.
+
+#[cfg(feature = "alloc-metrics")]
+use std::alloc::{GlobalAlloc, Layout, System};
+#[cfg(feature = "alloc-metrics")]
+use std::cell::Cell;
+use std::ffi::CString;
+use std::io::Cursor;
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+use std::slice;
+use std::time::{Duration, Instant};
+
+use oxidize_pdf::parser::PdfDocument;
+use oxidize_pdf::pipeline::{Element, HybridChunkConfig, HybridChunker, PartitionConfig, RagChunk};
+use serde::Serialize;
+
+use crate::chunk_stream::PagePartitioner;
+use crate::chunking::{chunk_page_texts, write_chunk_dtos};
+use crate::error_detail::LastError;
+use crate::parser::{
+    element_results, open_lenient, write_element_results, write_joined_text,
+    write_rag_chunk_results, RagChunkResult,
+};
+use crate::progress::{extract_pages, partition_pages_with, Progress};
+use crate::{clear_last_error, set_last_error, ErrorCode};
+
+type Document<'a> = PdfDocument<Cursor<&'a [u8]>>;
+
+// ── Allocation tracking ───────────────────────────────────────────────────────
+
+#[cfg(feature = "alloc-metrics")]
+thread_local! {
+    /// Bytes allocated minus bytes freed on this thread.
+    static LIVE: Cell<i64> = const { Cell::new(0) };
+    /// Highest `LIVE` since the last [`start_peak`].
+    static PEAK: Cell<i64> = const { Cell::new(0) };
+}
+
+/// The system allocator, counting live bytes per thread for
+/// `peak_alloc_bytes`.
+#[cfg(feature = "alloc-metrics")]
+struct CountingAllocator;
+
+#[cfg(feature = "alloc-metrics")]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[cfg(feature = "alloc-metrics")]
+fn track(delta: i64) {
+    // `try_with`: allocations during thread teardown are not tracked.
+    let _ = LIVE.try_with(|live| {
+        let now = live.get() + delta;
+        live.set(now);
+        let _ = PEAK.try_with(|peak| peak.set(peak.get().max(now)));
+    });
+}
+
+#[cfg(feature = "alloc-metrics")]
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let p = System.alloc(layout);
+        if !p.is_null() {
+            track(layout.size() as i64);
+        }
+        p
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let p = System.alloc_zeroed(layout);
+        if !p.is_null() {
+            track(layout.size() as i64);
+        }
+        p
+    }
+
+    unsafe fn dealloc(&self, p: *mut u8, layout: Layout) {
+        System.dealloc(p, layout);
+        track(-(layout.size() as i64));
+    }
+
+    unsafe fn realloc(&self, p: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let q = System.realloc(p, layout, new_size);
+        if !q.is_null() {
+            track(new_size as i64 - layout.size() as i64);
+        }
+        q
+    }
+}
+
+/// Reset this thread's peak to the bytes live now and return them; `None`
+/// without the `alloc-metrics` feature.
+#[cfg(feature = "alloc-metrics")]
+fn start_peak() -> Option<i64> {
+    let live = LIVE.with(Cell::get);
+    PEAK.with(|peak| peak.set(live));
+    Some(live)
+}
+
+#[cfg(not(feature = "alloc-metrics"))]
+fn start_peak() -> Option<i64> {
+    None
+}
+
+/// Peak bytes allocated on this thread above `live_at_start`.
+#[cfg(feature = "alloc-metrics")]
+fn peak_since(live_at_start: Option<i64>) -> Option<u64> {
+    live_at_start.map(|start| (PEAK.with(Cell::get) - start).max(0) as u64)
+}
+
+#[cfg(not(feature = "alloc-metrics"))]
+fn peak_since(_live_at_start: Option<i64>) -> Option<u64> {
+    None
+}
+
+// ── Recording ─────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Serialize)]
+struct PhaseTiming {
+    name: &'static str,
+    ms: f64,
+}
+
+#[derive(Debug, Serialize)]
+struct CallMetrics {
+    total_ms: f64,
+    phases: Vec<PhaseTiming>,
+    object_count: Option<u32>,
+    page_count: u32,
+    peak_alloc_bytes: Option<u64>,
+}
+
+/// Collects the metrics of one call.
+struct Recorder {
+    started: Instant,
+    live_at_start: Option<i64>,
+    metrics: CallMetrics,
+}
+
+fn ms(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+impl Recorder {
+    fn start() -> Self {
+        Recorder {
+            started: Instant::now(),
+            live_at_start: start_peak(),
+            metrics: CallMetrics {
+                total_ms: 0.0,
+                phases: Vec::new(),
+                object_count: None,
+                page_count: 0,
+                peak_alloc_bytes: None,
+            },
+        }
+    }
+
+    fn record(&mut self, name: &'static str, duration: Duration) {
+        self.metrics.phases.push(PhaseTiming {
+            name,
+            ms: ms(duration),
+        });
+    }
+
+    fn phase<T>(&mut self, name: &'static str, f: impl FnOnce() -> T) -> T {
+        let started = Instant::now();
+        let value = f();
+        self.record(name, started.elapsed());
+        value
+    }
+
+    /// `parse` phase: open `bytes` as the plain entry points do and load the
+    /// page tree.
+    fn open<'a>(&mut self, bytes: &'a [u8]) -> Result<Document<'a>, c_int> {
+        let started = Instant::now();
+        let opened = open_lenient(bytes).map(|reader| {
+            let object_count = reader.trailer().size().ok();
+            let document = PdfDocument::new(reader);
+            let page_count = document.page_count().unwrap_or(0);
+            (document, object_count, page_count)
+        });
+        self.record("parse", started.elapsed());
+        match opened {
+            Ok((document, object_count, page_count)) => {
+                self.metrics.object_count = object_count;
+                self.metrics.page_count = page_count;
+                Ok(document)
+            }
+            Err(e) => {
+                set_last_error(e);
+                Err(ErrorCode::PdfParseError as c_int)
+            }
+        }
+    }
+
+    /// `text_extraction`, `table_detection` and `layout` phases: partition
+    /// with the default config a page at a time, which gives the same
+    /// elements as `PdfDocument::partition`.
+    fn partition(&mut self, document: &Document) -> Result<Vec<Element>, c_int> {
+        let mut partitioner = PagePartitioner::new(PartitionConfig::default());
+        let elements = partition_pages_with(document, &mut partitioner, Progress::NONE);
+        let times = partitioner.times;
+        self.record("text_extraction", times.text);
+        self.record("table_detection", times.tables);
+        self.record("layout", times.layout);
+        elements
+    }
+
+    /// Write the metrics JSON into `*out_metrics` once the call succeeded.
+    unsafe fn finish(mut self, code: c_int, out_metrics: *mut *mut c_char) -> c_int {
+        if code != ErrorCode::Success as c_int {
+            return code;
+        }
+        self.metrics.total_ms = ms(self.started.elapsed());
+        self.metrics.peak_alloc_bytes = peak_since(self.live_at_start);
+
+        let json = match serde_json::to_string(&self.metrics) {
+            Ok(j) => j,
+            Err(e) => {
+                set_last_error(LastError::caused_by("Failed to serialize metrics", &e));
+                return ErrorCode::SerializationError as c_int;
+            }
+        };
+        match CString::new(json) {
+            Ok(s) => {
+                *out_metrics = s.into_raw();
+                ErrorCode::Success as c_int
+            }
+            Err(e) => {
+                set_last_error(LastError::caused_by("JSON contains null bytes", &e));
+                ErrorCode::InvalidUtf8 as c_int
+            }
+        }
+    }
+}
+
+/// Shared prologue: validate pointers, null the outputs, check the length.
+unsafe fn input<'a>(
+    fn_name: &str,
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    out: *mut *mut c_char,
+    out_metrics: *mut *mut c_char,
+) -> Result<&'a [u8], c_int> {
+    if pdf_bytes.is_null() || out.is_null() || out_metrics.is_null() {
+        set_last_error(format!("Null pointer provided to {fn_name}"));
+        return Err(ErrorCode::NullPointer as c_int);
+    }
+    *out = ptr::null_mut();
+    *out_metrics = ptr::null_mut();
+
+    if pdf_len == 0 {
+        set_last_error("PDF data is empty (0 bytes)");
+        return Err(ErrorCode::PdfParseError as c_int);
+    }
+    Ok(slice::from_raw_parts(pdf_bytes, pdf_len))
+}
+
+/// Free `*out` again when the metrics could not be written, so a failed call
+/// returns no output.
+unsafe fn discard_on_failure(code: c_int, out: *mut *mut c_char) -> c_int {
+    if code != ErrorCode::Success as c_int && !(*out).is_null() {
+        drop(CString::from_raw(*out));
+        *out = ptr::null_mut();
+    }
+    code
+}
+
+// ── Entry points ──────────────────────────────────────────────────────────────
+
+/// [`crate::parser::oxidize_extract_text`] plus per-call metrics (see the
+/// module docs). Phases: `parse`, `text_extraction`, `serialize`.
+///
+/// # Safety
+/// - `pdf_bytes` must be a valid pointer to `pdf_len` bytes.
+/// - `out_text` and `out_metrics` will be allocated and must be freed with
+///   `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_extract_text_with_metrics(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    out_text: *mut *mut c_char,
+    out_metrics: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        let bytes = match input(
+            "oxidize_extract_text_with_metrics",
+            pdf_bytes,
+            pdf_len,
+            out_text,
+            out_metrics,
+        ) {
+            Ok(b) => b,
+            Err(code) => return code,
+        };
+
+        let mut recorder = Recorder::start();
+        let document = match recorder.open(bytes) {
+            Ok(d) => d,
+            Err(code) => return code,
+        };
+        let pages = match recorder.phase("text_extraction", || {
+            extract_pages(&document, Default::default(), Progress::NONE)
+        }) {
+            Ok(p) => p,
+            Err(code) => return code,
+        };
+        let code = recorder.phase("serialize", || write_joined_text(&pages, out_text));
+        discard_on_failure(recorder.finish(code, out_metrics), out_text)
+    })
+}
+
+/// [`crate::parser::oxidize_partition`] plus per-call metrics (see the module
+/// docs). Phases: `parse`, `text_extraction`, `table_detection`, `layout`,
+/// `serialize`.
+///
+/// # Safety
+/// - `pdf_bytes` must be a valid pointer to `pdf_len` bytes.
+/// - `out_json` and `out_metrics` will be allocated and must be freed with
+///   `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_partition_with_metrics(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    out_json: *mut *mut c_char,
+    out_metrics: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        let bytes = match input(
+            "oxidize_partition_with_metrics",
+            pdf_bytes,
+            pdf_len,
+            out_json,
+            out_metrics,
+        ) {
+            Ok(b) => b,
+            Err(code) => return code,
+        };
+
+        let mut recorder = Recorder::start();
+        let document = match recorder.open(bytes) {
+            Ok(d) => d,
+            Err(code) => return code,
+        };
+        let elements = match recorder.partition(&document) {
+            Ok(e) => e,
+            Err(code) => return code,
+        };
+        let code = recorder.phase("serialize", || {
+            write_element_results(&element_results(&elements), out_json)
+        });
+        discard_on_failure(recorder.finish(code, out_metrics), out_json)
+    })
+}
+
+/// [`crate::parser::oxidize_rag_chunks`] plus per-call metrics (see the
+/// module docs). Phases: `parse`, `text_extraction`, `table_detection`,
+/// `layout`, `chunking`, `serialize`.
+///
+/// # Safety
+/// - `pdf_bytes` must be a valid pointer to `pdf_len` bytes.
+/// - `out_json` and `out_metrics` will be allocated and must be freed with
+///   `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_rag_chunks_with_metrics(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    out_json: *mut *mut c_char,
+    out_metrics: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        let bytes = match input(
+            "oxidize_rag_chunks_with_metrics",
+            pdf_bytes,
+            pdf_len,
+            out_json,
+            out_metrics,
+        ) {
+            Ok(b) => b,
+            Err(code) => return code,
+        };
+
+        let mut recorder = Recorder::start();
+        let document = match recorder.open(bytes) {
+            Ok(d) => d,
+            Err(code) => return code,
+        };
+        let elements = match recorder.partition(&document) {
+            Ok(e) => e,
+            Err(code) => return code,
+        };
+        let results: Vec<RagChunkResult> = recorder.phase("chunking", || {
+            HybridChunker::new(HybridChunkConfig::default())
+                .chunk(&elements)
+                .iter()
+                .enumerate()
+                .map(|(i, hc)| RagChunkResult::from_core(i, &RagChunk::from_hybrid_chunk(i, hc)))
+                .collect()
+        });
+        let code = recorder.phase("serialize", || write_rag_chunk_results(&results, out_json));
+        discard_on_failure(recorder.finish(code, out_metrics), out_json)
+    })
+}
+
+/// [`crate::chunking::oxidize_chunk_pdf`] plus per-call metrics (see the
+/// module docs). Phases: `parse`, `text_extraction`, `chunking`, `serialize`.
+///
+/// # Safety
+/// - `pdf_bytes` must be a valid pointer to `pdf_len` bytes.
+/// - `out_json` and `out_metrics` will be allocated and must be freed with
+///   `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_chunk_pdf_with_metrics(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    chunk_size: usize,
+    overlap: usize,
+    detect_language: u8,
+    out_json: *mut *mut c_char,
+    out_metrics: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        let bytes = match input(
+            "oxidize_chunk_pdf_with_metrics",
+            pdf_bytes,
+            pdf_len,
+            out_json,
+            out_metrics,
+        ) {
+            Ok(b) => b,
+            Err(code) => return code,
+        };
+
+        let mut recorder = Recorder::start();
+        let document = match recorder.open(bytes) {
+            Ok(d) => d,
+            Err(code) => return code,
+        };
+        let pages = match recorder.phase("text_extraction", || {
+            extract_pages(&document, Default::default(), Progress::NONE)
+        }) {
+            Ok(p) => p,
+            Err(code) => return code,
+        };
+        let chunks = match recorder.phase("chunking", || {
+            chunk_page_texts(&pages, chunk_size, overlap, detect_language)
+        }) {
+            Ok(c) => c,
+            Err(code) => return code,
+        };
+        let code = recorder.phase("serialize", || write_chunk_dtos(&chunks, out_json));
+        discard_on_failure(recorder.finish(code, out_metrics), out_json)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::SamplePdf;
+    use std::ffi::CStr;
+
+    unsafe fn take(p: *mut c_char) -> String {
+        assert!(!p.is_null());
+        let s = CStr::from_ptr(p).to_str().unwrap().to_owned();
+        crate::oxidize_free_string(p);
+        s
+    }
+
+    fn phase_names(metrics: &serde_json::Value) -> Vec<&str> {
+        metrics["phases"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|p| p["name"].as_str().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn metrics_variants_match_plain_output_and_report_phases() {
+        let pdf = SamplePdf::new(3).lines(5).build();
+        unsafe {
+            let mut plain = ptr::null_mut();
+            assert_eq!(
+                crate::parser::oxidize_partition(pdf.as_ptr(), pdf.len(), &mut plain),
+                ErrorCode::Success as c_int
+            );
+            let plain = take(plain);
+
+            let (mut out, mut metrics) = (ptr::null_mut(), ptr::null_mut());
+            let code =
+                oxidize_partition_with_metrics(pdf.as_ptr(), pdf.len(), &mut out, &mut metrics);
+            assert_eq!(code, ErrorCode::Success as c_int);
+            assert_eq!(take(out), plain);
+
+            let metrics: serde_json::Value = serde_json::from_str(&take(metrics)).unwrap();
+            assert_eq!(
+                phase_names(&metrics),
+                [
+                    "parse",
+                    "text_extraction",
+                    "table_detection",
+                    "layout",
+                    "serialize"
+                ]
+            );
+            let total = metrics["total_ms"].as_f64().unwrap();
+            let phases: f64 = metrics["phases"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|p| p["ms"].as_f64().unwrap())
+                .sum();
+            assert!(phases <= total + 1e-6, "{phases} > {total}");
+            assert_eq!(metrics["page_count"], 3);
+            assert!(metrics["object_count"].as_u64().unwrap() > 3);
+            assert!(metrics.get("bytes_decompressed").is_none());
+            if cfg!(feature = "alloc-metrics") {
+                assert!(metrics["peak_alloc_bytes"].as_u64().unwrap() > 0);
+            } else {
+                assert!(metrics["peak_alloc_bytes"].is_null());
+            }
+
+            let (mut out, mut metrics) = (ptr::null_mut(), ptr::null_mut());
+            let code = oxidize_chunk_pdf_with_metrics(
+                pdf.as_ptr(),
+                pdf.len(),
+                64,
+                8,
+                0,
+                &mut out,
+                &mut metrics,
+            );
+            assert_eq!(code, ErrorCode::Success as c_int);
+            assert!(take(out).contains("Page 3 line 5 carries"));
+            let metrics: serde_json::Value = serde_json::from_str(&take(metrics)).unwrap();
+            assert_eq!(
+                phase_names(&metrics),
+                ["parse", "text_extraction", "chunking", "serialize"]
+            );
+        }
+    }
+
+    #[test]
+    fn staged_partition_matches_plain_output_on_ruled_tables() {
+        let pdf = SamplePdf::new(3).ruled_table_on(1).build();
+        type Plain = unsafe extern "C" fn(*const u8, usize, *mut *mut c_char) -> c_int;
+        type WithMetrics =
+            unsafe extern "C" fn(*const u8, usize, *mut *mut c_char, *mut *mut c_char) -> c_int;
+        let pairs: [(Plain, WithMetrics); 2] = [
+            (
+                crate::parser::oxidize_partition,
+                oxidize_partition_with_metrics,
+            ),
+            (
+                crate::parser::oxidize_rag_chunks,
+                oxidize_rag_chunks_with_metrics,
+            ),
+        ];
+        unsafe {
+            for (plain_fn, metrics_fn) in pairs {
+                let mut plain = ptr::null_mut();
+                assert_eq!(
+                    plain_fn(pdf.as_ptr(), pdf.len(), &mut plain),
+                    ErrorCode::Success as c_int
+                );
+                let (mut out, mut metrics) = (ptr::null_mut(), ptr::null_mut());
+                assert_eq!(
+                    metrics_fn(pdf.as_ptr(), pdf.len(), &mut out, &mut metrics),
+                    ErrorCode::Success as c_int
+                );
+                assert_eq!(take(out), take(plain));
+                take(metrics);
+            }
+        }
+    }
+
+    #[test]
+    fn failures_return_no_output_or_metrics() {
+        let garbage = b"not a pdf at all";
+        unsafe {
+            let (mut out, mut metrics) = (ptr::null_mut(), ptr::null_mut());
+            let code = oxidize_extract_text_with_metrics(
+                garbage.as_ptr(),
+                garbage.len(),
+                &mut out,
+                &mut metrics,
+            );
+            assert_eq!(code, ErrorCode::PdfParseError as c_int);
+            assert!(out.is_null() && metrics.is_null());
+
+            let code = oxidize_rag_chunks_with_metrics(
+                garbage.as_ptr(),
+                garbage.len(),
+                &mut out,
+                ptr::null_mut(),
+            );
+            assert_eq!(code, ErrorCode::NullPointer as c_int);
+        }
+    }
+}
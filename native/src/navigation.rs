@@ -0,0 +1,1171 @@
+//! Reading document navigation from parsed PDFs: the outline (bookmark)
+//! tree, the destinations it points at, and the catalog's open action,
+//! viewer preferences, named destinations and page labels.
+//!
+//! `PdfDocument` does not expose the catalog, so the bytes entry points read
+//! it from the reader before wrapping it ([`open_with_catalog`]) and parsed
+//! handles keep the copy taken when they were opened.
+//!
+//! Destinations resolve the way viewers do: explicit `[page /Fit ...]`
+//! arrays, names looked up in the catalog `/Dests` dictionary or the
+//! `/Names /Dests` name tree, and dictionaries carrying the array under `/D`.
+//! The page slot may be a page object reference or, as this library's writer
+//! emits, a 0-based page index.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::ffi::CString;
+use std::io::{Cursor, Read, Seek};
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+use std::slice;
+
+use oxidize_pdf::parser::{PdfArray, PdfDictionary, PdfDocument, PdfObject, PdfString};
+use oxidize_pdf::{PageLabel, PageLabelStyle, PageLabelTree};
+use serde::Serialize;
+
+use crate::error_detail::{ErrorCategory, LastError};
+use crate::limits;
+use crate::parser::open_lenient;
+use crate::{clear_last_error, set_last_error, ErrorCode};
+
+/// Outline nesting deeper than this is not descended into.
+const MAX_OUTLINE_DEPTH: usize = 64;
+/// Name-tree nesting deeper than this is not descended into.
+const MAX_NAME_TREE_DEPTH: usize = 32;
+/// Named destinations resolving to other names are followed this many times.
+const MAX_NAME_INDIRECTION: usize = 4;
+
+// ── Catalog ──────────────────────────────────────────────────────────────────
+
+/// A document opened over borrowed bytes, with its catalog.
+pub(crate) type OpenedDocument<'a> = (PdfDocument<Cursor<&'a [u8]>>, Option<PdfDictionary>);
+
+/// Open `bytes` leniently and return the document with a copy of its
+/// catalog (`None` when the reader cannot locate one).
+pub(crate) fn open_with_catalog(bytes: &[u8]) -> Result<OpenedDocument<'_>, LastError> {
+    let mut reader = open_lenient(bytes)?;
+    let catalog = reader.catalog().ok().cloned();
+    Ok((PdfDocument::new(reader), catalog))
+}
+
+/// The catalog, or a `PdfParseError`-class error naming `what` needed it.
+pub(crate) fn require_catalog<'a>(
+    catalog: Option<&'a PdfDictionary>,
+    what: &str,
+) -> Result<&'a PdfDictionary, LastError> {
+    catalog.ok_or_else(|| {
+        LastError::new(
+            ErrorCategory::Syntax,
+            format!("Cannot read {what}: the document catalog could not be located"),
+        )
+    })
+}
+
+/// Decode a PDF text string: UTF-16BE or UTF-8 when it carries the byte
+/// order mark, else UTF-8 when the bytes are valid UTF-8 (this library's
+/// writer emits unmarked UTF-8), else PDFDocEncoding.
+pub(crate) fn text_string(s: &PdfString) -> String {
+    let bytes = s.as_bytes();
+    if let Some(rest) = bytes.strip_prefix(&[0xFE, 0xFF]) {
+        let units: Vec<u16> = rest
+            .chunks_exact(2)
+            .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+            .collect();
+        return String::from_utf16_lossy(&units);
+    }
+    if let Some(rest) = bytes.strip_prefix(&[0xEF, 0xBB, 0xBF]) {
+        return String::from_utf8_lossy(rest).into_owned();
+    }
+    if let Ok(utf8) = std::str::from_utf8(bytes) {
+        return utf8.to_owned();
+    }
+    bytes.iter().map(|&b| pdf_doc_char(b)).collect()
+}
+
+/// PDFDocEncoding 0x18–0x1F (spacing diacritics).
+const PDF_DOC_18: [char; 8] = ['˘', 'ˇ', 'ˆ', '˙', '˝', '˛', '˚', '˜'];
+
+/// PDFDocEncoding 0x80–0xA0; 0x9F is undefined.
+const PDF_DOC_80: [char; 33] = [
+    '•', '†', '‡', '…', '—', '–', 'ƒ', '⁄', '‹', '›', '−', '‰', '„', '“', '”', '‘', '’', '‚', '™',
+    'ﬁ', 'ﬂ', 'Ł', 'Œ', 'Š', 'Ÿ', 'Ž', 'ı', 'ł', 'œ', 'š', 'ž', '\u{FFFD}', '€',
+];
+
+/// One PDFDocEncoding byte (PDF 2.0 Annex D). Bytes outside the two ranges
+/// that differ from Latin-1 map as Latin-1, except the undefined 0x7F and
+/// 0xAD.
+fn pdf_doc_char(b: u8) -> char {
+    match b {
+        0x18..=0x1F => PDF_DOC_18[(b - 0x18) as usize],
+        0x80..=0xA0 => PDF_DOC_80[(b - 0x80) as usize],
+        0x7F | 0xAD => char::REPLACEMENT_CHARACTER,
+        _ => b as char,
+    }
+}
+
+// ── Destinations ─────────────────────────────────────────────────────────────
+
+/// A resolved destination. `fit` uses the codes of the write-side
+/// destination payload (0=XYZ, 1=Fit, 2=FitH, 3=FitV, 5=FitB) plus
+/// 4=FitR, 6=FitBH, 7=FitBV; parameters the mode does not take are `null`.
+#[derive(Debug, Clone, Serialize)]
+pub struct DestinationResult {
+    /// 0-based target page; `null` when the page reference does not resolve.
+    pub page: Option<u32>,
+    pub fit: u8,
+    pub left: Option<f64>,
+    pub top: Option<f64>,
+    pub zoom: Option<f64>,
+    pub right: Option<f64>,
+    pub bottom: Option<f64>,
+}
+
+/// Resolves destinations and other navigation targets against one document.
+pub(crate) struct Navigator<'a, R: Read + Seek> {
+    document: &'a PdfDocument<R>,
+    catalog: &'a PdfDictionary,
+    pages: Option<HashMap<(u32, u16), u32>>,
+    names: Option<BTreeMap<Vec<u8>, PdfObject>>,
+}
+
+impl<'a, R: Read + Seek> Navigator<'a, R> {
+    pub(crate) fn new(document: &'a PdfDocument<R>, catalog: &'a PdfDictionary) -> Self {
+        Self {
+            document,
+            catalog,
+            pages: None,
+            names: None,
+        }
+    }
+
+    /// `obj` with a top-level reference followed; `None` when it dangles.
+    pub(crate) fn resolve(&self, obj: &PdfObject) -> Option<PdfObject> {
+        self.document.resolve(obj).ok()
+    }
+
+    /// The resolved dictionary under `key` of `dict`.
+    pub(crate) fn dict_entry(&self, dict: &PdfDictionary, key: &str) -> Option<PdfDictionary> {
+        match self.resolve(dict.get(key)?)? {
+            PdfObject::Dictionary(d) => Some(d),
+            PdfObject::Stream(s) => Some(s.dict),
+            _ => None,
+        }
+    }
+
+    /// 0-based index of the page `obj` designates: a page object reference,
+    /// or an integer page index.
+    pub(crate) fn page_index(&mut self, obj: &PdfObject) -> Result<Option<u32>, LastError> {
+        match obj {
+            PdfObject::Reference(num, gen) => Ok(self.page_map()?.get(&(*num, *gen)).copied()),
+            PdfObject::Integer(n) => {
+                let count = self.document.page_count().unwrap_or(0);
+                Ok(u32::try_from(*n).ok().filter(|&i| i < count))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Page object reference → 0-based index, built on first use.
+    fn page_map(&mut self) -> Result<&HashMap<(u32, u16), u32>, LastError> {
+        if self.pages.is_none() {
+            let mut map = HashMap::new();
+            let count = self.document.page_count().unwrap_or(0);
+            for index in 0..count {
+                limits::check_deadline()?;
+                // A page that fails to load is simply not a target.
+                if let Ok(page) = self.document.get_page(index) {
+                    map.entry(page.obj_ref).or_insert(index);
+                }
+            }
+            self.pages = Some(map);
+        }
+        Ok(self.pages.get_or_insert_with(HashMap::new))
+    }
+
+    /// Every named destination (catalog `/Dests` and the `/Names /Dests`
+    /// tree), keyed by raw name bytes. A name in both keeps the name-tree
+    /// entry, which PDF 1.2+ readers consult first.
+    pub(crate) fn named_destinations(&mut self) -> &BTreeMap<Vec<u8>, PdfObject> {
+        if self.names.is_none() {
+            let mut names = BTreeMap::new();
+            if let Some(dests) = self.dict_entry(self.catalog, "Dests") {
+                for (name, value) in dests.0 {
+                    names.insert(name.0.into_bytes(), value);
+                }
+            }
+            let tree = self
+                .dict_entry(self.catalog, "Names")
+                .and_then(|names| names.get("Dests").cloned());
+            if let Some(root) = tree {
+                let mut visited = HashSet::new();
+                self.collect_name_tree(&root, 0, &mut visited, &mut names);
+            }
+            self.names = Some(names);
+        }
+        self.names.get_or_insert_with(BTreeMap::new)
+    }
+
+    /// Add the leaves of the name-tree node `node` to `out`.
+    fn collect_name_tree(
+        &self,
+        node: &PdfObject,
+        depth: usize,
+        visited: &mut HashSet<(u32, u16)>,
+        out: &mut BTreeMap<Vec<u8>, PdfObject>,
+    ) {
+        if depth > MAX_NAME_TREE_DEPTH {
+            return;
+        }
+        if let Some(r) = node.as_reference() {
+            if !visited.insert(r) {
+                return;
+            }
+        }
+        let Some(PdfObject::Dictionary(dict)) = self.resolve(node) else {
+            return;
+        };
+        if let Some(PdfObject::Array(pairs)) = dict.get("Names").and_then(|n| self.resolve(n)) {
+            for pair in pairs.0.chunks_exact(2) {
+                let key = match self.resolve(&pair[0]) {
+                    Some(PdfObject::String(s)) => s.as_bytes().to_vec(),
+                    Some(PdfObject::Name(n)) => n.0.into_bytes(),
+                    _ => continue,
+                };
+                out.insert(key, pair[1].clone());
+            }
+        }
+        if let Some(PdfObject::Array(kids)) = dict.get("Kids").and_then(|k| self.resolve(k)) {
+            for kid in &kids.0 {
+                self.collect_name_tree(kid, depth + 1, visited, out);
+            }
+        }
+    }
+
+    /// Resolve a destination given explicitly, by name, or as a `/D`
+    /// dictionary. `None` when it is malformed or names nothing.
+    pub(crate) fn destination(
+        &mut self,
+        obj: &PdfObject,
+    ) -> Result<Option<DestinationResult>, LastError> {
+        let mut current = obj.clone();
+        for _ in 0..=MAX_NAME_INDIRECTION {
+            let Some(resolved) = self.resolve(&current) else {
+                return Ok(None);
+            };
+            current = match resolved {
+                PdfObject::Array(array) => return self.explicit_destination(&array),
+                PdfObject::Dictionary(dict) => match dict.get("D") {
+                    Some(d) => d.clone(),
+                    None => return Ok(None),
+                },
+                PdfObject::Name(name) => match self.named_destinations().get(name.0.as_bytes()) {
+                    Some(target) => target.clone(),
+                    None => return Ok(None),
+                },
+                PdfObject::String(name) => match self.named_destinations().get(name.as_bytes()) {
+                    Some(target) => target.clone(),
+                    None => return Ok(None),
+                },
+                _ => return Ok(None),
+            };
+        }
+        Ok(None)
+    }
+
+    /// Decode `[page /Mode params...]`.
+    fn explicit_destination(
+        &mut self,
+        array: &PdfArray,
+    ) -> Result<Option<DestinationResult>, LastError> {
+        let Some(first) = array.get(0) else {
+            return Ok(None);
+        };
+        let page = self.page_index(first)?;
+        let param = |i: usize| {
+            array
+                .get(i)
+                .and_then(|o| self.resolve(o))
+                .and_then(|o| o.as_real())
+        };
+        let mode = array
+            .get(1)
+            .and_then(|o| self.resolve(o))
+            .and_then(|o| o.as_name().map(|n| n.0.clone()))
+            .unwrap_or_default();
+        let mut dest = DestinationResult {
+            page,
+            fit: 1,
+            left: None,
+            top: None,
+            zoom: None,
+            right: None,
+            bottom: None,
+        };
+        match mode.as_str() {
+            "XYZ" => {
+                dest.fit = 0;
+                dest.left = param(2);
+                dest.top = param(3);
+                // 0 means "keep the current zoom", the same as null.
+                dest.zoom = param(4).filter(|&z| z != 0.0);
+            }
+            "FitH" => {
+                dest.fit = 2;
+                dest.top = param(2);
+            }
+            "FitV" => {
+                dest.fit = 3;
+                dest.left = param(2);
+            }
+            "FitR" => {
+                dest.fit = 4;
+                dest.left = param(2);
+                dest.bottom = param(3);
+                dest.right = param(4);
+                dest.top = param(5);
+            }
+            "FitB" => dest.fit = 5,
+            "FitBH" => {
+                dest.fit = 6;
+                dest.top = param(2);
+            }
+            "FitBV" => {
+                dest.fit = 7;
+                dest.left = param(2);
+            }
+            // `/Fit`, and an unknown or missing mode, fit the page.
+            _ => {}
+        }
+        Ok(Some(dest))
+    }
+
+    /// Summarize the action dictionary (or reference to one) `obj`.
+    pub(crate) fn action(&mut self, obj: &PdfObject) -> Result<Option<ActionResult>, LastError> {
+        let Some(PdfObject::Dictionary(dict)) = self.resolve(obj) else {
+            return Ok(None);
+        };
+        let Some(kind) = dict
+            .get("S")
+            .and_then(|s| self.resolve(s))
+            .and_then(|s| s.as_name().map(|n| n.0.clone()))
+        else {
+            return Ok(None);
+        };
+        let destination = match (kind.as_str(), dict.get("D")) {
+            ("GoTo", Some(d)) => self.destination(d)?,
+            _ => None,
+        };
+        let uri = match kind.as_str() {
+            "URI" => dict
+                .get("URI")
+                .and_then(|u| self.resolve(u))
+                .and_then(|u| u.as_string().map(text_string)),
+            _ => None,
+        };
+        Ok(Some(ActionResult {
+            kind,
+            destination,
+            uri,
+        }))
+    }
+}
+
+/// The parts of an action this module reports.
+pub(crate) struct ActionResult {
+    /// The action subtype (`GoTo`, `URI`, `Named`, `GoToR`, …).
+    pub(crate) kind: String,
+    /// The target of a `GoTo` action.
+    pub(crate) destination: Option<DestinationResult>,
+    /// The target of a `URI` action.
+    pub(crate) uri: Option<String>,
+}
+
+// ── Outline ──────────────────────────────────────────────────────────────────
+
+/// One outline item. The `title`, `page`, `bold`, `italic`, `open` and
+/// `children` fields are those `oxidize_document_set_outline` accepts.
+#[derive(Debug, Serialize)]
+pub struct OutlineEntry {
+    pub title: String,
+    /// 0-based target page; `null` when the item has no resolvable target.
+    pub page: Option<u32>,
+    pub destination: Option<DestinationResult>,
+    /// Subtype of the item's `/A` action (`"GoTo"`, `"URI"`, …); `null`
+    /// when it links through `/Dest` or has no target.
+    pub action: Option<String>,
+    pub uri: Option<String>,
+    pub bold: bool,
+    pub italic: bool,
+    /// Text colour as RGB components in 0..=1; `null` when unset.
+    pub color: Option<[f64; 3]>,
+    /// Whether the item's children are shown expanded.
+    pub open: bool,
+    pub children: Vec<OutlineEntry>,
+}
+
+#[derive(Serialize)]
+struct OutlineResult {
+    items: Vec<OutlineEntry>,
+}
+
+/// Read the outline tree. A document without one yields no items.
+pub(crate) fn read_outline<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    catalog: &PdfDictionary,
+) -> Result<Vec<OutlineEntry>, LastError> {
+    let mut nav = Navigator::new(document, catalog);
+    let Some(root) = nav.dict_entry(catalog, "Outlines") else {
+        return Ok(Vec::new());
+    };
+    let mut visited = HashSet::new();
+    outline_items(&mut nav, root.get("First"), 0, &mut visited)
+}
+
+/// The sibling chain starting at `first`. Revisited items end the chain, so
+/// cyclic `/Next` or `/First` links cannot loop.
+fn outline_items<R: Read + Seek>(
+    nav: &mut Navigator<'_, R>,
+    first: Option<&PdfObject>,
+    depth: usize,
+    visited: &mut HashSet<(u32, u16)>,
+) -> Result<Vec<OutlineEntry>, LastError> {
+    let mut items = Vec::new();
+    let mut next = first.cloned();
+    while let Some(obj) = next.take() {
+        limits::check_deadline()?;
+        if let Some(r) = obj.as_reference() {
+            if !visited.insert(r) {
+                break;
+            }
+        }
+        let Some(PdfObject::Dictionary(dict)) = nav.resolve(&obj) else {
+            break;
+        };
+        let children = if depth < MAX_OUTLINE_DEPTH {
+            outline_items(nav, dict.get("First"), depth + 1, visited)?
+        } else {
+            Vec::new()
+        };
+        items.push(outline_entry(nav, &dict, children)?);
+        next = dict.get("Next").cloned();
+    }
+    Ok(items)
+}
+
+fn outline_entry<R: Read + Seek>(
+    nav: &mut Navigator<'_, R>,
+    dict: &PdfDictionary,
+    children: Vec<OutlineEntry>,
+) -> Result<OutlineEntry, LastError> {
+    let title = dict
+        .get("Title")
+        .and_then(|t| nav.resolve(t))
+        .and_then(|t| t.as_string().map(text_string))
+        .unwrap_or_default();
+    let (destination, action, uri) = match (dict.get("Dest"), dict.get("A")) {
+        (Some(dest), _) => (nav.destination(dest)?, None, None),
+        (None, Some(action)) => match nav.action(action)? {
+            Some(a) => (a.destination, Some(a.kind), a.uri),
+            None => (None, None, None),
+        },
+        (None, None) => (None, None, None),
+    };
+    let flags = dict
+        .get("F")
+        .and_then(|f| nav.resolve(f))
+        .and_then(|f| f.as_integer())
+        .unwrap_or(0);
+    let color = match dict.get("C").and_then(|c| nav.resolve(c)) {
+        Some(PdfObject::Array(c)) if c.len() == 3 => {
+            let component = |i: usize| c.get(i).and_then(|v| v.as_real());
+            match (component(0), component(1), component(2)) {
+                (Some(r), Some(g), Some(b)) => Some([r, g, b]),
+                _ => None,
+            }
+        }
+        _ => None,
+    };
+    let count = dict
+        .get("Count")
+        .and_then(|c| nav.resolve(c))
+        .and_then(|c| c.as_integer())
+        .unwrap_or(0);
+    Ok(OutlineEntry {
+        title,
+        page: destination.as_ref().and_then(|d| d.page),
+        destination,
+        action,
+        uri,
+        bold: flags & 2 != 0,
+        italic: flags & 1 != 0,
+        color,
+        open: count > 0,
+        children,
+    })
+}
+
+// ── Document navigation metadata ─────────────────────────────────────────────
+
+/// The catalog's `/OpenAction`. `kind` is `"goto"` (a `GoTo` action or a bare
+/// destination) or `"uri"` as in `oxidize_document_set_open_action_json`,
+/// else the action subtype as written (`"JavaScript"`, `"Named"`, …).
+#[derive(Debug, Serialize)]
+pub struct OpenActionResult {
+    pub kind: String,
+    pub destination: Option<DestinationResult>,
+    pub uri: Option<String>,
+}
+
+/// Viewer preferences, with the integer codes of the
+/// `oxidize_document_set_viewer_preferences_json` payload. Unset entries are
+/// `null`.
+#[derive(Debug, Default, Serialize)]
+pub struct ViewerPreferencesResult {
+    pub hide_toolbar: Option<bool>,
+    pub hide_menubar: Option<bool>,
+    pub hide_window_ui: Option<bool>,
+    pub fit_window: Option<bool>,
+    pub center_window: Option<bool>,
+    pub display_doc_title: Option<bool>,
+    pub page_layout: Option<u8>,
+    pub page_mode: Option<u8>,
+    pub print_scaling: Option<u8>,
+    pub duplex: Option<u8>,
+    pub num_copies: Option<u32>,
+    pub pick_tray_by_pdf_size: Option<bool>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NamedDestinationResult {
+    pub name: String,
+    pub destination: DestinationResult,
+}
+
+/// One `/PageLabels` range, in the `oxidize_document_set_page_labels_json`
+/// shape.
+#[derive(Debug, Serialize)]
+pub struct PageLabelRangeResult {
+    pub start_page: u32,
+    pub style: u8,
+    pub prefix: Option<String>,
+    pub start_at: Option<u32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PageLabelsResult {
+    pub ranges: Vec<PageLabelRangeResult>,
+    /// The label of every page, in page order.
+    pub labels: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NavigationMetadata {
+    pub open_action: Option<OpenActionResult>,
+    pub viewer_preferences: ViewerPreferencesResult,
+    pub named_destinations: Vec<NamedDestinationResult>,
+    pub page_labels: Option<PageLabelsResult>,
+}
+
+/// Read the catalog's open action, viewer preferences, named destinations
+/// and page labels.
+pub(crate) fn read_navigation_metadata<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    catalog: &PdfDictionary,
+) -> Result<NavigationMetadata, LastError> {
+    let mut nav = Navigator::new(document, catalog);
+    let open_action = match catalog.get("OpenAction") {
+        Some(action) => open_action(&mut nav, action)?,
+        None => None,
+    };
+    let viewer_preferences = viewer_preferences(&nav, catalog);
+
+    let names: Vec<(Vec<u8>, PdfObject)> = nav
+        .named_destinations()
+        .iter()
+        .map(|(name, target)| (name.clone(), target.clone()))
+        .collect();
+    let mut named_destinations = Vec::with_capacity(names.len());
+    for (name, target) in names {
+        limits::check_deadline()?;
+        // Entries that do not decode to a destination are left out.
+        if let Some(destination) = nav.destination(&target)? {
+            named_destinations.push(NamedDestinationResult {
+                name: text_string(&PdfString::new(name)),
+                destination,
+            });
+        }
+    }
+
+    let page_labels = match catalog.get("PageLabels") {
+        Some(root) => Some(page_labels(&nav, root)?),
+        None => None,
+    };
+    Ok(NavigationMetadata {
+        open_action,
+        viewer_preferences,
+        named_destinations,
+        page_labels,
+    })
+}
+
+fn open_action<R: Read + Seek>(
+    nav: &mut Navigator<'_, R>,
+    obj: &PdfObject,
+) -> Result<Option<OpenActionResult>, LastError> {
+    if let Some(PdfObject::Array(_)) = nav.resolve(obj) {
+        return Ok(Some(OpenActionResult {
+            kind: "goto".to_string(),
+            destination: nav.destination(obj)?,
+            uri: None,
+        }));
+    }
+    Ok(nav.action(obj)?.map(|action| OpenActionResult {
+        kind: match action.kind.as_str() {
+            "GoTo" => "goto".to_string(),
+            "URI" => "uri".to_string(),
+            _ => action.kind,
+        },
+        destination: action.destination,
+        uri: action.uri,
+    }))
+}
+
+/// `/ViewerPreferences`, with the page layout and mode taken from the
+/// catalog (where the specification puts them) or, failing that, from the
+/// preferences dictionary (where this library's writer puts them).
+fn viewer_preferences<R: Read + Seek>(
+    nav: &Navigator<'_, R>,
+    catalog: &PdfDictionary,
+) -> ViewerPreferencesResult {
+    let prefs = nav
+        .dict_entry(catalog, "ViewerPreferences")
+        .unwrap_or_default();
+    let value = |key: &str| {
+        catalog
+            .get(key)
+            .filter(|_| matches!(key, "PageLayout" | "PageMode"))
+            .or_else(|| prefs.get(key))
+            .and_then(|v| nav.resolve(v))
+    };
+    let flag = |key: &str| value(key).and_then(|v| v.as_bool());
+    let name = |key: &str| value(key).and_then(|v| v.as_name().map(|n| n.0.clone()));
+    ViewerPreferencesResult {
+        hide_toolbar: flag("HideToolbar"),
+        hide_menubar: flag("HideMenubar"),
+        hide_window_ui: flag("HideWindowUI"),
+        fit_window: flag("FitWindow"),
+        center_window: flag("CenterWindow"),
+        display_doc_title: flag("DisplayDocTitle"),
+        page_layout: name("PageLayout").and_then(|n| match n.as_str() {
+            "SinglePage" => Some(0),
+            "OneColumn" => Some(1),
+            "TwoColumnLeft" => Some(2),
+            "TwoColumnRight" => Some(3),
+            "TwoPageLeft" => Some(4),
+            "TwoPageRight" => Some(5),
+            _ => None,
+        }),
+        page_mode: name("PageMode").and_then(|n| match n.as_str() {
+            "UseNone" => Some(0),
+            "UseOutlines" => Some(1),
+            "UseThumbs" => Some(2),
+            "FullScreen" => Some(3),
+            "UseOC" => Some(4),
+            "UseAttachments" => Some(5),
+            _ => None,
+        }),
+        print_scaling: name("PrintScaling").and_then(|n| match n.as_str() {
+            "AppDefault" => Some(0),
+            "None" => Some(1),
+            _ => None,
+        }),
+        duplex: name("Duplex").and_then(|n| match n.as_str() {
+            "Simplex" => Some(0),
+            "DuplexFlipShortEdge" => Some(1),
+            "DuplexFlipLongEdge" => Some(2),
+            _ => None,
+        }),
+        num_copies: value("NumCopies")
+            .and_then(|v| v.as_integer())
+            .and_then(|n| u32::try_from(n).ok()),
+        pick_tray_by_pdf_size: flag("PickTrayByPDFSize"),
+    }
+}
+
+/// Read the `/PageLabels` number tree and label every page with it.
+fn page_labels<R: Read + Seek>(
+    nav: &Navigator<'_, R>,
+    root: &PdfObject,
+) -> Result<PageLabelsResult, LastError> {
+    let mut entries = BTreeMap::new();
+    let mut visited = HashSet::new();
+    collect_number_tree(nav, root, 0, &mut visited, &mut entries);
+
+    let mut tree = PageLabelTree::new();
+    let mut ranges = Vec::with_capacity(entries.len());
+    for (start_page, dict) in entries {
+        let text = |key: &str| {
+            dict.get(key)
+                .and_then(|v| nav.resolve(v))
+                .and_then(|v| v.as_string().map(text_string))
+        };
+        let (style, core_style) = match dict
+            .get("S")
+            .and_then(|v| nav.resolve(v))
+            .and_then(|v| v.as_name().map(|n| n.0.clone()))
+            .as_deref()
+        {
+            Some("D") => (0, PageLabelStyle::DecimalArabic),
+            Some("r") => (1, PageLabelStyle::LowercaseRoman),
+            Some("R") => (2, PageLabelStyle::UppercaseRoman),
+            Some("a") => (3, PageLabelStyle::LowercaseLetters),
+            Some("A") => (4, PageLabelStyle::UppercaseLetters),
+            _ => (5, PageLabelStyle::None),
+        };
+        let prefix = text("P");
+        let start_at = dict
+            .get("St")
+            .and_then(|v| nav.resolve(v))
+            .and_then(|v| v.as_integer())
+            .and_then(|n| u32::try_from(n).ok())
+            .filter(|&n| n >= 1);
+
+        let mut label = PageLabel::new(core_style);
+        if let Some(prefix) = &prefix {
+            label = label.with_prefix(prefix.clone());
+        }
+        if let Some(start) = start_at {
+            label = label.starting_at(start);
+        }
+        tree.add_range(start_page, label);
+        ranges.push(PageLabelRangeResult {
+            start_page,
+            style,
+            prefix,
+            start_at,
+        });
+    }
+
+    let page_count = nav.document.page_count().unwrap_or(0);
+    limits::check_deadline()?;
+    Ok(PageLabelsResult {
+        ranges,
+        labels: tree.get_all_labels(page_count),
+    })
+}
+
+/// Add the `(page index, label dictionary)` leaves of the number-tree node
+/// `node` to `out`.
+fn collect_number_tree<R: Read + Seek>(
+    nav: &Navigator<'_, R>,
+    node: &PdfObject,
+    depth: usize,
+    visited: &mut HashSet<(u32, u16)>,
+    out: &mut BTreeMap<u32, PdfDictionary>,
+) {
+    if depth > MAX_NAME_TREE_DEPTH {
+        return;
+    }
+    if let Some(r) = node.as_reference() {
+        if !visited.insert(r) {
+            return;
+        }
+    }
+    let Some(PdfObject::Dictionary(dict)) = nav.resolve(node) else {
+        return;
+    };
+    if let Some(PdfObject::Array(pairs)) = dict.get("Nums").and_then(|n| nav.resolve(n)) {
+        for pair in pairs.0.chunks_exact(2) {
+            let Some(start) = nav
+                .resolve(&pair[0])
+                .and_then(|k| k.as_integer())
+                .and_then(|k| u32::try_from(k).ok())
+            else {
+                continue;
+            };
+            if let Some(PdfObject::Dictionary(label)) = nav.resolve(&pair[1]) {
+                out.insert(start, label);
+            }
+        }
+    }
+    if let Some(PdfObject::Array(kids)) = dict.get("Kids").and_then(|k| nav.resolve(k)) {
+        for kid in &kids.0 {
+            collect_number_tree(nav, kid, depth + 1, visited, out);
+        }
+    }
+}
+
+// ── FFI ──────────────────────────────────────────────────────────────────────
+
+/// Body of [`oxidize_get_outline`] over an already-opened document.
+pub(crate) unsafe fn get_outline_impl<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    catalog: Option<&PdfDictionary>,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    let items = match require_catalog(catalog, "the outline")
+        .and_then(|catalog| read_outline(document, catalog))
+    {
+        Ok(items) => items,
+        Err(e) => {
+            set_last_error(e);
+            return ErrorCode::PdfParseError as c_int;
+        }
+    };
+    let json = match serde_json::to_string(&OutlineResult { items }) {
+        Ok(j) => j,
+        Err(e) => {
+            set_last_error(LastError::caused_by("Failed to serialize outline", &e));
+            return ErrorCode::SerializationError as c_int;
+        }
+    };
+    match CString::new(json) {
+        Ok(s) => {
+            *out_json = s.into_raw();
+            ErrorCode::Success as c_int
+        }
+        Err(e) => {
+            set_last_error(LastError::caused_by("Outline JSON contains null bytes", &e));
+            ErrorCode::InvalidUtf8 as c_int
+        }
+    }
+}
+
+/// Read the document outline (bookmarks) as a JSON tree.
+///
+/// `*out_json` receives `{"items": [...]}`, a superset of the
+/// `oxidize_document_set_outline` input: each item has `title`, `page`
+/// (0-based target page, `null` when unresolvable), `bold`, `italic`, `open`
+/// (children shown expanded), `children`, plus `destination` (`{page, fit,
+/// left, top, zoom, right, bottom}`, `fit` 0=XYZ, 1=Fit, 2=FitH, 3=FitV,
+/// 4=FitR, 5=FitB, 6=FitBH, 7=FitBV), `action` (the `/A` subtype, e.g.
+/// `"GoTo"`, `"URI"`), `uri` and `color` (`[r, g, b]` in 0..=1 or `null`).
+/// Named destinations are resolved. A document without an outline yields
+/// no items.
+///
+/// # Returns
+/// `Success`; `NullPointer`; `PdfParseError` (`pdf_len == 0`, parse
+/// failure, no catalog); `LimitExceeded`; `SerializationError`.
+///
+/// # Safety
+/// - `pdf_bytes` must point to `pdf_len` readable bytes.
+/// - `out_json` must be a writeable `*mut *mut c_char`; free the result with
+///   `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_get_outline(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if pdf_bytes.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_get_outline");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+
+        if pdf_len == 0 {
+            set_last_error("PDF data is empty (0 bytes)");
+            return ErrorCode::PdfParseError as c_int;
+        }
+        let bytes = slice::from_raw_parts(pdf_bytes, pdf_len);
+        let (document, catalog) = match open_with_catalog(bytes) {
+            Ok(opened) => opened,
+            Err(e) => {
+                set_last_error(e);
+                return ErrorCode::PdfParseError as c_int;
+            }
+        };
+        get_outline_impl(&document, catalog.as_ref(), out_json)
+    })
+}
+
+/// Body of [`oxidize_get_navigation_metadata`] over an already-opened
+/// document.
+pub(crate) unsafe fn get_navigation_metadata_impl<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    catalog: Option<&PdfDictionary>,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    let metadata = match require_catalog(catalog, "navigation metadata")
+        .and_then(|catalog| read_navigation_metadata(document, catalog))
+    {
+        Ok(m) => m,
+        Err(e) => {
+            set_last_error(e);
+            return ErrorCode::PdfParseError as c_int;
+        }
+    };
+    let json = match serde_json::to_string(&metadata) {
+        Ok(j) => j,
+        Err(e) => {
+            set_last_error(LastError::caused_by(
+                "Failed to serialize navigation metadata",
+                &e,
+            ));
+            return ErrorCode::SerializationError as c_int;
+        }
+    };
+    match CString::new(json) {
+        Ok(s) => {
+            *out_json = s.into_raw();
+            ErrorCode::Success as c_int
+        }
+        Err(e) => {
+            set_last_error(LastError::caused_by(
+                "Navigation metadata JSON contains null bytes",
+                &e,
+            ));
+            ErrorCode::InvalidUtf8 as c_int
+        }
+    }
+}
+
+/// Read the document-level navigation entries of the catalog as JSON: the
+/// read counterpart of the `document_metadata` setters.
+///
+/// `*out_json` receives `{"open_action", "viewer_preferences",
+/// "named_destinations", "page_labels"}`:
+/// - `open_action`: `null`, or `{kind, destination, uri}` with `kind`
+///   `"goto"` / `"uri"` as `oxidize_document_set_open_action_json` takes
+///   them (other action subtypes keep their PDF name, e.g. `"JavaScript"`).
+/// - `viewer_preferences`: the `oxidize_document_set_viewer_preferences_json`
+///   fields and integer codes; unset entries are `null`.
+/// - `named_destinations`: `[{name, destination}]` sorted by name, from the
+///   catalog `/Dests` dictionary and the `/Names /Dests` tree.
+/// - `page_labels`: `null`, or `{ranges, labels}` where `ranges` uses the
+///   `oxidize_document_set_page_labels_json` shape and `labels` holds the
+///   resolved label of every page in order.
+///
+/// Destinations have the `{page, fit, left, top, zoom, right, bottom}` shape
+/// of [`oxidize_get_outline`], with 0-based pages.
+///
+/// # Returns
+/// `Success`; `NullPointer`; `PdfParseError` (`pdf_len == 0`, parse
+/// failure, no catalog); `LimitExceeded`; `SerializationError`.
+///
+/// # Safety
+/// - `pdf_bytes` must point to `pdf_len` readable bytes.
+/// - `out_json` must be a writeable `*mut *mut c_char`; free the result with
+///   `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_get_navigation_metadata(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if pdf_bytes.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_get_navigation_metadata");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+
+        if pdf_len == 0 {
+            set_last_error("PDF data is empty (0 bytes)");
+            return ErrorCode::PdfParseError as c_int;
+        }
+        let bytes = slice::from_raw_parts(pdf_bytes, pdf_len);
+        let (document, catalog) = match open_with_catalog(bytes) {
+            Ok(opened) => opened,
+            Err(e) => {
+                set_last_error(e);
+                return ErrorCode::PdfParseError as c_int;
+            }
+        };
+        get_navigation_metadata_impl(&document, catalog.as_ref(), out_json)
+    })
+}
+
+// ── Tests ────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxidize_pdf::actions::Action;
+    use oxidize_pdf::structure::{
+        Destination, NamedDestinations, OutlineItem, OutlineTree, PageDestination,
+    };
+    use oxidize_pdf::viewer_preferences::{PageLayout, ViewerPreferences};
+    use oxidize_pdf::{Color, Document, Page};
+    use serde_json::Value;
+    use std::ffi::CStr;
+
+    /// The writer links siblings as if earlier ones had no descendants, so
+    /// only the last item at each level has children here.
+    fn outline_pdf() -> Vec<u8> {
+        let mut doc = Document::new();
+        for _ in 0..3 {
+            doc.add_page(Page::a4());
+        }
+        let preface = OutlineItem::new("Preface")
+            .with_destination(Destination::fit(PageDestination::PageNumber(0)))
+            .bold()
+            .with_color(Color::rgb(1.0, 0.0, 0.0));
+        let mut chapter = OutlineItem::new("Chapitre 1 : Données").with_destination(
+            Destination::fit_h(PageDestination::PageNumber(2), Some(500.0)),
+        );
+        chapter.add_child(
+            OutlineItem::new("Section 1.1")
+                .with_destination(Destination::xyz(
+                    PageDestination::PageNumber(1),
+                    Some(72.0),
+                    Some(700.0),
+                    None,
+                ))
+                .italic(),
+        );
+        let mut section = OutlineItem::new("Section 1.2").closed();
+        section.add_child(OutlineItem::new("Untargeted"));
+        chapter.add_child(section);
+        let mut tree = OutlineTree::new();
+        tree.add_item(preface);
+        tree.add_item(chapter);
+        doc.set_outline(tree);
+        doc.to_bytes().unwrap()
+    }
+
+    fn outline(pdf: &[u8]) -> Result<Value, c_int> {
+        let mut out: *mut c_char = ptr::null_mut();
+        let code = unsafe { oxidize_get_outline(pdf.as_ptr(), pdf.len(), &mut out) };
+        if code != ErrorCode::Success as c_int {
+            return Err(code);
+        }
+        let json = unsafe { CStr::from_ptr(out) }.to_str().unwrap().to_owned();
+        unsafe { crate::oxidize_free_string(out) };
+        Ok(serde_json::from_str(&json).unwrap())
+    }
+
+    fn navigation_metadata(pdf: &[u8]) -> Value {
+        let mut out: *mut c_char = ptr::null_mut();
+        let code = unsafe { oxidize_get_navigation_metadata(pdf.as_ptr(), pdf.len(), &mut out) };
+        assert_eq!(code, ErrorCode::Success as c_int);
+        let json = unsafe { CStr::from_ptr(out) }.to_str().unwrap().to_owned();
+        unsafe { crate::oxidize_free_string(out) };
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn outline_round_trips_nesting_targets_and_styles() {
+        let tree = outline(&outline_pdf()).unwrap();
+        let items = tree["items"].as_array().unwrap();
+        assert_eq!(items.len(), 2);
+
+        let preface = &items[0];
+        assert_eq!(preface["title"], "Preface");
+        assert_eq!(preface["page"], 0);
+        assert_eq!(preface["destination"]["fit"], 1);
+        assert_eq!(preface["bold"], true);
+        assert_eq!(preface["italic"], false);
+        assert_eq!(preface["color"], serde_json::json!([1.0, 0.0, 0.0]));
+        assert_eq!(preface["children"], serde_json::json!([]));
+
+        let chapter = &items[1];
+        assert_eq!(chapter["title"], "Chapitre 1 : Données");
+        assert_eq!(chapter["page"], 2);
+        assert_eq!(chapter["destination"]["fit"], 2);
+        assert_eq!(chapter["destination"]["top"], 500.0);
+        assert_eq!(chapter["open"], true);
+        assert_eq!(chapter["children"].as_array().unwrap().len(), 2);
+
+        let first = &chapter["children"][0];
+        assert_eq!(first["title"], "Section 1.1");
+        assert_eq!(first["page"], 1);
+        assert_eq!(first["italic"], true);
+        assert_eq!(first["destination"]["fit"], 0);
+        assert_eq!(first["destination"]["left"], 72.0);
+        assert_eq!(first["destination"]["top"], 700.0);
+        assert!(first["destination"]["zoom"].is_null());
+
+        let second = &chapter["children"][1];
+        assert_eq!(second["open"], false);
+        assert!(second["page"].is_null());
+        assert_eq!(second["children"][0]["title"], "Untargeted");
+        assert!(second["children"][0]["destination"].is_null());
+    }
+
+    #[test]
+    fn document_without_outline_has_no_items() {
+        let mut doc = Document::new();
+        doc.add_page(Page::a4());
+        let tree = outline(&doc.to_bytes().unwrap()).unwrap();
+        assert_eq!(tree["items"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn text_strings_decode_utf16_and_pdfdoc() {
+        let utf16 = PdfString::new(vec![0xFE, 0xFF, 0x00, 0x41, 0x00, 0xE9]);
+        assert_eq!(text_string(&utf16), "Aé");
+        assert_eq!(text_string(&PdfString::new(b"Caf\xE9".to_vec())), "Café");
+        let pdfdoc = PdfString::new(vec![0x80, 0x84, 0x93, 0x9F, 0xA0, 0x18, 0x1F, 0xE9]);
+        assert_eq!(text_string(&pdfdoc), "•—ﬁ\u{FFFD}€˘˜é");
+        assert_eq!(outline(&[]).unwrap_err(), ErrorCode::PdfParseError as c_int);
+    }
+
+    #[test]
+    fn navigation_metadata_reads_back_catalog_entries() {
+        let mut doc = Document::new();
+        for _ in 0..4 {
+            doc.add_page(Page::a4());
+        }
+        doc.set_open_action(Action::goto(Destination::xyz(
+            PageDestination::PageNumber(1),
+            Some(10.0),
+            Some(20.0),
+            Some(1.5),
+        )));
+        doc.set_viewer_preferences(
+            ViewerPreferences::new()
+                .hide_toolbar(true)
+                .page_layout(PageLayout::TwoColumnLeft),
+        );
+        let mut dests = NamedDestinations::new();
+        dests.add_destination(
+            "chapter".to_string(),
+            Destination::fit(PageDestination::PageNumber(3)).to_array(),
+        );
+        doc.set_named_destinations(dests);
+        let mut labels = PageLabelTree::new();
+        labels.add_range(0, PageLabel::roman_lowercase());
+        labels.add_range(2, PageLabel::decimal().with_prefix("A-").starting_at(5));
+        doc.set_page_labels(labels);
+        let pdf = doc.to_bytes().unwrap();
+
+        let meta = navigation_metadata(&pdf);
+        assert_eq!(meta["open_action"]["kind"], "goto");
+        assert_eq!(meta["open_action"]["destination"]["page"], 1);
+        assert_eq!(meta["open_action"]["destination"]["zoom"], 1.5);
+        assert_eq!(meta["viewer_preferences"]["hide_toolbar"], true);
+        assert_eq!(meta["viewer_preferences"]["page_layout"], 2);
+        assert!(meta["viewer_preferences"]["fit_window"].is_null());
+        assert_eq!(meta["named_destinations"][0]["name"], "chapter");
+        assert_eq!(meta["named_destinations"][0]["destination"]["page"], 3);
+        assert_eq!(meta["page_labels"]["ranges"][1]["style"], 0);
+        assert_eq!(meta["page_labels"]["ranges"][1]["prefix"], "A-");
+        assert_eq!(meta["page_labels"]["ranges"][1]["start_at"], 5);
+        assert_eq!(
+            meta["page_labels"]["labels"],
+            serde_json::json!(["i", "ii", "A-5", "A-6"])
+        );
+
+        // A `/Dest (chapter)` string resolves through the name tree.
+        let (document, catalog) = open_with_catalog(&pdf).unwrap();
+        let catalog = catalog.unwrap();
+        let mut nav = Navigator::new(&document, &catalog);
+        let named = PdfObject::String(PdfString::new(b"chapter".to_vec()));
+        assert_eq!(nav.destination(&named).unwrap().unwrap().page, Some(3));
+    }
+
+    #[test]
+    fn navigation_metadata_of_plain_document_is_empty() {
+        let mut doc = Document::new();
+        doc.add_page(Page::a4());
+        let meta = navigation_metadata(&doc.to_bytes().unwrap());
+        assert!(meta["open_action"].is_null());
+        assert!(meta["page_labels"].is_null());
+        assert_eq!(meta["named_destinations"], serde_json::json!([]));
+        assert!(meta["viewer_preferences"]["hide_toolbar"].is_null());
+    }
+}
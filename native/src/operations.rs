@@ -1,73 +1,307 @@
+use std::collections::{HashMap, HashSet};
 use std::ffi::{CStr, CString};
-use std::fs;
+use std::io::{Cursor, Read, Seek};
 use std::os::raw::{c_char, c_int};
-use std::path::PathBuf;
 use std::ptr;
-use std::time::{SystemTime, UNIX_EPOCH};
 
+use crate::error_detail::{ErrorCategory, LastError};
+use crate::parser::{open_lenient, open_with_password, with_password};
+use crate::progress::{Progress, ProgressCallbacks};
 use crate::{clear_last_error, set_last_error, ErrorCode};
 use base64::Engine as _;
+use oxidize_pdf::objects::Object;
+use oxidize_pdf::operations::{PageRange, RotationAngle, SplitMode};
+use oxidize_pdf::parser::content::{ContentOperation, ContentParser};
+use oxidize_pdf::parser::objects::{PdfDictionary, PdfName, PdfObject, PdfStream, PdfString};
+use oxidize_pdf::parser::page_tree::ParsedPage;
+use oxidize_pdf::parser::PdfDocument;
+use oxidize_pdf::{Document, Page};
 
 // ── Helpers ───────────────────────────────────────────────────────────────────
+//
+// Every operation below works on the caller's buffers directly: input PDFs are
+// parsed from borrowed slices and output PDFs are serialized with
+// `Document::to_bytes`. Nothing is staged in `std::env::temp_dir()`, so the
+// operations work on read-only filesystems and do not contend on temp paths
+// under concurrency.
+//
+// Merge inputs carry their own password; the other operations take one
+// through their `*_with_password` variants (see `crate::parser::with_password`).
+
+/// Parse a borrowed PDF buffer into a document without copying it.
+fn open_document(bytes: &[u8]) -> Result<PdfDocument<Cursor<&[u8]>>, LastError> {
+    open_document_with(bytes, None)
+}
+
+/// [`open_document`], unlocking an encrypted buffer with `password` when given.
+fn open_document_with<'a>(
+    bytes: &'a [u8],
+    password: Option<&str>,
+) -> Result<PdfDocument<Cursor<&'a [u8]>>, LastError> {
+    match password {
+        Some(password) => open_with_password(bytes, password),
+        None => open_lenient(bytes),
+    }
+    .map(PdfDocument::new)
+}
 
-/// Generate a unique temp file path using timestamp + thread id to avoid collisions.
-fn temp_pdf_path(suffix: &str) -> PathBuf {
-    let ts = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .map(|d| d.as_nanos())
-        .unwrap_or(0);
-    let tid = std::thread::current().id();
-    let name = format!("oxidize_{ts}_{tid:?}{suffix}.pdf");
-    std::env::temp_dir().join(name)
+fn page_count<R: Read + Seek>(document: &PdfDocument<R>) -> Result<usize, LastError> {
+    document
+        .page_count()
+        .map(|n| n as usize)
+        .map_err(|e| LastError::caused_by("Failed to get page count", &e))
 }
 
-/// RAII guard that removes a temp file on drop — including unwinding panics
-/// from the wrapped closure. The previous design relied on a manual
-/// `fs::remove_file` after the closure returned, which leaked the file if
-/// the closure panicked. Rust panics that cross a `cdylib` boundary are UB,
-/// but a panic that unwinds before reaching the boundary still skipped
-/// cleanup; the guard closes that hole. Errors during removal are silently
-/// ignored (best-effort cleanup).
-struct TempFileGuard(PathBuf);
-
-impl TempFileGuard {
-    fn path_str(&self) -> Result<&str, String> {
-        self.0
-            .to_str()
-            .ok_or_else(|| "Temp path is not valid UTF-8".to_string())
+/// Resolve `range` against the document's page count.
+fn range_indices<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    range: &PageRange,
+) -> Result<Vec<usize>, LastError> {
+    range
+        .get_indices(page_count(document)?)
+        .map_err(|e| LastError::new(ErrorCategory::PageOutOfRange, e.to_string()))
+}
+
+/// Copy the Info dictionary fields the path-based operations used to preserve.
+fn copy_metadata<R: Read + Seek>(source: &PdfDocument<R>, target: &mut Document) {
+    if let Ok(metadata) = source.metadata() {
+        if let Some(title) = metadata.title {
+            target.set_title(&title);
+        }
+        if let Some(author) = metadata.author {
+            target.set_author(&author);
+        }
+        if let Some(subject) = metadata.subject {
+            target.set_subject(&subject);
+        }
+        if let Some(keywords) = metadata.keywords {
+            target.set_keywords(&keywords);
+        }
     }
 }
 
-impl Drop for TempFileGuard {
-    fn drop(&mut self) {
-        let _ = fs::remove_file(&self.0);
+/// Append the pages at `indices` (0-based, in that order) to `target`, keeping
+/// each page's original content streams and resources. `rotation` gives the
+/// turn to apply to a source index (see [`rotated_page`]).
+fn append_pages<R: Read + Seek>(
+    target: &mut Document,
+    source: &PdfDocument<R>,
+    indices: &[usize],
+    rotation: impl Fn(usize) -> RotationAngle,
+) -> Result<(), LastError> {
+    let total = page_count(source)?;
+    for &index in indices {
+        if index >= total {
+            return Err(LastError::new(
+                ErrorCategory::PageOutOfRange,
+                format!("Page index {index} is out of bounds (document has {total} pages)"),
+            ));
+        }
+        let parsed = source.get_page(index as u32).map_err(|e| {
+            LastError::caused_by(format_args!("Failed to get page {index}"), &e)
+                .at_page(index as u32)
+        })?;
+        let page = rotated_page(&parsed, source, rotation(index)).map_err(|e| {
+            LastError::caused_by(format_args!("Failed to copy page {index}"), &e)
+                .at_page(index as u32)
+        })?;
+        target.add_page(page);
     }
+    Ok(())
 }
 
-/// Write bytes to a temp file, run `f`, then delete the file (including on panic).
-fn with_temp_input<F, T>(bytes: &[u8], f: F) -> Result<T, String>
-where
-    F: FnOnce(&str) -> Result<T, String>,
-{
-    let guard = TempFileGuard(temp_pdf_path("_in"));
-    fs::write(&guard.0, bytes).map_err(|e| format!("Failed to write temp input: {e}"))?;
-    f(guard.path_str()?)
+/// Copy `parsed` turned clockwise by `angle`. The MediaBox turns with the
+/// page (width and height swap for 90° and 270°) and the original content is
+/// drawn through the matching transform; the page `/Rotate` entry is kept.
+fn rotated_page<R: Read + Seek>(
+    parsed: &ParsedPage,
+    source: &PdfDocument<R>,
+    angle: RotationAngle,
+) -> oxidize_pdf::Result<Page> {
+    let [x0, y0, x1, y1] = parsed.media_box;
+    let (width, height) = (x1 - x0, y1 - y0);
+    // `0.0 - x0` rather than `-x0`, so a zero origin is not written as -0.
+    let (matrix, media_box) = match angle {
+        RotationAngle::None => return Page::from_parsed_with_content(parsed, source),
+        RotationAngle::Clockwise90 => (
+            [0.0, 1.0, -1.0, 0.0, height + y0, 0.0 - x0],
+            [0.0, 0.0, height, width],
+        ),
+        RotationAngle::Rotate180 => (
+            [-1.0, 0.0, 0.0, -1.0, width + x0, height + y0],
+            [0.0, 0.0, width, height],
+        ),
+        RotationAngle::Clockwise270 => (
+            [0.0, -1.0, 1.0, 0.0, 0.0 - y0, width + x0],
+            [0.0, 0.0, height, width],
+        ),
+    };
+    let mut turned = parsed.clone();
+    turned.media_box = media_box;
+    let mut page = Page::from_parsed_with_content(&turned, source)?;
+    // Page graphics are written ahead of the preserved content, so the
+    // transform applies to all of it.
+    let [a, b, c, d, e, f] = matrix;
+    page.graphics().transform(a, b, c, d, e, f);
+    Ok(page)
 }
 
-/// Run `f` with a temp output path, then read the result bytes and delete the file
-/// (including on panic).
-fn with_temp_output<F>(f: F) -> Result<Vec<u8>, String>
-where
-    F: FnOnce(&str) -> Result<(), String>,
-{
-    let guard = TempFileGuard(temp_pdf_path("_out"));
-    f(guard.path_str()?)?;
-    fs::read(&guard.0).map_err(|e| format!("Failed to read temp output: {e}"))
+/// Build a new PDF from the pages at `indices` of `source`, carrying over its metadata.
+fn assemble_pages<R: Read + Seek>(
+    source: &PdfDocument<R>,
+    indices: &[usize],
+    rotation: impl Fn(usize) -> RotationAngle,
+) -> Result<Vec<u8>, LastError> {
+    if indices.is_empty() {
+        return Err("No pages to process".into());
+    }
+    let mut doc = Document::new();
+    copy_metadata(source, &mut doc);
+    append_pages(&mut doc, source, indices, rotation)?;
+    document_to_bytes(doc)
+}
+
+fn document_to_bytes(mut doc: Document) -> Result<Vec<u8>, LastError> {
+    doc.to_bytes()
+        .map_err(|e| LastError::caused_by("Failed to write PDF", &e))
+}
+
+/// Reorder `bytes` into a new PDF following `order` (0-based source indices).
+fn reorder_bytes(
+    bytes: &[u8],
+    order: impl FnOnce(usize) -> Result<Vec<usize>, LastError>,
+) -> Result<Vec<u8>, LastError> {
+    let document = open_document(bytes)?;
+    let order = order(page_count(&document)?)?;
+    assemble_pages(&document, &order, |_| RotationAngle::None)
+}
+
+/// Rotate the pages of `bytes` selected by `range` by `angle`, keeping the rest as-is.
+fn rotate_bytes(
+    bytes: &[u8],
+    angle: RotationAngle,
+    range: &PageRange,
+) -> Result<Vec<u8>, LastError> {
+    let document = open_document(bytes)?;
+    let selected: HashSet<usize> = range_indices(&document, range)?.into_iter().collect();
+    let all: Vec<usize> = (0..page_count(&document)?).collect();
+    assemble_pages(&document, &all, |index| {
+        if selected.contains(&index) {
+            angle
+        } else {
+            RotationAngle::None
+        }
+    })
+}
+
+/// One PDF to merge: its bytes, the pages to take and an optional password.
+struct MergeInput {
+    bytes: Vec<u8>,
+    range: PageRange,
+    password: Option<String>,
+}
+
+impl MergeInput {
+    fn all_pages(bytes: Vec<u8>) -> Self {
+        MergeInput {
+            bytes,
+            range: PageRange::All,
+            password: None,
+        }
+    }
+}
+
+/// Merge `inputs` (PDF bytes plus page selection) into one PDF. Metadata is
+/// taken from the first input, matching `MergeOptions::default()`.
+///
+/// `progress` counts copied pages across all inputs and is polled for
+/// cancellation before each page. Failures set the last error and return
+/// `IoError` (or `Cancelled`).
+fn merge_bytes(inputs: &[MergeInput], progress: Progress) -> Result<Vec<u8>, c_int> {
+    let io_error = |err: LastError| {
+        set_last_error(err);
+        ErrorCode::IoError as c_int
+    };
+
+    let mut selected = Vec::with_capacity(inputs.len());
+    for (i, input) in inputs.iter().enumerate() {
+        let document = open_document_with(&input.bytes, input.password.as_deref())
+            .map_err(|e| io_error(e.context(format_args!("Failed to open PDF #{i}"))))?;
+        let indices = range_indices(&document, &input.range)
+            .map_err(|e| io_error(e.context(format_args!("PDF #{i}"))))?;
+        selected.push((document, indices));
+    }
+    let total: usize = selected.iter().map(|(_, indices)| indices.len()).sum();
+
+    let mut merged = Document::new();
+    let mut done = 0;
+    for (i, (document, indices)) in selected.iter().enumerate() {
+        for &index in indices {
+            progress.check()?;
+            append_pages(&mut merged, document, &[index], |_| RotationAngle::None)
+                .map_err(|e| io_error(e.context(format_args!("PDF #{i}"))))?;
+            done += 1;
+            progress.report(done as u32, total as u32);
+        }
+        if i == 0 {
+            copy_metadata(document, &mut merged);
+        }
+    }
+    progress.check()?;
+    document_to_bytes(merged).map_err(io_error)
+}
+
+/// Page ranges produced by `mode` for a document of `total` pages, mirroring
+/// `oxidize_pdf::operations::PdfSplitter`.
+fn split_ranges(mode: SplitMode, total: usize) -> Result<Vec<PageRange>, LastError> {
+    if total == 0 {
+        return Err("No pages to process".into());
+    }
+    let ranges = match mode {
+        SplitMode::SinglePages => (0..total).map(PageRange::Single).collect(),
+        SplitMode::Ranges(ranges) => ranges,
+        SplitMode::ChunkSize(0) => return Err("chunk_size must be >= 1".into()),
+        SplitMode::ChunkSize(size) => (0..total)
+            .step_by(size)
+            .map(|start| PageRange::Range(start, (start + size - 1).min(total - 1)))
+            .collect(),
+        SplitMode::SplitAt(points) => {
+            let mut ranges = Vec::new();
+            let mut start = 0;
+            for point in points {
+                if point > 0 && point < total && point > start {
+                    ranges.push(PageRange::Range(start, point - 1));
+                    start = point;
+                }
+            }
+            if start < total {
+                ranges.push(PageRange::Range(start, total - 1));
+            }
+            ranges
+        }
+    };
+    Ok(ranges)
+}
+
+/// Split `bytes` according to `mode` and return a JSON array of base64 PDFs.
+fn split_to_json(bytes: &[u8], mode: SplitMode) -> Result<String, LastError> {
+    let document = open_document(bytes)?;
+    let ranges = split_ranges(mode, page_count(&document)?)?;
+
+    let mut encoded = Vec::with_capacity(ranges.len());
+    for range in &ranges {
+        let indices = range_indices(&document, range)?;
+        let part = assemble_pages(&document, &indices, |_| RotationAngle::None)?;
+        encoded.push(base64::engine::general_purpose::STANDARD.encode(&part));
+    }
+
+    serde_json::to_string(&encoded)
+        .map_err(|e| LastError::caused_by("JSON serialization failed", &e))
 }
 
 /// Allocate a byte buffer on the heap for the caller.  The caller must free it with
 /// `oxidize_free_bytes`.
-unsafe fn set_out_bytes(bytes: Vec<u8>, out_bytes: *mut *mut u8, out_len: *mut usize) {
+pub(crate) unsafe fn set_out_bytes(bytes: Vec<u8>, out_bytes: *mut *mut u8, out_len: *mut usize) {
     let len = bytes.len();
     let mut boxed = bytes.into_boxed_slice();
     *out_bytes = boxed.as_mut_ptr();
@@ -106,28 +340,7 @@ pub unsafe extern "C" fn oxidize_split_pdf_bytes(
 
         let input_bytes = std::slice::from_raw_parts(pdf_bytes, pdf_len);
 
-        let result = with_temp_input(input_bytes, |input_path| {
-            let out_dir = std::env::temp_dir();
-            let ts = SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .map(|d| d.as_nanos())
-                .unwrap_or(0);
-            let pattern = format!("{}/oxidize_split_{}_page_{{}}.pdf", out_dir.display(), ts);
-
-            let paths = oxidize_pdf::operations::split_into_pages(input_path, &pattern)
-                .map_err(|e| format!("split_into_pages failed: {e}"))?;
-
-            let mut encoded_pages = Vec::with_capacity(paths.len());
-            for path in &paths {
-                let page_bytes =
-                    fs::read(path).map_err(|e| format!("Failed to read split page: {e}"))?;
-                encoded_pages.push(base64::engine::general_purpose::STANDARD.encode(&page_bytes));
-                let _ = fs::remove_file(path);
-            }
-
-            serde_json::to_string(&encoded_pages)
-                .map_err(|e| format!("JSON serialization failed: {e}"))
-        });
+        let result = split_to_json(input_bytes, SplitMode::SinglePages);
 
         match result {
             Ok(json) => match CString::new(json) {
@@ -195,72 +408,23 @@ pub unsafe extern "C" fn oxidize_merge_pdfs_bytes(
             return ErrorCode::PdfParseError as c_int;
         }
 
-        // Decode all base64 PDFs and write to temp files.
-        let mut temp_paths: Vec<PathBuf> = Vec::with_capacity(encoded.len());
+        let mut inputs = Vec::with_capacity(encoded.len());
         for (i, b64) in encoded.iter().enumerate() {
-            let decoded = match base64::engine::general_purpose::STANDARD.decode(b64) {
-                Ok(d) => d,
+            match base64::engine::general_purpose::STANDARD.decode(b64) {
+                Ok(d) => inputs.push(MergeInput::all_pages(d)),
                 Err(e) => {
-                    for p in &temp_paths {
-                        let _ = fs::remove_file(p);
-                    }
                     set_last_error(format!("Failed to decode PDF #{i}: {e}"));
                     return ErrorCode::PdfParseError as c_int;
                 }
-            };
-            let path = temp_pdf_path(&format!("_merge_{i}"));
-            if let Err(e) = fs::write(&path, &decoded) {
-                for p in &temp_paths {
-                    let _ = fs::remove_file(p);
-                }
-                set_last_error(format!("Failed to write temp file for PDF #{i}: {e}"));
-                return ErrorCode::IoError as c_int;
             }
-            temp_paths.push(path);
         }
 
-        let inputs: Vec<oxidize_pdf::operations::MergeInput> = match temp_paths
-            .iter()
-            .map(|p| {
-                p.to_str()
-                    .ok_or_else(|| "Temp path is not valid UTF-8".to_string())
-                    .map(oxidize_pdf::operations::MergeInput::new)
-            })
-            .collect::<Result<Vec<_>, _>>()
-        {
-            Ok(v) => v,
-            Err(msg) => {
-                for p in &temp_paths {
-                    let _ = fs::remove_file(p);
-                }
-                set_last_error(msg);
-                return ErrorCode::IoError as c_int;
-            }
-        };
-
-        let merge_result = with_temp_output(|output_path| {
-            oxidize_pdf::operations::merge_pdfs(
-                inputs,
-                output_path,
-                oxidize_pdf::operations::MergeOptions::default(),
-            )
-            .map_err(|e| format!("merge_pdfs failed: {e}"))
-        });
-
-        // Clean up temp input files regardless.
-        for p in &temp_paths {
-            let _ = fs::remove_file(p);
-        }
-
-        match merge_result {
+        match merge_bytes(&inputs, Progress::NONE) {
             Ok(bytes) => {
                 set_out_bytes(bytes, out_bytes, out_len);
                 ErrorCode::Success as c_int
             }
-            Err(msg) => {
-                set_last_error(msg);
-                ErrorCode::IoError as c_int
-            }
+            Err(code) => code,
         }
     })
 }
@@ -297,7 +461,7 @@ pub unsafe extern "C" fn oxidize_rotate_pdf_bytes(
             return ErrorCode::PdfParseError as c_int;
         }
 
-        let angle = match oxidize_pdf::operations::RotationAngle::from_degrees(degrees) {
+        let angle = match RotationAngle::from_degrees(degrees) {
             Ok(a) => a,
             Err(e) => {
                 set_last_error(format!("Invalid rotation angle {degrees}: {e}"));
@@ -307,12 +471,7 @@ pub unsafe extern "C" fn oxidize_rotate_pdf_bytes(
 
         let input_bytes = std::slice::from_raw_parts(pdf_bytes, pdf_len);
 
-        let result = with_temp_input(input_bytes, |input_path| {
-            with_temp_output(|output_path| {
-                oxidize_pdf::operations::rotate_all_pages(input_path, output_path, angle)
-                    .map_err(|e| format!("rotate_all_pages failed: {e}"))
-            })
-        });
+        let result = rotate_bytes(input_bytes, angle, &PageRange::All);
 
         match result {
             Ok(bytes) => {
@@ -383,12 +542,8 @@ pub unsafe extern "C" fn oxidize_extract_pages_bytes(
 
         let input_bytes = std::slice::from_raw_parts(pdf_bytes, pdf_len);
 
-        let result = with_temp_input(input_bytes, |input_path| {
-            with_temp_output(|output_path| {
-                oxidize_pdf::operations::extract_pages_to_file(input_path, &indices, output_path)
-                    .map_err(|e| format!("extract_pages_to_file failed: {e}"))
-            })
-        });
+        let result = open_document(input_bytes)
+            .and_then(|document| assemble_pages(&document, &indices, |_| RotationAngle::None));
 
         match result {
             Ok(bytes) => {
@@ -456,12 +611,7 @@ pub unsafe extern "C" fn oxidize_reorder_pages_bytes(
 
         let input_bytes = std::slice::from_raw_parts(pdf_bytes, pdf_len);
 
-        let result = with_temp_input(input_bytes, |input_path| {
-            with_temp_output(|output_path| {
-                oxidize_pdf::operations::reorder_pdf_pages(input_path, output_path, order.clone())
-                    .map_err(|e| format!("reorder_pdf_pages failed: {e}"))
-            })
-        });
+        let result = reorder_bytes(input_bytes, |_| Ok(order));
 
         match result {
             Ok(bytes) => {
@@ -508,11 +658,16 @@ pub unsafe extern "C" fn oxidize_swap_pages_bytes(
 
         let input_bytes = std::slice::from_raw_parts(pdf_bytes, pdf_len);
 
-        let result = with_temp_input(input_bytes, |input_path| {
-            with_temp_output(|output_path| {
-                oxidize_pdf::operations::swap_pdf_pages(input_path, output_path, page_a, page_b)
-                    .map_err(|e| format!("swap_pdf_pages failed: {e}"))
-            })
+        let result = reorder_bytes(input_bytes, |total| {
+            if page_a >= total || page_b >= total {
+                return Err(LastError::new(
+                    ErrorCategory::PageOutOfRange,
+                    "Page index out of bounds",
+                ));
+            }
+            let mut order: Vec<usize> = (0..total).collect();
+            order.swap(page_a, page_b);
+            Ok(order)
         });
 
         match result {
@@ -560,16 +715,17 @@ pub unsafe extern "C" fn oxidize_move_page_bytes(
 
         let input_bytes = std::slice::from_raw_parts(pdf_bytes, pdf_len);
 
-        let result = with_temp_input(input_bytes, |input_path| {
-            with_temp_output(|output_path| {
-                oxidize_pdf::operations::move_pdf_page(
-                    input_path,
-                    output_path,
-                    from_index,
-                    to_index,
-                )
-                .map_err(|e| format!("move_pdf_page failed: {e}"))
-            })
+        let result = reorder_bytes(input_bytes, |total| {
+            if from_index >= total || to_index >= total {
+                return Err(LastError::new(
+                    ErrorCategory::PageOutOfRange,
+                    "Page index out of bounds",
+                ));
+            }
+            let mut order: Vec<usize> = (0..total).collect();
+            let page = order.remove(from_index);
+            order.insert(to_index, page);
+            Ok(order)
         });
 
         match result {
@@ -615,12 +771,7 @@ pub unsafe extern "C" fn oxidize_reverse_pages_bytes(
 
         let input_bytes = std::slice::from_raw_parts(pdf_bytes, pdf_len);
 
-        let result = with_temp_input(input_bytes, |input_path| {
-            with_temp_output(|output_path| {
-                oxidize_pdf::operations::reverse_pdf_pages(input_path, output_path)
-                    .map_err(|e| format!("reverse_pdf_pages failed: {e}"))
-            })
-        });
+        let result = reorder_bytes(input_bytes, |total| Ok((0..total).rev().collect()));
 
         match result {
             Ok(bytes) => {
@@ -695,56 +846,21 @@ pub unsafe extern "C" fn oxidize_split_pdf_bytes_with_options(
         };
 
         let split_mode = match opts_json {
-            SplitOptionsJson::SinglePages => oxidize_pdf::operations::SplitMode::SinglePages,
-            SplitOptionsJson::ChunkSize { chunk_size } => {
-                oxidize_pdf::operations::SplitMode::ChunkSize(chunk_size)
-            }
+            SplitOptionsJson::SinglePages => SplitMode::SinglePages,
+            SplitOptionsJson::ChunkSize { chunk_size } => SplitMode::ChunkSize(chunk_size),
             SplitOptionsJson::Ranges { ranges } => {
-                let page_ranges: Vec<oxidize_pdf::operations::PageRange> = ranges
+                let page_ranges: Vec<PageRange> = ranges
                     .iter()
-                    .map(|&[from, to]| oxidize_pdf::operations::PageRange::Range(from, to))
+                    .map(|&[from, to]| PageRange::Range(from, to))
                     .collect();
-                oxidize_pdf::operations::SplitMode::Ranges(page_ranges)
-            }
-            SplitOptionsJson::SplitAt { split_at } => {
-                oxidize_pdf::operations::SplitMode::SplitAt(split_at)
+                SplitMode::Ranges(page_ranges)
             }
+            SplitOptionsJson::SplitAt { split_at } => SplitMode::SplitAt(split_at),
         };
 
         let input_bytes = std::slice::from_raw_parts(pdf_bytes, pdf_len);
 
-        let result = with_temp_input(input_bytes, |input_path| {
-            let out_dir = std::env::temp_dir();
-            let ts = SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .map(|d| d.as_nanos())
-                .unwrap_or(0);
-            let pattern = format!(
-                "{}/oxidize_split_opts_{}_chunk_{{}}.pdf",
-                out_dir.display(),
-                ts
-            );
-
-            let options = oxidize_pdf::operations::SplitOptions {
-                mode: split_mode,
-                output_pattern: pattern,
-                ..Default::default()
-            };
-
-            let paths = oxidize_pdf::operations::split_pdf(input_path, options)
-                .map_err(|e| format!("split_pdf failed: {e}"))?;
-
-            let mut encoded_chunks = Vec::with_capacity(paths.len());
-            for path in &paths {
-                let chunk_bytes =
-                    fs::read(path).map_err(|e| format!("Failed to read split chunk: {e}"))?;
-                encoded_chunks.push(base64::engine::general_purpose::STANDARD.encode(&chunk_bytes));
-                let _ = fs::remove_file(path);
-            }
-
-            serde_json::to_string(&encoded_chunks)
-                .map_err(|e| format!("JSON serialization failed: {e}"))
-        });
+        let result = split_to_json(input_bytes, split_mode);
 
         match result {
             Ok(json) => match CString::new(json) {
@@ -778,31 +894,75 @@ enum PageRangeJson {
 }
 
 impl PageRangeJson {
-    fn into_core(self) -> oxidize_pdf::operations::PageRange {
+    fn into_core(self) -> PageRange {
         match self {
-            PageRangeJson::All => oxidize_pdf::operations::PageRange::All,
-            PageRangeJson::Single { index } => oxidize_pdf::operations::PageRange::Single(index),
-            PageRangeJson::Range { from, to } => {
-                oxidize_pdf::operations::PageRange::Range(from, to)
-            }
-            PageRangeJson::List { indices } => oxidize_pdf::operations::PageRange::List(indices),
+            PageRangeJson::All => PageRange::All,
+            PageRangeJson::Single { index } => PageRange::Single(index),
+            PageRangeJson::Range { from, to } => PageRange::Range(from, to),
+            PageRangeJson::List { indices } => PageRange::List(indices),
         }
     }
 }
 
 /// JSON representation of a single merge input.
 #[derive(serde::Deserialize)]
-struct MergeInputJson {
+pub(crate) struct MergeInputJson {
     /// Base64-encoded PDF bytes.
     pdf: String,
     /// Optional page range selector.
     pages: Option<PageRangeJson>,
+    /// Password for an encrypted input (user or owner password).
+    #[serde(default)]
+    password: Option<String>,
+}
+
+/// Decode an `inputs_json` array of [`MergeInputJson`] into PDF bytes plus
+/// page selection.
+unsafe fn parse_merge_inputs(inputs_json: *const c_char) -> Result<Vec<MergeInput>, c_int> {
+    let json_str = match CStr::from_ptr(inputs_json).to_str() {
+        Ok(s) => s,
+        Err(_) => {
+            set_last_error("Invalid UTF-8 in inputs_json");
+            return Err(ErrorCode::InvalidUtf8 as c_int);
+        }
+    };
+
+    let inputs_data: Vec<MergeInputJson> = match serde_json::from_str(json_str) {
+        Ok(v) => v,
+        Err(e) => {
+            set_last_error(format!("Failed to parse inputs_json: {e}"));
+            return Err(ErrorCode::SerializationError as c_int);
+        }
+    };
+
+    if inputs_data.is_empty() {
+        set_last_error("At least one PDF is required for merge");
+        return Err(ErrorCode::PdfParseError as c_int);
+    }
+
+    let mut inputs = Vec::with_capacity(inputs_data.len());
+    for (i, input) in inputs_data.into_iter().enumerate() {
+        let decoded = match base64::engine::general_purpose::STANDARD.decode(&input.pdf) {
+            Ok(d) => d,
+            Err(e) => {
+                set_last_error(format!("Failed to decode PDF #{i}: {e}"));
+                return Err(ErrorCode::PdfParseError as c_int);
+            }
+        };
+        inputs.push(MergeInput {
+            bytes: decoded,
+            range: input.pages.map_or(PageRange::All, PageRangeJson::into_core),
+            password: input.password,
+        });
+    }
+    Ok(inputs)
 }
 
 /// Merge multiple PDFs with per-input page range selection.
 ///
 /// `inputs_json` must be a null-terminated JSON array of objects with shape
 /// `{"pdf":"<base64>","pages":null}` or `{"pdf":"<base64>","pages":{"kind":"Range","from":0,"to":2}}`.
+/// An encrypted input may carry `"password":"<user or owner password>"`.
 /// On success, `out_bytes` / `out_len` receive the merged PDF bytes (free with `oxidize_free_bytes`).
 ///
 /// # Safety
@@ -823,96 +983,61 @@ pub unsafe extern "C" fn oxidize_merge_pdfs_with_ranges(
         *out_bytes = ptr::null_mut();
         *out_len = 0;
 
-        let json_str = match CStr::from_ptr(inputs_json).to_str() {
-            Ok(s) => s,
-            Err(_) => {
-                set_last_error("Invalid UTF-8 in inputs_json");
-                return ErrorCode::InvalidUtf8 as c_int;
-            }
+        let inputs = match parse_merge_inputs(inputs_json) {
+            Ok(inputs) => inputs,
+            Err(code) => return code,
         };
 
-        let inputs_data: Vec<MergeInputJson> = match serde_json::from_str(json_str) {
-            Ok(v) => v,
-            Err(e) => {
-                set_last_error(format!("Failed to parse inputs_json: {e}"));
-                return ErrorCode::SerializationError as c_int;
-            }
-        };
-
-        if inputs_data.is_empty() {
-            set_last_error("At least one PDF is required for merge");
-            return ErrorCode::PdfParseError as c_int;
-        }
-
-        // Decode all base64 PDFs and write to temp files.
-        let mut temp_paths: Vec<PathBuf> = Vec::with_capacity(inputs_data.len());
-
-        for (i, input) in inputs_data.iter().enumerate() {
-            let decoded = match base64::engine::general_purpose::STANDARD.decode(&input.pdf) {
-                Ok(d) => d,
-                Err(e) => {
-                    for p in &temp_paths {
-                        let _ = fs::remove_file(p);
-                    }
-                    set_last_error(format!("Failed to decode PDF #{i}: {e}"));
-                    return ErrorCode::PdfParseError as c_int;
-                }
-            };
-            let path = temp_pdf_path(&format!("_merge_ranges_{i}"));
-            if let Err(e) = fs::write(&path, &decoded) {
-                for p in &temp_paths {
-                    let _ = fs::remove_file(p);
-                }
-                set_last_error(format!("Failed to write temp file for PDF #{i}: {e}"));
-                return ErrorCode::IoError as c_int;
+        match merge_bytes(&inputs, Progress::NONE) {
+            Ok(bytes) => {
+                set_out_bytes(bytes, out_bytes, out_len);
+                ErrorCode::Success as c_int
             }
-            temp_paths.push(path);
+            Err(code) => code,
         }
+    })
+}
 
-        // Build MergeInput list with optional page ranges.
-        let merge_inputs: Vec<oxidize_pdf::operations::MergeInput> = temp_paths
-            .iter()
-            .zip(inputs_data)
-            .filter_map(|(path, data)| {
-                path.to_str().map(|path_str| match data.pages {
-                    Some(range) => {
-                        oxidize_pdf::operations::MergeInput::with_pages(path_str, range.into_core())
-                    }
-                    None => oxidize_pdf::operations::MergeInput::new(path_str),
-                })
-            })
-            .collect();
-
-        if merge_inputs.len() != temp_paths.len() {
-            for p in &temp_paths {
-                let _ = fs::remove_file(p);
-            }
-            set_last_error("One or more temp paths are not valid UTF-8");
-            return ErrorCode::IoError as c_int;
+/// [`oxidize_merge_pdfs_with_ranges`] with progress reporting and
+/// cancellation.
+///
+/// `on_progress` receives `(copied_pages, total_pages)` summed over all
+/// inputs; `is_cancelled` is polled before each page and before the merged
+/// PDF is written. `progress` may be null. Returns `Cancelled` when the
+/// caller's callback fires; no bytes are returned then.
+///
+/// # Safety
+/// - `inputs_json` must be a valid null-terminated UTF-8 string.
+/// - `progress` must be null or point to a valid `ProgressCallbacks` for
+///   the duration of the call.
+/// - `out_bytes` and `out_len` must be valid non-null pointers.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_merge_pdfs_with_progress(
+    inputs_json: *const c_char,
+    progress: *const ProgressCallbacks,
+    out_bytes: *mut *mut u8,
+    out_len: *mut usize,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if inputs_json.is_null() || out_bytes.is_null() || out_len.is_null() {
+            set_last_error("Null pointer provided to oxidize_merge_pdfs_with_progress");
+            return ErrorCode::NullPointer as c_int;
         }
+        *out_bytes = ptr::null_mut();
+        *out_len = 0;
 
-        let merge_result = with_temp_output(|output_path| {
-            oxidize_pdf::operations::merge_pdfs(
-                merge_inputs,
-                output_path,
-                oxidize_pdf::operations::MergeOptions::default(),
-            )
-            .map_err(|e| format!("merge_pdfs failed: {e}"))
-        });
-
-        for p in &temp_paths {
-            let _ = fs::remove_file(p);
-        }
+        let inputs = match parse_merge_inputs(inputs_json) {
+            Ok(inputs) => inputs,
+            Err(code) => return code,
+        };
 
-        match merge_result {
+        match merge_bytes(&inputs, Progress::from_ptr(progress)) {
             Ok(bytes) => {
                 set_out_bytes(bytes, out_bytes, out_len);
                 ErrorCode::Success as c_int
             }
-            Err(msg) => {
-                set_last_error(msg);
-                ErrorCode::IoError as c_int
-            }
+            Err(code) => code,
         }
     })
 }
@@ -953,7 +1078,7 @@ pub unsafe extern "C" fn oxidize_rotate_pages_bytes(
             return ErrorCode::PdfParseError as c_int;
         }
 
-        let angle = match oxidize_pdf::operations::RotationAngle::from_degrees(degrees) {
+        let angle = match RotationAngle::from_degrees(degrees) {
             Ok(a) => a,
             Err(e) => {
                 set_last_error(format!("Invalid rotation angle {degrees}: {e}"));
@@ -962,7 +1087,7 @@ pub unsafe extern "C" fn oxidize_rotate_pages_bytes(
         };
 
         let page_range = if pages_json.is_null() {
-            oxidize_pdf::operations::PageRange::All
+            PageRange::All
         } else {
             let range_str = match CStr::from_ptr(pages_json).to_str() {
                 Ok(s) => s,
@@ -983,17 +1108,7 @@ pub unsafe extern "C" fn oxidize_rotate_pages_bytes(
 
         let input_bytes = std::slice::from_raw_parts(pdf_bytes, pdf_len);
 
-        let result = with_temp_input(input_bytes, |input_path| {
-            with_temp_output(|output_path| {
-                let options = oxidize_pdf::operations::RotateOptions {
-                    pages: page_range,
-                    angle,
-                    preserve_page_size: false,
-                };
-                oxidize_pdf::operations::rotate_pdf_pages(input_path, output_path, options)
-                    .map_err(|e| format!("rotate_pdf_pages failed: {e}"))
-            })
-        });
+        let result = rotate_bytes(input_bytes, angle, &page_range);
 
         match result {
             Ok(bytes) => {
@@ -1046,31 +1161,16 @@ pub unsafe extern "C" fn oxidize_overlay_pdf_bytes(
         let base_data = std::slice::from_raw_parts(base_bytes, base_len);
         let overlay_data = std::slice::from_raw_parts(overlay_bytes, overlay_len);
 
-        let base_path = temp_pdf_path("_overlay_base");
-        let overlay_path = temp_pdf_path("_overlay_top");
-
-        if let Err(e) = fs::write(&base_path, base_data) {
-            set_last_error(format!("Failed to write base temp file: {e}"));
-            return ErrorCode::IoError as c_int;
-        }
-        if let Err(e) = fs::write(&overlay_path, overlay_data) {
-            let _ = fs::remove_file(&base_path);
-            set_last_error(format!("Failed to write overlay temp file: {e}"));
-            return ErrorCode::IoError as c_int;
-        }
-
-        let result = with_temp_output(|output_path| {
-            oxidize_pdf::operations::overlay_pdf(
-                &base_path,
-                &overlay_path,
-                output_path,
-                oxidize_pdf::operations::OverlayOptions::default(),
-            )
-            .map_err(|e| format!("overlay_pdf failed: {e}"))
-        });
-
-        let _ = fs::remove_file(&base_path);
-        let _ = fs::remove_file(&overlay_path);
+        let result = open_document(base_data)
+            .map_err(|e| e.context("Failed to open base PDF"))
+            .and_then(|base_doc| {
+                let overlay_doc = open_document(overlay_data)
+                    .map_err(|e| e.context("Failed to open overlay PDF"))?;
+                oxidize_pdf::operations::PdfOverlay::new(base_doc, overlay_doc)
+                    .apply(&oxidize_pdf::operations::OverlayOptions::default())
+                    .map_err(|e| LastError::caused_by("overlay failed", &e))
+            })
+            .and_then(document_to_bytes);
 
         match result {
             Ok(bytes) => {
@@ -1087,6 +1187,440 @@ pub unsafe extern "C" fn oxidize_overlay_pdf_bytes(
 
 // ── extract images ───────────────────────────────────────────────────────────
 
+/// One image in the `oxidize_extract_images_bytes` result.
+#[derive(serde::Serialize)]
+pub(crate) struct ExtractedImageResult {
+    /// 0-based page index.
+    page_number: usize,
+    image_index: usize,
+    width: u32,
+    height: u32,
+    format: &'static str,
+    /// Image file (JPEG stream as stored, or a PNG re-encoding): base64 in
+    /// JSON, a byte string in binary encodings.
+    #[serde(serialize_with = "crate::encoding::raw_bytes")]
+    data: Vec<u8>,
+}
+
+/// Images smaller than this in either dimension are skipped, matching the
+/// default `ExtractImagesOptions::min_size` of the path-based extractor.
+const MIN_IMAGE_SIZE: u32 = 10;
+
+/// Colour spaces the in-memory extractor can re-encode as PNG.
+enum ImageColorSpace {
+    Gray,
+    Rgb,
+    Cmyk,
+    /// Palette already expanded to RGB triplets.
+    Indexed(Vec<u8>),
+}
+
+impl ImageColorSpace {
+    fn components(&self) -> usize {
+        match self {
+            ImageColorSpace::Gray | ImageColorSpace::Indexed(_) => 1,
+            ImageColorSpace::Rgb => 3,
+            ImageColorSpace::Cmyk => 4,
+        }
+    }
+}
+
+/// Walk every page's `/XObject` resources and collect its image XObjects
+/// entirely in memory. DCT images are returned as stored (`jpeg`); Flate, LZW,
+/// CCITT and uncompressed images are decoded and re-encoded as `png`, with an
+/// 8-bit `/SMask` composited as the alpha channel of 8-bit images. JPX and
+/// JBIG2 images are skipped. Inline images (`BI`…`EI`) in the page content
+/// are encoded the same way and listed after the page's XObjects.
+pub(crate) fn extract_images<R: Read + Seek>(
+    document: &PdfDocument<R>,
+) -> Result<Vec<ExtractedImageResult>, LastError> {
+    let mut images = Vec::new();
+
+    for page_number in 0..page_count(document)? {
+        let page = document.get_page(page_number as u32).map_err(|e| {
+            LastError::caused_by(format_args!("Failed to get page {page_number}"), &e)
+                .at_page(page_number as u32)
+        })?;
+        let resources = document.get_page_resources(&page).map_err(|e| {
+            LastError::caused_by(
+                format_args!("Failed to get resources of page {page_number}"),
+                &e,
+            )
+            .at_page(page_number as u32)
+        })?;
+        let xobjects = resources
+            .and_then(|r| r.get("XObject"))
+            .and_then(|x| document.resolve(x).ok());
+        let mut image_index = 0;
+        let mut push = |image: EncodedImage| {
+            images.push(ExtractedImageResult {
+                page_number,
+                image_index,
+                width: image.width,
+                height: image.height,
+                format: image.format,
+                data: image.data,
+            });
+            image_index += 1;
+        };
+
+        if let Some(xobjects) = xobjects.as_ref().and_then(|x| x.as_dict()) {
+            // Resource dictionaries are unordered; sort by name so
+            // `image_index` is stable across calls.
+            let mut names: Vec<_> = xobjects.0.keys().collect();
+            names.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+            for name in names {
+                let Ok(PdfObject::Stream(stream)) = document.resolve(&xobjects.0[name]) else {
+                    continue;
+                };
+                if let Some(image) = encode_image(document, &stream)? {
+                    push(image);
+                }
+            }
+        }
+
+        // Inline images follow, in content-stream order. Content that does
+        // not parse has no inline images to offer.
+        let Ok(contents) = document.get_page_content_streams(&page) else {
+            continue;
+        };
+        for content in contents {
+            let Ok(operations) = ContentParser::parse_content(&content) else {
+                continue;
+            };
+            for operation in operations {
+                if let ContentOperation::InlineImage { params, data } = operation {
+                    if let Some(image) = encode_image(document, &inline_image_stream(params, data))?
+                    {
+                        push(image);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(images)
+}
+
+/// The image XObject an inline image stands for: its (expanded) parameters
+/// as the stream dictionary and its data as the stream data.
+fn inline_image_stream(params: HashMap<String, Object>, data: Vec<u8>) -> PdfStream {
+    let mut dict = HashMap::with_capacity(params.len() + 1);
+    dict.insert(
+        PdfName("Subtype".into()),
+        PdfObject::Name(PdfName("Image".into())),
+    );
+    for (key, value) in params {
+        let value = match value {
+            Object::Boolean(b) => PdfObject::Boolean(b),
+            Object::Integer(n) => PdfObject::Integer(n),
+            Object::Real(n) => PdfObject::Real(n),
+            Object::Name(name) => PdfObject::Name(PdfName(name)),
+            Object::String(s) => PdfObject::String(PdfString(s.into_bytes())),
+            _ => continue,
+        };
+        dict.insert(PdfName(key), value);
+    }
+    PdfStream {
+        dict: PdfDictionary(dict),
+        data,
+    }
+}
+
+/// An image XObject encoded as a standalone image file.
+struct EncodedImage {
+    width: u32,
+    height: u32,
+    format: &'static str,
+    data: Vec<u8>,
+}
+
+/// Encode an image XObject as a file, or return `None` when the stream is not
+/// an image or cannot be represented.
+fn encode_image<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    stream: &PdfStream,
+) -> Result<Option<EncodedImage>, String> {
+    let dict = &stream.dict;
+    if dict
+        .get("Subtype")
+        .and_then(|s| s.as_name())
+        .map(|n| n.as_str())
+        != Some("Image")
+    {
+        return Ok(None);
+    }
+    let dimension = |key: &str| {
+        dict.get(key)
+            .and_then(|v| document.resolve(v).ok())
+            .and_then(|v| v.as_integer())
+            .and_then(|v| u32::try_from(v).ok())
+    };
+    let (Some(width), Some(height)) = (dimension("Width"), dimension("Height")) else {
+        return Ok(None);
+    };
+    if width < MIN_IMAGE_SIZE || height < MIN_IMAGE_SIZE {
+        return Ok(None);
+    }
+
+    let filter = match dict.get("Filter") {
+        Some(PdfObject::Name(name)) => Some(name.as_str().to_string()),
+        Some(PdfObject::Array(filters)) => filters
+            .0
+            .first()
+            .and_then(|f| f.as_name())
+            .map(|n| n.as_str().to_string()),
+        _ => None,
+    };
+    match filter.as_deref() {
+        Some("DCTDecode") => {
+            return Ok(Some(EncodedImage {
+                width,
+                height,
+                format: "jpeg",
+                data: stream.data.clone(),
+            }))
+        }
+        None | Some("FlateDecode" | "LZWDecode" | "CCITTFaxDecode" | "RunLengthDecode") => {}
+        Some(_) => return Ok(None),
+    }
+
+    let image_mask = dict.get("ImageMask").and_then(|m| m.as_bool()) == Some(true);
+    let bits = if image_mask {
+        1
+    } else {
+        dict.get("BitsPerComponent")
+            .and_then(|b| b.as_integer())
+            .unwrap_or(8) as u8
+    };
+    let data = stream
+        .decode(&document.options())
+        .map_err(|e| format!("Failed to decode image stream: {e}"))?;
+
+    let color_space = if image_mask {
+        Some(ImageColorSpace::Gray)
+    } else {
+        dict.get("ColorSpace")
+            .and_then(|cs| image_color_space(document, cs))
+    };
+    // Without a recognised colour space, infer the component count from the
+    // sample data so the image is still extracted.
+    let color_space = match color_space {
+        Some(cs) => cs,
+        None => {
+            let samples = (width as usize * height as usize * bits as usize).div_ceil(8);
+            match data.len().checked_div(samples) {
+                Some(1) => ImageColorSpace::Gray,
+                Some(3) => ImageColorSpace::Rgb,
+                Some(4) => ImageColorSpace::Cmyk,
+                _ => return Ok(None),
+            }
+        }
+    };
+
+    let row_len = (width as usize * color_space.components() * bits as usize).div_ceil(8);
+    let expected = row_len * height as usize;
+    if data.len() < expected {
+        return Err(format!(
+            "Image data too small: expected {expected}, got {}",
+            data.len()
+        ));
+    }
+    let samples = &data[..expected];
+
+    let alpha = soft_mask_alpha(document, dict, width, height);
+    let png = match (&color_space, bits, alpha.as_deref()) {
+        (_, 8, Some(alpha)) => {
+            let rgba = composite_alpha(&color_space, samples, alpha);
+            encode_png(width, height, png::ColorType::Rgba, 8, None, &rgba)?
+        }
+        (ImageColorSpace::Gray, 1 | 2 | 4 | 8 | 16, _) => encode_png(
+            width,
+            height,
+            png::ColorType::Grayscale,
+            bits,
+            None,
+            samples,
+        )?,
+        (ImageColorSpace::Rgb, 8 | 16, _) => {
+            encode_png(width, height, png::ColorType::Rgb, bits, None, samples)?
+        }
+        (ImageColorSpace::Cmyk, 8 | 16, _) => {
+            let step = if bits == 16 { 2 } else { 1 };
+            let cmyk: Vec<u8> = samples.iter().step_by(step).copied().collect();
+            let rgb: Vec<u8> = cmyk.chunks_exact(4).flat_map(cmyk_to_rgb).collect();
+            encode_png(width, height, png::ColorType::Rgb, 8, None, &rgb)?
+        }
+        (ImageColorSpace::Indexed(palette), 1 | 2 | 4 | 8, _) => encode_png(
+            width,
+            height,
+            png::ColorType::Indexed,
+            bits,
+            Some(palette),
+            samples,
+        )?,
+        _ => return Ok(None),
+    };
+    Ok(Some(EncodedImage {
+        width,
+        height,
+        format: "png",
+        data: png,
+    }))
+}
+
+/// Decode the `/SMask` of an image into one 8-bit alpha sample per pixel,
+/// nearest-neighbour resized to `width`×`height`. Returns `None` without a
+/// soft mask or when it is not a plain 8-bit gray raster, in which case the
+/// image is encoded without alpha.
+fn soft_mask_alpha<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    dict: &PdfDictionary,
+    width: u32,
+    height: u32,
+) -> Option<Vec<u8>> {
+    let PdfObject::Stream(mask) = document.resolve(dict.get("SMask")?).ok()? else {
+        return None;
+    };
+    let dimension = |key: &str| {
+        let value = document.resolve(mask.dict.get(key)?).ok()?.as_integer()?;
+        usize::try_from(value).ok().filter(|&v| v > 0)
+    };
+    let (mask_width, mask_height) = (dimension("Width")?, dimension("Height")?);
+    if mask
+        .dict
+        .get("BitsPerComponent")
+        .and_then(|b| b.as_integer())
+        != Some(8)
+    {
+        return None;
+    }
+    let gray = mask.decode(&document.options()).ok()?;
+    let gray = gray.get(..mask_width.checked_mul(mask_height)?)?;
+
+    let (width, height) = (width as usize, height as usize);
+    if (mask_width, mask_height) == (width, height) {
+        return Some(gray.to_vec());
+    }
+    let mut alpha = Vec::with_capacity(width * height);
+    for y in 0..height {
+        let row = y * mask_height / height * mask_width;
+        alpha.extend((0..width).map(|x| gray[row + x * mask_width / width]));
+    }
+    Some(alpha)
+}
+
+/// Expand 8-bit `samples` to RGBA with one `alpha` sample per pixel.
+fn composite_alpha(color_space: &ImageColorSpace, samples: &[u8], alpha: &[u8]) -> Vec<u8> {
+    let components = color_space.components();
+    let mut rgba = Vec::with_capacity(alpha.len() * 4);
+    for (pixel, &a) in samples.chunks_exact(components).zip(alpha) {
+        let [r, g, b] = match color_space {
+            ImageColorSpace::Gray => [pixel[0]; 3],
+            ImageColorSpace::Rgb => [pixel[0], pixel[1], pixel[2]],
+            ImageColorSpace::Cmyk => cmyk_to_rgb(pixel),
+            ImageColorSpace::Indexed(palette) => {
+                let entry = pixel[0] as usize * 3;
+                palette
+                    .get(entry..entry + 3)
+                    .map_or([0; 3], |rgb| [rgb[0], rgb[1], rgb[2]])
+            }
+        };
+        rgba.extend_from_slice(&[r, g, b, a]);
+    }
+    rgba
+}
+
+/// Map a PDF colour space object onto [`ImageColorSpace`].
+fn image_color_space<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    color_space: &PdfObject,
+) -> Option<ImageColorSpace> {
+    let resolved = document.resolve(color_space).ok()?;
+    match &resolved {
+        PdfObject::Name(name) => match name.as_str() {
+            "DeviceGray" | "CalGray" | "G" => Some(ImageColorSpace::Gray),
+            "DeviceRGB" | "CalRGB" | "RGB" => Some(ImageColorSpace::Rgb),
+            "DeviceCMYK" | "CMYK" => Some(ImageColorSpace::Cmyk),
+            _ => None,
+        },
+        PdfObject::Array(parts) => {
+            let family = parts.0.first()?.as_name()?.as_str();
+            match family {
+                "CalGray" => Some(ImageColorSpace::Gray),
+                "CalRGB" => Some(ImageColorSpace::Rgb),
+                "ICCBased" => {
+                    let profile = document.resolve(parts.0.get(1)?).ok()?;
+                    let n = profile.as_stream()?.dict.get("N")?.as_integer()?;
+                    match n {
+                        1 => Some(ImageColorSpace::Gray),
+                        3 => Some(ImageColorSpace::Rgb),
+                        4 => Some(ImageColorSpace::Cmyk),
+                        _ => None,
+                    }
+                }
+                "Indexed" | "I" => {
+                    let base = image_color_space(document, parts.0.get(1)?)?;
+                    let hival = document.resolve(parts.0.get(2)?).ok()?.as_integer()?;
+                    let lookup = match document.resolve(parts.0.get(3)?).ok()? {
+                        PdfObject::String(s) => s.as_bytes().to_vec(),
+                        PdfObject::Stream(s) => s.decode(&document.options()).ok()?,
+                        _ => return None,
+                    };
+                    let entries = (hival.clamp(0, 255) + 1) as usize;
+                    let width = base.components();
+                    let palette: Vec<u8> = lookup
+                        .chunks_exact(width)
+                        .take(entries)
+                        .flat_map(|entry| match base {
+                            ImageColorSpace::Gray => [entry[0]; 3],
+                            ImageColorSpace::Cmyk => cmyk_to_rgb(entry),
+                            _ => [entry[0], entry[1], entry[2]],
+                        })
+                        .collect();
+                    match base {
+                        ImageColorSpace::Indexed(_) => None,
+                        _ => Some(ImageColorSpace::Indexed(palette)),
+                    }
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Naive CMYK → RGB conversion (no colour management).
+fn cmyk_to_rgb(cmyk: &[u8]) -> [u8; 3] {
+    let k = cmyk[3] as u16;
+    let channel = |c: u8| 255 - (c as u16 + k).min(255) as u8;
+    [channel(cmyk[0]), channel(cmyk[1]), channel(cmyk[2])]
+}
+
+fn encode_png(
+    width: u32,
+    height: u32,
+    color: png::ColorType,
+    bits: u8,
+    palette: Option<&[u8]>,
+    samples: &[u8],
+) -> Result<Vec<u8>, String> {
+    let depth =
+        png::BitDepth::from_u8(bits).ok_or_else(|| format!("Unsupported bit depth {bits}"))?;
+    let mut out = Vec::new();
+    let mut encoder = png::Encoder::new(&mut out, width, height);
+    encoder.set_color(color);
+    encoder.set_depth(depth);
+    if let Some(palette) = palette {
+        encoder.set_palette(palette);
+    }
+    encoder
+        .write_header()
+        .and_then(|mut writer| writer.write_image_data(samples))
+        .map_err(|e| format!("Failed to encode PNG: {e}"))?;
+    Ok(out)
+}
+
 /// Extract all images from a PDF (supplied as bytes).
 ///
 /// On success, `out_json` receives a null-terminated JSON array of image objects:
@@ -1117,54 +1651,12 @@ pub unsafe extern "C" fn oxidize_extract_images_bytes(
 
         let input_bytes = std::slice::from_raw_parts(pdf_bytes, pdf_len);
 
-        let result = with_temp_input(input_bytes, |input_path| {
-            let ts = SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .map(|d| d.as_nanos())
-                .unwrap_or(0);
-            let tid = std::thread::current().id();
-            let out_dir = std::env::temp_dir().join(format!("oxidize_imgs_{ts}_{tid:?}"));
-
-            let options = oxidize_pdf::operations::ExtractImagesOptions {
-                output_dir: out_dir.clone(),
-                create_dir: true,
-                ..Default::default()
-            };
-
-            let images = oxidize_pdf::operations::extract_images_from_pdf(input_path, options)
-                .map_err(|e| format!("extract_images_from_pdf failed: {e}"))?;
-
-            let mut json_items: Vec<String> = Vec::with_capacity(images.len());
-            for img in &images {
-                let img_bytes = match fs::read(&img.file_path) {
-                    Ok(b) => b,
-                    Err(e) => {
-                        let _ = fs::remove_dir_all(&out_dir);
-                        return Err(format!("Failed to read extracted image: {e}"));
-                    }
-                };
-                let data_b64 = base64::engine::general_purpose::STANDARD.encode(&img_bytes);
-                let format_str = match img.format {
-                    oxidize_pdf::ImageFormat::Jpeg => "jpeg",
-                    oxidize_pdf::ImageFormat::Png => "png",
-                    oxidize_pdf::ImageFormat::Tiff => "tiff",
-                    oxidize_pdf::ImageFormat::Raw => "raw",
-                };
-                json_items.push(format!(
-                r#"{{"page_number":{page},"image_index":{idx},"width":{w},"height":{h},"format":"{fmt}","data":"{b64}"}}"#,
-                page = img.page_number,
-                idx = img.image_index,
-                w = img.width,
-                h = img.height,
-                fmt = format_str,
-                b64 = data_b64,
-            ));
-            }
-
-            let _ = fs::remove_dir_all(&out_dir);
-
-            Ok(format!("[{}]", json_items.join(",")))
-        });
+        let result = open_document(input_bytes)
+            .and_then(|document| extract_images(&document))
+            .and_then(|images| {
+                serde_json::to_string(&images)
+                    .map_err(|e| LastError::caused_by("JSON serialization failed", &e))
+            });
 
         match result {
             Ok(json) => match CString::new(json) {
@@ -1184,3 +1676,701 @@ pub unsafe extern "C" fn oxidize_extract_images_bytes(
         }
     })
 }
+
+// ── Password variants ────────────────────────────────────────────────────────
+//
+// The page operations with a `password` argument after the input: an
+// encrypted input is unlocked with it (user or owner password) before the
+// pages are copied, as by `crate::parser::with_password`; an overlay unlocks
+// whichever of its two inputs is encrypted. The output is not encrypted.
+
+/// [`oxidize_split_pdf_bytes`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_split_pdf_bytes`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_split_pdf_bytes_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    with_password(password, || {
+        oxidize_split_pdf_bytes(pdf_bytes, pdf_len, out_json)
+    })
+}
+
+/// [`oxidize_split_pdf_bytes_with_options`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_split_pdf_bytes_with_options`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_split_pdf_bytes_with_options_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    options_json: *const c_char,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    with_password(password, || {
+        oxidize_split_pdf_bytes_with_options(pdf_bytes, pdf_len, options_json, out_json)
+    })
+}
+
+/// [`oxidize_rotate_pdf_bytes`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_rotate_pdf_bytes`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_rotate_pdf_bytes_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    degrees: c_int,
+    out_bytes: *mut *mut u8,
+    out_len: *mut usize,
+) -> c_int {
+    with_password(password, || {
+        oxidize_rotate_pdf_bytes(pdf_bytes, pdf_len, degrees, out_bytes, out_len)
+    })
+}
+
+/// [`oxidize_rotate_pages_bytes`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_rotate_pages_bytes`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_rotate_pages_bytes_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    degrees: c_int,
+    pages_json: *const c_char,
+    out_bytes: *mut *mut u8,
+    out_len: *mut usize,
+) -> c_int {
+    with_password(password, || {
+        oxidize_rotate_pages_bytes(pdf_bytes, pdf_len, degrees, pages_json, out_bytes, out_len)
+    })
+}
+
+/// [`oxidize_extract_pages_bytes`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_extract_pages_bytes`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_extract_pages_bytes_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    pages_json: *const c_char,
+    out_bytes: *mut *mut u8,
+    out_len: *mut usize,
+) -> c_int {
+    with_password(password, || {
+        oxidize_extract_pages_bytes(pdf_bytes, pdf_len, pages_json, out_bytes, out_len)
+    })
+}
+
+/// [`oxidize_reorder_pages_bytes`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_reorder_pages_bytes`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_reorder_pages_bytes_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    order_json: *const c_char,
+    out_bytes: *mut *mut u8,
+    out_len: *mut usize,
+) -> c_int {
+    with_password(password, || {
+        oxidize_reorder_pages_bytes(pdf_bytes, pdf_len, order_json, out_bytes, out_len)
+    })
+}
+
+/// [`oxidize_swap_pages_bytes`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_swap_pages_bytes`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_swap_pages_bytes_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    page_a: usize,
+    page_b: usize,
+    out_bytes: *mut *mut u8,
+    out_len: *mut usize,
+) -> c_int {
+    with_password(password, || {
+        oxidize_swap_pages_bytes(pdf_bytes, pdf_len, page_a, page_b, out_bytes, out_len)
+    })
+}
+
+/// [`oxidize_move_page_bytes`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_move_page_bytes`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_move_page_bytes_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    from_index: usize,
+    to_index: usize,
+    out_bytes: *mut *mut u8,
+    out_len: *mut usize,
+) -> c_int {
+    with_password(password, || {
+        oxidize_move_page_bytes(pdf_bytes, pdf_len, from_index, to_index, out_bytes, out_len)
+    })
+}
+
+/// [`oxidize_reverse_pages_bytes`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_reverse_pages_bytes`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_reverse_pages_bytes_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    out_bytes: *mut *mut u8,
+    out_len: *mut usize,
+) -> c_int {
+    with_password(password, || {
+        oxidize_reverse_pages_bytes(pdf_bytes, pdf_len, out_bytes, out_len)
+    })
+}
+
+/// [`oxidize_overlay_pdf_bytes`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_overlay_pdf_bytes`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_overlay_pdf_bytes_with_password(
+    base_bytes: *const u8,
+    base_len: usize,
+    overlay_bytes: *const u8,
+    overlay_len: usize,
+    password: *const c_char,
+    out_bytes: *mut *mut u8,
+    out_len: *mut usize,
+) -> c_int {
+    with_password(password, || {
+        oxidize_overlay_pdf_bytes(
+            base_bytes,
+            base_len,
+            overlay_bytes,
+            overlay_len,
+            out_bytes,
+            out_len,
+        )
+    })
+}
+
+/// [`oxidize_extract_images_bytes`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_extract_images_bytes`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_extract_images_bytes_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    with_password(password, || {
+        oxidize_extract_images_bytes(pdf_bytes, pdf_len, out_json)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::SamplePdf;
+
+    /// Pages headed `Marker page {n}`, so reordering shows in the text.
+    fn marked(pages: usize) -> SamplePdf {
+        SamplePdf::new(pages)
+            .heading("Marker page")
+            .title("Operations Fixture")
+    }
+
+    /// Page markers in document order, e.g. `[1, 3, 2]`.
+    fn markers(pdf: &[u8]) -> Vec<usize> {
+        let document = open_document(pdf).unwrap();
+        (0..page_count(&document).unwrap())
+            .map(|i| {
+                let text = document.extract_text_from_page(i as u32).unwrap().text;
+                let tail = text.split("Marker page").nth(1).unwrap();
+                tail.split_whitespace().next().unwrap().parse().unwrap()
+            })
+            .collect()
+    }
+
+    unsafe fn take_bytes(ptr: *mut u8, len: usize) -> Vec<u8> {
+        let bytes = std::slice::from_raw_parts(ptr, len).to_vec();
+        crate::oxidize_free_bytes(ptr, len);
+        bytes
+    }
+
+    unsafe fn take_json(ptr: *mut c_char) -> serde_json::Value {
+        let value = serde_json::from_str(CStr::from_ptr(ptr).to_str().unwrap()).unwrap();
+        crate::oxidize_free_string(ptr);
+        value
+    }
+
+    fn decode_parts(json: &serde_json::Value) -> Vec<Vec<u8>> {
+        json.as_array()
+            .unwrap()
+            .iter()
+            .map(|p| {
+                base64::engine::general_purpose::STANDARD
+                    .decode(p.as_str().unwrap())
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    unsafe fn run_bytes_op(
+        op: impl FnOnce(*mut *mut u8, *mut usize) -> c_int,
+    ) -> Result<Vec<u8>, c_int> {
+        let mut out: *mut u8 = ptr::null_mut();
+        let mut len = 0usize;
+        let code = op(&mut out, &mut len);
+        if code == ErrorCode::Success as c_int {
+            Ok(take_bytes(out, len))
+        } else {
+            assert!(out.is_null());
+            Err(code)
+        }
+    }
+
+    #[test]
+    fn split_returns_one_pdf_per_page() {
+        let pdf = marked(3).build();
+        unsafe {
+            let mut out: *mut c_char = ptr::null_mut();
+            let code = oxidize_split_pdf_bytes(pdf.as_ptr(), pdf.len(), &mut out);
+            assert_eq!(code, ErrorCode::Success as c_int);
+            let parts = decode_parts(&take_json(out));
+            assert_eq!(parts.len(), 3);
+            for (i, part) in parts.iter().enumerate() {
+                assert_eq!(markers(part), vec![i + 1]);
+            }
+        }
+    }
+
+    #[test]
+    fn split_with_options_chunks_and_rejects_zero_chunk_size() {
+        let pdf = marked(3).build();
+        unsafe {
+            let opts = CString::new(r#"{"mode":"ChunkSize","chunk_size":2}"#).unwrap();
+            let mut out: *mut c_char = ptr::null_mut();
+            let code = oxidize_split_pdf_bytes_with_options(
+                pdf.as_ptr(),
+                pdf.len(),
+                opts.as_ptr(),
+                &mut out,
+            );
+            assert_eq!(code, ErrorCode::Success as c_int);
+            let parts = decode_parts(&take_json(out));
+            assert_eq!(markers(&parts[0]), vec![1, 2]);
+            assert_eq!(markers(&parts[1]), vec![3]);
+
+            let opts = CString::new(r#"{"mode":"SplitAt","split_at":[1]}"#).unwrap();
+            let code = oxidize_split_pdf_bytes_with_options(
+                pdf.as_ptr(),
+                pdf.len(),
+                opts.as_ptr(),
+                &mut out,
+            );
+            assert_eq!(code, ErrorCode::Success as c_int);
+            let parts = decode_parts(&take_json(out));
+            assert_eq!(markers(&parts[0]), vec![1]);
+            assert_eq!(markers(&parts[1]), vec![2, 3]);
+
+            let opts = CString::new(r#"{"mode":"ChunkSize","chunk_size":0}"#).unwrap();
+            let code = oxidize_split_pdf_bytes_with_options(
+                pdf.as_ptr(),
+                pdf.len(),
+                opts.as_ptr(),
+                &mut out,
+            );
+            assert_eq!(code, ErrorCode::IoError as c_int);
+            assert!(out.is_null());
+        }
+    }
+
+    #[test]
+    fn merge_concatenates_inputs_and_honours_ranges() {
+        let a = base64::engine::general_purpose::STANDARD.encode(marked(2).build());
+        let b = base64::engine::general_purpose::STANDARD.encode(marked(3).build());
+        unsafe {
+            let json = CString::new(serde_json::to_string(&[&a, &b]).unwrap()).unwrap();
+            let merged =
+                run_bytes_op(|o, l| oxidize_merge_pdfs_bytes(json.as_ptr(), o, l)).unwrap();
+            assert_eq!(markers(&merged), vec![1, 2, 1, 2, 3]);
+
+            let json = CString::new(format!(
+                r#"[{{"pdf":"{a}","pages":{{"kind":"Single","index":1}}}},{{"pdf":"{b}","pages":{{"kind":"List","indices":[2,0]}}}}]"#
+            ))
+            .unwrap();
+            let merged =
+                run_bytes_op(|o, l| oxidize_merge_pdfs_with_ranges(json.as_ptr(), o, l)).unwrap();
+            assert_eq!(markers(&merged), vec![2, 3, 1]);
+
+            let json = CString::new(format!(
+                r#"[{{"pdf":"{a}","pages":{{"kind":"Single","index":5}}}}]"#
+            ))
+            .unwrap();
+            let code = run_bytes_op(|o, l| oxidize_merge_pdfs_with_ranges(json.as_ptr(), o, l));
+            assert_eq!(code, Err(ErrorCode::PageOutOfRange as c_int));
+        }
+    }
+
+    #[test]
+    fn encrypted_inputs_open_with_password() {
+        let pdf = marked(2).encrypted("user-secret").build();
+        unsafe {
+            let code =
+                run_bytes_op(|o, l| oxidize_reverse_pages_bytes(pdf.as_ptr(), pdf.len(), o, l));
+            assert_eq!(code, Err(ErrorCode::PasswordRequired as c_int));
+            let wrong = CString::new("nope").unwrap();
+            let code = run_bytes_op(|o, l| {
+                oxidize_reverse_pages_bytes_with_password(
+                    pdf.as_ptr(),
+                    pdf.len(),
+                    wrong.as_ptr(),
+                    o,
+                    l,
+                )
+            });
+            assert_eq!(code, Err(ErrorCode::PasswordRequired as c_int));
+
+            let password = CString::new("user-secret").unwrap();
+            let reversed = run_bytes_op(|o, l| {
+                oxidize_reverse_pages_bytes_with_password(
+                    pdf.as_ptr(),
+                    pdf.len(),
+                    password.as_ptr(),
+                    o,
+                    l,
+                )
+            })
+            .unwrap();
+            assert_eq!(markers(&reversed), vec![2, 1]);
+
+            let pages = CString::new("[1]").unwrap();
+            let extracted = run_bytes_op(|o, l| {
+                oxidize_extract_pages_bytes_with_password(
+                    pdf.as_ptr(),
+                    pdf.len(),
+                    password.as_ptr(),
+                    pages.as_ptr(),
+                    o,
+                    l,
+                )
+            })
+            .unwrap();
+            assert_eq!(markers(&extracted), vec![2]);
+
+            // Each encrypted input of an overlay is unlocked.
+            let plain = marked(2).build();
+            let overlaid = run_bytes_op(|o, l| {
+                oxidize_overlay_pdf_bytes_with_password(
+                    plain.as_ptr(),
+                    plain.len(),
+                    pdf.as_ptr(),
+                    pdf.len(),
+                    password.as_ptr(),
+                    o,
+                    l,
+                )
+            });
+            assert!(overlaid.is_ok());
+
+            let b64 = base64::engine::general_purpose::STANDARD.encode(&pdf);
+            let json = CString::new(format!(
+                r#"[{{"pdf":"{b64}","pages":null,"password":"owner-secret"}}]"#
+            ))
+            .unwrap();
+            let merged =
+                run_bytes_op(|o, l| oxidize_merge_pdfs_with_ranges(json.as_ptr(), o, l)).unwrap();
+            assert_eq!(markers(&merged), vec![1, 2]);
+        }
+    }
+
+    #[test]
+    fn page_order_operations_produce_expected_order() {
+        let pdf = marked(4).build();
+        unsafe {
+            let pages = CString::new("[3,1]").unwrap();
+            let out = run_bytes_op(|o, l| {
+                oxidize_extract_pages_bytes(pdf.as_ptr(), pdf.len(), pages.as_ptr(), o, l)
+            })
+            .unwrap();
+            assert_eq!(markers(&out), vec![4, 2]);
+
+            let order = CString::new("[2,0,3,1]").unwrap();
+            let out = run_bytes_op(|o, l| {
+                oxidize_reorder_pages_bytes(pdf.as_ptr(), pdf.len(), order.as_ptr(), o, l)
+            })
+            .unwrap();
+            assert_eq!(markers(&out), vec![3, 1, 4, 2]);
+
+            let out =
+                run_bytes_op(|o, l| oxidize_swap_pages_bytes(pdf.as_ptr(), pdf.len(), 0, 3, o, l))
+                    .unwrap();
+            assert_eq!(markers(&out), vec![4, 2, 3, 1]);
+
+            let out =
+                run_bytes_op(|o, l| oxidize_move_page_bytes(pdf.as_ptr(), pdf.len(), 0, 2, o, l))
+                    .unwrap();
+            assert_eq!(markers(&out), vec![2, 3, 1, 4]);
+
+            let out =
+                run_bytes_op(|o, l| oxidize_reverse_pages_bytes(pdf.as_ptr(), pdf.len(), o, l))
+                    .unwrap();
+            assert_eq!(markers(&out), vec![4, 3, 2, 1]);
+        }
+    }
+
+    #[test]
+    fn page_order_operations_reject_out_of_bounds_indices() {
+        let pdf = marked(2).build();
+        unsafe {
+            let pages = CString::new("[0,7]").unwrap();
+            let code = run_bytes_op(|o, l| {
+                oxidize_extract_pages_bytes(pdf.as_ptr(), pdf.len(), pages.as_ptr(), o, l)
+            });
+            assert_eq!(code, Err(ErrorCode::PageOutOfRange as c_int));
+
+            let code =
+                run_bytes_op(|o, l| oxidize_swap_pages_bytes(pdf.as_ptr(), pdf.len(), 0, 2, o, l));
+            assert_eq!(code, Err(ErrorCode::PageOutOfRange as c_int));
+
+            let code =
+                run_bytes_op(|o, l| oxidize_move_page_bytes(pdf.as_ptr(), pdf.len(), 5, 0, o, l));
+            assert_eq!(code, Err(ErrorCode::PageOutOfRange as c_int));
+        }
+    }
+
+    #[test]
+    fn rotate_turns_the_media_box_and_content_of_selected_pages() {
+        let pdf = marked(3).build();
+        let boxes = |bytes: &[u8]| {
+            let document = open_document(bytes).unwrap();
+            (0..3)
+                .map(|i| {
+                    let page = document.get_page(i).unwrap();
+                    assert_eq!(page.rotation, 0, "/Rotate is left as is");
+                    [page.media_box[2], page.media_box[3]]
+                })
+                .collect::<Vec<_>>()
+        };
+        let (portrait, landscape) = ([595.0, 842.0], [842.0, 595.0]);
+        unsafe {
+            let out =
+                run_bytes_op(|o, l| oxidize_rotate_pdf_bytes(pdf.as_ptr(), pdf.len(), 90, o, l))
+                    .unwrap();
+            assert_eq!(boxes(&out), vec![landscape; 3]);
+            assert_eq!(markers(&out), vec![1, 2, 3]);
+            let rotated = open_document(&out).unwrap();
+            let content = rotated
+                .get_page_content_streams(&rotated.get_page(0).unwrap())
+                .unwrap()
+                .concat();
+            let content = String::from_utf8_lossy(&content);
+            assert!(
+                content
+                    .trim_start()
+                    .starts_with("0.00 1.00 -1.00 0.00 842.00 0.00 cm"),
+                "{content}"
+            );
+
+            let pages = CString::new(r#"{"kind":"Single","index":1}"#).unwrap();
+            let out = run_bytes_op(|o, l| {
+                oxidize_rotate_pages_bytes(pdf.as_ptr(), pdf.len(), 270, pages.as_ptr(), o, l)
+            })
+            .unwrap();
+            assert_eq!(boxes(&out), vec![portrait, landscape, portrait]);
+            assert_eq!(markers(&out), vec![1, 2, 3]);
+
+            let code =
+                run_bytes_op(|o, l| oxidize_rotate_pdf_bytes(pdf.as_ptr(), pdf.len(), 45, o, l));
+            assert_eq!(code, Err(ErrorCode::PdfParseError as c_int));
+        }
+    }
+
+    #[test]
+    fn overlay_keeps_base_page_count() {
+        let base = marked(2).build();
+        let overlay = marked(1).build();
+        unsafe {
+            let out = run_bytes_op(|o, l| {
+                oxidize_overlay_pdf_bytes(
+                    base.as_ptr(),
+                    base.len(),
+                    overlay.as_ptr(),
+                    overlay.len(),
+                    o,
+                    l,
+                )
+            })
+            .unwrap();
+            let document = open_document(&out).unwrap();
+            assert_eq!(page_count(&document).unwrap(), 2);
+        }
+    }
+
+    #[test]
+    fn extract_images_reencodes_raw_images_as_png() {
+        use oxidize_pdf::{Image, Page};
+        let mut doc = Document::new();
+        let mut page = Page::a4();
+        let gray: Vec<u8> = (0..16 * 12).map(|i| (i % 256) as u8).collect();
+        page.add_image("Im1", Image::from_gray_data(gray, 16, 12).unwrap());
+        page.draw_image("Im1", 50.0, 500.0, 160.0, 120.0).unwrap();
+        doc.add_page(page);
+        doc.add_page(Page::a4());
+        let pdf = doc.to_bytes().unwrap();
+
+        unsafe {
+            let mut out: *mut c_char = ptr::null_mut();
+            let code = oxidize_extract_images_bytes(pdf.as_ptr(), pdf.len(), &mut out);
+            assert_eq!(code, ErrorCode::Success as c_int);
+            let images = take_json(out);
+            let images = images.as_array().unwrap();
+            assert_eq!(images.len(), 1);
+            assert_eq!(images[0]["page_number"], 0);
+            assert_eq!(images[0]["width"], 16);
+            assert_eq!(images[0]["height"], 12);
+            assert_eq!(images[0]["format"], "png");
+            let data = base64::engine::general_purpose::STANDARD
+                .decode(images[0]["data"].as_str().unwrap())
+                .unwrap();
+            assert!(data.starts_with(b"\x89PNG"));
+        }
+    }
+
+    #[test]
+    fn extract_images_includes_inline_images() {
+        let gray: Vec<u8> = (0..12 * 12).map(|i| 0x80 + (i % 64) as u8).collect();
+        let mut content = b"q 120 0 0 120 50 500 cm\nBI /W 12 /H 12 /CS /G /BPC 8 ID\n".to_vec();
+        content.extend_from_slice(&gray);
+        content.extend_from_slice(b"\nEI Q\n");
+        let mut stream = format!("<< /Length {} >>\nstream\n", content.len()).into_bytes();
+        stream.extend_from_slice(&content);
+        stream.extend_from_slice(b"\nendstream");
+        let pdf = crate::test_fixtures::raw_pdf(&[
+            b"<< /Type /Catalog /Pages 2 0 R >>".to_vec(),
+            b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_vec(),
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] /Contents 4 0 R >>".to_vec(),
+            stream,
+        ]);
+
+        let document = open_document(&pdf).unwrap();
+        let images = extract_images(&document).unwrap();
+        assert_eq!(images.len(), 1);
+        assert_eq!((images[0].page_number, images[0].image_index), (0, 0));
+        assert_eq!((images[0].width, images[0].height), (12, 12));
+        assert_eq!(images[0].format, "png");
+
+        let decoder = png::Decoder::new(std::io::Cursor::new(&images[0].data));
+        let mut reader = decoder.read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size().unwrap()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+        assert_eq!(info.color_type, png::ColorType::Grayscale);
+        assert_eq!(&pixels[..info.buffer_size()], &gray[..]);
+    }
+
+    #[test]
+    fn extract_images_composites_soft_masks_as_alpha() {
+        use oxidize_pdf::{Image, Page};
+        let (width, height) = (16u32, 12u32);
+        let rgba: Vec<u8> = (0..width * height)
+            .flat_map(|i| [200, 100, 50, (i * 255 / (width * height - 1)) as u8])
+            .collect();
+        let mut doc = Document::new();
+        let mut page = Page::a4();
+        page.add_image(
+            "Im1",
+            Image::from_rgba_data(rgba.clone(), width, height).unwrap(),
+        );
+        page.draw_image("Im1", 50.0, 500.0, 160.0, 120.0).unwrap();
+        doc.add_page(page);
+        let pdf = doc.to_bytes().unwrap();
+
+        let document = open_document(&pdf).unwrap();
+        let images = extract_images(&document).unwrap();
+        // The soft mask itself is not listed as a separate image.
+        assert_eq!(images.len(), 1);
+        assert_eq!(images[0].format, "png");
+
+        let decoder = png::Decoder::new(std::io::Cursor::new(&images[0].data));
+        let mut reader = decoder.read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size().unwrap()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+        assert_eq!(info.color_type, png::ColorType::Rgba);
+        assert_eq!(&pixels[..info.buffer_size()], &rgba[..]);
+    }
+
+    #[test]
+    fn operations_do_not_create_temp_files() {
+        let staged = || {
+            std::fs::read_dir(std::env::temp_dir())
+                .map(|entries| {
+                    entries
+                        .filter_map(Result::ok)
+                        .filter(|e| e.file_name().to_string_lossy().starts_with("oxidize_"))
+                        .count()
+                })
+                .unwrap_or(0)
+        };
+        let before = staged();
+        let pdf = marked(2).build();
+        unsafe {
+            let mut out: *mut c_char = ptr::null_mut();
+            assert_eq!(
+                oxidize_split_pdf_bytes(pdf.as_ptr(), pdf.len(), &mut out),
+                ErrorCode::Success as c_int
+            );
+            crate::oxidize_free_string(out);
+            run_bytes_op(|o, l| oxidize_reverse_pages_bytes(pdf.as_ptr(), pdf.len(), o, l))
+                .unwrap();
+        }
+        assert_eq!(staged(), before);
+    }
+
+    #[test]
+    fn invalid_input_reports_errors() {
+        let garbage = b"not a pdf at all";
+        unsafe {
+            let mut out: *mut c_char = ptr::null_mut();
+            let code = oxidize_split_pdf_bytes(garbage.as_ptr(), garbage.len(), &mut out);
+            assert_eq!(code, ErrorCode::IoError as c_int);
+            assert!(out.is_null());
+
+            let code = oxidize_split_pdf_bytes(garbage.as_ptr(), 0, &mut out);
+            assert_eq!(code, ErrorCode::PdfParseError as c_int);
+
+            let code = oxidize_split_pdf_bytes(ptr::null(), 1, &mut out);
+            assert_eq!(code, ErrorCode::NullPointer as c_int);
+        }
+    }
+}
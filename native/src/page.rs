@@ -1,5 +1,5 @@
 use std::ffi::CStr;
-use std::io::Cursor;
+use std::io::{Cursor, Read, Seek};
 use std::os::raw::{c_char, c_int};
 use std::slice;
 
@@ -9,6 +9,9 @@ use crate::types::PagePreset;
 use crate::{clear_last_error, set_last_error, ErrorCode};
 
 /// Opaque handle wrapping an `oxidize_pdf::Page`.
+///
+/// Not thread-safe: a handle may move between threads but must be used by
+/// one thread at a time.
 pub struct PageHandle {
     pub(crate) inner: oxidize_pdf::Page,
 }
@@ -54,29 +57,34 @@ pub unsafe extern "C" fn oxidize_page_from_parsed_bytes(
             }
         };
         let document: PdfDocument<Cursor<&[u8]>> = PdfDocument::new(reader);
+        page_from_parsed_impl(&document, page_index, "oxidize_page_from_parsed_bytes")
+    })
+}
 
-        let parsed = match document.get_page(page_index) {
-            Ok(p) => p,
-            Err(e) => {
-                set_last_error(format!(
-                    "oxidize_page_from_parsed_bytes: page {page_index} not found: {e}"
-                ));
-                return std::ptr::null_mut();
-            }
-        };
+/// Body of [`oxidize_page_from_parsed_bytes`] over an already-opened
+/// document; `fn_name` prefixes the recorded error messages.
+pub(crate) fn page_from_parsed_impl<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    page_index: u32,
+    fn_name: &str,
+) -> *mut PageHandle {
+    let parsed = match document.get_page(page_index) {
+        Ok(p) => p,
+        Err(e) => {
+            set_last_error(format!("{fn_name}: page {page_index} not found: {e}"));
+            return std::ptr::null_mut();
+        }
+    };
 
-        let page = match oxidize_pdf::Page::from_parsed_with_content(&parsed, &document) {
-            Ok(p) => p,
-            Err(e) => {
-                set_last_error(format!(
-                    "oxidize_page_from_parsed_bytes: from_parsed_with_content failed: {e}"
-                ));
-                return std::ptr::null_mut();
-            }
-        };
+    let page = match oxidize_pdf::Page::from_parsed_with_content(&parsed, document) {
+        Ok(p) => p,
+        Err(e) => {
+            set_last_error(format!("{fn_name}: from_parsed_with_content failed: {e}"));
+            return std::ptr::null_mut();
+        }
+    };
 
-        Box::into_raw(Box::new(PageHandle { inner: page }))
-    })
+    Box::into_raw(Box::new(PageHandle { inner: page }))
 }
 
 /// Create a new page with explicit dimensions (in PDF points).
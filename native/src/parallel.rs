@@ -0,0 +1,448 @@
+//! Opt-in parallel page extraction within a single call.
+//!
+//! `oxidize_extract_text_parallel` and `oxidize_partition_parallel` spread
+//! the pages of one document over a pool of `threads` native worker threads
+//! (0 = one per available CPU). A parsed document is not thread-safe, so
+//! every worker opens its own reader over the shared input bytes; the
+//! resource-limit pre-flight runs once, on the calling thread. Every call
+//! shares one pool with a thread per available CPU, built on first use;
+//! `threads` above that count is clamped to it. Workers
+//! pull page indices from a shared counter and the per-page results are
+//! reassembled in page order, so the output is byte for byte that of
+//! `oxidize_extract_text` / `oxidize_partition` whatever the thread count.
+//!
+//! Partitioning runs page by page, which matches `oxidize_partition` except
+//! on pages with a ruled table grid: upstream assigns their cell text in a
+//! pass internal to `partition_with`. When a worker meets such a page, or a
+//! page fails, the document is partitioned sequentially instead so output
+//! and errors stay identical.
+//!
+//! The first failing page in page order decides the error. The caller's
+//! wall-clock budget (`max_duration_ms`) applies to every worker.
+
+use std::io::Cursor;
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+use std::slice;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+
+use oxidize_pdf::parser::PdfDocument;
+use oxidize_pdf::pipeline::PartitionConfig;
+use oxidize_pdf::text::TextExtractor;
+use rayon::prelude::*;
+use rayon::ThreadPool;
+
+use crate::chunk_stream::PagePartitioner;
+use crate::error_detail::LastError;
+use crate::limits::{self, Deadline};
+use crate::parser::{
+    element_results, extract_text_impl, open_lenient, partition_impl, reopen_lenient,
+    write_element_results, write_joined_text,
+};
+use crate::progress::Progress;
+use crate::{clear_last_error, set_last_error, ErrorCode};
+
+type Document<'a> = PdfDocument<Cursor<&'a [u8]>>;
+
+/// A failed page: error code and detail.
+type PageError = (ErrorCode, LastError);
+
+/// Number of workers for a `threads` argument: 0, or more threads than
+/// there are CPUs, means one per available CPU.
+pub(crate) fn worker_count(threads: u32) -> usize {
+    let available = std::thread::available_parallelism().map_or(1, |n| n.get());
+    match threads {
+        0 => available,
+        n => (n as usize).min(available),
+    }
+}
+
+/// The worker pool, kept for the life of the process.
+static POOL: Mutex<Option<Arc<ThreadPool>>> = Mutex::new(None);
+
+/// The shared pool of one thread per available CPU, built on first use.
+pub(crate) fn worker_pool() -> Result<Arc<ThreadPool>, LastError> {
+    let mut pool = POOL.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(pool) = pool.as_ref() {
+        return Ok(Arc::clone(pool));
+    }
+    let built = rayon::ThreadPoolBuilder::new()
+        .num_threads(worker_count(0))
+        .thread_name(|i| format!("oxidize-worker-{i}"))
+        .build()
+        .map_err(|e| LastError::caused_by("Failed to start worker threads", &e))?;
+    Ok(Arc::clone(pool.insert(Arc::new(built))))
+}
+
+/// Run `job` once on each of `workers` threads of the shared pool and return
+/// what every run produced.
+pub(crate) fn run_workers<T: Send>(
+    workers: usize,
+    job: impl Fn() -> T + Sync,
+) -> Result<Vec<T>, LastError> {
+    let pool = worker_pool()?;
+    let workers = workers.clamp(1, pool.current_num_threads());
+    Ok(pool.install(|| {
+        (0..workers)
+            .into_par_iter()
+            .with_max_len(1)
+            .map(|_| job())
+            .collect()
+    }))
+}
+
+/// Run `page` for every page index on `workers` threads of the pool and
+/// return the results in page order.
+///
+/// `bytes` must already have passed [`open_lenient`]: each worker opens its
+/// own document over them without repeating the pre-flight, and builds its
+/// own state with `init`. `page` returns `Ok(None)` to abandon the parallel
+/// run, in which case `Ok(None)` is returned once the workers stop.
+fn run_pages<S, T: Send>(
+    bytes: &[u8],
+    total: u32,
+    workers: usize,
+    init: impl Fn() -> S + Sync,
+    page: impl Fn(&mut S, &Document, u32) -> Result<Option<T>, PageError> + Sync,
+) -> Result<Option<Vec<T>>, PageError> {
+    let next = AtomicU32::new(0);
+    let stop = AtomicBool::new(false);
+    let abandoned = AtomicBool::new(false);
+    let deadline = limits::armed_deadline();
+
+    let outcomes = run_workers(workers, || {
+        let _deadline = Deadline::inherit(deadline);
+        let mut done = Vec::new();
+        let document = match reopen_lenient(bytes) {
+            Ok(reader) => PdfDocument::new(reader),
+            Err(e) => {
+                stop.store(true, Ordering::Relaxed);
+                return (done, Some((0, (ErrorCode::PdfParseError, e))));
+            }
+        };
+        let mut state = init();
+        while !stop.load(Ordering::Relaxed) {
+            let index = next.fetch_add(1, Ordering::Relaxed);
+            if index >= total {
+                break;
+            }
+            let result = limits::check_deadline()
+                .map_err(|e| (ErrorCode::LimitExceeded, e))
+                .and_then(|()| page(&mut state, &document, index));
+            match result {
+                Ok(Some(value)) => done.push((index, value)),
+                Ok(None) => {
+                    abandoned.store(true, Ordering::Relaxed);
+                    stop.store(true, Ordering::Relaxed);
+                }
+                Err(e) => {
+                    stop.store(true, Ordering::Relaxed);
+                    return (done, Some((index, e)));
+                }
+            }
+        }
+        (done, None)
+    })
+    .map_err(|e| (ErrorCode::IoError, e))?;
+
+    // Pages are handed out in order and a worker finishes its page before
+    // stopping, so every page before the lowest failing one was processed.
+    let mut slots: Vec<Option<T>> = (0..total).map(|_| None).collect();
+    let mut first_error: Option<(u32, PageError)> = None;
+    for (done, error) in outcomes {
+        for (index, value) in done {
+            slots[index as usize] = Some(value);
+        }
+        if let Some((index, e)) = error {
+            if first_error
+                .as_ref()
+                .map_or(true, |(first, _)| index < *first)
+            {
+                first_error = Some((index, e));
+            }
+        }
+    }
+    if let Some((_, e)) = first_error {
+        return Err(e);
+    }
+    if abandoned.load(Ordering::Relaxed) {
+        return Ok(None);
+    }
+    Ok(Some(slots.into_iter().flatten().collect()))
+}
+
+/// Validate the common arguments and open the document on the calling thread
+/// (which also runs the resource-limit pre-flight once, up front).
+unsafe fn open_input<'a>(
+    fn_name: &str,
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    out: *mut *mut c_char,
+) -> Result<(&'a [u8], Document<'a>), c_int> {
+    if pdf_bytes.is_null() || out.is_null() {
+        set_last_error(format!("Null pointer provided to {fn_name}"));
+        return Err(ErrorCode::NullPointer as c_int);
+    }
+    *out = ptr::null_mut();
+
+    if pdf_len == 0 {
+        set_last_error("PDF data is empty (0 bytes)");
+        return Err(ErrorCode::PdfParseError as c_int);
+    }
+
+    let bytes: &'a [u8] = slice::from_raw_parts(pdf_bytes, pdf_len);
+    match open_lenient(bytes) {
+        Ok(reader) => Ok((bytes, PdfDocument::new(reader))),
+        Err(e) => {
+            set_last_error(e);
+            Err(ErrorCode::PdfParseError as c_int)
+        }
+    }
+}
+
+fn page_count(document: &Document) -> Result<u32, c_int> {
+    document.page_count().map_err(|e| {
+        set_last_error(LastError::caused_by("Failed to get page count", &e));
+        ErrorCode::PdfParseError as c_int
+    })
+}
+
+/// [`crate::parser::oxidize_extract_text`] with pages extracted on `threads`
+/// worker threads (0 = one per available CPU, 1 = sequential; clamped to the
+/// CPU count). The result is identical to the sequential call.
+///
+/// # Safety
+/// - `pdf_bytes` must be a valid pointer to `pdf_len` bytes.
+/// - `out_text` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_extract_text_parallel(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    threads: u32,
+    out_text: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        let (bytes, document) = match open_input(
+            "oxidize_extract_text_parallel",
+            pdf_bytes,
+            pdf_len,
+            out_text,
+        ) {
+            Ok(input) => input,
+            Err(code) => return code,
+        };
+        let total = match page_count(&document) {
+            Ok(n) => n,
+            Err(code) => return code,
+        };
+        let workers = worker_count(threads).min(total as usize);
+        if workers <= 1 {
+            return extract_text_impl(&document, Progress::NONE, out_text);
+        }
+
+        let pages = run_pages(
+            bytes,
+            total,
+            workers,
+            || TextExtractor::with_options(Default::default()),
+            |extractor, document, index| {
+                extractor
+                    .extract_from_page(document, index)
+                    .map(Some)
+                    .map_err(|e| {
+                        (
+                            ErrorCode::PdfParseError,
+                            LastError::caused_by("Failed to extract text from PDF", &e)
+                                .at_page(index),
+                        )
+                    })
+            },
+        );
+        match pages {
+            Ok(Some(pages)) => write_joined_text(&pages, out_text),
+            Ok(None) => {
+                set_last_error("Parallel text extraction stopped without a result");
+                ErrorCode::PdfParseError as c_int
+            }
+            Err((code, e)) => {
+                set_last_error(e);
+                code as c_int
+            }
+        }
+    })
+}
+
+/// [`crate::parser::oxidize_partition`] with pages partitioned on `threads`
+/// worker threads (0 = one per available CPU, 1 = sequential; clamped to the
+/// CPU count). The result is identical to the sequential call; documents with ruled tables are
+/// partitioned sequentially (see the module docs).
+///
+/// # Safety
+/// - `pdf_bytes` must be a valid pointer to `pdf_len` bytes.
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_partition_parallel(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    threads: u32,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        let (bytes, document) =
+            match open_input("oxidize_partition_parallel", pdf_bytes, pdf_len, out_json) {
+                Ok(input) => input,
+                Err(code) => return code,
+            };
+        let total = match page_count(&document) {
+            Ok(n) => n,
+            Err(code) => return code,
+        };
+        let workers = worker_count(threads).min(total as usize);
+        if workers <= 1 {
+            return partition_impl(&document, out_json);
+        }
+
+        let pages = run_pages(
+            bytes,
+            total,
+            workers,
+            || PagePartitioner::new(PartitionConfig::default()).without_fallback(),
+            |partitioner, document, index| match partitioner.partition_page(document, index) {
+                Ok(elements) if !partitioner.saw_table_grid => Ok(Some(elements)),
+                _ => Ok(None),
+            },
+        );
+        match pages {
+            Ok(Some(pages)) => {
+                let elements: Vec<_> = pages.into_iter().flatten().collect();
+                write_element_results(&element_results(&elements), out_json)
+            }
+            Ok(None) => {
+                tracing::debug!("ruled table grid or page error; partitioning sequentially");
+                partition_impl(&document, out_json)
+            }
+            Err((code, e)) => {
+                set_last_error(e);
+                code as c_int
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{sample_pdf, SamplePdf};
+    use std::ffi::CStr;
+
+    type Entry = unsafe extern "C" fn(*const u8, usize, u32, *mut *mut c_char) -> c_int;
+
+    unsafe fn run(entry: Entry, pdf: &[u8], threads: u32) -> String {
+        let mut out: *mut c_char = ptr::null_mut();
+        let code = entry(pdf.as_ptr(), pdf.len(), threads, &mut out);
+        assert_eq!(code, ErrorCode::Success as c_int);
+        let s = CStr::from_ptr(out).to_str().unwrap().to_owned();
+        crate::oxidize_free_string(out);
+        s
+    }
+
+    unsafe fn sequential(
+        entry: unsafe extern "C" fn(*const u8, usize, *mut *mut c_char) -> c_int,
+        pdf: &[u8],
+    ) -> String {
+        let mut out: *mut c_char = ptr::null_mut();
+        assert_eq!(
+            entry(pdf.as_ptr(), pdf.len(), &mut out),
+            ErrorCode::Success as c_int
+        );
+        let s = CStr::from_ptr(out).to_str().unwrap().to_owned();
+        crate::oxidize_free_string(out);
+        s
+    }
+
+    #[test]
+    fn parallel_output_matches_sequential_for_any_thread_count() {
+        let pdf = SamplePdf::new(24).lines(6).build();
+        unsafe {
+            let text = sequential(crate::parser::oxidize_extract_text, &pdf);
+            let elements = sequential(crate::parser::oxidize_partition, &pdf);
+            assert!(text.contains("Page 24 line"));
+            for threads in [0, 1, 2, 3, 8, 64] {
+                assert_eq!(
+                    run(oxidize_extract_text_parallel, &pdf, threads),
+                    text,
+                    "text, {threads} threads"
+                );
+                assert_eq!(
+                    run(oxidize_partition_parallel, &pdf, threads),
+                    elements,
+                    "partition, {threads} threads"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn ruled_tables_fall_back_to_the_sequential_partition() {
+        let pdf = SamplePdf::new(6).lines(6).ruled_table_on(4).build();
+        unsafe {
+            let elements = sequential(crate::parser::oxidize_partition, &pdf);
+            assert_eq!(run(oxidize_partition_parallel, &pdf, 4), elements);
+        }
+    }
+
+    #[test]
+    fn one_pool_serves_every_thread_count() {
+        let pdf = SamplePdf::new(3).build();
+        let pool = worker_pool().unwrap();
+        let available = worker_count(0);
+        assert_eq!(pool.current_num_threads(), available);
+        for threads in [1, 2, 3, 5, 8, 64, 1000] {
+            assert!(worker_count(threads) <= available);
+            unsafe { run(oxidize_extract_text_parallel, &pdf, threads) };
+            assert!(Arc::ptr_eq(&pool, &worker_pool().unwrap()));
+        }
+        assert_eq!(run_workers(64, || ()).unwrap().len(), available);
+    }
+
+    #[test]
+    fn errors_report_the_first_failing_page() {
+        let pdf = sample_pdf(4);
+        let bytes = &pdf[..];
+        {
+            // An exhausted budget stops every worker, including at open.
+            let _deadline = Deadline::inherit(Some((std::time::Instant::now(), 7)));
+            let result = run_pages(
+                bytes,
+                40,
+                4,
+                || (),
+                |_, _, _| -> Result<Option<()>, PageError> { Ok(Some(())) },
+            );
+            let (_, e) = result.expect_err("budget is exhausted");
+            assert!(e.message.contains("7 ms exceeded"), "{}", e.message);
+        }
+
+        let result = run_pages(
+            bytes,
+            40,
+            4,
+            || (),
+            |_, _, index| {
+                if index % 7 == 5 {
+                    Err((
+                        ErrorCode::PdfParseError,
+                        LastError::from(format!("page {index}")),
+                    ))
+                } else {
+                    Ok(Some(index))
+                }
+            },
+        );
+        let (_, e) = result.err().unwrap();
+        assert_eq!(e.message, "page 5");
+    }
+}
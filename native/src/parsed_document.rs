@@ -0,0 +1,1801 @@
+//! Persistent parsed-document handle for the read path.
+//!
+//! Every bytes-based reader entry point (`oxidize_extract_text`,
+//! `oxidize_partition`, `oxidize_get_annotations`, …) parses the PDF from
+//! scratch: the xref table is rebuilt and the object/resource caches start
+//! empty on each call. A [`ParsedDocumentHandle`] parses once (lenient mode,
+//! same settings as [`crate::parser::open_lenient`]) and keeps the
+//! `PdfDocument` alive until [`oxidize_parsed_document_free`], so a caller
+//! that runs several extractions on the same file pays the parse cost once
+//! and reuses the cached objects across calls.
+//!
+//! Each `oxidize_parsed_document_<op>(handle, …)` mirrors `oxidize_<op>(pdf_bytes,
+//! pdf_len, …)`: same arguments after the handle, same output format, same
+//! error codes (the bodies are shared — see the `*_impl` helpers in
+//! [`crate::parser`] and [`crate::chunking`]). Two exceptions:
+//! - the signature functions hash the signed byte ranges directly and need a
+//!   `PdfReader` for field detection, so they work on the copy of the bytes
+//!   retained by the handle rather than on the cached document;
+//!   Handles opened from read callbacks keep no bytes, so these return
+//!   `Unsupported`;
+//! - there is no `unlock` variant — the handle is immutable once opened, so
+//!   encrypted files are unlocked up front by
+//!   [`oxidize_parsed_document_open_with_password`].
+//!
+//! [`oxidize_parsed_document_open_path`] opens a file by path instead,
+//! memory-mapping it (see [`crate::mapped_file`]) so the caller never holds
+//! the PDF in a managed buffer.
+//!
+//! A handle is not thread-safe: use it from one thread at a time. For
+//! concurrent readers see [`crate::shared_document`].
+
+use oxidize_pdf::parser::{ParseOptions, PdfDictionary, PdfDocument};
+use std::io::Cursor;
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+use std::slice;
+
+use crate::callback_reader::{self, CallbackReader, PdfSource, ReadCallbacks};
+use crate::chunking::chunk_pdf_impl;
+use crate::error_detail::{ErrorCategory, LastError};
+use crate::mapped_file::{map_path, path_arg, SharedBytes};
+use crate::navigation::{get_navigation_metadata_impl, get_outline_impl};
+use crate::page::{page_from_parsed_impl, PageHandle};
+use crate::parser::{
+    self, open_reader, password_arg, unlock_reader, ChunkOptions, ExtractionOptionsFFI,
+};
+use crate::progress::Progress;
+use crate::table_extraction::extract_tables_impl;
+use crate::tagged::get_struct_tree_impl;
+use crate::words::extract_words_impl;
+use crate::{clear_last_error, set_last_error, ErrorCode};
+
+/// Opaque handle wrapping a parsed `oxidize_pdf::parser::PdfDocument`.
+pub struct ParsedDocumentHandle {
+    pub(crate) inner: PdfDocument<PdfSource>,
+    /// The buffer `inner` reads from (shared, not copied); `None` when the
+    /// handle reads through caller callbacks.
+    pub(crate) bytes: Option<SharedBytes>,
+    pub(crate) version: String,
+    pub(crate) encrypted: bool,
+    /// Copy of the document catalog, read when the handle was opened
+    /// (`None` when the reader could not locate one).
+    pub(crate) catalog: Option<PdfDictionary>,
+}
+
+impl ParsedDocumentHandle {
+    /// Parse `bytes` leniently, unlocking it with `password` when given, and
+    /// capture the reader-level facts (version, encryption, catalog) that
+    /// `PdfDocument` does not expose.
+    pub(crate) fn open(bytes: SharedBytes, password: Option<&str>) -> Result<Self, LastError> {
+        let source = PdfSource::Bytes(Cursor::new(bytes.clone()));
+        Self::from_source(source, ParseOptions::lenient(), Some(bytes), password)
+    }
+
+    fn from_source(
+        source: PdfSource,
+        options: ParseOptions,
+        bytes: Option<SharedBytes>,
+        password: Option<&str>,
+    ) -> Result<Self, LastError> {
+        let mut reader = open_reader(source, options)?;
+        if let Some(password) = password {
+            unlock_reader(&mut reader, password)?;
+        }
+        let version = reader.version().to_string();
+        let encrypted = reader.is_encrypted();
+        let catalog = reader.catalog().ok().cloned();
+        Ok(Self {
+            inner: PdfDocument::new(reader),
+            bytes,
+            version,
+            encrypted,
+            catalog,
+        })
+    }
+
+    /// The raw bytes the signature functions hash. Callback-backed handles
+    /// have none: those calls fail with `Unsupported`.
+    fn signed_bytes(&self) -> Result<&[u8], c_int> {
+        self.bytes.as_deref().ok_or_else(|| {
+            set_last_error(LastError::new(
+                ErrorCategory::Unsupported,
+                "Signature functions need the PDF bytes; not available on a callback-backed handle",
+            ));
+            ErrorCode::Unsupported as c_int
+        })
+    }
+}
+
+// ── Lifecycle ─────────────────────────────────────────────────────────────────
+
+/// Parse a PDF once and return a handle usable with every
+/// `oxidize_parsed_document_*` read function.
+///
+/// The bytes are copied; the caller's buffer may be released as soon as this
+/// returns.
+///
+/// # Returns
+/// `Success`; `NullPointer` if `pdf_bytes` or `out_handle` is null;
+/// `PdfParseError` if `pdf_len == 0` or the lenient parser rejects the bytes.
+/// `*out_handle` is null on any error.
+///
+/// # Safety
+/// - `pdf_bytes` must be a valid pointer to `pdf_len` bytes.
+/// - `out_handle` must be a valid pointer to receive the new handle.
+/// - The returned handle must be freed with `oxidize_parsed_document_free`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_open(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    out_handle: *mut *mut ParsedDocumentHandle,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if pdf_bytes.is_null() || out_handle.is_null() {
+            set_last_error("Null pointer provided to oxidize_parsed_document_open");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_handle = ptr::null_mut();
+
+        if pdf_len == 0 {
+            set_last_error("PDF data is empty (0 bytes)");
+            return ErrorCode::PdfParseError as c_int;
+        }
+
+        let bytes = SharedBytes::from(slice::from_raw_parts(pdf_bytes, pdf_len));
+        match ParsedDocumentHandle::open(bytes, None) {
+            Ok(doc) => {
+                *out_handle = Box::into_raw(Box::new(doc));
+                ErrorCode::Success as c_int
+            }
+            Err(e) => {
+                set_last_error(e);
+                ErrorCode::PdfParseError as c_int
+            }
+        }
+    })
+}
+
+/// [`oxidize_parsed_document_open`] for encrypted PDFs: the file is unlocked
+/// with `password` (user or owner password) before the handle is returned,
+/// so every `oxidize_parsed_document_*` read function works on it.
+/// Unencrypted files open normally and the password is ignored.
+///
+/// # Returns
+/// As [`oxidize_parsed_document_open`], plus `InvalidUtf8` for a non-UTF-8
+/// password and `PasswordRequired` (category `wrong_password`) when the
+/// password unlocks neither the user nor the owner key.
+///
+/// # Safety
+/// - `pdf_bytes` must be a valid pointer to `pdf_len` bytes.
+/// - `password` must be a valid NUL-terminated UTF-8 string.
+/// - `out_handle` must be a valid pointer to receive the new handle.
+/// - The returned handle must be freed with `oxidize_parsed_document_free`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_open_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    out_handle: *mut *mut ParsedDocumentHandle,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if pdf_bytes.is_null() || password.is_null() || out_handle.is_null() {
+            set_last_error("Null pointer provided to oxidize_parsed_document_open_with_password");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_handle = ptr::null_mut();
+
+        if pdf_len == 0 {
+            set_last_error("PDF data is empty (0 bytes)");
+            return ErrorCode::PdfParseError as c_int;
+        }
+
+        let password = match password_arg(password) {
+            Ok(p) => p,
+            Err(code) => return code,
+        };
+
+        let bytes = SharedBytes::from(slice::from_raw_parts(pdf_bytes, pdf_len));
+        match ParsedDocumentHandle::open(bytes, Some(password)) {
+            Ok(doc) => {
+                *out_handle = Box::into_raw(Box::new(doc));
+                ErrorCode::Success as c_int
+            }
+            Err(e) => {
+                set_last_error(e);
+                ErrorCode::PdfParseError as c_int
+            }
+        }
+    })
+}
+
+/// [`oxidize_parsed_document_open`] for a file on disk: the file is
+/// memory-mapped rather than copied, so multi-gigabyte PDFs are not held in
+/// a managed buffer. `password` may be null; when given, an encrypted file
+/// is unlocked as by [`oxidize_parsed_document_open_with_password`].
+///
+/// The file must not be truncated or replaced in place while the handle is
+/// alive.
+///
+/// # Returns
+/// `Success`; `NullPointer` if `path` or `out_handle` is null; `InvalidUtf8`
+/// for a non-UTF-8 path or password; `IoError` if the file cannot be opened
+/// or mapped; `PdfParseError` for an empty or unparsable file;
+/// `PasswordRequired` for a wrong password. `*out_handle` is null on any
+/// error.
+///
+/// # Safety
+/// - `path` must be a valid NUL-terminated UTF-8 string.
+/// - `password` must be null or a valid NUL-terminated UTF-8 string.
+/// - `out_handle` must be a valid pointer to receive the new handle.
+/// - The returned handle must be freed with `oxidize_parsed_document_free`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_open_path(
+    path: *const c_char,
+    password: *const c_char,
+    out_handle: *mut *mut ParsedDocumentHandle,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if path.is_null() || out_handle.is_null() {
+            set_last_error("Null pointer provided to oxidize_parsed_document_open_path");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_handle = ptr::null_mut();
+
+        let path = match path_arg(path) {
+            Ok(p) => p,
+            Err(code) => return code,
+        };
+        let password = if password.is_null() {
+            None
+        } else {
+            match password_arg(password) {
+                Ok(p) => Some(p),
+                Err(code) => return code,
+            }
+        };
+
+        let bytes = match map_path(path) {
+            Ok(b) => b,
+            Err((code, e)) => {
+                set_last_error(e);
+                return code as c_int;
+            }
+        };
+        match ParsedDocumentHandle::open(bytes, password) {
+            Ok(doc) => {
+                *out_handle = Box::into_raw(Box::new(doc));
+                ErrorCode::Success as c_int
+            }
+            Err(e) => {
+                set_last_error(e);
+                ErrorCode::PdfParseError as c_int
+            }
+        }
+    })
+}
+
+/// Open a handle that reads the PDF through caller callbacks (see
+/// [`crate::callback_reader`]) instead of a buffer, so a remote or very large
+/// file is fetched lazily, region by region, as the parser needs it.
+/// `password` may be null; when given, an encrypted file is unlocked as by
+/// [`oxidize_parsed_document_open_with_password`].
+///
+/// The callbacks struct is copied; `user_data` must stay valid, and the
+/// callbacks callable, until the handle is freed. The signature functions
+/// are not available on such a handle (`Unsupported`).
+///
+/// # Returns
+/// `Success`; `NullPointer` if `callbacks` or `out_handle` is null;
+/// `InvalidArgument` if a callback is missing; `IoError` if `length` fails;
+/// `PdfParseError` if the input cannot be parsed (including failed reads);
+/// `PasswordRequired` for a wrong password. `*out_handle` is null on any
+/// error.
+///
+/// # Safety
+/// - `callbacks` must point to a valid `ReadCallbacks`.
+/// - `password` must be null or a valid NUL-terminated UTF-8 string.
+/// - `out_handle` must be a valid pointer to receive the new handle.
+/// - The returned handle must be freed with `oxidize_parsed_document_free`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_open_callbacks(
+    callbacks: *const ReadCallbacks,
+    password: *const c_char,
+    out_handle: *mut *mut ParsedDocumentHandle,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if callbacks.is_null() || out_handle.is_null() {
+            set_last_error("Null pointer provided to oxidize_parsed_document_open_callbacks");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_handle = ptr::null_mut();
+
+        let password = if password.is_null() {
+            None
+        } else {
+            match password_arg(password) {
+                Ok(p) => Some(p),
+                Err(code) => return code,
+            }
+        };
+
+        let reader = match CallbackReader::new(&*callbacks) {
+            Ok(r) => r,
+            Err(e) if e.kind() == std::io::ErrorKind::InvalidInput => {
+                set_last_error(LastError::caused_by("Invalid read callbacks", &e));
+                return ErrorCode::InvalidArgument as c_int;
+            }
+            Err(e) => {
+                set_last_error(LastError::caused_by("Failed to query input length", &e));
+                return ErrorCode::IoError as c_int;
+            }
+        };
+
+        match ParsedDocumentHandle::from_source(
+            PdfSource::Callbacks(reader),
+            callback_reader::parse_options(),
+            None,
+            password,
+        ) {
+            Ok(doc) => {
+                *out_handle = Box::into_raw(Box::new(doc));
+                ErrorCode::Success as c_int
+            }
+            Err(e) => {
+                set_last_error(e);
+                ErrorCode::PdfParseError as c_int
+            }
+        }
+    })
+}
+
+/// Free a parsed-document handle.
+///
+/// # Safety
+/// - `handle` must have been returned by `oxidize_parsed_document_open`.
+/// - `handle` must not have been freed previously.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_free(handle: *mut ParsedDocumentHandle) {
+    crate::ffi_guard_unit(move || {
+        if handle.is_null() {
+            return;
+        }
+        drop(Box::from_raw(handle));
+    })
+}
+
+// ── Document info ─────────────────────────────────────────────────────────────
+
+/// Handle variant of [`parser::oxidize_get_page_count`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `out_count` must be a valid pointer to a `usize`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_get_page_count(
+    handle: *const ParsedDocumentHandle,
+    out_count: *mut usize,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_count.is_null() {
+            set_last_error("Null pointer provided to oxidize_parsed_document_get_page_count");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_count = 0;
+        parser::get_page_count_impl(&(*handle).inner, out_count)
+    })
+}
+
+/// Handle variant of [`parser::oxidize_is_encrypted`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `out_encrypted` must be a valid pointer to a `bool`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_is_encrypted(
+    handle: *const ParsedDocumentHandle,
+    out_encrypted: *mut bool,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_encrypted.is_null() {
+            set_last_error("Null pointer provided to oxidize_parsed_document_is_encrypted");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_encrypted = (*handle).encrypted;
+        ErrorCode::Success as c_int
+    })
+}
+
+/// Handle variant of [`parser::oxidize_get_pdf_version`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `out_version` must be a valid pointer; on success it will point to a
+///   heap-allocated C string that must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_get_pdf_version(
+    handle: *const ParsedDocumentHandle,
+    out_version: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_version.is_null() {
+            set_last_error("Null pointer provided to oxidize_parsed_document_get_pdf_version");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_version = ptr::null_mut();
+        match std::ffi::CString::new((*handle).version.as_str()) {
+            Ok(s) => {
+                *out_version = s.into_raw();
+                ErrorCode::Success as c_int
+            }
+            Err(e) => {
+                set_last_error(LastError::caused_by(
+                    "Version string contains invalid data",
+                    &e,
+                ));
+                ErrorCode::InvalidUtf8 as c_int
+            }
+        }
+    })
+}
+
+/// Handle variant of [`parser::oxidize_get_page_dimensions`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `page_number` is 1-based (first page = 1).
+/// - `out_width` and `out_height` must be valid pointers to `f64`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_get_page_dimensions(
+    handle: *const ParsedDocumentHandle,
+    page_number: usize,
+    out_width: *mut f64,
+    out_height: *mut f64,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_width.is_null() || out_height.is_null() {
+            set_last_error("Null pointer provided to oxidize_parsed_document_get_page_dimensions");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_width = 0.0;
+        *out_height = 0.0;
+        if page_number == 0 {
+            set_last_error("Page number must be >= 1 (1-based indexing)");
+            return ErrorCode::PageOutOfRange as c_int;
+        }
+        parser::get_page_dimensions_impl(&(*handle).inner, page_number, out_width, out_height)
+    })
+}
+
+/// Handle variant of [`parser::oxidize_get_metadata`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_get_metadata(
+    handle: *const ParsedDocumentHandle,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_parsed_document_get_metadata");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+        parser::get_metadata_impl(&(*handle).inner, out_json)
+    })
+}
+
+// ── Text extraction ───────────────────────────────────────────────────────────
+
+/// Handle variant of [`parser::oxidize_extract_text`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `out_text` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_extract_text(
+    handle: *const ParsedDocumentHandle,
+    out_text: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_text.is_null() {
+            set_last_error("Null pointer provided to oxidize_parsed_document_extract_text");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_text = ptr::null_mut();
+        parser::extract_text_impl(&(*handle).inner, Progress::NONE, out_text)
+    })
+}
+
+/// Handle variant of [`parser::oxidize_extract_text_from_page`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `page_number` is 1-based (first page = 1).
+/// - `out_text` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_extract_text_from_page(
+    handle: *const ParsedDocumentHandle,
+    page_number: usize,
+    out_text: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_text.is_null() {
+            set_last_error(
+                "Null pointer provided to oxidize_parsed_document_extract_text_from_page",
+            );
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_text = ptr::null_mut();
+        if page_number == 0 {
+            set_last_error("Page number must be >= 1 (1-based indexing)");
+            return ErrorCode::PageOutOfRange as c_int;
+        }
+        parser::extract_text_from_page_impl(&(*handle).inner, page_number, out_text)
+    })
+}
+
+/// Handle variant of [`parser::oxidize_extract_text_with_options`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `options` may be null (upstream defaults) or point to an
+///   `ExtractionOptionsFFI` struct.
+/// - `out_text` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_extract_text_with_options(
+    handle: *const ParsedDocumentHandle,
+    options: *const ExtractionOptionsFFI,
+    out_text: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_text.is_null() {
+            set_last_error(
+                "Null pointer provided to oxidize_parsed_document_extract_text_with_options",
+            );
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_text = ptr::null_mut();
+        let core_options = if options.is_null() {
+            oxidize_pdf::text::ExtractionOptions::default()
+        } else {
+            (*options).to_core()
+        };
+        parser::extract_text_with_options_impl(&(*handle).inner, core_options, out_text)
+    })
+}
+
+/// Handle variant of [`parser::oxidize_extract_chunks`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `options` can be null (defaults will be used).
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_extract_chunks(
+    handle: *const ParsedDocumentHandle,
+    options: *const ChunkOptions,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_parsed_document_extract_chunks");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+        let chunk_opts = if options.is_null() {
+            parser::default_chunk_options()
+        } else {
+            *options
+        };
+        parser::extract_chunks_impl(&(*handle).inner, chunk_opts, out_json)
+    })
+}
+
+/// Handle variant of [`parser::oxidize_extract_chunks_from_page`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `page_number` is 1-based (first page = 1).
+/// - `options` can be null (defaults will be used).
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_extract_chunks_from_page(
+    handle: *const ParsedDocumentHandle,
+    page_number: usize,
+    options: *const ChunkOptions,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_json.is_null() {
+            set_last_error(
+                "Null pointer provided to oxidize_parsed_document_extract_chunks_from_page",
+            );
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+        if page_number == 0 {
+            set_last_error("Page number must be >= 1 (1-based indexing)");
+            return ErrorCode::PageOutOfRange as c_int;
+        }
+        let chunk_opts = if options.is_null() {
+            parser::default_chunk_options()
+        } else {
+            *options
+        };
+        parser::extract_chunks_from_page_impl(&(*handle).inner, page_number, chunk_opts, out_json)
+    })
+}
+
+/// Handle variant of [`crate::words::oxidize_extract_words`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `page_number` is 1-based (first page = 1).
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_extract_words(
+    handle: *const ParsedDocumentHandle,
+    page_number: usize,
+    glyphs: u8,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_parsed_document_extract_words");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+        if page_number == 0 {
+            set_last_error("Page number must be >= 1 (1-based indexing)");
+            return ErrorCode::PageOutOfRange as c_int;
+        }
+        extract_words_impl(&(*handle).inner, page_number, glyphs != 0, out_json)
+    })
+}
+
+/// Handle variant of [`crate::table_extraction::oxidize_extract_tables`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `partition_config_json`, if non-null, must be a NUL-terminated UTF-8 C string.
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_extract_tables(
+    handle: *const ParsedDocumentHandle,
+    partition_config_json: *const c_char,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_parsed_document_extract_tables");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+        let config = match parser::parse_partition_config_opt(partition_config_json) {
+            Ok(c) => c,
+            Err(code) => return code,
+        };
+        extract_tables_impl(&(*handle).inner, config, out_json)
+    })
+}
+
+/// Handle variant of [`crate::navigation::oxidize_get_outline`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_get_outline(
+    handle: *const ParsedDocumentHandle,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_parsed_document_get_outline");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+        let handle = &*handle;
+        get_outline_impl(&handle.inner, handle.catalog.as_ref(), out_json)
+    })
+}
+
+/// Handle variant of [`crate::navigation::oxidize_get_navigation_metadata`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_get_navigation_metadata(
+    handle: *const ParsedDocumentHandle,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_json.is_null() {
+            set_last_error(
+                "Null pointer provided to oxidize_parsed_document_get_navigation_metadata",
+            );
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+        let handle = &*handle;
+        get_navigation_metadata_impl(&handle.inner, handle.catalog.as_ref(), out_json)
+    })
+}
+
+/// Handle variant of [`crate::tagged::oxidize_get_struct_tree`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_get_struct_tree(
+    handle: *const ParsedDocumentHandle,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_parsed_document_get_struct_tree");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+        let handle = &*handle;
+        get_struct_tree_impl(&handle.inner, handle.catalog.as_ref(), out_json)
+    })
+}
+
+// ── Structured export ─────────────────────────────────────────────────────────
+
+/// Handle variant of [`parser::oxidize_to_markdown`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `out_text` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_to_markdown(
+    handle: *const ParsedDocumentHandle,
+    out_text: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_text.is_null() {
+            set_last_error("Null pointer provided to oxidize_parsed_document_to_markdown");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_text = ptr::null_mut();
+        parser::structured_export_document(
+            &(*handle).inner,
+            out_text,
+            "oxidize_parsed_document_to_markdown",
+            |doc| doc.to_markdown(),
+        )
+    })
+}
+
+/// Handle variant of [`parser::oxidize_to_contextual`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `out_text` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_to_contextual(
+    handle: *const ParsedDocumentHandle,
+    out_text: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_text.is_null() {
+            set_last_error("Null pointer provided to oxidize_parsed_document_to_contextual");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_text = ptr::null_mut();
+        parser::structured_export_document(
+            &(*handle).inner,
+            out_text,
+            "oxidize_parsed_document_to_contextual",
+            |doc| doc.to_contextual(),
+        )
+    })
+}
+
+/// Handle variant of [`parser::oxidize_to_json`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `out_text` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_to_json(
+    handle: *const ParsedDocumentHandle,
+    out_text: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_text.is_null() {
+            set_last_error("Null pointer provided to oxidize_parsed_document_to_json");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_text = ptr::null_mut();
+        parser::structured_export_document(
+            &(*handle).inner,
+            out_text,
+            "oxidize_parsed_document_to_json",
+            |doc| doc.to_json(),
+        )
+    })
+}
+
+/// Handle variant of [`parser::oxidize_to_markdown_with_options`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `options_json` must be a NUL-terminated UTF-8 C string.
+/// - `out_text` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_to_markdown_with_options(
+    handle: *const ParsedDocumentHandle,
+    options_json: *const c_char,
+    out_text: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || options_json.is_null() || out_text.is_null() {
+            set_last_error(
+                "Null pointer provided to oxidize_parsed_document_to_markdown_with_options",
+            );
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_text = ptr::null_mut();
+        let opts: oxidize_pdf::ai::MarkdownOptions =
+            match parser::parse_config_arg::<crate::pipeline_config::MarkdownOptionsDto>(
+                options_json,
+                "options_json",
+                "MarkdownOptions",
+            ) {
+                Ok(d) => d.into(),
+                Err(code) => return code,
+            };
+        parser::to_markdown_with_options_impl(&(*handle).inner, opts, out_text)
+    })
+}
+
+// ── Pipeline ──────────────────────────────────────────────────────────────────
+
+/// Handle variant of [`parser::oxidize_partition`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_partition(
+    handle: *const ParsedDocumentHandle,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_parsed_document_partition");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+        parser::partition_impl(&(*handle).inner, out_json)
+    })
+}
+
+/// Handle variant of [`parser::oxidize_partition_with_profile`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_partition_with_profile(
+    handle: *const ParsedDocumentHandle,
+    profile: u8,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_json.is_null() {
+            set_last_error(
+                "Null pointer provided to oxidize_parsed_document_partition_with_profile",
+            );
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+        let prof = match crate::profiles::Profile::resolve(profile) {
+            Ok(p) => p,
+            Err(e) => {
+                set_last_error(e);
+                return ErrorCode::InvalidArgument as c_int;
+            }
+        };
+        parser::partition_with_profile_impl(&(*handle).inner, &prof, out_json)
+    })
+}
+
+/// Handle variant of [`parser::oxidize_partition_with_config`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `config_json` must be a valid NUL-terminated UTF-8 C string.
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_partition_with_config(
+    handle: *const ParsedDocumentHandle,
+    config_json: *const c_char,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || config_json.is_null() || out_json.is_null() {
+            set_last_error(
+                "Null pointer provided to oxidize_parsed_document_partition_with_config",
+            );
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+        let dto: crate::pipeline_config::PartitionConfigDto =
+            match parser::parse_config_arg(config_json, "config_json", "PartitionConfig") {
+                Ok(d) => d,
+                Err(code) => return code,
+            };
+        parser::partition_with_config_impl(&(*handle).inner, dto.into(), Progress::NONE, out_json)
+    })
+}
+
+/// Handle variant of [`parser::oxidize_rag_chunks`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_rag_chunks(
+    handle: *const ParsedDocumentHandle,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_parsed_document_rag_chunks");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+        parser::rag_chunks_impl(&(*handle).inner, out_json)
+    })
+}
+
+/// Handle variant of [`parser::oxidize_rag_chunks_with_profile`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_rag_chunks_with_profile(
+    handle: *const ParsedDocumentHandle,
+    profile: u8,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_json.is_null() {
+            set_last_error(
+                "Null pointer provided to oxidize_parsed_document_rag_chunks_with_profile",
+            );
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+        let prof = match crate::profiles::Profile::resolve(profile) {
+            Ok(p) => p,
+            Err(e) => {
+                set_last_error(e);
+                return ErrorCode::InvalidArgument as c_int;
+            }
+        };
+        parser::rag_chunks_with_profile_impl(&(*handle).inner, &prof, out_json)
+    })
+}
+
+/// Handle variant of [`parser::oxidize_rag_chunks_with_config`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `partition_config_json` and `hybrid_config_json`, if non-null, must
+///   each be NUL-terminated UTF-8 C strings.
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_rag_chunks_with_config(
+    handle: *const ParsedDocumentHandle,
+    partition_config_json: *const c_char,
+    hybrid_config_json: *const c_char,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_json.is_null() {
+            set_last_error(
+                "Null pointer provided to oxidize_parsed_document_rag_chunks_with_config",
+            );
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+        let partition_cfg = match parser::parse_partition_config_opt(partition_config_json) {
+            Ok(c) => c,
+            Err(code) => return code,
+        };
+        let hybrid_cfg = match parser::parse_hybrid_config_opt(hybrid_config_json) {
+            Ok(c) => c,
+            Err(code) => return code,
+        };
+        parser::rag_chunks_with_config_impl(
+            &(*handle).inner,
+            partition_cfg,
+            hybrid_cfg,
+            Progress::NONE,
+            out_json,
+        )
+    })
+}
+
+/// Handle variant of [`parser::oxidize_semantic_chunks`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `partition_config_json`, if non-null, must be a NUL-terminated UTF-8
+///   C string. `semantic_config_json` must always be one.
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_semantic_chunks(
+    handle: *const ParsedDocumentHandle,
+    partition_config_json: *const c_char,
+    semantic_config_json: *const c_char,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || semantic_config_json.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_parsed_document_semantic_chunks");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+        let partition_cfg = match parser::parse_partition_config_opt(partition_config_json) {
+            Ok(c) => c,
+            Err(code) => return code,
+        };
+        let sem_cfg = match parser::parse_semantic_config(semantic_config_json) {
+            Ok(c) => c,
+            Err(code) => return code,
+        };
+        parser::semantic_chunks_impl(
+            &(*handle).inner,
+            partition_cfg,
+            sem_cfg,
+            Progress::NONE,
+            out_json,
+        )
+    })
+}
+
+/// Handle variant of [`parser::oxidize_semantic_chunks_with_profile`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_semantic_chunks_with_profile(
+    handle: *const ParsedDocumentHandle,
+    profile: u8,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_json.is_null() {
+            set_last_error(
+                "Null pointer provided to oxidize_parsed_document_semantic_chunks_with_profile",
+            );
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+        let prof = match crate::profiles::Profile::resolve(profile) {
+            Ok(p) => p,
+            Err(e) => {
+                set_last_error(e);
+                return ErrorCode::InvalidArgument as c_int;
+            }
+        };
+        parser::semantic_chunks_with_profile_impl(&(*handle).inner, &prof, out_json)
+    })
+}
+
+/// Handle variant of [`crate::chunking::oxidize_chunk_pdf`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_chunk_pdf(
+    handle: *const ParsedDocumentHandle,
+    chunk_size: usize,
+    overlap: usize,
+    detect_language: u8,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_parsed_document_chunk_pdf");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+        chunk_pdf_impl(
+            &(*handle).inner,
+            chunk_size,
+            overlap,
+            detect_language,
+            Progress::NONE,
+            out_json,
+        )
+    })
+}
+
+// ── Page-level reads ──────────────────────────────────────────────────────────
+
+/// Handle variant of [`parser::oxidize_get_annotations`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_get_annotations(
+    handle: *const ParsedDocumentHandle,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_parsed_document_get_annotations");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+        parser::get_annotations_impl(&(*handle).inner, out_json)
+    })
+}
+
+/// Handle variant of [`parser::oxidize_get_page_resources`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `page_number` is 1-based (first page = 1).
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_get_page_resources(
+    handle: *const ParsedDocumentHandle,
+    page_number: usize,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_parsed_document_get_page_resources");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+        if page_number == 0 {
+            set_last_error("Page number must be >= 1 (1-based indexing)");
+            return ErrorCode::PageOutOfRange as c_int;
+        }
+        parser::get_page_resources_impl(&(*handle).inner, page_number, out_json)
+    })
+}
+
+/// Handle variant of [`parser::oxidize_get_page_content_stream`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `page_number` is 1-based (first page = 1).
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_get_page_content_stream(
+    handle: *const ParsedDocumentHandle,
+    page_number: usize,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_json.is_null() {
+            set_last_error(
+                "Null pointer provided to oxidize_parsed_document_get_page_content_stream",
+            );
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+        if page_number == 0 {
+            set_last_error("Page number must be >= 1 (1-based indexing)");
+            return ErrorCode::PageOutOfRange as c_int;
+        }
+        parser::get_page_content_stream_impl(&(*handle).inner, page_number, out_json)
+    })
+}
+
+/// Handle variant of [`parser::oxidize_analyze_page_content`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `page_number` is 1-based (first page = 1).
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_analyze_page_content(
+    handle: *const ParsedDocumentHandle,
+    page_number: usize,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_parsed_document_analyze_page_content");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+        if page_number == 0 {
+            set_last_error("Page number must be >= 1 (1-based indexing)");
+            return ErrorCode::PageOutOfRange as c_int;
+        }
+        parser::analyze_page_content_impl(&(*handle).inner, page_number, out_json)
+    })
+}
+
+/// Handle variant of [`crate::page::oxidize_page_from_parsed_bytes`]
+/// (`page_index` is zero-based, as there).
+///
+/// Returns a heap-allocated `PageHandle` pointer, or null on error (inspect
+/// `oxidize_get_last_error`).
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - The returned pointer must be freed with `oxidize_page_free`, or handed to
+///   `oxidize_document_add_page`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_page_to_writable(
+    handle: *const ParsedDocumentHandle,
+    page_index: u32,
+) -> *mut PageHandle {
+    crate::ffi_guard_ptr(move || {
+        clear_last_error();
+        if handle.is_null() {
+            set_last_error("Null pointer provided to oxidize_parsed_document_page_to_writable");
+            return ptr::null_mut();
+        }
+        page_from_parsed_impl(
+            &(*handle).inner,
+            page_index,
+            "oxidize_parsed_document_page_to_writable",
+        )
+    })
+}
+
+// ── Signatures & forms ────────────────────────────────────────────────────────
+
+/// Handle variant of [`parser::oxidize_has_signatures`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `out_has_signatures` must be a valid pointer to a `bool`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_has_signatures(
+    handle: *const ParsedDocumentHandle,
+    out_has_signatures: *mut bool,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_has_signatures.is_null() {
+            set_last_error("Null pointer provided to oxidize_parsed_document_has_signatures");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_has_signatures = false;
+        match (*handle).signed_bytes() {
+            Ok(bytes) => parser::has_signatures_impl(bytes, out_has_signatures),
+            Err(code) => code,
+        }
+    })
+}
+
+/// Handle variant of [`parser::oxidize_get_signatures`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_get_signatures(
+    handle: *const ParsedDocumentHandle,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_parsed_document_get_signatures");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+        match (*handle).signed_bytes() {
+            Ok(bytes) => parser::get_signatures_impl(bytes, out_json),
+            Err(code) => code,
+        }
+    })
+}
+
+/// Handle variant of [`parser::oxidize_verify_signatures`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_verify_signatures(
+    handle: *const ParsedDocumentHandle,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_parsed_document_verify_signatures");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+        match (*handle).signed_bytes() {
+            Ok(bytes) => parser::verify_signatures_impl(bytes, out_json),
+            Err(code) => code,
+        }
+    })
+}
+
+/// Handle variant of [`parser::oxidize_has_form_fields`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `out_has` must be a valid pointer to a `bool`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_has_form_fields(
+    handle: *const ParsedDocumentHandle,
+    out_has: *mut bool,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_has.is_null() {
+            set_last_error("Null pointer provided to oxidize_parsed_document_has_form_fields");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_has = false;
+        parser::has_form_fields_impl(&(*handle).inner, out_has)
+    })
+}
+
+/// Handle variant of [`parser::oxidize_get_form_fields`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_parsed_document_open`.
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parsed_document_get_form_fields(
+    handle: *const ParsedDocumentHandle,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_parsed_document_get_form_fields");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+        parser::get_form_fields_impl(&(*handle).inner, out_json)
+    })
+}
+
+// ── Tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::SamplePdf;
+    use std::ffi::{CStr, CString};
+
+    unsafe fn open(pdf: &[u8]) -> *mut ParsedDocumentHandle {
+        let mut handle: *mut ParsedDocumentHandle = ptr::null_mut();
+        let code = oxidize_parsed_document_open(pdf.as_ptr(), pdf.len(), &mut handle);
+        assert_eq!(code, ErrorCode::Success as c_int);
+        assert!(!handle.is_null());
+        handle
+    }
+
+    unsafe fn take_string(p: *mut c_char) -> String {
+        assert!(!p.is_null());
+        let s = CStr::from_ptr(p).to_string_lossy().into_owned();
+        crate::oxidize_free_string(p);
+        s
+    }
+
+    #[test]
+    fn open_rejects_null_empty_and_garbage() {
+        unsafe {
+            let mut handle: *mut ParsedDocumentHandle = ptr::null_mut();
+            let code = oxidize_parsed_document_open(ptr::null(), 10, &mut handle);
+            assert_eq!(code, ErrorCode::NullPointer as c_int);
+            assert!(handle.is_null());
+
+            let dummy: u8 = 0;
+            let code = oxidize_parsed_document_open(&dummy, 0, &mut handle);
+            assert_eq!(code, ErrorCode::PdfParseError as c_int);
+            assert!(handle.is_null());
+
+            let garbage = b"definitely not a pdf";
+            let code = oxidize_parsed_document_open(garbage.as_ptr(), garbage.len(), &mut handle);
+            assert_eq!(code, ErrorCode::PdfParseError as c_int);
+            assert!(handle.is_null());
+        }
+    }
+
+    #[test]
+    fn free_null_is_noop() {
+        unsafe { oxidize_parsed_document_free(ptr::null_mut()) };
+    }
+
+    #[test]
+    fn handle_functions_reject_null_handle() {
+        unsafe {
+            let mut out: *mut c_char = ptr::null_mut();
+            let code = oxidize_parsed_document_extract_text(ptr::null(), &mut out);
+            assert_eq!(code, ErrorCode::NullPointer as c_int);
+            let mut count = 0usize;
+            let code = oxidize_parsed_document_get_page_count(ptr::null(), &mut count);
+            assert_eq!(code, ErrorCode::NullPointer as c_int);
+            assert!(oxidize_parsed_document_page_to_writable(ptr::null(), 0).is_null());
+        }
+    }
+
+    #[test]
+    fn handle_outputs_match_bytes_variants() {
+        let pdf = SamplePdf::new(2).title("Handle Fixture").build();
+        unsafe {
+            let handle = open(&pdf);
+
+            let mut count = 0usize;
+            assert_eq!(
+                oxidize_parsed_document_get_page_count(handle, &mut count),
+                ErrorCode::Success as c_int
+            );
+            assert_eq!(count, 2);
+
+            let mut a: *mut c_char = ptr::null_mut();
+            let mut b: *mut c_char = ptr::null_mut();
+            assert_eq!(
+                oxidize_parsed_document_extract_text(handle, &mut a),
+                ErrorCode::Success as c_int
+            );
+            assert_eq!(
+                parser::oxidize_extract_text(pdf.as_ptr(), pdf.len(), &mut b),
+                ErrorCode::Success as c_int
+            );
+            assert_eq!(take_string(a), take_string(b));
+
+            assert_eq!(
+                oxidize_parsed_document_partition(handle, &mut a),
+                ErrorCode::Success as c_int
+            );
+            assert_eq!(
+                parser::oxidize_partition(pdf.as_ptr(), pdf.len(), &mut b),
+                ErrorCode::Success as c_int
+            );
+            assert_eq!(take_string(a), take_string(b));
+
+            assert_eq!(
+                oxidize_parsed_document_rag_chunks(handle, &mut a),
+                ErrorCode::Success as c_int
+            );
+            assert_eq!(
+                parser::oxidize_rag_chunks(pdf.as_ptr(), pdf.len(), &mut b),
+                ErrorCode::Success as c_int
+            );
+            assert_eq!(take_string(a), take_string(b));
+
+            assert_eq!(
+                oxidize_parsed_document_get_metadata(handle, &mut a),
+                ErrorCode::Success as c_int
+            );
+            assert_eq!(
+                parser::oxidize_get_metadata(pdf.as_ptr(), pdf.len(), &mut b),
+                ErrorCode::Success as c_int
+            );
+            assert_eq!(take_string(a), take_string(b));
+
+            oxidize_parsed_document_free(handle);
+        }
+    }
+
+    #[test]
+    fn handle_survives_repeated_calls() {
+        let pdf = SamplePdf::new(2).title("Handle Fixture").build();
+        unsafe {
+            let handle = open(&pdf);
+            for page in 1..=2usize {
+                for _ in 0..3 {
+                    let mut out: *mut c_char = ptr::null_mut();
+                    let code =
+                        oxidize_parsed_document_extract_text_from_page(handle, page, &mut out);
+                    assert_eq!(code, ErrorCode::Success as c_int);
+                    let text = take_string(out);
+                    assert!(text.contains(&format!("Section {page}")), "got {text:?}");
+                }
+            }
+            oxidize_parsed_document_free(handle);
+        }
+    }
+
+    #[test]
+    fn page_number_validation_matches_bytes_variants() {
+        let pdf = SamplePdf::new(2).title("Handle Fixture").build();
+        unsafe {
+            let handle = open(&pdf);
+            let mut out: *mut c_char = ptr::null_mut();
+            let code = oxidize_parsed_document_extract_text_from_page(handle, 0, &mut out);
+            assert_eq!(code, ErrorCode::PageOutOfRange as c_int);
+            assert!(out.is_null());
+
+            let code = oxidize_parsed_document_extract_text_from_page(handle, 9, &mut out);
+            assert_eq!(code, ErrorCode::PageOutOfRange as c_int);
+            assert!(out.is_null());
+
+            let (mut w, mut h) = (0.0, 0.0);
+            let code = oxidize_parsed_document_get_page_dimensions(handle, 1, &mut w, &mut h);
+            assert_eq!(code, ErrorCode::Success as c_int);
+            assert!((w - 595.0).abs() < 1.0 && (h - 842.0).abs() < 1.0);
+            oxidize_parsed_document_free(handle);
+        }
+    }
+
+    #[test]
+    fn version_and_encryption_are_reported() {
+        let pdf = SamplePdf::new(2).title("Handle Fixture").build();
+        unsafe {
+            let handle = open(&pdf);
+            let mut encrypted = true;
+            assert_eq!(
+                oxidize_parsed_document_is_encrypted(handle, &mut encrypted),
+                ErrorCode::Success as c_int
+            );
+            assert!(!encrypted);
+
+            let mut a: *mut c_char = ptr::null_mut();
+            let mut b: *mut c_char = ptr::null_mut();
+            assert_eq!(
+                oxidize_parsed_document_get_pdf_version(handle, &mut a),
+                ErrorCode::Success as c_int
+            );
+            assert_eq!(
+                parser::oxidize_get_pdf_version(pdf.as_ptr(), pdf.len(), &mut b),
+                ErrorCode::Success as c_int
+            );
+            assert_eq!(take_string(a), take_string(b));
+            oxidize_parsed_document_free(handle);
+        }
+    }
+
+    #[test]
+    fn invalid_config_is_rejected_before_work() {
+        let pdf = SamplePdf::new(2).title("Handle Fixture").build();
+        unsafe {
+            let handle = open(&pdf);
+            let mut out: *mut c_char = ptr::null_mut();
+            let code = oxidize_parsed_document_partition_with_profile(handle, 99, &mut out);
+            assert_eq!(code, ErrorCode::InvalidArgument as c_int);
+            assert!(out.is_null());
+
+            let bad = std::ffi::CString::new("{not json").unwrap();
+            let code =
+                oxidize_parsed_document_partition_with_config(handle, bad.as_ptr(), &mut out);
+            assert_eq!(code, ErrorCode::InvalidArgument as c_int);
+            assert!(out.is_null());
+            oxidize_parsed_document_free(handle);
+        }
+    }
+
+    #[test]
+    fn page_to_writable_returns_page_handle() {
+        let pdf = SamplePdf::new(2).title("Handle Fixture").build();
+        unsafe {
+            let handle = open(&pdf);
+            let page = oxidize_parsed_document_page_to_writable(handle, 1);
+            assert!(!page.is_null());
+            crate::page::oxidize_page_free(page);
+            assert!(oxidize_parsed_document_page_to_writable(handle, 42).is_null());
+            oxidize_parsed_document_free(handle);
+        }
+    }
+
+    #[test]
+    fn password_handle_reads_encrypted_pdf() {
+        use oxidize_pdf::{Document, Font, Page};
+        let mut doc = Document::new();
+        let mut page = Page::a4();
+        page.text()
+            .set_font(Font::Helvetica, 12.0)
+            .at(50.0, 750.0)
+            .write("Encrypted body text")
+            .unwrap();
+        doc.add_page(page);
+        doc.encrypt_with_passwords("user-secret", "owner-secret");
+        let pdf = doc.to_bytes().unwrap();
+
+        unsafe {
+            let mut handle: *mut ParsedDocumentHandle = ptr::null_mut();
+            let wrong = CString::new("nope").unwrap();
+            let code = oxidize_parsed_document_open_with_password(
+                pdf.as_ptr(),
+                pdf.len(),
+                wrong.as_ptr(),
+                &mut handle,
+            );
+            assert_eq!(code, ErrorCode::PasswordRequired as c_int);
+            assert!(handle.is_null());
+
+            for password in ["user-secret", "owner-secret"] {
+                let password = CString::new(password).unwrap();
+                let code = oxidize_parsed_document_open_with_password(
+                    pdf.as_ptr(),
+                    pdf.len(),
+                    password.as_ptr(),
+                    &mut handle,
+                );
+                assert_eq!(code, ErrorCode::Success as c_int);
+
+                let mut out: *mut c_char = ptr::null_mut();
+                assert_eq!(
+                    oxidize_parsed_document_extract_text(handle, &mut out),
+                    ErrorCode::Success as c_int
+                );
+                assert!(take_string(out).contains("Encrypted body text"));
+                assert_eq!(
+                    oxidize_parsed_document_rag_chunks(handle, &mut out),
+                    ErrorCode::Success as c_int
+                );
+                assert!(take_string(out).contains("Encrypted body text"));
+                oxidize_parsed_document_free(handle);
+            }
+        }
+    }
+
+    #[test]
+    fn path_handle_matches_bytes_handle() {
+        let pdf = SamplePdf::new(2).title("Handle Fixture").build();
+        let file = crate::mapped_file::tests::TempPdf::new("handle-path", &pdf);
+        unsafe {
+            let mut mapped: *mut ParsedDocumentHandle = ptr::null_mut();
+            let code =
+                oxidize_parsed_document_open_path(file.c_path().as_ptr(), ptr::null(), &mut mapped);
+            assert_eq!(code, ErrorCode::Success as c_int);
+            let copied = open(&pdf);
+
+            let mut a: *mut c_char = ptr::null_mut();
+            let mut b: *mut c_char = ptr::null_mut();
+            assert_eq!(
+                oxidize_parsed_document_rag_chunks(mapped, &mut a),
+                ErrorCode::Success as c_int
+            );
+            assert_eq!(
+                oxidize_parsed_document_rag_chunks(copied, &mut b),
+                ErrorCode::Success as c_int
+            );
+            assert_eq!(take_string(a), take_string(b));
+            oxidize_parsed_document_free(mapped);
+            oxidize_parsed_document_free(copied);
+
+            let missing = CString::new("/nonexistent/oxidize.pdf").unwrap();
+            let code =
+                oxidize_parsed_document_open_path(missing.as_ptr(), ptr::null(), &mut mapped);
+            assert_eq!(code, ErrorCode::IoError as c_int);
+            assert!(mapped.is_null());
+        }
+    }
+
+    #[test]
+    fn callback_handle_reads_lazily() {
+        use crate::callback_reader::tests::Source;
+        use oxidize_pdf::{Document, Font, Page};
+
+        let mut doc = Document::new();
+        let mut seed = 0x2545_f491_u32;
+        for p in 0..12 {
+            let mut page = Page::a4();
+            for line in 0..400 {
+                // Pseudo-random words so the content streams do not compress away.
+                let words: Vec<String> = (0..12)
+                    .map(|_| {
+                        seed ^= seed << 13;
+                        seed ^= seed >> 17;
+                        seed ^= seed << 5;
+                        format!("{seed:x}")
+                    })
+                    .collect();
+                page.text()
+                    .set_font(Font::Helvetica, 2.0)
+                    .at(20.0, 800.0 - line as f64 * 2.0)
+                    .write(&format!("Page {} {}", p + 1, words.join(" ")))
+                    .unwrap();
+            }
+            doc.add_page(page);
+        }
+        let pdf = doc.to_bytes().unwrap();
+
+        let mut source = Source::new(pdf.clone());
+        let callbacks = source.callbacks();
+        unsafe {
+            let mut handle: *mut ParsedDocumentHandle = ptr::null_mut();
+            let code = oxidize_parsed_document_open_callbacks(&callbacks, ptr::null(), &mut handle);
+            assert_eq!(code, ErrorCode::Success as c_int);
+            // Header, trailer and xref only.
+            assert!(
+                source.bytes_read < pdf.len() / 10,
+                "read {} of {} bytes to open",
+                source.bytes_read,
+                pdf.len()
+            );
+
+            // Page dictionaries, never the content streams.
+            let mut count = 0usize;
+            assert_eq!(
+                oxidize_parsed_document_get_page_count(handle, &mut count),
+                ErrorCode::Success as c_int
+            );
+            assert_eq!(count, 12);
+            assert!(
+                source.bytes_read < pdf.len() / 2,
+                "read {} of {} bytes to count pages",
+                source.bytes_read,
+                pdf.len()
+            );
+
+            let mut a: *mut c_char = ptr::null_mut();
+            let mut b: *mut c_char = ptr::null_mut();
+            assert_eq!(
+                oxidize_parsed_document_extract_text_from_page(handle, 11, &mut a),
+                ErrorCode::Success as c_int
+            );
+            assert_eq!(
+                parser::oxidize_extract_text_from_page(pdf.as_ptr(), pdf.len(), 11, &mut b),
+                ErrorCode::Success as c_int
+            );
+            assert_eq!(take_string(a), take_string(b));
+
+            let mut has = false;
+            assert_eq!(
+                oxidize_parsed_document_has_signatures(handle, &mut has),
+                ErrorCode::Unsupported as c_int
+            );
+            oxidize_parsed_document_free(handle);
+
+            let mut incomplete = source.callbacks();
+            incomplete.length = None;
+            let code =
+                oxidize_parsed_document_open_callbacks(&incomplete, ptr::null(), &mut handle);
+            assert_eq!(code, ErrorCode::InvalidArgument as c_int);
+            assert!(handle.is_null());
+        }
+    }
+}
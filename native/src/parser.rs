@@ -1,13 +1,18 @@
 use base64::Engine as _;
 use oxidize_pdf::parser::{ParseOptions, PdfDocument, PdfReader};
+use oxidize_pdf::pipeline::Element;
 use oxidize_pdf::signatures;
+use oxidize_pdf::text::ExtractedText;
 use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
 use std::ffi::{CStr, CString};
-use std::io::Cursor;
+use std::io::{Cursor, Read, Seek};
 use std::os::raw::{c_char, c_int};
 use std::ptr;
 use std::slice;
 
+use crate::error_detail::LastError;
+use crate::progress::{extract_pages, partition_pages, Progress, ProgressCallbacks};
 use crate::{clear_last_error, find_char_boundary, set_last_error, ErrorCode};
 
 // ── PDF reader helper ─────────────────────────────────────────────────────────
@@ -18,10 +23,130 @@ use crate::{clear_last_error, find_char_boundary, set_last_error, ErrorCode};
 /// recovery, and other compatibility mechanisms required for real-world PDFs.
 /// This is the only way to create a PdfReader in this module — all FFI
 /// functions must use this helper instead of `PdfReader::new()`.
-pub(crate) fn open_lenient(bytes: &[u8]) -> Result<PdfReader<Cursor<&[u8]>>, String> {
-    let cursor = Cursor::new(bytes);
-    PdfReader::new_with_options(cursor, ParseOptions::lenient())
-        .map_err(|e| format!("Failed to parse PDF: {e}"))
+///
+/// Generic over the byte container so borrowed slices (one-shot entry points)
+/// and owned buffers (`ParsedDocumentHandle`) share the same parse settings.
+///
+/// The configured resource limits are checked here, so every entry point
+/// rejects over-limit documents before decoding any page (see [`crate::limits`]).
+/// While strict parsing is on (see [`crate::recovery_report`]) the document
+/// is parsed strictly instead, so damaged files fail rather than being
+/// repaired. Inside a `*_with_password` call (see [`with_password`]) an
+/// encrypted file is unlocked with the caller's password.
+pub(crate) fn open_lenient<B: AsRef<[u8]>>(bytes: B) -> Result<PdfReader<Cursor<B>>, LastError> {
+    let mut reader = open_reader(Cursor::new(bytes), ParseOptions::lenient())?;
+    if let Some(password) = PASSWORD.with(|p| p.borrow().clone()) {
+        unlock_reader(&mut reader, &password)?;
+    }
+    Ok(reader)
+}
+
+/// [`open_lenient`] without the resource-limit pre-flight, for another
+/// reader over bytes that already passed it (parallel workers).
+pub(crate) fn reopen_lenient(bytes: &[u8]) -> Result<PdfReader<Cursor<&[u8]>>, LastError> {
+    let options = crate::recovery_report::parse_options(ParseOptions::lenient());
+    PdfReader::new_with_options(Cursor::new(bytes), options)
+        .map_err(|e| LastError::caused_by("Failed to parse PDF", &e))
+}
+
+/// [`open_lenient`] over any seekable source and parse options (caller read
+/// callbacks use [`crate::callback_reader::parse_options`]).
+pub(crate) fn open_reader<R: Read + Seek>(
+    source: R,
+    options: ParseOptions,
+) -> Result<PdfReader<R>, LastError> {
+    open_reader_with(source, crate::recovery_report::parse_options(options))
+}
+
+/// [`open_reader`] with exactly `options`, whether or not strict parsing is
+/// on; the recovery report needs the lenient parse to list its repairs.
+pub(crate) fn open_reader_with<R: Read + Seek>(
+    mut source: R,
+    options: ParseOptions,
+) -> Result<PdfReader<R>, LastError> {
+    let limits = crate::limits::current();
+    let object_streams = crate::limits::object_streams(&mut source, &options, &limits);
+    let mut reader = PdfReader::new_with_options(source, options)
+        .map_err(|e| LastError::caused_by("Failed to parse PDF", &e))?;
+    crate::limits::check_object_streams(&mut reader, &object_streams, &limits)?;
+    crate::limits::preflight(&mut reader, &limits)?;
+    Ok(reader)
+}
+
+/// [`open_lenient`], then unlock an encrypted file with `password` (user or
+/// owner password).
+pub(crate) fn open_with_password<B: AsRef<[u8]>>(
+    bytes: B,
+    password: &str,
+) -> Result<PdfReader<Cursor<B>>, LastError> {
+    let mut reader = open_lenient(bytes)?;
+    unlock_reader(&mut reader, password)?;
+    Ok(reader)
+}
+
+/// Unlock an encrypted reader with `password`; a no-op when the file is not
+/// encrypted or already unlocked.
+///
+/// The page tree of a locked file cannot be walked, so the resource limits
+/// are checked again once it is unlocked. A wrong password fails with the
+/// `wrong_password` category (`ErrorCode::PasswordRequired`).
+pub(crate) fn unlock_reader<R: Read + Seek>(
+    reader: &mut PdfReader<R>,
+    password: &str,
+) -> Result<(), LastError> {
+    if reader.is_encrypted() && !reader.is_unlocked() {
+        reader
+            .unlock(password)
+            .map_err(|e| LastError::caused_by("Failed to unlock PDF", &e))?;
+        crate::limits::preflight(reader, &crate::limits::current())?;
+    }
+    Ok(())
+}
+
+thread_local! {
+    /// Password of the `*_with_password` call running on this thread.
+    static PASSWORD: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Run the entry point `f` with `password` armed, so every document it opens
+/// with [`open_lenient`] is unlocked as by [`open_with_password`]; this is
+/// how the `*_with_password` variants of the one-shot, chunk-stream and page
+/// functions take a password. Unencrypted documents ignore it.
+///
+/// A null `password` fails with `NullPointer` and a non-UTF-8 one with
+/// `InvalidUtf8` before `f` runs; a wrong one fails the open with
+/// `PasswordRequired` (category `wrong_password`).
+pub(crate) unsafe fn with_password(password: *const c_char, f: impl FnOnce() -> c_int) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if password.is_null() {
+            set_last_error("Null password provided");
+            return ErrorCode::NullPointer as c_int;
+        }
+        let password = match password_arg(password) {
+            Ok(p) => p.to_owned(),
+            Err(code) => return code,
+        };
+        let _armed = ArmedPassword(PASSWORD.with(|p| p.replace(Some(password))));
+        f()
+    })
+}
+
+/// Restores the password armed before a [`with_password`] call.
+struct ArmedPassword(Option<String>);
+
+impl Drop for ArmedPassword {
+    fn drop(&mut self) {
+        PASSWORD.with(|p| *p.borrow_mut() = self.0.take());
+    }
+}
+
+/// Read a NUL-terminated UTF-8 password argument.
+pub(crate) unsafe fn password_arg<'a>(password: *const c_char) -> Result<&'a str, c_int> {
+    CStr::from_ptr(password).to_str().map_err(|_| {
+        set_last_error("Invalid UTF-8 in password");
+        ErrorCode::InvalidUtf8 as c_int
+    })
 }
 
 // ── Chunk types ───────────────────────────────────────────────────────────────
@@ -51,7 +176,7 @@ pub struct ChunkOptions {
 
 // ── Internal helpers ──────────────────────────────────────────────────────────
 
-fn default_chunk_options() -> ChunkOptions {
+pub(crate) fn default_chunk_options() -> ChunkOptions {
     ChunkOptions {
         max_chunk_size: 512,
         overlap: 50,
@@ -126,19 +251,113 @@ fn chunks_to_cstring(chunks: &[DocumentChunk]) -> Result<CString, c_int> {
     let json = match serde_json::to_string(chunks) {
         Ok(j) => j,
         Err(e) => {
-            set_last_error(format!("Failed to serialize chunks to JSON: {e}"));
+            set_last_error(LastError::caused_by(
+                "Failed to serialize chunks to JSON",
+                &e,
+            ));
             return Err(ErrorCode::SerializationError as c_int);
         }
     };
     match CString::new(json) {
         Ok(cs) => Ok(cs),
         Err(e) => {
-            set_last_error(format!("JSON contains invalid UTF-8: {e}"));
+            set_last_error(LastError::caused_by("JSON contains invalid UTF-8", &e));
             Err(ErrorCode::InvalidUtf8 as c_int)
         }
     }
 }
 
+/// Parse a NUL-terminated UTF-8 JSON C string into `D`.
+///
+/// Errors are recorded with the parameter name (`InvalidUtf8`) or the config
+/// type name (`InvalidArgument`), e.g. "invalid PartitionConfig JSON: …".
+pub(crate) unsafe fn parse_json_arg<D: serde::de::DeserializeOwned>(
+    json: *const c_char,
+    param: &str,
+    what: &str,
+) -> Result<D, c_int> {
+    let s = match CStr::from_ptr(json).to_str() {
+        Ok(v) => v,
+        Err(e) => {
+            set_last_error(LastError::caused_by(
+                format_args!("invalid UTF-8 in {param}"),
+                &e,
+            ));
+            return Err(ErrorCode::InvalidUtf8 as c_int);
+        }
+    };
+    serde_json::from_str(s).map_err(|e| {
+        set_last_error(LastError::caused_by(
+            format_args!("invalid {what} JSON"),
+            &e,
+        ));
+        ErrorCode::InvalidArgument as c_int
+    })
+}
+
+/// Parse a pipeline config DTO, validating every field first (see
+/// [`crate::pipeline_config::from_json_value`]).
+///
+/// Invalid fields are reported together as `InvalidArgument`, e.g.
+/// "invalid PartitionConfig in config_json: $.header_zone must be a number
+/// in [0, 1], got 1.5; …".
+pub(crate) unsafe fn parse_config_arg<D: crate::pipeline_config::ValidatedConfig>(
+    json: *const c_char,
+    param: &str,
+    what: &str,
+) -> Result<D, c_int> {
+    let value: serde_json::Value = parse_json_arg(json, param, what)?;
+    crate::pipeline_config::from_json_value(value).map_err(|e| {
+        set_last_error(LastError::caused_by(
+            format_args!("invalid {what} in {param}"),
+            &e,
+        ));
+        ErrorCode::InvalidArgument as c_int
+    })
+}
+
+/// Parse an optional `PartitionConfigDto` JSON (`NULL` → upstream default).
+pub(crate) unsafe fn parse_partition_config_opt(
+    json: *const c_char,
+) -> Result<oxidize_pdf::pipeline::PartitionConfig, c_int> {
+    if json.is_null() {
+        return Ok(oxidize_pdf::pipeline::PartitionConfig::default());
+    }
+    parse_config_arg::<crate::pipeline_config::PartitionConfigDto>(
+        json,
+        "partition_config_json",
+        "PartitionConfig",
+    )
+    .map(Into::into)
+}
+
+/// Parse an optional `HybridChunkConfigDto` JSON (`NULL` → upstream default).
+pub(crate) unsafe fn parse_hybrid_config_opt(
+    json: *const c_char,
+) -> Result<oxidize_pdf::pipeline::HybridChunkConfig, c_int> {
+    if json.is_null() {
+        return Ok(oxidize_pdf::pipeline::HybridChunkConfig::default());
+    }
+    parse_config_arg::<crate::pipeline_config::HybridChunkConfigDto>(
+        json,
+        "hybrid_config_json",
+        "HybridChunkConfig",
+    )
+    .map(Into::into)
+}
+
+/// Parse a required `SemanticChunkConfigDto` JSON.
+pub(crate) unsafe fn parse_semantic_config(
+    json: *const c_char,
+) -> Result<oxidize_pdf::pipeline::SemanticChunkConfig, c_int> {
+    parse_config_arg::<crate::pipeline_config::SemanticChunkConfigDto>(
+        json,
+        "semantic_config_json",
+        "SemanticChunkConfig",
+    )
+    .map(Into::into)
+}
+
 // ── Extraction options (FFI-compatible) ─────────────────────────────────────
 
 /// FFI-compatible extraction options struct.
@@ -170,7 +389,7 @@ pub struct ExtractionOptionsFFI {
 }
 
 impl ExtractionOptionsFFI {
-    fn to_core(self) -> oxidize_pdf::text::ExtractionOptions {
+    pub(crate) fn to_core(self) -> oxidize_pdf::text::ExtractionOptions {
         oxidize_pdf::text::ExtractionOptions {
             preserve_layout: self.preserve_layout,
             space_threshold: self.space_threshold,
@@ -181,6 +400,7 @@ impl ExtractionOptionsFFI {
             column_threshold: self.column_threshold,
             merge_hyphenated: self.merge_hyphenated,
             track_space_decisions: false,
+            reorder_columns: false,
             reconstruct_paragraphs: self.reconstruct_paragraphs,
             include_artifacts: self.include_artifacts,
         }
@@ -191,7 +411,7 @@ impl ExtractionOptionsFFI {
 
 /// Serialization-friendly element struct for FFI output (partition).
 #[derive(Debug, Serialize)]
-struct PdfElementResult {
+pub(crate) struct PdfElementResult {
     element_type: String,
     text: String,
     page_number: u32,
@@ -204,7 +424,7 @@ struct PdfElementResult {
 
 /// Serialization-friendly RAG chunk struct for FFI output.
 #[derive(Debug, Serialize)]
-struct RagChunkResult {
+pub(crate) struct RagChunkResult {
     chunk_index: usize,
     text: String,
     full_text: String,
@@ -220,7 +440,7 @@ struct RagChunkResult {
 /// (`text()`, `token_estimate()`, `page_numbers()`, `is_oversized()`).
 /// Page numbers are emitted 1-based (FFI contract).
 #[derive(Debug, Serialize)]
-struct SemanticChunkResult {
+pub(crate) struct SemanticChunkResult {
     chunk_index: usize,
     text: String,
     page_numbers: Vec<u32>,
@@ -228,6 +448,36 @@ struct SemanticChunkResult {
     is_oversized: bool,
 }
 
+impl RagChunkResult {
+    pub(crate) fn from_core(chunk_index: usize, chunk: &oxidize_pdf::pipeline::RagChunk) -> Self {
+        Self {
+            chunk_index,
+            text: chunk.text.clone(),
+            full_text: chunk.full_text.clone(),
+            page_numbers: chunk.page_numbers.iter().map(|p| p + 1).collect(), // 0-based to 1-based
+            element_types: chunk.element_types.clone(),
+            heading_context: chunk.heading_context.clone(),
+            token_estimate: chunk.token_estimate,
+            is_oversized: chunk.is_oversized,
+        }
+    }
+}
+
+impl SemanticChunkResult {
+    pub(crate) fn from_core(
+        chunk_index: usize,
+        chunk: &oxidize_pdf::pipeline::SemanticChunk,
+    ) -> Self {
+        Self {
+            chunk_index,
+            text: chunk.text(),
+            page_numbers: chunk.page_numbers().into_iter().map(|p| p + 1).collect(),
+            token_estimate: chunk.token_estimate(),
+            is_oversized: chunk.is_oversized(),
+        }
+    }
+}
+
 /// Serialization-friendly text-chunk struct for the standalone
 /// `DocumentChunker` FFI (`oxidize_chunk_text`). Mirrors
 /// `oxidize_pdf::ai::DocumentChunk`'s public scalar fields, intentionally
@@ -291,13 +541,13 @@ struct SignatureVerificationFFIResult {
 // ── Form field result types ───────────────────────────────────────────────────
 
 #[derive(Debug, Serialize)]
-struct FormFieldOptionResult {
+pub(crate) struct FormFieldOptionResult {
     export_value: String,
     display_text: String,
 }
 
 #[derive(Debug, Serialize)]
-struct FormFieldResult {
+pub(crate) struct FormFieldResult {
     field_name: String,
     field_type: String,
     page_number: u32,
@@ -342,7 +592,7 @@ struct ContentStreamResult {
 
 /// Serialization-friendly annotation struct for FFI output.
 #[derive(Debug, Serialize)]
-struct AnnotationResult {
+pub(crate) struct AnnotationResult {
     subtype: String,
     contents: Option<String>,
     title: Option<String>,
@@ -406,30 +656,90 @@ pub unsafe extern "C" fn oxidize_extract_text(
         };
 
         let document = PdfDocument::new(reader);
-        let text_pages = match document.extract_text() {
-            Ok(pages) => pages,
-            Err(e) => {
-                set_last_error(format!("Failed to extract text from PDF: {e}"));
-                return ErrorCode::PdfParseError as c_int;
-            }
-        };
+        extract_text_impl(&document, Progress::NONE, out_text)
+    })
+}
 
-        let text = text_pages
-            .iter()
-            .map(|p| p.text.as_str())
-            .collect::<Vec<_>>()
-            .join("\n\n");
+/// Body of [`oxidize_extract_text`] over an already-opened document.
+pub(crate) unsafe fn extract_text_impl<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    progress: Progress,
+    out_text: *mut *mut c_char,
+) -> c_int {
+    match extract_pages(document, Default::default(), progress) {
+        Ok(pages) => write_joined_text(&pages, out_text),
+        Err(code) => code,
+    }
+}
 
-        let c_string = match CString::new(text) {
-            Ok(s) => s,
+/// Join page texts as [`oxidize_extract_text`] does (blank line between
+/// pages) into `*out_text`.
+pub(crate) unsafe fn write_joined_text(
+    text_pages: &[ExtractedText],
+    out_text: *mut *mut c_char,
+) -> c_int {
+    let text = text_pages
+        .iter()
+        .map(|p| p.text.as_str())
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+    let c_string = match CString::new(text) {
+        Ok(s) => s,
+        Err(e) => {
+            set_last_error(LastError::caused_by("Text contains invalid UTF-8", &e));
+            return ErrorCode::InvalidUtf8 as c_int;
+        }
+    };
+
+    *out_text = c_string.into_raw();
+    ErrorCode::Success as c_int
+}
+
+/// [`oxidize_extract_text`] with per-page progress reporting and cancellation.
+///
+/// `progress` may be null (behaves like [`oxidize_extract_text`]). See
+/// [`crate::progress`] for the callback contract. Returns `Cancelled` when
+/// the caller's `is_cancelled` callback fires; no text is returned then.
+///
+/// # Safety
+/// - `pdf_bytes` must be a valid pointer to `pdf_len` bytes.
+/// - `progress` must be null or point to a valid `ProgressCallbacks` for
+///   the duration of the call.
+/// - `out_text` will be allocated by this function and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_extract_text_with_progress(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    progress: *const ProgressCallbacks,
+    out_text: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+
+        if pdf_bytes.is_null() || out_text.is_null() {
+            set_last_error("Null pointer provided to oxidize_extract_text_with_progress");
+            return ErrorCode::NullPointer as c_int;
+        }
+
+        *out_text = ptr::null_mut();
+
+        if pdf_len == 0 {
+            set_last_error("PDF data is empty (0 bytes)");
+            return ErrorCode::PdfParseError as c_int;
+        }
+
+        let bytes = slice::from_raw_parts(pdf_bytes, pdf_len);
+        let reader = match open_lenient(bytes) {
+            Ok(r) => r,
             Err(e) => {
-                set_last_error(format!("Text contains invalid UTF-8: {e}"));
-                return ErrorCode::InvalidUtf8 as c_int;
+                set_last_error(e);
+                return ErrorCode::PdfParseError as c_int;
             }
         };
 
-        *out_text = c_string.into_raw();
-        ErrorCode::Success as c_int
+        let document = PdfDocument::new(reader);
+        extract_text_impl(&document, Progress::from_ptr(progress), out_text)
     })
 }
 
@@ -479,35 +789,44 @@ pub unsafe extern "C" fn oxidize_extract_chunks(
         };
 
         let document = PdfDocument::new(reader);
-        let text_pages = match document.extract_text() {
-            Ok(pages) => pages,
-            Err(e) => {
-                set_last_error(format!("Failed to extract text from PDF: {e}"));
-                return ErrorCode::PdfParseError as c_int;
-            }
-        };
-
-        let mut chunks = Vec::new();
-        let mut chunk_index = 0;
+        extract_chunks_impl(&document, chunk_opts, out_json)
+    })
+}
 
-        for (page_num, page_text) in text_pages.iter().enumerate() {
-            chunk_page_content(
-                &page_text.text,
-                page_num + 1,
-                chunk_opts,
-                &mut chunks,
-                &mut chunk_index,
-            );
+/// Body of [`oxidize_extract_chunks`] over an already-opened document.
+pub(crate) unsafe fn extract_chunks_impl<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    chunk_opts: ChunkOptions,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    let text_pages = match document.extract_text() {
+        Ok(pages) => pages,
+        Err(e) => {
+            set_last_error(LastError::caused_by("Failed to extract text from PDF", &e));
+            return ErrorCode::PdfParseError as c_int;
         }
+    };
 
-        match chunks_to_cstring(&chunks) {
-            Ok(cs) => {
-                *out_json = cs.into_raw();
-                ErrorCode::Success as c_int
-            }
-            Err(code) => code,
+    let mut chunks = Vec::new();
+    let mut chunk_index = 0;
+
+    for (page_num, page_text) in text_pages.iter().enumerate() {
+        chunk_page_content(
+            &page_text.text,
+            page_num + 1,
+            chunk_opts,
+            &mut chunks,
+            &mut chunk_index,
+        );
+    }
+
+    match chunks_to_cstring(&chunks) {
+        Ok(cs) => {
+            *out_json = cs.into_raw();
+            ErrorCode::Success as c_int
         }
-    })
+        Err(code) => code,
+    }
 }
 
 /// Get the number of pages in a PDF.
@@ -547,19 +866,27 @@ pub unsafe extern "C" fn oxidize_get_page_count(
         };
 
         let document = PdfDocument::new(reader);
-        let count = match document.page_count() {
-            Ok(c) => c,
-            Err(e) => {
-                set_last_error(format!("Failed to get page count: {e}"));
-                return ErrorCode::PdfParseError as c_int;
-            }
-        };
-
-        *out_count = count as usize;
-        ErrorCode::Success as c_int
+        get_page_count_impl(&document, out_count)
     })
 }
 
+/// Body of [`oxidize_get_page_count`] over an already-opened document.
+pub(crate) unsafe fn get_page_count_impl<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    out_count: *mut usize,
+) -> c_int {
+    let count = match document.page_count() {
+        Ok(c) => c,
+        Err(e) => {
+            set_last_error(LastError::caused_by("Failed to get page count", &e));
+            return ErrorCode::PdfParseError as c_int;
+        }
+    };
+
+    *out_count = count as usize;
+    ErrorCode::Success as c_int
+}
+
 /// Extract plain text from a specific page of a PDF (1-based page number).
 ///
 /// # Safety
@@ -591,7 +918,7 @@ pub unsafe extern "C" fn oxidize_extract_text_from_page(
 
         if page_number == 0 {
             set_last_error("Page number must be >= 1 (1-based indexing)");
-            return ErrorCode::PdfParseError as c_int;
+            return ErrorCode::PageOutOfRange as c_int;
         }
 
         let bytes = slice::from_raw_parts(pdf_bytes, pdf_len);
@@ -604,36 +931,57 @@ pub unsafe extern "C" fn oxidize_extract_text_from_page(
         };
 
         let document = PdfDocument::new(reader);
-        let text_pages = match document.extract_text() {
-            Ok(pages) => pages,
-            Err(e) => {
-                set_last_error(format!("Failed to extract text from PDF: {e}"));
-                return ErrorCode::PdfParseError as c_int;
-            }
-        };
+        extract_text_from_page_impl(&document, page_number, out_text)
+    })
+}
 
-        let page_index = page_number - 1;
-        if page_index >= text_pages.len() {
-            set_last_error(format!(
-                "Page number {page_number} is out of range (PDF has {} pages)",
-                text_pages.len()
-            ));
-            return ErrorCode::PdfParseError as c_int;
-        }
+/// Text of one page (`page_number` >= 1), extracting only that page once
+/// it is known to be in range.
+fn extract_page_text<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    page_number: usize,
+) -> Result<ExtractedText, c_int> {
+    let page_count = document.page_count().map_err(|e| {
+        set_last_error(LastError::caused_by("Failed to get page count", &e));
+        ErrorCode::PdfParseError as c_int
+    })?;
+    let page_index = page_number - 1;
+    if page_index >= page_count as usize {
+        set_last_error(format!(
+            "Page number {page_number} is out of range (PDF has {page_count} pages)"
+        ));
+        return Err(ErrorCode::PageOutOfRange as c_int);
+    }
+    document
+        .extract_text_from_page(page_index as u32)
+        .map_err(|e| {
+            set_last_error(LastError::caused_by("Failed to extract text from PDF", &e));
+            ErrorCode::PdfParseError as c_int
+        })
+}
 
-        let text = &text_pages[page_index].text;
+/// Body of [`oxidize_extract_text_from_page`] over an already-opened
+/// document. `page_number` must already be validated as >= 1.
+pub(crate) unsafe fn extract_text_from_page_impl<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    page_number: usize,
+    out_text: *mut *mut c_char,
+) -> c_int {
+    let page_text = match extract_page_text(document, page_number) {
+        Ok(text) => text,
+        Err(code) => return code,
+    };
 
-        let c_string = match CString::new(text.as_str()) {
-            Ok(s) => s,
-            Err(e) => {
-                set_last_error(format!("Text contains invalid UTF-8: {e}"));
-                return ErrorCode::InvalidUtf8 as c_int;
-            }
-        };
+    let c_string = match CString::new(page_text.text) {
+        Ok(s) => s,
+        Err(e) => {
+            set_last_error(LastError::caused_by("Text contains invalid UTF-8", &e));
+            return ErrorCode::InvalidUtf8 as c_int;
+        }
+    };
 
-        *out_text = c_string.into_raw();
-        ErrorCode::Success as c_int
-    })
+    *out_text = c_string.into_raw();
+    ErrorCode::Success as c_int
 }
 
 /// Extract text chunks from a specific page of a PDF (1-based page number).
@@ -670,7 +1018,7 @@ pub unsafe extern "C" fn oxidize_extract_chunks_from_page(
 
         if page_number == 0 {
             set_last_error("Page number must be >= 1 (1-based indexing)");
-            return ErrorCode::PdfParseError as c_int;
+            return ErrorCode::PageOutOfRange as c_int;
         }
 
         let chunk_opts = if options.is_null() {
@@ -689,43 +1037,41 @@ pub unsafe extern "C" fn oxidize_extract_chunks_from_page(
         };
 
         let document = PdfDocument::new(reader);
-        let text_pages = match document.extract_text() {
-            Ok(pages) => pages,
-            Err(e) => {
-                set_last_error(format!("Failed to extract text from PDF: {e}"));
-                return ErrorCode::PdfParseError as c_int;
-            }
-        };
-
-        let page_index = page_number - 1;
-        if page_index >= text_pages.len() {
-            set_last_error(format!(
-                "Page number {page_number} is out of range (PDF has {} pages)",
-                text_pages.len()
-            ));
-            return ErrorCode::PdfParseError as c_int;
-        }
+        extract_chunks_from_page_impl(&document, page_number, chunk_opts, out_json)
+    })
+}
 
-        let page_text = &text_pages[page_index];
-        let mut chunks = Vec::new();
-        let mut chunk_index = 0;
+/// Body of [`oxidize_extract_chunks_from_page`] over an already-opened
+/// document. `page_number` must already be validated as >= 1.
+pub(crate) unsafe fn extract_chunks_from_page_impl<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    page_number: usize,
+    chunk_opts: ChunkOptions,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    let page_text = match extract_page_text(document, page_number) {
+        Ok(text) => text,
+        Err(code) => return code,
+    };
 
-        chunk_page_content(
-            &page_text.text,
-            page_number,
-            chunk_opts,
-            &mut chunks,
-            &mut chunk_index,
-        );
+    let mut chunks = Vec::new();
+    let mut chunk_index = 0;
 
-        match chunks_to_cstring(&chunks) {
-            Ok(cs) => {
-                *out_json = cs.into_raw();
-                ErrorCode::Success as c_int
-            }
-            Err(code) => code,
+    chunk_page_content(
+        &page_text.text,
+        page_number,
+        chunk_opts,
+        &mut chunks,
+        &mut chunk_index,
+    );
+
+    match chunks_to_cstring(&chunks) {
+        Ok(cs) => {
+            *out_json = cs.into_raw();
+            ErrorCode::Success as c_int
         }
-    })
+        Err(code) => code,
+    }
 }
 
 // ── Additional parser FFI functions ─────────────────────────────────────────
@@ -814,7 +1160,7 @@ pub unsafe extern "C" fn oxidize_unlock_pdf(
                 ErrorCode::Success as c_int
             }
             Err(e) => {
-                set_last_error(format!("Failed to unlock PDF: {e}"));
+                set_last_error(LastError::caused_by("Failed to unlock PDF", &e));
                 ErrorCode::EncryptionError as c_int
             }
         }
@@ -856,7 +1202,10 @@ pub unsafe extern "C" fn oxidize_get_pdf_version(
         let c_string = match CString::new(version) {
             Ok(s) => s,
             Err(e) => {
-                set_last_error(format!("Version string contains invalid data: {e}"));
+                set_last_error(LastError::caused_by(
+                    "Version string contains invalid data",
+                    &e,
+                ));
                 return ErrorCode::InvalidUtf8 as c_int;
             }
         };
@@ -893,7 +1242,7 @@ pub unsafe extern "C" fn oxidize_get_page_dimensions(
         }
         if page_number == 0 {
             set_last_error("Page number must be >= 1 (1-based indexing)");
-            return ErrorCode::PdfParseError as c_int;
+            return ErrorCode::PageOutOfRange as c_int;
         }
         let bytes = slice::from_raw_parts(pdf_bytes, pdf_len);
         let reader = match open_lenient(bytes) {
@@ -904,20 +1253,34 @@ pub unsafe extern "C" fn oxidize_get_page_dimensions(
             }
         };
         let document = PdfDocument::new(reader);
-        let page_index = (page_number - 1) as u32;
-        let page = match document.get_page(page_index) {
-            Ok(p) => p,
-            Err(e) => {
-                set_last_error(format!("Failed to get page {page_number}: {e}"));
-                return ErrorCode::PdfParseError as c_int;
-            }
-        };
-        *out_width = page.width();
-        *out_height = page.height();
-        ErrorCode::Success as c_int
+        get_page_dimensions_impl(&document, page_number, out_width, out_height)
     })
 }
 
+/// Body of [`oxidize_get_page_dimensions`] over an already-opened document.
+/// `page_number` must already be validated as >= 1.
+pub(crate) unsafe fn get_page_dimensions_impl<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    page_number: usize,
+    out_width: *mut f64,
+    out_height: *mut f64,
+) -> c_int {
+    let page_index = (page_number - 1) as u32;
+    let page = match document.get_page(page_index) {
+        Ok(p) => p,
+        Err(e) => {
+            set_last_error(
+                LastError::caused_by(format_args!("Failed to get page {page_number}"), &e)
+                    .at_page(page_index),
+            );
+            return ErrorCode::PdfParseError as c_int;
+        }
+    };
+    *out_width = page.width();
+    *out_height = page.height();
+    ErrorCode::Success as c_int
+}
+
 /// Extract document metadata (Info dictionary + version + page count) from a PDF.
 ///
 /// # Safety
@@ -955,46 +1318,57 @@ pub unsafe extern "C" fn oxidize_get_metadata(
         };
 
         let document = PdfDocument::new(reader);
-        let metadata = match document.metadata() {
-            Ok(m) => m,
-            Err(e) => {
-                set_last_error(format!("Failed to extract metadata: {e}"));
-                return ErrorCode::PdfParseError as c_int;
-            }
-        };
+        get_metadata_impl(&document, out_json)
+    })
+}
 
-        let result = MetadataResult {
-            title: metadata.title,
-            author: metadata.author,
-            subject: metadata.subject,
-            keywords: metadata.keywords,
-            creator: metadata.creator,
-            producer: metadata.producer,
-            creation_date: metadata.creation_date,
-            modification_date: metadata.modification_date,
-            version: metadata.version,
-            page_count: metadata.page_count,
-        };
+/// Body of [`oxidize_get_metadata`] over an already-opened document.
+pub(crate) unsafe fn get_metadata_impl<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    let metadata = match document.metadata() {
+        Ok(m) => m,
+        Err(e) => {
+            set_last_error(LastError::caused_by("Failed to extract metadata", &e));
+            return ErrorCode::PdfParseError as c_int;
+        }
+    };
 
-        let json = match serde_json::to_string(&result) {
-            Ok(j) => j,
-            Err(e) => {
-                set_last_error(format!("Failed to serialize metadata to JSON: {e}"));
-                return ErrorCode::SerializationError as c_int;
-            }
-        };
+    let result = MetadataResult {
+        title: metadata.title,
+        author: metadata.author,
+        subject: metadata.subject,
+        keywords: metadata.keywords,
+        creator: metadata.creator,
+        producer: metadata.producer,
+        creation_date: metadata.creation_date,
+        modification_date: metadata.modification_date,
+        version: metadata.version,
+        page_count: metadata.page_count,
+    };
 
-        let c_string = match CString::new(json) {
-            Ok(cs) => cs,
-            Err(e) => {
-                set_last_error(format!("JSON contains null bytes: {e}"));
-                return ErrorCode::InvalidUtf8 as c_int;
-            }
-        };
+    let json = match serde_json::to_string(&result) {
+        Ok(j) => j,
+        Err(e) => {
+            set_last_error(LastError::caused_by(
+                "Failed to serialize metadata to JSON",
+                &e,
+            ));
+            return ErrorCode::SerializationError as c_int;
+        }
+    };
 
-        *out_json = c_string.into_raw();
-        ErrorCode::Success as c_int
-    })
+    let c_string = match CString::new(json) {
+        Ok(cs) => cs,
+        Err(e) => {
+            set_last_error(LastError::caused_by("JSON contains null bytes", &e));
+            return ErrorCode::InvalidUtf8 as c_int;
+        }
+    };
+
+    *out_json = c_string.into_raw();
+    ErrorCode::Success as c_int
 }
 
 /// Extract plain text from PDF bytes using custom extraction options.
@@ -1041,36 +1415,49 @@ pub unsafe extern "C" fn oxidize_extract_text_with_options(
             (*options).to_core()
         };
         let document = PdfDocument::new(reader);
-        let text_pages = match document.extract_text_with_options(core_options) {
-            Ok(pages) => pages,
-            Err(e) => {
-                set_last_error(format!("Failed to extract text with options: {e}"));
-                return ErrorCode::PdfParseError as c_int;
-            }
-        };
+        extract_text_with_options_impl(&document, core_options, out_text)
+    })
+}
 
-        let text = text_pages
-            .iter()
-            .map(|p| p.text.as_str())
-            .collect::<Vec<_>>()
-            .join("\n\n");
+/// Body of [`oxidize_extract_text_with_options`] over an already-opened
+/// document.
+pub(crate) unsafe fn extract_text_with_options_impl<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    core_options: oxidize_pdf::text::ExtractionOptions,
+    out_text: *mut *mut c_char,
+) -> c_int {
+    let text_pages = match document.extract_text_with_options(core_options) {
+        Ok(pages) => pages,
+        Err(e) => {
+            set_last_error(LastError::caused_by(
+                "Failed to extract text with options",
+                &e,
+            ));
+            return ErrorCode::PdfParseError as c_int;
+        }
+    };
 
-        let c_string = match CString::new(text) {
-            Ok(s) => s,
-            Err(e) => {
-                set_last_error(format!("Text contains invalid UTF-8: {e}"));
-                return ErrorCode::InvalidUtf8 as c_int;
-            }
-        };
+    let text = text_pages
+        .iter()
+        .map(|p| p.text.as_str())
+        .collect::<Vec<_>>()
+        .join("\n\n");
 
-        *out_text = c_string.into_raw();
-        ErrorCode::Success as c_int
-    })
+    let c_string = match CString::new(text) {
+        Ok(s) => s,
+        Err(e) => {
+            set_last_error(LastError::caused_by("Text contains invalid UTF-8", &e));
+            return ErrorCode::InvalidUtf8 as c_int;
+        }
+    };
+
+    *out_text = c_string.into_raw();
+    ErrorCode::Success as c_int
 }
 
 // ── Structured export helpers ────────────────────────────────────────────────
 
-type ExportFn = fn(&PdfDocument<Cursor<&[u8]>>) -> Result<String, oxidize_pdf::error::PdfError>;
+type ExportFn<R> = fn(&PdfDocument<R>) -> Result<String, oxidize_pdf::error::PdfError>;
 
 /// Common implementation for structured export functions.
 /// Opens the PDF, calls `export_fn` on the PdfDocument, and returns the result as a C string.
@@ -1079,7 +1466,7 @@ unsafe fn structured_export_impl(
     pdf_len: usize,
     out_text: *mut *mut c_char,
     fn_name: &str,
-    export_fn: ExportFn,
+    export_fn: ExportFn<Cursor<&[u8]>>,
 ) -> c_int {
     clear_last_error();
 
@@ -1105,10 +1492,24 @@ unsafe fn structured_export_impl(
     };
 
     let document = PdfDocument::new(reader);
-    let output = match export_fn(&document) {
+    structured_export_document(&document, out_text, fn_name, export_fn)
+}
+
+/// Run `export_fn` on an already-opened document and return the result as a
+/// C string. Shared by the bytes and `ParsedDocumentHandle` export functions.
+pub(crate) unsafe fn structured_export_document<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    out_text: *mut *mut c_char,
+    fn_name: &str,
+    export_fn: ExportFn<R>,
+) -> c_int {
+    let output = match export_fn(document) {
         Ok(s) => s,
         Err(e) => {
-            set_last_error(format!("Failed in {fn_name}: {e}"));
+            set_last_error(LastError::caused_by(
+                format_args!("Failed in {fn_name}"),
+                &e,
+            ));
             return ErrorCode::PdfParseError as c_int;
         }
     };
@@ -1116,7 +1517,7 @@ unsafe fn structured_export_impl(
     let c_string = match CString::new(output) {
         Ok(s) => s,
         Err(e) => {
-            set_last_error(format!("Output contains null bytes: {e}"));
+            set_last_error(LastError::caused_by("Output contains null bytes", &e));
             return ErrorCode::InvalidUtf8 as c_int;
         }
     };
@@ -1180,21 +1581,14 @@ pub unsafe extern "C" fn oxidize_to_markdown_with_options(
             return ErrorCode::PdfParseError as c_int;
         }
 
-        let opts_str = match CStr::from_ptr(options_json).to_str() {
-            Ok(s) => s,
-            Err(e) => {
-                set_last_error(format!("invalid UTF-8 in options_json: {e}"));
-                return ErrorCode::InvalidUtf8 as c_int;
-            }
-        };
-
         let opts: oxidize_pdf::ai::MarkdownOptions =
-            match serde_json::from_str::<crate::pipeline_config::MarkdownOptionsDto>(opts_str) {
+            match parse_config_arg::<crate::pipeline_config::MarkdownOptionsDto>(
+                options_json,
+                "options_json",
+                "MarkdownOptions",
+            ) {
                 Ok(d) => d.into(),
-                Err(e) => {
-                    set_last_error(format!("invalid MarkdownOptions JSON: {e}"));
-                    return ErrorCode::InvalidArgument as c_int;
-                }
+                Err(code) => return code,
             };
 
         let bytes = slice::from_raw_parts(pdf_bytes, pdf_len);
@@ -1207,82 +1601,88 @@ pub unsafe extern "C" fn oxidize_to_markdown_with_options(
         };
 
         let document = PdfDocument::new(reader);
+        to_markdown_with_options_impl(&document, opts, out_text)
+    })
+}
 
-        // Extract per-page text once — used regardless of flag combination.
-        let extracted = match document.extract_text() {
-            Ok(t) => t,
-            Err(e) => {
-                set_last_error(format!("Failed to extract text: {e}"));
-                return ErrorCode::PdfParseError as c_int;
-            }
-        };
-        let pages: Vec<(usize, String)> = extracted
-            .iter()
-            .enumerate()
-            .map(|(i, p)| (i + 1, p.text.clone()))
-            .collect();
-        let flat_text = pages
-            .iter()
-            .map(|(_, t)| t.clone())
-            .collect::<Vec<_>>()
-            .join("\n\n");
-
-        // Dispatch by the flag matrix. Each branch uses the concrete static
-        // exporter that matches its semantics — we never call the Phase-1
-        // stub `MarkdownExporter::new(opts).export()`.
-        let md_result = match (opts.include_metadata, opts.include_page_numbers) {
-            (true, _) => {
-                // Need DocumentMetadata for any metadata-enabled path.
-                let parsed = match document.metadata() {
-                    Ok(m) => m,
-                    Err(e) => {
-                        set_last_error(format!("Failed to read PDF metadata: {e}"));
-                        return ErrorCode::PdfParseError as c_int;
-                    }
-                };
-                let ai_metadata = oxidize_pdf::ai::DocumentMetadata {
-                    title: parsed
-                        .title
-                        .unwrap_or_else(|| "Untitled Document".to_string()),
-                    page_count: pages.len(),
-                    created_at: parsed.creation_date.clone(),
-                    author: parsed.author,
-                };
-                if opts.include_page_numbers {
-                    oxidize_pdf::ai::MarkdownExporter::export_with_metadata_and_pages(
-                        &pages,
-                        &ai_metadata,
-                    )
-                } else {
-                    oxidize_pdf::ai::MarkdownExporter::export_with_metadata(
-                        &flat_text,
-                        &ai_metadata,
-                    )
+/// Body of [`oxidize_to_markdown_with_options`] over an already-opened
+/// document.
+pub(crate) unsafe fn to_markdown_with_options_impl<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    opts: oxidize_pdf::ai::MarkdownOptions,
+    out_text: *mut *mut c_char,
+) -> c_int {
+    // Extract per-page text once — used regardless of flag combination.
+    let extracted = match document.extract_text() {
+        Ok(t) => t,
+        Err(e) => {
+            set_last_error(LastError::caused_by("Failed to extract text", &e));
+            return ErrorCode::PdfParseError as c_int;
+        }
+    };
+    let pages: Vec<(usize, String)> = extracted
+        .iter()
+        .enumerate()
+        .map(|(i, p)| (i + 1, p.text.clone()))
+        .collect();
+    let flat_text = pages
+        .iter()
+        .map(|(_, t)| t.clone())
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+    // Dispatch by the flag matrix. Each branch uses the concrete static
+    // exporter that matches its semantics — we never call the Phase-1
+    // stub `MarkdownExporter::new(opts).export()`.
+    let md_result = match (opts.include_metadata, opts.include_page_numbers) {
+        (true, _) => {
+            // Need DocumentMetadata for any metadata-enabled path.
+            let parsed = match document.metadata() {
+                Ok(m) => m,
+                Err(e) => {
+                    set_last_error(LastError::caused_by("Failed to read PDF metadata", &e));
+                    return ErrorCode::PdfParseError as c_int;
                 }
+            };
+            let ai_metadata = oxidize_pdf::ai::DocumentMetadata {
+                title: parsed
+                    .title
+                    .unwrap_or_else(|| "Untitled Document".to_string()),
+                page_count: pages.len(),
+                created_at: parsed.creation_date.clone(),
+                author: parsed.author,
+            };
+            if opts.include_page_numbers {
+                oxidize_pdf::ai::MarkdownExporter::export_with_metadata_and_pages(
+                    &pages,
+                    &ai_metadata,
+                )
+            } else {
+                oxidize_pdf::ai::MarkdownExporter::export_with_metadata(&flat_text, &ai_metadata)
             }
-            (false, true) => oxidize_pdf::ai::MarkdownExporter::export_with_pages(&pages),
-            (false, false) => oxidize_pdf::ai::MarkdownExporter::export_text(&flat_text),
-        };
+        }
+        (false, true) => oxidize_pdf::ai::MarkdownExporter::export_with_pages(&pages),
+        (false, false) => oxidize_pdf::ai::MarkdownExporter::export_text(&flat_text),
+    };
 
-        let md = match md_result {
-            Ok(s) => s,
-            Err(e) => {
-                set_last_error(format!("Markdown export failed: {e}"));
-                return ErrorCode::PdfParseError as c_int;
-            }
-        };
+    let md = match md_result {
+        Ok(s) => s,
+        Err(e) => {
+            set_last_error(LastError::caused_by("Markdown export failed", &e));
+            return ErrorCode::PdfParseError as c_int;
+        }
+    };
 
-        let c_string = match CString::new(md) {
-            Ok(cs) => cs,
-            Err(e) => {
-                set_last_error(format!("Markdown contains null bytes: {e}"));
-                return ErrorCode::InvalidUtf8 as c_int;
-            }
-        };
+    let c_string = match CString::new(md) {
+        Ok(cs) => cs,
+        Err(e) => {
+            set_last_error(LastError::caused_by("Markdown contains null bytes", &e));
+            return ErrorCode::InvalidUtf8 as c_int;
+        }
+    };
 
-        *out_text = c_string.into_raw();
-        ErrorCode::Success as c_int
-    })
+    *out_text = c_string.into_raw();
+    ErrorCode::Success as c_int
 }
 
 /// Export PDF content as Markdown.
@@ -1381,50 +1781,80 @@ pub unsafe extern "C" fn oxidize_partition(
         };
 
         let document = PdfDocument::new(reader);
-        let elements = match document.partition() {
-            Ok(elems) => elems,
-            Err(e) => {
-                set_last_error(format!("Failed to partition PDF: {e}"));
-                return ErrorCode::PdfParseError as c_int;
-            }
-        };
+        partition_impl(&document, out_json)
+    })
+}
 
-        let results: Vec<PdfElementResult> = elements
-            .iter()
-            .map(|el| {
-                let bbox = el.bbox();
-                PdfElementResult {
-                    element_type: el.type_name().to_string(),
-                    text: el.display_text(),
-                    page_number: el.page() + 1, // Convert 0-based to 1-based
-                    x: bbox.x,
-                    y: bbox.y,
-                    width: bbox.width,
-                    height: bbox.height,
-                    confidence: el.metadata().confidence,
-                }
-            })
-            .collect();
+/// Body of [`oxidize_partition`] over an already-opened document.
+pub(crate) unsafe fn partition_impl<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    match partition_results(document) {
+        Ok(results) => write_element_results(&results, out_json),
+        Err(code) => code,
+    }
+}
 
-        let json = match serde_json::to_string(&results) {
-            Ok(j) => j,
-            Err(e) => {
-                set_last_error(format!("Failed to serialize elements: {e}"));
-                return ErrorCode::SerializationError as c_int;
-            }
-        };
+/// Serialize element records into `*out_json`.
+pub(crate) unsafe fn write_element_results(
+    results: &[PdfElementResult],
+    out_json: *mut *mut c_char,
+) -> c_int {
+    let json = match serde_json::to_string(results) {
+        Ok(j) => j,
+        Err(e) => {
+            set_last_error(LastError::caused_by("Failed to serialize elements", &e));
+            return ErrorCode::SerializationError as c_int;
+        }
+    };
 
-        let c_string = match CString::new(json) {
-            Ok(cs) => cs,
-            Err(e) => {
-                set_last_error(format!("JSON contains null bytes: {e}"));
-                return ErrorCode::InvalidUtf8 as c_int;
-            }
-        };
+    let c_string = match CString::new(json) {
+        Ok(cs) => cs,
+        Err(e) => {
+            set_last_error(LastError::caused_by("JSON contains null bytes", &e));
+            return ErrorCode::InvalidUtf8 as c_int;
+        }
+    };
 
-        *out_json = c_string.into_raw();
-        ErrorCode::Success as c_int
-    })
+    *out_json = c_string.into_raw();
+    ErrorCode::Success as c_int
+}
+
+/// Partition `document` into the element records of [`oxidize_partition`].
+/// Sets the last error on failure.
+pub(crate) fn partition_results<R: Read + Seek>(
+    document: &PdfDocument<R>,
+) -> Result<Vec<PdfElementResult>, c_int> {
+    let elements = match document.partition() {
+        Ok(elems) => elems,
+        Err(e) => {
+            set_last_error(LastError::caused_by("Failed to partition PDF", &e));
+            return Err(ErrorCode::PdfParseError as c_int);
+        }
+    };
+
+    Ok(element_results(&elements))
+}
+
+/// Element records (1-based page numbers) for partitioned `elements`.
+pub(crate) fn element_results(elements: &[Element]) -> Vec<PdfElementResult> {
+    elements
+        .iter()
+        .map(|el| {
+            let bbox = el.bbox();
+            PdfElementResult {
+                element_type: el.type_name().to_string(),
+                text: el.display_text(),
+                page_number: el.page() + 1, // Convert 0-based to 1-based
+                x: bbox.x,
+                y: bbox.y,
+                width: bbox.width,
+                height: bbox.height,
+                confidence: el.metadata().confidence,
+            }
+        })
+        .collect()
 }
 
 /// Partition a PDF using a pre-configured extraction profile.
@@ -1440,8 +1870,9 @@ pub unsafe extern "C" fn oxidize_partition(
 /// * `pdf_len` — length in bytes.
 /// * `profile` — `u8` discriminant matching the C# `ExtractionProfile` enum
 ///   (0 = Standard, 1 = Academic, 2 = Form, 3 = Government, 4 = Dense,
-///   5 = Presentation, 6 = Rag). Mapping verified by
-///   [`crate::pipeline_config::profile_from_u8`].
+///   5 = Presentation, 6 = Rag; mapping verified by
+///   [`crate::pipeline_config::profile_from_u8`]), or the id of a custom
+///   profile returned by [`crate::profiles::oxidize_register_profile`].
 /// * `out_json` — receives a heap-allocated UTF-8 JSON array of element
 ///   results. Caller must free with `oxidize_free_string`.
 ///
@@ -1477,7 +1908,7 @@ pub unsafe extern "C" fn oxidize_partition_with_profile(
             return ErrorCode::PdfParseError as c_int;
         }
 
-        let prof = match crate::pipeline_config::profile_from_u8(profile) {
+        let prof = match crate::profiles::Profile::resolve(profile) {
             Ok(p) => p,
             Err(e) => {
                 set_last_error(e);
@@ -1495,52 +1926,23 @@ pub unsafe extern "C" fn oxidize_partition_with_profile(
         };
 
         let document = PdfDocument::new(reader);
-        let elements = match document.partition_with_profile(prof) {
-            Ok(elems) => elems,
-            Err(e) => {
-                set_last_error(format!("Failed to partition PDF: {e}"));
-                return ErrorCode::PdfParseError as c_int;
-            }
-        };
-
-        let results: Vec<PdfElementResult> = elements
-            .iter()
-            .map(|el| {
-                let bbox = el.bbox();
-                PdfElementResult {
-                    element_type: el.type_name().to_string(),
-                    text: el.display_text(),
-                    page_number: el.page() + 1, // Convert 0-based to 1-based
-                    x: bbox.x,
-                    y: bbox.y,
-                    width: bbox.width,
-                    height: bbox.height,
-                    confidence: el.metadata().confidence,
-                }
-            })
-            .collect();
-
-        let json = match serde_json::to_string(&results) {
-            Ok(j) => j,
-            Err(e) => {
-                set_last_error(format!("Failed to serialize elements: {e}"));
-                return ErrorCode::SerializationError as c_int;
-            }
-        };
-
-        let c_string = match CString::new(json) {
-            Ok(cs) => cs,
-            Err(e) => {
-                set_last_error(format!("JSON contains null bytes: {e}"));
-                return ErrorCode::InvalidUtf8 as c_int;
-            }
-        };
-
-        *out_json = c_string.into_raw();
-        ErrorCode::Success as c_int
+        partition_with_profile_impl(&document, &prof, out_json)
     })
 }
 
+/// Body of [`oxidize_partition_with_profile`] over an already-opened
+/// document.
+pub(crate) unsafe fn partition_with_profile_impl<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    prof: &crate::profiles::Profile,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    match prof.partition(document) {
+        Ok(elements) => write_element_results(&element_results(&elements), out_json),
+        Err(code) => code,
+    }
+}
+
 /// Partition a PDF using an explicit `PartitionConfig` supplied as JSON.
 ///
 /// Use this when callers need fine-grained control over the partitioner —
@@ -1564,7 +1966,8 @@ pub unsafe extern "C" fn oxidize_partition_with_profile(
 /// `ErrorCode::Success` on success. Error codes:
 /// - `NullPointer`: any of the three pointer parameters is null.
 /// - `InvalidUtf8`: `config_json` is not valid UTF-8.
-/// - `InvalidArgument`: `config_json` does not deserialize as `PartitionConfigDto`.
+/// - `InvalidArgument`: `config_json` is not valid JSON or fails validation
+///   (the message lists every invalid field with its path and accepted values).
 /// - `PdfParseError`: `pdf_len == 0`, the lenient parser rejects the bytes,
 ///   or `partition_with` itself fails.
 /// - `SerializationError` / `InvalidUtf8`: serde or `CString::new` failure
@@ -1598,21 +2001,11 @@ pub unsafe extern "C" fn oxidize_partition_with_config(
             return ErrorCode::PdfParseError as c_int;
         }
 
-        let cfg_str = match CStr::from_ptr(config_json).to_str() {
-            Ok(s) => s,
-            Err(e) => {
-                set_last_error(format!("invalid UTF-8 in config_json: {e}"));
-                return ErrorCode::InvalidUtf8 as c_int;
-            }
-        };
-
-        let dto: crate::pipeline_config::PartitionConfigDto = match serde_json::from_str(cfg_str) {
-            Ok(d) => d,
-            Err(e) => {
-                set_last_error(format!("invalid PartitionConfig JSON: {e}"));
-                return ErrorCode::InvalidArgument as c_int;
-            }
-        };
+        let dto: crate::pipeline_config::PartitionConfigDto =
+            match parse_config_arg(config_json, "config_json", "PartitionConfig") {
+                Ok(d) => d,
+                Err(code) => return code,
+            };
         let cfg: oxidize_pdf::pipeline::PartitionConfig = dto.into();
 
         let bytes = slice::from_raw_parts(pdf_bytes, pdf_len);
@@ -1625,49 +2018,113 @@ pub unsafe extern "C" fn oxidize_partition_with_config(
         };
 
         let document = PdfDocument::new(reader);
-        let elements = match document.partition_with(cfg) {
-            Ok(elems) => elems,
-            Err(e) => {
-                set_last_error(format!("Failed to partition PDF: {e}"));
-                return ErrorCode::PdfParseError as c_int;
-            }
-        };
+        partition_with_config_impl(&document, cfg, Progress::NONE, out_json)
+    })
+}
 
-        let results: Vec<PdfElementResult> = elements
-            .iter()
-            .map(|el| {
-                let bbox = el.bbox();
-                PdfElementResult {
-                    element_type: el.type_name().to_string(),
-                    text: el.display_text(),
-                    page_number: el.page() + 1,
-                    x: bbox.x,
-                    y: bbox.y,
-                    width: bbox.width,
-                    height: bbox.height,
-                    confidence: el.metadata().confidence,
-                }
-            })
-            .collect();
+/// Body of [`oxidize_partition_with_config`] over an already-opened
+/// document.
+pub(crate) unsafe fn partition_with_config_impl<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    cfg: oxidize_pdf::pipeline::PartitionConfig,
+    progress: Progress,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    let elements = match partition_pages(document, cfg, progress) {
+        Ok(elems) => elems,
+        Err(code) => return code,
+    };
 
-        let json = match serde_json::to_string(&results) {
-            Ok(j) => j,
-            Err(e) => {
-                set_last_error(format!("Failed to serialize elements: {e}"));
-                return ErrorCode::SerializationError as c_int;
+    let results: Vec<PdfElementResult> = elements
+        .iter()
+        .map(|el| {
+            let bbox = el.bbox();
+            PdfElementResult {
+                element_type: el.type_name().to_string(),
+                text: el.display_text(),
+                page_number: el.page() + 1,
+                x: bbox.x,
+                y: bbox.y,
+                width: bbox.width,
+                height: bbox.height,
+                confidence: el.metadata().confidence,
             }
-        };
+        })
+        .collect();
 
-        let c_string = match CString::new(json) {
-            Ok(cs) => cs,
-            Err(e) => {
-                set_last_error(format!("JSON contains null bytes: {e}"));
-                return ErrorCode::InvalidUtf8 as c_int;
-            }
-        };
+    let json = match serde_json::to_string(&results) {
+        Ok(j) => j,
+        Err(e) => {
+            set_last_error(LastError::caused_by("Failed to serialize elements", &e));
+            return ErrorCode::SerializationError as c_int;
+        }
+    };
 
-        *out_json = c_string.into_raw();
-        ErrorCode::Success as c_int
+    let c_string = match CString::new(json) {
+        Ok(cs) => cs,
+        Err(e) => {
+            set_last_error(LastError::caused_by("JSON contains null bytes", &e));
+            return ErrorCode::InvalidUtf8 as c_int;
+        }
+    };
+
+    *out_json = c_string.into_raw();
+    ErrorCode::Success as c_int
+}
+
+/// Partition a PDF with per-page progress reporting and cancellation.
+///
+/// Like [`oxidize_partition_with_config`], except that `config_json` is
+/// optional (`NULL` → `PartitionConfig::default()`) and pages are
+/// partitioned one at a time when `progress` is non-null (see
+/// [`crate::progress::partition_pages`]). Returns `Cancelled` when the
+/// caller's `is_cancelled` callback fires.
+///
+/// # Safety
+/// - `pdf_bytes` must be a valid pointer to `pdf_len` bytes.
+/// - `config_json`, if non-null, must be a NUL-terminated UTF-8 C string.
+/// - `progress` must be null or point to a valid `ProgressCallbacks` for
+///   the duration of the call.
+/// - `out_json` must be a writeable `*mut *mut c_char`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_partition_with_progress(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    config_json: *const c_char,
+    progress: *const ProgressCallbacks,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+
+        if pdf_bytes.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_partition_with_progress");
+            return ErrorCode::NullPointer as c_int;
+        }
+
+        *out_json = ptr::null_mut();
+
+        if pdf_len == 0 {
+            set_last_error("PDF data is empty (0 bytes)");
+            return ErrorCode::PdfParseError as c_int;
+        }
+
+        let cfg = match parse_partition_config_opt(config_json) {
+            Ok(c) => c,
+            Err(code) => return code,
+        };
+
+        let bytes = slice::from_raw_parts(pdf_bytes, pdf_len);
+        let reader = match open_lenient(bytes) {
+            Ok(r) => r,
+            Err(e) => {
+                set_last_error(e);
+                return ErrorCode::PdfParseError as c_int;
+            }
+        };
+
+        let document = PdfDocument::new(reader);
+        partition_with_config_impl(&document, cfg, Progress::from_ptr(progress), out_json)
     })
 }
 
@@ -1675,8 +2132,9 @@ pub unsafe extern "C" fn oxidize_partition_with_config(
 ///
 /// Combines [`oxidize_partition_with_profile`] with the default
 /// `HybridChunker` settings (max_tokens 512, overlap_tokens 50,
-/// `MergePolicy::AnyInlineContent`). Use [`oxidize_rag_chunks_with_config`]
-/// when you need to tune the chunk size or merge policy.
+/// `MergePolicy::AnyInlineContent`), or a custom profile's own `hybrid`
+/// settings. Use [`oxidize_rag_chunks_with_config`] when you need to tune the
+/// chunk size or merge policy.
 ///
 /// # Arguments
 /// * `pdf_bytes` — pointer to `pdf_len` bytes of PDF data.
@@ -1717,7 +2175,7 @@ pub unsafe extern "C" fn oxidize_rag_chunks_with_profile(
             return ErrorCode::PdfParseError as c_int;
         }
 
-        let prof = match crate::pipeline_config::profile_from_u8(profile) {
+        let prof = match crate::profiles::Profile::resolve(profile) {
             Ok(p) => p,
             Err(e) => {
                 set_last_error(e);
@@ -1735,50 +2193,29 @@ pub unsafe extern "C" fn oxidize_rag_chunks_with_profile(
         };
 
         let document = PdfDocument::new(reader);
-        let chunks = match document.rag_chunks_with_profile(prof) {
-            Ok(c) => c,
-            Err(e) => {
-                set_last_error(format!("Failed to extract RAG chunks: {e}"));
-                return ErrorCode::PdfParseError as c_int;
-            }
-        };
-
-        let results: Vec<RagChunkResult> = chunks
-            .iter()
-            .enumerate()
-            .map(|(i, chunk)| RagChunkResult {
-                chunk_index: i,
-                text: chunk.text.clone(),
-                full_text: chunk.full_text.clone(),
-                page_numbers: chunk.page_numbers.iter().map(|p| p + 1).collect(), // 0-based to 1-based
-                element_types: chunk.element_types.clone(),
-                heading_context: chunk.heading_context.clone(),
-                token_estimate: chunk.token_estimate,
-                is_oversized: chunk.is_oversized,
-            })
-            .collect();
-
-        let json = match serde_json::to_string(&results) {
-            Ok(j) => j,
-            Err(e) => {
-                set_last_error(format!("Failed to serialize RAG chunks: {e}"));
-                return ErrorCode::SerializationError as c_int;
-            }
-        };
-
-        let c_string = match CString::new(json) {
-            Ok(cs) => cs,
-            Err(e) => {
-                set_last_error(format!("JSON contains null bytes: {e}"));
-                return ErrorCode::InvalidUtf8 as c_int;
-            }
-        };
-
-        *out_json = c_string.into_raw();
-        ErrorCode::Success as c_int
+        rag_chunks_with_profile_impl(&document, &prof, out_json)
     })
 }
 
+/// Body of [`oxidize_rag_chunks_with_profile`] over an already-opened
+/// document.
+pub(crate) unsafe fn rag_chunks_with_profile_impl<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    prof: &crate::profiles::Profile,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    let chunks = match prof.rag_chunks(document) {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+    let results: Vec<RagChunkResult> = chunks
+        .iter()
+        .enumerate()
+        .map(|(i, chunk)| RagChunkResult::from_core(i, chunk))
+        .collect();
+    write_rag_chunk_results(&results, out_json)
+}
+
 /// Extract semantic chunks (element-boundary-aware) from a PDF.
 ///
 /// The `SemanticChunker` differs from the `HybridChunker` (used by
@@ -1841,41 +2278,15 @@ pub unsafe extern "C" fn oxidize_semantic_chunks(
             return ErrorCode::PdfParseError as c_int;
         }
 
-        let partition_cfg: oxidize_pdf::pipeline::PartitionConfig =
-            if partition_config_json.is_null() {
-                oxidize_pdf::pipeline::PartitionConfig::default()
-            } else {
-                let s = match CStr::from_ptr(partition_config_json).to_str() {
-                    Ok(v) => v,
-                    Err(e) => {
-                        set_last_error(format!("invalid UTF-8 in partition_config_json: {e}"));
-                        return ErrorCode::InvalidUtf8 as c_int;
-                    }
-                };
-                match serde_json::from_str::<crate::pipeline_config::PartitionConfigDto>(s) {
-                    Ok(d) => d.into(),
-                    Err(e) => {
-                        set_last_error(format!("invalid PartitionConfig JSON: {e}"));
-                        return ErrorCode::InvalidArgument as c_int;
-                    }
-                }
-            };
+        let partition_cfg = match parse_partition_config_opt(partition_config_json) {
+            Ok(c) => c,
+            Err(code) => return code,
+        };
 
-        let sem_str = match CStr::from_ptr(semantic_config_json).to_str() {
-            Ok(v) => v,
-            Err(e) => {
-                set_last_error(format!("invalid UTF-8 in semantic_config_json: {e}"));
-                return ErrorCode::InvalidUtf8 as c_int;
-            }
+        let sem_cfg = match parse_semantic_config(semantic_config_json) {
+            Ok(c) => c,
+            Err(code) => return code,
         };
-        let sem_cfg: oxidize_pdf::pipeline::SemanticChunkConfig =
-            match serde_json::from_str::<crate::pipeline_config::SemanticChunkConfigDto>(sem_str) {
-                Ok(d) => d.into(),
-                Err(e) => {
-                    set_last_error(format!("invalid SemanticChunkConfig JSON: {e}"));
-                    return ErrorCode::InvalidArgument as c_int;
-                }
-            };
 
         let bytes = slice::from_raw_parts(pdf_bytes, pdf_len);
         let reader = match open_lenient(bytes) {
@@ -1887,97 +2298,100 @@ pub unsafe extern "C" fn oxidize_semantic_chunks(
         };
 
         let document = PdfDocument::new(reader);
-        let elements = match document.partition_with(partition_cfg) {
-            Ok(e) => e,
-            Err(e) => {
-                set_last_error(format!("Failed to partition PDF: {e}"));
-                return ErrorCode::PdfParseError as c_int;
-            }
-        };
+        semantic_chunks_impl(&document, partition_cfg, sem_cfg, Progress::NONE, out_json)
+    })
+}
 
-        let chunker = oxidize_pdf::pipeline::SemanticChunker::new(sem_cfg);
-        let sem_chunks = chunker.chunk(&elements);
+/// Body of [`oxidize_semantic_chunks`] over an already-opened document.
+pub(crate) unsafe fn semantic_chunks_impl<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    partition_cfg: oxidize_pdf::pipeline::PartitionConfig,
+    sem_cfg: oxidize_pdf::pipeline::SemanticChunkConfig,
+    progress: Progress,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    let elements = match partition_pages(document, partition_cfg, progress) {
+        Ok(e) => e,
+        Err(code) => return code,
+    };
 
-        let results: Vec<SemanticChunkResult> = sem_chunks
-            .iter()
-            .enumerate()
-            .map(|(i, sc)| SemanticChunkResult {
-                chunk_index: i,
-                text: sc.text(),
-                page_numbers: sc.page_numbers().into_iter().map(|p| p + 1).collect(),
-                token_estimate: sc.token_estimate(),
-                is_oversized: sc.is_oversized(),
-            })
-            .collect();
+    let chunker = oxidize_pdf::pipeline::SemanticChunker::new(sem_cfg);
+    let sem_chunks = chunker.chunk(&elements);
+    write_semantic_chunk_results(&sem_chunks, out_json)
+}
 
-        let json = match serde_json::to_string(&results) {
-            Ok(j) => j,
-            Err(e) => {
-                set_last_error(format!("Failed to serialize semantic chunks: {e}"));
-                return ErrorCode::SerializationError as c_int;
-            }
-        };
+/// Serialize semantic chunks into `*out_json`.
+unsafe fn write_semantic_chunk_results(
+    chunks: &[oxidize_pdf::pipeline::SemanticChunk],
+    out_json: *mut *mut c_char,
+) -> c_int {
+    let results: Vec<SemanticChunkResult> = chunks
+        .iter()
+        .enumerate()
+        .map(|(i, sc)| SemanticChunkResult::from_core(i, sc))
+        .collect();
 
-        let c_string = match CString::new(json) {
-            Ok(cs) => cs,
-            Err(e) => {
-                set_last_error(format!("JSON contains null bytes: {e}"));
-                return ErrorCode::InvalidUtf8 as c_int;
-            }
-        };
+    let json = match serde_json::to_string(&results) {
+        Ok(j) => j,
+        Err(e) => {
+            set_last_error(LastError::caused_by(
+                "Failed to serialize semantic chunks",
+                &e,
+            ));
+            return ErrorCode::SerializationError as c_int;
+        }
+    };
 
-        *out_json = c_string.into_raw();
-        ErrorCode::Success as c_int
-    })
+    let c_string = match CString::new(json) {
+        Ok(cs) => cs,
+        Err(e) => {
+            set_last_error(LastError::caused_by("JSON contains null bytes", &e));
+            return ErrorCode::InvalidUtf8 as c_int;
+        }
+    };
+
+    *out_json = c_string.into_raw();
+    ErrorCode::Success as c_int
 }
 
-/// Extract RAG chunks using explicit partition and/or hybrid configs.
+/// Extract semantic chunks using a pre-configured or custom extraction
+/// profile.
 ///
-/// Both config pointers are independently optional: passing `NULL` for either
-/// uses the upstream default (`PartitionConfig::default()` /
-/// `HybridChunkConfig::default()`). This lets callers tune just the chunk
-/// size while keeping default partitioning, or vice versa.
+/// The profile selects the partitioning as for
+/// [`oxidize_partition_with_profile`]. A custom profile also supplies the
+/// chunk config through its `semantic` section; built-in profiles, and
+/// custom profiles without one, use the default `SemanticChunkConfig`
+/// (`max_tokens` 512, `overlap_tokens` 50, element boundaries respected).
+/// Use [`oxidize_semantic_chunks`] to pass a chunk config per call.
 ///
 /// # Arguments
 /// * `pdf_bytes` — pointer to `pdf_len` bytes of PDF data.
 /// * `pdf_len` — length in bytes.
-/// * `partition_config_json` — optional `PartitionConfigDto` UTF-8 JSON string,
-///   or `NULL` for defaults.
-/// * `hybrid_config_json` — optional `HybridChunkConfigDto` UTF-8 JSON string,
-///   or `NULL` for defaults.
+/// * `profile` — `u8` discriminant or custom profile id; same mapping as
+///   [`oxidize_partition_with_profile`].
 /// * `out_json` — receives a heap-allocated UTF-8 JSON array of
-///   `RagChunkResult` records (1-based page numbers). Free with
-///   `oxidize_free_string`.
+///   `SemanticChunkResult` records, as for [`oxidize_semantic_chunks`].
+///   Free with `oxidize_free_string`.
 ///
 /// # Returns
-/// `ErrorCode::Success` on success. Error codes:
-/// - `NullPointer`: `pdf_bytes` or `out_json` is null.
-/// - `InvalidUtf8`: a non-null config pointer is not valid UTF-8.
-/// - `InvalidArgument`: a non-null config does not deserialize as the
-///   matching DTO (malformed JSON or missing fields).
-/// - `PdfParseError`: `pdf_len == 0`, parser fails, or partition fails.
-/// - `SerializationError` / `InvalidUtf8`: response build failure.
-///
-/// On any non-Success return, `*out_json` is set to null.
+/// `ErrorCode::Success` on success. Error codes match
+/// [`oxidize_partition_with_profile`].
 ///
 /// # Safety
 /// - `pdf_bytes` must be a valid pointer to `pdf_len` bytes.
-/// - `partition_config_json` and `hybrid_config_json`, if non-null, must
-///   each be NUL-terminated UTF-8 C strings.
 /// - `out_json` must be a writeable `*mut *mut c_char`.
 #[no_mangle]
-pub unsafe extern "C" fn oxidize_rag_chunks_with_config(
+pub unsafe extern "C" fn oxidize_semantic_chunks_with_profile(
     pdf_bytes: *const u8,
     pdf_len: usize,
-    partition_config_json: *const c_char,
-    hybrid_config_json: *const c_char,
+    profile: u8,
     out_json: *mut *mut c_char,
 ) -> c_int {
     crate::ffi_guard(move || {
         clear_last_error();
 
         if pdf_bytes.is_null() || out_json.is_null() {
-            set_last_error("Null pointer provided to oxidize_rag_chunks_with_config");
+            set_last_error("Null pointer provided to oxidize_semantic_chunks_with_profile");
             return ErrorCode::NullPointer as c_int;
         }
 
@@ -1988,42 +2402,11 @@ pub unsafe extern "C" fn oxidize_rag_chunks_with_config(
             return ErrorCode::PdfParseError as c_int;
         }
 
-        let partition_cfg: oxidize_pdf::pipeline::PartitionConfig =
-            if partition_config_json.is_null() {
-                oxidize_pdf::pipeline::PartitionConfig::default()
-            } else {
-                let s = match CStr::from_ptr(partition_config_json).to_str() {
-                    Ok(v) => v,
-                    Err(e) => {
-                        set_last_error(format!("invalid UTF-8 in partition_config_json: {e}"));
-                        return ErrorCode::InvalidUtf8 as c_int;
-                    }
-                };
-                match serde_json::from_str::<crate::pipeline_config::PartitionConfigDto>(s) {
-                    Ok(d) => d.into(),
-                    Err(e) => {
-                        set_last_error(format!("invalid PartitionConfig JSON: {e}"));
-                        return ErrorCode::InvalidArgument as c_int;
-                    }
-                }
-            };
-
-        let hybrid_cfg: oxidize_pdf::pipeline::HybridChunkConfig = if hybrid_config_json.is_null() {
-            oxidize_pdf::pipeline::HybridChunkConfig::default()
-        } else {
-            let s = match CStr::from_ptr(hybrid_config_json).to_str() {
-                Ok(v) => v,
-                Err(e) => {
-                    set_last_error(format!("invalid UTF-8 in hybrid_config_json: {e}"));
-                    return ErrorCode::InvalidUtf8 as c_int;
-                }
-            };
-            match serde_json::from_str::<crate::pipeline_config::HybridChunkConfigDto>(s) {
-                Ok(d) => d.into(),
-                Err(e) => {
-                    set_last_error(format!("invalid HybridChunkConfig JSON: {e}"));
-                    return ErrorCode::InvalidArgument as c_int;
-                }
+        let prof = match crate::profiles::Profile::resolve(profile) {
+            Ok(p) => p,
+            Err(e) => {
+                set_last_error(e);
+                return ErrorCode::InvalidArgument as c_int;
             }
         };
 
@@ -2037,74 +2420,46 @@ pub unsafe extern "C" fn oxidize_rag_chunks_with_config(
         };
 
         let document = PdfDocument::new(reader);
-        let elements = match document.partition_with(partition_cfg) {
-            Ok(e) => e,
-            Err(e) => {
-                set_last_error(format!("Failed to partition PDF: {e}"));
-                return ErrorCode::PdfParseError as c_int;
-            }
-        };
-
-        let chunker = oxidize_pdf::pipeline::HybridChunker::new(hybrid_cfg);
-        let hybrid_chunks = chunker.chunk(&elements);
-        let chunks: Vec<oxidize_pdf::pipeline::RagChunk> = hybrid_chunks
-            .iter()
-            .enumerate()
-            .map(|(i, hc)| oxidize_pdf::pipeline::RagChunk::from_hybrid_chunk(i, hc))
-            .collect();
-
-        let results: Vec<RagChunkResult> = chunks
-            .iter()
-            .enumerate()
-            .map(|(i, chunk)| RagChunkResult {
-                chunk_index: i,
-                text: chunk.text.clone(),
-                full_text: chunk.full_text.clone(),
-                page_numbers: chunk.page_numbers.iter().map(|p| p + 1).collect(),
-                element_types: chunk.element_types.clone(),
-                heading_context: chunk.heading_context.clone(),
-                token_estimate: chunk.token_estimate,
-                is_oversized: chunk.is_oversized,
-            })
-            .collect();
-
-        let json = match serde_json::to_string(&results) {
-            Ok(j) => j,
-            Err(e) => {
-                set_last_error(format!("Failed to serialize RAG chunks: {e}"));
-                return ErrorCode::SerializationError as c_int;
-            }
-        };
-
-        let c_string = match CString::new(json) {
-            Ok(cs) => cs,
-            Err(e) => {
-                set_last_error(format!("JSON contains null bytes: {e}"));
-                return ErrorCode::InvalidUtf8 as c_int;
-            }
-        };
-
-        *out_json = c_string.into_raw();
-        ErrorCode::Success as c_int
+        semantic_chunks_with_profile_impl(&document, &prof, out_json)
     })
 }
 
-/// Extract structure-aware RAG chunks from a PDF.
+/// Body of [`oxidize_semantic_chunks_with_profile`] over an already-opened
+/// document.
+pub(crate) unsafe fn semantic_chunks_with_profile_impl<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    prof: &crate::profiles::Profile,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    match prof.semantic_chunks(document) {
+        Ok(chunks) => write_semantic_chunk_results(&chunks, out_json),
+        Err(code) => code,
+    }
+}
+
+/// [`oxidize_semantic_chunks`] with per-page progress reporting and
+/// cancellation during partitioning.
+///
+/// `progress` may be null. Returns `Cancelled` when the caller's
+/// `is_cancelled` callback fires.
 ///
 /// # Safety
-/// - `pdf_bytes` must be a valid pointer to `pdf_len` bytes.
-/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+/// Same as [`oxidize_semantic_chunks`]; additionally `progress` must be null
+/// or point to a valid `ProgressCallbacks` for the duration of the call.
 #[no_mangle]
-pub unsafe extern "C" fn oxidize_rag_chunks(
+pub unsafe extern "C" fn oxidize_semantic_chunks_with_progress(
     pdf_bytes: *const u8,
     pdf_len: usize,
+    partition_config_json: *const c_char,
+    semantic_config_json: *const c_char,
+    progress: *const ProgressCallbacks,
     out_json: *mut *mut c_char,
 ) -> c_int {
     crate::ffi_guard(move || {
         clear_last_error();
 
-        if pdf_bytes.is_null() || out_json.is_null() {
-            set_last_error("Null pointer provided to oxidize_rag_chunks");
+        if pdf_bytes.is_null() || semantic_config_json.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_semantic_chunks_with_progress");
             return ErrorCode::NullPointer as c_int;
         }
 
@@ -2115,6 +2470,16 @@ pub unsafe extern "C" fn oxidize_rag_chunks(
             return ErrorCode::PdfParseError as c_int;
         }
 
+        let partition_cfg = match parse_partition_config_opt(partition_config_json) {
+            Ok(c) => c,
+            Err(code) => return code,
+        };
+
+        let sem_cfg = match parse_semantic_config(semantic_config_json) {
+            Ok(c) => c,
+            Err(code) => return code,
+        };
+
         let bytes = slice::from_raw_parts(pdf_bytes, pdf_len);
         let reader = match open_lenient(bytes) {
             Ok(r) => r,
@@ -2125,50 +2490,311 @@ pub unsafe extern "C" fn oxidize_rag_chunks(
         };
 
         let document = PdfDocument::new(reader);
-        let chunks = match document.rag_chunks() {
-            Ok(c) => c,
-            Err(e) => {
-                set_last_error(format!("Failed to extract RAG chunks: {e}"));
-                return ErrorCode::PdfParseError as c_int;
-            }
-        };
-
-        let results: Vec<RagChunkResult> = chunks
-            .iter()
-            .enumerate()
-            .map(|(i, chunk)| RagChunkResult {
-                chunk_index: i,
-                text: chunk.text.clone(),
-                full_text: chunk.full_text.clone(),
-                page_numbers: chunk.page_numbers.iter().map(|p| p + 1).collect(), // 0-based to 1-based
-                element_types: chunk.element_types.clone(),
-                heading_context: chunk.heading_context.clone(),
-                token_estimate: chunk.token_estimate,
-                is_oversized: chunk.is_oversized,
-            })
-            .collect();
+        semantic_chunks_impl(
+            &document,
+            partition_cfg,
+            sem_cfg,
+            Progress::from_ptr(progress),
+            out_json,
+        )
+    })
+}
 
-        let json = match serde_json::to_string(&results) {
-            Ok(j) => j,
+/// Extract RAG chunks using explicit partition and/or hybrid configs.
+///
+/// Both config pointers are independently optional: passing `NULL` for either
+/// uses the upstream default (`PartitionConfig::default()` /
+/// `HybridChunkConfig::default()`). This lets callers tune just the chunk
+/// size while keeping default partitioning, or vice versa.
+///
+/// # Arguments
+/// * `pdf_bytes` — pointer to `pdf_len` bytes of PDF data.
+/// * `pdf_len` — length in bytes.
+/// * `partition_config_json` — optional `PartitionConfigDto` UTF-8 JSON string,
+///   or `NULL` for defaults.
+/// * `hybrid_config_json` — optional `HybridChunkConfigDto` UTF-8 JSON string,
+///   or `NULL` for defaults.
+/// * `out_json` — receives a heap-allocated UTF-8 JSON array of
+///   `RagChunkResult` records (1-based page numbers). Free with
+///   `oxidize_free_string`.
+///
+/// # Returns
+/// `ErrorCode::Success` on success. Error codes:
+/// - `NullPointer`: `pdf_bytes` or `out_json` is null.
+/// - `InvalidUtf8`: a non-null config pointer is not valid UTF-8.
+/// - `InvalidArgument`: a non-null config does not deserialize as the
+///   matching DTO (malformed JSON or missing fields).
+/// - `PdfParseError`: `pdf_len == 0`, parser fails, or partition fails.
+/// - `SerializationError` / `InvalidUtf8`: response build failure.
+///
+/// On any non-Success return, `*out_json` is set to null.
+///
+/// # Safety
+/// - `pdf_bytes` must be a valid pointer to `pdf_len` bytes.
+/// - `partition_config_json` and `hybrid_config_json`, if non-null, must
+///   each be NUL-terminated UTF-8 C strings.
+/// - `out_json` must be a writeable `*mut *mut c_char`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_rag_chunks_with_config(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    partition_config_json: *const c_char,
+    hybrid_config_json: *const c_char,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+
+        if pdf_bytes.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_rag_chunks_with_config");
+            return ErrorCode::NullPointer as c_int;
+        }
+
+        *out_json = ptr::null_mut();
+
+        if pdf_len == 0 {
+            set_last_error("PDF data is empty (0 bytes)");
+            return ErrorCode::PdfParseError as c_int;
+        }
+
+        let partition_cfg = match parse_partition_config_opt(partition_config_json) {
+            Ok(c) => c,
+            Err(code) => return code,
+        };
+
+        let hybrid_cfg = match parse_hybrid_config_opt(hybrid_config_json) {
+            Ok(c) => c,
+            Err(code) => return code,
+        };
+
+        let bytes = slice::from_raw_parts(pdf_bytes, pdf_len);
+        let reader = match open_lenient(bytes) {
+            Ok(r) => r,
             Err(e) => {
-                set_last_error(format!("Failed to serialize RAG chunks: {e}"));
-                return ErrorCode::SerializationError as c_int;
+                set_last_error(e);
+                return ErrorCode::PdfParseError as c_int;
             }
         };
 
-        let c_string = match CString::new(json) {
-            Ok(cs) => cs,
+        let document = PdfDocument::new(reader);
+        rag_chunks_with_config_impl(
+            &document,
+            partition_cfg,
+            hybrid_cfg,
+            Progress::NONE,
+            out_json,
+        )
+    })
+}
+
+/// Body of [`oxidize_rag_chunks_with_config`] over an already-opened
+/// document.
+pub(crate) unsafe fn rag_chunks_with_config_impl<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    partition_cfg: oxidize_pdf::pipeline::PartitionConfig,
+    hybrid_cfg: oxidize_pdf::pipeline::HybridChunkConfig,
+    progress: Progress,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    let elements = match partition_pages(document, partition_cfg, progress) {
+        Ok(e) => e,
+        Err(code) => return code,
+    };
+
+    let chunker = oxidize_pdf::pipeline::HybridChunker::new(hybrid_cfg);
+    let hybrid_chunks = chunker.chunk(&elements);
+    let chunks: Vec<oxidize_pdf::pipeline::RagChunk> = hybrid_chunks
+        .iter()
+        .enumerate()
+        .map(|(i, hc)| oxidize_pdf::pipeline::RagChunk::from_hybrid_chunk(i, hc))
+        .collect();
+
+    let results: Vec<RagChunkResult> = chunks
+        .iter()
+        .enumerate()
+        .map(|(i, chunk)| RagChunkResult::from_core(i, chunk))
+        .collect();
+
+    let json = match serde_json::to_string(&results) {
+        Ok(j) => j,
+        Err(e) => {
+            set_last_error(LastError::caused_by("Failed to serialize RAG chunks", &e));
+            return ErrorCode::SerializationError as c_int;
+        }
+    };
+
+    let c_string = match CString::new(json) {
+        Ok(cs) => cs,
+        Err(e) => {
+            set_last_error(LastError::caused_by("JSON contains null bytes", &e));
+            return ErrorCode::InvalidUtf8 as c_int;
+        }
+    };
+
+    *out_json = c_string.into_raw();
+    ErrorCode::Success as c_int
+}
+
+/// [`oxidize_rag_chunks_with_config`] with per-page progress reporting and
+/// cancellation during partitioning.
+///
+/// `progress` may be null. Returns `Cancelled` when the caller's
+/// `is_cancelled` callback fires.
+///
+/// # Safety
+/// Same as [`oxidize_rag_chunks_with_config`]; additionally `progress` must
+/// be null or point to a valid `ProgressCallbacks` for the duration of the
+/// call.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_rag_chunks_with_progress(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    partition_config_json: *const c_char,
+    hybrid_config_json: *const c_char,
+    progress: *const ProgressCallbacks,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+
+        if pdf_bytes.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_rag_chunks_with_progress");
+            return ErrorCode::NullPointer as c_int;
+        }
+
+        *out_json = ptr::null_mut();
+
+        if pdf_len == 0 {
+            set_last_error("PDF data is empty (0 bytes)");
+            return ErrorCode::PdfParseError as c_int;
+        }
+
+        let partition_cfg = match parse_partition_config_opt(partition_config_json) {
+            Ok(c) => c,
+            Err(code) => return code,
+        };
+
+        let hybrid_cfg = match parse_hybrid_config_opt(hybrid_config_json) {
+            Ok(c) => c,
+            Err(code) => return code,
+        };
+
+        let bytes = slice::from_raw_parts(pdf_bytes, pdf_len);
+        let reader = match open_lenient(bytes) {
+            Ok(r) => r,
             Err(e) => {
-                set_last_error(format!("JSON contains null bytes: {e}"));
-                return ErrorCode::InvalidUtf8 as c_int;
+                set_last_error(e);
+                return ErrorCode::PdfParseError as c_int;
             }
         };
 
-        *out_json = c_string.into_raw();
-        ErrorCode::Success as c_int
+        let document = PdfDocument::new(reader);
+        rag_chunks_with_config_impl(
+            &document,
+            partition_cfg,
+            hybrid_cfg,
+            Progress::from_ptr(progress),
+            out_json,
+        )
+    })
+}
+
+/// Extract structure-aware RAG chunks from a PDF.
+///
+/// # Safety
+/// - `pdf_bytes` must be a valid pointer to `pdf_len` bytes.
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_rag_chunks(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+
+        if pdf_bytes.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_rag_chunks");
+            return ErrorCode::NullPointer as c_int;
+        }
+
+        *out_json = ptr::null_mut();
+
+        if pdf_len == 0 {
+            set_last_error("PDF data is empty (0 bytes)");
+            return ErrorCode::PdfParseError as c_int;
+        }
+
+        let bytes = slice::from_raw_parts(pdf_bytes, pdf_len);
+        let reader = match open_lenient(bytes) {
+            Ok(r) => r,
+            Err(e) => {
+                set_last_error(e);
+                return ErrorCode::PdfParseError as c_int;
+            }
+        };
+
+        let document = PdfDocument::new(reader);
+        rag_chunks_impl(&document, out_json)
     })
 }
 
+/// Body of [`oxidize_rag_chunks`] over an already-opened document.
+pub(crate) unsafe fn rag_chunks_impl<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    match rag_chunk_results(document) {
+        Ok(results) => write_rag_chunk_results(&results, out_json),
+        Err(code) => code,
+    }
+}
+
+/// Serialize RAG chunk records into `*out_json`.
+pub(crate) unsafe fn write_rag_chunk_results(
+    results: &[RagChunkResult],
+    out_json: *mut *mut c_char,
+) -> c_int {
+    let json = match serde_json::to_string(results) {
+        Ok(j) => j,
+        Err(e) => {
+            set_last_error(LastError::caused_by("Failed to serialize RAG chunks", &e));
+            return ErrorCode::SerializationError as c_int;
+        }
+    };
+
+    let c_string = match CString::new(json) {
+        Ok(cs) => cs,
+        Err(e) => {
+            set_last_error(LastError::caused_by("JSON contains null bytes", &e));
+            return ErrorCode::InvalidUtf8 as c_int;
+        }
+    };
+
+    *out_json = c_string.into_raw();
+    ErrorCode::Success as c_int
+}
+
+/// The chunk records of [`oxidize_rag_chunks`]. Sets the last error on
+/// failure.
+pub(crate) fn rag_chunk_results<R: Read + Seek>(
+    document: &PdfDocument<R>,
+) -> Result<Vec<RagChunkResult>, c_int> {
+    let chunks = match document.rag_chunks() {
+        Ok(c) => c,
+        Err(e) => {
+            set_last_error(LastError::caused_by("Failed to extract RAG chunks", &e));
+            return Err(ErrorCode::PdfParseError as c_int);
+        }
+    };
+
+    Ok(chunks
+        .iter()
+        .enumerate()
+        .map(|(i, chunk)| RagChunkResult::from_core(i, chunk))
+        .collect())
+}
+
 /// Estimate the number of tokens in a text string using the upstream
 /// `DocumentChunker::estimate_tokens` heuristic — currently
 /// `(words * 1.33) as usize` where `words` is `text.split_whitespace().count()`.
@@ -2200,7 +2826,7 @@ pub unsafe extern "C" fn oxidize_estimate_tokens(
         let s = match CStr::from_ptr(text).to_str() {
             Ok(v) => v,
             Err(e) => {
-                set_last_error(format!("invalid UTF-8 in text: {e}"));
+                set_last_error(LastError::caused_by("invalid UTF-8 in text", &e));
                 return ErrorCode::InvalidUtf8 as c_int;
             }
         };
@@ -2274,7 +2900,7 @@ pub unsafe extern "C" fn oxidize_chunk_text(
         let s = match CStr::from_ptr(text).to_str() {
             Ok(v) => v,
             Err(e) => {
-                set_last_error(format!("invalid UTF-8 in text: {e}"));
+                set_last_error(LastError::caused_by("invalid UTF-8 in text", &e));
                 return ErrorCode::InvalidUtf8 as c_int;
             }
         };
@@ -2283,7 +2909,7 @@ pub unsafe extern "C" fn oxidize_chunk_text(
         let chunks = match chunker.chunk_text(s) {
             Ok(c) => c,
             Err(e) => {
-                set_last_error(format!("chunk_text failed: {e}"));
+                set_last_error(LastError::caused_by("chunk_text failed", &e));
                 return ErrorCode::PdfParseError as c_int;
             }
         };
@@ -2302,7 +2928,7 @@ pub unsafe extern "C" fn oxidize_chunk_text(
         let json = match serde_json::to_string(&results) {
             Ok(j) => j,
             Err(e) => {
-                set_last_error(format!("Failed to serialize chunks: {e}"));
+                set_last_error(LastError::caused_by("Failed to serialize chunks", &e));
                 return ErrorCode::SerializationError as c_int;
             }
         };
@@ -2310,7 +2936,7 @@ pub unsafe extern "C" fn oxidize_chunk_text(
         let c_string = match CString::new(json) {
             Ok(cs) => cs,
             Err(e) => {
-                set_last_error(format!("JSON contains null bytes: {e}"));
+                set_last_error(LastError::caused_by("JSON contains null bytes", &e));
                 return ErrorCode::InvalidUtf8 as c_int;
             }
         };
@@ -2361,82 +2987,106 @@ pub unsafe extern "C" fn oxidize_get_annotations(
         };
 
         let document = PdfDocument::new(reader);
-        let all_annotations = match document.get_all_annotations() {
-            Ok(a) => a,
-            Err(e) => {
-                set_last_error(format!("Failed to get annotations: {e}"));
-                return ErrorCode::PdfParseError as c_int;
-            }
-        };
+        get_annotations_impl(&document, out_json)
+    })
+}
 
-        let mut annotations: Vec<AnnotationResult> = Vec::new();
-
-        for (page_index, dicts) in &all_annotations {
-            for dict in dicts {
-                let subtype = dict
-                    .get("Subtype")
-                    .and_then(|o| o.as_name())
-                    .map(|n| n.as_str().to_string())
-                    .unwrap_or_default();
-
-                let contents = dict
-                    .get("Contents")
-                    .and_then(|o| o.as_string())
-                    .and_then(|s| s.as_str().ok())
-                    .map(|s| s.to_string());
-
-                let title = dict
-                    .get("T")
-                    .and_then(|o| o.as_string())
-                    .and_then(|s| s.as_str().ok())
-                    .map(|s| s.to_string());
-
-                let rect = dict.get("Rect").and_then(|o| o.as_array()).and_then(|arr| {
-                    if arr.0.len() == 4 {
-                        let values: Vec<f64> = arr.0.iter().filter_map(|v| v.as_real()).collect();
-                        if values.len() == 4 {
-                            Some([values[0], values[1], values[2], values[3]])
-                        } else {
-                            None
-                        }
-                    } else {
-                        None
-                    }
-                });
+/// Body of [`oxidize_get_annotations`] over an already-opened document.
+pub(crate) unsafe fn get_annotations_impl<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    let annotations = match annotation_results(document) {
+        Ok(a) => a,
+        Err(code) => return code,
+    };
 
-                annotations.push(AnnotationResult {
-                    subtype,
-                    contents,
-                    title,
-                    page_number: page_index.saturating_add(1), // 0-based to 1-based
-                    rect,
-                });
-            }
+    let json = match serde_json::to_string(&annotations) {
+        Ok(j) => j,
+        Err(e) => {
+            set_last_error(LastError::caused_by("Failed to serialize annotations", &e));
+            return ErrorCode::SerializationError as c_int;
         }
+    };
 
-        let json = match serde_json::to_string(&annotations) {
-            Ok(j) => j,
-            Err(e) => {
-                set_last_error(format!("Failed to serialize annotations: {e}"));
-                return ErrorCode::SerializationError as c_int;
-            }
-        };
-
-        let c_string = match CString::new(json) {
-            Ok(cs) => cs,
-            Err(e) => {
-                set_last_error(format!("Annotations JSON contains null bytes: {e}"));
-                return ErrorCode::InvalidUtf8 as c_int;
-            }
-        };
+    let c_string = match CString::new(json) {
+        Ok(cs) => cs,
+        Err(e) => {
+            set_last_error(LastError::caused_by(
+                "Annotations JSON contains null bytes",
+                &e,
+            ));
+            return ErrorCode::InvalidUtf8 as c_int;
+        }
+    };
 
-        *out_json = c_string.into_raw();
-        ErrorCode::Success as c_int
-    })
+    *out_json = c_string.into_raw();
+    ErrorCode::Success as c_int
 }
 
-// ── Page Resources FFI ───────────────────────────────────────────────────────
-
+/// The annotation records of [`oxidize_get_annotations`]. Sets the last
+/// error on failure.
+pub(crate) fn annotation_results<R: Read + Seek>(
+    document: &PdfDocument<R>,
+) -> Result<Vec<AnnotationResult>, c_int> {
+    let all_annotations = match document.get_all_annotations() {
+        Ok(a) => a,
+        Err(e) => {
+            set_last_error(LastError::caused_by("Failed to get annotations", &e));
+            return Err(ErrorCode::PdfParseError as c_int);
+        }
+    };
+
+    let mut annotations: Vec<AnnotationResult> = Vec::new();
+
+    for (page_index, dicts) in &all_annotations {
+        for dict in dicts {
+            let subtype = dict
+                .get("Subtype")
+                .and_then(|o| o.as_name())
+                .map(|n| n.as_str().to_string())
+                .unwrap_or_default();
+
+            let contents = dict
+                .get("Contents")
+                .and_then(|o| o.as_string())
+                .and_then(|s| s.as_str().ok())
+                .map(|s| s.to_string());
+
+            let title = dict
+                .get("T")
+                .and_then(|o| o.as_string())
+                .and_then(|s| s.as_str().ok())
+                .map(|s| s.to_string());
+
+            let rect = dict.get("Rect").and_then(|o| o.as_array()).and_then(|arr| {
+                if arr.0.len() == 4 {
+                    let values: Vec<f64> = arr.0.iter().filter_map(|v| v.as_real()).collect();
+                    if values.len() == 4 {
+                        Some([values[0], values[1], values[2], values[3]])
+                    } else {
+                        None
+                    }
+                } else {
+                    None
+                }
+            });
+
+            annotations.push(AnnotationResult {
+                subtype,
+                contents,
+                title,
+                page_number: page_index.saturating_add(1), // 0-based to 1-based
+                rect,
+            });
+        }
+    }
+
+    Ok(annotations)
+}
+
+// ── Page Resources FFI ───────────────────────────────────────────────────────
+
 /// Get the resources for a specific page (fonts, images, resource keys).
 ///
 /// # Safety
@@ -2467,7 +3117,7 @@ pub unsafe extern "C" fn oxidize_get_page_resources(
 
         if page_number == 0 {
             set_last_error("Page number must be >= 1 (1-based indexing)");
-            return ErrorCode::PdfParseError as c_int;
+            return ErrorCode::PageOutOfRange as c_int;
         }
 
         let bytes = slice::from_raw_parts(pdf_bytes, pdf_len);
@@ -2480,65 +3130,84 @@ pub unsafe extern "C" fn oxidize_get_page_resources(
         };
 
         let document = PdfDocument::new(reader);
-        let page_index = (page_number - 1) as u32;
-        let page = match document.get_page(page_index) {
-            Ok(p) => p,
-            Err(e) => {
-                set_last_error(format!("Failed to get page {page_number}: {e}"));
-                return ErrorCode::PdfParseError as c_int;
-            }
-        };
+        get_page_resources_impl(&document, page_number, out_json)
+    })
+}
 
-        let mut font_names = Vec::new();
-        let mut has_xobjects = false;
-        let mut resource_keys = Vec::new();
+/// Body of [`oxidize_get_page_resources`] over an already-opened document.
+/// `page_number` must already be validated as >= 1.
+pub(crate) unsafe fn get_page_resources_impl<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    page_number: usize,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    let page_index = (page_number - 1) as u32;
+    let page = match document.get_page(page_index) {
+        Ok(p) => p,
+        Err(e) => {
+            set_last_error(
+                LastError::caused_by(format_args!("Failed to get page {page_number}"), &e)
+                    .at_page(page_index),
+            );
+            return ErrorCode::PdfParseError as c_int;
+        }
+    };
 
-        if let Some(resources) = page.get_resources() {
-            // Collect top-level resource keys
-            for key in resources.0.keys() {
-                resource_keys.push(key.as_str().to_string());
-            }
-            resource_keys.sort();
+    let mut font_names = Vec::new();
+    let mut has_xobjects = false;
+    let mut resource_keys = Vec::new();
 
-            // Extract font names
-            if let Some(fonts) = resources.get("Font").and_then(|f| f.as_dict()) {
-                for key in fonts.0.keys() {
-                    font_names.push(key.as_str().to_string());
-                }
-                font_names.sort();
-            }
+    if let Some(resources) = page.get_resources() {
+        // Collect top-level resource keys
+        for key in resources.0.keys() {
+            resource_keys.push(key.as_str().to_string());
+        }
+        resource_keys.sort();
 
-            // Check for images in XObjects
-            if let Some(xobjects) = resources.get("XObject").and_then(|x| x.as_dict()) {
-                has_xobjects = !xobjects.0.is_empty();
+        // Extract font names
+        if let Some(fonts) = resources.get("Font").and_then(|f| f.as_dict()) {
+            for key in fonts.0.keys() {
+                font_names.push(key.as_str().to_string());
             }
+            font_names.sort();
         }
 
-        let result = PageResourcesResult {
-            font_names,
-            has_xobjects,
-            resource_keys,
-        };
+        // Check for images in XObjects
+        if let Some(xobjects) = resources.get("XObject").and_then(|x| x.as_dict()) {
+            has_xobjects = !xobjects.0.is_empty();
+        }
+    }
 
-        let json = match serde_json::to_string(&result) {
-            Ok(j) => j,
-            Err(e) => {
-                set_last_error(format!("Failed to serialize page resources: {e}"));
-                return ErrorCode::SerializationError as c_int;
-            }
-        };
+    let result = PageResourcesResult {
+        font_names,
+        has_xobjects,
+        resource_keys,
+    };
 
-        let c_string = match CString::new(json) {
-            Ok(cs) => cs,
-            Err(e) => {
-                set_last_error(format!("Page resources JSON contains null bytes: {e}"));
-                return ErrorCode::InvalidUtf8 as c_int;
-            }
-        };
+    let json = match serde_json::to_string(&result) {
+        Ok(j) => j,
+        Err(e) => {
+            set_last_error(LastError::caused_by(
+                "Failed to serialize page resources",
+                &e,
+            ));
+            return ErrorCode::SerializationError as c_int;
+        }
+    };
 
-        *out_json = c_string.into_raw();
-        ErrorCode::Success as c_int
-    })
+    let c_string = match CString::new(json) {
+        Ok(cs) => cs,
+        Err(e) => {
+            set_last_error(LastError::caused_by(
+                "Page resources JSON contains null bytes",
+                &e,
+            ));
+            return ErrorCode::InvalidUtf8 as c_int;
+        }
+    };
+
+    *out_json = c_string.into_raw();
+    ErrorCode::Success as c_int
 }
 
 /// Get the raw content streams for a specific page as base64-encoded JSON.
@@ -2571,7 +3240,7 @@ pub unsafe extern "C" fn oxidize_get_page_content_stream(
 
         if page_number == 0 {
             set_last_error("Page number must be >= 1 (1-based indexing)");
-            return ErrorCode::PdfParseError as c_int;
+            return ErrorCode::PageOutOfRange as c_int;
         }
 
         let bytes = slice::from_raw_parts(pdf_bytes, pdf_len);
@@ -2584,49 +3253,68 @@ pub unsafe extern "C" fn oxidize_get_page_content_stream(
         };
 
         let document = PdfDocument::new(reader);
-        let page_index = (page_number - 1) as u32;
-        let page = match document.get_page(page_index) {
-            Ok(p) => p,
-            Err(e) => {
-                set_last_error(format!("Failed to get page {page_number}: {e}"));
-                return ErrorCode::PdfParseError as c_int;
-            }
-        };
+        get_page_content_stream_impl(&document, page_number, out_json)
+    })
+}
 
-        let raw_streams = match page.content_streams_with_document(&document) {
-            Ok(s) => s,
-            Err(e) => {
-                set_last_error(format!(
-                    "Failed to get content streams for page {page_number}: {e}"
-                ));
-                return ErrorCode::PdfParseError as c_int;
-            }
-        };
+/// Body of [`oxidize_get_page_content_stream`] over an already-opened
+/// document. `page_number` must already be validated as >= 1.
+pub(crate) unsafe fn get_page_content_stream_impl<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    page_number: usize,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    let page_index = (page_number - 1) as u32;
+    let page = match document.get_page(page_index) {
+        Ok(p) => p,
+        Err(e) => {
+            set_last_error(
+                LastError::caused_by(format_args!("Failed to get page {page_number}"), &e)
+                    .at_page(page_index),
+            );
+            return ErrorCode::PdfParseError as c_int;
+        }
+    };
+
+    let raw_streams = match page.content_streams_with_document(document) {
+        Ok(s) => s,
+        Err(e) => {
+            set_last_error(format!(
+                "Failed to get content streams for page {page_number}: {e}"
+            ));
+            return ErrorCode::PdfParseError as c_int;
+        }
+    };
 
-        let b64_engine = base64::engine::general_purpose::STANDARD;
-        let streams: Vec<String> = raw_streams.iter().map(|s| b64_engine.encode(s)).collect();
+    let b64_engine = base64::engine::general_purpose::STANDARD;
+    let streams: Vec<String> = raw_streams.iter().map(|s| b64_engine.encode(s)).collect();
 
-        let result = ContentStreamResult { streams };
+    let result = ContentStreamResult { streams };
 
-        let json = match serde_json::to_string(&result) {
-            Ok(j) => j,
-            Err(e) => {
-                set_last_error(format!("Failed to serialize content streams: {e}"));
-                return ErrorCode::SerializationError as c_int;
-            }
-        };
+    let json = match serde_json::to_string(&result) {
+        Ok(j) => j,
+        Err(e) => {
+            set_last_error(LastError::caused_by(
+                "Failed to serialize content streams",
+                &e,
+            ));
+            return ErrorCode::SerializationError as c_int;
+        }
+    };
 
-        let c_string = match CString::new(json) {
-            Ok(cs) => cs,
-            Err(e) => {
-                set_last_error(format!("Content stream JSON contains null bytes: {e}"));
-                return ErrorCode::InvalidUtf8 as c_int;
-            }
-        };
+    let c_string = match CString::new(json) {
+        Ok(cs) => cs,
+        Err(e) => {
+            set_last_error(LastError::caused_by(
+                "Content stream JSON contains null bytes",
+                &e,
+            ));
+            return ErrorCode::InvalidUtf8 as c_int;
+        }
+    };
 
-        *out_json = c_string.into_raw();
-        ErrorCode::Success as c_int
-    })
+    *out_json = c_string.into_raw();
+    ErrorCode::Success as c_int
 }
 
 // ── Page Content Analysis FFI ────────────────────────────────────────────────
@@ -2667,7 +3355,7 @@ pub unsafe extern "C" fn oxidize_analyze_page_content(
 
         if page_number == 0 {
             set_last_error("Page number must be >= 1 (1-based indexing)");
-            return ErrorCode::PdfParseError as c_int;
+            return ErrorCode::PageOutOfRange as c_int;
         }
 
         let bytes = slice::from_raw_parts(pdf_bytes, pdf_len);
@@ -2680,72 +3368,91 @@ pub unsafe extern "C" fn oxidize_analyze_page_content(
         };
 
         let document = PdfDocument::new(reader);
-        let page_index = (page_number - 1) as u32;
-        let page = match document.get_page(page_index) {
-            Ok(p) => p,
-            Err(e) => {
-                set_last_error(format!("Failed to get page {page_number}: {e}"));
-                return ErrorCode::PdfParseError as c_int;
-            }
-        };
+        analyze_page_content_impl(&document, page_number, out_json)
+    })
+}
 
-        // Count characters from extracted text for this specific page only
-        let character_count = match document.extract_text_from_page(page_index) {
-            Ok(extracted) => extracted.text.chars().count(),
-            Err(_) => 0,
-        };
+/// Body of [`oxidize_analyze_page_content`] over an already-opened
+/// document. `page_number` must already be validated as >= 1.
+pub(crate) unsafe fn analyze_page_content_impl<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    page_number: usize,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    let page_index = (page_number - 1) as u32;
+    let page = match document.get_page(page_index) {
+        Ok(p) => p,
+        Err(e) => {
+            set_last_error(
+                LastError::caused_by(format_args!("Failed to get page {page_number}"), &e)
+                    .at_page(page_index),
+            );
+            return ErrorCode::PdfParseError as c_int;
+        }
+    };
 
-        // Check for content streams
-        let has_content_stream = page.get_contents().is_some();
+    // Count characters from extracted text for this specific page only
+    let character_count = match document.extract_text_from_page(page_index) {
+        Ok(extracted) => extracted.text.chars().count(),
+        Err(_) => 0,
+    };
 
-        // Count image XObjects
-        let image_count = if let Some(resources) = page.get_resources() {
-            if let Some(xobjects) = resources.get("XObject").and_then(|x| x.as_dict()) {
-                xobjects.0.len()
-            } else {
-                0
-            }
+    // Check for content streams
+    let has_content_stream = page.get_contents().is_some();
+
+    // Count image XObjects
+    let image_count = if let Some(resources) = page.get_resources() {
+        if let Some(xobjects) = resources.get("XObject").and_then(|x| x.as_dict()) {
+            xobjects.0.len()
         } else {
             0
-        };
+        }
+    } else {
+        0
+    };
 
-        // Determine page type using heuristic
-        let page_type = if character_count > 0 && image_count > 0 {
-            "Mixed"
-        } else if character_count == 0 && image_count > 0 {
-            "Scanned"
-        } else if character_count > 0 {
-            "Text"
-        } else {
-            "Unknown" // truly empty page — no text, no images
-        };
+    // Determine page type using heuristic
+    let page_type = if character_count > 0 && image_count > 0 {
+        "Mixed"
+    } else if character_count == 0 && image_count > 0 {
+        "Scanned"
+    } else if character_count > 0 {
+        "Text"
+    } else {
+        "Unknown" // truly empty page — no text, no images
+    };
 
-        let result = ContentAnalysisResult {
-            page_type: page_type.to_string(),
-            character_count,
-            has_content_stream,
-            image_count,
-        };
+    let result = ContentAnalysisResult {
+        page_type: page_type.to_string(),
+        character_count,
+        has_content_stream,
+        image_count,
+    };
 
-        let json = match serde_json::to_string(&result) {
-            Ok(j) => j,
-            Err(e) => {
-                set_last_error(format!("Failed to serialize content analysis: {e}"));
-                return ErrorCode::SerializationError as c_int;
-            }
-        };
+    let json = match serde_json::to_string(&result) {
+        Ok(j) => j,
+        Err(e) => {
+            set_last_error(LastError::caused_by(
+                "Failed to serialize content analysis",
+                &e,
+            ));
+            return ErrorCode::SerializationError as c_int;
+        }
+    };
 
-        let c_string = match CString::new(json) {
-            Ok(cs) => cs,
-            Err(e) => {
-                set_last_error(format!("Content analysis JSON contains null bytes: {e}"));
-                return ErrorCode::InvalidUtf8 as c_int;
-            }
-        };
+    let c_string = match CString::new(json) {
+        Ok(cs) => cs,
+        Err(e) => {
+            set_last_error(LastError::caused_by(
+                "Content analysis JSON contains null bytes",
+                &e,
+            ));
+            return ErrorCode::InvalidUtf8 as c_int;
+        }
+    };
 
-        *out_json = c_string.into_raw();
-        ErrorCode::Success as c_int
-    })
+    *out_json = c_string.into_raw();
+    ErrorCode::Success as c_int
 }
 
 // ── Digital Signatures FFI ───────────────────────────────────────────────────
@@ -2758,7 +3465,7 @@ fn detect_sigs(bytes: &[u8]) -> Result<Vec<signatures::SignatureField>, c_int> {
     })?;
 
     signatures::detect_signature_fields(&mut reader).map_err(|e| {
-        set_last_error(format!("Failed to detect signatures: {e}"));
+        set_last_error(LastError::caused_by("Failed to detect signatures", &e));
         ErrorCode::PdfParseError as c_int
     })
 }
@@ -2811,16 +3518,21 @@ pub unsafe extern "C" fn oxidize_has_signatures(
         }
 
         let bytes = slice::from_raw_parts(pdf_bytes, pdf_len);
-        let sigs = match detect_sigs(bytes) {
-            Ok(s) => s,
-            Err(code) => return code,
-        };
-
-        *out_has_signatures = !sigs.is_empty();
-        ErrorCode::Success as c_int
+        has_signatures_impl(bytes, out_has_signatures)
     })
 }
 
+/// Body of [`oxidize_has_signatures`] over the raw PDF bytes.
+pub(crate) unsafe fn has_signatures_impl(bytes: &[u8], out_has_signatures: *mut bool) -> c_int {
+    let sigs = match detect_sigs(bytes) {
+        Ok(s) => s,
+        Err(code) => return code,
+    };
+
+    *out_has_signatures = !sigs.is_empty();
+    ErrorCode::Success as c_int
+}
+
 /// Extract all digital signature fields from a PDF as JSON.
 ///
 /// # Safety
@@ -2848,32 +3560,40 @@ pub unsafe extern "C" fn oxidize_get_signatures(
         }
 
         let bytes = slice::from_raw_parts(pdf_bytes, pdf_len);
-        let sigs = match detect_sigs(bytes) {
-            Ok(s) => s,
-            Err(code) => return code,
-        };
+        get_signatures_impl(bytes, out_json)
+    })
+}
 
-        let results: Vec<SignatureFieldResult> = sigs.iter().map(build_signature_result).collect();
+/// Body of [`oxidize_get_signatures`] over the raw PDF bytes.
+pub(crate) unsafe fn get_signatures_impl(bytes: &[u8], out_json: *mut *mut c_char) -> c_int {
+    let sigs = match detect_sigs(bytes) {
+        Ok(s) => s,
+        Err(code) => return code,
+    };
 
-        let json = match serde_json::to_string(&results) {
-            Ok(j) => j,
-            Err(e) => {
-                set_last_error(format!("Failed to serialize signatures: {e}"));
-                return ErrorCode::SerializationError as c_int;
-            }
-        };
+    let results: Vec<SignatureFieldResult> = sigs.iter().map(build_signature_result).collect();
 
-        let c_string = match CString::new(json) {
-            Ok(cs) => cs,
-            Err(e) => {
-                set_last_error(format!("Signatures JSON contains null bytes: {e}"));
-                return ErrorCode::InvalidUtf8 as c_int;
-            }
-        };
+    let json = match serde_json::to_string(&results) {
+        Ok(j) => j,
+        Err(e) => {
+            set_last_error(LastError::caused_by("Failed to serialize signatures", &e));
+            return ErrorCode::SerializationError as c_int;
+        }
+    };
 
-        *out_json = c_string.into_raw();
-        ErrorCode::Success as c_int
-    })
+    let c_string = match CString::new(json) {
+        Ok(cs) => cs,
+        Err(e) => {
+            set_last_error(LastError::caused_by(
+                "Signatures JSON contains null bytes",
+                &e,
+            ));
+            return ErrorCode::InvalidUtf8 as c_int;
+        }
+    };
+
+    *out_json = c_string.into_raw();
+    ErrorCode::Success as c_int
 }
 
 /// Verify all digital signatures in a PDF and return detailed results as JSON.
@@ -2903,138 +3623,150 @@ pub unsafe extern "C" fn oxidize_verify_signatures(
         }
 
         let bytes = slice::from_raw_parts(pdf_bytes, pdf_len);
-        let sigs = match detect_sigs(bytes) {
-            Ok(s) => s,
-            Err(code) => return code,
-        };
+        verify_signatures_impl(bytes, out_json)
+    })
+}
 
-        let mut results: Vec<SignatureVerificationFFIResult> = Vec::new();
-
-        for sig in &sigs {
-            let has_modifications = signatures::has_incremental_update(bytes, &sig.byte_range);
-
-            // Parse CMS and verify
-            let parsed = signatures::parse_pkcs7_signature(&sig.contents);
-
-            let (
-                signer_name,
-                hash_valid,
-                signature_valid,
-                digest_algorithm,
-                signature_algorithm,
-                certificate,
-                errors,
-                mut warnings,
-            ) = match &parsed {
-                Ok(p) => {
-                    let signer = p.signer_common_name().ok();
-                    let verify_result = signatures::verify_signature(bytes, p, &sig.byte_range);
-                    let (hv, sv, da, sa, mut errs) = match verify_result {
-                        Ok(vr) => (
-                            vr.hash_valid,
-                            vr.signature_valid,
-                            Some(vr.digest_algorithm.name().to_string()),
-                            Some(vr.signature_algorithm.name().to_string()),
-                            vec![],
-                        ),
-                        Err(e) => (
-                            false,
-                            false,
-                            None,
-                            None,
-                            vec![format!("Verify failed: {e}")],
-                        ),
-                    };
-
-                    let cert_result = signatures::validate_certificate(
-                        &p.signer_certificate_der,
-                        &signatures::TrustStore::default(),
-                    );
-                    let (cert_info, cert_warnings) = match cert_result {
-                        Ok(cr) => {
-                            let w = cr.warnings.clone();
-                            (
-                                Some(CertificateInfoResult {
-                                    subject: cr.subject,
-                                    issuer: cr.issuer,
-                                    valid_from: cr.valid_from,
-                                    valid_to: cr.valid_to,
-                                    is_time_valid: cr.is_time_valid,
-                                    is_trusted: cr.is_trusted,
-                                    is_signature_capable: cr.is_signature_capable,
-                                    warnings: cr.warnings,
-                                }),
-                                w,
-                            )
-                        }
-                        Err(e) => {
-                            errs.push(format!("Certificate validation failed: {e}"));
-                            (None, vec![])
-                        }
-                    };
+/// Body of [`oxidize_verify_signatures`] over the raw PDF bytes (the
+/// signed byte ranges are hashed directly, so a parsed document is not enough).
+pub(crate) unsafe fn verify_signatures_impl(bytes: &[u8], out_json: *mut *mut c_char) -> c_int {
+    let sigs = match detect_sigs(bytes) {
+        Ok(s) => s,
+        Err(code) => return code,
+    };
 
-                    (signer, hv, sv, da, sa, cert_info, errs, cert_warnings)
-                }
-                Err(e) => (
-                    None,
-                    false,
-                    false,
-                    None,
-                    None,
-                    None,
-                    vec![format!("CMS parsing failed: {e}")],
-                    vec![],
-                ),
-            };
+    let mut results: Vec<SignatureVerificationFFIResult> = Vec::new();
+
+    for sig in &sigs {
+        let has_modifications = signatures::has_incremental_update(bytes, &sig.byte_range);
+
+        // Parse CMS and verify
+        let parsed = signatures::parse_pkcs7_signature(&sig.contents);
+
+        let (
+            signer_name,
+            hash_valid,
+            signature_valid,
+            digest_algorithm,
+            signature_algorithm,
+            certificate,
+            errors,
+            mut warnings,
+        ) = match &parsed {
+            Ok(p) => {
+                let signer = p.signer_common_name().ok();
+                let verify_result = signatures::verify_signature(bytes, p, &sig.byte_range);
+                let (hv, sv, da, sa, mut errs) = match verify_result {
+                    Ok(vr) => (
+                        vr.hash_valid,
+                        vr.signature_valid,
+                        Some(vr.digest_algorithm.name().to_string()),
+                        Some(vr.signature_algorithm.name().to_string()),
+                        vec![],
+                    ),
+                    Err(e) => (
+                        false,
+                        false,
+                        None,
+                        None,
+                        vec![format!("Verify failed: {e}")],
+                    ),
+                };
+
+                let cert_result = signatures::validate_certificate(
+                    &p.signer_certificate_der,
+                    &signatures::TrustStore::default(),
+                );
+                let (cert_info, cert_warnings) = match cert_result {
+                    Ok(cr) => {
+                        let w = cr.warnings.clone();
+                        (
+                            Some(CertificateInfoResult {
+                                subject: cr.subject,
+                                issuer: cr.issuer,
+                                valid_from: cr.valid_from,
+                                valid_to: cr.valid_to,
+                                is_time_valid: cr.is_time_valid,
+                                is_trusted: cr.is_trusted,
+                                is_signature_capable: cr.is_signature_capable,
+                                warnings: cr.warnings,
+                            }),
+                            w,
+                        )
+                    }
+                    Err(e) => {
+                        errs.push(format!("Certificate validation failed: {e}"));
+                        (None, vec![])
+                    }
+                };
 
-            if has_modifications {
-                warnings.push("Document was modified after signing".to_string());
+                (signer, hv, sv, da, sa, cert_info, errs, cert_warnings)
             }
+            Err(e) => (
+                None,
+                false,
+                false,
+                None,
+                None,
+                None,
+                vec![format!("CMS parsing failed: {e}")],
+                vec![],
+            ),
+        };
 
-            let is_valid = hash_valid
-                && signature_valid
-                && errors.is_empty()
-                && !has_modifications
-                && certificate
-                    .as_ref()
-                    .map(|c| c.is_time_valid && c.is_trusted && c.is_signature_capable)
-                    .unwrap_or(false);
-
-            results.push(SignatureVerificationFFIResult {
-                field_name: sig.name.clone(),
-                signer_name,
-                signing_time: sig.signing_time.clone(),
-                hash_valid,
-                signature_valid,
-                is_valid,
-                has_modifications_after_signing: has_modifications,
-                errors,
-                warnings,
-                digest_algorithm,
-                signature_algorithm,
-                certificate,
-            });
+        if has_modifications {
+            warnings.push("Document was modified after signing".to_string());
         }
 
-        let json = match serde_json::to_string(&results) {
-            Ok(j) => j,
-            Err(e) => {
-                set_last_error(format!("Failed to serialize verification results: {e}"));
-                return ErrorCode::SerializationError as c_int;
-            }
-        };
+        let is_valid = hash_valid
+            && signature_valid
+            && errors.is_empty()
+            && !has_modifications
+            && certificate
+                .as_ref()
+                .map(|c| c.is_time_valid && c.is_trusted && c.is_signature_capable)
+                .unwrap_or(false);
+
+        results.push(SignatureVerificationFFIResult {
+            field_name: sig.name.clone(),
+            signer_name,
+            signing_time: sig.signing_time.clone(),
+            hash_valid,
+            signature_valid,
+            is_valid,
+            has_modifications_after_signing: has_modifications,
+            errors,
+            warnings,
+            digest_algorithm,
+            signature_algorithm,
+            certificate,
+        });
+    }
 
-        let c_string = match CString::new(json) {
-            Ok(cs) => cs,
-            Err(e) => {
-                set_last_error(format!("Verification JSON contains null bytes: {e}"));
-                return ErrorCode::InvalidUtf8 as c_int;
-            }
-        };
+    let json = match serde_json::to_string(&results) {
+        Ok(j) => j,
+        Err(e) => {
+            set_last_error(LastError::caused_by(
+                "Failed to serialize verification results",
+                &e,
+            ));
+            return ErrorCode::SerializationError as c_int;
+        }
+    };
 
-        *out_json = c_string.into_raw();
-        ErrorCode::Success as c_int
-    })
+    let c_string = match CString::new(json) {
+        Ok(cs) => cs,
+        Err(e) => {
+            set_last_error(LastError::caused_by(
+                "Verification JSON contains null bytes",
+                &e,
+            ));
+            return ErrorCode::InvalidUtf8 as c_int;
+        }
+    };
+
+    *out_json = c_string.into_raw();
+    ErrorCode::Success as c_int
 }
 
 // ── Form Fields FFI ──────────────────────────────────────────────────────────
@@ -3220,148 +3952,812 @@ fn classify_form_field<R: std::io::Read + std::io::Seek>(
     })
 }
 
-/// Check if a PDF contains any form fields (Widget annotations with FT entry).
+/// Check if a PDF contains any form fields (Widget annotations with FT entry).
+///
+/// # Safety
+/// - `pdf_bytes` must be a valid pointer to `pdf_len` bytes.
+/// - `out_has` must be a valid pointer to a `bool`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_has_form_fields(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    out_has: *mut bool,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+
+        if pdf_bytes.is_null() || out_has.is_null() {
+            set_last_error("Null pointer provided to oxidize_has_form_fields");
+            return ErrorCode::NullPointer as c_int;
+        }
+
+        *out_has = false;
+
+        if pdf_len == 0 {
+            set_last_error("PDF data is empty (0 bytes)");
+            return ErrorCode::PdfParseError as c_int;
+        }
+
+        let bytes = slice::from_raw_parts(pdf_bytes, pdf_len);
+        let reader = match open_lenient(bytes) {
+            Ok(r) => r,
+            Err(e) => {
+                set_last_error(e);
+                return ErrorCode::PdfParseError as c_int;
+            }
+        };
+
+        let document = PdfDocument::new(reader);
+        has_form_fields_impl(&document, out_has)
+    })
+}
+
+/// Body of [`oxidize_has_form_fields`] over an already-opened document.
+pub(crate) unsafe fn has_form_fields_impl<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    out_has: *mut bool,
+) -> c_int {
+    let all_annots = match document.get_all_annotations() {
+        Ok(a) => a,
+        Err(e) => {
+            set_last_error(LastError::caused_by("Failed to get annotations", &e));
+            return ErrorCode::PdfParseError as c_int;
+        }
+    };
+
+    for (page_index, dicts) in &all_annots {
+        for dict in dicts {
+            if classify_form_field(document, dict, page_index.saturating_add(1)).is_some() {
+                *out_has = true;
+                return ErrorCode::Success as c_int;
+            }
+        }
+    }
+
+    ErrorCode::Success as c_int
+}
+
+/// Extract all form fields from a PDF as JSON.
+///
+/// # Safety
+/// - `pdf_bytes` must be a valid pointer to `pdf_len` bytes.
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_get_form_fields(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+
+        if pdf_bytes.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_get_form_fields");
+            return ErrorCode::NullPointer as c_int;
+        }
+
+        *out_json = ptr::null_mut();
+
+        if pdf_len == 0 {
+            set_last_error("PDF data is empty (0 bytes)");
+            return ErrorCode::PdfParseError as c_int;
+        }
+
+        let bytes = slice::from_raw_parts(pdf_bytes, pdf_len);
+        let reader = match open_lenient(bytes) {
+            Ok(r) => r,
+            Err(e) => {
+                set_last_error(e);
+                return ErrorCode::PdfParseError as c_int;
+            }
+        };
+
+        let document = PdfDocument::new(reader);
+        get_form_fields_impl(&document, out_json)
+    })
+}
+
+/// Body of [`oxidize_get_form_fields`] over an already-opened document.
+pub(crate) unsafe fn get_form_fields_impl<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    let fields = match form_field_results(document) {
+        Ok(f) => f,
+        Err(code) => return code,
+    };
+
+    let json = match serde_json::to_string(&fields) {
+        Ok(j) => j,
+        Err(e) => {
+            set_last_error(LastError::caused_by("Failed to serialize form fields", &e));
+            return ErrorCode::SerializationError as c_int;
+        }
+    };
+
+    let c_string = match CString::new(json) {
+        Ok(cs) => cs,
+        Err(e) => {
+            set_last_error(LastError::caused_by(
+                "Form fields JSON contains null bytes",
+                &e,
+            ));
+            return ErrorCode::InvalidUtf8 as c_int;
+        }
+    };
+
+    *out_json = c_string.into_raw();
+    ErrorCode::Success as c_int
+}
+
+/// The field records of [`oxidize_get_form_fields`]. Sets the last error on
+/// failure.
+pub(crate) fn form_field_results<R: Read + Seek>(
+    document: &PdfDocument<R>,
+) -> Result<Vec<FormFieldResult>, c_int> {
+    let all_annots = match document.get_all_annotations() {
+        Ok(a) => a,
+        Err(e) => {
+            set_last_error(LastError::caused_by("Failed to get annotations", &e));
+            return Err(ErrorCode::PdfParseError as c_int);
+        }
+    };
+
+    let mut fields: Vec<FormFieldResult> = Vec::new();
+    // A field with several widgets (e.g. a radio group) appears once per
+    // widget annotation, each resolving to the same `/Parent` field object.
+    // Dedup by that parent reference so the group is reported as one field;
+    // widgets carrying their own `/FT` (merged/standalone fields) have no
+    // `/Parent` and are never collapsed.
+    let mut seen_parents: std::collections::HashSet<(u32, u16)> = std::collections::HashSet::new();
+    for (page_index, dicts) in &all_annots {
+        for dict in dicts {
+            if let Some(field) = classify_form_field(document, dict, page_index.saturating_add(1)) {
+                if let Some(parent_ref) = dict.get("Parent").and_then(|o| o.as_reference()) {
+                    if !seen_parents.insert(parent_ref) {
+                        continue;
+                    }
+                }
+                fields.push(field);
+            }
+        }
+    }
+
+    Ok(fields)
+}
+
+// ── Password variants ────────────────────────────────────────────────────────
+//
+// Each function below is its counterpart with a `password` argument after the
+// PDF buffer: an encrypted input is unlocked with it (user or owner password)
+// as by `with_password`, and unencrypted input ignores it. A wrong password
+// fails with `PasswordRequired` (category `wrong_password`).
+
+/// [`oxidize_extract_text`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_extract_text`]; `password` must be a valid NUL-terminated UTF-8
+/// string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_extract_text_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    out_text: *mut *mut c_char,
+) -> c_int {
+    with_password(password, || {
+        oxidize_extract_text(pdf_bytes, pdf_len, out_text)
+    })
+}
+
+/// [`oxidize_extract_text_with_progress`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_extract_text_with_progress`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_extract_text_with_progress_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    progress: *const ProgressCallbacks,
+    out_text: *mut *mut c_char,
+) -> c_int {
+    with_password(password, || {
+        oxidize_extract_text_with_progress(pdf_bytes, pdf_len, progress, out_text)
+    })
+}
+
+/// [`oxidize_extract_chunks`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_extract_chunks`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_extract_chunks_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    options: *const ChunkOptions,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    with_password(password, || {
+        oxidize_extract_chunks(pdf_bytes, pdf_len, options, out_json)
+    })
+}
+
+/// [`oxidize_get_page_count`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_get_page_count`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_get_page_count_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    out_count: *mut usize,
+) -> c_int {
+    with_password(password, || {
+        oxidize_get_page_count(pdf_bytes, pdf_len, out_count)
+    })
+}
+
+/// [`oxidize_extract_text_from_page`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_extract_text_from_page`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_extract_text_from_page_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    page_number: usize,
+    out_text: *mut *mut c_char,
+) -> c_int {
+    with_password(password, || {
+        oxidize_extract_text_from_page(pdf_bytes, pdf_len, page_number, out_text)
+    })
+}
+
+/// [`oxidize_extract_chunks_from_page`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_extract_chunks_from_page`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_extract_chunks_from_page_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    page_number: usize,
+    options: *const ChunkOptions,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    with_password(password, || {
+        oxidize_extract_chunks_from_page(pdf_bytes, pdf_len, page_number, options, out_json)
+    })
+}
+
+/// [`oxidize_get_pdf_version`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_get_pdf_version`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_get_pdf_version_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    out_version: *mut *mut c_char,
+) -> c_int {
+    with_password(password, || {
+        oxidize_get_pdf_version(pdf_bytes, pdf_len, out_version)
+    })
+}
+
+/// [`oxidize_get_page_dimensions`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_get_page_dimensions`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_get_page_dimensions_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    page_number: usize,
+    out_width: *mut f64,
+    out_height: *mut f64,
+) -> c_int {
+    with_password(password, || {
+        oxidize_get_page_dimensions(pdf_bytes, pdf_len, page_number, out_width, out_height)
+    })
+}
+
+/// [`oxidize_get_metadata`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_get_metadata`]; `password` must be a valid NUL-terminated UTF-8
+/// string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_get_metadata_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    with_password(password, || {
+        oxidize_get_metadata(pdf_bytes, pdf_len, out_json)
+    })
+}
+
+/// [`oxidize_extract_text_with_options`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_extract_text_with_options`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_extract_text_with_options_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    options: *const ExtractionOptionsFFI,
+    out_text: *mut *mut c_char,
+) -> c_int {
+    with_password(password, || {
+        oxidize_extract_text_with_options(pdf_bytes, pdf_len, options, out_text)
+    })
+}
+
+/// [`oxidize_to_markdown_with_options`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_to_markdown_with_options`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_to_markdown_with_options_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    options_json: *const c_char,
+    out_text: *mut *mut c_char,
+) -> c_int {
+    with_password(password, || {
+        oxidize_to_markdown_with_options(pdf_bytes, pdf_len, options_json, out_text)
+    })
+}
+
+/// [`oxidize_to_markdown`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_to_markdown`]; `password` must be a valid NUL-terminated UTF-8
+/// string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_to_markdown_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    out_text: *mut *mut c_char,
+) -> c_int {
+    with_password(password, || {
+        oxidize_to_markdown(pdf_bytes, pdf_len, out_text)
+    })
+}
+
+/// [`oxidize_to_contextual`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_to_contextual`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_to_contextual_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    out_text: *mut *mut c_char,
+) -> c_int {
+    with_password(password, || {
+        oxidize_to_contextual(pdf_bytes, pdf_len, out_text)
+    })
+}
+
+/// [`oxidize_to_json`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_to_json`]; `password` must be a valid NUL-terminated UTF-8
+/// string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_to_json_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    out_text: *mut *mut c_char,
+) -> c_int {
+    with_password(password, || oxidize_to_json(pdf_bytes, pdf_len, out_text))
+}
+
+/// [`oxidize_partition`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_partition`]; `password` must be a valid NUL-terminated UTF-8
+/// string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_partition_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    with_password(password, || oxidize_partition(pdf_bytes, pdf_len, out_json))
+}
+
+/// [`oxidize_partition_with_profile`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_partition_with_profile`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_partition_with_profile_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    profile: u8,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    with_password(password, || {
+        oxidize_partition_with_profile(pdf_bytes, pdf_len, profile, out_json)
+    })
+}
+
+/// [`oxidize_partition_with_config`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_partition_with_config`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_partition_with_config_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    config_json: *const c_char,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    with_password(password, || {
+        oxidize_partition_with_config(pdf_bytes, pdf_len, config_json, out_json)
+    })
+}
+
+/// [`oxidize_partition_with_progress`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_partition_with_progress`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_partition_with_progress_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    config_json: *const c_char,
+    progress: *const ProgressCallbacks,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    with_password(password, || {
+        oxidize_partition_with_progress(pdf_bytes, pdf_len, config_json, progress, out_json)
+    })
+}
+
+/// [`oxidize_rag_chunks`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_rag_chunks`]; `password` must be a valid NUL-terminated UTF-8
+/// string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_rag_chunks_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    with_password(password, || {
+        oxidize_rag_chunks(pdf_bytes, pdf_len, out_json)
+    })
+}
+
+/// [`oxidize_rag_chunks_with_profile`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_rag_chunks_with_profile`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_rag_chunks_with_profile_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    profile: u8,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    with_password(password, || {
+        oxidize_rag_chunks_with_profile(pdf_bytes, pdf_len, profile, out_json)
+    })
+}
+
+/// [`oxidize_rag_chunks_with_config`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_rag_chunks_with_config`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_rag_chunks_with_config_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    partition_config_json: *const c_char,
+    hybrid_config_json: *const c_char,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    with_password(password, || {
+        oxidize_rag_chunks_with_config(
+            pdf_bytes,
+            pdf_len,
+            partition_config_json,
+            hybrid_config_json,
+            out_json,
+        )
+    })
+}
+
+/// [`oxidize_rag_chunks_with_progress`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_rag_chunks_with_progress`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_rag_chunks_with_progress_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    partition_config_json: *const c_char,
+    hybrid_config_json: *const c_char,
+    progress: *const ProgressCallbacks,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    with_password(password, || {
+        oxidize_rag_chunks_with_progress(
+            pdf_bytes,
+            pdf_len,
+            partition_config_json,
+            hybrid_config_json,
+            progress,
+            out_json,
+        )
+    })
+}
+
+/// [`oxidize_semantic_chunks`] for an encrypted PDF.
 ///
 /// # Safety
-/// - `pdf_bytes` must be a valid pointer to `pdf_len` bytes.
-/// - `out_has` must be a valid pointer to a `bool`.
+/// As [`oxidize_semantic_chunks`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
 #[no_mangle]
-pub unsafe extern "C" fn oxidize_has_form_fields(
+pub unsafe extern "C" fn oxidize_semantic_chunks_with_password(
     pdf_bytes: *const u8,
     pdf_len: usize,
-    out_has: *mut bool,
+    password: *const c_char,
+    partition_config_json: *const c_char,
+    semantic_config_json: *const c_char,
+    out_json: *mut *mut c_char,
 ) -> c_int {
-    crate::ffi_guard(move || {
-        clear_last_error();
-
-        if pdf_bytes.is_null() || out_has.is_null() {
-            set_last_error("Null pointer provided to oxidize_has_form_fields");
-            return ErrorCode::NullPointer as c_int;
-        }
-
-        *out_has = false;
-
-        if pdf_len == 0 {
-            set_last_error("PDF data is empty (0 bytes)");
-            return ErrorCode::PdfParseError as c_int;
-        }
-
-        let bytes = slice::from_raw_parts(pdf_bytes, pdf_len);
-        let reader = match open_lenient(bytes) {
-            Ok(r) => r,
-            Err(e) => {
-                set_last_error(e);
-                return ErrorCode::PdfParseError as c_int;
-            }
-        };
-
-        let document = PdfDocument::new(reader);
-        let all_annots = match document.get_all_annotations() {
-            Ok(a) => a,
-            Err(e) => {
-                set_last_error(format!("Failed to get annotations: {e}"));
-                return ErrorCode::PdfParseError as c_int;
-            }
-        };
-
-        for (page_index, dicts) in &all_annots {
-            for dict in dicts {
-                if classify_form_field(&document, dict, page_index.saturating_add(1)).is_some() {
-                    *out_has = true;
-                    return ErrorCode::Success as c_int;
-                }
-            }
-        }
+    with_password(password, || {
+        oxidize_semantic_chunks(
+            pdf_bytes,
+            pdf_len,
+            partition_config_json,
+            semantic_config_json,
+            out_json,
+        )
+    })
+}
 
-        ErrorCode::Success as c_int
+/// [`oxidize_semantic_chunks_with_profile`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_semantic_chunks_with_profile`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_semantic_chunks_with_profile_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    profile: u8,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    with_password(password, || {
+        oxidize_semantic_chunks_with_profile(pdf_bytes, pdf_len, profile, out_json)
     })
 }
 
-/// Extract all form fields from a PDF as JSON.
+/// [`oxidize_semantic_chunks_with_progress`] for an encrypted PDF.
 ///
 /// # Safety
-/// - `pdf_bytes` must be a valid pointer to `pdf_len` bytes.
-/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+/// As [`oxidize_semantic_chunks_with_progress`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
 #[no_mangle]
-pub unsafe extern "C" fn oxidize_get_form_fields(
+pub unsafe extern "C" fn oxidize_semantic_chunks_with_progress_with_password(
     pdf_bytes: *const u8,
     pdf_len: usize,
+    password: *const c_char,
+    partition_config_json: *const c_char,
+    semantic_config_json: *const c_char,
+    progress: *const ProgressCallbacks,
     out_json: *mut *mut c_char,
 ) -> c_int {
-    crate::ffi_guard(move || {
-        clear_last_error();
+    with_password(password, || {
+        oxidize_semantic_chunks_with_progress(
+            pdf_bytes,
+            pdf_len,
+            partition_config_json,
+            semantic_config_json,
+            progress,
+            out_json,
+        )
+    })
+}
 
-        if pdf_bytes.is_null() || out_json.is_null() {
-            set_last_error("Null pointer provided to oxidize_get_form_fields");
-            return ErrorCode::NullPointer as c_int;
-        }
+/// [`oxidize_get_annotations`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_get_annotations`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_get_annotations_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    with_password(password, || {
+        oxidize_get_annotations(pdf_bytes, pdf_len, out_json)
+    })
+}
 
-        *out_json = ptr::null_mut();
+/// [`oxidize_get_page_resources`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_get_page_resources`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_get_page_resources_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    page_number: usize,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    with_password(password, || {
+        oxidize_get_page_resources(pdf_bytes, pdf_len, page_number, out_json)
+    })
+}
 
-        if pdf_len == 0 {
-            set_last_error("PDF data is empty (0 bytes)");
-            return ErrorCode::PdfParseError as c_int;
-        }
+/// [`oxidize_get_page_content_stream`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_get_page_content_stream`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_get_page_content_stream_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    page_number: usize,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    with_password(password, || {
+        oxidize_get_page_content_stream(pdf_bytes, pdf_len, page_number, out_json)
+    })
+}
 
-        let bytes = slice::from_raw_parts(pdf_bytes, pdf_len);
-        let reader = match open_lenient(bytes) {
-            Ok(r) => r,
-            Err(e) => {
-                set_last_error(e);
-                return ErrorCode::PdfParseError as c_int;
-            }
-        };
+/// [`oxidize_analyze_page_content`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_analyze_page_content`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_analyze_page_content_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    page_number: usize,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    with_password(password, || {
+        oxidize_analyze_page_content(pdf_bytes, pdf_len, page_number, out_json)
+    })
+}
 
-        let document = PdfDocument::new(reader);
-        let all_annots = match document.get_all_annotations() {
-            Ok(a) => a,
-            Err(e) => {
-                set_last_error(format!("Failed to get annotations: {e}"));
-                return ErrorCode::PdfParseError as c_int;
-            }
-        };
+/// [`oxidize_has_signatures`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_has_signatures`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_has_signatures_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    out_has_signatures: *mut bool,
+) -> c_int {
+    with_password(password, || {
+        oxidize_has_signatures(pdf_bytes, pdf_len, out_has_signatures)
+    })
+}
 
-        let mut fields: Vec<FormFieldResult> = Vec::new();
-        // A field with several widgets (e.g. a radio group) appears once per
-        // widget annotation, each resolving to the same `/Parent` field object.
-        // Dedup by that parent reference so the group is reported as one field;
-        // widgets carrying their own `/FT` (merged/standalone fields) have no
-        // `/Parent` and are never collapsed.
-        let mut seen_parents: std::collections::HashSet<(u32, u16)> =
-            std::collections::HashSet::new();
-        for (page_index, dicts) in &all_annots {
-            for dict in dicts {
-                if let Some(field) =
-                    classify_form_field(&document, dict, page_index.saturating_add(1))
-                {
-                    if let Some(parent_ref) = dict.get("Parent").and_then(|o| o.as_reference()) {
-                        if !seen_parents.insert(parent_ref) {
-                            continue;
-                        }
-                    }
-                    fields.push(field);
-                }
-            }
-        }
+/// [`oxidize_get_signatures`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_get_signatures`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_get_signatures_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    with_password(password, || {
+        oxidize_get_signatures(pdf_bytes, pdf_len, out_json)
+    })
+}
 
-        let json = match serde_json::to_string(&fields) {
-            Ok(j) => j,
-            Err(e) => {
-                set_last_error(format!("Failed to serialize form fields: {e}"));
-                return ErrorCode::SerializationError as c_int;
-            }
-        };
+/// [`oxidize_verify_signatures`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_verify_signatures`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_verify_signatures_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    with_password(password, || {
+        oxidize_verify_signatures(pdf_bytes, pdf_len, out_json)
+    })
+}
 
-        let c_string = match CString::new(json) {
-            Ok(cs) => cs,
-            Err(e) => {
-                set_last_error(format!("Form fields JSON contains null bytes: {e}"));
-                return ErrorCode::InvalidUtf8 as c_int;
-            }
-        };
+/// [`oxidize_has_form_fields`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_has_form_fields`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_has_form_fields_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    out_has: *mut bool,
+) -> c_int {
+    with_password(password, || {
+        oxidize_has_form_fields(pdf_bytes, pdf_len, out_has)
+    })
+}
 
-        *out_json = c_string.into_raw();
-        ErrorCode::Success as c_int
+/// [`oxidize_get_form_fields`] for an encrypted PDF.
+///
+/// # Safety
+/// As [`oxidize_get_form_fields`]; `password` must be a
+/// valid NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_get_form_fields_with_password(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    with_password(password, || {
+        oxidize_get_form_fields(pdf_bytes, pdf_len, out_json)
     })
 }
 
@@ -3821,6 +5217,37 @@ mod profile_ffi_tests {
         assert!(out.is_null());
     }
 
+    #[test]
+    fn oxidize_partition_with_config_reports_out_of_range_fields() {
+        let pdf = sample_pdf();
+        let cfg = std::ffi::CString::new(
+            r#"{"detect_tables":true,"detect_headers_footers":true,
+                "title_min_font_ratio":1.3,"header_zone":1.5,"footer_zone":-1,
+                "reading_order":"Bogus","min_table_confidence":0.5}"#,
+        )
+        .unwrap();
+        let mut out: *mut c_char = std::ptr::null_mut();
+        let code = unsafe {
+            oxidize_partition_with_config(pdf.as_ptr(), pdf.len(), cfg.as_ptr(), &mut out)
+        };
+        assert_eq!(code, ErrorCode::InvalidArgument as c_int);
+        assert!(out.is_null());
+
+        let mut err: *mut c_char = std::ptr::null_mut();
+        unsafe { crate::oxidize_get_last_error(&mut err) };
+        let message = unsafe { CStr::from_ptr(err) }
+            .to_string_lossy()
+            .into_owned();
+        unsafe { crate::oxidize_free_string(err) };
+        assert!(
+            message.starts_with("invalid PartitionConfig in config_json: "),
+            "{message}"
+        );
+        for path in ["$.header_zone", "$.footer_zone", "$.reading_order"] {
+            assert!(message.contains(path), "{path} missing from {message}");
+        }
+    }
+
     #[test]
     fn oxidize_rag_chunks_with_config_rejects_bad_hybrid_json() {
         let pdf = sample_pdf();
@@ -4386,3 +5813,105 @@ mod ffi_layout_tests {
         assert_eq!(offset_of!(ExtractionOptionsFFI, include_artifacts), 57);
     }
 }
+
+#[cfg(test)]
+mod password_tests {
+    use super::*;
+    use crate::test_fixtures::{SamplePdf, OWNER_PASSWORD};
+
+    unsafe fn read(call: impl FnOnce(*mut *mut c_char) -> c_int) -> Result<String, c_int> {
+        let mut out: *mut c_char = ptr::null_mut();
+        let code = call(&mut out);
+        if code != ErrorCode::Success as c_int {
+            assert!(out.is_null());
+            return Err(code);
+        }
+        let text = CStr::from_ptr(out).to_str().unwrap().to_owned();
+        crate::oxidize_free_string(out);
+        Ok(text)
+    }
+
+    #[test]
+    fn password_variants_unlock_one_shot_reads() {
+        let plain = SamplePdf::new(2).build();
+        let pdf = SamplePdf::new(2).encrypted("user-secret").build();
+        unsafe {
+            let text = read(|o| oxidize_extract_text(plain.as_ptr(), plain.len(), o)).unwrap();
+            let chunks = read(|o| oxidize_rag_chunks(plain.as_ptr(), plain.len(), o)).unwrap();
+
+            for password in ["user-secret", OWNER_PASSWORD] {
+                let password = CString::new(password).unwrap();
+                let unlocked = read(|o| {
+                    oxidize_extract_text_with_password(
+                        pdf.as_ptr(),
+                        pdf.len(),
+                        password.as_ptr(),
+                        o,
+                    )
+                });
+                assert_eq!(unlocked.unwrap(), text);
+                let unlocked = read(|o| {
+                    oxidize_rag_chunks_with_password(pdf.as_ptr(), pdf.len(), password.as_ptr(), o)
+                });
+                assert_eq!(unlocked.unwrap(), chunks);
+                let mut count = 0;
+                assert_eq!(
+                    oxidize_get_page_count_with_password(
+                        pdf.as_ptr(),
+                        pdf.len(),
+                        password.as_ptr(),
+                        &mut count
+                    ),
+                    ErrorCode::Success as c_int
+                );
+                assert_eq!(count, 2);
+            }
+
+            // The password is only armed for the call that takes it.
+            let code = read(|o| oxidize_extract_text(pdf.as_ptr(), pdf.len(), o));
+            assert_eq!(code, Err(ErrorCode::PasswordRequired as c_int));
+
+            // Unencrypted input ignores the password.
+            let password = CString::new("user-secret").unwrap();
+            let unlocked = read(|o| {
+                oxidize_extract_text_with_password(
+                    plain.as_ptr(),
+                    plain.len(),
+                    password.as_ptr(),
+                    o,
+                )
+            });
+            assert_eq!(unlocked.unwrap(), text);
+        }
+    }
+
+    #[test]
+    fn password_variants_reject_bad_passwords() {
+        let pdf = SamplePdf::new(1).encrypted("user-secret").build();
+        unsafe {
+            let wrong = CString::new("nope").unwrap();
+            let code = read(|o| {
+                oxidize_partition_with_password(pdf.as_ptr(), pdf.len(), wrong.as_ptr(), o)
+            });
+            assert_eq!(code, Err(ErrorCode::PasswordRequired as c_int));
+            let mut json: *mut c_char = ptr::null_mut();
+            assert_eq!(
+                crate::oxidize_get_last_error_json(&mut json),
+                ErrorCode::Success as c_int
+            );
+            let detail: serde_json::Value =
+                serde_json::from_str(CStr::from_ptr(json).to_str().unwrap()).unwrap();
+            crate::oxidize_free_string(json);
+            assert_eq!(detail["category"], "wrong_password");
+
+            let code =
+                read(|o| oxidize_partition_with_password(pdf.as_ptr(), pdf.len(), ptr::null(), o));
+            assert_eq!(code, Err(ErrorCode::NullPointer as c_int));
+            let invalid = [0xff, 0];
+            let code = read(|o| {
+                oxidize_partition_with_password(pdf.as_ptr(), pdf.len(), invalid.as_ptr().cast(), o)
+            });
+            assert_eq!(code, Err(ErrorCode::InvalidUtf8 as c_int));
+        }
+    }
+}
@@ -11,6 +11,14 @@
 //! upstream could swap the meaning of any discriminant without a compile error.
 //! The exhaustive `profile_discriminants` test in this module is the contract
 //! guard — every variant is asserted by name.
+//!
+//! Entry points do not deserialize these DTOs directly: [`from_json_value`]
+//! first checks the raw JSON field by field against the same rules as the
+//! C# `Validate()` methods (types, accepted tags, numeric ranges) and reports
+//! every invalid field with its path, instead of stopping at serde's first
+//! error.
+
+use std::fmt;
 
 use oxidize_pdf::pipeline::partition::ReadingOrderStrategy as RustReadingOrder;
 use oxidize_pdf::pipeline::{
@@ -18,18 +26,27 @@ use oxidize_pdf::pipeline::{
     MergePolicy as RustMergePolicy, PartitionConfig as RustPartition,
     SemanticChunkConfig as RustSemantic,
 };
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
+use serde_json::{Map, Value};
 
 #[derive(Debug, Deserialize)]
 #[serde(untagged)]
 pub enum ReadingOrderDto {
-    Unit(String),
+    Unit(ReadingOrderTag),
     XyCut {
         #[serde(rename = "XYCut")]
         x: XyCutDto,
     },
 }
 
+/// Payload-free reading orders, spelled as bare strings in JSON.
+#[derive(Debug, Deserialize)]
+pub enum ReadingOrderTag {
+    Simple,
+    None,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct XyCutDto {
     pub min_gap: f64,
@@ -38,9 +55,8 @@ pub struct XyCutDto {
 impl From<ReadingOrderDto> for RustReadingOrder {
     fn from(d: ReadingOrderDto) -> Self {
         match d {
-            ReadingOrderDto::Unit(s) if s == "Simple" => RustReadingOrder::Simple,
-            ReadingOrderDto::Unit(s) if s == "None" => RustReadingOrder::None,
-            ReadingOrderDto::Unit(s) => panic!("unknown reading_order tag: {s}"),
+            ReadingOrderDto::Unit(ReadingOrderTag::Simple) => RustReadingOrder::Simple,
+            ReadingOrderDto::Unit(ReadingOrderTag::None) => RustReadingOrder::None,
             ReadingOrderDto::XyCut { x } => RustReadingOrder::XYCut { min_gap: x.min_gap },
         }
     }
@@ -163,6 +179,47 @@ impl From<MarkdownOptionsDto> for oxidize_pdf::ai::MarkdownOptions {
     }
 }
 
+/// JSON mirror of [`crate::parser::ExtractionOptionsFFI`] in which every field
+/// is optional; omitted fields keep the value of the options it is applied to.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct ExtractionOptionsDto {
+    pub preserve_layout: Option<bool>,
+    pub space_threshold: Option<f64>,
+    pub newline_threshold: Option<f64>,
+    pub sort_by_position: Option<bool>,
+    pub detect_columns: Option<bool>,
+    pub column_threshold: Option<f64>,
+    pub merge_hyphenated: Option<bool>,
+    pub tj_space_threshold: Option<f64>,
+    pub reconstruct_paragraphs: Option<bool>,
+    pub include_artifacts: Option<bool>,
+}
+
+impl ExtractionOptionsDto {
+    /// `base` with every field this DTO sets overridden.
+    pub fn apply(
+        self,
+        base: oxidize_pdf::text::ExtractionOptions,
+    ) -> oxidize_pdf::text::ExtractionOptions {
+        oxidize_pdf::text::ExtractionOptions {
+            preserve_layout: self.preserve_layout.unwrap_or(base.preserve_layout),
+            space_threshold: self.space_threshold.unwrap_or(base.space_threshold),
+            newline_threshold: self.newline_threshold.unwrap_or(base.newline_threshold),
+            sort_by_position: self.sort_by_position.unwrap_or(base.sort_by_position),
+            detect_columns: self.detect_columns.unwrap_or(base.detect_columns),
+            column_threshold: self.column_threshold.unwrap_or(base.column_threshold),
+            merge_hyphenated: self.merge_hyphenated.unwrap_or(base.merge_hyphenated),
+            tj_space_threshold: self.tj_space_threshold.unwrap_or(base.tj_space_threshold),
+            reconstruct_paragraphs: self
+                .reconstruct_paragraphs
+                .unwrap_or(base.reconstruct_paragraphs),
+            include_artifacts: self.include_artifacts.unwrap_or(base.include_artifacts),
+            ..base
+        }
+    }
+}
+
 /// Map the `u8` discriminant received across the FFI boundary to the Rust enum.
 ///
 /// Order MUST match the C# `ExtractionProfile` enum and the Rust core
@@ -181,6 +238,386 @@ pub fn profile_from_u8(v: u8) -> Result<RustProfile, String> {
     })
 }
 
+// ── Validation ───────────────────────────────────────────────────────────────
+
+/// One invalid field of a config payload.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldError {
+    /// JSONPath-style location, e.g. `$.reading_order.XYCut.min_gap`.
+    pub path: String,
+    /// What the field accepts and what it held.
+    pub message: String,
+}
+
+/// Every invalid field found in one config payload.
+#[derive(Debug)]
+pub struct ConfigError(pub Vec<FieldError>);
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, e) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{} {}", e.path, e.message)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// A config DTO whose JSON object is validated before deserializing.
+pub trait ValidatedConfig: DeserializeOwned {
+    /// Record an error for every invalid field of the payload.
+    fn check(fields: &mut Fields<'_>);
+}
+
+/// Validate `value` field by field, then deserialize it.
+pub fn from_json_value<D: ValidatedConfig>(value: Value) -> Result<D, ConfigError> {
+    let mut errors = Vec::new();
+    check_object::<D>(&value, "$".into(), &mut errors);
+    if !errors.is_empty() {
+        return Err(ConfigError(errors));
+    }
+    // Anything `check` accepts must deserialize; this only fires if the two
+    // drift apart.
+    serde_json::from_value(value).map_err(|e| {
+        ConfigError(vec![FieldError {
+            path: "$".into(),
+            message: e.to_string(),
+        }])
+    })
+}
+
+fn check_object<D: ValidatedConfig>(value: &Value, path: String, errors: &mut Vec<FieldError>) {
+    match value {
+        Value::Object(object) => D::check(&mut Fields::new(object, path, errors)),
+        other => errors.push(FieldError {
+            path,
+            message: format!("must be a JSON object, got {other}"),
+        }),
+    }
+}
+
+/// Accepted range of a numeric field.
+#[derive(Clone, Copy)]
+pub enum Bound {
+    /// `> 0`
+    Positive,
+    /// `>= 0`
+    NonNegative,
+    /// `[0, 1]`
+    Fraction,
+}
+
+impl Bound {
+    fn accepts(self, v: f64) -> bool {
+        match self {
+            Bound::Positive => v > 0.0,
+            Bound::NonNegative => v >= 0.0,
+            Bound::Fraction => (0.0..=1.0).contains(&v),
+        }
+    }
+
+    fn describe(self) -> &'static str {
+        match self {
+            Bound::Positive => "a number > 0",
+            Bound::NonNegative => "a number >= 0",
+            Bound::Fraction => "a number in [0, 1]",
+        }
+    }
+}
+
+/// The fields of one JSON object being validated, with their common path.
+pub struct Fields<'a> {
+    object: &'a Map<String, Value>,
+    path: String,
+    errors: &'a mut Vec<FieldError>,
+}
+
+impl<'a> Fields<'a> {
+    fn new(object: &'a Map<String, Value>, path: String, errors: &'a mut Vec<FieldError>) -> Self {
+        Self {
+            object,
+            path,
+            errors,
+        }
+    }
+
+    fn fail(&mut self, name: &str, message: String) {
+        self.errors.push(FieldError {
+            path: format!("{}.{name}", self.path),
+            message,
+        });
+    }
+
+    /// The field's value; a missing field is an error when `required`.
+    fn get(&mut self, name: &str, required: bool, accepted: &str) -> Option<&'a Value> {
+        let value = self.object.get(name);
+        if value.is_none() && required {
+            self.fail(name, format!("is required ({accepted})"));
+        }
+        value
+    }
+
+    pub fn boolean(&mut self, name: &str, required: bool) {
+        if let Some(v) = self.get(name, required, "true or false") {
+            if !v.is_boolean() {
+                self.fail(name, format!("must be true or false, got {v}"));
+            }
+        }
+    }
+
+    pub fn number(&mut self, name: &str, bound: Bound) -> Option<f64> {
+        let v = self.get(name, true, bound.describe())?;
+        match v.as_f64() {
+            Some(n) if bound.accepts(n) => Some(n),
+            _ => {
+                self.fail(name, format!("must be {}, got {v}", bound.describe()));
+                None
+            }
+        }
+    }
+
+    /// Like [`Fields::number`], for a field that may be omitted.
+    pub fn optional_number(&mut self, name: &str, bound: Bound) {
+        if self.object.contains_key(name) {
+            self.number(name, bound);
+        }
+    }
+
+    /// An optional nested object validated as `D`.
+    pub fn section<D: ValidatedConfig>(&mut self, name: &str) {
+        if let Some(v) = self.object.get(name) {
+            check_object::<D>(v, format!("{}.{name}", self.path), self.errors);
+        }
+    }
+
+    /// Reject members other than `accepted`.
+    pub fn only(&mut self, accepted: &[&str]) {
+        let unknown: Vec<&String> = self
+            .object
+            .keys()
+            .filter(|k| !accepted.contains(&k.as_str()))
+            .collect();
+        for key in unknown {
+            self.fail(
+                key,
+                format!("is not a known field (accepted: {})", accepted.join(", ")),
+            );
+        }
+    }
+
+    pub fn count(&mut self, name: &str, min: u64) -> Option<u64> {
+        let accepted = format!("an integer >= {min}");
+        let v = self.get(name, true, &accepted)?;
+        match v.as_u64() {
+            Some(n) if n >= min => Some(n),
+            _ => {
+                self.fail(name, format!("must be {accepted}, got {v}"));
+                None
+            }
+        }
+    }
+
+    /// A required string field that must be one of `accepted`; returns it
+    /// when valid.
+    pub fn one_of(&mut self, name: &str, accepted: &[&str]) -> Option<&'a str> {
+        let list = accepted
+            .iter()
+            .map(|a| format!("\"{a}\""))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let v = self.get(name, true, &format!("one of {list}"))?;
+        match v.as_str() {
+            Some(s) if accepted.contains(&s) => Some(s),
+            _ => {
+                self.fail(name, format!("must be one of {list}, got {v}"));
+                None
+            }
+        }
+    }
+
+    /// Like [`Fields::count`], for a field that may be omitted.
+    pub fn optional_count(&mut self, name: &str, min: u64) {
+        if self.object.contains_key(name) {
+            self.count(name, min);
+        }
+    }
+
+    pub fn string(&mut self, name: &str, required: bool) {
+        if let Some(v) = self.get(name, required, "a string") {
+            if !v.is_string() {
+                self.fail(name, format!("must be a string, got {v}"));
+            }
+        }
+    }
+
+    /// An optional integer in `[0, 255]` (a profile id).
+    pub fn optional_byte(&mut self, name: &str) {
+        if let Some(v) = self.object.get(name) {
+            if !v.as_u64().is_some_and(|n| n <= u64::from(u8::MAX)) {
+                self.fail(name, format!("must be an integer in [0, 255], got {v}"));
+            }
+        }
+    }
+
+    /// At most one of `names` may be present; exactly one when `required`.
+    pub fn exclusive(&mut self, names: &[&str], required: bool) {
+        let present: Vec<&str> = names
+            .iter()
+            .copied()
+            .filter(|n| self.object.contains_key(*n))
+            .collect();
+        match present.as_slice() {
+            [] if required => self.fail(
+                names[0],
+                format!("is required (or {})", names[1..].join(", ")),
+            ),
+            [] | [_] => {}
+            [first, rest @ ..] => {
+                for name in rest {
+                    self.fail(name, format!("cannot be combined with {first}"));
+                }
+            }
+        }
+    }
+
+    /// A required array whose elements are objects checked by `each`.
+    pub fn items(&mut self, name: &str, mut each: impl FnMut(&mut Fields<'_>)) {
+        let Some(v) = self.get(name, true, "an array of objects") else {
+            return;
+        };
+        let Some(items) = v.as_array() else {
+            self.fail(name, format!("must be an array of objects, got {v}"));
+            return;
+        };
+        for (i, item) in items.iter().enumerate() {
+            let path = format!("{}.{name}[{i}]", self.path);
+            match item {
+                Value::Object(object) => each(&mut Fields::new(object, path, self.errors)),
+                other => self.errors.push(FieldError {
+                    path,
+                    message: format!("must be a JSON object, got {other}"),
+                }),
+            }
+        }
+    }
+
+    fn reading_order(&mut self, name: &str) {
+        const ACCEPTED: &str = r#""Simple", "None" or {"XYCut": {"min_gap": <number >= 0>}}"#;
+        let Some(v) = self.get(name, true, ACCEPTED) else {
+            return;
+        };
+        match v {
+            Value::String(s) if s == "Simple" || s == "None" => {}
+            Value::Object(o) if o.len() == 1 && o.contains_key("XYCut") => match &o["XYCut"] {
+                Value::Object(xy) => {
+                    let path = format!("{}.{name}.XYCut", self.path);
+                    Fields::new(xy, path, self.errors).number("min_gap", Bound::NonNegative);
+                }
+                other => self.errors.push(FieldError {
+                    path: format!("{}.{name}.XYCut", self.path),
+                    message: format!("must be an object with min_gap, got {other}"),
+                }),
+            },
+            other => self.fail(name, format!("must be {ACCEPTED}, got {other}")),
+        }
+    }
+
+    /// `overlap_tokens` must stay below `max_tokens`.
+    fn token_budget(&mut self) {
+        self.budget("max_tokens", "overlap_tokens");
+    }
+
+    /// A required size (>= 1) and overlap (>= 0) with overlap below size.
+    pub fn budget(&mut self, size: &str, overlap: &str) {
+        let max = self.count(size, 1);
+        let over = self.count(overlap, 0);
+        if let (Some(max), Some(over)) = (max, over) {
+            if over >= max {
+                self.fail(
+                    overlap,
+                    format!("must be less than {size} ({max}), got {over}"),
+                );
+            }
+        }
+    }
+}
+
+impl ValidatedConfig for PartitionConfigDto {
+    fn check(f: &mut Fields<'_>) {
+        f.boolean("detect_tables", true);
+        f.boolean("detect_headers_footers", true);
+        f.number("title_min_font_ratio", Bound::Positive);
+        f.number("header_zone", Bound::Fraction);
+        f.number("footer_zone", Bound::Fraction);
+        f.reading_order("reading_order");
+        f.number("min_table_confidence", Bound::Fraction);
+        f.boolean("prefer_ruling_tables", false);
+        f.boolean("detect_spatial_tables", false);
+    }
+}
+
+impl ValidatedConfig for HybridChunkConfigDto {
+    fn check(f: &mut Fields<'_>) {
+        f.token_budget();
+        f.boolean("merge_adjacent", true);
+        f.boolean("propagate_headings", true);
+        f.one_of("merge_policy", &["SameTypeOnly", "AnyInlineContent"]);
+    }
+}
+
+impl ValidatedConfig for SemanticChunkConfigDto {
+    fn check(f: &mut Fields<'_>) {
+        f.token_budget();
+        f.boolean("respect_element_boundaries", true);
+    }
+}
+
+impl ValidatedConfig for ExtractionOptionsDto {
+    fn check(f: &mut Fields<'_>) {
+        f.only(&[
+            "preserve_layout",
+            "space_threshold",
+            "newline_threshold",
+            "sort_by_position",
+            "detect_columns",
+            "column_threshold",
+            "merge_hyphenated",
+            "tj_space_threshold",
+            "reconstruct_paragraphs",
+            "include_artifacts",
+        ]);
+        for name in [
+            "preserve_layout",
+            "sort_by_position",
+            "detect_columns",
+            "merge_hyphenated",
+            "reconstruct_paragraphs",
+            "include_artifacts",
+        ] {
+            f.boolean(name, false);
+        }
+        for name in [
+            "space_threshold",
+            "newline_threshold",
+            "column_threshold",
+            "tj_space_threshold",
+        ] {
+            f.optional_number(name, Bound::NonNegative);
+        }
+    }
+}
+
+impl ValidatedConfig for MarkdownOptionsDto {
+    fn check(f: &mut Fields<'_>) {
+        f.boolean("include_metadata", true);
+        f.boolean("include_page_numbers", true);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -375,10 +812,111 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "unknown reading_order tag")]
-    fn reading_order_unknown_tag_panics() {
+    fn reading_order_unknown_tag_is_rejected() {
         let json = r#""Bogus""#;
-        let dto: ReadingOrderDto = serde_json::from_str(json).unwrap();
-        let _: RustReadingOrder = dto.into();
+        assert!(serde_json::from_str::<ReadingOrderDto>(json).is_err());
+    }
+
+    fn rejected<D: ValidatedConfig + fmt::Debug>(json: &str) -> Vec<FieldError> {
+        from_json_value::<D>(serde_json::from_str(json).unwrap())
+            .expect_err("payload should be rejected")
+            .0
+    }
+
+    #[test]
+    fn partition_config_reports_every_invalid_field() {
+        let errors = rejected::<PartitionConfigDto>(
+            r#"{
+                "detect_tables": "yes",
+                "title_min_font_ratio": 0,
+                "header_zone": 1.5,
+                "footer_zone": 0.05,
+                "reading_order": "Bogus",
+                "min_table_confidence": -0.1
+            }"#,
+        );
+        let paths: Vec<&str> = errors.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "$.detect_tables",
+                "$.detect_headers_footers",
+                "$.title_min_font_ratio",
+                "$.header_zone",
+                "$.reading_order",
+                "$.min_table_confidence",
+            ]
+        );
+        assert_eq!(errors[3].message, "must be a number in [0, 1], got 1.5");
+        assert!(errors[4]
+            .message
+            .contains(r#""Simple", "None" or {"XYCut""#));
+        assert!(errors[4].message.ends_with(r#"got "Bogus""#));
+    }
+
+    #[test]
+    fn partition_config_checks_nested_xycut_gap() {
+        let errors = rejected::<PartitionConfigDto>(
+            r#"{
+                "detect_tables": true,
+                "detect_headers_footers": true,
+                "title_min_font_ratio": 1.3,
+                "header_zone": 0.05,
+                "footer_zone": 0.05,
+                "reading_order": {"XYCut":{"min_gap":-4}},
+                "min_table_confidence": 0.5
+            }"#,
+        );
+        assert_eq!(
+            errors,
+            [FieldError {
+                path: "$.reading_order.XYCut.min_gap".into(),
+                message: "must be a number >= 0, got -4".into(),
+            }]
+        );
+    }
+
+    #[test]
+    fn chunk_configs_range_check_token_budget() {
+        let errors = rejected::<HybridChunkConfigDto>(
+            r#"{"max_tokens":-1,"overlap_tokens":0,"merge_adjacent":true,
+                "propagate_headings":true,"merge_policy":"Everything"}"#,
+        );
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].path, "$.max_tokens");
+        assert_eq!(errors[0].message, "must be an integer >= 1, got -1");
+        assert_eq!(
+            errors[1].message,
+            r#"must be one of "SameTypeOnly", "AnyInlineContent", got "Everything""#
+        );
+
+        let errors = rejected::<SemanticChunkConfigDto>(
+            r#"{"max_tokens":10,"overlap_tokens":10,"respect_element_boundaries":true}"#,
+        );
+        assert_eq!(errors[0].path, "$.overlap_tokens");
+        assert_eq!(
+            errors[0].message,
+            "must be less than max_tokens (10), got 10"
+        );
+
+        let errors = rejected::<MarkdownOptionsDto>("[]");
+        assert_eq!(errors[0].path, "$");
+    }
+
+    #[test]
+    fn valid_configs_pass_validation() {
+        let dto: HybridChunkConfigDto = from_json_value(serde_json::json!({
+            "max_tokens": 64, "overlap_tokens": 10, "merge_adjacent": true,
+            "propagate_headings": false, "merge_policy": "SameTypeOnly"
+        }))
+        .unwrap();
+        assert_eq!(dto.max_tokens, 64);
+        let dto: PartitionConfigDto = from_json_value(serde_json::json!({
+            "detect_tables": true, "detect_headers_footers": true,
+            "title_min_font_ratio": 1.3, "header_zone": 0, "footer_zone": 1,
+            "reading_order": {"XYCut": {"min_gap": 20}}, "min_table_confidence": 0.5
+        }))
+        .unwrap();
+        assert!(matches!(dto.reading_order, ReadingOrderDto::XyCut { .. }));
     }
 }
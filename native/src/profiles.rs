@@ -0,0 +1,491 @@
+//! Named custom extraction profiles.
+//!
+//! Built-in profiles cross the boundary as `u8` discriminants 0–6 (see
+//! [`crate::pipeline_config::profile_from_u8`]). [`oxidize_register_profile`]
+//! adds a named profile bundling text extraction options, a partition config
+//! and hybrid and semantic chunk configs, and returns the id (from
+//! [`OXIDIZE_CUSTOM_PROFILE_BASE`] up) that selects it wherever a built-in
+//! discriminant is accepted: `oxidize_partition_with_profile`,
+//! `oxidize_rag_chunks_with_profile`, `oxidize_semantic_chunks_with_profile`
+//! and their parsed-document variants.
+//! [`oxidize_profile_id`] resolves built-in and custom names to ids.
+//!
+//! The registry is process-wide. Re-registering a name replaces its
+//! definition and keeps its id; calls already running finish with the
+//! definition they started with.
+//!
+//! Custom profiles partition one page at a time, so on pages with a ruled
+//! table grid cell text is assigned from the paragraph-level fragments
+//! (upstream's cell-granular re-extraction is internal to the built-in path).
+
+use std::ffi::CStr;
+use std::io::{Read, Seek};
+use std::os::raw::{c_char, c_int};
+use std::sync::{Arc, RwLock};
+
+use oxidize_pdf::parser::PdfDocument;
+use oxidize_pdf::pipeline::{
+    Element, ExtractionProfile, HybridChunkConfig, HybridChunker, PartitionConfig, RagChunk,
+    SemanticChunk, SemanticChunkConfig, SemanticChunker,
+};
+use oxidize_pdf::text::ExtractionOptions;
+use serde::Deserialize;
+
+use crate::chunk_stream::PagePartitioner;
+use crate::error_detail::{ErrorCategory, LastError};
+use crate::parser::parse_config_arg;
+use crate::pipeline_config::{
+    profile_from_u8, ExtractionOptionsDto, Fields, HybridChunkConfigDto, PartitionConfigDto,
+    SemanticChunkConfigDto, ValidatedConfig,
+};
+use crate::progress::{partition_pages_with, Progress};
+use crate::{clear_last_error, set_last_error, ErrorCode};
+
+/// Id of the first registered custom profile; ids below are built-ins.
+pub const OXIDIZE_CUSTOM_PROFILE_BASE: u8 = 128;
+
+/// Built-in profile names, indexed by discriminant.
+const BUILTIN_NAMES: [&str; 7] = [
+    "Standard",
+    "Academic",
+    "Form",
+    "Government",
+    "Dense",
+    "Presentation",
+    "Rag",
+];
+
+/// A registered profile, resolved to core types.
+pub(crate) struct CustomProfile {
+    extraction: ExtractionOptions,
+    partition: PartitionConfig,
+    hybrid: HybridChunkConfig,
+    semantic: SemanticChunkConfig,
+}
+
+/// Custom profiles in id order (index + [`OXIDIZE_CUSTOM_PROFILE_BASE`]).
+static REGISTRY: RwLock<Vec<(String, Arc<CustomProfile>)>> = RwLock::new(Vec::new());
+
+/// `profile_json` of [`oxidize_register_profile`]. Every section is optional.
+#[derive(Deserialize)]
+pub(crate) struct ProfileDto {
+    #[serde(default)]
+    extraction: ExtractionOptionsDto,
+    partition: Option<PartitionConfigDto>,
+    hybrid: Option<HybridChunkConfigDto>,
+    semantic: Option<SemanticChunkConfigDto>,
+}
+
+impl ValidatedConfig for ProfileDto {
+    fn check(f: &mut Fields<'_>) {
+        f.only(&["extraction", "partition", "hybrid", "semantic"]);
+        f.section::<ExtractionOptionsDto>("extraction");
+        f.section::<PartitionConfigDto>("partition");
+        f.section::<HybridChunkConfigDto>("hybrid");
+        f.section::<SemanticChunkConfigDto>("semantic");
+    }
+}
+
+impl From<ProfileDto> for CustomProfile {
+    fn from(d: ProfileDto) -> Self {
+        Self {
+            extraction: d.extraction.apply(PagePartitioner::default_options()),
+            partition: d.partition.map(Into::into).unwrap_or_default(),
+            hybrid: d.hybrid.map(Into::into).unwrap_or_default(),
+            semantic: d.semantic.map(Into::into).unwrap_or_default(),
+        }
+    }
+}
+
+/// A built-in or custom profile selected by id.
+pub(crate) enum Profile {
+    Builtin(ExtractionProfile),
+    Custom(Arc<CustomProfile>),
+}
+
+impl Profile {
+    pub(crate) fn resolve(id: u8) -> Result<Self, String> {
+        if id < OXIDIZE_CUSTOM_PROFILE_BASE {
+            return profile_from_u8(id).map(Profile::Builtin);
+        }
+        let registry = REGISTRY.read().unwrap_or_else(|e| e.into_inner());
+        registry
+            .get(usize::from(id - OXIDIZE_CUSTOM_PROFILE_BASE))
+            .map(|(_, p)| Profile::Custom(Arc::clone(p)))
+            .ok_or_else(|| format!("no custom profile is registered with id {id}"))
+    }
+
+    pub(crate) fn partition<R: Read + Seek>(
+        &self,
+        document: &PdfDocument<R>,
+    ) -> Result<Vec<Element>, c_int> {
+        match self {
+            Profile::Builtin(p) => document.partition_with_profile(p.clone()).map_err(|e| {
+                set_last_error(LastError::caused_by("Failed to partition PDF", &e));
+                ErrorCode::PdfParseError as c_int
+            }),
+            Profile::Custom(c) => partition_pages_with(
+                document,
+                &mut PagePartitioner::with_options(c.partition.clone(), c.extraction.clone()),
+                Progress::NONE,
+            ),
+        }
+    }
+
+    pub(crate) fn rag_chunks<R: Read + Seek>(
+        &self,
+        document: &PdfDocument<R>,
+    ) -> Result<Vec<RagChunk>, c_int> {
+        match self {
+            Profile::Builtin(p) => document.rag_chunks_with_profile(p.clone()).map_err(|e| {
+                set_last_error(LastError::caused_by("Failed to extract RAG chunks", &e));
+                ErrorCode::PdfParseError as c_int
+            }),
+            Profile::Custom(c) => {
+                let elements = self.partition(document)?;
+                let chunker = HybridChunker::new(c.hybrid.clone());
+                Ok(chunker
+                    .chunk(&elements)
+                    .iter()
+                    .enumerate()
+                    .map(|(i, hc)| RagChunk::from_hybrid_chunk(i, hc))
+                    .collect())
+            }
+        }
+    }
+
+    /// Partition with the profile, then chunk with its semantic config
+    /// (the default `SemanticChunkConfig` for built-ins).
+    pub(crate) fn semantic_chunks<R: Read + Seek>(
+        &self,
+        document: &PdfDocument<R>,
+    ) -> Result<Vec<SemanticChunk>, c_int> {
+        let elements = self.partition(document)?;
+        let config = match self {
+            Profile::Builtin(_) => SemanticChunkConfig::default(),
+            Profile::Custom(c) => c.semantic.clone(),
+        };
+        Ok(SemanticChunker::new(config).chunk(&elements))
+    }
+}
+
+/// Read a profile name argument.
+unsafe fn name_arg<'a>(name: *const c_char) -> Result<&'a str, c_int> {
+    let name = CStr::from_ptr(name).to_str().map_err(|_| {
+        set_last_error("Invalid UTF-8 in profile name");
+        ErrorCode::InvalidUtf8 as c_int
+    })?;
+    if name.is_empty() {
+        set_last_error(LastError::new(
+            ErrorCategory::InvalidArgument,
+            "profile name must not be empty",
+        ));
+        return Err(ErrorCode::InvalidArgument as c_int);
+    }
+    Ok(name)
+}
+
+/// Register (or replace) the custom profile `name`.
+///
+/// `profile_json` is an object with four optional sections:
+/// - `extraction` — any `ExtractionOptionsFFI` field (`space_threshold`,
+///   `detect_columns`, …); omitted fields keep the options the partitioner
+///   uses by default (`preserve_layout` and `reconstruct_paragraphs` on,
+///   upstream defaults otherwise);
+/// - `partition` — a `PartitionConfig` object, as for
+///   `oxidize_partition_with_config`; default `PartitionConfig` if omitted;
+/// - `hybrid` — a `HybridChunkConfig` object, used by
+///   `oxidize_rag_chunks_with_profile`; default `HybridChunkConfig` if
+///   omitted;
+/// - `semantic` — a `SemanticChunkConfig` object, as for
+///   `oxidize_semantic_chunks`, used by
+///   `oxidize_semantic_chunks_with_profile`; default `SemanticChunkConfig`
+///   if omitted.
+///
+/// Sections are validated like the standalone configs and every invalid
+/// field is reported. On success `*out_id` receives the profile id to pass
+/// as `profile`; re-registering a name keeps its id. Built-in names
+/// (`Standard` … `Rag`) cannot be registered.
+///
+/// # Returns
+/// `Success`; `NullPointer`; `InvalidUtf8`; `InvalidArgument` for an empty
+/// or built-in name, invalid JSON, or when all 128 custom ids are taken.
+///
+/// # Safety
+/// - `name` and `profile_json` must be NUL-terminated UTF-8 C strings.
+/// - `out_id` must be a writeable `*mut u8`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_register_profile(
+    name: *const c_char,
+    profile_json: *const c_char,
+    out_id: *mut u8,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if name.is_null() || profile_json.is_null() || out_id.is_null() {
+            set_last_error("Null pointer provided to oxidize_register_profile");
+            return ErrorCode::NullPointer as c_int;
+        }
+        let name = match name_arg(name) {
+            Ok(n) => n,
+            Err(code) => return code,
+        };
+        if BUILTIN_NAMES.contains(&name) {
+            set_last_error(LastError::new(
+                ErrorCategory::InvalidArgument,
+                format!("\"{name}\" is a built-in profile and cannot be redefined"),
+            ));
+            return ErrorCode::InvalidArgument as c_int;
+        }
+        let dto: ProfileDto = match parse_config_arg(profile_json, "profile_json", "Profile") {
+            Ok(d) => d,
+            Err(code) => return code,
+        };
+        let profile = Arc::new(CustomProfile::from(dto));
+
+        let mut registry = REGISTRY.write().unwrap_or_else(|e| e.into_inner());
+        let index = match registry.iter().position(|(n, _)| n == name) {
+            Some(i) => {
+                registry[i].1 = profile;
+                i
+            }
+            None => {
+                let capacity = usize::from(u8::MAX - OXIDIZE_CUSTOM_PROFILE_BASE) + 1;
+                if registry.len() == capacity {
+                    set_last_error(LastError::new(
+                        ErrorCategory::InvalidArgument,
+                        format!("at most {capacity} custom profiles can be registered"),
+                    ));
+                    return ErrorCode::InvalidArgument as c_int;
+                }
+                registry.push((name.to_owned(), profile));
+                registry.len() - 1
+            }
+        };
+        // `index` < capacity, so the id fits in a u8.
+        *out_id = OXIDIZE_CUSTOM_PROFILE_BASE + index as u8;
+        ErrorCode::Success as c_int
+    })
+}
+
+/// Look up the id of a built-in (`Standard` … `Rag`) or registered custom
+/// profile by name.
+///
+/// # Returns
+/// `Success`; `NullPointer`; `InvalidUtf8`; `InvalidArgument` if no profile
+/// has that name.
+///
+/// # Safety
+/// - `name` must be a NUL-terminated UTF-8 C string.
+/// - `out_id` must be a writeable `*mut u8`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_profile_id(name: *const c_char, out_id: *mut u8) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if name.is_null() || out_id.is_null() {
+            set_last_error("Null pointer provided to oxidize_profile_id");
+            return ErrorCode::NullPointer as c_int;
+        }
+        let name = match name_arg(name) {
+            Ok(n) => n,
+            Err(code) => return code,
+        };
+        if let Some(i) = BUILTIN_NAMES.iter().position(|n| *n == name) {
+            *out_id = i as u8;
+            return ErrorCode::Success as c_int;
+        }
+        let registry = REGISTRY.read().unwrap_or_else(|e| e.into_inner());
+        match registry.iter().position(|(n, _)| n == name) {
+            Some(i) => {
+                *out_id = OXIDIZE_CUSTOM_PROFILE_BASE + i as u8;
+                ErrorCode::Success as c_int
+            }
+            None => {
+                set_last_error(LastError::new(
+                    ErrorCategory::InvalidArgument,
+                    format!("no profile named \"{name}\""),
+                ));
+                ErrorCode::InvalidArgument as c_int
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{oxidize_partition, oxidize_partition_with_profile};
+    use crate::parser::{oxidize_rag_chunks, oxidize_rag_chunks_with_profile};
+    use crate::test_fixtures::sample_pdf;
+    use std::ffi::CString;
+    use std::ptr;
+
+    fn register(name: &str, json: &str) -> Result<u8, c_int> {
+        let name = CString::new(name).unwrap();
+        let json = CString::new(json).unwrap();
+        let mut id = 0u8;
+        match unsafe { oxidize_register_profile(name.as_ptr(), json.as_ptr(), &mut id) } {
+            0 => Ok(id),
+            code => Err(code),
+        }
+    }
+
+    fn profile_id(name: &str) -> Result<u8, c_int> {
+        let name = CString::new(name).unwrap();
+        let mut id = 0u8;
+        match unsafe { oxidize_profile_id(name.as_ptr(), &mut id) } {
+            0 => Ok(id),
+            code => Err(code),
+        }
+    }
+
+    unsafe fn take(code: c_int, out: *mut c_char) -> String {
+        assert_eq!(code, ErrorCode::Success as c_int);
+        let s = CStr::from_ptr(out).to_str().unwrap().to_owned();
+        crate::oxidize_free_string(out);
+        s
+    }
+
+    #[test]
+    fn empty_custom_profile_matches_default_partition_and_chunks() {
+        let pdf = sample_pdf(1);
+        let id = register("test-empty", "{}").unwrap();
+        assert!(id >= OXIDIZE_CUSTOM_PROFILE_BASE);
+        assert_eq!(profile_id("test-empty"), Ok(id));
+        unsafe {
+            let mut a = ptr::null_mut();
+            let mut b = ptr::null_mut();
+            let plain = take(oxidize_partition(pdf.as_ptr(), pdf.len(), &mut a), a);
+            let custom = take(
+                oxidize_partition_with_profile(pdf.as_ptr(), pdf.len(), id, &mut b),
+                b,
+            );
+            assert_eq!(custom, plain);
+
+            let plain = take(oxidize_rag_chunks(pdf.as_ptr(), pdf.len(), &mut a), a);
+            let custom = take(
+                oxidize_rag_chunks_with_profile(pdf.as_ptr(), pdf.len(), id, &mut b),
+                b,
+            );
+            assert_eq!(custom, plain);
+        }
+    }
+
+    #[test]
+    fn custom_hybrid_budget_is_used_and_reregistering_keeps_the_id() {
+        let pdf = sample_pdf(1);
+        let chunk_count = |id: u8| unsafe {
+            let mut out = ptr::null_mut();
+            let json = take(
+                oxidize_rag_chunks_with_profile(pdf.as_ptr(), pdf.len(), id, &mut out),
+                out,
+            );
+            serde_json::from_str::<Vec<serde_json::Value>>(&json)
+                .unwrap()
+                .len()
+        };
+        let id = register("test-budget", r#"{"extraction":{"space_threshold":0.25}}"#).unwrap();
+        let whole = chunk_count(id);
+
+        let small = r#"{
+            "extraction": {"space_threshold": 0.25},
+            "hybrid": {"max_tokens": 4, "overlap_tokens": 0, "merge_adjacent": false,
+                       "propagate_headings": false, "merge_policy": "SameTypeOnly"}
+        }"#;
+        assert_eq!(register("test-budget", small), Ok(id));
+        assert!(chunk_count(id) > whole);
+    }
+
+    #[test]
+    fn semantic_section_configures_semantic_chunks_with_profile() {
+        use crate::parsed_document::{
+            oxidize_parsed_document_free, oxidize_parsed_document_open,
+            oxidize_parsed_document_semantic_chunks_with_profile,
+        };
+        use crate::parser::{oxidize_semantic_chunks, oxidize_semantic_chunks_with_profile};
+
+        let pdf = sample_pdf(2);
+        let semantic_chunks = |config: &str| unsafe {
+            let config = CString::new(config).unwrap();
+            let mut out = ptr::null_mut();
+            let code = oxidize_semantic_chunks(
+                pdf.as_ptr(),
+                pdf.len(),
+                ptr::null(),
+                config.as_ptr(),
+                &mut out,
+            );
+            take(code, out)
+        };
+        let with_profile = |id: u8| unsafe {
+            let mut out = ptr::null_mut();
+            let code = oxidize_semantic_chunks_with_profile(pdf.as_ptr(), pdf.len(), id, &mut out);
+            take(code, out)
+        };
+
+        let default_config =
+            r#"{"max_tokens": 512, "overlap_tokens": 50, "respect_element_boundaries": true}"#;
+        let id = register("test-semantic", "{}").unwrap();
+        assert_eq!(with_profile(id), semantic_chunks(default_config));
+
+        let small = r#"{"max_tokens": 8, "overlap_tokens": 0, "respect_element_boundaries": true}"#;
+        let id = register("test-semantic", &format!(r#"{{"semantic": {small}}}"#)).unwrap();
+        let chunks = with_profile(id);
+        assert_eq!(chunks, semantic_chunks(small));
+        assert_ne!(chunks, semantic_chunks(default_config));
+
+        unsafe {
+            let mut handle = ptr::null_mut();
+            assert_eq!(
+                oxidize_parsed_document_open(pdf.as_ptr(), pdf.len(), &mut handle),
+                ErrorCode::Success as c_int
+            );
+            let mut out = ptr::null_mut();
+            let code = oxidize_parsed_document_semantic_chunks_with_profile(handle, id, &mut out);
+            assert_eq!(take(code, out), chunks);
+            oxidize_parsed_document_free(handle);
+
+            let mut out = ptr::null_mut();
+            let code = oxidize_semantic_chunks_with_profile(pdf.as_ptr(), pdf.len(), 255, &mut out);
+            assert_eq!(code, ErrorCode::InvalidArgument as c_int);
+            assert!(out.is_null());
+        }
+        assert!(!with_profile(6).is_empty());
+
+        let code = register(
+            "test-semantic-invalid",
+            r#"{"semantic": {"max_tokens": 0, "overlap_tokens": 0}}"#,
+        );
+        assert_eq!(code, Err(ErrorCode::InvalidArgument as c_int));
+    }
+
+    #[test]
+    fn invalid_registrations_are_rejected() {
+        let invalid = ErrorCode::InvalidArgument as c_int;
+        assert_eq!(register("Rag", "{}"), Err(invalid));
+        assert_eq!(register("", "{}"), Err(invalid));
+        assert_eq!(profile_id("Rag"), Ok(6));
+        assert_eq!(profile_id("test-missing"), Err(invalid));
+
+        let code = register(
+            "test-invalid",
+            r#"{"partition":{"header_zone":2},"hybird":{}}"#,
+        );
+        assert_eq!(code, Err(invalid));
+        let mut err = ptr::null_mut();
+        let message = unsafe { take(crate::oxidize_get_last_error(&mut err), err) };
+        assert!(
+            message.contains("$.hybird is not a known field"),
+            "{message}"
+        );
+        assert!(message.contains("$.partition.header_zone must be a number in [0, 1]"));
+        assert!(message.contains("$.partition.detect_tables is required"));
+
+        let pdf = sample_pdf(1);
+        let mut out = ptr::null_mut();
+        let code =
+            unsafe { oxidize_partition_with_profile(pdf.as_ptr(), pdf.len(), 255, &mut out) };
+        assert_eq!(code, invalid);
+        assert!(out.is_null());
+    }
+}
@@ -0,0 +1,481 @@
+//! Progress reporting and cooperative cancellation for long-running calls.
+//!
+//! The `*_with_progress` entry points (text extraction, partitioning, RAG /
+//! semantic / text chunking, merge) take an optional [`ProgressCallbacks`]
+//! pointer. Work is done a page at a time; before each page the library
+//! polls `is_cancelled`, and after each page it calls
+//! `on_progress(completed, total)`. A cancelled call stops at the next page
+//! boundary, discards partial output and returns [`ErrorCode::Cancelled`].
+//!
+//! Both callbacks run synchronously on the calling thread. They must not
+//! call back into the library with the same document and must not unwind.
+
+use std::ffi::c_void;
+use std::io::{Read, Seek};
+use std::os::raw::c_int;
+
+use oxidize_pdf::parser::PdfDocument;
+use oxidize_pdf::pipeline::{Element, PartitionConfig};
+use oxidize_pdf::text::{ExtractedText, ExtractionOptions, TextExtractor};
+
+use crate::chunk_stream::PagePartitioner;
+use crate::error_detail::LastError;
+use crate::limits;
+use crate::{set_last_error, ErrorCode};
+
+/// Callbacks supplied by the caller of a `*_with_progress` entry point.
+///
+/// Every field may be null: a null `on_progress` disables reporting and a
+/// null `is_cancelled` means the call is never cancelled. `user_data` is
+/// passed through untouched.
+#[repr(C)]
+pub struct ProgressCallbacks {
+    /// Opaque caller state handed to both callbacks.
+    pub user_data: *mut c_void,
+    /// Called after each unit of work with `(user_data, completed, total)`.
+    pub on_progress: Option<unsafe extern "C" fn(*mut c_void, u32, u32)>,
+    /// Polled before each unit of work; non-zero cancels the call.
+    pub is_cancelled: Option<unsafe extern "C" fn(*mut c_void) -> u8>,
+}
+
+/// Borrowed view of the optional callbacks threaded through the `_impl` bodies.
+#[derive(Clone, Copy)]
+pub(crate) struct Progress<'a> {
+    callbacks: Option<&'a ProgressCallbacks>,
+}
+
+impl Progress<'_> {
+    /// No reporting, never cancelled — what the plain entry points pass.
+    pub(crate) const NONE: Progress<'static> = Progress { callbacks: None };
+
+    /// Wrap a caller-supplied pointer; null behaves like [`Progress::NONE`].
+    ///
+    /// # Safety
+    /// `callbacks` must be null or point to a `ProgressCallbacks` that
+    /// outlives the returned value.
+    pub(crate) unsafe fn from_ptr(callbacks: *const ProgressCallbacks) -> Self {
+        Progress {
+            callbacks: callbacks.as_ref(),
+        }
+    }
+
+    /// Whether the caller asked for progress or cancellation at all.
+    pub(crate) fn is_active(&self) -> bool {
+        self.callbacks.is_some()
+    }
+
+    /// Poll the cancel flag and the call's wall-clock budget. On cancellation
+    /// the last error is set and `Err(ErrorCode::Cancelled)` is returned; an
+    /// exhausted budget returns `Err(ErrorCode::LimitExceeded)`.
+    pub(crate) fn check(&self) -> Result<(), c_int> {
+        if let Err(e) = limits::check_deadline() {
+            set_last_error(e);
+            return Err(ErrorCode::LimitExceeded as c_int);
+        }
+        let cancelled = self.callbacks.is_some_and(|cb| match cb.is_cancelled {
+            Some(is_cancelled) => unsafe { is_cancelled(cb.user_data) != 0 },
+            None => false,
+        });
+        if cancelled {
+            set_last_error("Operation cancelled by caller");
+            return Err(ErrorCode::Cancelled as c_int);
+        }
+        Ok(())
+    }
+
+    /// Report `completed` of `total` units done.
+    pub(crate) fn report(&self, completed: u32, total: u32) {
+        if let Some(cb) = self.callbacks {
+            if let Some(on_progress) = cb.on_progress {
+                unsafe { on_progress(cb.user_data, completed, total) };
+            }
+        }
+    }
+}
+
+fn page_count<R: Read + Seek>(document: &PdfDocument<R>) -> Result<u32, c_int> {
+    document.page_count().map_err(|e| {
+        set_last_error(LastError::caused_by("Failed to get page count", &e));
+        ErrorCode::PdfParseError as c_int
+    })
+}
+
+/// Extract every page's text, one page at a time. Matches
+/// `PdfDocument::extract_text_with_options` (one extractor, so font caches
+/// are shared across pages).
+pub(crate) fn extract_pages<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    options: ExtractionOptions,
+    progress: Progress,
+) -> Result<Vec<ExtractedText>, c_int> {
+    let total = page_count(document)?;
+    let mut extractor = TextExtractor::with_options(options);
+    let mut pages = Vec::with_capacity(total as usize);
+    for page_index in 0..total {
+        progress.check()?;
+        let page = extractor
+            .extract_from_page(document, page_index)
+            .map_err(|e| {
+                set_last_error(
+                    LastError::caused_by("Failed to extract text from PDF", &e).at_page(page_index),
+                );
+                ErrorCode::PdfParseError as c_int
+            })?;
+        pages.push(page);
+        progress.report(page_index + 1, total);
+    }
+    progress.check()?;
+    Ok(pages)
+}
+
+/// Partition the document into elements.
+///
+/// Without callbacks or a wall-clock budget this is
+/// `PdfDocument::partition_with`. Otherwise the pages are partitioned one at
+/// a time so progress can be reported and cancellation and the budget
+/// honoured. The output is the same either way: from the first page with a
+/// ruled table grid on, [`PagePartitioner`] takes the elements from one
+/// `partition_with` call, which cannot be interrupted midway.
+pub(crate) fn partition_pages<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    config: PartitionConfig,
+    progress: Progress,
+) -> Result<Vec<Element>, c_int> {
+    if !progress.is_active() && !limits::has_deadline() {
+        return document.partition_with(config).map_err(|e| {
+            set_last_error(LastError::caused_by("Failed to partition PDF", &e));
+            ErrorCode::PdfParseError as c_int
+        });
+    }
+
+    partition_pages_with(document, &mut PagePartitioner::new(config), progress)
+}
+
+/// Partition every page with `partitioner`, one page at a time.
+pub(crate) fn partition_pages_with<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    partitioner: &mut PagePartitioner,
+    progress: Progress,
+) -> Result<Vec<Element>, c_int> {
+    let total = page_count(document)?;
+    let mut elements = Vec::new();
+    for page_index in 0..total {
+        progress.check()?;
+        let page =
+            partitioner
+                .partition_page(document, page_index)
+                .map_err(|(code, message)| {
+                    set_last_error(message);
+                    code as c_int
+                })?;
+        elements.extend(page);
+        progress.report(page_index + 1, total);
+    }
+    progress.check()?;
+    Ok(elements)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{sample_pdf, SamplePdf};
+    use std::ffi::{CStr, CString};
+    use std::os::raw::c_char;
+    use std::ptr;
+
+    /// Records every progress tick; cancels once `cancel_after` ticks were seen.
+    #[derive(Default)]
+    struct Recorder {
+        ticks: Vec<(u32, u32)>,
+        cancel_after: Option<usize>,
+    }
+
+    unsafe extern "C" fn on_progress(user_data: *mut c_void, completed: u32, total: u32) {
+        (*(user_data as *mut Recorder))
+            .ticks
+            .push((completed, total));
+    }
+
+    unsafe extern "C" fn is_cancelled(user_data: *mut c_void) -> u8 {
+        let recorder = &*(user_data as *mut Recorder);
+        recorder
+            .cancel_after
+            .is_some_and(|n| recorder.ticks.len() >= n) as u8
+    }
+
+    fn callbacks(recorder: &mut Recorder) -> ProgressCallbacks {
+        ProgressCallbacks {
+            user_data: recorder as *mut Recorder as *mut c_void,
+            on_progress: Some(on_progress),
+            is_cancelled: Some(is_cancelled),
+        }
+    }
+
+    unsafe fn take_string(out: *mut c_char) -> String {
+        let s = CStr::from_ptr(out).to_str().unwrap().to_string();
+        crate::oxidize_free_string(out);
+        s
+    }
+
+    #[test]
+    fn extract_text_reports_every_page_and_matches_plain_call() {
+        let pdf = sample_pdf(3);
+        let mut recorder = Recorder::default();
+        let cb = callbacks(&mut recorder);
+        unsafe {
+            let mut plain: *mut c_char = ptr::null_mut();
+            assert_eq!(
+                crate::parser::oxidize_extract_text(pdf.as_ptr(), pdf.len(), &mut plain),
+                ErrorCode::Success as c_int
+            );
+            let mut out: *mut c_char = ptr::null_mut();
+            assert_eq!(
+                crate::parser::oxidize_extract_text_with_progress(
+                    pdf.as_ptr(),
+                    pdf.len(),
+                    &cb,
+                    &mut out
+                ),
+                ErrorCode::Success as c_int
+            );
+            assert_eq!(take_string(out), take_string(plain));
+        }
+        assert_eq!(recorder.ticks, vec![(1, 3), (2, 3), (3, 3)]);
+    }
+
+    #[test]
+    fn cancellation_between_pages_returns_cancelled_and_no_output() {
+        let pdf = sample_pdf(4);
+        let mut recorder = Recorder {
+            cancel_after: Some(2),
+            ..Default::default()
+        };
+        let cb = callbacks(&mut recorder);
+        unsafe {
+            let mut out: *mut c_char = ptr::null_mut();
+            assert_eq!(
+                crate::parser::oxidize_rag_chunks_with_progress(
+                    pdf.as_ptr(),
+                    pdf.len(),
+                    ptr::null(),
+                    ptr::null(),
+                    &cb,
+                    &mut out
+                ),
+                ErrorCode::Cancelled as c_int
+            );
+            assert!(out.is_null());
+
+            let mut err: *mut c_char = ptr::null_mut();
+            crate::oxidize_get_last_error(&mut err);
+            assert!(take_string(err).contains("cancelled"));
+        }
+        assert_eq!(recorder.ticks, vec![(1, 4), (2, 4)]);
+    }
+
+    #[test]
+    fn null_callbacks_behave_like_plain_entry_points() {
+        let pdf = sample_pdf(2);
+        let sem = CString::new(
+            r#"{"max_tokens":64,"overlap_tokens":0,"respect_element_boundaries":true}"#,
+        )
+        .unwrap();
+        let empty = ProgressCallbacks {
+            user_data: ptr::null_mut(),
+            on_progress: None,
+            is_cancelled: None,
+        };
+        unsafe {
+            let mut plain: *mut c_char = ptr::null_mut();
+            assert_eq!(
+                crate::parser::oxidize_semantic_chunks(
+                    pdf.as_ptr(),
+                    pdf.len(),
+                    ptr::null(),
+                    sem.as_ptr(),
+                    &mut plain
+                ),
+                ErrorCode::Success as c_int
+            );
+            let plain = take_string(plain);
+            for progress in [ptr::null(), &empty as *const ProgressCallbacks] {
+                let mut out: *mut c_char = ptr::null_mut();
+                assert_eq!(
+                    crate::parser::oxidize_semantic_chunks_with_progress(
+                        pdf.as_ptr(),
+                        pdf.len(),
+                        ptr::null(),
+                        sem.as_ptr(),
+                        progress,
+                        &mut out
+                    ),
+                    ErrorCode::Success as c_int
+                );
+                assert_eq!(take_string(out), plain);
+            }
+        }
+    }
+
+    /// `(partition, rag chunks)` of `pdf` from the plain entry points.
+    unsafe fn plain_partition_and_chunks(pdf: &[u8]) -> (String, String) {
+        let mut out: *mut c_char = ptr::null_mut();
+        assert_eq!(
+            crate::parser::oxidize_partition(pdf.as_ptr(), pdf.len(), &mut out),
+            ErrorCode::Success as c_int
+        );
+        let elements = take_string(out);
+        assert_eq!(
+            crate::parser::oxidize_rag_chunks(pdf.as_ptr(), pdf.len(), &mut out),
+            ErrorCode::Success as c_int
+        );
+        (elements, take_string(out))
+    }
+
+    #[test]
+    fn ruled_tables_partition_the_same_under_a_budget() {
+        let pdf = SamplePdf::new(3).ruled_table_on(1).build();
+
+        // The fixture exercises upstream's cell pass: partitioning its grid
+        // page from paragraph-level fragments gives different elements.
+        let document = PdfDocument::new(crate::parser::open_lenient(&pdf[..]).unwrap());
+        let whole = document.partition_with(PartitionConfig::default()).unwrap();
+        let mut per_page = PagePartitioner::new(PartitionConfig::default()).without_fallback();
+        let approximated: Vec<Element> = (0..3)
+            .flat_map(|i| per_page.partition_page(&document, i).ok().unwrap())
+            .collect();
+        assert!(per_page.saw_table_grid);
+        assert_ne!(format!("{approximated:?}"), format!("{whole:?}"));
+
+        unsafe {
+            let plain = plain_partition_and_chunks(&pdf);
+            let budget = std::time::Instant::now() + std::time::Duration::from_secs(60);
+            let _deadline = limits::Deadline::inherit(Some((budget, 60_000)));
+            assert_eq!(plain_partition_and_chunks(&pdf), plain);
+        }
+    }
+
+    #[test]
+    fn ruled_tables_partition_the_same_with_progress() {
+        let pdf = SamplePdf::new(3).ruled_table_on(1).build();
+        let mut recorder = Recorder::default();
+        let cb = callbacks(&mut recorder);
+        unsafe {
+            let (elements, chunks) = plain_partition_and_chunks(&pdf);
+            let mut out: *mut c_char = ptr::null_mut();
+            assert_eq!(
+                crate::parser::oxidize_partition_with_progress(
+                    pdf.as_ptr(),
+                    pdf.len(),
+                    ptr::null(),
+                    &cb,
+                    &mut out
+                ),
+                ErrorCode::Success as c_int
+            );
+            assert_eq!(take_string(out), elements);
+            assert_eq!(
+                crate::parser::oxidize_rag_chunks_with_progress(
+                    pdf.as_ptr(),
+                    pdf.len(),
+                    ptr::null(),
+                    ptr::null(),
+                    &cb,
+                    &mut out
+                ),
+                ErrorCode::Success as c_int
+            );
+            assert_eq!(take_string(out), chunks);
+        }
+        // Pages after the grid come from the whole-document pass but are
+        // still reported one by one.
+        assert_eq!(
+            recorder.ticks,
+            vec![(1, 3), (2, 3), (3, 3), (1, 3), (2, 3), (3, 3)]
+        );
+    }
+
+    #[test]
+    fn partition_and_chunk_pdf_report_pages() {
+        let pdf = sample_pdf(2);
+        unsafe {
+            let mut recorder = Recorder::default();
+            let cb = callbacks(&mut recorder);
+            let mut out: *mut c_char = ptr::null_mut();
+            assert_eq!(
+                crate::parser::oxidize_partition_with_progress(
+                    pdf.as_ptr(),
+                    pdf.len(),
+                    ptr::null(),
+                    &cb,
+                    &mut out
+                ),
+                ErrorCode::Success as c_int
+            );
+            assert!(take_string(out).contains("Page 2 line 1"));
+            assert_eq!(recorder.ticks, vec![(1, 2), (2, 2)]);
+
+            let mut recorder = Recorder::default();
+            let cb = callbacks(&mut recorder);
+            assert_eq!(
+                crate::chunking::oxidize_chunk_pdf_with_progress(
+                    pdf.as_ptr(),
+                    pdf.len(),
+                    100,
+                    0,
+                    0,
+                    &cb,
+                    &mut out
+                ),
+                ErrorCode::Success as c_int
+            );
+            crate::oxidize_free_string(out);
+            assert_eq!(recorder.ticks, vec![(1, 2), (2, 2)]);
+        }
+    }
+
+    #[test]
+    fn merge_counts_pages_across_inputs_and_can_be_cancelled() {
+        use base64::Engine as _;
+        let encode = |pages| base64::engine::general_purpose::STANDARD.encode(sample_pdf(pages));
+        let inputs = CString::new(format!(
+            r#"[{{"pdf":"{}","pages":null}},{{"pdf":"{}","pages":null}}]"#,
+            encode(2),
+            encode(1)
+        ))
+        .unwrap();
+        unsafe {
+            let mut recorder = Recorder::default();
+            let cb = callbacks(&mut recorder);
+            let mut bytes: *mut u8 = ptr::null_mut();
+            let mut len = 0usize;
+            assert_eq!(
+                crate::operations::oxidize_merge_pdfs_with_progress(
+                    inputs.as_ptr(),
+                    &cb,
+                    &mut bytes,
+                    &mut len
+                ),
+                ErrorCode::Success as c_int
+            );
+            crate::oxidize_free_bytes(bytes, len);
+            assert_eq!(recorder.ticks, vec![(1, 3), (2, 3), (3, 3)]);
+
+            let mut recorder = Recorder {
+                cancel_after: Some(1),
+                ..Default::default()
+            };
+            let cb = callbacks(&mut recorder);
+            assert_eq!(
+                crate::operations::oxidize_merge_pdfs_with_progress(
+                    inputs.as_ptr(),
+                    &cb,
+                    &mut bytes,
+                    &mut len
+                ),
+                ErrorCode::Cancelled as c_int
+            );
+            assert!(bytes.is_null());
+            assert_eq!(len, 0);
+        }
+    }
+}
@@ -0,0 +1,700 @@
+//! Report of the repairs lenient parsing performed on a PDF.
+//!
+//! `open_lenient` recovers from broken cross-reference tables, bad stream
+//! lengths and undecodable streams without telling the caller. The core
+//! library does report each recovery as a `tracing` event when
+//! `collect_warnings` is on. [`oxidize_parse_recovery_report`] parses the
+//! document that way, loads every page with its content streams and fonts
+//! as extraction does, and captures those events for the duration of the
+//! call (they are still forwarded to the log callback). Together with the
+//! objects that could not be recovered at all they become the issues:
+//!
+//! - `xref_rebuilt` — the cross-reference table was reconstructed by
+//!   scanning the file;
+//! - `structure_repaired` — the reader worked around a syntax or structure
+//!   error (missing keywords, trailer or catalog entries, generation
+//!   mismatches);
+//! - `unreadable_page` — a page or page-tree node could not be loaded and is
+//!   skipped;
+//! - `skipped_object` — a referenced object is missing and was read as null,
+//!   or a content stream could not be loaded;
+//! - `repaired_stream` — a stream was read despite a wrong or missing
+//!   `/Length`, or decoded only through a recovery strategy;
+//! - `broken_stream` — a content stream does not decode at all;
+//! - `bad_font` — a font dictionary lacks required entries or its embedded
+//!   program / `ToUnicode` CMap does not decode.
+//!
+//! Core events count as repairs at warning level and above, and at debug
+//! level when the message starts with `Warning` (the core library's marker
+//! for recovered input).
+//!
+//! [`oxidize_set_strict_parsing`] makes every other entry point parse
+//! strictly: damaged files then fail with the core parser's error instead of
+//! being repaired.
+
+use std::collections::HashSet;
+use std::ffi::CString;
+use std::io::Cursor;
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+use std::slice;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+
+use oxidize_pdf::parser::{ParseOptions, ParsedPage, PdfDictionary, PdfDocument, PdfObject};
+use serde::Serialize;
+use tracing::span::{Attributes, Id, Record};
+use tracing::subscriber::{Interest, Subscriber};
+use tracing::{Event, Level, Metadata};
+
+use crate::error_detail::{ErrorCategory, LastError};
+use crate::parser::open_reader_with;
+use crate::{clear_last_error, set_last_error, ErrorCode};
+
+/// Whether every entry point parses strictly.
+static STRICT: AtomicBool = AtomicBool::new(false);
+
+/// `options`, or strict options while strict parsing is on.
+pub(crate) fn parse_options(options: ParseOptions) -> ParseOptions {
+    if STRICT.load(Ordering::Relaxed) {
+        ParseOptions::strict()
+    } else {
+        options
+    }
+}
+
+/// Kind of repair recorded in a [`RecoveryIssue`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecoveryKind {
+    XrefRebuilt,
+    StructureRepaired,
+    UnreadablePage,
+    SkippedObject,
+    RepairedStream,
+    BrokenStream,
+    BadFont,
+}
+
+impl RecoveryKind {
+    fn category(self) -> ErrorCategory {
+        match self {
+            RecoveryKind::XrefRebuilt => ErrorCategory::CorruptXref,
+            RecoveryKind::RepairedStream | RecoveryKind::BrokenStream => {
+                ErrorCategory::StreamDecode
+            }
+            RecoveryKind::SkippedObject => ErrorCategory::InvalidReference,
+            RecoveryKind::BadFont => ErrorCategory::Encoding,
+            RecoveryKind::StructureRepaired | RecoveryKind::UnreadablePage => ErrorCategory::Syntax,
+        }
+    }
+
+    /// Kind of a core recovery event, from the emitting module and message.
+    fn of_event(target: &str, message: &str) -> Self {
+        let module = target.strip_prefix("oxidize_pdf::").unwrap_or(target);
+        if module.starts_with("parser::xref") {
+            RecoveryKind::XrefRebuilt
+        } else if module.starts_with("parser::page_tree") {
+            RecoveryKind::UnreadablePage
+        } else if message.contains("not found in XRef") {
+            RecoveryKind::SkippedObject
+        } else if module.starts_with("parser::objects")
+            || module.starts_with("parser::filter")
+            || message.contains("stream")
+        {
+            RecoveryKind::RepairedStream
+        } else if module.contains("font") {
+            RecoveryKind::BadFont
+        } else {
+            RecoveryKind::StructureRepaired
+        }
+    }
+}
+
+/// One repair performed by lenient parsing.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct RecoveryIssue {
+    pub kind: RecoveryKind,
+    pub message: String,
+    pub page_index: Option<u32>,
+    pub object_number: Option<u32>,
+    pub generation: Option<u16>,
+}
+
+/// JSON document returned by [`oxidize_parse_recovery_report`].
+#[derive(Debug, Serialize)]
+pub struct RecoveryReport {
+    /// `true` when at least one issue was recorded.
+    pub repaired: bool,
+    pub page_count: u32,
+    pub issues: Vec<RecoveryIssue>,
+}
+
+#[derive(Default)]
+struct Collector {
+    /// Page being loaded, attributed to the core events it triggers.
+    page_index: Option<u32>,
+    issues: Vec<RecoveryIssue>,
+}
+
+impl Collector {
+    fn push(
+        &mut self,
+        kind: RecoveryKind,
+        message: String,
+        page_index: Option<u32>,
+        object: Option<(u32, u16)>,
+    ) {
+        let issue = RecoveryIssue {
+            kind,
+            message,
+            page_index,
+            object_number: object.map(|(n, _)| n),
+            generation: object.map(|(_, g)| g),
+        };
+        if !self.issues.contains(&issue) {
+            self.issues.push(issue);
+        }
+    }
+}
+
+/// Collects the core library's recovery events on the thread it is the
+/// default subscriber of, and forwards every event to the log callback.
+#[derive(Default)]
+struct RecoveryLog(Mutex<Collector>);
+
+impl RecoveryLog {
+    fn collector(&self) -> std::sync::MutexGuard<'_, Collector> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+fn is_core(metadata: &Metadata<'_>) -> bool {
+    metadata.target().starts_with("oxidize_pdf::")
+}
+
+impl Subscriber for RecoveryLog {
+    fn register_callsite(&self, _: &'static Metadata<'static>) -> Interest {
+        Interest::sometimes()
+    }
+
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.is_event()
+            && ((is_core(metadata) && *metadata.level() <= Level::DEBUG)
+                || crate::logging::wants(metadata.level()))
+    }
+
+    fn new_span(&self, _: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        crate::logging::forward(event);
+        let metadata = event.metadata();
+        if !is_core(metadata) {
+            return;
+        }
+        let message = crate::logging::event_text(event);
+        let message = match message.strip_prefix("Warning") {
+            Some(rest) => rest.trim_start_matches(':').trim().to_owned(),
+            None if *metadata.level() <= Level::WARN => message,
+            None => return,
+        };
+        let kind = RecoveryKind::of_event(metadata.target(), &message);
+        let mut collector = self.collector();
+        let page_index = collector.page_index;
+        collector.push(kind, message, page_index, None);
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+/// Build the recovery report for `bytes`.
+///
+/// Fails only when the document cannot be opened even leniently.
+pub(crate) fn build_report(bytes: &[u8]) -> Result<RecoveryReport, LastError> {
+    let log = Arc::new(RecoveryLog::default());
+    let page_count = tracing::subscriber::with_default(log.clone(), || load_pages(bytes, &log))?;
+    let issues = std::mem::take(&mut log.collector().issues);
+    Ok(RecoveryReport {
+        repaired: !issues.is_empty(),
+        page_count,
+        issues,
+    })
+}
+
+/// Open `bytes` leniently and load every page, its content streams and
+/// fonts, recording what could not be recovered into `log`.
+fn load_pages(bytes: &[u8], log: &RecoveryLog) -> Result<u32, LastError> {
+    let options = ParseOptions {
+        collect_warnings: true,
+        ..ParseOptions::lenient()
+    };
+    let document = PdfDocument::new(open_reader_with(Cursor::new(bytes), options)?);
+    let page_count = document
+        .page_count()
+        .map_err(|e| LastError::caused_by("Failed to get page count", &e))?;
+
+    let mut seen_fonts = HashSet::new();
+    for page_index in 0..page_count {
+        crate::limits::check_deadline()?;
+        log.collector().page_index = Some(page_index);
+        let page = match document.get_page(page_index) {
+            Ok(p) => p,
+            Err(e) => {
+                log.collector().push(
+                    RecoveryKind::UnreadablePage,
+                    format!("Page could not be loaded: {e}"),
+                    Some(page_index),
+                    None,
+                );
+                continue;
+            }
+        };
+        check_contents(&document, &page, page_index, log);
+        check_fonts(&document, &page, page_index, &mut seen_fonts, log);
+    }
+    Ok(page_count)
+}
+
+fn check_contents(
+    document: &PdfDocument<Cursor<&[u8]>>,
+    page: &ParsedPage,
+    page_index: u32,
+    log: &RecoveryLog,
+) {
+    let refs: Vec<(u32, u16)> = match page.dict.get("Contents") {
+        Some(PdfObject::Reference(n, g)) => vec![(*n, *g)],
+        Some(PdfObject::Array(array)) => array.0.iter().filter_map(|o| o.as_reference()).collect(),
+        _ => Vec::new(),
+    };
+    let options = document.options();
+
+    for object in refs {
+        let problem = match document.get_object(object.0, object.1) {
+            Ok(PdfObject::Stream(stream)) => match stream.decode(&options) {
+                Ok(_) => continue,
+                Err(e) => (
+                    RecoveryKind::BrokenStream,
+                    format!("Content stream does not decode: {e}"),
+                ),
+            },
+            Ok(_) => (
+                RecoveryKind::SkippedObject,
+                "Content stream reference does not resolve to a stream".to_string(),
+            ),
+            Err(e) => (
+                RecoveryKind::SkippedObject,
+                format!("Content stream could not be loaded: {e}"),
+            ),
+        };
+        log.collector()
+            .push(problem.0, problem.1, Some(page_index), Some(object));
+    }
+}
+
+fn check_fonts(
+    document: &PdfDocument<Cursor<&[u8]>>,
+    page: &ParsedPage,
+    page_index: u32,
+    seen: &mut HashSet<(u32, u16)>,
+    log: &RecoveryLog,
+) {
+    let Some(fonts) = page
+        .get_resources()
+        .and_then(|r| r.get("Font"))
+        .and_then(|f| document.resolve(f).ok())
+        .and_then(|f| f.as_dict().cloned())
+    else {
+        return;
+    };
+
+    for (name, entry) in &fonts.0 {
+        let object = entry.as_reference();
+        if let Some(object) = object {
+            if !seen.insert(object) {
+                continue;
+            }
+        }
+        let font = match document.resolve(entry) {
+            Ok(PdfObject::Dictionary(d)) => d,
+            Ok(_) | Err(_) => {
+                log.collector().push(
+                    RecoveryKind::BadFont,
+                    format!("Font /{} does not resolve to a dictionary", name.0),
+                    Some(page_index),
+                    object,
+                );
+                continue;
+            }
+        };
+        if let Some(problem) = font_problem(document, &font) {
+            log.collector().push(
+                RecoveryKind::BadFont,
+                format!("Font /{}: {problem}", name.0),
+                Some(page_index),
+                object,
+            );
+        }
+    }
+}
+
+/// First defect found in `font`, if any.
+fn font_problem(document: &PdfDocument<Cursor<&[u8]>>, font: &PdfDictionary) -> Option<String> {
+    let subtype = match font.get("Subtype").and_then(PdfObject::as_name) {
+        Some(s) => s.0.clone(),
+        None => return Some("missing /Subtype".to_string()),
+    };
+    if subtype == "Type0" && font.get("DescendantFonts").is_none() {
+        return Some("Type0 font without /DescendantFonts".to_string());
+    }
+    if subtype != "Type3" && font.get("BaseFont").is_none() {
+        return Some("missing /BaseFont".to_string());
+    }
+
+    let options = document.options();
+    let decodes = |obj: &PdfObject| match document.resolve(obj) {
+        Ok(PdfObject::Stream(s)) => s.decode(&options).err().map(|e| e.to_string()),
+        Ok(_) => Some("not a stream".to_string()),
+        Err(e) => Some(e.to_string()),
+    };
+
+    if let Some(cmap) = font.get("ToUnicode") {
+        if let Some(e) = decodes(cmap) {
+            return Some(format!("/ToUnicode does not decode: {e}"));
+        }
+    }
+    let descriptor = font
+        .get("FontDescriptor")
+        .and_then(|d| document.resolve(d).ok())
+        .and_then(|d| d.as_dict().cloned());
+    if let Some(descriptor) = descriptor {
+        for key in ["FontFile", "FontFile2", "FontFile3"] {
+            if let Some(program) = descriptor.get(key) {
+                if let Some(e) = decodes(program) {
+                    return Some(format!("embedded /{key} does not decode: {e}"));
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Parse a PDF and report every repair lenient parsing performed.
+///
+/// Writes a JSON object `{"repaired", "page_count", "issues"}`; each issue has `kind` (`xref_rebuilt`, `structure_repaired`,
+/// `unreadable_page`, `skipped_object`, `repaired_stream`, `broken_stream`,
+/// `bad_font`), `message`, and `page_index` / `object_number` /
+/// `generation` when known.
+///
+/// With `strict` non-zero, or while strict parsing is on (see
+/// [`oxidize_set_strict_parsing`]), the call fails instead of reporting
+/// repairs: the
+/// first issue becomes the last error (category `corrupt_xref`,
+/// `stream_decode`, `invalid_reference`, `encoding` or `syntax`) and
+/// `PdfParseError` is returned.
+///
+/// # Safety
+/// - `pdf_bytes` must point to `pdf_len` readable bytes.
+/// - `out_json` must be a valid pointer to a mutable pointer location.
+/// - The returned string must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_parse_recovery_report(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    strict: u8,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+
+        if pdf_bytes.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_parse_recovery_report");
+            return ErrorCode::NullPointer as c_int;
+        }
+
+        *out_json = ptr::null_mut();
+
+        if pdf_len == 0 {
+            set_last_error("PDF data is empty (0 bytes)");
+            return ErrorCode::PdfParseError as c_int;
+        }
+
+        let bytes = slice::from_raw_parts(pdf_bytes, pdf_len);
+        let report = match build_report(bytes) {
+            Ok(r) => r,
+            Err(e) => {
+                set_last_error(e);
+                return ErrorCode::PdfParseError as c_int;
+            }
+        };
+
+        if strict != 0 || STRICT.load(Ordering::Relaxed) {
+            if let Some(first) = report.issues.first() {
+                let mut err = LastError::new(
+                    first.kind.category(),
+                    format!(
+                        "Strict parsing failed ({} repair(s) needed): {}",
+                        report.issues.len(),
+                        first.message
+                    ),
+                );
+                if let Some(page_index) = first.page_index {
+                    err = err.at_page(page_index);
+                }
+                if let (Some(n), Some(g)) = (first.object_number, first.generation) {
+                    err = err.at_object(n, g);
+                }
+                set_last_error(err);
+                return ErrorCode::PdfParseError as c_int;
+            }
+        }
+
+        let json = match serde_json::to_string(&report) {
+            Ok(j) => j,
+            Err(e) => {
+                set_last_error(LastError::caused_by("Failed to serialize report", &e));
+                return ErrorCode::SerializationError as c_int;
+            }
+        };
+        match CString::new(json) {
+            Ok(c_string) => {
+                *out_json = c_string.into_raw();
+                ErrorCode::Success as c_int
+            }
+            Err(e) => {
+                set_last_error(LastError::caused_by("JSON contains null bytes", &e));
+                ErrorCode::InvalidUtf8 as c_int
+            }
+        }
+    })
+}
+
+/// Turn strict parsing on (`strict` non-zero) or off for the whole process.
+///
+/// While it is on, every entry point that opens a document parses it with
+/// the core library's strict options: files that lenient parsing would
+/// repair fail with `PdfParseError` instead, and
+/// [`oxidize_parse_recovery_report`] fails on the first repair. Off by
+/// default.
+#[no_mangle]
+pub extern "C" fn oxidize_set_strict_parsing(strict: u8) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        STRICT.store(strict != 0, Ordering::Relaxed);
+        ErrorCode::Success as c_int
+    })
+}
+
+/// Get whether strict parsing is on.
+///
+/// # Safety
+/// - `out_strict` must be a valid pointer to a `bool`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_get_strict_parsing(out_strict: *mut bool) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+
+        if out_strict.is_null() {
+            set_last_error("Null pointer provided to oxidize_get_strict_parsing");
+            return ErrorCode::NullPointer as c_int;
+        }
+
+        *out_strict = STRICT.load(Ordering::Relaxed);
+        ErrorCode::Success as c_int
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{raw_pdf, sample_pdf, strict_parsing_lock};
+    use std::ffi::CStr;
+
+    /// Point `startxref` past the end of the file, forcing xref reconstruction.
+    fn break_startxref(bytes: &[u8]) -> Vec<u8> {
+        let pos = bytes
+            .windows(9)
+            .rposition(|w| w == b"startxref")
+            .expect("startxref present");
+        let mut out = bytes[..pos].to_vec();
+        out.extend_from_slice(b"startxref\n999999999\n%%EOF\n");
+        out
+    }
+
+    unsafe fn report_json(bytes: &[u8], strict: u8) -> (c_int, Option<serde_json::Value>) {
+        let mut out: *mut c_char = ptr::null_mut();
+        let rc = oxidize_parse_recovery_report(bytes.as_ptr(), bytes.len(), strict, &mut out);
+        if out.is_null() {
+            return (rc, None);
+        }
+        let json = serde_json::from_str(CStr::from_ptr(out).to_str().unwrap()).unwrap();
+        crate::oxidize_free_string(out);
+        (rc, Some(json))
+    }
+
+    #[test]
+    fn clean_file_reports_no_repairs() {
+        let bytes = sample_pdf(2);
+        let (rc, json) = unsafe { report_json(&bytes, 1) };
+        assert_eq!(rc, ErrorCode::Success as c_int);
+        let json = json.unwrap();
+        assert_eq!(json["repaired"], false);
+        assert_eq!(json["page_count"], 2);
+        assert_eq!(json["issues"].as_array().unwrap().len(), 0);
+    }
+
+    #[test]
+    fn rebuilt_xref_is_reported_and_fails_strict_mode() {
+        let _lenient = strict_parsing_lock();
+        let bytes = break_startxref(&sample_pdf(2));
+        let (rc, json) = unsafe { report_json(&bytes, 0) };
+        assert_eq!(rc, ErrorCode::Success as c_int);
+        let json = json.unwrap();
+        assert_eq!(json["repaired"], true);
+        assert_eq!(json["page_count"], 2);
+        let issues = json["issues"].as_array().unwrap();
+        assert!(issues.iter().all(|i| i["page_index"].is_null()));
+        assert_eq!(issues[0]["kind"], "xref_rebuilt");
+        assert!(issues[0]["message"]
+            .as_str()
+            .unwrap()
+            .contains("XRef parsing failed"));
+
+        let (rc, json) = unsafe { report_json(&bytes, 1) };
+        assert_eq!(rc, ErrorCode::PdfParseError as c_int);
+        assert!(json.is_none());
+        let mut err: *mut c_char = ptr::null_mut();
+        unsafe { crate::oxidize_get_last_error_json(&mut err) };
+        let detail: serde_json::Value =
+            serde_json::from_str(unsafe { CStr::from_ptr(err) }.to_str().unwrap()).unwrap();
+        unsafe { crate::oxidize_free_string(err) };
+        assert_eq!(detail["category"], "corrupt_xref");
+    }
+
+    #[test]
+    fn bad_font_is_reported() {
+        let mut font = PdfDictionary::new();
+        font.insert(
+            "Type".to_string(),
+            PdfObject::Name(oxidize_pdf::parser::PdfName("Font".into())),
+        );
+        let bytes = sample_pdf(2);
+        let document = PdfDocument::new(crate::parser::open_lenient(&bytes[..]).unwrap());
+        assert_eq!(
+            font_problem(&document, &font).as_deref(),
+            Some("missing /Subtype")
+        );
+        font.insert(
+            "Subtype".to_string(),
+            PdfObject::Name(oxidize_pdf::parser::PdfName("Type1".into())),
+        );
+        assert_eq!(
+            font_problem(&document, &font).as_deref(),
+            Some("missing /BaseFont")
+        );
+    }
+
+    #[test]
+    fn bad_font_is_reported_through_the_ffi() {
+        let content = b"BT /F1 12 Tf 72 720 Td (Hi) Tj ET";
+        let mut stream = format!("<< /Length {} >>\nstream\n", content.len()).into_bytes();
+        stream.extend_from_slice(content);
+        stream.extend_from_slice(b"\nendstream");
+        let bytes = raw_pdf(&[
+            b"<< /Type /Catalog /Pages 2 0 R >>".to_vec(),
+            b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_vec(),
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] \
+              /Resources << /Font << /F1 4 0 R >> >> /Contents 5 0 R >>"
+                .to_vec(),
+            b"<< /Type /Font /BaseFont /Helvetica >>".to_vec(),
+            stream,
+        ]);
+
+        let (rc, json) = unsafe { report_json(&bytes, 0) };
+        assert_eq!(rc, ErrorCode::Success as c_int);
+        let json = json.unwrap();
+        assert_eq!(json["repaired"], true);
+        let issue = &json["issues"][0];
+        assert_eq!(issue["kind"], "bad_font");
+        assert_eq!(issue["message"], "Font /F1: missing /Subtype");
+        assert_eq!(issue["page_index"], 0);
+        assert_eq!(issue["object_number"], 4);
+
+        let (rc, _) = unsafe { report_json(&bytes, 1) };
+        assert_eq!(rc, ErrorCode::PdfParseError as c_int);
+    }
+
+    #[test]
+    fn strict_parsing_makes_other_entry_points_fail() {
+        let _strict = strict_parsing_lock();
+        let bytes = break_startxref(&sample_pdf(2));
+        let extract = |bytes: &[u8]| unsafe {
+            let mut text: *mut c_char = ptr::null_mut();
+            let rc = crate::parser::oxidize_extract_text(bytes.as_ptr(), bytes.len(), &mut text);
+            crate::oxidize_free_string(text);
+            rc
+        };
+        assert_eq!(extract(&bytes), ErrorCode::Success as c_int);
+
+        assert_eq!(oxidize_set_strict_parsing(1), ErrorCode::Success as c_int);
+        let mut strict = false;
+        unsafe { oxidize_get_strict_parsing(&mut strict) };
+        let (repaired, clean) = (extract(&bytes), extract(&sample_pdf(2)));
+        assert_eq!(oxidize_set_strict_parsing(0), ErrorCode::Success as c_int);
+
+        assert!(strict);
+        assert_ne!(repaired, ErrorCode::Success as c_int);
+        assert_eq!(clean, ErrorCode::Success as c_int);
+        unsafe { oxidize_get_strict_parsing(&mut strict) };
+        assert!(!strict);
+    }
+
+    #[test]
+    fn null_arguments_are_rejected() {
+        let mut out: *mut c_char = ptr::null_mut();
+        let rc = unsafe { oxidize_parse_recovery_report(ptr::null(), 10, 0, &mut out) };
+        assert_eq!(rc, ErrorCode::NullPointer as c_int);
+        let rc = unsafe { oxidize_parse_recovery_report(b"x".as_ptr(), 0, 0, &mut out) };
+        assert_eq!(rc, ErrorCode::PdfParseError as c_int);
+        let rc = unsafe { oxidize_get_strict_parsing(ptr::null_mut()) };
+        assert_eq!(rc, ErrorCode::NullPointer as c_int);
+    }
+
+    #[test]
+    fn strict_options_refuse_what_lenient_parsing_repairs() {
+        let bytes = break_startxref(&sample_pdf(2));
+        assert!(open_reader_with(Cursor::new(&bytes[..]), ParseOptions::strict()).is_err());
+        assert!(open_reader_with(Cursor::new(&bytes[..]), ParseOptions::lenient()).is_ok());
+    }
+
+    #[test]
+    fn core_warnings_are_classified_by_module() {
+        let kind = RecoveryKind::of_event;
+        assert_eq!(
+            kind("oxidize_pdf::parser::xref", "Primary XRef parsing failed"),
+            RecoveryKind::XrefRebuilt
+        );
+        assert_eq!(
+            kind(
+                "oxidize_pdf::parser::reader",
+                "Object 7 0 R not found in XRef, returning null object"
+            ),
+            RecoveryKind::SkippedObject
+        );
+        assert_eq!(
+            kind("oxidize_pdf::parser::filters", "FlateDecode recovered"),
+            RecoveryKind::RepairedStream
+        );
+        assert_eq!(
+            kind("oxidize_pdf::parser::reader", "Catalog recovered"),
+            RecoveryKind::StructureRepaired
+        );
+    }
+}
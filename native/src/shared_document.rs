@@ -0,0 +1,916 @@
+//! Read-only document handle that may be shared across threads.
+//!
+//! A [`crate::parsed_document::ParsedDocumentHandle`] wraps one
+//! `PdfDocument`, whose reader position and object caches are updated on
+//! every read, so it must be used from one thread at a time. The core
+//! library keeps that state in single-threaded cells, so one parsed document
+//! cannot be shared behind a lock without serialising every call on it.
+//!
+//! A [`SharedDocumentHandle`] is therefore a bounded pool of readers: it owns
+//! the PDF bytes and up to one parsed document per available CPU over them.
+//! Each call checks a document out of the pool (parsing a new one while the
+//! pool is below its bound, otherwise waiting for one to be returned), runs
+//! on it alone and puts it back. Concurrent calls never touch the same
+//! reader, and each reader's caches are reused by later calls on it; caches
+//! are not shared between readers, so a page read on one reader is parsed
+//! again on another. The pool is released on free.
+//!
+//! All `oxidize_shared_document_*` functions may be called concurrently on
+//! the same handle from any thread. Outputs and error codes match the
+//! corresponding `oxidize_parsed_document_*` functions; the last error is
+//! recorded on the calling thread as usual. Only freeing requires that no
+//! other call on the handle is in progress.
+
+use std::io::Cursor;
+use std::mem::ManuallyDrop;
+use std::ops::Deref;
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+use std::slice;
+use std::sync::{Condvar, Mutex, MutexGuard, PoisonError};
+
+use oxidize_pdf::parser::PdfDocument;
+
+use crate::mapped_file::{map_path, path_arg, SharedBytes};
+use crate::page::{page_from_parsed_impl, PageHandle};
+use crate::parallel::worker_count;
+use crate::parser::{self, open_lenient, open_with_password, password_arg, ChunkOptions};
+use crate::progress::Progress;
+use crate::words::extract_words_impl;
+use crate::{clear_last_error, set_last_error, ErrorCode};
+
+type Document = PdfDocument<Cursor<SharedBytes>>;
+
+/// Opaque thread-safe handle over a pool of parsed readers of one PDF.
+pub struct SharedDocumentHandle {
+    bytes: SharedBytes,
+    password: Option<String>,
+    /// Most readers parsed at once.
+    max_readers: usize,
+    readers: Mutex<Readers>,
+    /// Signalled when a reader is returned or a parse slot frees up.
+    returned: Condvar,
+}
+
+struct Readers {
+    /// Parsed documents not in use by any call.
+    idle: Vec<Document>,
+    /// Documents parsed (or being parsed) and not discarded, idle or in use.
+    open: usize,
+}
+
+/// A reader checked out of the pool.
+///
+/// Dropping it puts the reader back; if the call panicked the reader is
+/// discarded instead, so a half-updated cache is never reused.
+struct Lease<'a> {
+    handle: &'a SharedDocumentHandle,
+    document: ManuallyDrop<Document>,
+}
+
+impl Deref for Lease<'_> {
+    type Target = Document;
+
+    fn deref(&self) -> &Document {
+        &self.document
+    }
+}
+
+impl Drop for Lease<'_> {
+    fn drop(&mut self) {
+        // SAFETY: `document` is not used again after this.
+        let document = unsafe { ManuallyDrop::take(&mut self.document) };
+        if std::thread::panicking() {
+            drop(document);
+            self.handle.readers().open -= 1;
+        } else {
+            self.handle.readers().idle.push(document);
+        }
+        self.handle.returned.notify_one();
+    }
+}
+
+impl SharedDocumentHandle {
+    /// Parse `bytes` once up front, so an unparsable file or wrong password
+    /// fails at open, and keep that document as the first pooled one.
+    fn open(bytes: SharedBytes, password: Option<&str>, max_readers: usize) -> Result<Self, c_int> {
+        let handle = SharedDocumentHandle {
+            bytes,
+            password: password.map(str::to_owned),
+            max_readers: max_readers.max(1),
+            readers: Mutex::new(Readers {
+                idle: Vec::new(),
+                open: 1,
+            }),
+            returned: Condvar::new(),
+        };
+        let document = handle.parse()?;
+        handle.readers().idle.push(document);
+        Ok(handle)
+    }
+
+    fn parse(&self) -> Result<Document, c_int> {
+        let reader = match &self.password {
+            Some(password) => open_with_password(self.bytes.clone(), password),
+            None => open_lenient(self.bytes.clone()),
+        };
+        match reader {
+            Ok(reader) => Ok(PdfDocument::new(reader)),
+            Err(e) => {
+                set_last_error(e);
+                Err(ErrorCode::PdfParseError as c_int)
+            }
+        }
+    }
+
+    fn readers(&self) -> MutexGuard<'_, Readers> {
+        // The lock only guards pushes, pops and the count; a panic cannot
+        // leave them half-updated.
+        self.readers.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Take an idle reader, parse a new one while below `max_readers`, or
+    /// wait for one to be returned.
+    fn check_out(&self) -> Result<Lease<'_>, c_int> {
+        let mut readers = self.readers();
+        loop {
+            if let Some(document) = readers.idle.pop() {
+                return Ok(self.lease(document));
+            }
+            if readers.open < self.max_readers {
+                readers.open += 1;
+                break;
+            }
+            readers = self
+                .returned
+                .wait(readers)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+        drop(readers);
+        match self.parse() {
+            Ok(document) => Ok(self.lease(document)),
+            Err(code) => {
+                self.readers().open -= 1;
+                self.returned.notify_one();
+                Err(code)
+            }
+        }
+    }
+
+    fn lease(&self, document: Document) -> Lease<'_> {
+        Lease {
+            handle: self,
+            document: ManuallyDrop::new(document),
+        }
+    }
+
+    /// Run `f` on a document no other call is using and return its code.
+    fn with_document(&self, f: impl FnOnce(&Document) -> c_int) -> c_int {
+        match self.check_out() {
+            Ok(document) => f(&document),
+            Err(code) => code,
+        }
+    }
+}
+
+// ── Lifecycle ─────────────────────────────────────────────────────────────────
+
+/// Open a thread-safe handle over a copy of `pdf_bytes`. `password` may be
+/// null; when given, an encrypted file is unlocked as by
+/// `oxidize_parsed_document_open_with_password`.
+///
+/// # Returns
+/// `Success`; `NullPointer` if `pdf_bytes` or `out_handle` is null;
+/// `InvalidUtf8` for a non-UTF-8 password; `PdfParseError` if
+/// `pdf_len == 0` or the bytes cannot be parsed; `PasswordRequired` for a
+/// wrong password. `*out_handle` is null on any error.
+///
+/// # Safety
+/// - `pdf_bytes` must be a valid pointer to `pdf_len` bytes.
+/// - `password` must be null or a valid NUL-terminated UTF-8 string.
+/// - `out_handle` must be a valid pointer to receive the new handle.
+/// - The returned handle must be freed with `oxidize_shared_document_free`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_shared_document_open(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    password: *const c_char,
+    out_handle: *mut *mut SharedDocumentHandle,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if pdf_bytes.is_null() || out_handle.is_null() {
+            set_last_error("Null pointer provided to oxidize_shared_document_open");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_handle = ptr::null_mut();
+
+        if pdf_len == 0 {
+            set_last_error("PDF data is empty (0 bytes)");
+            return ErrorCode::PdfParseError as c_int;
+        }
+
+        let password = match optional_password(password) {
+            Ok(p) => p,
+            Err(code) => return code,
+        };
+        let bytes = SharedBytes::from(slice::from_raw_parts(pdf_bytes, pdf_len));
+        match SharedDocumentHandle::open(bytes, password, worker_count(0)) {
+            Ok(handle) => {
+                *out_handle = Box::into_raw(Box::new(handle));
+                ErrorCode::Success as c_int
+            }
+            Err(code) => code,
+        }
+    })
+}
+
+/// [`oxidize_shared_document_open`] for a file on disk, memory-mapped as by
+/// `oxidize_parsed_document_open_path`. The file must not be truncated or
+/// replaced in place while the handle is alive.
+///
+/// # Returns
+/// As [`oxidize_shared_document_open`], with `NullPointer` for a null
+/// `path`, `InvalidUtf8` for a non-UTF-8 path and `IoError` if the file
+/// cannot be opened or mapped.
+///
+/// # Safety
+/// - `path` must be a valid NUL-terminated UTF-8 string.
+/// - `password` must be null or a valid NUL-terminated UTF-8 string.
+/// - `out_handle` must be a valid pointer to receive the new handle.
+/// - The returned handle must be freed with `oxidize_shared_document_free`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_shared_document_open_path(
+    path: *const c_char,
+    password: *const c_char,
+    out_handle: *mut *mut SharedDocumentHandle,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if path.is_null() || out_handle.is_null() {
+            set_last_error("Null pointer provided to oxidize_shared_document_open_path");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_handle = ptr::null_mut();
+
+        let path = match path_arg(path) {
+            Ok(p) => p,
+            Err(code) => return code,
+        };
+        let password = match optional_password(password) {
+            Ok(p) => p,
+            Err(code) => return code,
+        };
+        let bytes = match map_path(path) {
+            Ok(b) => b,
+            Err((code, e)) => {
+                set_last_error(e);
+                return code as c_int;
+            }
+        };
+        match SharedDocumentHandle::open(bytes, password, worker_count(0)) {
+            Ok(handle) => {
+                *out_handle = Box::into_raw(Box::new(handle));
+                ErrorCode::Success as c_int
+            }
+            Err(code) => code,
+        }
+    })
+}
+
+unsafe fn optional_password<'a>(password: *const c_char) -> Result<Option<&'a str>, c_int> {
+    if password.is_null() {
+        Ok(None)
+    } else {
+        password_arg(password).map(Some)
+    }
+}
+
+/// Free a shared document handle and every pooled document.
+///
+/// # Safety
+/// - `handle` must have been returned by `oxidize_shared_document_open` or
+///   `oxidize_shared_document_open_path` and not freed previously.
+/// - No other call on `handle` may be in progress or start afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_shared_document_free(handle: *mut SharedDocumentHandle) {
+    crate::ffi_guard_unit(move || {
+        if handle.is_null() {
+            return;
+        }
+        drop(Box::from_raw(handle));
+    })
+}
+
+// ── Reads ─────────────────────────────────────────────────────────────────────
+
+/// Shared-handle variant of [`parser::oxidize_get_page_count`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_shared_document_open`.
+/// - `out_count` must be a valid pointer to a `usize`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_shared_document_get_page_count(
+    handle: *const SharedDocumentHandle,
+    out_count: *mut usize,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_count.is_null() {
+            set_last_error("Null pointer provided to oxidize_shared_document_get_page_count");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_count = 0;
+        (*handle).with_document(|document| parser::get_page_count_impl(document, out_count))
+    })
+}
+
+/// Shared-handle variant of [`parser::oxidize_get_metadata`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_shared_document_open`.
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_shared_document_get_metadata(
+    handle: *const SharedDocumentHandle,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_shared_document_get_metadata");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+        (*handle).with_document(|document| parser::get_metadata_impl(document, out_json))
+    })
+}
+
+/// Shared-handle variant of [`parser::oxidize_extract_text`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_shared_document_open`.
+/// - `out_text` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_shared_document_extract_text(
+    handle: *const SharedDocumentHandle,
+    out_text: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_text.is_null() {
+            set_last_error("Null pointer provided to oxidize_shared_document_extract_text");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_text = ptr::null_mut();
+        (*handle)
+            .with_document(|document| parser::extract_text_impl(document, Progress::NONE, out_text))
+    })
+}
+
+/// Shared-handle variant of [`parser::oxidize_extract_text_from_page`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_shared_document_open`.
+/// - `page_number` is 1-based (first page = 1).
+/// - `out_text` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_shared_document_extract_text_from_page(
+    handle: *const SharedDocumentHandle,
+    page_number: usize,
+    out_text: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_text.is_null() {
+            set_last_error(
+                "Null pointer provided to oxidize_shared_document_extract_text_from_page",
+            );
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_text = ptr::null_mut();
+        if page_number == 0 {
+            set_last_error("Page number must be >= 1 (1-based indexing)");
+            return ErrorCode::PageOutOfRange as c_int;
+        }
+        (*handle).with_document(|document| {
+            parser::extract_text_from_page_impl(document, page_number, out_text)
+        })
+    })
+}
+
+/// Shared-handle variant of [`parser::oxidize_partition`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_shared_document_open`.
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_shared_document_partition(
+    handle: *const SharedDocumentHandle,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_shared_document_partition");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+        (*handle).with_document(|document| parser::partition_impl(document, out_json))
+    })
+}
+
+/// Shared-handle variant of [`parser::oxidize_rag_chunks`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_shared_document_open`.
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_shared_document_rag_chunks(
+    handle: *const SharedDocumentHandle,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_shared_document_rag_chunks");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+        (*handle).with_document(|document| parser::rag_chunks_impl(document, out_json))
+    })
+}
+
+// ── Page-level reads ──────────────────────────────────────────────────────────
+
+/// Shared-handle variant of [`parser::oxidize_get_page_dimensions`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_shared_document_open`.
+/// - `page_number` is 1-based (first page = 1).
+/// - `out_width` and `out_height` must be valid pointers to `f64`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_shared_document_get_page_dimensions(
+    handle: *const SharedDocumentHandle,
+    page_number: usize,
+    out_width: *mut f64,
+    out_height: *mut f64,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_width.is_null() || out_height.is_null() {
+            set_last_error("Null pointer provided to oxidize_shared_document_get_page_dimensions");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_width = 0.0;
+        *out_height = 0.0;
+        if page_number == 0 {
+            set_last_error("Page number must be >= 1 (1-based indexing)");
+            return ErrorCode::PageOutOfRange as c_int;
+        }
+        (*handle).with_document(|document| {
+            parser::get_page_dimensions_impl(document, page_number, out_width, out_height)
+        })
+    })
+}
+
+/// Shared-handle variant of [`parser::oxidize_extract_chunks_from_page`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_shared_document_open`.
+/// - `page_number` is 1-based (first page = 1).
+/// - `options` can be null (defaults will be used).
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_shared_document_extract_chunks_from_page(
+    handle: *const SharedDocumentHandle,
+    page_number: usize,
+    options: *const ChunkOptions,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_json.is_null() {
+            set_last_error(
+                "Null pointer provided to oxidize_shared_document_extract_chunks_from_page",
+            );
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+        if page_number == 0 {
+            set_last_error("Page number must be >= 1 (1-based indexing)");
+            return ErrorCode::PageOutOfRange as c_int;
+        }
+        let chunk_opts = if options.is_null() {
+            parser::default_chunk_options()
+        } else {
+            *options
+        };
+        (*handle).with_document(|document| {
+            parser::extract_chunks_from_page_impl(document, page_number, chunk_opts, out_json)
+        })
+    })
+}
+
+/// Shared-handle variant of [`crate::words::oxidize_extract_words`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_shared_document_open`.
+/// - `page_number` is 1-based (first page = 1).
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_shared_document_extract_words(
+    handle: *const SharedDocumentHandle,
+    page_number: usize,
+    glyphs: u8,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_shared_document_extract_words");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+        if page_number == 0 {
+            set_last_error("Page number must be >= 1 (1-based indexing)");
+            return ErrorCode::PageOutOfRange as c_int;
+        }
+        (*handle).with_document(|document| {
+            extract_words_impl(document, page_number, glyphs != 0, out_json)
+        })
+    })
+}
+
+/// Shared-handle variant of [`parser::oxidize_get_annotations`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_shared_document_open`.
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_shared_document_get_annotations(
+    handle: *const SharedDocumentHandle,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_shared_document_get_annotations");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+        (*handle).with_document(|document| parser::get_annotations_impl(document, out_json))
+    })
+}
+
+/// Shared-handle variant of [`parser::oxidize_get_page_resources`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_shared_document_open`.
+/// - `page_number` is 1-based (first page = 1).
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_shared_document_get_page_resources(
+    handle: *const SharedDocumentHandle,
+    page_number: usize,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_shared_document_get_page_resources");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+        if page_number == 0 {
+            set_last_error("Page number must be >= 1 (1-based indexing)");
+            return ErrorCode::PageOutOfRange as c_int;
+        }
+        (*handle).with_document(|document| {
+            parser::get_page_resources_impl(document, page_number, out_json)
+        })
+    })
+}
+
+/// Shared-handle variant of [`parser::oxidize_get_page_content_stream`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_shared_document_open`.
+/// - `page_number` is 1-based (first page = 1).
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_shared_document_get_page_content_stream(
+    handle: *const SharedDocumentHandle,
+    page_number: usize,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_json.is_null() {
+            set_last_error(
+                "Null pointer provided to oxidize_shared_document_get_page_content_stream",
+            );
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+        if page_number == 0 {
+            set_last_error("Page number must be >= 1 (1-based indexing)");
+            return ErrorCode::PageOutOfRange as c_int;
+        }
+        (*handle).with_document(|document| {
+            parser::get_page_content_stream_impl(document, page_number, out_json)
+        })
+    })
+}
+
+/// Shared-handle variant of [`parser::oxidize_analyze_page_content`].
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_shared_document_open`.
+/// - `page_number` is 1-based (first page = 1).
+/// - `out_json` will be allocated and must be freed with `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_shared_document_analyze_page_content(
+    handle: *const SharedDocumentHandle,
+    page_number: usize,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if handle.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_shared_document_analyze_page_content");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+        if page_number == 0 {
+            set_last_error("Page number must be >= 1 (1-based indexing)");
+            return ErrorCode::PageOutOfRange as c_int;
+        }
+        (*handle).with_document(|document| {
+            parser::analyze_page_content_impl(document, page_number, out_json)
+        })
+    })
+}
+
+/// Shared-handle variant of [`crate::page::oxidize_page_from_parsed_bytes`]
+/// (`page_index` is zero-based, as there).
+///
+/// Returns a heap-allocated `PageHandle` pointer, or null on error (inspect
+/// `oxidize_get_last_error`).
+///
+/// # Safety
+/// - `handle` must be a valid pointer returned by `oxidize_shared_document_open`.
+/// - The returned pointer must be freed with `oxidize_page_free`, or handed to
+///   `oxidize_document_add_page`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_shared_document_page_to_writable(
+    handle: *const SharedDocumentHandle,
+    page_index: u32,
+) -> *mut PageHandle {
+    crate::ffi_guard_ptr(move || {
+        clear_last_error();
+        if handle.is_null() {
+            set_last_error("Null pointer provided to oxidize_shared_document_page_to_writable");
+            return ptr::null_mut();
+        }
+        match (*handle).check_out() {
+            Ok(document) => page_from_parsed_impl(
+                &document,
+                page_index,
+                "oxidize_shared_document_page_to_writable",
+            ),
+            Err(_) => ptr::null_mut(),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::SamplePdf;
+    use std::ffi::CStr;
+    use std::sync::Barrier;
+
+    // The handle is shared by reference across threads.
+    const _: fn() = || {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<SharedDocumentHandle>();
+    };
+
+    unsafe fn take_string(code: c_int, p: *mut c_char) -> String {
+        assert_eq!(code, ErrorCode::Success as c_int);
+        let s = CStr::from_ptr(p).to_string_lossy().into_owned();
+        crate::oxidize_free_string(p);
+        s
+    }
+
+    unsafe fn page_text(handle: *const SharedDocumentHandle, page: usize) -> String {
+        let mut out = ptr::null_mut();
+        let code = oxidize_shared_document_extract_text_from_page(handle, page, &mut out);
+        take_string(code, out)
+    }
+
+    unsafe fn partition(handle: *const SharedDocumentHandle) -> String {
+        let mut out = ptr::null_mut();
+        let code = oxidize_shared_document_partition(handle, &mut out);
+        take_string(code, out)
+    }
+
+    /// Raw handle pointer moved into test threads.
+    #[derive(Clone, Copy)]
+    struct Shared(*const SharedDocumentHandle);
+    unsafe impl Send for Shared {}
+
+    #[test]
+    fn concurrent_reads_match_single_threaded_results() {
+        const THREADS: usize = 8;
+        const PAGES: usize = 12;
+        let pdf = SamplePdf::new(PAGES).title("Shared Fixture").build();
+        unsafe {
+            let mut handle = ptr::null_mut();
+            let code =
+                oxidize_shared_document_open(pdf.as_ptr(), pdf.len(), ptr::null(), &mut handle);
+            assert_eq!(code, ErrorCode::Success as c_int);
+
+            let expected_pages: Vec<String> = (1..=PAGES).map(|p| page_text(handle, p)).collect();
+            let expected_partition = partition(handle);
+            assert!(expected_pages[11].contains("Page 12 line 4"));
+
+            let shared = Shared(handle);
+            let barrier = Barrier::new(THREADS);
+            std::thread::scope(|scope| {
+                for t in 0..THREADS {
+                    let (barrier, expected_pages, expected_partition) =
+                        (&barrier, &expected_pages, &expected_partition);
+                    scope.spawn(move || {
+                        let handle = shared;
+                        barrier.wait();
+                        for round in 0..30 {
+                            let page = (t * 7 + round) % PAGES;
+                            assert_eq!(page_text(handle.0, page + 1), expected_pages[page]);
+                            if round % 10 == t % 10 {
+                                assert_eq!(&partition(handle.0), expected_partition);
+                            }
+                            // Errors stay on the thread that caused them.
+                            if round % 5 == 0 {
+                                let mut out = ptr::null_mut();
+                                let code = oxidize_shared_document_extract_text_from_page(
+                                    handle.0,
+                                    PAGES + 1 + t,
+                                    &mut out,
+                                );
+                                assert_eq!(code, ErrorCode::PageOutOfRange as c_int);
+                                assert!(out.is_null());
+                            }
+                        }
+                        let mut count = 0;
+                        let code = oxidize_shared_document_get_page_count(handle.0, &mut count);
+                        assert_eq!(code, ErrorCode::Success as c_int);
+                        assert_eq!(count, PAGES);
+                    });
+                }
+            });
+
+            let readers = (*handle).readers();
+            assert_eq!(readers.idle.len(), readers.open);
+            let bound = THREADS.min(worker_count(0));
+            assert!(
+                (1..=bound).contains(&readers.open),
+                "{} readers",
+                readers.open
+            );
+            drop(readers);
+            oxidize_shared_document_free(handle);
+        }
+    }
+
+    #[test]
+    fn pool_never_parses_more_readers_than_its_bound() {
+        const THREADS: usize = 8;
+        let pdf = SamplePdf::new(4).build();
+        let handle = SharedDocumentHandle::open(SharedBytes::from(&pdf[..]), None, 2).unwrap();
+        let barrier = Barrier::new(THREADS);
+        std::thread::scope(|scope| {
+            for _ in 0..THREADS {
+                let (handle, barrier) = (&handle, &barrier);
+                scope.spawn(move || {
+                    barrier.wait();
+                    for _ in 0..5 {
+                        let mut out = ptr::null_mut();
+                        let code = unsafe { oxidize_shared_document_partition(handle, &mut out) };
+                        unsafe { take_string(code, out) };
+                    }
+                });
+            }
+        });
+        // `open` only ever grows while no call fails, so it is the peak.
+        let readers = handle.readers();
+        assert!(readers.open <= 2, "{} readers", readers.open);
+        assert_eq!(readers.idle.len(), readers.open);
+    }
+
+    #[test]
+    fn page_reads_match_parsed_document_handle() {
+        use crate::parsed_document::*;
+
+        let pdf = SamplePdf::new(3).ruled_table_on(2).build();
+        unsafe {
+            let mut shared = ptr::null_mut();
+            let code =
+                oxidize_shared_document_open(pdf.as_ptr(), pdf.len(), ptr::null(), &mut shared);
+            assert_eq!(code, ErrorCode::Success as c_int);
+            let mut parsed = ptr::null_mut();
+            let code = oxidize_parsed_document_open(pdf.as_ptr(), pdf.len(), &mut parsed);
+            assert_eq!(code, ErrorCode::Success as c_int);
+
+            let (mut w, mut h, mut pw, mut ph) = (0.0, 0.0, 0.0, 0.0);
+            let code = oxidize_shared_document_get_page_dimensions(shared, 2, &mut w, &mut h);
+            assert_eq!(code, ErrorCode::Success as c_int);
+            oxidize_parsed_document_get_page_dimensions(parsed, 2, &mut pw, &mut ph);
+            assert_eq!((w, h), (pw, ph));
+
+            let (mut a, mut b) = (ptr::null_mut(), ptr::null_mut());
+            let code =
+                oxidize_shared_document_extract_chunks_from_page(shared, 2, ptr::null(), &mut a);
+            let code_b =
+                oxidize_parsed_document_extract_chunks_from_page(parsed, 2, ptr::null(), &mut b);
+            assert_eq!(take_string(code, a), take_string(code_b, b));
+
+            let code = oxidize_shared_document_extract_words(shared, 2, 1, &mut a);
+            let code_b = oxidize_parsed_document_extract_words(parsed, 2, 1, &mut b);
+            assert_eq!(take_string(code, a), take_string(code_b, b));
+
+            let code = oxidize_shared_document_get_annotations(shared, &mut a);
+            let code_b = oxidize_parsed_document_get_annotations(parsed, &mut b);
+            assert_eq!(take_string(code, a), take_string(code_b, b));
+
+            type PageRead =
+                unsafe extern "C" fn(*const SharedDocumentHandle, usize, *mut *mut c_char) -> c_int;
+            type ParsedPageRead =
+                unsafe extern "C" fn(*const ParsedDocumentHandle, usize, *mut *mut c_char) -> c_int;
+            let reads: [(PageRead, ParsedPageRead); 3] = [
+                (
+                    oxidize_shared_document_get_page_resources,
+                    oxidize_parsed_document_get_page_resources,
+                ),
+                (
+                    oxidize_shared_document_get_page_content_stream,
+                    oxidize_parsed_document_get_page_content_stream,
+                ),
+                (
+                    oxidize_shared_document_analyze_page_content,
+                    oxidize_parsed_document_analyze_page_content,
+                ),
+            ];
+            for (read, parsed_read) in reads {
+                let (code, code_b) = (read(shared, 2, &mut a), parsed_read(parsed, 2, &mut b));
+                assert_eq!(take_string(code, a), take_string(code_b, b));
+                assert_eq!(read(shared, 0, &mut a), ErrorCode::PageOutOfRange as c_int);
+                assert!(a.is_null());
+                assert_eq!(read(shared, 4, &mut a), parsed_read(parsed, 4, &mut b));
+            }
+
+            let page = oxidize_shared_document_page_to_writable(shared, 1);
+            assert!(!page.is_null());
+            crate::page::oxidize_page_free(page);
+            assert!(oxidize_shared_document_page_to_writable(shared, 3).is_null());
+
+            oxidize_parsed_document_free(parsed);
+            oxidize_shared_document_free(shared);
+        }
+    }
+
+    #[test]
+    fn open_validates_input_and_reuses_pooled_documents() {
+        let pdf = SamplePdf::new(2).title("Shared Fixture").build();
+        unsafe {
+            let mut handle = ptr::null_mut();
+            let code = oxidize_shared_document_open(pdf.as_ptr(), 0, ptr::null(), &mut handle);
+            assert_eq!(code, ErrorCode::PdfParseError as c_int);
+            assert!(handle.is_null());
+            let garbage = b"not a pdf";
+            let code = oxidize_shared_document_open(
+                garbage.as_ptr(),
+                garbage.len(),
+                ptr::null(),
+                &mut handle,
+            );
+            assert_eq!(code, ErrorCode::PdfParseError as c_int);
+            assert!(handle.is_null());
+
+            let code =
+                oxidize_shared_document_open(pdf.as_ptr(), pdf.len(), ptr::null(), &mut handle);
+            assert_eq!(code, ErrorCode::Success as c_int);
+            let mut out = ptr::null_mut();
+            let code = oxidize_shared_document_get_metadata(handle, &mut out);
+            assert!(take_string(code, out).contains("Shared Fixture"));
+            let mut out = ptr::null_mut();
+            let code = oxidize_shared_document_rag_chunks(handle, &mut out);
+            assert!(take_string(code, out).contains("Page 2 line 1"));
+            // Sequential calls keep reusing the document parsed at open.
+            assert_eq!((*handle).readers().open, 1);
+            oxidize_shared_document_free(handle);
+            oxidize_shared_document_free(ptr::null_mut());
+        }
+    }
+}
@@ -0,0 +1,1255 @@
+//! Structured table extraction.
+//!
+//! `oxidize_partition` reports a detected table as one element whose cells
+//! are flattened into `text`. [`oxidize_extract_tables`] runs the same table
+//! pass — ruling-based detection on pages with a drawn grid, then the
+//! spatial-cluster detector over the remaining fragments, gated and
+//! thresholded by the same `PartitionConfig` fields — and keeps the grid:
+//! cell text and bounding boxes, row/column spans and header rows.
+//!
+//! Spans come from the ruling lines: neighbouring grid cells are merged when
+//! no drawn line separates them. Borderless (spatial) tables carry no such
+//! evidence, so their cells are always 1 × 1. The top row is a header when it
+//! is styled apart from the body (bold or larger text), or heads a column
+//! whose body is numeric without being numeric itself; a header cell spanning
+//! several rows makes all of them header rows.
+//!
+//! [`oxidize_table_to_csv`], [`oxidize_table_to_markdown`] and
+//! [`oxidize_table_to_html`] render one table of that output.
+
+use std::ffi::CString;
+use std::fmt::Write as _;
+use std::io::{Read, Seek};
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+use std::slice;
+
+use oxidize_pdf::graphics::extraction::{ExtractedGraphics, GraphicsExtractor};
+use oxidize_pdf::parser::PdfDocument;
+use oxidize_pdf::pipeline::{
+    PartitionConfig, ReadingOrder, ReadingOrderStrategy, SimpleReadingOrder, XYCutReadingOrder,
+};
+use oxidize_pdf::text::structured::{StructuredDataDetector, Table as SpatialTable};
+use oxidize_pdf::text::table_detection::{DetectedTable, TableDetector};
+use oxidize_pdf::text::{TextExtractor, TextFragment};
+use serde::{Deserialize, Serialize};
+
+use crate::chunk_stream::PagePartitioner;
+use crate::error_detail::{ErrorCategory, LastError};
+use crate::parser::{open_lenient, parse_config_arg, parse_partition_config_opt};
+use crate::pipeline_config::{Fields, ValidatedConfig};
+use crate::progress::Progress;
+use crate::{clear_last_error, set_last_error, ErrorCode};
+
+/// Distance within which a drawn line counts as a cell border, in points
+/// (the ruling detector's line alignment tolerance).
+const RULE_TOLERANCE: f64 = 2.0;
+
+/// Longest text the partitioner still classifies as a header or footer.
+const MAX_HEADER_TEXT_LEN: usize = 100;
+
+/// Most grid positions (`row_count * column_count`) a caller-supplied table
+/// may have; the serializers allocate one slot per position.
+const MAX_GRID_POSITIONS: usize = 1_000_000;
+
+/// Which detector found a table.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TableSource {
+    /// Drawn grid lines.
+    Ruling,
+    /// Aligned text without borders.
+    Spatial,
+}
+
+/// One cell of an [`ExtractedTable`]; a merged cell appears once, at its
+/// top-left grid position.
+#[derive(Debug, Clone, Serialize)]
+pub struct TableCellResult {
+    pub row: usize,
+    pub column: usize,
+    pub row_span: usize,
+    pub col_span: usize,
+    pub text: String,
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl TableCellResult {
+    fn contains(&self, f: &TextFragment) -> bool {
+        let (cx, cy) = (f.x + f.width / 2.0, f.y + f.height / 2.0);
+        cx >= self.x && cx <= self.x + self.width && cy >= self.y && cy <= self.y + self.height
+    }
+}
+
+/// A detected table as serialized into `oxidize_extract_tables` output.
+#[derive(Debug, Clone, Serialize)]
+pub struct ExtractedTable {
+    /// 1-based, as in `PdfElementResult`.
+    pub page_number: u32,
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+    pub confidence: f64,
+    pub source: TableSource,
+    pub row_count: usize,
+    pub column_count: usize,
+    pub header_rows: usize,
+    /// Row-major; grid positions covered by a span have no entry of their own.
+    pub cells: Vec<TableCellResult>,
+}
+
+// ── Detection ────────────────────────────────────────────────────────────────
+
+/// Whether the partitioner claims `f` before its table pass: struct-tagged
+/// headings and list items, then page header and footer text.
+fn claimed_before_tables(f: &TextFragment, config: &PartitionConfig, page_height: f64) -> bool {
+    let tag = f.struct_tag.as_deref();
+    let heading_or_item = matches!(
+        tag,
+        Some("H" | "H1" | "H2" | "H3" | "H4" | "H5" | "H6" | "Title" | "LI" | "Lbl" | "LBody")
+    );
+    if heading_or_item && !f.text.trim().is_empty() {
+        return true;
+    }
+    let body = heading_or_item || matches!(tag, Some("P" | "Span" | "L"));
+    if !config.detect_headers_footers
+        || page_height <= 0.0
+        || body
+        || f.text.chars().count() > MAX_HEADER_TEXT_LEN
+    {
+        return false;
+    }
+    f.y >= page_height * (1.0 - config.header_zone)
+        || f.y + f.height <= page_height * config.footer_zone
+}
+
+/// Split fragments into table candidate regions at vertical gaps wider than
+/// twice the median line height, keeping regions of at least 4 fragments
+/// (the partitioner's segmentation).
+fn table_regions<'a>(fragments: &[&'a TextFragment]) -> Vec<Vec<&'a TextFragment>> {
+    let mut sorted = fragments.to_vec();
+    sorted.sort_by(|a, b| b.y.total_cmp(&a.y));
+    let mut heights: Vec<f64> = sorted
+        .iter()
+        .map(|f| f.height)
+        .filter(|h| *h > 0.0)
+        .collect();
+    heights.sort_by(f64::total_cmp);
+    let median = match heights.len() {
+        0 => 12.0,
+        n if n % 2 == 0 => (heights[n / 2 - 1] + heights[n / 2]) / 2.0,
+        n => heights[n / 2],
+    };
+
+    let mut regions = Vec::new();
+    let mut current: Vec<&TextFragment> = Vec::new();
+    for f in sorted {
+        if current
+            .last()
+            .is_some_and(|prev| prev.y - (f.y + f.height) > median * 2.0)
+        {
+            let region = std::mem::take(&mut current);
+            if region.len() >= 4 {
+                regions.push(region);
+            }
+        }
+        current.push(f);
+    }
+    if current.len() >= 4 {
+        regions.push(current);
+    }
+    regions
+}
+
+/// Whether a region is a numbered or bulleted list: exactly two x clusters,
+/// the left one averaging at most 3 characters (the partitioner's filter).
+fn looks_like_list(region: &[&TextFragment]) -> bool {
+    const TOLERANCE: f64 = 15.0;
+    let mut clusters: Vec<f64> = Vec::new();
+    for f in region {
+        if !clusters.iter().any(|x| (x - f.x).abs() <= TOLERANCE) {
+            clusters.push(f.x);
+        }
+    }
+    if clusters.len() != 2 {
+        return false;
+    }
+    let left = clusters[0].min(clusters[1]);
+    let lengths: Vec<usize> = region
+        .iter()
+        .filter(|f| (f.x - left).abs() <= TOLERANCE)
+        .map(|f| f.text.trim().chars().count())
+        .collect();
+    !lengths.is_empty() && lengths.iter().sum::<usize>() as f64 / lengths.len() as f64 <= 3.0
+}
+
+/// Whether a drawn line runs along `x` (vertical) or `y` (horizontal)
+/// through the point `along` on the other axis.
+fn has_rule(graphics: &ExtractedGraphics, vertical: bool, at: f64, along: f64) -> bool {
+    let covers =
+        |a: f64, b: f64| a.min(b) - RULE_TOLERANCE <= along && along <= a.max(b) + RULE_TOLERANCE;
+    if vertical {
+        graphics
+            .vertical_lines()
+            .any(|l| (l.x1 - at).abs() <= RULE_TOLERANCE && covers(l.y1, l.y2))
+    } else {
+        graphics
+            .horizontal_lines()
+            .any(|l| (l.y1 - at).abs() <= RULE_TOLERANCE && covers(l.x1, l.x2))
+    }
+}
+
+/// Cells of a ruled table, merging grid cells no drawn line separates.
+fn ruling_cells(table: &DetectedTable, graphics: &ExtractedGraphics) -> Vec<TableCellResult> {
+    let (rows, cols) = (table.rows, table.columns);
+    let mut grid = vec![None; rows * cols];
+    for cell in &table.cells {
+        if cell.row < rows && cell.column < cols {
+            grid[cell.row * cols + cell.column] = Some(cell);
+        }
+    }
+    // Grid cells `a` (left or above) and `b` are separated by a drawn line.
+    let split = |a: usize, b: usize| match (grid[a], grid[b]) {
+        (Some(a), Some(_)) if b == a.row * cols + a.column + 1 => has_rule(
+            graphics,
+            true,
+            a.bbox.right(),
+            a.bbox.y + a.bbox.height / 2.0,
+        ),
+        (Some(a), Some(_)) => has_rule(graphics, false, a.bbox.y, a.bbox.x + a.bbox.width / 2.0),
+        _ => true,
+    };
+
+    let mut covered = vec![false; rows * cols];
+    let mut cells = Vec::new();
+    for r in 0..rows {
+        for c in 0..cols {
+            let at = r * cols + c;
+            if covered[at] || grid[at].is_none() {
+                continue;
+            }
+            let mut col_span = 1;
+            while c + col_span < cols
+                && !covered[at + col_span]
+                && !split(at + col_span - 1, at + col_span)
+            {
+                col_span += 1;
+            }
+            let mut row_span = 1;
+            while r + row_span < rows
+                && (c..c + col_span).all(|k| {
+                    let below = (r + row_span) * cols + k;
+                    !covered[below]
+                        && !split(below - cols, below)
+                        && (k + 1 == c + col_span || !split(below, below + 1))
+                })
+            {
+                row_span += 1;
+            }
+
+            let mut text = Vec::new();
+            let (mut x0, mut y0, mut x1, mut y1) = (f64::MAX, f64::MAX, f64::MIN, f64::MIN);
+            for rr in r..r + row_span {
+                for cc in c..c + col_span {
+                    covered[rr * cols + cc] = true;
+                    if let Some(cell) = grid[rr * cols + cc] {
+                        if !cell.text.trim().is_empty() {
+                            text.push(cell.text.as_str());
+                        }
+                        x0 = x0.min(cell.bbox.x);
+                        y0 = y0.min(cell.bbox.y);
+                        x1 = x1.max(cell.bbox.right());
+                        y1 = y1.max(cell.bbox.top());
+                    }
+                }
+            }
+            cells.push(TableCellResult {
+                row: r,
+                column: c,
+                row_span,
+                col_span,
+                text: text.join(" "),
+                x: x0,
+                y: y0,
+                width: x1 - x0,
+                height: y1 - y0,
+            });
+        }
+    }
+    cells
+}
+
+fn spatial_cells(table: &SpatialTable) -> Vec<TableCellResult> {
+    table
+        .rows
+        .iter()
+        .enumerate()
+        .flat_map(|(r, row)| {
+            row.cells.iter().map(move |cell| TableCellResult {
+                row: r,
+                column: cell.column_index,
+                row_span: 1,
+                col_span: 1,
+                text: cell.text.clone(),
+                x: cell.bounding_box.x,
+                y: cell.bounding_box.y,
+                width: cell.bounding_box.width,
+                height: cell.bounding_box.height,
+            })
+        })
+        .collect()
+}
+
+/// Whether `text` is a number, allowing sign, currency, percent, grouping
+/// and accounting parentheses.
+fn is_numeric(text: &str) -> bool {
+    let stripped: String = text
+        .trim()
+        .trim_matches(|c: char| "()%$€£¥+-".contains(c) || c.is_whitespace())
+        .chars()
+        .filter(|&c| c != ',' && c != '\u{a0}' && c != ' ')
+        .collect();
+    !stripped.is_empty() && stripped.parse::<f64>().is_ok()
+}
+
+/// Number of header rows at the top of the table (0 when none is detected).
+fn header_rows(cells: &[TableCellResult], row_count: usize, fragments: &[TextFragment]) -> usize {
+    let depth = cells
+        .iter()
+        .filter(|c| c.row == 0)
+        .map(|c| c.row_span)
+        .max()
+        .unwrap_or(0);
+    if depth == 0 || depth >= row_count {
+        return 0;
+    }
+    let (head, body): (Vec<&TableCellResult>, Vec<&TableCellResult>) =
+        cells.iter().partition(|c| c.row < depth);
+    if head.iter().all(|c| c.text.trim().is_empty()) || head.iter().any(|c| is_numeric(&c.text)) {
+        return 0;
+    }
+
+    let style = |cells: &[&TableCellResult]| {
+        let inside: Vec<&TextFragment> = fragments
+            .iter()
+            .filter(|f| !f.text.trim().is_empty() && cells.iter().any(|c| c.contains(f)))
+            .collect();
+        let bold = !inside.is_empty() && inside.iter().all(|f| f.is_bold);
+        let size = inside.iter().map(|f| f.font_size).sum::<f64>() / inside.len().max(1) as f64;
+        (inside.is_empty(), bold, size)
+    };
+    let (head_empty, head_bold, head_size) = style(&head);
+    let (body_empty, body_bold, body_size) = style(&body);
+    let styled =
+        !head_empty && !body_empty && ((head_bold && !body_bold) || head_size > body_size * 1.1);
+
+    let numeric_column = head.iter().filter(|h| !h.text.trim().is_empty()).any(|h| {
+        let values: Vec<&str> = body
+            .iter()
+            .filter(|c| c.column >= h.column && c.column < h.column + h.col_span)
+            .map(|c| c.text.trim())
+            .filter(|t| !t.is_empty())
+            .collect();
+        !values.is_empty() && values.iter().filter(|t| is_numeric(t)).count() * 2 >= values.len()
+    });
+
+    if styled || numeric_column {
+        depth
+    } else {
+        0
+    }
+}
+
+/// Runs the partitioner's table pass one page at a time.
+struct TableScanner {
+    config: PartitionConfig,
+    text: TextExtractor,
+    /// Cell-granular text for ruled grids (paragraph reconstruction off).
+    raw_text: TextExtractor,
+    graphics: Option<GraphicsExtractor>,
+}
+
+impl TableScanner {
+    fn new(config: PartitionConfig) -> Self {
+        let options = PagePartitioner::default_options();
+        let mut raw_options = options.clone();
+        raw_options.reconstruct_paragraphs = false;
+        let graphics = config.prefer_ruling_tables.then(GraphicsExtractor::default);
+        Self {
+            config,
+            text: TextExtractor::with_options(options),
+            raw_text: TextExtractor::with_options(raw_options),
+            graphics,
+        }
+    }
+
+    fn page_tables<R: Read + Seek>(
+        &mut self,
+        document: &PdfDocument<R>,
+        page_index: u32,
+    ) -> Result<Vec<ExtractedTable>, LastError> {
+        let mut fragments = self
+            .text
+            .extract_from_page(document, page_index)
+            .map_err(|e| {
+                LastError::caused_by(
+                    format_args!("Failed to extract tables from page {}", page_index + 1),
+                    &e,
+                )
+                .at_page(page_index)
+            })?
+            .fragments;
+        match &self.config.reading_order {
+            ReadingOrderStrategy::Simple => SimpleReadingOrder::default().order(&mut fragments),
+            ReadingOrderStrategy::XYCut { min_gap } => {
+                XYCutReadingOrder::new(*min_gap).order(&mut fragments)
+            }
+            ReadingOrderStrategy::None => {}
+        }
+        let page_height = document
+            .get_page(page_index)
+            .map(|p| p.height())
+            .unwrap_or(842.0);
+        let mut claimed: Vec<bool> = fragments
+            .iter()
+            .map(|f| claimed_before_tables(f, &self.config, page_height))
+            .collect();
+        let mut claim = |x: f64, y: f64, width: f64, height: f64| {
+            for (i, f) in fragments.iter().enumerate() {
+                if f.x >= x - 1.0
+                    && f.x <= x + width + 1.0
+                    && f.y >= y - 1.0
+                    && f.y <= y + height + 1.0
+                {
+                    claimed[i] = true;
+                }
+            }
+        };
+        let mut tables = Vec::new();
+        let min_confidence = self.config.min_table_confidence;
+
+        let graphics = self
+            .graphics
+            .as_mut()
+            .and_then(|g| g.extract_from_page(document, page_index as usize).ok())
+            .filter(ExtractedGraphics::has_table_structure);
+        if let Some(graphics) = &graphics {
+            let raw = self.raw_text.extract_from_page(document, page_index).ok();
+            let ruling_fragments = raw.as_ref().map_or(&fragments, |r| &r.fragments);
+            let detected = TableDetector::default()
+                .detect(graphics, ruling_fragments)
+                .unwrap_or_default();
+            for table in detected.iter().filter(|t| t.confidence >= min_confidence) {
+                let cells = ruling_cells(table, graphics);
+                let b = &table.bbox;
+                tables.push(ExtractedTable {
+                    page_number: page_index + 1,
+                    x: b.x,
+                    y: b.y,
+                    width: b.width,
+                    height: b.height,
+                    confidence: table.confidence,
+                    source: TableSource::Ruling,
+                    row_count: table.rows,
+                    column_count: table.columns,
+                    header_rows: header_rows(&cells, table.rows, ruling_fragments),
+                    cells,
+                });
+                claim(b.x, b.y, b.width, b.height);
+            }
+        }
+
+        if self.config.detect_spatial_tables {
+            let unclaimed: Vec<&TextFragment> = fragments
+                .iter()
+                .zip(&claimed)
+                .filter(|(_, claimed)| !**claimed)
+                .map(|(f, _)| f)
+                .collect();
+            let detector = StructuredDataDetector::new(Default::default());
+            for region in table_regions(&unclaimed) {
+                if looks_like_list(&region) {
+                    continue;
+                }
+                let region: Vec<TextFragment> = region.into_iter().cloned().collect();
+                let Ok(result) = detector.detect(&region) else {
+                    continue;
+                };
+                for table in result
+                    .tables
+                    .iter()
+                    .filter(|t| t.confidence >= min_confidence)
+                {
+                    let cells = spatial_cells(table);
+                    let b = &table.bounding_box;
+                    let row_count = table.row_count();
+                    tables.push(ExtractedTable {
+                        page_number: page_index + 1,
+                        x: b.x,
+                        y: b.y,
+                        width: b.width,
+                        height: b.height,
+                        confidence: table.confidence,
+                        source: TableSource::Spatial,
+                        row_count,
+                        column_count: table.column_count(),
+                        header_rows: header_rows(&cells, row_count, &region),
+                        cells,
+                    });
+                }
+            }
+        }
+        Ok(tables)
+    }
+}
+
+/// Every table of the document, in page order.
+fn document_tables<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    config: PartitionConfig,
+) -> Result<Vec<ExtractedTable>, c_int> {
+    let page_count = document.page_count().map_err(|e| {
+        set_last_error(LastError::caused_by("Failed to get page count", &e));
+        ErrorCode::PdfParseError as c_int
+    })?;
+    let mut tables = Vec::new();
+    if !config.detect_tables {
+        return Ok(tables);
+    }
+    let mut scanner = TableScanner::new(config);
+    for page_index in 0..page_count {
+        Progress::NONE.check()?;
+        let page = scanner.page_tables(document, page_index).map_err(|e| {
+            set_last_error(e);
+            ErrorCode::PdfParseError as c_int
+        })?;
+        tables.extend(page);
+    }
+    Ok(tables)
+}
+
+// ── Serializers ──────────────────────────────────────────────────────────────
+
+/// `table_json` of the serializers: one element of `oxidize_extract_tables`
+/// output, or any object with this grid shape (geometry is ignored).
+#[derive(Debug, Deserialize)]
+pub struct TableGrid {
+    row_count: usize,
+    column_count: usize,
+    #[serde(default)]
+    header_rows: usize,
+    cells: Vec<GridCell>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct GridCell {
+    row: usize,
+    column: usize,
+    #[serde(default = "one")]
+    row_span: usize,
+    #[serde(default = "one")]
+    col_span: usize,
+    text: String,
+}
+
+fn one() -> usize {
+    1
+}
+
+impl ValidatedConfig for TableGrid {
+    fn check(f: &mut Fields<'_>) {
+        f.count("row_count", 0);
+        f.count("column_count", 0);
+        f.optional_count("header_rows", 0);
+        f.items("cells", |cell| {
+            cell.count("row", 0);
+            cell.count("column", 0);
+            cell.optional_count("row_span", 1);
+            cell.optional_count("col_span", 1);
+            cell.string("text", true);
+        });
+    }
+}
+
+/// What occupies one grid position.
+#[derive(Clone, Copy)]
+enum Slot {
+    Empty,
+    /// Top-left position of `cells[i]`.
+    Origin(usize),
+    /// Covered by a span.
+    Covered,
+}
+
+impl TableGrid {
+    /// Place every cell on the grid, rejecting cells outside it or
+    /// overlapping another.
+    fn layout(&self) -> Result<Vec<Vec<Slot>>, String> {
+        if self.header_rows > self.row_count {
+            return Err(format!(
+                "header_rows ({}) exceeds row_count ({})",
+                self.header_rows, self.row_count
+            ));
+        }
+        match self.row_count.checked_mul(self.column_count) {
+            Some(positions) if positions <= MAX_GRID_POSITIONS => {}
+            _ => {
+                return Err(format!(
+                    "the {} x {} grid exceeds {MAX_GRID_POSITIONS} positions",
+                    self.row_count, self.column_count
+                ))
+            }
+        }
+        let mut slots = vec![vec![Slot::Empty; self.column_count]; self.row_count];
+        for (i, cell) in self.cells.iter().enumerate() {
+            let (Some(row_end), Some(col_end)) = (
+                cell.row.checked_add(cell.row_span),
+                cell.column.checked_add(cell.col_span),
+            ) else {
+                return Err(format!("cells[{i}] span overflows the grid"));
+            };
+            let (rows, cols) = (cell.row..row_end, cell.column..col_end);
+            if rows.end > self.row_count || cols.end > self.column_count {
+                return Err(format!(
+                    "cells[{i}] at row {}, column {} does not fit the {} x {} grid",
+                    cell.row, cell.column, self.row_count, self.column_count
+                ));
+            }
+            for r in rows {
+                for c in cols.clone() {
+                    if !matches!(slots[r][c], Slot::Empty) {
+                        return Err(format!(
+                            "cells[{i}] overlaps another cell at row {r}, column {c}"
+                        ));
+                    }
+                    slots[r][c] = if (r, c) == (cell.row, cell.column) {
+                        Slot::Origin(i)
+                    } else {
+                        Slot::Covered
+                    };
+                }
+            }
+        }
+        Ok(slots)
+    }
+
+    fn text(&self, slot: Slot) -> &str {
+        match slot {
+            Slot::Origin(i) => &self.cells[i].text,
+            Slot::Empty | Slot::Covered => "",
+        }
+    }
+}
+
+/// RFC 4180: one CRLF-terminated line per row; a merged cell's text sits at
+/// its top-left position and the positions it covers are empty.
+fn render_csv(table: &TableGrid, slots: &[Vec<Slot>]) -> String {
+    let mut out = String::new();
+    for row in slots {
+        let fields: Vec<String> = row
+            .iter()
+            .map(|&slot| {
+                let text = table.text(slot);
+                if text.contains([',', '"', '\r', '\n']) {
+                    format!("\"{}\"", text.replace('"', "\"\""))
+                } else {
+                    text.to_owned()
+                }
+            })
+            .collect();
+        out.push_str(&fields.join(","));
+        out.push_str("\r\n");
+    }
+    out
+}
+
+/// GitHub-flavoured Markdown. The first header row becomes the table header
+/// (blank when no header was detected); spans are flattened as in CSV.
+fn render_markdown(table: &TableGrid, slots: &[Vec<Slot>]) -> String {
+    if table.column_count == 0 {
+        return String::new();
+    }
+    let line = |cells: Vec<String>| format!("| {} |\n", cells.join(" | "));
+    let cells = |row: &[Slot]| -> Vec<String> {
+        row.iter()
+            .map(|&slot| {
+                table
+                    .text(slot)
+                    .replace('|', "\\|")
+                    .replace("\r\n", "<br>")
+                    .replace('\n', "<br>")
+            })
+            .collect()
+    };
+    let (header, body) = if table.header_rows > 0 {
+        (cells(&slots[0]), &slots[1..])
+    } else {
+        (vec![String::new(); table.column_count], slots)
+    };
+    let mut out = line(header);
+    out.push_str(&line(vec!["---".into(); table.column_count]));
+    for row in body {
+        out.push_str(&line(cells(row)));
+    }
+    out
+}
+
+fn escape_html(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// An HTML `<table>`: header rows in `<thead>` as `<th>`, the rest in
+/// `<tbody>`, spans as `rowspan` / `colspan`.
+fn render_html(table: &TableGrid, slots: &[Vec<Slot>]) -> String {
+    let mut out = String::from("<table>\n");
+    let sections = [
+        ("thead", "th", &slots[..table.header_rows]),
+        ("tbody", "td", &slots[table.header_rows..]),
+    ];
+    for (section, tag, rows) in sections {
+        if rows.is_empty() {
+            continue;
+        }
+        let _ = writeln!(out, "<{section}>");
+        for row in rows {
+            out.push_str("<tr>");
+            for &slot in row {
+                match slot {
+                    Slot::Covered => {}
+                    Slot::Empty => {
+                        let _ = write!(out, "<{tag}></{tag}>");
+                    }
+                    Slot::Origin(i) => {
+                        let cell = &table.cells[i];
+                        let _ = write!(out, "<{tag}");
+                        if cell.row_span > 1 {
+                            let _ = write!(out, " rowspan=\"{}\"", cell.row_span);
+                        }
+                        if cell.col_span > 1 {
+                            let _ = write!(out, " colspan=\"{}\"", cell.col_span);
+                        }
+                        let _ = write!(out, ">{}</{tag}>", escape_html(&cell.text));
+                    }
+                }
+            }
+            out.push_str("</tr>\n");
+        }
+        let _ = writeln!(out, "</{section}>");
+    }
+    out.push_str("</table>\n");
+    out
+}
+
+/// Body of the three serializer entry points.
+unsafe fn render_table(
+    table_json: *const c_char,
+    out_text: *mut *mut c_char,
+    function: &str,
+    render: fn(&TableGrid, &[Vec<Slot>]) -> String,
+) -> c_int {
+    clear_last_error();
+    if table_json.is_null() || out_text.is_null() {
+        set_last_error(format!("Null pointer provided to {function}"));
+        return ErrorCode::NullPointer as c_int;
+    }
+    *out_text = ptr::null_mut();
+
+    let table: TableGrid = match parse_config_arg(table_json, "table_json", "TableGrid") {
+        Ok(t) => t,
+        Err(code) => return code,
+    };
+    let slots = match table.layout() {
+        Ok(s) => s,
+        Err(message) => {
+            set_last_error(LastError::new(
+                ErrorCategory::InvalidArgument,
+                format!("invalid TableGrid in table_json: {message}"),
+            ));
+            return ErrorCode::InvalidArgument as c_int;
+        }
+    };
+    match CString::new(render(&table, &slots)) {
+        Ok(s) => {
+            *out_text = s.into_raw();
+            ErrorCode::Success as c_int
+        }
+        Err(e) => {
+            set_last_error(LastError::caused_by("Table text contains null bytes", &e));
+            ErrorCode::InvalidUtf8 as c_int
+        }
+    }
+}
+
+// ── FFI ──────────────────────────────────────────────────────────────────────
+
+/// Body of [`oxidize_extract_tables`] over an already-opened document.
+pub(crate) unsafe fn extract_tables_impl<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    config: PartitionConfig,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    let tables = match document_tables(document, config) {
+        Ok(t) => t,
+        Err(code) => return code,
+    };
+    let json = match serde_json::to_string(&tables) {
+        Ok(j) => j,
+        Err(e) => {
+            set_last_error(LastError::caused_by("Failed to serialize tables", &e));
+            return ErrorCode::SerializationError as c_int;
+        }
+    };
+    match CString::new(json) {
+        Ok(s) => {
+            *out_json = s.into_raw();
+            ErrorCode::Success as c_int
+        }
+        Err(e) => {
+            set_last_error(LastError::caused_by("Tables JSON contains null bytes", &e));
+            ErrorCode::InvalidUtf8 as c_int
+        }
+    }
+}
+
+/// Extract every detected table as a grid of cells.
+///
+/// `partition_config_json` is an optional `PartitionConfig` (as for
+/// `oxidize_partition_with_config`; `NULL` = default) whose
+/// `detect_tables`, `prefer_ruling_tables`, `detect_spatial_tables`,
+/// `min_table_confidence`, header/footer zones and reading order apply, so
+/// the tables found are those `oxidize_partition` reports.
+///
+/// `*out_json` receives a JSON array of tables in page order. Each has
+/// `page_number` (1-based), the table box `x`, `y`, `width`, `height`,
+/// `confidence`, `source` (`"ruling"` or `"spatial"`), `row_count`,
+/// `column_count`, `header_rows` and `cells`: row-major objects with `row`,
+/// `column` (0-based, top-left first), `row_span`, `col_span`, `text` and the
+/// cell box. Grid positions covered by a merged cell have no entry.
+///
+/// # Returns
+/// `Success`; `NullPointer`; `PdfParseError` (`pdf_len == 0`, parse
+/// failure); `InvalidUtf8` / `InvalidArgument` (bad config);
+/// `LimitExceeded`; `SerializationError`.
+///
+/// # Safety
+/// - `pdf_bytes` must point to `pdf_len` readable bytes.
+/// - `partition_config_json`, if non-null, must be a NUL-terminated UTF-8
+///   C string.
+/// - `out_json` must be a writeable `*mut *mut c_char`; free the result with
+///   `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_extract_tables(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    partition_config_json: *const c_char,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if pdf_bytes.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_extract_tables");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+
+        if pdf_len == 0 {
+            set_last_error("PDF data is empty (0 bytes)");
+            return ErrorCode::PdfParseError as c_int;
+        }
+        let config = match parse_partition_config_opt(partition_config_json) {
+            Ok(c) => c,
+            Err(code) => return code,
+        };
+
+        let bytes = slice::from_raw_parts(pdf_bytes, pdf_len);
+        let reader = match open_lenient(bytes) {
+            Ok(r) => r,
+            Err(e) => {
+                set_last_error(e);
+                return ErrorCode::PdfParseError as c_int;
+            }
+        };
+        let document = PdfDocument::new(reader);
+        extract_tables_impl(&document, config, out_json)
+    })
+}
+
+/// Render one table (an element of [`oxidize_extract_tables`] output) as
+/// RFC 4180 CSV: one CRLF-terminated line per grid row, quoted where needed.
+/// A merged cell's text is placed at its top-left position.
+///
+/// # Returns
+/// `Success`; `NullPointer`; `InvalidUtf8`; `InvalidArgument` for invalid
+/// JSON, grids over a million positions, or cells outside the grid or
+/// overlapping each other.
+///
+/// # Safety
+/// - `table_json` must be a NUL-terminated UTF-8 C string.
+/// - `out_text` must be a writeable `*mut *mut c_char`; free the result with
+///   `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_table_to_csv(
+    table_json: *const c_char,
+    out_text: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || render_table(table_json, out_text, "oxidize_table_to_csv", render_csv))
+}
+
+/// Render one table as a GitHub-flavoured Markdown table. The first header
+/// row is the Markdown header (blank cells when `header_rows` is 0); merged
+/// cells are flattened as in [`oxidize_table_to_csv`].
+///
+/// # Returns
+/// As [`oxidize_table_to_csv`].
+///
+/// # Safety
+/// As [`oxidize_table_to_csv`].
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_table_to_markdown(
+    table_json: *const c_char,
+    out_text: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        render_table(
+            table_json,
+            out_text,
+            "oxidize_table_to_markdown",
+            render_markdown,
+        )
+    })
+}
+
+/// Render one table as an HTML `<table>`: header rows in `<thead>` with
+/// `<th>` cells, merged cells with `rowspan` / `colspan`, text escaped.
+///
+/// # Returns
+/// As [`oxidize_table_to_csv`].
+///
+/// # Safety
+/// As [`oxidize_table_to_csv`].
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_table_to_html(
+    table_json: *const c_char,
+    out_text: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        render_table(table_json, out_text, "oxidize_table_to_html", render_html)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::SamplePdf;
+    use oxidize_pdf::graphics::Color;
+    use oxidize_pdf::{Document, Font, Page};
+    use serde_json::{json, Value};
+    use std::ffi::CStr;
+
+    /// A ruled 4 × 3 grid whose bold header cell "Region" spans two columns,
+    /// over a numeric column.
+    fn ruled_pdf() -> Vec<u8> {
+        let mut doc = Document::new();
+        let mut page = Page::a4();
+        page.text()
+            .set_font(Font::Helvetica, 12.0)
+            .at(50.0, 700.0)
+            .write("Quarterly results by region.")
+            .unwrap();
+        let graphics = page.graphics();
+        graphics
+            .set_stroke_color(Color::black())
+            .set_line_width(1.0);
+        for row in 0..5 {
+            let y = 600.0 - row as f64 * 20.0;
+            graphics.move_to(50.0, y).line_to(350.0, y);
+        }
+        for x in [50.0, 250.0, 350.0] {
+            graphics.move_to(x, 600.0).line_to(x, 520.0);
+        }
+        // No line between the first two columns in the header row.
+        graphics.move_to(150.0, 580.0).line_to(150.0, 520.0);
+        graphics.stroke();
+
+        let cell = |page: &mut Page, font, col: f64, row: f64, text: &str| {
+            page.text()
+                .set_font(font, 10.0)
+                .at(55.0 + col * 100.0, 585.0 - row * 20.0)
+                .write(text)
+                .unwrap();
+        };
+        cell(&mut page, Font::HelveticaBold, 0.0, 0.0, "Region");
+        cell(&mut page, Font::HelveticaBold, 2.0, 0.0, "Sales");
+        for (row, (a, b, sales)) in [
+            ("North", "A", "1,200"),
+            ("South", "B", "950"),
+            ("East", "C", "87.5"),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let row = row as f64 + 1.0;
+            cell(&mut page, Font::Helvetica, 0.0, row, a);
+            cell(&mut page, Font::Helvetica, 1.0, row, b);
+            cell(&mut page, Font::Helvetica, 2.0, row, sales);
+        }
+        doc.add_page(page);
+        doc.to_bytes().unwrap()
+    }
+
+    fn extract(pdf: &[u8], config: Option<&str>) -> Result<Vec<Value>, c_int> {
+        let config = config.map(|c| CString::new(c).unwrap());
+        let config_ptr = config.as_ref().map_or(ptr::null(), |c| c.as_ptr());
+        let mut out = ptr::null_mut();
+        let code = unsafe { oxidize_extract_tables(pdf.as_ptr(), pdf.len(), config_ptr, &mut out) };
+        if code != ErrorCode::Success as c_int {
+            assert!(out.is_null());
+            return Err(code);
+        }
+        let json = unsafe { CStr::from_ptr(out) }.to_str().unwrap().to_owned();
+        unsafe { crate::oxidize_free_string(out) };
+        Ok(serde_json::from_str(&json).unwrap())
+    }
+
+    type Render = unsafe extern "C" fn(*const c_char, *mut *mut c_char) -> c_int;
+
+    fn render(entry: Render, table: &Value) -> Result<String, c_int> {
+        let json = CString::new(table.to_string()).unwrap();
+        let mut out = ptr::null_mut();
+        match unsafe { entry(json.as_ptr(), &mut out) } {
+            0 => {
+                let text = unsafe { CStr::from_ptr(out) }.to_str().unwrap().to_owned();
+                unsafe { crate::oxidize_free_string(out) };
+                Ok(text)
+            }
+            code => Err(code),
+        }
+    }
+
+    #[test]
+    fn ruled_table_keeps_cells_spans_and_header() {
+        let tables = extract(&ruled_pdf(), None).unwrap();
+        assert_eq!(tables.len(), 1, "{tables:?}");
+        let table = &tables[0];
+        assert_eq!(table["source"], "ruling");
+        assert_eq!(table["page_number"], 1);
+        assert_eq!(
+            (table["row_count"].as_u64(), table["column_count"].as_u64()),
+            (Some(4), Some(3))
+        );
+        assert_eq!(table["header_rows"], 1);
+
+        let cells = table["cells"].as_array().unwrap();
+        assert_eq!(cells.len(), 11);
+        let region = &cells[0];
+        assert_eq!(region["text"], "Region");
+        assert_eq!(
+            (region["row_span"].as_u64(), region["col_span"].as_u64()),
+            (Some(1), Some(2))
+        );
+        assert!((region["width"].as_f64().unwrap() - 200.0).abs() < 1.0);
+        assert_eq!(cells[1]["column"], 2);
+        assert_eq!(cells[1]["text"], "Sales");
+        let sales = cells
+            .iter()
+            .find(|c| c["row"] == 1 && c["column"] == 2)
+            .unwrap();
+        assert_eq!(sales["text"], "1,200");
+        assert!((sales["y"].as_f64().unwrap() - 560.0).abs() < 1.0);
+
+        let config = r#"{"detect_tables": false, "detect_headers_footers": true,
+            "title_min_font_ratio": 1.3, "header_zone": 0.05, "footer_zone": 0.05,
+            "reading_order": "Simple", "min_table_confidence": 0.5}"#;
+        assert_eq!(extract(&ruled_pdf(), Some(config)).unwrap().len(), 0);
+    }
+
+    /// Draw a borderless 5 × 3 table of aligned text. Columns are written
+    /// one after the other so the extractor keeps every cell a fragment of
+    /// its own instead of joining each row into one line.
+    fn draw_spatial_table(page: &mut Page) {
+        let rows = [
+            ["Item", "Qty", "Price"],
+            ["Apples", "12", "3.50"],
+            ["Pears", "7", "2.25"],
+            ["Plums", "30", "9.00"],
+            ["Figs", "4", "6.75"],
+        ];
+        for col in 0..3 {
+            for (row, cells) in rows.iter().enumerate() {
+                page.text()
+                    .set_font(Font::Helvetica, 10.0)
+                    .at(60.0 + col as f64 * 120.0, 600.0 - row as f64 * 28.0)
+                    .write(cells[col])
+                    .unwrap();
+            }
+        }
+    }
+
+    /// `(page_number, x, y, width, height)` of every `Table` element
+    /// `oxidize_partition_with_config` reports.
+    fn partition_tables(pdf: &[u8], config: &str) -> Vec<(u64, [f64; 4])> {
+        let config = CString::new(config).unwrap();
+        let mut out = ptr::null_mut();
+        let code = unsafe {
+            crate::parser::oxidize_partition_with_config(
+                pdf.as_ptr(),
+                pdf.len(),
+                config.as_ptr(),
+                &mut out,
+            )
+        };
+        assert_eq!(code, ErrorCode::Success as c_int);
+        let json = unsafe { CStr::from_ptr(out) }.to_str().unwrap().to_owned();
+        unsafe { crate::oxidize_free_string(out) };
+        let elements: Vec<Value> = serde_json::from_str(&json).unwrap();
+        elements
+            .iter()
+            .filter(|e| e["element_type"] == "table")
+            .map(|e| {
+                let f = |key: &str| e[key].as_f64().unwrap();
+                let page = e["page_number"].as_u64().unwrap();
+                (page, [f("x"), f("y"), f("width"), f("height")])
+            })
+            .collect()
+    }
+
+    #[test]
+    fn tables_match_partition_table_elements() {
+        let spatial = |pages| {
+            SamplePdf::new(pages).lines(2).decorate(|page, i| {
+                if i == 0 {
+                    draw_spatial_table(page)
+                }
+            })
+        };
+        let fixtures = [
+            ("ruled grid", ruled_pdf(), vec!["ruling"]),
+            (
+                "ruled grid after text pages",
+                SamplePdf::new(3).ruled_table_on(1).build(),
+                vec!["ruling"],
+            ),
+            ("borderless table", spatial(1).build(), vec!["spatial"]),
+            (
+                "borderless and ruled tables",
+                spatial(2).ruled_table_on(1).build(),
+                vec!["spatial", "ruling"],
+            ),
+        ];
+        let config = |overrides: Value| {
+            let mut config = json!({"detect_tables": true, "detect_headers_footers": true,
+                "title_min_font_ratio": 1.3, "header_zone": 0.05, "footer_zone": 0.05,
+                "reading_order": "Simple", "min_table_confidence": 0.5});
+            for (key, value) in overrides.as_object().unwrap() {
+                config[key] = value.clone();
+            }
+            config.to_string()
+        };
+        let configs = [
+            config(json!({})),
+            config(json!({"prefer_ruling_tables": false})),
+            config(json!({"detect_spatial_tables": false})),
+            config(json!({"reading_order": {"XYCut": {"min_gap": 20.0}},
+                "min_table_confidence": 0.3})),
+        ];
+        for (name, pdf, sources) in fixtures {
+            for (i, config) in configs.iter().enumerate() {
+                let config = config.as_str();
+                let extracted = extract(&pdf, Some(config)).unwrap();
+                if i == 0 {
+                    let found: Vec<_> = extracted.iter().map(|t| &t["source"]).collect();
+                    assert_eq!(found, sources, "{name}");
+                }
+                let tables: Vec<(u64, [f64; 4])> = extracted
+                    .iter()
+                    .map(|t| {
+                        let f = |key: &str| t[key].as_f64().unwrap();
+                        let page = t["page_number"].as_u64().unwrap();
+                        (page, [f("x"), f("y"), f("width"), f("height")])
+                    })
+                    .collect();
+                assert_eq!(
+                    tables,
+                    partition_tables(&pdf, config),
+                    "{name} with {config}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn serializers_render_grid() {
+        let table = json!({
+            "row_count": 3,
+            "column_count": 3,
+            "header_rows": 1,
+            "cells": [
+                {"row": 0, "column": 0, "col_span": 2, "text": "Name"},
+                {"row": 0, "column": 2, "text": "Note"},
+                {"row": 1, "column": 0, "row_span": 2, "text": "A|B"},
+                {"row": 1, "column": 1, "text": "x"},
+                {"row": 1, "column": 2, "text": "say \"hi\", <b>"},
+                {"row": 2, "column": 1, "text": "y"}
+            ]
+        });
+        assert_eq!(
+            render(oxidize_table_to_csv, &table).unwrap(),
+            "Name,,Note\r\nA|B,x,\"say \"\"hi\"\", <b>\"\r\n,y,\r\n"
+        );
+        assert_eq!(
+            render(oxidize_table_to_markdown, &table).unwrap(),
+            "| Name |  | Note |\n| --- | --- | --- |\n| A\\|B | x | say \"hi\", <b> |\n|  | y |  |\n"
+        );
+        assert_eq!(
+            render(oxidize_table_to_html, &table).unwrap(),
+            "<table>\n<thead>\n<tr><th colspan=\"2\">Name</th><th>Note</th></tr>\n</thead>\n\
+             <tbody>\n<tr><td rowspan=\"2\">A|B</td><td>x</td><td>say &quot;hi&quot;, &lt;b&gt;</td></tr>\n\
+             <tr><td>y</td><td></td></tr>\n</tbody>\n</table>\n"
+        );
+    }
+
+    #[test]
+    fn invalid_tables_are_rejected() {
+        let invalid = Err(ErrorCode::InvalidArgument as c_int);
+        let overlap = json!({"row_count": 1, "column_count": 2, "cells": [
+            {"row": 0, "column": 0, "col_span": 2, "text": "a"},
+            {"row": 0, "column": 1, "text": "b"}
+        ]});
+        assert_eq!(render(oxidize_table_to_csv, &overlap), invalid);
+        let mut err = ptr::null_mut();
+        unsafe { crate::oxidize_get_last_error(&mut err) };
+        let message = unsafe { CStr::from_ptr(err) }.to_str().unwrap().to_owned();
+        unsafe { crate::oxidize_free_string(err) };
+        assert!(message.contains("cells[1] overlaps"), "{message}");
+
+        let outside = json!({"row_count": 1, "column_count": 1, "cells": [{"row": 1, "column": 0, "text": ""}]});
+        assert_eq!(render(oxidize_table_to_html, &outside), invalid);
+        let missing = json!({"row_count": 1, "cells": [{"row": 0, "column": 0, "row_span": 0}]});
+        assert_eq!(render(oxidize_table_to_markdown, &missing), invalid);
+        let huge =
+            json!({"row_count": 100_000_000u64, "column_count": 100_000_000u64, "cells": []});
+        assert_eq!(render(oxidize_table_to_csv, &huge), invalid);
+        let overflow = json!({"row_count": 2, "column_count": 2, "cells": [
+            {"row": usize::MAX, "column": 0, "row_span": 2, "text": ""}
+        ]});
+        assert_eq!(render(oxidize_table_to_csv, &overflow), invalid);
+
+        let code = unsafe { oxidize_table_to_csv(ptr::null(), ptr::null_mut()) };
+        assert_eq!(code, ErrorCode::NullPointer as c_int);
+        assert_eq!(extract(&[], None), Err(ErrorCode::PdfParseError as c_int));
+    }
+}
@@ -6,21 +6,34 @@
 //! document is serialized the writer emits `/StructTreeRoot`, `/MarkInfo
 //! <</Marked true>>`, and the `/StructElem` dictionaries (ISO 32000-1 §14.7-14.8),
 //! producing a Tagged PDF — the basis for PDF/UA accessibility.
+//!
+//! The read direction, `oxidize_get_struct_tree`, walks the `/StructTreeRoot`
+//! of a parsed PDF and returns it in the same JSON shape, so a tree can be
+//! audited or copied onto a new document. Any non-empty output is accepted
+//! back as input: role-map chains are resolved to a standard type on read.
 
-use std::ffi::CStr;
+use std::collections::{BTreeMap, HashSet};
+use std::ffi::{CStr, CString};
+use std::io::{Read, Seek};
 use std::os::raw::{c_char, c_int};
+use std::ptr;
+use std::slice;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
+use oxidize_pdf::parser::{PdfDictionary, PdfDocument, PdfObject};
 use oxidize_pdf::structure::{StandardStructureType, StructTree, StructureElement};
 
 use crate::document::DocumentHandle;
+use crate::error_detail::LastError;
+use crate::limits;
+use crate::navigation::{open_with_catalog, require_catalog, text_string, Navigator};
 use crate::{clear_last_error, set_last_error, ErrorCode};
 
 /// A marked-content reference linking a structure element to tagged content on
 /// a page (the MCID returned by `oxidize_page_begin_marked_content`).
-#[derive(Debug, Deserialize)]
-struct McidDto {
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct McidDto {
     page: usize,
     mcid: u32,
 }
@@ -28,8 +41,8 @@ struct McidDto {
 /// One structure element. `parent` is the zero-based index of the parent in the
 /// `elements` array; the single element with `parent == null` is the root.
 /// Parents must appear before their children in the array.
-#[derive(Debug, Deserialize)]
-struct StructElementDto {
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct StructElementDto {
     /// PDF structure type name, e.g. "Document", "H1", "P", "Figure". Unknown
     /// names become custom structure types (and should be role-mapped).
     #[serde(rename = "type")]
@@ -45,12 +58,12 @@ struct StructElementDto {
 }
 
 /// Top-level structure-tree description. `role_map` maps custom structure type
-/// names to standard PDF structure type names (e.g. {"Sidebar": "Aside"}).
-#[derive(Debug, Deserialize)]
-struct StructTreeDto {
+/// names to standard PDF structure type names (e.g. {"Sidebar": "Note"}).
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct StructTreeDto {
     elements: Vec<StructElementDto>,
     #[serde(default)]
-    role_map: std::collections::HashMap<String, String>,
+    role_map: BTreeMap<String, String>,
 }
 
 fn build_element(dto: &StructElementDto) -> StructureElement {
@@ -141,7 +154,7 @@ fn build_struct_tree(dto: &StructTreeDto) -> Result<StructTree, String> {
 ///     { "type": "P", "parent": 0, "lang": "en-US", "alt_text": "...",
 ///       "mcids": [ { "page": 0, "mcid": 0 } ] }
 ///   ],
-///   "role_map": { "Sidebar": "Aside" }
+///   "role_map": { "Sidebar": "Note" }
 /// }
 /// ```
 ///
@@ -185,11 +198,268 @@ pub unsafe extern "C" fn oxidize_document_set_struct_tree_json(
     })
 }
 
+// ── Reading ──────────────────────────────────────────────────────────────────
+
+/// Read the `/StructTreeRoot` of a parsed document. Elements are listed
+/// depth-first, so every parent precedes its children.
+fn read_struct_tree<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    catalog: &PdfDictionary,
+) -> Result<StructTreeDto, LastError> {
+    let mut nav = Navigator::new(document, catalog);
+    let mut tree = StructTreeDto {
+        elements: Vec::new(),
+        role_map: BTreeMap::new(),
+    };
+    let Some(root) = nav.dict_entry(catalog, "StructTreeRoot") else {
+        return Ok(tree);
+    };
+    if let Some(role_map) = nav.dict_entry(&root, "RoleMap") {
+        let mut entries = BTreeMap::new();
+        for (custom, target) in role_map.0 {
+            if let Some(PdfObject::Name(target)) = nav.resolve(&target) {
+                entries.insert(custom.0, target.0);
+            }
+        }
+        tree.role_map = standard_role_map(&entries);
+    }
+
+    // The JSON shape has a single root: several top-level elements are
+    // placed under a synthetic `Document` element.
+    let top = kids(&nav, root.get("K"));
+    let mut stack: Vec<(PdfObject, Option<usize>)> = Vec::new();
+    if top.len() > 1 {
+        tree.elements.push(element("Document".to_string(), None));
+        stack.extend(top.into_iter().rev().map(|kid| (kid, Some(0))));
+    } else {
+        stack.extend(top.into_iter().map(|kid| (kid, None)));
+    }
+
+    let mut visited = HashSet::new();
+    while let Some((obj, parent)) = stack.pop() {
+        limits::check_deadline()?;
+        if let Some(r) = obj.as_reference() {
+            if !visited.insert(r) {
+                continue;
+            }
+        }
+        let Some(PdfObject::Dictionary(dict)) = nav.resolve(&obj) else {
+            continue;
+        };
+        let Some(type_name) = dict
+            .get("S")
+            .and_then(|s| nav.resolve(s))
+            .and_then(|s| s.as_name().map(|n| n.0.clone()))
+        else {
+            continue;
+        };
+        let index = tree.elements.len();
+        let mut elem = element(type_name, parent);
+        let text = |key: &str| {
+            dict.get(key)
+                .and_then(|v| nav.resolve(v))
+                .and_then(|v| v.as_string().map(text_string))
+        };
+        elem.id = text("ID");
+        elem.lang = text("Lang");
+        elem.alt_text = text("Alt");
+        elem.actual_text = text("ActualText");
+        elem.title = text("T");
+
+        let default_page = match dict.get("Pg") {
+            Some(pg) => nav.page_index(pg)?,
+            None => None,
+        };
+        let mut children = Vec::new();
+        for kid in kids(&nav, dict.get("K")) {
+            match nav.resolve(&kid) {
+                Some(PdfObject::Integer(mcid)) => {
+                    push_mcid(&mut elem, default_page, mcid);
+                }
+                // A marked-content reference (`/Type /MCR`, often omitted).
+                Some(PdfObject::Dictionary(kid_dict)) if kid_dict.contains_key("MCID") => {
+                    let page = match kid_dict.get("Pg") {
+                        Some(pg) => nav.page_index(pg)?,
+                        None => default_page,
+                    };
+                    if let Some(mcid) = kid_dict
+                        .get("MCID")
+                        .and_then(|m| nav.resolve(m))
+                        .and_then(|m| m.as_integer())
+                    {
+                        push_mcid(&mut elem, page, mcid);
+                    }
+                }
+                // Object references (annotations, forms) have no place in the
+                // JSON shape.
+                Some(PdfObject::Dictionary(kid_dict)) if kid_dict.get_type() == Some("OBJR") => {}
+                Some(PdfObject::Dictionary(_)) => children.push(kid),
+                _ => {}
+            }
+        }
+        tree.elements.push(elem);
+        stack.extend(children.into_iter().rev().map(|kid| (kid, Some(index))));
+    }
+    Ok(tree)
+}
+
+/// Map every custom type to the standard type its role-map chain ends at
+/// (`Sidebar → Box → Note` becomes `Sidebar → Note`). Entries that end at a
+/// type `StandardStructureType` does not know, or loop, are dropped.
+fn standard_role_map(entries: &BTreeMap<String, String>) -> BTreeMap<String, String> {
+    entries
+        .keys()
+        .filter_map(|custom| {
+            let mut target = &entries[custom];
+            for _ in 0..entries.len() {
+                if StandardStructureType::from_pdf_name(target).is_some() {
+                    return Some((custom.clone(), target.clone()));
+                }
+                target = entries.get(target)?;
+            }
+            None
+        })
+        .collect()
+}
+
+/// The entries of a `/K` value, which may be a single object or an array.
+fn kids<R: Read + Seek>(nav: &Navigator<'_, R>, k: Option<&PdfObject>) -> Vec<PdfObject> {
+    let Some(k) = k else {
+        return Vec::new();
+    };
+    match k {
+        PdfObject::Array(items) => items.0.clone(),
+        PdfObject::Reference(..) => match nav.resolve(k) {
+            Some(PdfObject::Array(items)) => items.0,
+            _ => vec![k.clone()],
+        },
+        other => vec![other.clone()],
+    }
+}
+
+fn element(type_name: String, parent: Option<usize>) -> StructElementDto {
+    StructElementDto {
+        type_name,
+        parent,
+        id: None,
+        lang: None,
+        alt_text: None,
+        actual_text: None,
+        title: None,
+        mcids: Vec::new(),
+    }
+}
+
+/// Record a marked-content reference; one whose page cannot be resolved is
+/// dropped, since the JSON shape requires a page.
+fn push_mcid(elem: &mut StructElementDto, page: Option<u32>, mcid: i64) {
+    if let (Some(page), Ok(mcid)) = (page, u32::try_from(mcid)) {
+        elem.mcids.push(McidDto {
+            page: page as usize,
+            mcid,
+        });
+    }
+}
+
+/// Body of [`oxidize_get_struct_tree`] over an already-opened document.
+pub(crate) unsafe fn get_struct_tree_impl<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    catalog: Option<&PdfDictionary>,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    let tree = match require_catalog(catalog, "the structure tree")
+        .and_then(|catalog| read_struct_tree(document, catalog))
+    {
+        Ok(t) => t,
+        Err(e) => {
+            set_last_error(e);
+            return ErrorCode::PdfParseError as c_int;
+        }
+    };
+    let json = match serde_json::to_string(&tree) {
+        Ok(j) => j,
+        Err(e) => {
+            set_last_error(LastError::caused_by(
+                "Failed to serialize structure tree",
+                &e,
+            ));
+            return ErrorCode::SerializationError as c_int;
+        }
+    };
+    match CString::new(json) {
+        Ok(s) => {
+            *out_json = s.into_raw();
+            ErrorCode::Success as c_int
+        }
+        Err(e) => {
+            set_last_error(LastError::caused_by(
+                "Structure tree JSON contains null bytes",
+                &e,
+            ));
+            ErrorCode::InvalidUtf8 as c_int
+        }
+    }
+}
+
+/// Read the logical structure tree of a tagged PDF in the JSON shape
+/// [`oxidize_document_set_struct_tree_json`] accepts: `elements` in
+/// depth-first order (each with `type`, `parent`, `id`, `lang`, `alt_text`,
+/// `actual_text`, `title` and `mcids` of `{page, mcid}` with 0-based pages)
+/// and `role_map`.
+///
+/// An untagged document, or a tree without structure elements, yields
+/// `"elements": []`, which `oxidize_document_set_struct_tree_json` rejects:
+/// there is no tree to copy. Several top-level elements are wrapped in a
+/// synthetic `Document` root; object references (`/OBJR`) and marked-content
+/// references whose page cannot be resolved are omitted.
+///
+/// Role-map chains are followed to the standard type they end at, so
+/// `{"Sidebar": "Box", "Box": "Note"}` is read as `{"Box": "Note",
+/// "Sidebar": "Note"}`. Entries ending at a type the library does not know
+/// as standard (such as the PDF 2.0 `Aside`), or that loop, are omitted.
+///
+/// # Returns
+/// `Success`; `NullPointer`; `PdfParseError` (`pdf_len == 0`, parse
+/// failure, no catalog); `LimitExceeded`; `SerializationError`.
+///
+/// # Safety
+/// - `pdf_bytes` must point to `pdf_len` readable bytes.
+/// - `out_json` must be a writeable `*mut *mut c_char`; free the result with
+///   `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_get_struct_tree(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if pdf_bytes.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_get_struct_tree");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+
+        if pdf_len == 0 {
+            set_last_error("PDF data is empty (0 bytes)");
+            return ErrorCode::PdfParseError as c_int;
+        }
+        let bytes = slice::from_raw_parts(pdf_bytes, pdf_len);
+        let (document, catalog) = match open_with_catalog(bytes) {
+            Ok(opened) => opened,
+            Err(e) => {
+                set_last_error(e);
+                return ErrorCode::PdfParseError as c_int;
+            }
+        };
+        get_struct_tree_impl(&document, catalog.as_ref(), out_json)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::document::{oxidize_document_create, oxidize_document_free};
-    use std::ffi::CString;
 
     #[test]
     fn set_struct_tree_emits_struct_tree_root_and_elements() {
@@ -292,4 +562,131 @@ mod tests {
             oxidize_document_free(doc);
         }
     }
+
+    fn tagged_page(tag: &str) -> oxidize_pdf::Page {
+        let mut page = oxidize_pdf::Page::a4();
+        page.begin_marked_content(tag).unwrap();
+        page.text()
+            .set_font(oxidize_pdf::text::Font::Helvetica, 12.0)
+            .at(72.0, 700.0)
+            .write("TAGGED")
+            .unwrap();
+        page.end_marked_content().unwrap();
+        page
+    }
+
+    unsafe fn get_struct_tree(pdf: &[u8]) -> Result<serde_json::Value, c_int> {
+        let mut out: *mut c_char = std::ptr::null_mut();
+        let code = oxidize_get_struct_tree(pdf.as_ptr(), pdf.len(), &mut out);
+        if code != ErrorCode::Success as c_int {
+            return Err(code);
+        }
+        let json = CStr::from_ptr(out).to_str().unwrap().to_owned();
+        crate::oxidize_free_string(out);
+        Ok(serde_json::from_str(&json).unwrap())
+    }
+
+    #[test]
+    fn get_struct_tree_round_trips_set_struct_tree_json() {
+        unsafe {
+            let doc = oxidize_document_create();
+            (*doc).inner.add_page(tagged_page("H1"));
+            (*doc).inner.add_page(tagged_page("P"));
+            let expected = serde_json::json!({
+                "elements": [
+                    { "type": "Document", "parent": null, "id": null, "lang": "en-US",
+                      "alt_text": null, "actual_text": null, "title": null, "mcids": [] },
+                    { "type": "H1", "parent": 0, "id": null, "lang": null,
+                      "alt_text": null, "actual_text": null, "title": "Intro",
+                      "mcids": [ { "page": 0, "mcid": 0 } ] },
+                    { "type": "Sidebar", "parent": 0, "id": "side-1", "lang": null,
+                      "alt_text": "Side note", "actual_text": null, "title": null, "mcids": [] },
+                    { "type": "P", "parent": 2, "id": null, "lang": "fr",
+                      "alt_text": null, "actual_text": "TAGGED", "title": null,
+                      "mcids": [ { "page": 1, "mcid": 0 } ] }
+                ],
+                "role_map": { "Sidebar": "Note" }
+            });
+            let json = CString::new(expected.to_string()).unwrap();
+            assert_eq!(oxidize_document_set_struct_tree_json(doc, json.as_ptr()), 0);
+            let pdf = (*doc).inner.to_bytes().unwrap();
+            oxidize_document_free(doc);
+
+            let tree = get_struct_tree(&pdf).unwrap();
+            assert_eq!(tree, expected);
+
+            // The output is accepted as input again.
+            let copy = oxidize_document_create();
+            let json = CString::new(tree.to_string()).unwrap();
+            assert_eq!(
+                oxidize_document_set_struct_tree_json(copy, json.as_ptr()),
+                0
+            );
+            oxidize_document_free(copy);
+        }
+    }
+
+    #[test]
+    fn get_struct_tree_of_untagged_document_is_empty() {
+        unsafe {
+            let mut doc = oxidize_pdf::Document::new();
+            doc.add_page(oxidize_pdf::Page::a4());
+            let tree = get_struct_tree(&doc.to_bytes().unwrap()).unwrap();
+            assert_eq!(tree, serde_json::json!({ "elements": [], "role_map": {} }));
+            // Documented: an empty tree is not accepted back.
+            let copy = oxidize_document_create();
+            let json = CString::new(tree.to_string()).unwrap();
+            assert_eq!(
+                oxidize_document_set_struct_tree_json(copy, json.as_ptr()),
+                ErrorCode::InvalidArgument as c_int
+            );
+            oxidize_document_free(copy);
+            assert_eq!(
+                get_struct_tree(&[]).unwrap_err(),
+                ErrorCode::PdfParseError as c_int
+            );
+        }
+    }
+
+    #[test]
+    fn get_struct_tree_resolves_role_map_chains() {
+        let pdf = crate::test_fixtures::raw_pdf(&[
+            b"<< /Type /Catalog /Pages 2 0 R /StructTreeRoot 4 0 R >>".to_vec(),
+            b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_vec(),
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] >>".to_vec(),
+            b"<< /Type /StructTreeRoot /K 5 0 R /RoleMap << /Sidebar /Box /Box /Note \
+              /Callout /Aside /Ping /Pong /Pong /Ping >> >>"
+                .to_vec(),
+            b"<< /Type /StructElem /S /Sidebar /P 4 0 R /K [6 0 R] >>".to_vec(),
+            b"<< /Type /StructElem /S /Callout /P 5 0 R >>".to_vec(),
+        ]);
+        unsafe {
+            let tree = get_struct_tree(&pdf).unwrap();
+            let types: Vec<_> = tree["elements"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|e| (e["type"].clone(), e["parent"].clone()))
+                .collect();
+            assert_eq!(
+                types,
+                vec![
+                    ("Sidebar".into(), serde_json::Value::Null),
+                    ("Callout".into(), 0.into())
+                ]
+            );
+            assert_eq!(
+                tree["role_map"],
+                serde_json::json!({ "Box": "Note", "Sidebar": "Note" })
+            );
+
+            let copy = oxidize_document_create();
+            let json = CString::new(tree.to_string()).unwrap();
+            assert_eq!(
+                oxidize_document_set_struct_tree_json(copy, json.as_ptr()),
+                0
+            );
+            oxidize_document_free(copy);
+        }
+    }
 }
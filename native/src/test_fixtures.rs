@@ -0,0 +1,198 @@
+//! Generated PDFs shared by the unit tests.
+//!
+//! [`sample_pdf`] covers the common case; [`SamplePdf`] adds a document
+//! title, encryption, a ruled table or extra page content on top of it.
+//! [`raw_pdf`] assembles hand-written objects the writer cannot produce.
+
+use oxidize_pdf::graphics::Color;
+use oxidize_pdf::{Document, Font, Page};
+
+/// Extra per-page drawing, see [`SamplePdf::decorate`].
+type Decorate = Box<dyn Fn(&mut Page, usize)>;
+
+/// Owner password of every encrypted fixture.
+pub(crate) const OWNER_PASSWORD: &str = "owner-secret";
+
+/// Held by tests that turn process-wide strict parsing on and by tests that
+/// rely on lenient parsing repairing a damaged file.
+pub(crate) static STRICT_PARSING: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+/// Take [`STRICT_PARSING`], ignoring poisoning by a failed test.
+pub(crate) fn strict_parsing_lock() -> std::sync::MutexGuard<'static, ()> {
+    STRICT_PARSING
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+/// Builder for a test PDF of A4 pages.
+///
+/// Page `n` (1-based) carries a bold heading `"{heading} {n}"` and `lines`
+/// body lines `"Page {n} line {l} carries enough words to fill a paragraph."`.
+pub(crate) struct SamplePdf {
+    pages: usize,
+    heading: Option<String>,
+    lines: usize,
+    title: Option<String>,
+    password: Option<String>,
+    ruled_table_on: Option<usize>,
+    decorate: Option<Decorate>,
+}
+
+impl SamplePdf {
+    /// `pages` pages with a `"Section {n}"` heading and four body lines each.
+    pub(crate) fn new(pages: usize) -> Self {
+        Self {
+            pages,
+            heading: Some("Section".to_string()),
+            lines: 4,
+            title: None,
+            password: None,
+            ruled_table_on: None,
+            decorate: None,
+        }
+    }
+
+    /// `pages` empty pages, for fixtures that draw everything themselves.
+    pub(crate) fn blank(pages: usize) -> Self {
+        Self {
+            heading: None,
+            lines: 0,
+            ..Self::new(pages)
+        }
+    }
+
+    /// Replace the `"Section"` heading prefix.
+    pub(crate) fn heading(mut self, heading: impl Into<String>) -> Self {
+        self.heading = Some(heading.into());
+        self
+    }
+
+    /// Number of body lines per page.
+    pub(crate) fn lines(mut self, lines: usize) -> Self {
+        self.lines = lines;
+        self
+    }
+
+    /// Set the document info title.
+    pub(crate) fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Encrypt with `password` as the user password and [`OWNER_PASSWORD`].
+    pub(crate) fn encrypted(mut self, password: impl Into<String>) -> Self {
+        self.password = Some(password.into());
+        self
+    }
+
+    /// Draw a ruled 3×3 table with cell text `r{row}c{col}` on page `page`
+    /// (0-based), below the body lines.
+    pub(crate) fn ruled_table_on(mut self, page: usize) -> Self {
+        self.ruled_table_on = Some(page);
+        self
+    }
+
+    /// Extra drawing for each page, called with the 0-based page index after
+    /// the heading and body lines.
+    pub(crate) fn decorate(mut self, decorate: impl Fn(&mut Page, usize) + 'static) -> Self {
+        self.decorate = Some(Box::new(decorate));
+        self
+    }
+
+    pub(crate) fn build(self) -> Vec<u8> {
+        let mut doc = Document::new();
+        if let Some(title) = &self.title {
+            doc.set_title(title);
+        }
+        for p in 0..self.pages {
+            let mut page = Page::a4();
+            if let Some(heading) = &self.heading {
+                page.text()
+                    .set_font(Font::HelveticaBold, 18.0)
+                    .at(50.0, 780.0)
+                    .write(&format!("{heading} {}", p + 1))
+                    .unwrap();
+            }
+            for line in 0..self.lines {
+                page.text()
+                    .set_font(Font::Helvetica, 11.0)
+                    .at(50.0, 740.0 - line as f64 * 14.0)
+                    .write(&format!(
+                        "Page {} line {} carries enough words to fill a paragraph.",
+                        p + 1,
+                        line + 1
+                    ))
+                    .unwrap();
+            }
+            if self.ruled_table_on == Some(p) {
+                draw_ruled_table(&mut page);
+            }
+            if let Some(decorate) = &self.decorate {
+                decorate(&mut page, p);
+            }
+            doc.add_page(page);
+        }
+        if let Some(password) = &self.password {
+            doc.encrypt_with_passwords(password, OWNER_PASSWORD);
+        }
+        doc.to_bytes().unwrap()
+    }
+}
+
+/// A plain `pages`-page fixture: `SamplePdf::new(pages).build()`.
+pub(crate) fn sample_pdf(pages: usize) -> Vec<u8> {
+    SamplePdf::new(pages).build()
+}
+
+/// A PDF whose object `n` (1-based) is `objects[n - 1]`, with `1 0 R` as
+/// the catalog and a classic cross-reference table.
+pub(crate) fn raw_pdf(objects: &[Vec<u8>]) -> Vec<u8> {
+    let mut out = b"%PDF-1.4\n".to_vec();
+    let mut offsets = Vec::new();
+    for (i, body) in objects.iter().enumerate() {
+        offsets.push(out.len());
+        out.extend_from_slice(format!("{} 0 obj\n", i + 1).as_bytes());
+        out.extend_from_slice(body);
+        out.extend_from_slice(b"\nendobj\n");
+    }
+    let xref = out.len();
+    out.extend_from_slice(
+        format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).as_bytes(),
+    );
+    for offset in offsets {
+        out.extend_from_slice(format!("{offset:010} 00000 n \n").as_bytes());
+    }
+    out.extend_from_slice(
+        format!(
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref}\n%%EOF\n",
+            objects.len() + 1
+        )
+        .as_bytes(),
+    );
+    out
+}
+
+fn draw_ruled_table(page: &mut Page) {
+    let graphics = page.graphics();
+    graphics
+        .set_stroke_color(Color::black())
+        .set_line_width(1.0);
+    for row in 0..4 {
+        let y = 500.0 - row as f64 * 20.0;
+        graphics.move_to(50.0, y).line_to(350.0, y);
+    }
+    for col in 0..4 {
+        let x = 50.0 + col as f64 * 100.0;
+        graphics.move_to(x, 500.0).line_to(x, 440.0);
+    }
+    graphics.stroke();
+    for row in 0..3 {
+        for col in 0..3 {
+            page.text()
+                .set_font(Font::Helvetica, 9.0)
+                .at(55.0 + col as f64 * 100.0, 485.0 - row as f64 * 20.0)
+                .write(&format!("r{row}c{col}"))
+                .unwrap();
+        }
+    }
+}
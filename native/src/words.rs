@@ -0,0 +1,1437 @@
+//! Word- and glyph-level text extraction with geometry and style.
+//!
+//! The upstream extractor reports one fragment per text-showing operator and
+//! drops the rendering mode and orientation, so `oxidize_extract_words`
+//! interprets the page's content stream (and the form XObjects it paints)
+//! itself. Every glyph is placed with the full text rendering matrix, so
+//! boxes and rotation are exact for rotated, scaled and skewed text.
+//!
+//! Character codes decode through the font's `ToUnicode` CMap, else (simple
+//! fonts) its `/Differences` glyph names and base encoding, WinAnsi when none
+//! is given; a composite-font code without a `ToUnicode` entry becomes
+//! U+FFFD. Composite-font strings split into codes by the codespace ranges
+//! of the `/Encoding` CMap, whose CID ranges select the widths: an embedded
+//! CMap, `Identity-H` / `-V` (two-byte codes), the `Uni*-UCS2` / `UTF16`
+//! CMaps (UTF-16BE, decoded as such without `ToUnicode`) or one of the CJK
+//! CMaps the core library vendors (`90ms-RKSJ-H`, `90pv-RKSJ-H`,
+//! `GBK-EUC-H`, `GBKp-EUC-H`, `KSCms-UHC-H`). A page using any other
+//! composite encoding, or a Unicode CMap together with per-CID `/W` widths,
+//! fails with `Unsupported` rather than returning mis-split words. Glyph
+//! boxes span the advance width horizontally and the font descriptor's
+//! descent to ascent vertically (standard 14 metrics, else 0.8 / -0.2 em,
+//! when the descriptor has none).
+//!
+//! Words are runs of glyphs on one baseline and orientation, split at
+//! whitespace and at gaps wider than 0.2 em (upstream's `tj_space_threshold`
+//! default). Items are listed in content-stream order.
+
+use std::collections::HashMap;
+use std::ffi::CString;
+use std::io::{Read, Seek};
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+use std::slice;
+use std::sync::Arc;
+
+use oxidize_pdf::parser::{
+    ContentOperation, ContentParser, PdfArray, PdfDictionary, PdfDocument, PdfObject, TextElement,
+};
+use oxidize_pdf::text::cmap::{CMap, CodeRange};
+use oxidize_pdf::text::{Font, TextEncoding};
+use serde::Serialize;
+
+use crate::error_detail::{ErrorCategory, LastError};
+use crate::limits;
+use crate::parser::open_lenient;
+use crate::{clear_last_error, set_last_error, ErrorCode};
+
+/// Gap between glyphs, in em, above which a word ends.
+const WORD_GAP: f64 = 0.2;
+
+/// Deepest chain of nested form XObjects followed.
+const MAX_FORM_DEPTH: usize = 12;
+
+type Matrix = [f64; 6];
+
+const IDENTITY: Matrix = [1.0, 0.0, 0.0, 1.0, 0.0, 0.0];
+
+/// `a` then `b` (PDF row-vector convention: `a × b`).
+fn multiply(a: &Matrix, b: &Matrix) -> Matrix {
+    [
+        a[0] * b[0] + a[1] * b[2],
+        a[0] * b[1] + a[1] * b[3],
+        a[2] * b[0] + a[3] * b[2],
+        a[2] * b[1] + a[3] * b[3],
+        a[4] * b[0] + a[5] * b[2] + b[4],
+        a[4] * b[1] + a[5] * b[3] + b[5],
+    ]
+}
+
+fn transform(m: &Matrix, x: f64, y: f64) -> (f64, f64) {
+    (m[0] * x + m[2] * y + m[4], m[1] * x + m[3] * y + m[5])
+}
+
+fn translate(tx: f64, ty: f64) -> Matrix {
+    [1.0, 0.0, 0.0, 1.0, tx, ty]
+}
+
+/// A word or glyph as serialized into `oxidize_extract_words` output.
+#[derive(Debug, Clone, Serialize)]
+pub struct TextItem {
+    pub text: String,
+    /// Axis-aligned bounding box in user space (origin bottom-left).
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+    pub font_name: String,
+    /// Effective size: `Tf` size scaled by the text and graphics matrices.
+    pub font_size: f64,
+    /// Non-stroking colour as RGB in `[0, 1]`; `null` for pattern,
+    /// separation, indexed and other colour spaces.
+    pub fill_color: Option<[f64; 3]>,
+    /// Text rendering mode (`Tr`), 0–7.
+    pub render_mode: u8,
+    /// Baseline direction in degrees counter-clockwise from the x axis.
+    pub rotation: f64,
+}
+
+/// A placed glyph with the baseline points words are grouped by.
+struct Glyph {
+    item: TextItem,
+    origin: (f64, f64),
+    end: (f64, f64),
+}
+
+// ── Fonts ─────────────────────────────────────────────────────────────────────
+
+/// How a font's string bytes split into character codes.
+enum CodeSpace {
+    /// One byte per code: simple fonts.
+    OneByte,
+    /// `Identity-H` / `Identity-V`: two-byte codes that are their own CIDs.
+    Identity,
+    /// `Uni*-UCS2-*` / `Uni*-UTF16-*`: UTF-16BE code units (a surrogate pair
+    /// is one four-byte code with `surrogates`). Their code-to-CID mapping
+    /// is not available, so such fonts are only read when every glyph has
+    /// the default width.
+    Unicode { surrogates: bool },
+    /// An embedded or vendored predefined CMap: its codespace ranges and
+    /// code-to-CID ranges.
+    Embedded {
+        ranges: Vec<CodeRange>,
+        cids: Vec<CidRange>,
+    },
+}
+
+/// `first..=last` codes map to consecutive CIDs from `cid`.
+#[derive(Clone)]
+struct CidRange {
+    first: Vec<u8>,
+    last: Vec<u8>,
+    cid: u32,
+}
+
+/// What the interpreter needs from a font dictionary.
+struct FontModel {
+    name: String,
+    /// Composite (`Type0`) font: widths are by CID.
+    composite: bool,
+    codes: CodeSpace,
+    to_unicode: Option<CMap>,
+    differences: HashMap<u8, String>,
+    encoding: TextEncoding,
+    /// Glyph widths in glyph space, by code.
+    widths: HashMap<u32, f64>,
+    default_width: f64,
+    /// Glyph space to text space (0.001, or `FontMatrix[0]` for Type 3).
+    scale: f64,
+    ascent: f64,
+    descent: f64,
+}
+
+fn resolve_dict<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    object: Option<&PdfObject>,
+) -> Option<PdfDictionary> {
+    document.resolve(object?).ok()?.as_dict().cloned()
+}
+
+fn resolve_array<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    object: Option<&PdfObject>,
+) -> Option<PdfArray> {
+    document.resolve(object?).ok()?.as_array().cloned()
+}
+
+fn number<R: Read + Seek>(document: &PdfDocument<R>, object: Option<&PdfObject>) -> Option<f64> {
+    document.resolve(object?).ok()?.as_real()
+}
+
+fn name_of(dict: &PdfDictionary, key: &str) -> Option<String> {
+    dict.get(key)
+        .and_then(PdfObject::as_name)
+        .map(|n| n.0.clone())
+}
+
+/// Standard 14 font named `base` (subset prefix already removed).
+fn standard_font(base: &str) -> Option<Font> {
+    Some(match base {
+        "Helvetica" => Font::Helvetica,
+        "Helvetica-Bold" => Font::HelveticaBold,
+        "Helvetica-Oblique" => Font::HelveticaOblique,
+        "Helvetica-BoldOblique" => Font::HelveticaBoldOblique,
+        "Times-Roman" => Font::TimesRoman,
+        "Times-Bold" => Font::TimesBold,
+        "Times-Italic" => Font::TimesItalic,
+        "Times-BoldItalic" => Font::TimesBoldItalic,
+        "Courier" => Font::Courier,
+        "Courier-Bold" => Font::CourierBold,
+        "Courier-Oblique" => Font::CourierOblique,
+        "Courier-BoldOblique" => Font::CourierBoldOblique,
+        "Symbol" => Font::Symbol,
+        "ZapfDingbats" => Font::ZapfDingbats,
+        _ => return None,
+    })
+}
+
+/// Strip a subset tag (`ABCDEF+`) from a font name.
+fn strip_subset(name: &str) -> &str {
+    match name.split_once('+') {
+        Some((tag, rest)) if tag.len() == 6 && tag.bytes().all(|b| b.is_ascii_uppercase()) => rest,
+        _ => name,
+    }
+}
+
+/// Unicode for a `/Differences` glyph name: `uniXXXX`, single-character
+/// names and the common Latin punctuation and ligature names.
+fn glyph_name_to_unicode(name: &str) -> Option<String> {
+    if let Some(hex) = name.strip_prefix("uni").filter(|h| h.len() == 4) {
+        return u32::from_str_radix(hex, 16)
+            .ok()
+            .and_then(char::from_u32)
+            .map(String::from);
+    }
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(c.to_string());
+    }
+    let s = match name {
+        "space" => " ",
+        "exclam" => "!",
+        "quotedbl" => "\"",
+        "numbersign" => "#",
+        "dollar" => "$",
+        "percent" => "%",
+        "ampersand" => "&",
+        "quotesingle" => "'",
+        "quoteright" => "\u{2019}",
+        "quoteleft" => "\u{2018}",
+        "quotedblleft" => "\u{201C}",
+        "quotedblright" => "\u{201D}",
+        "parenleft" => "(",
+        "parenright" => ")",
+        "asterisk" => "*",
+        "plus" => "+",
+        "comma" => ",",
+        "hyphen" | "minus" => "-",
+        "endash" => "\u{2013}",
+        "emdash" => "\u{2014}",
+        "period" => ".",
+        "slash" => "/",
+        "zero" => "0",
+        "one" => "1",
+        "two" => "2",
+        "three" => "3",
+        "four" => "4",
+        "five" => "5",
+        "six" => "6",
+        "seven" => "7",
+        "eight" => "8",
+        "nine" => "9",
+        "colon" => ":",
+        "semicolon" => ";",
+        "less" => "<",
+        "equal" => "=",
+        "greater" => ">",
+        "question" => "?",
+        "at" => "@",
+        "bracketleft" => "[",
+        "backslash" => "\\",
+        "bracketright" => "]",
+        "underscore" => "_",
+        "braceleft" => "{",
+        "bar" => "|",
+        "braceright" => "}",
+        "bullet" => "\u{2022}",
+        "ellipsis" => "\u{2026}",
+        "fi" => "fi",
+        "fl" => "fl",
+        "ff" => "ff",
+        "ffi" => "ffi",
+        "ffl" => "ffl",
+        _ => return None,
+    };
+    Some(s.to_owned())
+}
+
+/// `/W` array of a CIDFont: `c [w1 w2 …]` and `c_first c_last w` runs.
+fn cid_widths<R: Read + Seek>(document: &PdfDocument<R>, w: &PdfArray) -> HashMap<u32, f64> {
+    let mut widths = HashMap::new();
+    let items = &w.0;
+    let mut i = 0;
+    while i + 1 < items.len() {
+        let Some(first) = number(document, items.get(i)) else {
+            break;
+        };
+        let first = first as u32;
+        if let Some(list) = resolve_array(document, items.get(i + 1)) {
+            for (k, width) in list.0.iter().enumerate() {
+                if let Some(width) = width.as_real() {
+                    widths.insert(first + k as u32, width);
+                }
+            }
+            i += 2;
+        } else {
+            let (Some(last), Some(width)) = (
+                number(document, items.get(i + 1)),
+                number(document, items.get(i + 2)),
+            ) else {
+                break;
+            };
+            // A range never spans more than the two-byte code space.
+            for code in first..=(last as u32).min(first.saturating_add(0xFFFF)) {
+                widths.insert(code, width);
+            }
+            i += 3;
+        }
+    }
+    widths
+}
+
+fn code_value(code: &[u8]) -> u32 {
+    code.iter().fold(0u32, |acc, &b| (acc << 8) | u32::from(b))
+}
+
+/// Length of the code at the start of `bytes`: the shortest prefix inside a
+/// codespace range, else the shortest range's length (ISO 32000-1 9.7.6.2).
+fn code_len(ranges: &[CodeRange], bytes: &[u8]) -> usize {
+    (1..=bytes.len().min(4))
+        .find(|&n| ranges.iter().any(|r| r.contains(&bytes[..n])))
+        .or_else(|| ranges.iter().map(|r| r.start.len()).min())
+        .unwrap_or(1)
+        .max(1)
+}
+
+/// Text of Adobe's predefined CMaps that the core library also vendors
+/// (BSD-3-Clause, see `cmap_resources/LICENSE.md`).
+fn vendored_cmap(name: &str) -> Option<&'static [u8]> {
+    Some(match name {
+        "90ms-RKSJ-H" => include_bytes!("cmap_resources/90ms-RKSJ-H"),
+        "90pv-RKSJ-H" => include_bytes!("cmap_resources/90pv-RKSJ-H"),
+        "GBK-EUC-H" => include_bytes!("cmap_resources/GBK-EUC-H"),
+        "GBKp-EUC-H" => include_bytes!("cmap_resources/GBKp-EUC-H"),
+        "KSCms-UHC-H" => include_bytes!("cmap_resources/KSCms-UHC-H"),
+        _ => return None,
+    })
+}
+
+/// Code space of the predefined CMap `name`.
+fn predefined_code_space(name: &str) -> Result<CodeSpace, String> {
+    if matches!(name, "Identity-H" | "Identity-V") {
+        return Ok(CodeSpace::Identity);
+    }
+    if name.starts_with("Uni") && (name.contains("UCS2") || name.contains("UTF16")) {
+        return Ok(CodeSpace::Unicode {
+            surrogates: name.contains("UTF16"),
+        });
+    }
+    let data = vendored_cmap(name).ok_or_else(|| format!("predefined CMap {name}"))?;
+    let cmap = CMap::parse(data).map_err(|e| format!("predefined CMap {name} ({e})"))?;
+    Ok(CodeSpace::Embedded {
+        ranges: cmap.codespace_ranges,
+        cids: cid_ranges(data),
+    })
+}
+
+/// Code space of a composite font's `/Encoding`, or what makes it
+/// unsupported.
+fn code_space<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    font: &PdfDictionary,
+) -> Result<CodeSpace, String> {
+    match font.get("Encoding").map(|e| document.resolve(e)) {
+        Some(Ok(PdfObject::Name(name))) => predefined_code_space(&name.0),
+        Some(Ok(PdfObject::Stream(stream))) => {
+            let data = stream
+                .decode(&document.options())
+                .map_err(|e| format!("undecodable /Encoding CMap ({e})"))?;
+            let cmap =
+                CMap::parse(&data).map_err(|e| format!("unparsable /Encoding CMap ({e})"))?;
+            let mut cids = cid_ranges(&data);
+            if !cmap.codespace_ranges.is_empty() {
+                return Ok(CodeSpace::Embedded {
+                    ranges: cmap.codespace_ranges,
+                    cids,
+                });
+            }
+            let parent = cmap
+                .inherited_predefined
+                .as_deref()
+                .ok_or("/Encoding CMap without codespace ranges")?;
+            // Entries of the embedded CMap take precedence over its parent's.
+            Ok(match predefined_code_space(parent)? {
+                CodeSpace::Embedded {
+                    ranges,
+                    cids: inherited,
+                } => {
+                    cids.extend(inherited);
+                    CodeSpace::Embedded { ranges, cids }
+                }
+                space => space,
+            })
+        }
+        _ => Err("missing /Encoding".to_owned()),
+    }
+}
+
+/// `begincidrange` and `begincidchar` entries of an embedded CMap.
+fn cid_ranges(data: &[u8]) -> Vec<CidRange> {
+    let text = String::from_utf8_lossy(data)
+        .replace('<', " <")
+        .replace('>', "> ");
+    let hex = |token: &str| -> Option<Vec<u8>> {
+        let digits = token.strip_prefix('<')?.strip_suffix('>')?;
+        (0..digits.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(digits.get(i..i + 2)?, 16).ok())
+            .collect()
+    };
+    let mut ranges = Vec::new();
+    for (begin, end, arity) in [
+        ("begincidrange", "endcidrange", 3),
+        ("begincidchar", "endcidchar", 2),
+    ] {
+        let mut rest = text.as_str();
+        while let Some(start) = rest.find(begin) {
+            let body = &rest[start + begin.len()..];
+            let stop = body.find(end).unwrap_or(body.len());
+            let tokens: Vec<&str> = body[..stop].split_whitespace().collect();
+            for entry in tokens.chunks_exact(arity) {
+                let (Some(first), Some(last), Ok(cid)) = (
+                    hex(entry[0]),
+                    hex(entry[arity - 2]),
+                    entry[arity - 1].parse::<u32>(),
+                ) else {
+                    continue;
+                };
+                if first.len() == last.len() {
+                    ranges.push(CidRange { first, last, cid });
+                }
+            }
+            rest = &body[stop..];
+        }
+    }
+    ranges
+}
+
+impl FontModel {
+    /// Fails with `Unsupported` for a composite font whose codes cannot be
+    /// split or whose widths cannot be looked up.
+    fn load<R: Read + Seek>(
+        document: &PdfDocument<R>,
+        font: &PdfDictionary,
+        key: &str,
+    ) -> Result<Self, LastError> {
+        let subtype = name_of(font, "Subtype").unwrap_or_default();
+        let base = name_of(font, "BaseFont");
+        let name = strip_subset(base.as_deref().unwrap_or(key)).to_owned();
+        let to_unicode = match font.get("ToUnicode").map(|o| document.resolve(o)) {
+            Some(Ok(PdfObject::Stream(s))) => s
+                .decode(&document.options())
+                .ok()
+                .and_then(|data| CMap::parse(&data).ok()),
+            _ => None,
+        };
+
+        let mut model = FontModel {
+            name,
+            composite: subtype == "Type0",
+            codes: CodeSpace::OneByte,
+            to_unicode,
+            differences: HashMap::new(),
+            encoding: TextEncoding::WinAnsiEncoding,
+            widths: HashMap::new(),
+            default_width: 0.0,
+            scale: 0.001,
+            ascent: 0.8,
+            descent: -0.2,
+        };
+
+        let descriptor = if model.composite {
+            let unsupported = |reason: &str| {
+                LastError::new(
+                    ErrorCategory::Unsupported,
+                    format!("Font /{key} ({}): {reason}", model.name),
+                )
+            };
+            model.codes = code_space(document, font)
+                .map_err(|reason| unsupported(&format!("unsupported {reason}")))?;
+            let descendant = resolve_array(document, font.get("DescendantFonts"))
+                .and_then(|fonts| resolve_dict(document, fonts.0.first()));
+            if let Some(cid_font) = &descendant {
+                model.default_width = number(document, cid_font.get("DW")).unwrap_or(1000.0);
+                if let Some(w) = resolve_array(document, cid_font.get("W")) {
+                    if let CodeSpace::Unicode { .. } = model.codes {
+                        return Err(unsupported(
+                            "/W widths are by CID and the Unicode CMap's CIDs are unknown",
+                        ));
+                    }
+                    model.widths = cid_widths(document, &w);
+                }
+            } else {
+                model.default_width = 1000.0;
+            }
+            descendant.and_then(|d| resolve_dict(document, d.get("FontDescriptor")))
+        } else {
+            model.load_simple_encoding(document, font);
+            let first = number(document, font.get("FirstChar")).unwrap_or(0.0) as u32;
+            if let Some(widths) = resolve_array(document, font.get("Widths")) {
+                for (k, width) in widths.0.iter().enumerate() {
+                    if let Some(width) = document.resolve(width).ok().and_then(|w| w.as_real()) {
+                        model.widths.insert(first + k as u32, width);
+                    }
+                }
+            }
+            if subtype == "Type3" {
+                if let Some(matrix) = resolve_array(document, font.get("FontMatrix")) {
+                    model.scale = matrix
+                        .0
+                        .first()
+                        .and_then(PdfObject::as_real)
+                        .unwrap_or(0.001);
+                }
+            }
+            resolve_dict(document, font.get("FontDescriptor"))
+        };
+
+        let standard = standard_font(&model.name).and_then(|f| f.get_metrics());
+        if let Some(metrics) = standard {
+            if model.widths.is_empty() {
+                model.widths = (0u32..256)
+                    .map(|code| (code, f64::from(metrics.widths[code as usize])))
+                    .collect();
+            }
+            model.default_width = f64::from(metrics.default_width);
+            model.ascent = f64::from(metrics.ascender) / 1000.0;
+            model.descent = f64::from(metrics.descender) / 1000.0;
+        }
+        if let Some(descriptor) = descriptor {
+            if let Some(missing) = number(document, descriptor.get("MissingWidth")) {
+                if !model.composite {
+                    model.default_width = missing;
+                }
+            }
+            let ascent = number(document, descriptor.get("Ascent"));
+            let descent = number(document, descriptor.get("Descent"));
+            if let (Some(ascent), Some(descent)) = (ascent, descent) {
+                if ascent > descent {
+                    model.ascent = ascent / 1000.0;
+                    model.descent = descent.min(0.0) / 1000.0;
+                }
+            }
+        }
+        Ok(model)
+    }
+
+    /// `/Encoding` of a simple font: base encoding and `/Differences`.
+    fn load_simple_encoding<R: Read + Seek>(
+        &mut self,
+        document: &PdfDocument<R>,
+        font: &PdfDictionary,
+    ) {
+        let encoding = font.get("Encoding").and_then(|e| document.resolve(e).ok());
+        let (base, differences) = match &encoding {
+            Some(PdfObject::Name(n)) => (Some(n.0.as_str()), None),
+            Some(PdfObject::Dictionary(d)) => (
+                d.get("BaseEncoding")
+                    .and_then(PdfObject::as_name)
+                    .map(|n| n.0.as_str()),
+                resolve_array(document, d.get("Differences")),
+            ),
+            _ => (None, None),
+        };
+        if base == Some("MacRomanEncoding") {
+            self.encoding = TextEncoding::MacRomanEncoding;
+        }
+        let mut code = 0u32;
+        for entry in differences.iter().flat_map(|d| d.0.iter()) {
+            match entry {
+                PdfObject::Integer(n) => code = *n as u32,
+                PdfObject::Name(glyph) => {
+                    if let Ok(byte) = u8::try_from(code) {
+                        self.differences.insert(byte, glyph.0.clone());
+                    }
+                    code += 1;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Split `bytes` into character codes, each with the key its width is
+    /// looked up by (the CID for composite fonts).
+    fn codes<'a>(&'a self, mut bytes: &'a [u8]) -> impl Iterator<Item = (u32, &'a [u8])> + 'a {
+        std::iter::from_fn(move || {
+            if bytes.is_empty() {
+                return None;
+            }
+            let len = match &self.codes {
+                CodeSpace::OneByte => 1,
+                CodeSpace::Identity => 2,
+                CodeSpace::Unicode { surrogates } => {
+                    if *surrogates && (0xD8..=0xDB).contains(&bytes[0]) {
+                        4
+                    } else {
+                        2
+                    }
+                }
+                CodeSpace::Embedded { ranges, .. } => code_len(ranges, bytes),
+            }
+            .min(bytes.len());
+            let (code, rest) = bytes.split_at(len);
+            bytes = rest;
+            let key = match &self.codes {
+                CodeSpace::Embedded { cids, .. } => cids
+                    .iter()
+                    .find(|r| r.first.len() == len && *r.first <= *code && *code <= *r.last)
+                    .map_or(0, |r| r.cid + (code_value(code) - code_value(&r.first))),
+                _ => code_value(code),
+            };
+            Some((key, code))
+        })
+    }
+
+    /// Advance width of `code` in text space units per unit font size.
+    fn width(&self, code: u32) -> f64 {
+        self.widths
+            .get(&code)
+            .copied()
+            .unwrap_or(self.default_width)
+            * self.scale
+    }
+
+    fn text(&self, code: u32, bytes: &[u8]) -> String {
+        if let Some(cmap) = &self.to_unicode {
+            if let Some(s) = cmap.map(bytes).and_then(|m| cmap.to_unicode(&m)) {
+                return s;
+            }
+        }
+        if let CodeSpace::Unicode { .. } = self.codes {
+            return char::decode_utf16(
+                bytes
+                    .chunks_exact(2)
+                    .map(|u| u16::from_be_bytes([u[0], u[1]])),
+            )
+            .map(|c| c.unwrap_or('\u{FFFD}'))
+            .collect();
+        }
+        if self.composite {
+            return '\u{FFFD}'.to_string();
+        }
+        let byte = code as u8;
+        if let Some(s) = self
+            .differences
+            .get(&byte)
+            .and_then(|g| glyph_name_to_unicode(g))
+        {
+            return s;
+        }
+        self.encoding.decode(&[byte])
+    }
+}
+
+// ── Interpreter ──────────────────────────────────────────────────────────────
+
+#[derive(Clone, Copy, PartialEq)]
+enum ColorSpace {
+    Gray,
+    Rgb,
+    Cmyk,
+    Other,
+}
+
+impl ColorSpace {
+    /// The colour a `cs` operator selects (the space's initial colour).
+    fn initial(self) -> Option<[f64; 3]> {
+        (self != ColorSpace::Other).then_some([0.0; 3])
+    }
+
+    fn color(self, components: &[f32]) -> Option<[f64; 3]> {
+        let c: Vec<f64> = components.iter().map(|&v| f64::from(v)).collect();
+        match (self, c.as_slice()) {
+            (ColorSpace::Gray, &[g]) => Some([g; 3]),
+            (ColorSpace::Rgb, &[r, g, b]) => Some([r, g, b]),
+            (ColorSpace::Cmyk, &[c, m, y, k]) => Some([
+                (1.0 - c) * (1.0 - k),
+                (1.0 - m) * (1.0 - k),
+                (1.0 - y) * (1.0 - k),
+            ]),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct GraphicsState {
+    ctm: Matrix,
+    fill: Option<[f64; 3]>,
+    fill_space: ColorSpace,
+    font: Option<Arc<FontModel>>,
+    font_size: f64,
+    char_spacing: f64,
+    word_spacing: f64,
+    /// `Tz` as a fraction (1.0 = 100 %).
+    horizontal_scaling: f64,
+    leading: f64,
+    rise: f64,
+    render_mode: u8,
+}
+
+impl GraphicsState {
+    fn new() -> Self {
+        Self {
+            ctm: IDENTITY,
+            fill: Some([0.0; 3]),
+            fill_space: ColorSpace::Gray,
+            font: None,
+            font_size: 0.0,
+            char_spacing: 0.0,
+            word_spacing: 0.0,
+            horizontal_scaling: 1.0,
+            leading: 0.0,
+            rise: 0.0,
+            render_mode: 0,
+        }
+    }
+}
+
+struct Interpreter<'a, R: Read + Seek> {
+    document: &'a PdfDocument<R>,
+    /// Fonts loaded through an indirect reference, shared across scopes.
+    fonts: HashMap<(u32, u16), Arc<FontModel>>,
+    glyphs: Vec<Glyph>,
+}
+
+impl<'a, R: Read + Seek> Interpreter<'a, R> {
+    /// The font `key` of `resources`, `None` when there is no such font.
+    fn font(
+        &mut self,
+        resources: Option<&PdfDictionary>,
+        key: &str,
+    ) -> Result<Option<Arc<FontModel>>, LastError> {
+        let Some(entry) = resources
+            .and_then(|r| resolve_dict(self.document, r.get("Font")))
+            .and_then(|fonts| fonts.get(key).cloned())
+        else {
+            return Ok(None);
+        };
+        let reference = entry.as_reference();
+        if let Some(font) = reference.and_then(|r| self.fonts.get(&r)) {
+            return Ok(Some(Arc::clone(font)));
+        }
+        let Some(dict) = resolve_dict(self.document, Some(&entry)) else {
+            return Ok(None);
+        };
+        let font = Arc::new(FontModel::load(self.document, &dict, key)?);
+        if let Some(reference) = reference {
+            self.fonts.insert(reference, Arc::clone(&font));
+        }
+        Ok(Some(font))
+    }
+
+    fn color_space(&self, resources: Option<&PdfDictionary>, name: &str) -> ColorSpace {
+        let family = |name: &str| match name {
+            "DeviceGray" | "CalGray" | "G" => ColorSpace::Gray,
+            "DeviceRGB" | "CalRGB" | "RGB" => ColorSpace::Rgb,
+            "DeviceCMYK" | "CMYK" => ColorSpace::Cmyk,
+            _ => ColorSpace::Other,
+        };
+        if family(name) != ColorSpace::Other {
+            return family(name);
+        }
+        let Some(spaces) = resources.and_then(|r| resolve_dict(self.document, r.get("ColorSpace")))
+        else {
+            return ColorSpace::Other;
+        };
+        match spaces.get(name).map(|s| self.document.resolve(s)) {
+            Some(Ok(PdfObject::Name(n))) => family(&n.0),
+            Some(Ok(PdfObject::Array(a))) => match a.0.first().and_then(PdfObject::as_name) {
+                Some(n) if n.0 == "ICCBased" => {
+                    let components = match a.0.get(1).map(|s| self.document.resolve(s)) {
+                        Some(Ok(PdfObject::Stream(s))) => {
+                            s.dict.get("N").and_then(PdfObject::as_integer)
+                        }
+                        _ => None,
+                    };
+                    match components {
+                        Some(1) => ColorSpace::Gray,
+                        Some(3) => ColorSpace::Rgb,
+                        Some(4) => ColorSpace::Cmyk,
+                        _ => ColorSpace::Other,
+                    }
+                }
+                Some(n) => family(&n.0),
+                None => ColorSpace::Other,
+            },
+            _ => ColorSpace::Other,
+        }
+    }
+
+    fn run(
+        &mut self,
+        operations: &[ContentOperation],
+        resources: Option<&PdfDictionary>,
+        mut gs: GraphicsState,
+        depth: usize,
+    ) -> Result<(), LastError> {
+        let mut saved = Vec::new();
+        let mut fonts: HashMap<&str, Option<Arc<FontModel>>> = HashMap::new();
+        let mut tm = IDENTITY;
+        let mut tlm = IDENTITY;
+        let next_line = |tx: f64, ty: f64, tm: &mut Matrix, tlm: &mut Matrix| {
+            *tlm = multiply(&translate(tx, ty), tlm);
+            *tm = *tlm;
+        };
+
+        for operation in operations {
+            match operation {
+                ContentOperation::SaveGraphicsState => saved.push(gs.clone()),
+                ContentOperation::RestoreGraphicsState => {
+                    if let Some(previous) = saved.pop() {
+                        gs = previous;
+                    }
+                }
+                &ContentOperation::SetTransformMatrix(a, b, c, d, e, f) => {
+                    let m = [a, b, c, d, e, f].map(f64::from);
+                    gs.ctm = multiply(&m, &gs.ctm);
+                }
+                ContentOperation::BeginText => {
+                    tm = IDENTITY;
+                    tlm = IDENTITY;
+                }
+                &ContentOperation::SetTextMatrix(a, b, c, d, e, f) => {
+                    tm = [a, b, c, d, e, f].map(f64::from);
+                    tlm = tm;
+                }
+                &ContentOperation::MoveText(tx, ty) => {
+                    next_line(f64::from(tx), f64::from(ty), &mut tm, &mut tlm)
+                }
+                &ContentOperation::MoveTextSetLeading(tx, ty) => {
+                    gs.leading = -f64::from(ty);
+                    next_line(f64::from(tx), f64::from(ty), &mut tm, &mut tlm);
+                }
+                ContentOperation::NextLine => next_line(0.0, -gs.leading, &mut tm, &mut tlm),
+                ContentOperation::ShowText(bytes) => self.show(bytes, &gs, &mut tm),
+                ContentOperation::ShowTextArray(elements) => {
+                    for element in elements {
+                        match element {
+                            TextElement::Text(bytes) => self.show(bytes, &gs, &mut tm),
+                            &TextElement::Spacing(adjust) => {
+                                let tx = -f64::from(adjust) / 1000.0
+                                    * gs.font_size
+                                    * gs.horizontal_scaling;
+                                tm = multiply(&translate(tx, 0.0), &tm);
+                            }
+                        }
+                    }
+                }
+                ContentOperation::NextLineShowText(bytes) => {
+                    next_line(0.0, -gs.leading, &mut tm, &mut tlm);
+                    self.show(bytes, &gs, &mut tm);
+                }
+                ContentOperation::SetSpacingNextLineShowText(word, char, bytes) => {
+                    gs.word_spacing = f64::from(*word);
+                    gs.char_spacing = f64::from(*char);
+                    next_line(0.0, -gs.leading, &mut tm, &mut tlm);
+                    self.show(bytes, &gs, &mut tm);
+                }
+                ContentOperation::SetFont(key, size) => {
+                    gs.font = match fonts.get(key.as_str()) {
+                        Some(font) => font.clone(),
+                        None => {
+                            let font = self.font(resources, key)?;
+                            fonts.insert(key, font.clone());
+                            font
+                        }
+                    };
+                    gs.font_size = f64::from(*size);
+                }
+                &ContentOperation::SetLeading(leading) => gs.leading = f64::from(leading),
+                &ContentOperation::SetCharSpacing(s) => gs.char_spacing = f64::from(s),
+                &ContentOperation::SetWordSpacing(s) => gs.word_spacing = f64::from(s),
+                &ContentOperation::SetHorizontalScaling(s) => {
+                    gs.horizontal_scaling = f64::from(s) / 100.0
+                }
+                &ContentOperation::SetTextRise(rise) => gs.rise = f64::from(rise),
+                &ContentOperation::SetTextRenderMode(mode) => {
+                    gs.render_mode = mode.clamp(0, 7) as u8
+                }
+                &ContentOperation::SetNonStrokingGray(g) => {
+                    gs.fill_space = ColorSpace::Gray;
+                    gs.fill = ColorSpace::Gray.color(&[g]);
+                }
+                &ContentOperation::SetNonStrokingRGB(r, g, b) => {
+                    gs.fill_space = ColorSpace::Rgb;
+                    gs.fill = ColorSpace::Rgb.color(&[r, g, b]);
+                }
+                &ContentOperation::SetNonStrokingCMYK(c, m, y, k) => {
+                    gs.fill_space = ColorSpace::Cmyk;
+                    gs.fill = ColorSpace::Cmyk.color(&[c, m, y, k]);
+                }
+                ContentOperation::SetNonStrokingColorSpace(name) => {
+                    gs.fill_space = self.color_space(resources, name);
+                    gs.fill = gs.fill_space.initial();
+                }
+                ContentOperation::SetNonStrokingColor(components) => {
+                    gs.fill = gs.fill_space.color(components);
+                }
+                ContentOperation::PaintXObject(name) if depth < MAX_FORM_DEPTH => {
+                    self.paint_form(resources, name, &gs, depth)?;
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Interpret the form XObject `name`, if it is one.
+    fn paint_form(
+        &mut self,
+        resources: Option<&PdfDictionary>,
+        name: &str,
+        gs: &GraphicsState,
+        depth: usize,
+    ) -> Result<(), LastError> {
+        let Some(xobjects) = resources.and_then(|r| resolve_dict(self.document, r.get("XObject")))
+        else {
+            return Ok(());
+        };
+        let Some(Ok(PdfObject::Stream(form))) =
+            xobjects.get(name).map(|x| self.document.resolve(x))
+        else {
+            return Ok(());
+        };
+        if name_of(&form.dict, "Subtype").as_deref() != Some("Form") {
+            return Ok(());
+        }
+        limits::check_deadline()?;
+        // An undecodable form is skipped, as the upstream extractor does.
+        let Some(operations) = form
+            .decode(&self.document.options())
+            .ok()
+            .and_then(|data| ContentParser::parse_content(&data).ok())
+        else {
+            return Ok(());
+        };
+        let matrix = resolve_array(self.document, form.dict.get("Matrix"))
+            .map(|m| {
+                m.0.iter()
+                    .filter_map(PdfObject::as_real)
+                    .collect::<Vec<_>>()
+            })
+            .and_then(|m| <Matrix>::try_from(m.as_slice()).ok())
+            .unwrap_or(IDENTITY);
+        let mut state = gs.clone();
+        state.ctm = multiply(&matrix, &gs.ctm);
+        let form_resources = resolve_dict(self.document, form.dict.get("Resources"));
+        self.run(
+            &operations,
+            form_resources.as_ref().or(resources),
+            state,
+            depth + 1,
+        )
+    }
+
+    /// Place every glyph of a string and advance the text matrix.
+    fn show(&mut self, bytes: &[u8], gs: &GraphicsState, tm: &mut Matrix) {
+        let Some(font) = gs.font.clone() else {
+            return;
+        };
+        let size = gs.font_size;
+        let scaling = gs.horizontal_scaling;
+        for (code, code_bytes) in font.codes(bytes) {
+            let advance = font.width(code);
+
+            let text_to_user = multiply(tm, &gs.ctm);
+            let glyph_to_user = multiply(
+                &[size * scaling, 0.0, 0.0, size, 0.0, gs.rise],
+                &text_to_user,
+            );
+            let corners = [
+                transform(&glyph_to_user, 0.0, font.descent),
+                transform(&glyph_to_user, advance, font.descent),
+                transform(&glyph_to_user, 0.0, font.ascent),
+                transform(&glyph_to_user, advance, font.ascent),
+            ];
+            let (x0, x1) = corners.iter().fold((f64::MAX, f64::MIN), |(lo, hi), p| {
+                (lo.min(p.0), hi.max(p.0))
+            });
+            let (y0, y1) = corners.iter().fold((f64::MAX, f64::MIN), |(lo, hi), p| {
+                (lo.min(p.1), hi.max(p.1))
+            });
+
+            self.glyphs.push(Glyph {
+                item: TextItem {
+                    text: font.text(code, code_bytes),
+                    x: x0,
+                    y: y0,
+                    width: x1 - x0,
+                    height: y1 - y0,
+                    font_name: font.name.clone(),
+                    font_size: size * text_to_user[2].hypot(text_to_user[3]),
+                    fill_color: gs.fill,
+                    render_mode: gs.render_mode,
+                    rotation: text_to_user[1]
+                        .atan2(text_to_user[0])
+                        .to_degrees()
+                        .rem_euclid(360.0),
+                },
+                origin: transform(&glyph_to_user, 0.0, 0.0),
+                end: transform(&glyph_to_user, advance, 0.0),
+            });
+
+            let word_spacing = if code_bytes == [b' '] {
+                gs.word_spacing
+            } else {
+                0.0
+            };
+            let tx = (advance * size + gs.char_spacing + word_spacing) * scaling;
+            *tm = multiply(&translate(tx, 0.0), tm);
+        }
+    }
+}
+
+/// Whether `next` continues the word whose last glyph is `last`.
+fn continues_word(last: &Glyph, next: &Glyph) -> bool {
+    let turn = (next.item.rotation - last.item.rotation).abs();
+    if turn.min(360.0 - turn) > 0.5 {
+        return false;
+    }
+    let (sin, cos) = last.item.rotation.to_radians().sin_cos();
+    let (dx, dy) = (next.origin.0 - last.end.0, next.origin.1 - last.end.1);
+    let along = dx * cos + dy * sin;
+    let across = dy * cos - dx * sin;
+    let size = last.item.font_size.max(next.item.font_size);
+    across.abs() <= 0.5 * size && along <= WORD_GAP * size && along >= -0.5 * size
+}
+
+/// Group glyphs into words.
+fn words(glyphs: Vec<Glyph>) -> Vec<TextItem> {
+    let mut words = Vec::new();
+    let mut current: Option<(TextItem, Glyph)> = None;
+    for glyph in glyphs {
+        if glyph.item.text.is_empty() {
+            continue;
+        }
+        if glyph.item.text.chars().all(char::is_whitespace) {
+            words.extend(current.take().map(|(word, _)| word));
+            continue;
+        }
+        match current.as_mut() {
+            Some((word, last)) if continues_word(last, &glyph) => {
+                let (x1, y1) = (word.x + word.width, word.y + word.height);
+                let g = &glyph.item;
+                word.x = word.x.min(g.x);
+                word.y = word.y.min(g.y);
+                word.width = x1.max(g.x + g.width) - word.x;
+                word.height = y1.max(g.y + g.height) - word.y;
+                word.text.push_str(&g.text);
+                *last = glyph;
+            }
+            _ => {
+                words.extend(current.take().map(|(word, _)| word));
+                current = Some((glyph.item.clone(), glyph));
+            }
+        }
+    }
+    words.extend(current.map(|(word, _)| word));
+    words
+}
+
+/// Place every glyph of page `page_index`.
+fn page_glyphs<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    page_index: u32,
+) -> Result<Vec<Glyph>, (ErrorCode, LastError)> {
+    let page_error = |context: &str, e: &(dyn std::error::Error + 'static)| {
+        (
+            ErrorCode::PdfParseError,
+            LastError::caused_by(format_args!("{context} {}", page_index + 1), e)
+                .at_page(page_index),
+        )
+    };
+    let page = document
+        .get_page(page_index)
+        .map_err(|e| page_error("Failed to get page", &e))?;
+    let content = document
+        .get_page_content_streams(&page)
+        .map_err(|e| page_error("Failed to read content of page", &e))?
+        .join(&b'\n');
+    let operations = ContentParser::parse_content(&content)
+        .map_err(|e| page_error("Failed to parse content of page", &e))?;
+
+    let mut interpreter = Interpreter {
+        document,
+        fonts: HashMap::new(),
+        glyphs: Vec::new(),
+    };
+    // `LimitExceeded` or `Unsupported`, narrowed from the error's category.
+    interpreter
+        .run(&operations, page.get_resources(), GraphicsState::new(), 0)
+        .map_err(|e| (ErrorCode::PdfParseError, e.at_page(page_index)))?;
+    Ok(interpreter.glyphs)
+}
+
+/// Words (or glyphs) of page `page_index`.
+pub(crate) fn page_text_items<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    page_index: u32,
+    glyphs: bool,
+) -> Result<Vec<TextItem>, (ErrorCode, LastError)> {
+    let placed = page_glyphs(document, page_index)?;
+    Ok(if glyphs {
+        placed.into_iter().map(|g| g.item).collect()
+    } else {
+        words(placed)
+    })
+}
+
+/// Body of [`oxidize_extract_words`] over an already-opened document.
+/// `page_number` must already be validated as >= 1.
+pub(crate) unsafe fn extract_words_impl<R: Read + Seek>(
+    document: &PdfDocument<R>,
+    page_number: usize,
+    glyphs: bool,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    let page_count = match document.page_count() {
+        Ok(n) => n as usize,
+        Err(e) => {
+            set_last_error(LastError::caused_by("Failed to get page count", &e));
+            return ErrorCode::PdfParseError as c_int;
+        }
+    };
+    if page_number > page_count {
+        set_last_error(format!(
+            "Page number {page_number} is out of range (PDF has {page_count} pages)"
+        ));
+        return ErrorCode::PageOutOfRange as c_int;
+    }
+
+    let items = match page_text_items(document, (page_number - 1) as u32, glyphs) {
+        Ok(items) => items,
+        Err((code, e)) => {
+            set_last_error(e);
+            return code as c_int;
+        }
+    };
+    let json = match serde_json::to_string(&items) {
+        Ok(j) => j,
+        Err(e) => {
+            set_last_error(LastError::caused_by("Failed to serialize words", &e));
+            return ErrorCode::SerializationError as c_int;
+        }
+    };
+    match CString::new(json) {
+        Ok(s) => {
+            *out_json = s.into_raw();
+            ErrorCode::Success as c_int
+        }
+        Err(e) => {
+            set_last_error(LastError::caused_by("Words JSON contains null bytes", &e));
+            ErrorCode::InvalidUtf8 as c_int
+        }
+    }
+}
+
+/// Extract the words, or every glyph, of one page with geometry and style.
+///
+/// `*out_json` receives a JSON array in content-stream order; each item has
+/// `text`, the user-space bounding box `x`, `y`, `width`, `height` (origin
+/// bottom-left, page rotation not applied), `font_name` (subset tag
+/// removed), the effective `font_size`, `fill_color` (`[r, g, b]` in
+/// `[0, 1]`, gray and CMYK converted; `null` for other colour spaces),
+/// `render_mode` (`Tr`, 0–7; 3 is invisible text such as an OCR layer) and
+/// `rotation` (baseline angle in degrees, counter-clockwise).
+///
+/// With `glyphs` zero, glyphs are grouped into words (whitespace is
+/// dropped) and a word takes the style of its first glyph. With `glyphs`
+/// non-zero, every glyph is returned, whitespace included; a ligature glyph
+/// may carry several characters.
+///
+/// # Returns
+/// `Success`; `NullPointer`; `PdfParseError` (`pdf_len == 0`, parse
+/// failure); `PageOutOfRange` (`page_number` is 0 or past the last page);
+/// `Unsupported` (a composite font's encoding CMap is not available, see
+/// the module docs); `LimitExceeded`; `SerializationError` / `InvalidUtf8`.
+///
+/// # Safety
+/// - `pdf_bytes` must point to `pdf_len` readable bytes.
+/// - `page_number` is 1-based (first page = 1).
+/// - `out_json` must be a writeable `*mut *mut c_char`; free the result with
+///   `oxidize_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn oxidize_extract_words(
+    pdf_bytes: *const u8,
+    pdf_len: usize,
+    page_number: usize,
+    glyphs: u8,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    crate::ffi_guard(move || {
+        clear_last_error();
+        if pdf_bytes.is_null() || out_json.is_null() {
+            set_last_error("Null pointer provided to oxidize_extract_words");
+            return ErrorCode::NullPointer as c_int;
+        }
+        *out_json = ptr::null_mut();
+
+        if pdf_len == 0 {
+            set_last_error("PDF data is empty (0 bytes)");
+            return ErrorCode::PdfParseError as c_int;
+        }
+        if page_number == 0 {
+            set_last_error("Page number must be >= 1 (1-based indexing)");
+            return ErrorCode::PageOutOfRange as c_int;
+        }
+
+        let bytes = slice::from_raw_parts(pdf_bytes, pdf_len);
+        let reader = match open_lenient(bytes) {
+            Ok(r) => r,
+            Err(e) => {
+                set_last_error(e);
+                return ErrorCode::PdfParseError as c_int;
+            }
+        };
+        let document = PdfDocument::new(reader);
+        extract_words_impl(&document, page_number, glyphs != 0, out_json)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::SamplePdf;
+    use oxidize_pdf::graphics::Color;
+    use oxidize_pdf::text::TextRenderingMode;
+    use serde_json::Value;
+    use std::ffi::CStr;
+
+    /// One page with plain, red fill-and-stroke and rotated text.
+    fn styled_pdf() -> Vec<u8> {
+        SamplePdf::blank(1)
+            .decorate(|page, _| {
+                page.text()
+                    .set_font(Font::Helvetica, 12.0)
+                    .at(50.0, 750.0)
+                    .write("Hello brave world")
+                    .unwrap();
+                page.text()
+                    .set_font(Font::TimesBold, 20.0)
+                    .set_fill_color(Color::rgb(1.0, 0.0, 0.0))
+                    .set_rendering_mode(TextRenderingMode::FillStroke)
+                    .at(50.0, 700.0)
+                    .write("Red")
+                    .unwrap();
+                page.graphics()
+                    .save_state()
+                    .transform(0.0, 1.0, -1.0, 0.0, 300.0, 300.0)
+                    .begin_text()
+                    .set_font(Font::Courier, 10.0)
+                    .set_text_position(0.0, 0.0)
+                    .show_text("Up")
+                    .unwrap()
+                    .end_text()
+                    .restore_state();
+            })
+            .build()
+    }
+
+    fn extract(pdf: &[u8], page_number: usize, glyphs: u8) -> Result<Vec<Value>, c_int> {
+        let mut out = ptr::null_mut();
+        let code = unsafe {
+            oxidize_extract_words(pdf.as_ptr(), pdf.len(), page_number, glyphs, &mut out)
+        };
+        if code != ErrorCode::Success as c_int {
+            assert!(out.is_null());
+            return Err(code);
+        }
+        let json = unsafe { CStr::from_ptr(out) }.to_str().unwrap().to_owned();
+        unsafe { crate::oxidize_free_string(out) };
+        Ok(serde_json::from_str(&json).unwrap())
+    }
+
+    fn close(a: &Value, b: f64) -> bool {
+        (a.as_f64().unwrap() - b).abs() < 0.01
+    }
+
+    #[test]
+    fn words_carry_geometry_and_style() {
+        let words = extract(&styled_pdf(), 1, 0).unwrap();
+        let texts: Vec<&str> = words.iter().map(|w| w["text"].as_str().unwrap()).collect();
+        assert_eq!(texts, ["Hello", "brave", "world", "Red", "Up"]);
+
+        let hello = &words[0];
+        assert!(close(&hello["x"], 50.0));
+        // Helvetica: H e l l o = 722 + 556 + 222 + 222 + 556 units.
+        assert!(close(&hello["width"], 2278.0 * 12.0 / 1000.0));
+        assert!(hello["y"].as_f64().unwrap() < 750.0);
+        assert_eq!(hello["font_name"], "Helvetica");
+        assert!(close(&hello["font_size"], 12.0));
+        assert_eq!(hello["fill_color"], serde_json::json!([0.0, 0.0, 0.0]));
+        assert_eq!(hello["render_mode"], 0);
+        assert!(close(&hello["rotation"], 0.0));
+        assert!(words[1]["x"].as_f64().unwrap() > 50.0 + 2278.0 * 12.0 / 1000.0);
+
+        let red = &words[3];
+        assert_eq!(red["font_name"], "Times-Bold");
+        assert_eq!(red["fill_color"], serde_json::json!([1.0, 0.0, 0.0]));
+        assert_eq!(red["render_mode"], 2);
+
+        let up = &words[4];
+        assert!(close(&up["rotation"], 90.0));
+        // Rotated a quarter turn: the run extends upwards from (300, 300).
+        assert!(close(&up["height"], 2.0 * 600.0 * 10.0 / 1000.0));
+        assert!(close(&up["y"], 300.0));
+    }
+
+    #[test]
+    fn glyph_level_keeps_every_glyph() {
+        let glyphs = extract(&styled_pdf(), 1, 1).unwrap();
+        let text: String = glyphs.iter().map(|g| g["text"].as_str().unwrap()).collect();
+        assert_eq!(text, "Hello brave worldRedUp");
+        assert!(glyphs
+            .windows(2)
+            .take(4)
+            .all(|w| w[0]["x"].as_f64() < w[1]["x"].as_f64()));
+    }
+
+    fn stream(body: &str) -> Vec<u8> {
+        format!("<< /Length {} >>\nstream\n{body}\nendstream", body.len()).into_bytes()
+    }
+
+    /// One page showing the hex string `codes` in a `Type0` font with
+    /// `/Encoding` `encoding` (object 6), CIDFont widths `widths` and a
+    /// `ToUnicode` CMap mapping `A`, `B`, `<8140>` and `<889F>`.
+    fn type0_pdf(encoding: &[u8], widths: &str, codes: &str) -> Vec<u8> {
+        let to_unicode = "/CIDInit /ProcSet findresource begin 12 dict begin begincmap\n\
+            2 begincodespacerange <00> <80> <8140> <9FFC> endcodespacerange\n\
+            4 beginbfchar <41> <0041> <42> <0042> <8140> <4E00> <889F> <4E9C> endbfchar\n\
+            endcmap CMapName currentdict /CMap defineresource pop end end";
+        crate::test_fixtures::raw_pdf(&[
+            b"<< /Type /Catalog /Pages 2 0 R >>".to_vec(),
+            b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_vec(),
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] \
+              /Resources << /Font << /F1 5 0 R >> >> /Contents 4 0 R >>"
+                .to_vec(),
+            stream(&format!("BT /F1 10 Tf 100 700 Td <{codes}> Tj ET")),
+            b"<< /Type /Font /Subtype /Type0 /BaseFont /Mixed /Encoding 6 0 R \
+              /DescendantFonts [8 0 R] /ToUnicode 7 0 R >>"
+                .to_vec(),
+            encoding.to_vec(),
+            stream(to_unicode),
+            format!(
+                "<< /Type /Font /Subtype /CIDFontType0 /BaseFont /Mixed \
+                 /CIDSystemInfo << /Registry (Adobe) /Ordering (Japan1) /Supplement 0 >> \
+                 /DW 1000 {widths} >>"
+            )
+            .into_bytes(),
+        ])
+    }
+
+    fn texts_and_widths(glyphs: &[Value]) -> (Vec<String>, Vec<f64>) {
+        glyphs
+            .iter()
+            .map(|g| {
+                (
+                    g["text"].as_str().unwrap().to_owned(),
+                    g["width"].as_f64().unwrap(),
+                )
+            })
+            .unzip()
+    }
+
+    #[test]
+    fn non_identity_type0_codes_follow_the_codespace() {
+        // An embedded CMap mixing one-byte (`<00>`–`<7F>`) and two-byte
+        // (`<8140>`–`<9FFC>`) codes.
+        let encoding = stream(
+            "/CIDInit /ProcSet findresource begin 12 dict begin begincmap\n\
+            /CMapName /Mixed-H def\n\
+            2 begincodespacerange <00> <7F> <8140> <9FFC> endcodespacerange\n\
+            1 begincidrange <20> <7E> 1 endcidrange\n\
+            1 begincidchar <8140> 100 endcidchar\n\
+            endcmap CMapName currentdict /CMap defineresource pop end end",
+        );
+        let pdf = type0_pdf(&encoding, "/W [34 [500 600]]", "41428140");
+        let glyphs = extract(&pdf, 1, 1).unwrap();
+        let (texts, widths) = texts_and_widths(&glyphs);
+        assert_eq!(texts, ["A", "B", "\u{4E00}"]);
+        // `A` and `B` are CIDs 34 and 35 (W), the two-byte code CID 100 (DW).
+        assert_eq!(widths, [5.0, 6.0, 10.0]);
+        assert!(close(&glyphs[2]["x"], 111.0));
+    }
+
+    #[test]
+    fn vendored_predefined_cmaps_split_codes_and_select_cids() {
+        // 90ms-RKSJ-H: `A` is CID 231 + 0x21, `<889F>` CID 1125.
+        let pdf = type0_pdf(b"/90ms-RKSJ-H", "/W [264 [500] 1125 [800]]", "41889F");
+        let (texts, widths) = texts_and_widths(&extract(&pdf, 1, 1).unwrap());
+        assert_eq!(texts, ["A", "\u{4E9C}"]);
+        assert_eq!(widths, [5.0, 8.0]);
+    }
+
+    #[test]
+    fn unicode_cmaps_decode_utf16_when_widths_are_uniform() {
+        let pdf = type0_pdf(b"/UniJIS-UCS2-H", "", "4E9C0041");
+        let (texts, widths) = texts_and_widths(&extract(&pdf, 1, 1).unwrap());
+        // `<4E9C>` has no `ToUnicode` entry and decodes as UTF-16BE.
+        assert_eq!(texts, ["\u{4E9C}", "A"]);
+        assert_eq!(widths, [10.0, 10.0]);
+
+        let pdf = type0_pdf(b"/UniJIS-UTF16-H", "", "D840DC0B");
+        let (texts, _) = texts_and_widths(&extract(&pdf, 1, 1).unwrap());
+        assert_eq!(texts, ["\u{2000B}"]);
+    }
+
+    #[test]
+    fn unknown_composite_encodings_are_unsupported() {
+        let unsupported = Err(ErrorCode::Unsupported as c_int);
+        let pdf = type0_pdf(b"/UniJIS-UCS2-H", "/W [1 [500]]", "4E9C");
+        assert_eq!(extract(&pdf, 1, 0), unsupported);
+        let pdf = type0_pdf(b"/EUC-H", "", "4E9C");
+        assert_eq!(extract(&pdf, 1, 0), unsupported);
+
+        let mut err = ptr::null_mut();
+        unsafe { crate::oxidize_get_last_error_json(&mut err) };
+        let json: Value =
+            serde_json::from_str(unsafe { CStr::from_ptr(err) }.to_str().unwrap()).unwrap();
+        unsafe { crate::oxidize_free_string(err) };
+        assert_eq!(json["category"], "unsupported");
+        assert_eq!(json["page_index"], 0);
+        assert!(json["message"]
+            .as_str()
+            .unwrap()
+            .contains("predefined CMap EUC-H"));
+    }
+
+    #[test]
+    fn page_number_is_validated() {
+        let pdf = styled_pdf();
+        assert_eq!(extract(&pdf, 0, 0), Err(ErrorCode::PageOutOfRange as c_int));
+        assert_eq!(extract(&pdf, 2, 0), Err(ErrorCode::PageOutOfRange as c_int));
+        let code = unsafe { oxidize_extract_words(pdf.as_ptr(), 0, 1, 0, ptr::null_mut()) };
+        assert_eq!(code, ErrorCode::NullPointer as c_int);
+    }
+}